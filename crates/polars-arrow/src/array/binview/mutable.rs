@@ -138,12 +138,12 @@ impl<T: ViewType + ?Sized> MutableBinaryViewArray<T> {
     #[inline]
     pub unsafe fn push_view(&mut self, v: View, buffers: &[Buffer<u8>]) {
         let len = v.length;
-        self.total_bytes_len += len as usize;
         if len <= 12 {
+            self.total_bytes_len += len as usize;
             debug_assert!(self.views.capacity() > self.views.len());
             self.views.push_unchecked(v)
         } else {
-            self.total_buffer_len += len as usize;
+            // The lengths are accounted when the value is pushed.
             let data = buffers.get_unchecked_release(v.buffer_idx as usize);
             let offset = v.offset as usize;
             let bytes = data.get_unchecked_release(offset..offset + len as usize);
@@ -152,6 +152,28 @@ impl<T: ViewType + ?Sized> MutableBinaryViewArray<T> {
         }
     }
 
+    /// Add a buffer that views can refer to without copying its bytes, and return its index.
+    pub fn push_buffer(&mut self, buffer: Buffer<u8>) -> u32 {
+        // Views into the buffer in progress refer to it by the index it gets when it's completed.
+        self.finish_in_progress();
+        self.total_buffer_len += buffer.len();
+        self.completed_buffers.push(buffer);
+        (self.completed_buffers.len() - 1).try_into().unwrap()
+    }
+
+    /// Push a valid view that refers to a buffer of this array by index.
+    ///
+    /// # Safety
+    /// The view must be in bounds of its buffer, and its bytes must be valid for `T`.
+    #[inline]
+    pub unsafe fn push_view_unchecked(&mut self, view: View) {
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        self.total_bytes_len += view.length as usize;
+        self.views.push(view)
+    }
+
     #[inline]
     pub fn push_value_ignore_validity<V: AsRef<T>>(&mut self, value: V) {
         let value = value.as_ref();
@@ -16,7 +16,7 @@ mod iterator;
 mod mutable;
 use crate::array::specification::check_indexes_unchecked;
 mod typed_iterator;
-mod value_map;
+pub(crate) mod value_map;
 
 pub use iterator::*;
 pub use mutable::*;
@@ -12,6 +12,7 @@ mod list;
 mod map;
 mod null;
 mod primitive;
+mod run_end_encoded;
 mod struct_;
 mod union;
 mod utf8;
@@ -196,6 +197,18 @@ impl PartialEq<&dyn Array> for MapArray {
     }
 }
 
+impl PartialEq<RunEndEncodedArray> for RunEndEncodedArray {
+    fn eq(&self, other: &Self) -> bool {
+        run_end_encoded::equal(self, other)
+    }
+}
+
+impl PartialEq<&dyn Array> for RunEndEncodedArray {
+    fn eq(&self, other: &&dyn Array) -> bool {
+        equal(self, *other)
+    }
+}
+
 /// Logically compares two [`Array`]s.
 /// Two arrays are logically equal if and only if:
 /// * their data types are equal
@@ -294,5 +307,10 @@ pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
             let rhs = rhs.as_any().downcast_ref().unwrap();
             binary_view::equal::<str>(lhs, rhs)
         },
+        RunEndEncoded => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            run_end_encoded::equal(lhs, rhs)
+        },
     }
 }
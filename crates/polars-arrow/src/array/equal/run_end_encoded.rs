@@ -0,0 +1,7 @@
+use crate::array::{Array, RunEndEncodedArray};
+
+pub(super) fn equal(lhs: &RunEndEncodedArray, rhs: &RunEndEncodedArray) -> bool {
+    lhs.data_type() == rhs.data_type()
+        && lhs.len() == rhs.len()
+        && super::equal(lhs.decode().as_ref(), rhs.decode().as_ref())
+}
@@ -73,6 +73,7 @@ pub fn offset_buffers_children_dictionary(array: &dyn Array) -> BuffersChildren
         Map => ffi_dyn!(array, MapArray),
         BinaryView => ffi_dyn!(array, BinaryViewArray),
         Utf8View => ffi_dyn!(array, Utf8ViewArray),
+        RunEndEncoded => ffi_dyn!(array, RunEndEncodedArray),
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
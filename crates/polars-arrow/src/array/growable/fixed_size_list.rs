@@ -45,7 +45,7 @@ impl<'a> GrowableFixedSizeList<'a> {
             .iter()
             .map(|array| array.values().as_ref())
             .collect::<Vec<_>>();
-        let values = make_growable(&inner, use_validity, 0);
+        let values = make_growable(&inner, use_validity, capacity * size);
 
         Self {
             arrays,
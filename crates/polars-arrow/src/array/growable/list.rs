@@ -3,11 +3,23 @@ use std::sync::Arc;
 use polars_utils::slice::GetSaferUnchecked;
 
 use super::{make_growable, Growable};
-use crate::array::growable::utils::{extend_validity, prepare_validity};
+use crate::array::growable::utils::{extend_validity, extend_validity_copies, prepare_validity};
 use crate::array::{Array, ListArray};
 use crate::bitmap::MutableBitmap;
 use crate::offset::{Offset, Offsets};
 
+/// Returns the range of the values of the lists `start..start + len` as `(start, len)`.
+unsafe fn values_range<O: Offset>(
+    array: &ListArray<O>,
+    start: usize,
+    len: usize,
+) -> (usize, usize) {
+    let offsets = array.offsets().buffer();
+    let end = offsets.get_unchecked_release(start + len).to_usize();
+    let start = offsets.get_unchecked_release(start).to_usize();
+    (start, end - start)
+}
+
 unsafe fn extend_offset_values<O: Offset>(
     growable: &mut GrowableList<'_, O>,
     index: usize,
@@ -15,22 +27,29 @@ unsafe fn extend_offset_values<O: Offset>(
     len: usize,
 ) {
     let array = growable.arrays[index];
-    let offsets = array.offsets();
-
     growable
         .offsets
-        .try_extend_from_slice(offsets, start, len)
+        .try_extend_from_slice(array.offsets(), start, len)
         .unwrap();
 
-    let end = offsets
-        .buffer()
-        .get_unchecked_release(start + len)
-        .to_usize();
-    let start = offsets.buffer().get_unchecked_release(start).to_usize();
-    let len = end - start;
+    let (start, len) = values_range(array, start, len);
     growable.values.extend(index, start, len);
 }
 
+/// Estimates the capacity of the values for `capacity` lists, assuming they are as long as the
+/// lists of `arrays` on average, so nested growables don't reallocate as they grow.
+fn values_capacity<O: Offset>(arrays: &[&ListArray<O>], capacity: usize) -> usize {
+    let (len, values_len) = arrays.iter().fold((0, 0), |(len, values_len), array| {
+        let offsets = array.offsets();
+        (len + array.len(), values_len + offsets.range().to_usize())
+    });
+    if len == 0 {
+        0
+    } else {
+        (values_len as u128 * capacity as u128 / len as u128) as usize
+    }
+}
+
 /// Concrete [`Growable`] for the [`ListArray`].
 pub struct GrowableList<'a, O: Offset> {
     arrays: Vec<&'a ListArray<O>>,
@@ -54,7 +73,7 @@ impl<'a, O: Offset> GrowableList<'a, O> {
             .iter()
             .map(|array| array.values().as_ref())
             .collect::<Vec<_>>();
-        let values = make_growable(&inner, use_validity, 0);
+        let values = make_growable(&inner, use_validity, values_capacity(&arrays, capacity));
 
         Self {
             arrays,
@@ -85,6 +104,19 @@ impl<'a, O: Offset> Growable<'a> for GrowableList<'a, O> {
         extend_offset_values::<O>(self, index, start, len);
     }
 
+    unsafe fn extend_copies(&mut self, index: usize, start: usize, len: usize, copies: usize) {
+        let array = *self.arrays.get_unchecked_release(index);
+        extend_validity_copies(&mut self.validity, array, start, len, copies);
+        for _ in 0..copies {
+            self.offsets
+                .try_extend_from_slice(array.offsets(), start, len)
+                .unwrap();
+        }
+
+        let (start, len) = values_range(array, start, len);
+        self.values.extend_copies(index, start, len, copies);
+    }
+
     fn extend_validity(&mut self, additional: usize) {
         self.offsets.extend_constant(additional);
         if let Some(validity) = &mut self.validity {
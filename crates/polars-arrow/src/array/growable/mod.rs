@@ -27,6 +27,8 @@ pub use dictionary::GrowableDictionary;
 
 mod binview;
 pub use binview::GrowableBinaryViewArray;
+mod run_end_encoded;
+pub use run_end_encoded::GrowableRunEndEncoded;
 mod utils;
 
 /// Describes a struct that can be extended from slices of other pre-existing [`Array`]s.
@@ -163,6 +165,12 @@ pub fn make_growable<'a>(
                 ))
             })
         },
+        RunEndEncoded => dyn_growable!(
+            run_end_encoded::GrowableRunEndEncoded,
+            arrays,
+            use_validity,
+            capacity
+        ),
         Union | Map => unimplemented!(),
     }
 }
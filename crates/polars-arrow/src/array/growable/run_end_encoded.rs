@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use polars_utils::slice::GetSaferUnchecked;
+
+use super::{make_growable, Growable};
+use crate::array::{Array, RunEndEncodedArray, RunIter};
+
+/// Concrete [`Growable`] for the [`RunEndEncodedArray`], which keeps the runs of the arrays
+/// instead of decoding them.
+pub struct GrowableRunEndEncoded<'a> {
+    arrays: Vec<&'a RunEndEncodedArray>,
+    run_ends: Vec<i32>,
+    values: Box<dyn Growable<'a> + 'a>,
+    length: usize,
+}
+
+impl<'a> GrowableRunEndEncoded<'a> {
+    /// Creates a new [`GrowableRunEndEncoded`] bound to `arrays` with a pre-allocated `capacity`.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub fn new(arrays: Vec<&'a RunEndEncodedArray>, use_validity: bool, capacity: usize) -> Self {
+        assert!(!arrays.is_empty());
+
+        let values = arrays
+            .iter()
+            .map(|array| array.values().as_ref())
+            .collect::<Vec<_>>();
+        // The number of runs is unknown, but at most the number of elements.
+        let values = make_growable(&values, use_validity, 0);
+
+        Self {
+            arrays,
+            run_ends: Vec::with_capacity(capacity.min(1024)),
+            values,
+            length: 0,
+        }
+    }
+
+    fn push_run(&mut self, length: usize) {
+        self.length += length;
+        self.run_ends.push(self.length as i32);
+    }
+
+    fn to(&mut self) -> RunEndEncodedArray {
+        let run_ends = std::mem::take(&mut self.run_ends);
+        self.length = 0;
+
+        RunEndEncodedArray::new(
+            self.arrays[0].data_type().clone(),
+            run_ends.into(),
+            self.values.as_box(),
+        )
+    }
+}
+
+impl<'a> Growable<'a> for GrowableRunEndEncoded<'a> {
+    unsafe fn extend(&mut self, index: usize, start: usize, len: usize) {
+        let array = *self.arrays.get_unchecked_release(index);
+        for (value, length) in RunIter::new_range(array, start, len) {
+            self.values.extend(index, value, 1);
+            self.push_run(length);
+        }
+    }
+
+    unsafe fn extend_copies(&mut self, index: usize, start: usize, len: usize, copies: usize) {
+        let array = *self.arrays.get_unchecked_release(index);
+        // Copies of an element are a single run.
+        if len == 1 && copies > 0 {
+            self.values.extend(index, array.run_index(start), 1);
+            self.push_run(copies);
+        } else {
+            for _ in 0..copies {
+                self.extend(index, start, len)
+            }
+        }
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        if additional > 0 {
+            self.values.extend_validity(1);
+            self.push_run(additional);
+        }
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.to())
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.to())
+    }
+}
+
+impl<'a> From<GrowableRunEndEncoded<'a>> for RunEndEncodedArray {
+    fn from(mut val: GrowableRunEndEncoded<'a>) -> Self {
+        val.to()
+    }
+}
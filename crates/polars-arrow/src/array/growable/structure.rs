@@ -3,8 +3,9 @@ use std::sync::Arc;
 use polars_utils::slice::GetSaferUnchecked;
 
 use super::{make_growable, Growable};
-use crate::array::growable::utils::{extend_validity, prepare_validity};
+use crate::array::growable::utils::{extend_validity, extend_validity_copies, prepare_validity};
 use crate::array::{Array, StructArray};
+use crate::bitmap::utils::SlicesIterator;
 use crate::bitmap::MutableBitmap;
 
 /// Concrete [`Growable`] for the [`StructArray`].
@@ -53,6 +54,14 @@ impl<'a> GrowableStruct<'a> {
         }
     }
 
+    fn extend_fields_validity(&mut self, additional: usize) {
+        if additional > 0 {
+            self.values
+                .iter_mut()
+                .for_each(|child| child.extend_validity(additional));
+        }
+    }
+
     fn to(&mut self) -> StructArray {
         let validity = std::mem::take(&mut self.validity);
         let values = std::mem::take(&mut self.values);
@@ -76,17 +85,36 @@ impl<'a> Growable<'a> for GrowableStruct<'a> {
                 .iter_mut()
                 .for_each(|child| child.extend(index, start, len))
         } else {
-            (start..start + len).for_each(|i| {
-                if array.is_valid(i) {
-                    self.values
-                        .iter_mut()
-                        .for_each(|child| child.extend(index, i, 1))
-                } else {
-                    self.values
-                        .iter_mut()
-                        .for_each(|child| child.extend_validity(1))
-                }
-            })
+            // Copy the fields of every run of valid structs at once and null the fields of the
+            // null structs in between.
+            let validity = array
+                .validity()
+                .unwrap()
+                .clone()
+                .sliced_unchecked(start, len);
+            let mut end = 0;
+            for (run_start, run_len) in SlicesIterator::new(&validity) {
+                self.extend_fields_validity(run_start - end);
+                self.values
+                    .iter_mut()
+                    .for_each(|child| child.extend(index, start + run_start, run_len));
+                end = run_start + run_len;
+            }
+            self.extend_fields_validity(len - end);
+        }
+    }
+
+    unsafe fn extend_copies(&mut self, index: usize, start: usize, len: usize, copies: usize) {
+        let array = *self.arrays.get_unchecked_release(index);
+        if array.null_count() == 0 {
+            extend_validity_copies(&mut self.validity, array, start, len, copies);
+            self.values
+                .iter_mut()
+                .for_each(|child| child.extend_copies(index, start, len, copies))
+        } else {
+            for _ in 0..copies {
+                self.extend(index, start, len)
+            }
         }
     }
 
@@ -335,6 +335,7 @@ impl std::fmt::Debug for dyn Array + '_ {
                 })
             },
             Map => fmt_dyn!(self, MapArray, f),
+            RunEndEncoded => fmt_dyn!(self, RunEndEncodedArray, f),
         }
     }
 }
@@ -359,6 +360,7 @@ pub fn new_empty_array(data_type: ArrowDataType) -> Box<dyn Array> {
         Struct => Box::new(StructArray::new_empty(data_type)),
         Union => Box::new(UnionArray::new_empty(data_type)),
         Map => Box::new(MapArray::new_empty(data_type)),
+        RunEndEncoded => Box::new(RunEndEncodedArray::new_empty(data_type)),
         Utf8View => Box::new(Utf8ViewArray::new_empty(data_type)),
         BinaryView => Box::new(BinaryViewArray::new_empty(data_type)),
         Dictionary(key_type) => {
@@ -391,6 +393,7 @@ pub fn new_null_array(data_type: ArrowDataType, length: usize) -> Box<dyn Array>
         Struct => Box::new(StructArray::new_null(data_type, length)),
         Union => Box::new(UnionArray::new_null(data_type, length)),
         Map => Box::new(MapArray::new_null(data_type, length)),
+        RunEndEncoded => Box::new(RunEndEncodedArray::new_null(data_type, length)),
         BinaryView => Box::new(BinaryViewArray::new_null(data_type, length)),
         Utf8View => Box::new(Utf8ViewArray::new_null(data_type, length)),
         Dictionary(key_type) => {
@@ -475,7 +478,7 @@ pub fn to_data(array: &dyn Array) -> arrow_data::ArrayData {
             })
         },
         Map => to_data_dyn!(array, MapArray),
-        BinaryView | Utf8View => todo!(),
+        BinaryView | Utf8View | RunEndEncoded => todo!(),
     }
 }
 
@@ -506,7 +509,7 @@ pub fn from_data(data: &arrow_data::ArrayData) -> Box<dyn Array> {
             })
         },
         Map => Box::new(MapArray::from_data(data)),
-        BinaryView | Utf8View => todo!(),
+        BinaryView | Utf8View | RunEndEncoded => todo!(),
     }
 }
 
@@ -714,6 +717,7 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
         Struct => clone_dyn!(array, StructArray),
         Union => clone_dyn!(array, UnionArray),
         Map => clone_dyn!(array, MapArray),
+        RunEndEncoded => clone_dyn!(array, RunEndEncodedArray),
         BinaryView => clone_dyn!(array, BinaryViewArray),
         Utf8View => clone_dyn!(array, Utf8ViewArray),
         Dictionary(key_type) => {
@@ -741,6 +745,7 @@ mod list;
 mod map;
 mod null;
 mod primitive;
+mod run_end_encoded;
 pub mod specification;
 mod static_array;
 mod static_array_collect;
@@ -766,6 +771,7 @@ pub use binview::{
     MutablePlString, Utf8ViewArray, View, ViewType, INLINE_VIEW_SIZE,
 };
 pub use boolean::{BooleanArray, MutableBooleanArray};
+pub(crate) use dictionary::value_map::ValueMap;
 pub use dictionary::{DictionaryArray, DictionaryKey, MutableDictionaryArray};
 pub use equal::equal;
 pub use fixed_size_binary::{FixedSizeBinaryArray, MutableFixedSizeBinaryArray};
@@ -778,6 +784,7 @@ pub use map::MapArray;
 pub use null::{MutableNullArray, NullArray};
 use polars_error::PolarsResult;
 pub use primitive::*;
+pub use run_end_encoded::{RunEndEncodedArray, RunIter};
 pub use static_array::{ParameterFreeDtypeStaticArray, StaticArray};
 pub use static_array_collect::{ArrayCollectIterExt, ArrayFromIter, ArrayFromIterDtype};
 pub use struct_::{MutableStructArray, StructArray};
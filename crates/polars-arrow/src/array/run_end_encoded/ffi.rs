@@ -0,0 +1,53 @@
+use polars_error::{polars_ensure, PolarsResult};
+
+use super::super::ffi::ToFfi;
+use super::super::Array;
+use super::RunEndEncodedArray;
+use crate::array::{FromFfi, PrimitiveArray};
+use crate::datatypes::ArrowDataType;
+use crate::ffi;
+
+unsafe impl ToFfi for RunEndEncodedArray {
+    fn buffers(&self) -> Vec<Option<*const u8>> {
+        vec![]
+    }
+
+    fn children(&self) -> Vec<Box<dyn Array>> {
+        let run_ends = PrimitiveArray::new(ArrowDataType::Int32, self.run_ends.clone(), None);
+        vec![run_ends.boxed(), self.values.clone()]
+    }
+
+    fn offset(&self) -> Option<usize> {
+        Some(self.offset)
+    }
+
+    fn to_ffi_aligned(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<A: ffi::ArrowArrayRef> FromFfi<A> for RunEndEncodedArray {
+    unsafe fn try_from_ffi(array: A) -> PolarsResult<Self> {
+        let data_type = array.data_type().clone();
+        let offset = array.array().offset();
+        let length = array.array().len();
+
+        let run_ends = ffi::try_from(array.child(0)?)?;
+        let run_ends = run_ends
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i32>>()
+            .unwrap();
+        polars_ensure!(
+            run_ends.null_count() == 0,
+            ComputeError: "the run ends of a run-end encoded array cannot be null"
+        );
+        let values = ffi::try_from(array.child(1)?)?;
+
+        let out = Self::try_new(data_type, run_ends.values().clone(), values)?;
+        polars_ensure!(
+            offset + length <= out.len(),
+            ComputeError: "the offset and length of a run-end encoded array must be within its runs"
+        );
+        Ok(out.sliced(offset, length))
+    }
+}
@@ -0,0 +1,46 @@
+use super::RunEndEncodedArray;
+
+/// Iterator of the runs of a [`RunEndEncodedArray`] as `(value index, length)`.
+#[derive(Clone, Debug)]
+pub struct RunIter<'a> {
+    run_ends: &'a [i32],
+    run: usize,
+    // logical positions into the runs
+    position: usize,
+    end: usize,
+}
+
+impl<'a> RunIter<'a> {
+    #[inline]
+    pub fn new(array: &'a RunEndEncodedArray) -> Self {
+        Self::new_range(array, 0, array.len())
+    }
+
+    /// Returns the runs of the elements `start..start + len` of `array`.
+    #[inline]
+    pub(crate) fn new_range(array: &'a RunEndEncodedArray, start: usize, len: usize) -> Self {
+        Self {
+            run_ends: array.run_ends().as_slice(),
+            run: array.run_index(start),
+            position: array.logical_offset() + start,
+            end: array.logical_offset() + start + len,
+        }
+    }
+}
+
+impl<'a> Iterator for RunIter<'a> {
+    type Item = (usize, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position == self.end {
+            return None;
+        }
+        let run = self.run;
+        let run_end = (self.run_ends[run] as usize).min(self.end);
+        let length = run_end - self.position;
+        self.position = run_end;
+        self.run += 1;
+        Some((run, length))
+    }
+}
@@ -0,0 +1,259 @@
+use polars_error::{polars_bail, PolarsResult};
+
+use super::growable::make_growable;
+use super::{new_empty_array, new_null_array, Array, Splitable};
+use crate::bitmap::Bitmap;
+use crate::buffer::Buffer;
+use crate::datatypes::{ArrowDataType, Field};
+
+mod ffi;
+pub(super) mod fmt;
+mod iterator;
+
+pub use iterator::RunIter;
+
+/// An array of which runs of equal values are stored once, together with the (exclusive) end
+/// of every run. This keeps highly repetitive data, such as flags or constant partitions,
+/// compressed in memory.
+///
+/// As in the Arrow specification, slicing doesn't touch the runs but changes the logical
+/// offset and length of the array. The array has no validity of its own, its nulls are the null
+/// values.
+#[derive(Clone)]
+pub struct RunEndEncodedArray {
+    data_type: ArrowDataType,
+    // invariant: strictly increasing, positive and as long as `values`
+    run_ends: Buffer<i32>,
+    values: Box<dyn Array>,
+    // invariant: offset + length <= last run end
+    offset: usize,
+    length: usize,
+}
+
+impl RunEndEncodedArray {
+    /// Returns a new [`RunEndEncodedArray`].
+    /// # Errors
+    /// This function errors iff:
+    /// * The `data_type`'s physical type is not [`crate::datatypes::PhysicalType::RunEndEncoded`]
+    /// * The `values`' `data_type` is not equal to the values field of `data_type`
+    /// * The `run_ends` are not as long as the `values`
+    /// * The `run_ends` are not positive and strictly increasing
+    pub fn try_new(
+        data_type: ArrowDataType,
+        run_ends: Buffer<i32>,
+        values: Box<dyn Array>,
+    ) -> PolarsResult<Self> {
+        if Self::try_get_field(&data_type)?.data_type() != values.data_type() {
+            polars_bail!(ComputeError: "RunEndEncodedArray expects `values.data_type` to match its values field")
+        }
+        if run_ends.len() != values.len() {
+            polars_bail!(ComputeError: "RunEndEncodedArray expects as many run ends as values")
+        }
+        let mut previous = 0;
+        for &end in run_ends.iter() {
+            if end <= previous {
+                polars_bail!(ComputeError: "the run ends of a RunEndEncodedArray must be positive and strictly increasing")
+            }
+            previous = end;
+        }
+
+        Ok(Self {
+            data_type,
+            run_ends,
+            values,
+            offset: 0,
+            length: previous as usize,
+        })
+    }
+
+    /// Creates a new [`RunEndEncodedArray`].
+    /// # Panics
+    /// Panics iff [`Self::try_new`] errors.
+    pub fn new(data_type: ArrowDataType, run_ends: Buffer<i32>, values: Box<dyn Array>) -> Self {
+        Self::try_new(data_type, run_ends, values).unwrap()
+    }
+
+    /// Returns a new empty [`RunEndEncodedArray`].
+    pub fn new_empty(data_type: ArrowDataType) -> Self {
+        let values = new_empty_array(Self::get_field(&data_type).data_type().clone());
+        Self::new(data_type, Buffer::new(), values)
+    }
+
+    /// Returns a new [`RunEndEncodedArray`] of `length` nulls, which is a single run.
+    pub fn new_null(data_type: ArrowDataType, length: usize) -> Self {
+        if length == 0 {
+            return Self::new_empty(data_type);
+        }
+        let values = new_null_array(Self::get_field(&data_type).data_type().clone(), 1);
+        Self::new(data_type, vec![length as i32].into(), values)
+    }
+
+    /// Run-end encodes `values` that are already in runs, e.g. the values of a run-end encoded
+    /// IPC or FFI array, of which the run ends are `run_ends`.
+    pub fn from_runs(values: Box<dyn Array>, run_ends: Buffer<i32>) -> PolarsResult<Self> {
+        let field = Field::new("values", values.data_type().clone(), true);
+        Self::try_new(
+            ArrowDataType::RunEndEncoded(Box::new(field)),
+            run_ends,
+            values,
+        )
+    }
+
+    pub(crate) fn try_get_field(data_type: &ArrowDataType) -> PolarsResult<&Field> {
+        if let ArrowDataType::RunEndEncoded(field) = data_type.to_logical_type() {
+            Ok(field.as_ref())
+        } else {
+            polars_bail!(ComputeError: "The data_type's logical type must be DataType::RunEndEncoded")
+        }
+    }
+
+    pub(crate) fn get_field(data_type: &ArrowDataType) -> &Field {
+        Self::try_get_field(data_type).unwrap()
+    }
+}
+
+impl RunEndEncodedArray {
+    /// Slices this [`RunEndEncodedArray`].
+    /// # Panics
+    /// panics iff `offset + length > self.len()`
+    pub fn slice(&mut self, offset: usize, length: usize) {
+        assert!(
+            offset + length <= self.len(),
+            "the offset of the new array cannot exceed the existing length"
+        );
+        unsafe { self.slice_unchecked(offset, length) }
+    }
+
+    /// Slices this [`RunEndEncodedArray`].
+    ///
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[inline]
+    pub unsafe fn slice_unchecked(&mut self, offset: usize, length: usize) {
+        self.offset += offset;
+        self.length = length;
+    }
+
+    impl_sliced!();
+    impl_into_array!();
+}
+
+// Accessors
+impl RunEndEncodedArray {
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns the logical offset of this array into its runs.
+    #[inline]
+    pub fn logical_offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the (exclusive) ends of the runs, which are not affected by slicing.
+    #[inline]
+    pub fn run_ends(&self) -> &Buffer<i32> {
+        &self.run_ends
+    }
+
+    /// Returns the value of every run, which are not affected by slicing.
+    #[inline]
+    pub fn values(&self) -> &Box<dyn Array> {
+        &self.values
+    }
+
+    /// Returns the index of the run of the element at `i`.
+    #[inline]
+    pub fn run_index(&self, i: usize) -> usize {
+        let i = (self.offset + i) as i32;
+        self.run_ends.partition_point(|&end| end <= i)
+    }
+
+    /// Returns an iterator over the runs of this array as `(value index, length)`, where the
+    /// first and last runs are cut to the logical slice of this array.
+    pub fn runs(&self) -> RunIter<'_> {
+        RunIter::new(self)
+    }
+
+    /// Returns the number of nulls, which are the elements of the runs with a null value.
+    pub fn null_count(&self) -> usize {
+        if self.values.null_count() == 0 {
+            return 0;
+        }
+        self.runs()
+            .filter(|(value, _)| self.values.is_null(*value))
+            .map(|(_, length)| length)
+            .sum()
+    }
+
+    /// Returns an equal array without a logical offset, of which the runs are cut to the
+    /// logical slice of this array. Formats that don't know offsets, such as IPC, need this.
+    pub fn trimmed(&self) -> Self {
+        if self.offset == 0 && self.run_ends.last().copied() == Some(self.length as i32) {
+            return self.clone();
+        }
+        if self.length == 0 {
+            return Self::new_empty(self.data_type.clone());
+        }
+        let first = self.run_index(0);
+        let mut end = 0;
+        let run_ends = self
+            .runs()
+            .map(|(_, length)| {
+                end += length as i32;
+                end
+            })
+            .collect::<Vec<_>>();
+        let values = self.values.sliced(first, run_ends.len());
+        Self::new(self.data_type.clone(), run_ends.into(), values)
+    }
+
+    /// Decodes this array into a plain array of its values.
+    pub fn decode(&self) -> Box<dyn Array> {
+        let mut growable = make_growable(&[self.values.as_ref()], true, self.length);
+        for (value, length) in self.runs() {
+            unsafe { growable.extend_copies(0, value, 1, length) }
+        }
+        growable.as_box()
+    }
+}
+
+impl Array for RunEndEncodedArray {
+    impl_common_array!();
+
+    fn validity(&self) -> Option<&Bitmap> {
+        None
+    }
+
+    #[inline]
+    fn null_count(&self) -> usize {
+        self.null_count()
+    }
+
+    #[inline]
+    unsafe fn is_null_unchecked(&self, i: usize) -> bool {
+        self.values.is_null_unchecked(self.run_index(i))
+    }
+
+    /// A [`RunEndEncodedArray`] has no validity of its own, so with a validity it is decoded.
+    fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
+        match validity {
+            None => self.clone().boxed(),
+            Some(validity) => self.decode().with_validity(Some(validity)),
+        }
+    }
+}
+
+impl Splitable for RunEndEncodedArray {
+    fn check_bound(&self, offset: usize) -> bool {
+        offset <= self.len()
+    }
+
+    unsafe fn _split_at_unchecked(&self, offset: usize) -> (Self, Self) {
+        let lhs = self.clone().sliced_unchecked(0, offset);
+        let rhs = self.clone().sliced_unchecked(offset, self.length - offset);
+        (lhs, rhs)
+    }
+}
@@ -90,27 +90,64 @@ where
     Bitmap::from_u8_vec(buffer, length)
 }
 
-/// Apply a bitwise operation `op` to two inputs and return the result as a [`Bitmap`].
-pub fn binary<F>(lhs: &Bitmap, rhs: &Bitmap, op: F) -> Bitmap
+fn binary_impl<F, I, J>(lhs_chunks: I, rhs_chunks: J, op: F, length: usize) -> Bitmap
 where
+    I: BitChunkIterExact<u64>,
+    J: BitChunkIterExact<u64>,
     F: Fn(u64, u64) -> u64,
 {
-    assert_eq!(lhs.len(), rhs.len());
-    let lhs_chunks = lhs.chunks();
-    let rhs_chunks = rhs.chunks();
-    let rem_lhs = lhs_chunks.remainder();
-    let rem_rhs = rhs_chunks.remainder();
-
+    let rem = op(lhs_chunks.remainder(), rhs_chunks.remainder());
     let chunks = lhs_chunks
         .zip(rhs_chunks)
         .map(|(left, right)| op(left, right));
 
-    let buffer = chunk_iter_to_vec_and_remainder(chunks, op(rem_lhs, rem_rhs));
-    let length = lhs.len();
-
+    let buffer = chunk_iter_to_vec_and_remainder(chunks, rem);
     Bitmap::from_u8_vec(buffer, length)
 }
 
+/// Apply a bitwise operation `op` to two inputs and return the result as a [`Bitmap`].
+pub fn binary<F>(lhs: &Bitmap, rhs: &Bitmap, op: F) -> Bitmap
+where
+    F: Fn(u64, u64) -> u64,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let (lhs_slice, lhs_offset, length) = lhs.as_slice();
+    let (rhs_slice, rhs_offset, _) = rhs.as_slice();
+    if lhs_offset == 0 && rhs_offset == 0 {
+        // Without offsets no bits have to be shifted between the words, which lets the
+        // compiler vectorize the operation.
+        let lhs_chunks = BitChunksExact::<u64>::new(lhs_slice, length);
+        let rhs_chunks = BitChunksExact::<u64>::new(rhs_slice, length);
+        binary_impl(lhs_chunks, rhs_chunks, op, length)
+    } else {
+        binary_impl(lhs.chunks(), rhs.chunks(), op, length)
+    }
+}
+
+/// Apply a bitwise predicate `op` to the words of two inputs and return whether it holds for any
+/// of them, stopping at the first word for which it does. `op` must not hold for two zero words,
+/// as the bits past the end are zeroed.
+fn binary_any<F>(lhs: &Bitmap, rhs: &Bitmap, op: F) -> bool
+where
+    F: Fn(u64, u64) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let mut lhs_chunks = lhs.chunks::<u64>();
+    let mut rhs_chunks = rhs.chunks::<u64>();
+    let rem_mask = match lhs_chunks.remainder_len() {
+        0 => 0,
+        len => u64::MAX >> (64 - len),
+    };
+    let rem_lhs = lhs_chunks.remainder() & rem_mask;
+    let rem_rhs = rhs_chunks.remainder() & rem_mask;
+
+    lhs_chunks
+        .by_ref()
+        .zip(rhs_chunks.by_ref())
+        .any(|(left, right)| op(left, right))
+        || op(rem_lhs, rem_rhs)
+}
+
 /// Apply a bitwise operation `op` to two inputs and fold the result.
 pub fn binary_fold<B, F, R>(lhs: &Bitmap, rhs: &Bitmap, op: F, init: B, fold: R) -> B
 where
@@ -198,6 +235,13 @@ pub fn and(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
     if lhs.unset_bits() == lhs.len() || rhs.unset_bits() == rhs.len() {
         assert_eq!(lhs.len(), rhs.len());
         Bitmap::new_zeroed(lhs.len())
+    } else if lhs.unset_bits() == 0 {
+        // An all-true mask doesn't change the other one.
+        assert_eq!(lhs.len(), rhs.len());
+        rhs.clone()
+    } else if rhs.unset_bits() == 0 {
+        assert_eq!(lhs.len(), rhs.len());
+        lhs.clone()
     } else {
         binary(lhs, rhs, |x, y| x & y)
     }
@@ -215,6 +259,13 @@ pub fn or(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
         let mut mutable = MutableBitmap::with_capacity(lhs.len());
         mutable.extend_constant(lhs.len(), true);
         mutable.into()
+    } else if lhs.unset_bits() == lhs.len() {
+        // An all-false mask doesn't change the other one.
+        assert_eq!(lhs.len(), rhs.len());
+        rhs.clone()
+    } else if rhs.unset_bits() == rhs.len() {
+        assert_eq!(lhs.len(), rhs.len());
+        lhs.clone()
     } else {
         binary(lhs, rhs, |x, y| x | y)
     }
@@ -281,13 +332,16 @@ pub fn num_intersections_with(lhs: &Bitmap, rhs: &Bitmap) -> usize {
 }
 
 pub fn intersects_with(lhs: &Bitmap, rhs: &Bitmap) -> bool {
-    binary_fold(
-        lhs,
-        rhs,
-        |lhs, rhs| lhs & rhs != 0,
-        false,
-        |lhs, rhs| lhs || rhs,
-    )
+    binary_any(lhs, rhs, |lhs, rhs| lhs & rhs != 0)
+}
+
+/// Returns whether every set bit of `lhs` is also set in `rhs`.
+pub fn is_subset_of(lhs: &Bitmap, rhs: &Bitmap) -> bool {
+    if lhs.unset_bits() == lhs.len() || rhs.unset_bits() == 0 {
+        assert_eq!(lhs.len(), rhs.len());
+        return true;
+    }
+    !binary_any(lhs, rhs, |lhs, rhs| lhs & !rhs != 0)
 }
 
 pub fn intersects_with_mut(lhs: &MutableBitmap, rhs: &MutableBitmap) -> bool {
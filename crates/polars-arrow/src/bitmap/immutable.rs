@@ -6,7 +6,10 @@ use either::Either;
 use polars_error::{polars_bail, PolarsResult};
 
 use super::utils::{count_zeros, fmt, get_bit, get_bit_unchecked, BitChunk, BitChunks, BitmapIter};
-use super::{chunk_iter_to_vec, intersects_with, num_intersections_with, IntoIter, MutableBitmap};
+use super::{
+    chunk_iter_to_vec, intersects_with, is_subset_of, num_intersections_with, IntoIter,
+    MutableBitmap,
+};
 use crate::array::Splitable;
 use crate::bitmap::aligned::AlignedBitmapSlice;
 use crate::bitmap::iterator::{
@@ -481,6 +484,13 @@ impl Bitmap {
         intersects_with(self, other)
     }
 
+    /// Checks whether every set bit of this [`Bitmap`] is also set in `other`.
+    ///
+    /// This is an optimized version of `(self & !other) == 0000..`.
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        is_subset_of(self, other)
+    }
+
     /// Calculates the number of shared set bits between two [`Bitmap`]s.
     pub fn num_intersections_with(&self, other: &Self) -> usize {
         num_intersections_with(self, other)
@@ -78,6 +78,40 @@ pub fn bytes_for(bits: usize) -> usize {
     bits.saturating_add(7) / 8
 }
 
+/// Counts the set bits of `words`, with the hardware popcount instruction if the CPU has it.
+fn count_ones_bulk(words: &[u64]) -> usize {
+    #[cfg(target_arch = "x86_64")]
+    if std::arch::is_x86_feature_detected!("popcnt") {
+        // SAFETY: we just checked the CPU supports popcnt.
+        return unsafe { count_ones_bulk_popcnt(words) };
+    }
+    count_ones_bulk_impl(words)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "popcnt")]
+unsafe fn count_ones_bulk_popcnt(words: &[u64]) -> usize {
+    count_ones_bulk_impl(words)
+}
+
+#[inline(always)]
+fn count_ones_bulk_impl(words: &[u64]) -> usize {
+    // Independent accumulators don't wait on each other's additions.
+    let mut counts = [0usize; 4];
+    let mut chunks = words.chunks_exact(4);
+    for chunk in chunks.by_ref() {
+        for (count, word) in counts.iter_mut().zip(chunk) {
+            *count += word.count_ones() as usize;
+        }
+    }
+    let rest: usize = chunks
+        .remainder()
+        .iter()
+        .map(|w| w.count_ones() as usize)
+        .sum();
+    counts.iter().sum::<usize>() + rest
+}
+
 /// Returns the number of zero bits in the slice offsetted by `offset` and a length of `length`.
 /// # Panics
 /// This function panics iff `offset + len > 8 * slice.len()``.
@@ -100,7 +134,7 @@ pub fn count_zeros(slice: &[u8], offset: usize, len: usize) -> usize {
 
     let aligned = AlignedBitmapSlice::<u64>::new(slice, offset, len);
     let ones_in_prefix = aligned.prefix().count_ones() as usize;
-    let ones_in_bulk: usize = aligned.bulk_iter().map(|w| w.count_ones() as usize).sum();
+    let ones_in_bulk = count_ones_bulk(aligned.bulk());
     let ones_in_suffix = aligned.suffix().count_ones() as usize;
     len - ones_in_prefix - ones_in_bulk - ones_in_suffix
 }
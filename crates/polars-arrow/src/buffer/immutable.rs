@@ -1,10 +1,11 @@
 use std::ops::Deref;
+use std::panic::RefUnwindSafe;
 use std::sync::Arc;
 
 use either::Either;
 use num_traits::Zero;
 
-use super::{Bytes, IntoIter};
+use super::{Bytes, BytesAllocator, IntoIter};
 use crate::array::{ArrayAccessor, Splitable};
 
 /// [`Buffer`] is a contiguous memory region that can be shared across
@@ -260,6 +261,20 @@ impl<T: Zero + Copy> Buffer<T> {
     }
 }
 
+impl Buffer<u8> {
+    /// Creates a [`Buffer`] of the bytes of `owner` without copying them, e.g. of a memory
+    /// mapped file. `owner` is dropped when the buffer and all its clones are dropped.
+    pub fn from_owner<O: AsRef<[u8]> + Send + Sync + RefUnwindSafe + 'static>(owner: O) -> Self {
+        let owner: Box<dyn AsRef<[u8]> + Send + Sync + RefUnwindSafe> = Box::new(owner);
+        let bytes = (*owner).as_ref();
+        let (ptr, length) = (bytes.as_ptr(), bytes.len());
+        // SAFETY: the owner is boxed, so moving it doesn't move the bytes, and it keeps them
+        // valid until it is dropped with the storage.
+        let bytes = unsafe { Bytes::from_foreign(ptr, length, BytesAllocator::Owner(owner)) };
+        Self::from_bytes(bytes)
+    }
+}
+
 impl<T> From<Vec<T>> for Buffer<T> {
     #[inline]
     fn from(p: Vec<T>) -> Self {
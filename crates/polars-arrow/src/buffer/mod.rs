@@ -4,6 +4,7 @@ mod immutable;
 mod iterator;
 
 use std::ops::Deref;
+use std::panic::RefUnwindSafe;
 
 use crate::ffi::InternalArrowArray;
 
@@ -18,6 +19,10 @@ pub(crate) enum BytesAllocator {
     // remove once fixed in rustc
     #[allow(dead_code)]
     Arrow(arrow_buffer::Buffer),
+
+    // Only held to keep the bytes alive, see [`Buffer::from_owner`].
+    #[allow(dead_code)]
+    Owner(Box<dyn AsRef<[u8]> + Send + Sync + RefUnwindSafe>),
 }
 pub(crate) type BytesInner<T> = foreign_vec::ForeignVec<BytesAllocator, T>;
 
@@ -127,5 +127,10 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
             let offsets = array.offsets().len_proxy() * std::mem::size_of::<i32>();
             offsets + estimated_bytes_size(array.field().as_ref()) + validity_size(array.validity())
         },
+        RunEndEncoded => {
+            let array = array.as_any().downcast_ref::<RunEndEncodedArray>().unwrap();
+            array.run_ends().len() * std::mem::size_of::<i32>()
+                + estimated_bytes_size(array.values().as_ref())
+        },
     }
 }
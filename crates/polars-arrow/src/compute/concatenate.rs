@@ -16,8 +16,12 @@
 use polars_error::{polars_bail, PolarsResult};
 
 use crate::array::growable::make_growable;
-use crate::array::Array;
+use crate::array::{
+    Array, BinaryViewArray, DictionaryArray, DictionaryKey, MutableBinaryViewArray, PrimitiveArray,
+    Utf8ViewArray, ValueMap,
+};
 use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::datatypes::ArrowDataType;
 
 /// Concatenate multiple [Array] of the same type into a single [`Array`].
 pub fn concatenate(arrays: &[&dyn Array]) -> PolarsResult<Box<dyn Array>> {
@@ -45,6 +49,89 @@ pub fn concatenate(arrays: &[&dyn Array]) -> PolarsResult<Box<dyn Array>> {
     Ok(mutable.as_box())
 }
 
+/// Concatenate multiple [`DictionaryArray`]s of which the values are `Utf8View` or `BinaryView`
+/// into a single [`DictionaryArray`].
+///
+/// Unlike [`concatenate`], which appends the dictionaries one after the other, this merges the
+/// dictionaries by hashing their values, so every distinct value is stored once, and remaps the
+/// keys of each array to the merged dictionary. Keys that point to a null value become null.
+pub fn concatenate_dictionaries<K: DictionaryKey>(
+    arrays: &[&DictionaryArray<K>],
+) -> PolarsResult<DictionaryArray<K>> {
+    if arrays.is_empty() {
+        polars_bail!(InvalidOperation: "concat requires input of at least one array")
+    }
+    let data_type = arrays[0].data_type();
+    if arrays.iter().any(|array| array.data_type() != data_type) {
+        polars_bail!(InvalidOperation: "It is not possible to concatenate arrays of different data types.")
+    }
+    let ArrowDataType::Dictionary(_, values_type, _) = data_type.to_logical_type() else {
+        unreachable!()
+    };
+    let is_utf8 = match values_type.as_ref() {
+        ArrowDataType::Utf8View => true,
+        ArrowDataType::BinaryView => false,
+        dt => {
+            polars_bail!(InvalidOperation: "merging dictionaries requires view values, got {:?}", dt)
+        },
+    };
+    let dictionaries = arrays
+        .iter()
+        .map(|array| {
+            let values = array.values().as_any();
+            if is_utf8 {
+                values.downcast_ref::<Utf8ViewArray>().unwrap().to_binview()
+            } else {
+                values.downcast_ref::<BinaryViewArray>().unwrap().clone()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let capacity = arrays.iter().map(|array| array.len()).sum();
+    let mut keys = Vec::<K>::with_capacity(capacity);
+    let mut validity = MutableBitmap::with_capacity(capacity);
+    let mut map = ValueMap::<K, MutableBinaryViewArray<[u8]>>::try_empty(
+        MutableBinaryViewArray::with_capacity(dictionaries[0].len()),
+    )?;
+
+    for (array, dictionary) in arrays.iter().zip(&dictionaries) {
+        // The merged key of every value of this dictionary, or `None` for a null value.
+        let remap = dictionary
+            .iter()
+            .map(|value| {
+                value
+                    .map(|value| {
+                        map.try_push_valid(value, |values, value| {
+                            values.push_value(value);
+                            Ok(())
+                        })
+                    })
+                    .transpose()
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        for key in array.keys().iter() {
+            // SAFETY: the invariants of a `DictionaryArray` guarantee the keys are in bounds.
+            let key = key.and_then(|key| unsafe { *remap.get_unchecked(key.as_usize()) });
+            keys.push(key.unwrap_or_default());
+            validity.push(key.is_some());
+        }
+    }
+
+    let values = map.into_values().freeze();
+    let values = if is_utf8 {
+        // SAFETY: the merged values are taken from `Utf8View` dictionaries.
+        unsafe { values.to_utf8view_unchecked() }.boxed()
+    } else {
+        values.boxed()
+    };
+    let validity: Option<Bitmap> = validity.into();
+    let keys = PrimitiveArray::<K>::new(K::PRIMITIVE.into(), keys.into(), validity);
+
+    // SAFETY: every key is a valid index into the merged values.
+    unsafe { DictionaryArray::try_new_unchecked(data_type.clone(), keys, values) }
+}
+
 /// Concatenate the validities of multiple [Array]s into a single Bitmap.
 pub fn concatenate_validities(arrays: &[&dyn Array]) -> Option<Bitmap> {
     let null_count: usize = arrays.iter().map(|a| a.null_count()).sum();
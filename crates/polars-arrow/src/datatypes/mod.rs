@@ -152,6 +152,10 @@ pub enum ArrowDataType {
     ///
     /// The `bool` value indicates the `Dictionary` is sorted if set to `true`.
     Dictionary(IntegerType, Box<ArrowDataType>, bool),
+    /// A run-end encoded type, in which runs of equal values are stored once, together with the
+    /// (exclusive) end of every run. The field is the field of the values, the run ends are
+    /// always `Int32`.
+    RunEndEncoded(Box<Field>),
     /// Decimal value with precision and scale
     /// precision is the number of digits in the number and
     /// scale is the number of decimal places.
@@ -226,6 +230,10 @@ impl From<ArrowDataType> for arrow_schema::DataType {
                 Box::new(ArrowDataType::from(key).into()),
                 Box::new((*value).into()),
             ),
+            ArrowDataType::RunEndEncoded(values) => Self::RunEndEncoded(
+                Arc::new(ArrowField::new("run_ends", Self::Int32, false)),
+                Arc::new((*values).into()),
+            ),
             ArrowDataType::Decimal(precision, scale) => {
                 Self::Decimal128(precision as _, scale as _)
             },
@@ -299,8 +307,13 @@ impl From<arrow_schema::DataType> for ArrowDataType {
             },
             DataType::Decimal128(precision, scale) => Self::Decimal(precision as _, scale as _),
             DataType::Decimal256(precision, scale) => Self::Decimal256(precision as _, scale as _),
-            DataType::RunEndEncoded(_, _) => {
-                panic!("Run-end encoding not supported by polars_arrow")
+            DataType::RunEndEncoded(run_ends, values) => {
+                assert_eq!(
+                    run_ends.data_type(),
+                    &DataType::Int32,
+                    "only Int32 run ends are supported by polars_arrow"
+                );
+                Self::RunEndEncoded(Box::new(values.as_ref().into()))
             },
             // This ensures that it doesn't fail to compile when new variants are added to Arrow
             #[allow(unreachable_patterns)]
@@ -476,6 +489,7 @@ impl ArrowDataType {
             Union(_, _, _) => PhysicalType::Union,
             Map(_, _) => PhysicalType::Map,
             Dictionary(key, _, _) => PhysicalType::Dictionary(*key),
+            RunEndEncoded(_) => PhysicalType::RunEndEncoded,
             Extension(_, key, _) => key.to_physical_type(),
             Unknown => unimplemented!(),
         }
@@ -520,6 +534,7 @@ impl ArrowDataType {
             Dictionary(keys, _, _) => (*keys).into(),
             Union(_, _, _) => unimplemented!(),
             Map(_, _) => unimplemented!(),
+            RunEndEncoded(_) => unimplemented!(),
             Extension(_, inner, _) => inner.underlying_physical_type(),
             _ => self.clone(),
         }
@@ -576,7 +591,8 @@ impl From<PrimitiveType> for ArrowDataType {
             PrimitiveType::UInt16 => ArrowDataType::UInt16,
             PrimitiveType::UInt32 => ArrowDataType::UInt32,
             PrimitiveType::UInt64 => ArrowDataType::UInt64,
-            PrimitiveType::Int128 => ArrowDataType::Decimal(32, 32),
+            // A decimal without scale, scaled decimals relabel their arrays.
+            PrimitiveType::Int128 => ArrowDataType::Decimal(38, 0),
             PrimitiveType::Int256 => ArrowDataType::Decimal256(32, 32),
             PrimitiveType::Float16 => ArrowDataType::Float16,
             PrimitiveType::Float32 => ArrowDataType::Float32,
@@ -39,6 +39,8 @@ pub enum PhysicalType {
     Map,
     /// A dictionary encoded array by `IntegerType`.
     Dictionary(IntegerType),
+    /// A run-end encoded array, of which runs of equal values are stored once.
+    RunEndEncoded,
     /// A binary type that inlines small values
     /// and can intern bytes.
     BinaryView,
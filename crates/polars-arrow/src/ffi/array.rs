@@ -43,6 +43,7 @@ pub unsafe fn try_from<A: ArrowArrayRef>(array: A) -> PolarsResult<Box<dyn Array
         Map => Box::new(MapArray::try_from_ffi(array)?),
         BinaryView => Box::new(BinaryViewArray::try_from_ffi(array)?),
         Utf8View => Box::new(Utf8ViewArray::try_from_ffi(array)?),
+        RunEndEncoded => Box::new(RunEndEncodedArray::try_from_ffi(array)?),
     })
 }
 
@@ -147,7 +148,11 @@ impl ArrowArray {
             dictionary.map(|array| Box::into_raw(Box::new(ArrowArray::new(array))));
 
         let length = array.len() as i64;
-        let null_count = array.null_count() as i64;
+        // The nulls of a run-end encoded array are in its values, it has no null count itself.
+        let null_count = match array.data_type().to_physical_type() {
+            PhysicalType::RunEndEncoded => 0,
+            _ => array.null_count() as i64,
+        };
 
         let mut private_data = Box::new(PrivateData {
             array,
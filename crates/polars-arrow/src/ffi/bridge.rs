@@ -38,5 +38,6 @@ pub fn align_to_c_data_interface(array: Box<dyn Array>) -> Box<dyn Array> {
         },
         BinaryView => ffi_dyn!(array, BinaryViewArray),
         Utf8View => ffi_dyn!(array, Utf8ViewArray),
+        RunEndEncoded => ffi_dyn!(array, RunEndEncodedArray),
     }
 }
@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 use std::ffi::{CStr, CString};
 use std::ptr;
 
-use polars_error::{polars_bail, polars_err, PolarsResult};
+use polars_error::{polars_bail, polars_ensure, polars_err, PolarsResult};
 
 use super::ArrowSchema;
 use crate::datatypes::{
@@ -53,6 +53,13 @@ fn schema_children(data_type: &ArrowDataType, flags: &mut i64) -> Box<[*mut Arro
             .iter()
             .map(|field| Box::into_raw(Box::new(ArrowSchema::new(field))))
             .collect::<Box<[_]>>(),
+        ArrowDataType::RunEndEncoded(field) => {
+            let run_ends = Field::new("run_ends", ArrowDataType::Int32, false);
+            Box::new([
+                Box::into_raw(Box::new(ArrowSchema::new(&run_ends))),
+                Box::into_raw(Box::new(ArrowSchema::new(field.as_ref()))),
+            ])
+        },
         ArrowDataType::Extension(_, inner, _) => schema_children(inner, flags),
         _ => Box::new([]),
     }
@@ -286,6 +293,14 @@ unsafe fn to_data_type(schema: &ArrowSchema) -> PolarsResult<ArrowDataType> {
             let is_sorted = (schema.flags & 4) != 0;
             ArrowDataType::Map(Box::new(to_field(child)?), is_sorted)
         },
+        "+r" => {
+            let run_ends = to_field(schema.child(0))?;
+            polars_ensure!(
+                run_ends.data_type() == &ArrowDataType::Int32,
+                ComputeError: "only Int32 run ends are supported, found {:?}", run_ends.data_type()
+            );
+            ArrowDataType::RunEndEncoded(Box::new(to_field(schema.child(1))?))
+        },
         "+s" => {
             let children = (0..schema.n_children as usize)
                 .map(|x| to_field(schema.child(x)))
@@ -477,6 +492,7 @@ fn to_format(data_type: &ArrowDataType) -> String {
             r
         },
         ArrowDataType::Map(_, _) => "+m".to_string(),
+        ArrowDataType::RunEndEncoded(_) => "+r".to_string(),
         ArrowDataType::Dictionary(index, _, _) => to_format(&(*index).into()),
         ArrowDataType::Extension(_, inner, _) => to_format(inner.as_ref()),
         ArrowDataType::Unknown => unimplemented!(),
@@ -489,6 +505,8 @@ pub(super) fn get_child(data_type: &ArrowDataType, index: usize) -> PolarsResult
         (0, ArrowDataType::FixedSizeList(field, _)) => Ok(field.data_type().clone()),
         (0, ArrowDataType::LargeList(field)) => Ok(field.data_type().clone()),
         (0, ArrowDataType::Map(field, _)) => Ok(field.data_type().clone()),
+        (0, ArrowDataType::RunEndEncoded(_)) => Ok(ArrowDataType::Int32),
+        (1, ArrowDataType::RunEndEncoded(field)) => Ok(field.data_type().clone()),
         (index, ArrowDataType::Struct(fields)) => Ok(fields[index].data_type().clone()),
         (index, ArrowDataType::Union(fields, _, _)) => Ok(fields[index].data_type().clone()),
         (index, ArrowDataType::Extension(_, subtype, _)) => get_child(subtype, index),
@@ -609,6 +627,7 @@ mod tests {
                 ),
             ]),
             ArrowDataType::Map(Box::new(Field::new("a", ArrowDataType::Int64, true)), true),
+            ArrowDataType::RunEndEncoded(Box::new(Field::new("values", ArrowDataType::Utf8, true))),
             ArrowDataType::Union(
                 vec![
                     Field::new("a", ArrowDataType::Int64, true),
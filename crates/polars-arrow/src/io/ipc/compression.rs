@@ -1,8 +1,10 @@
-#[cfg(feature = "io_ipc_compression")]
+#[cfg(not(all(feature = "io_ipc_compression", not(target_family = "wasm"))))]
+use polars_error::polars_bail;
+#[cfg(all(feature = "io_ipc_compression", not(target_family = "wasm")))]
 use polars_error::to_compute_err;
 use polars_error::PolarsResult;
 
-#[cfg(feature = "io_ipc_compression")]
+#[cfg(all(feature = "io_ipc_compression", not(target_family = "wasm")))]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
 pub fn decompress_lz4(input_buf: &[u8], output_buf: &mut [u8]) -> PolarsResult<()> {
     use std::io::Read;
@@ -10,7 +12,7 @@ pub fn decompress_lz4(input_buf: &[u8], output_buf: &mut [u8]) -> PolarsResult<(
     decoder.read_exact(output_buf).map_err(|e| e.into())
 }
 
-#[cfg(feature = "io_ipc_compression")]
+#[cfg(all(feature = "io_ipc_compression", not(target_family = "wasm")))]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
 pub fn decompress_zstd(input_buf: &[u8], output_buf: &mut [u8]) -> PolarsResult<()> {
     use std::io::Read;
@@ -18,17 +20,23 @@ pub fn decompress_zstd(input_buf: &[u8], output_buf: &mut [u8]) -> PolarsResult<
     decoder.read_exact(output_buf).map_err(|e| e.into())
 }
 
-#[cfg(not(feature = "io_ipc_compression"))]
+#[cfg(not(all(feature = "io_ipc_compression", not(target_family = "wasm"))))]
 pub fn decompress_lz4(_input_buf: &[u8], _output_buf: &mut [u8]) -> PolarsResult<()> {
-    panic!("The crate was compiled without IPC compression. Use `io_ipc_compression` to read compressed IPC.");
+    polars_bail!(
+        InvalidOperation: "the crate was compiled without IPC compression (which is not available \
+        on wasm); use `io_ipc_compression` to read compressed IPC"
+    )
 }
 
-#[cfg(not(feature = "io_ipc_compression"))]
+#[cfg(not(all(feature = "io_ipc_compression", not(target_family = "wasm"))))]
 pub fn decompress_zstd(_input_buf: &[u8], _output_buf: &mut [u8]) -> PolarsResult<()> {
-    panic!("The crate was compiled without IPC compression. Use `io_ipc_compression` to read compressed IPC.");
+    polars_bail!(
+        InvalidOperation: "the crate was compiled without IPC compression (which is not available \
+        on wasm); use `io_ipc_compression` to read compressed IPC"
+    )
 }
 
-#[cfg(feature = "io_ipc_compression")]
+#[cfg(all(feature = "io_ipc_compression", not(target_family = "wasm")))]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
 pub fn compress_lz4(input_buf: &[u8], output_buf: &mut Vec<u8>) -> PolarsResult<()> {
     use std::io::Write;
@@ -40,27 +48,33 @@ pub fn compress_lz4(input_buf: &[u8], output_buf: &mut Vec<u8>) -> PolarsResult<
     encoder.finish().1.map_err(|e| e.into())
 }
 
-#[cfg(feature = "io_ipc_compression")]
+#[cfg(all(feature = "io_ipc_compression", not(target_family = "wasm")))]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
 pub fn compress_zstd(input_buf: &[u8], output_buf: &mut Vec<u8>) -> PolarsResult<()> {
     zstd::stream::copy_encode(input_buf, output_buf, 0).map_err(|e| e.into())
 }
 
-#[cfg(not(feature = "io_ipc_compression"))]
+#[cfg(not(all(feature = "io_ipc_compression", not(target_family = "wasm"))))]
 pub fn compress_lz4(_input_buf: &[u8], _output_buf: &[u8]) -> PolarsResult<()> {
-    panic!("The crate was compiled without IPC compression. Use `io_ipc_compression` to write compressed IPC.")
+    polars_bail!(
+        InvalidOperation: "the crate was compiled without IPC compression (which is not available \
+        on wasm); use `io_ipc_compression` to write compressed IPC"
+    )
 }
 
-#[cfg(not(feature = "io_ipc_compression"))]
+#[cfg(not(all(feature = "io_ipc_compression", not(target_family = "wasm"))))]
 pub fn compress_zstd(_input_buf: &[u8], _output_buf: &[u8]) -> PolarsResult<()> {
-    panic!("The crate was compiled without IPC compression. Use `io_ipc_compression` to write compressed IPC.")
+    polars_bail!(
+        InvalidOperation: "the crate was compiled without IPC compression (which is not available \
+        on wasm); use `io_ipc_compression` to write compressed IPC"
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[cfg(feature = "io_ipc_compression")]
+    #[cfg(all(feature = "io_ipc_compression", not(target_family = "wasm")))]
     #[test]
     #[cfg_attr(miri, ignore)] // ZSTD uses foreign calls that miri does not support
     fn round_trip_zstd() {
@@ -73,7 +87,7 @@ mod tests {
         assert_eq!(data, result);
     }
 
-    #[cfg(feature = "io_ipc_compression")]
+    #[cfg(all(feature = "io_ipc_compression", not(target_family = "wasm")))]
     #[test]
     #[cfg_attr(miri, ignore)] // LZ4 uses foreign calls that miri does not support
     fn round_trip_lz4() {
@@ -25,9 +25,11 @@ mod union;
 pub use union::*;
 mod binview;
 mod map;
+mod run_end_encoded;
 pub use binview::*;
 pub use map::*;
 use polars_error::{PolarsResult, *};
+pub use run_end_encoded::*;
 
 use super::{Compression, IpcBuffer, Node, OutOfSpecKind};
 use crate::datatypes::ArrowDataType;
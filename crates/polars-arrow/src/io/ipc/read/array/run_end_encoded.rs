@@ -0,0 +1,104 @@
+use std::collections::VecDeque;
+use std::io::{Read, Seek};
+
+use polars_error::{polars_bail, polars_err, PolarsResult};
+
+use super::super::super::IpcField;
+use super::super::deserialize::{read, skip};
+use super::super::{Compression, Dictionaries, IpcBuffer, Node, Version};
+use crate::array::{Array, PrimitiveArray, RunEndEncodedArray};
+use crate::datatypes::{ArrowDataType, Field};
+use crate::io::ipc::read::array::{try_get_array_length, try_get_field_node};
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_run_end_encoded<R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
+    data_type: ArrowDataType,
+    ipc_field: &IpcField,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+    limit: Option<usize>,
+    version: Version,
+    scratch: &mut Vec<u8>,
+) -> PolarsResult<RunEndEncodedArray> {
+    let field_node = try_get_field_node(field_nodes, &data_type)?;
+    let length = try_get_array_length(field_node, limit)?;
+
+    let values_field = RunEndEncodedArray::try_get_field(&data_type)?.clone();
+    let run_ends_field = Field::new("run_ends", ArrowDataType::Int32, false);
+
+    // The limit applies to the elements, not to the runs, so the runs are read completely.
+    let mut children = [
+        (&run_ends_field, &ipc_field.fields[0]),
+        (&values_field, &ipc_field.fields[1]),
+    ]
+    .into_iter()
+    .map(|(field, ipc_field)| {
+        read(
+            field_nodes,
+            variadic_buffer_counts,
+            field,
+            ipc_field,
+            buffers,
+            reader,
+            dictionaries,
+            block_offset,
+            is_little_endian,
+            compression,
+            None,
+            version,
+            scratch,
+        )
+    })
+    .collect::<PolarsResult<Vec<_>>>()?;
+
+    let values = children.pop().unwrap();
+    let run_ends = children.pop().unwrap();
+    let run_ends = run_ends
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i32>>()
+        .ok_or_else(|| {
+            polars_err!(oos = "IPC: the run ends of a run-end encoded array must be Int32")
+        })?;
+    if run_ends.null_count() > 0 {
+        polars_bail!(oos = "IPC: the run ends of a run-end encoded array cannot be null")
+    }
+
+    let array = RunEndEncodedArray::try_new(data_type, run_ends.values().clone(), values)?;
+    if length > array.len() {
+        polars_bail!(oos = "IPC: a run-end encoded array is longer than its runs")
+    }
+    Ok(array.sliced(0, length))
+}
+
+pub fn skip_run_end_encoded(
+    field_nodes: &mut VecDeque<Node>,
+    data_type: &ArrowDataType,
+    buffers: &mut VecDeque<IpcBuffer>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
+) -> PolarsResult<()> {
+    let _ = field_nodes.pop_front().ok_or_else(|| {
+        polars_err!(
+            oos = "IPC: unable to fetch the field for run-end encoded. The file or stream is corrupted."
+        )
+    })?;
+
+    let values = RunEndEncodedArray::try_get_field(data_type)?;
+    skip(
+        field_nodes,
+        &ArrowDataType::Int32,
+        buffers,
+        variadic_buffer_counts,
+    )?;
+    skip(
+        field_nodes,
+        values.data_type(),
+        buffers,
+        variadic_buffer_counts,
+    )
+}
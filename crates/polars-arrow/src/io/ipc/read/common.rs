@@ -7,6 +7,7 @@ use polars_error::{polars_bail, polars_err, PolarsResult};
 use super::deserialize::{read, skip};
 use super::Dictionaries;
 use crate::array::*;
+use crate::compute::concatenate::concatenate;
 use crate::datatypes::{ArrowDataType, Field};
 use crate::io::ipc::read::OutOfSpecKind;
 use crate::io::ipc::{IpcField, IpcSchema};
@@ -199,6 +200,7 @@ fn find_first_dict_field_d<'a>(
         List(field) | LargeList(field) | FixedSizeList(field, ..) | Map(field, ..) => {
             find_first_dict_field(id, field.as_ref(), &ipc_field.fields[0])
         },
+        RunEndEncoded(field) => find_first_dict_field(id, field.as_ref(), &ipc_field.fields[1]),
         Union(fields, ..) | Struct(fields) => {
             for (field, ipc_field) in fields.iter().zip(ipc_field.fields.iter()) {
                 if let Some(f) = find_first_dict_field(id, field, ipc_field) {
@@ -241,7 +243,8 @@ pub(crate) fn first_dict_field<'a>(
 }
 
 /// Reads a dictionary from the reader,
-/// updating `dictionaries` with the resulting dictionary
+/// updating `dictionaries` with the resulting dictionary.
+/// The values of delta dictionary batches are appended to the existing dictionary.
 #[allow(clippy::too_many_arguments)]
 pub fn read_dictionary<R: Read + Seek>(
     batch: arrow_format::ipc::DictionaryBatchRef,
@@ -253,12 +256,9 @@ pub fn read_dictionary<R: Read + Seek>(
     file_size: u64,
     scratch: &mut Vec<u8>,
 ) -> PolarsResult<()> {
-    if batch
+    let is_delta = batch
         .is_delta()
-        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferIsDelta(err)))?
-    {
-        polars_bail!(ComputeError: "delta dictionary batches not supported")
-    }
+        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferIsDelta(err)))?;
 
     let id = batch
         .id()
@@ -298,7 +298,18 @@ pub fn read_dictionary<R: Read + Seek>(
         scratch,
     )?;
 
-    dictionaries.insert(id, chunk.into_arrays().pop().unwrap());
+    let values = chunk.into_arrays().pop().unwrap();
+    let values = if is_delta {
+        let existing = dictionaries.get(&id).ok_or_else(|| {
+            polars_err!(
+                oos = "IPC: delta dictionary batch without a dictionary to extend. The file or stream is corrupted."
+            )
+        })?;
+        concatenate(&[existing.as_ref(), values.as_ref()])?
+    } else {
+        values
+    };
+    dictionaries.insert(id, values);
 
     Ok(())
 }
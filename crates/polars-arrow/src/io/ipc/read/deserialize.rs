@@ -256,6 +256,22 @@ pub fn read<R: Read + Seek>(
             limit,
             scratch,
         ),
+        RunEndEncoded => read_run_end_encoded(
+            field_nodes,
+            variadic_buffer_counts,
+            data_type,
+            ipc_field,
+            buffers,
+            reader,
+            dictionaries,
+            block_offset,
+            is_little_endian,
+            compression,
+            limit,
+            version,
+            scratch,
+        )
+        .map(|x| x.boxed()),
     }
 }
 
@@ -283,5 +299,8 @@ pub fn skip(
         Union => skip_union(field_nodes, data_type, buffers, variadic_buffer_counts),
         Map => skip_map(field_nodes, data_type, buffers, variadic_buffer_counts),
         BinaryView | Utf8View => skip_binview(field_nodes, buffers, variadic_buffer_counts),
+        RunEndEncoded => {
+            skip_run_end_encoded(field_nodes, data_type, buffers, variadic_buffer_counts)
+        },
     }
 }
@@ -11,7 +11,7 @@ use super::common::*;
 use super::schema::fb_to_schema;
 use super::{Dictionaries, OutOfSpecKind};
 use crate::array::Array;
-use crate::datatypes::ArrowSchemaRef;
+use crate::datatypes::{ArrowSchemaRef, Metadata};
 use crate::io::ipc::IpcSchema;
 use crate::record_batch::RecordBatchT;
 
@@ -34,6 +34,9 @@ pub struct FileMetadata {
 
     /// The total size of the file in bytes
     pub size: u64,
+
+    /// Custom metadata stored in the footer of the file
+    pub custom_metadata: Option<Metadata>,
 }
 
 /// Read the row count by summing the length of the of the record batches
@@ -211,12 +214,27 @@ pub fn deserialize_footer(footer_data: &[u8], size: u64) -> PolarsResult<FileMet
         })
         .transpose()?;
 
+    let custom_metadata = footer
+        .custom_metadata()?
+        .map(|list| {
+            let mut metadata = Metadata::new();
+            for kv in list {
+                let kv = kv?;
+                if let (Some(k), Some(v)) = (kv.key()?, kv.value()?) {
+                    metadata.insert(k.to_string(), v.to_string());
+                }
+            }
+            PolarsResult::Ok(metadata)
+        })
+        .transpose()?;
+
     Ok(FileMetadata {
         schema: Arc::new(schema),
         ipc_schema,
         blocks,
         dictionaries,
         size,
+        custom_metadata,
     })
 }
 
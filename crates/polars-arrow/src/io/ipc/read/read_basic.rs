@@ -119,8 +119,19 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
         .take(buffer_length as u64)
         .read_to_end(scratch)?;
 
-    let length = output_length
-        .unwrap_or_else(|| i64::from_le_bytes(scratch[..8].try_into().unwrap()) as usize);
+    let uncompressed_length = i64::from_le_bytes(scratch[..8].try_into().unwrap());
+    if uncompressed_length == -1 {
+        // the buffer was stored uncompressed
+        let mut buffer = vec![T::default(); (scratch.len() - 8) / std::mem::size_of::<T>()];
+        let out_slice = bytemuck::cast_slice_mut(&mut buffer);
+        let n_bytes = out_slice.len();
+        out_slice.copy_from_slice(&scratch[8..8 + n_bytes]);
+        if let Some(length) = output_length {
+            buffer.truncate(length);
+        }
+        return Ok(buffer);
+    }
+    let length = output_length.unwrap_or(uncompressed_length as usize);
 
     // It is undefined behavior to call read_exact on un-initialized, https://doc.rust-lang.org/std/io/trait.Read.html#tymethod.read
     // see also https://github.com/MaikKlein/ash/issues/354#issue-781730580
@@ -275,6 +286,13 @@ fn read_compressed_bitmap<R: Read + Seek>(
     scratch.try_reserve(bytes)?;
     reader.by_ref().take(bytes as u64).read_to_end(scratch)?;
 
+    if i64::from_le_bytes(scratch[..8].try_into().unwrap()) == -1 {
+        // the bitmap was stored uncompressed
+        let n = std::cmp::min(buffer.len(), scratch.len() - 8);
+        buffer[..n].copy_from_slice(&scratch[8..8 + n]);
+        return Ok(buffer);
+    }
+
     let compression = compression
         .codec()
         .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferCompression(err)))?;
@@ -151,6 +151,28 @@ fn deserialize_map(map: MapRef, field: FieldRef) -> PolarsResult<(ArrowDataType,
     ))
 }
 
+fn deserialize_run_end_encoded(field: FieldRef) -> PolarsResult<(ArrowDataType, IpcField)> {
+    let children = field
+        .children()?
+        .ok_or_else(|| polars_err!(oos = "IPC: RunEndEncoded must contain children"))?;
+    if children.len() != 2 {
+        polars_bail!(oos = "IPC: RunEndEncoded must contain two children")
+    }
+    let (run_ends, run_ends_ipc_field) = deserialize_field(children.get(0).unwrap()?)?;
+    if run_ends.data_type() != &ArrowDataType::Int32 {
+        polars_bail!(ComputeError: "only Int32 run ends are supported, found {:?}", run_ends.data_type())
+    }
+    let (values, values_ipc_field) = deserialize_field(children.get(1).unwrap()?)?;
+
+    Ok((
+        ArrowDataType::RunEndEncoded(Box::new(values)),
+        IpcField {
+            fields: vec![run_ends_ipc_field, values_ipc_field],
+            dictionary_id: None,
+        },
+    ))
+}
+
 fn deserialize_struct(field: FieldRef) -> PolarsResult<(ArrowDataType, IpcField)> {
     let fields = field
         .children()?
@@ -351,7 +373,7 @@ fn get_data_type(
         Struct(_) => deserialize_struct(field)?,
         Union(union_) => deserialize_union(union_, field)?,
         Map(map) => deserialize_map(map, field)?,
-        RunEndEncoded(_) => todo!(),
+        RunEndEncoded(_) => deserialize_run_end_encoded(field)?,
         LargeListView(_) | ListView(_) => todo!(),
     })
 }
@@ -4,7 +4,7 @@ use arrow_format::ipc::planus::Builder;
 use polars_error::{polars_bail, polars_err, PolarsResult};
 
 use super::super::IpcField;
-use super::{write, write_dictionary};
+use super::write;
 use crate::array::*;
 use crate::datatypes::*;
 use crate::io::ipc::endianness::is_native_little_endian;
@@ -28,6 +28,9 @@ pub struct WriteOptions {
     /// Whether the buffers should be compressed and which codec to use.
     /// Note: to use compression the crate must be compiled with feature `io_ipc_compression`.
     pub compression: Option<Compression>,
+    /// Whether a dictionary that only grew since it was last written is written as a delta
+    /// dictionary batch holding the new values, instead of being written again in full.
+    pub delta_dictionaries: bool,
 }
 
 fn encode_dictionary(
@@ -45,7 +48,7 @@ fn encode_dictionary(
             let dict_id = field.dictionary_id
                 .ok_or_else(|| polars_err!(InvalidOperation: "Dictionaries must have an associated id"))?;
 
-            let emit = dictionary_tracker.insert(dict_id, array)?;
+            let update = dictionary_tracker.update(dict_id, array, options.delta_dictionaries)?;
 
             let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
             let values = array.values();
@@ -56,13 +59,25 @@ fn encode_dictionary(
                 encoded_dictionaries
             )?;
 
-            if emit {
-                encoded_dictionaries.push(dictionary_batch_to_bytes::<$T>(
+            match update {
+                DictionaryUpdate::Unchanged => {},
+                DictionaryUpdate::New => encoded_dictionaries.push(dictionary_batch_to_bytes(
                     dict_id,
-                    array,
+                    values.as_ref(),
+                    false,
                     options,
                     is_native_little_endian(),
-                ));
+                )),
+                DictionaryUpdate::Delta { offset } => {
+                    let delta = values.sliced(offset, values.len() - offset);
+                    encoded_dictionaries.push(dictionary_batch_to_bytes(
+                        dict_id,
+                        delta.as_ref(),
+                        true,
+                        options,
+                        is_native_little_endian(),
+                    ))
+                },
             };
             Ok(())
         }),
@@ -168,6 +183,17 @@ fn encode_dictionary(
                 encoded_dictionaries,
             )
         },
+        RunEndEncoded => {
+            let array = array.as_any().downcast_ref::<RunEndEncodedArray>().unwrap();
+            let field = &field.fields[1]; // todo: error instead
+            encode_dictionary(
+                field,
+                array.values().as_ref(),
+                options,
+                dictionary_tracker,
+                encoded_dictionaries,
+            )
+        },
     }
 }
 
@@ -254,11 +280,17 @@ fn set_variadic_buffer_counts(counts: &mut Vec<i64>, array: &dyn Array) {
             let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
             set_variadic_buffer_counts(counts, array.values().as_ref())
         },
-        ArrowDataType::Dictionary(_, _, _) => {
-            let array = array
-                .as_any()
-                .downcast_ref::<DictionaryArray<u32>>()
-                .unwrap();
+        ArrowDataType::Dictionary(key_type, _, _) => {
+            match_integer_type!(key_type, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<$T>>()
+                    .unwrap();
+                set_variadic_buffer_counts(counts, array.values().as_ref())
+            })
+        },
+        ArrowDataType::RunEndEncoded(_) => {
+            let array = array.as_any().downcast_ref::<RunEndEncodedArray>().unwrap();
             set_variadic_buffer_counts(counts, array.values().as_ref())
         },
         _ => (),
@@ -346,9 +378,10 @@ fn chunk_to_bytes_amortized(
 
 /// Write dictionary values into two sets of bytes, one for the header (ipc::Schema::Message) and the
 /// other for the data
-fn dictionary_batch_to_bytes<K: DictionaryKey>(
+fn dictionary_batch_to_bytes(
     dict_id: i64,
-    array: &DictionaryArray<K>,
+    values: &dyn Array,
+    is_delta: bool,
     options: &WriteOptions,
     is_little_endian: bool,
 ) -> EncodedData {
@@ -356,7 +389,7 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
     let mut variadic_buffer_counts = vec![];
-    set_variadic_buffer_counts(&mut variadic_buffer_counts, array.values().as_ref());
+    set_variadic_buffer_counts(&mut variadic_buffer_counts, values);
 
     let variadic_buffer_counts = if variadic_buffer_counts.is_empty() {
         None
@@ -364,16 +397,16 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
         Some(variadic_buffer_counts)
     };
 
-    let length = write_dictionary(
-        array,
+    write(
+        values,
         &mut buffers,
         &mut arrow_data,
         &mut nodes,
         &mut 0,
         is_little_endian,
         options.compression,
-        false,
     );
+    let length = values.len();
 
     let compression = serialize_compression(options.compression);
 
@@ -389,7 +422,7 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
                     compression,
                     variadic_buffer_counts,
                 })),
-                is_delta: false,
+                is_delta,
             },
         ))),
         body_length: arrow_data.len() as i64,
@@ -405,6 +438,18 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
     }
 }
 
+/// How a dictionary has to be written, see [`DictionaryTracker::update`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DictionaryUpdate {
+    /// The dictionary was already written.
+    Unchanged,
+    /// The dictionary has to be written in full.
+    New,
+    /// The dictionary extends the written dictionary, the values from `offset` on have to be
+    /// written as a delta dictionary batch.
+    Delta { offset: usize },
+}
+
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
 /// multiple times. Can optionally error if an update to an existing dictionary is attempted, which
 /// isn't allowed in the `FileWriter`.
@@ -424,6 +469,19 @@ impl DictionaryTracker {
     ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
     ///   inserted.
     pub fn insert(&mut self, dict_id: i64, array: &dyn Array) -> PolarsResult<bool> {
+        Ok(self.update(dict_id, array, false)? != DictionaryUpdate::Unchanged)
+    }
+
+    /// Keep track of the dictionary with the given ID and values, like [`Self::insert`]. If
+    /// `allow_delta` is set and the dictionary only had values appended since it was last
+    /// written, this returns [`DictionaryUpdate::Delta`]; replacing a dictionary is then not
+    /// needed, so this doesn't error even if the tracker can't replace dictionaries.
+    pub fn update(
+        &mut self,
+        dict_id: i64,
+        array: &dyn Array,
+        allow_delta: bool,
+    ) -> PolarsResult<DictionaryUpdate> {
         let values = match array.data_type() {
             ArrowDataType::Dictionary(key_type, _, _) => {
                 match_integer_type!(key_type, |$T| {
@@ -441,7 +499,14 @@ impl DictionaryTracker {
         if let Some(last) = self.dictionaries.get(&dict_id) {
             if last.as_ref() == values.as_ref() {
                 // Same dictionary values => no need to emit it again
-                return Ok(false);
+                return Ok(DictionaryUpdate::Unchanged);
+            } else if allow_delta
+                && last.len() < values.len()
+                && last.as_ref() == values.sliced(0, last.len()).as_ref()
+            {
+                let offset = last.len();
+                self.dictionaries.insert(dict_id, values.clone());
+                return Ok(DictionaryUpdate::Delta { offset });
             } else if self.cannot_replace {
                 polars_bail!(InvalidOperation:
                     "Dictionary replacement detected when writing IPC file format. \
@@ -452,7 +517,7 @@ impl DictionaryTracker {
         };
 
         self.dictionaries.insert(dict_id, values.clone());
-        Ok(true)
+        Ok(DictionaryUpdate::New)
     }
 }
 
@@ -8,7 +8,6 @@ pub(crate) mod writer;
 pub use common::{Compression, Record, WriteOptions};
 pub use schema::schema_to_bytes;
 pub use serialize::write;
-use serialize::write_dictionary;
 pub use stream::StreamWriter;
 pub use writer::FileWriter;
 
@@ -43,6 +42,17 @@ fn default_ipc_field(data_type: &ArrowDataType, current_id: &mut i64) -> IpcFiel
                 .collect(),
             dictionary_id: None,
         },
+        // run ends and values => recurse into the values
+        RunEndEncoded(inner) => IpcField {
+            fields: vec![
+                IpcField {
+                    fields: vec![],
+                    dictionary_id: None,
+                },
+                default_ipc_field(inner.data_type(), current_id),
+            ],
+            dictionary_id: None,
+        },
         // dictionary => current_id
         Dictionary(_, data_type, _) => {
             let dictionary_id = Some(*current_id);
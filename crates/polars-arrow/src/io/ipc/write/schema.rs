@@ -247,6 +247,7 @@ fn serialize_type(data_type: &ArrowDataType) -> arrow_format::ipc::Type {
         Extension(_, v, _) => serialize_type(v),
         Utf8View => ipc::Type::Utf8View(Box::new(ipc::Utf8View {})),
         BinaryView => ipc::Type::BinaryView(Box::new(ipc::BinaryView {})),
+        RunEndEncoded(_) => ipc::Type::RunEndEncoded(Box::new(ipc::RunEndEncoded {})),
         Unknown => unimplemented!(),
     }
 }
@@ -294,6 +295,13 @@ fn serialize_children(
             .zip(ipc_field.fields.iter())
             .map(|(field, ipc)| serialize_field(field, ipc))
             .collect(),
+        RunEndEncoded(inner) => {
+            let run_ends = Field::new("run_ends", Int32, false);
+            vec![
+                serialize_field(&run_ends, &ipc_field.fields[0]),
+                serialize_field(inner, &ipc_field.fields[1]),
+            ]
+        },
         Dictionary(_, inner, _) => serialize_children(inner, ipc_field),
         Extension(_, inner, _) => serialize_children(inner, ipc_field),
         Unknown => unimplemented!(),
@@ -6,7 +6,7 @@ use super::super::endianness::is_native_little_endian;
 use super::common::{pad_to_64, Compression};
 use crate::array::*;
 use crate::bitmap::Bitmap;
-use crate::datatypes::PhysicalType;
+use crate::datatypes::{ArrowDataType, PhysicalType};
 use crate::offset::{Offset, OffsetsBuffer};
 use crate::trusted_len::TrustedLen;
 use crate::types::NativeType;
@@ -20,6 +20,7 @@ mod fixed_sized_list;
 mod list;
 mod map;
 mod primitive;
+mod run_end_encoded;
 mod struct_;
 mod union;
 
@@ -32,6 +33,7 @@ use fixed_sized_list::*;
 use list::*;
 use map::*;
 use primitive::*;
+use run_end_encoded::*;
 use struct_::*;
 use union::*;
 
@@ -45,11 +47,16 @@ pub fn write(
     is_little_endian: bool,
     compression: Option<Compression>,
 ) {
+    use PhysicalType::*;
+    // The nulls of a run-end encoded array are in its values, it has no null count itself.
+    let null_count = match array.data_type().to_physical_type() {
+        RunEndEncoded => 0,
+        _ => array.null_count(),
+    };
     nodes.push(ipc::FieldNode {
         length: array.len() as i64,
-        null_count: array.null_count() as i64,
+        null_count: null_count as i64,
     });
-    use PhysicalType::*;
     match array.data_type().to_physical_type() {
         Null => (),
         Boolean => write_boolean(
@@ -190,6 +197,15 @@ pub fn write(
             is_little_endian,
             compression,
         ),
+        RunEndEncoded => write_run_end_encoded(
+            array.as_any().downcast_ref().unwrap(),
+            buffers,
+            arrow_data,
+            nodes,
+            offset,
+            is_little_endian,
+            compression,
+        ),
     }
 }
 
@@ -201,6 +217,27 @@ fn pad_buffer_to_64(buffer: &mut Vec<u8>, length: usize) {
     }
 }
 
+/// Writes the uncompressed length followed by the compressed `bytes`. If compression does
+/// not reduce the size, the bytes are written uncompressed with a length of `-1`, as
+/// allowed by the Arrow specification.
+fn _write_compressed_bytes(bytes: &[u8], arrow_data: &mut Vec<u8>, compression: Compression) {
+    let start = arrow_data.len();
+    arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
+    match compression {
+        Compression::LZ4 => {
+            compression::compress_lz4(bytes, arrow_data).unwrap();
+        },
+        Compression::ZSTD => {
+            compression::compress_zstd(bytes, arrow_data).unwrap();
+        },
+    }
+    if arrow_data.len() - start - 8 >= bytes.len() {
+        arrow_data.truncate(start);
+        arrow_data.extend_from_slice(&(-1i64).to_le_bytes());
+        arrow_data.extend_from_slice(bytes);
+    }
+}
+
 /// writes `bytes` to `arrow_data` updating `buffers` and `offset` and guaranteeing a 8 byte boundary.
 fn write_bytes(
     bytes: &[u8],
@@ -211,15 +248,7 @@ fn write_bytes(
 ) {
     let start = arrow_data.len();
     if let Some(compression) = compression {
-        arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
-            Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
-            },
-            Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
-            },
-        }
+        _write_compressed_bytes(bytes, arrow_data, compression);
     } else {
         arrow_data.extend_from_slice(bytes);
     };
@@ -313,15 +342,7 @@ fn _write_compressed_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
             .map(|x| T::to_be_bytes(&x))
             .for_each(|x| swapped.extend_from_slice(x.as_ref()))
     };
-    arrow_data.extend_from_slice(&(swapped.len() as i64).to_le_bytes());
-    match compression {
-        Compression::LZ4 => {
-            compression::compress_lz4(&swapped, arrow_data).unwrap();
-        },
-        Compression::ZSTD => {
-            compression::compress_zstd(&swapped, arrow_data).unwrap();
-        },
-    }
+    _write_compressed_bytes(&swapped, arrow_data, compression);
 }
 
 fn _write_buffer<T: NativeType>(buffer: &[T], arrow_data: &mut Vec<u8>, is_little_endian: bool) {
@@ -342,15 +363,7 @@ fn _write_compressed_buffer<T: NativeType>(
 ) {
     if is_little_endian == is_native_little_endian() {
         let bytes = bytemuck::cast_slice(buffer);
-        arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
-            Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
-            },
-            Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
-            },
-        }
+        _write_compressed_bytes(bytes, arrow_data, compression);
     } else {
         todo!()
     }
@@ -0,0 +1,26 @@
+use super::*;
+
+pub(super) fn write_run_end_encoded(
+    array: &RunEndEncodedArray,
+    buffers: &mut Vec<ipc::Buffer>,
+    arrow_data: &mut Vec<u8>,
+    nodes: &mut Vec<ipc::FieldNode>,
+    offset: &mut i64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+) {
+    // IPC has no offsets, so the runs are cut to the slice of the array.
+    let array = array.trimmed();
+    let run_ends = PrimitiveArray::new(ArrowDataType::Int32, array.run_ends().clone(), None);
+    for child in [&run_ends as &dyn Array, array.values().as_ref()] {
+        write(
+            child,
+            buffers,
+            arrow_data,
+            nodes,
+            offset,
+            is_little_endian,
+            compression,
+        );
+    }
+}
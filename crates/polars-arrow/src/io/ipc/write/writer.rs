@@ -40,6 +40,8 @@ pub struct FileWriter<W: Write> {
     pub(crate) dictionary_tracker: DictionaryTracker,
     /// Buffer/scratch that is reused between writes
     pub(crate) encoded_message: EncodedData,
+    /// Custom metadata that will be written as part of the IPC footer
+    pub(crate) custom_metadata: Option<Metadata>,
 }
 
 impl<W: Write> FileWriter<W> {
@@ -83,9 +85,18 @@ impl<W: Write> FileWriter<W> {
                 cannot_replace: true,
             },
             encoded_message: Default::default(),
+            custom_metadata: None,
         }
     }
 
+    /// Set custom metadata that is written to the footer of the file.
+    ///
+    /// As the footer is written on [`FileWriter::finish`], this can be set at any time before
+    /// finishing, e.g. to store information gathered while writing the record batches.
+    pub fn set_custom_metadata(&mut self, custom_metadata: Metadata) {
+        self.custom_metadata = Some(custom_metadata);
+    }
+
     /// Consumes itself into the inner writer
     pub fn into_inner(self) -> W {
         self.writer
@@ -194,7 +205,15 @@ impl<W: Write> FileWriter<W> {
             schema: Some(Box::new(schema)),
             dictionaries: Some(std::mem::take(&mut self.dictionary_blocks)),
             record_batches: Some(std::mem::take(&mut self.record_blocks)),
-            custom_metadata: None,
+            custom_metadata: self.custom_metadata.take().map(|metadata| {
+                metadata
+                    .into_iter()
+                    .map(|(key, value)| arrow_format::ipc::KeyValue {
+                        key: Some(key),
+                        value: Some(value),
+                    })
+                    .collect()
+            }),
         };
         let mut builder = Builder::new();
         let footer_data = builder.finish(&root, None);
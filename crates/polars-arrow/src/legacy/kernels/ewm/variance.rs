@@ -153,6 +153,53 @@ where
     )
 }
 
+/// Exponentially weighted correlation of `xs` and `ys`.
+///
+/// Only the pairs in which both values are valid are used, so that the covariance and the
+/// variances share their weights. The bias correction cancels out and thus isn't an option.
+pub fn ewm_corr<I, T>(
+    xs: I,
+    ys: I,
+    alpha: T,
+    adjust: bool,
+    min_periods: usize,
+    ignore_nulls: bool,
+) -> PrimitiveArray<T>
+where
+    I: IntoIterator<Item = Option<T>>,
+    I::IntoIter: TrustedLen,
+    T: Float + NativeType + AddAssign + MulAssign + DivAssign,
+{
+    let (xs, ys): (Vec<_>, Vec<_>) = xs
+        .into_iter()
+        .zip(ys)
+        .map(|pair| match pair {
+            (Some(x), Some(y)) => (Some(x), Some(y)),
+            _ => (None, None),
+        })
+        .unzip();
+    let moment = |xs: &[Option<T>], ys: &[Option<T>]| {
+        ewm_cov_internal(
+            xs.to_vec(),
+            ys.to_vec(),
+            alpha,
+            adjust,
+            true,
+            min_periods,
+            ignore_nulls,
+            false,
+        )
+    };
+    let cov = moment(&xs, &ys);
+    let var_x = moment(&xs, &xs);
+    let var_y = moment(&ys, &ys);
+    cov.iter()
+        .zip(var_x.iter())
+        .zip(var_y.iter())
+        .map(|((cov, var_x), var_y)| Some(*cov? / (*var_x? * *var_y?).sqrt()))
+        .collect()
+}
+
 pub fn ewm_var<I, T>(
     xs: I,
     alpha: T,
@@ -539,6 +586,32 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_ewm_corr() {
+        let neg_xs = XS.map(|x| x.map(|x| -2.0 * x + 1.0));
+        assert_allclose!(
+            ewm_corr(XS.to_vec(), neg_xs.to_vec(), ALPHA, true, 2, true),
+            PrimitiveArray::from([
+                None,
+                Some(-1.0),
+                Some(-1.0),
+                Some(-1.0),
+                Some(-1.0),
+                Some(-1.0),
+                Some(-1.0),
+            ]),
+            1e-12
+        );
+        // The values of `XS` that are paired with a null don't count.
+        for adjust in [true, false] {
+            assert_allclose!(
+                ewm_corr(XS.to_vec(), YS.to_vec(), ALPHA, adjust, 2, false),
+                PrimitiveArray::from([None, None, Some(1.0), None, None, Some(1.0), Some(1.0)]),
+                1e-12
+            );
+        }
+    }
+
     #[test]
     fn test_ewm_std() {
         assert_allclose!(
@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 #[cfg(feature = "timezones")]
-use chrono::{LocalResult, NaiveDateTime, TimeZone};
+use chrono::{DateTime, LocalResult, NaiveDateTime, Offset, TimeZone};
 #[cfg(feature = "timezones")]
 use chrono_tz::Tz;
 #[cfg(feature = "timezones")]
@@ -10,6 +10,9 @@ use polars_error::{polars_bail, PolarsError};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "timezones")]
+use crate::temporal_conversions::SECONDS_IN_DAY;
+
 pub enum Ambiguous {
     Earliest,
     Latest,
@@ -37,6 +40,31 @@ impl FromStr for Ambiguous {
 pub enum NonExistent {
     Null,
     Raise,
+    /// Shift to the first datetime after the gap.
+    ShiftForward,
+}
+
+/// Get the first UTC datetime after the gap in the local datetimes of `tz` which contains
+/// `ndt`. Gaps are caused by a change to a larger UTC offset.
+#[cfg(feature = "timezones")]
+fn end_of_gap(tz: &Tz, ndt: NaiveDateTime) -> NaiveDateTime {
+    let offset_at = |t: i64| {
+        let t = DateTime::from_timestamp(t, 0).unwrap().naive_utc();
+        tz.offset_from_utc_datetime(&t).fix().local_minus_utc() as i64
+    };
+    let t = ndt.and_utc().timestamp();
+    let (before, after) = (offset_at(t - SECONDS_IN_DAY), offset_at(t + SECONDS_IN_DAY));
+    // The offset changes from `before` to `after` at a whole second in `(lo, hi]`.
+    let (mut lo, mut hi) = (t - after, t - before);
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if offset_at(mid) == after {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    DateTime::from_timestamp(hi, 0).unwrap().naive_utc()
 }
 
 #[cfg(feature = "timezones")]
@@ -64,6 +92,7 @@ pub fn convert_to_naive_local(
                 ndt, to_tz
             ),
             NonExistent::Null => Ok(None),
+            NonExistent::ShiftForward => Ok(Some(end_of_gap(to_tz, ndt))),
         },
     }
 }
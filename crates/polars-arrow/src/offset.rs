@@ -304,19 +304,15 @@ impl<O: Offset> Offsets<O> {
             return Ok(());
         }
         let other = &other.0[start..start + length + 1];
-        let other_length = other.last().expect("Length to be non-zero");
-        let mut length = *self.last();
+        let first = other[0];
+        let last = *self.last();
         // check if the operation would overflow
-        length
-            .checked_add(other_length)
+        last.checked_add(&(*other.last().unwrap() - first))
             .ok_or_else(|| polars_err!(ComputeError: "overflow"))?;
 
-        let lengths = other.windows(2).map(|w| w[1] - w[0]);
-        let offsets = lengths.map(|new_length| {
-            length += new_length;
-            length
-        });
-        self.0.extend(offsets);
+        // shifting the offsets in bulk vectorizes, unlike accumulating their lengths
+        self.0
+            .extend(other[1..].iter().map(|&offset| last + (offset - first)));
         Ok(())
     }
 
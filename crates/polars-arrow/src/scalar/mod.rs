@@ -205,5 +205,10 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
                 value,
             ))
         }),
+        // The element of a run-end encoded array is the value of its run.
+        RunEndEncoded => {
+            let array = array.as_any().downcast_ref::<RunEndEncodedArray>().unwrap();
+            new_scalar(array.values().as_ref(), array.run_index(index))
+        },
     }
 }
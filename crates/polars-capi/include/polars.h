@@ -0,0 +1,123 @@
+/*
+ * C interface of Polars, see `crates/polars-capi`.
+ *
+ * Handles are opaque and owned by the caller, who must release them with the matching
+ * `*_free` function. Fallible functions return a `polars_error_code_t` and write their result
+ * through an out pointer. Data is exchanged through the Arrow C Data Interface.
+ */
+#ifndef POLARS_H
+#define POLARS_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#ifndef ARROW_C_DATA_INTERFACE
+#define ARROW_C_DATA_INTERFACE
+
+#define ARROW_FLAG_DICTIONARY_ORDERED 1
+#define ARROW_FLAG_NULLABLE 2
+#define ARROW_FLAG_MAP_KEYS_SORTED 4
+
+struct ArrowSchema {
+  const char* format;
+  const char* name;
+  const char* metadata;
+  int64_t flags;
+  int64_t n_children;
+  struct ArrowSchema** children;
+  struct ArrowSchema* dictionary;
+  void (*release)(struct ArrowSchema*);
+  void* private_data;
+};
+
+struct ArrowArray {
+  int64_t length;
+  int64_t null_count;
+  int64_t offset;
+  int64_t n_buffers;
+  int64_t n_children;
+  const void** buffers;
+  struct ArrowArray** children;
+  struct ArrowArray* dictionary;
+  void (*release)(struct ArrowArray*);
+  void* private_data;
+};
+
+#endif /* ARROW_C_DATA_INTERFACE */
+
+/* The values are stable, new codes are only ever appended. */
+typedef enum polars_error_code_t {
+  POLARS_OK = 0,
+  POLARS_COLUMN_NOT_FOUND = 1,
+  POLARS_COMPUTE_ERROR = 2,
+  POLARS_DUPLICATE = 3,
+  POLARS_INVALID_OPERATION = 4,
+  POLARS_IO = 5,
+  POLARS_NO_DATA = 6,
+  POLARS_OUT_OF_BOUNDS = 7,
+  POLARS_SCHEMA_FIELD_NOT_FOUND = 8,
+  POLARS_SCHEMA_MISMATCH = 9,
+  POLARS_SHAPE_MISMATCH = 10,
+  POLARS_SQL_INTERFACE = 11,
+  POLARS_SQL_SYNTAX = 12,
+  POLARS_STRING_CACHE_MISMATCH = 13,
+  POLARS_STRUCT_FIELD_NOT_FOUND = 14,
+  POLARS_NULL_POINTER = 15,
+  POLARS_INVALID_UTF8 = 16,
+  POLARS_PANIC = 17,
+  POLARS_CANCELLED = 18,
+} polars_error_code_t;
+
+typedef struct PolarsDataFrame PolarsDataFrame;
+typedef struct PolarsLazyFrame PolarsLazyFrame;
+typedef struct PolarsSQLContext PolarsSQLContext;
+
+/* Message of the last error on the calling thread, or NULL. Owned by the library. */
+const char* polars_last_error_message(void);
+
+/* DataFrame */
+
+/* Moves `array`, a struct array, into a new DataFrame. `schema` is borrowed. */
+polars_error_code_t polars_data_frame_from_arrow(struct ArrowArray* array,
+                                                 const struct ArrowSchema* schema,
+                                                 PolarsDataFrame** out);
+/* Exports `df` as a single struct array. The caller releases the written structures. */
+polars_error_code_t polars_data_frame_to_arrow(const PolarsDataFrame* df,
+                                               struct ArrowArray* out_array,
+                                               struct ArrowSchema* out_schema);
+size_t polars_data_frame_height(const PolarsDataFrame* df);
+size_t polars_data_frame_width(const PolarsDataFrame* df);
+void polars_data_frame_free(PolarsDataFrame* df);
+
+/* LazyFrame */
+
+polars_error_code_t polars_lazy_frame_from_data_frame(const PolarsDataFrame* df,
+                                                      PolarsLazyFrame** out);
+polars_error_code_t polars_lazy_frame_deserialize_json(const char* json,
+                                                       PolarsLazyFrame** out);
+/* The string must be released with `polars_string_free`. */
+polars_error_code_t polars_lazy_frame_serialize_json(const PolarsLazyFrame* lf, char** out);
+polars_error_code_t polars_lazy_frame_collect(const PolarsLazyFrame* lf,
+                                              PolarsDataFrame** out);
+void polars_lazy_frame_free(PolarsLazyFrame* lf);
+
+/* SQL */
+
+PolarsSQLContext* polars_sql_context_new(void);
+polars_error_code_t polars_sql_context_register(PolarsSQLContext* ctx, const char* name,
+                                                const PolarsLazyFrame* lf);
+polars_error_code_t polars_sql_context_execute(PolarsSQLContext* ctx, const char* query,
+                                               PolarsLazyFrame** out);
+void polars_sql_context_free(PolarsSQLContext* ctx);
+
+void polars_string_free(char* s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* POLARS_H */
@@ -0,0 +1,128 @@
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use polars_error::{ErrorCode, PolarsError};
+
+/// Status returned by every fallible function of the C API.
+///
+/// The values are part of the stable interface, new codes are only ever appended.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PolarsErrorCode {
+    Ok = 0,
+    ColumnNotFound = 1,
+    ComputeError = 2,
+    Duplicate = 3,
+    InvalidOperation = 4,
+    IO = 5,
+    NoData = 6,
+    OutOfBounds = 7,
+    SchemaFieldNotFound = 8,
+    SchemaMismatch = 9,
+    ShapeMismatch = 10,
+    SQLInterface = 11,
+    SQLSyntax = 12,
+    StringCacheMismatch = 13,
+    StructFieldNotFound = 14,
+    /// A required pointer argument was null.
+    NullPointer = 15,
+    /// A string argument was not valid UTF-8.
+    InvalidUtf8 = 16,
+    /// Polars panicked; the panic was caught at the FFI boundary.
+    Panic = 17,
+    /// The query was cancelled before it completed.
+    Cancelled = 18,
+}
+
+impl From<&PolarsError> for PolarsErrorCode {
+    fn from(err: &PolarsError) -> Self {
+        match err.code() {
+            ErrorCode::ColumnNotFound => Self::ColumnNotFound,
+            ErrorCode::ComputeError => Self::ComputeError,
+            ErrorCode::Duplicate => Self::Duplicate,
+            ErrorCode::InvalidOperation => Self::InvalidOperation,
+            ErrorCode::IO => Self::IO,
+            ErrorCode::NoData => Self::NoData,
+            ErrorCode::OutOfBounds => Self::OutOfBounds,
+            ErrorCode::SchemaFieldNotFound => Self::SchemaFieldNotFound,
+            ErrorCode::SchemaMismatch => Self::SchemaMismatch,
+            ErrorCode::ShapeMismatch => Self::ShapeMismatch,
+            ErrorCode::SQLInterface => Self::SQLInterface,
+            ErrorCode::SQLSyntax => Self::SQLSyntax,
+            ErrorCode::StringCacheMismatch => Self::StringCacheMismatch,
+            ErrorCode::StructFieldNotFound => Self::StructFieldNotFound,
+            ErrorCode::Cancelled => Self::Cancelled,
+            // Codes added later map to the closest generic category.
+            _ => Self::ComputeError,
+        }
+    }
+}
+
+/// Errors that only exist at the FFI boundary.
+pub(crate) enum CApiError {
+    Polars(PolarsError),
+    NullPointer(&'static str),
+    InvalidUtf8(&'static str),
+}
+
+impl From<PolarsError> for CApiError {
+    fn from(err: PolarsError) -> Self {
+        Self::Polars(err)
+    }
+}
+
+pub(crate) type CApiResult<T> = Result<T, CApiError>;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(msg: String) {
+    // Interior nul bytes would truncate the message, so replace them.
+    let msg = CString::new(msg.replace('\0', " ")).unwrap();
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(msg));
+}
+
+/// Run `f`, converting errors and panics into an error code and recording the message.
+pub(crate) fn ffi_call<F>(f: F) -> PolarsErrorCode
+where
+    F: FnOnce() -> CApiResult<()>,
+{
+    let (code, msg) = match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => return PolarsErrorCode::Ok,
+        Ok(Err(CApiError::Polars(err))) => ((&err).into(), err.to_string()),
+        Ok(Err(CApiError::NullPointer(arg))) => (
+            PolarsErrorCode::NullPointer,
+            format!("argument `{arg}` is a null pointer"),
+        ),
+        Ok(Err(CApiError::InvalidUtf8(arg))) => (
+            PolarsErrorCode::InvalidUtf8,
+            format!("argument `{arg}` is not valid utf-8"),
+        ),
+        Err(payload) => {
+            let msg = if let Some(s) = payload.downcast_ref::<&str>() {
+                s.to_string()
+            } else if let Some(s) = payload.downcast_ref::<String>() {
+                s.clone()
+            } else {
+                "unknown panic".to_string()
+            };
+            (PolarsErrorCode::Panic, format!("polars panicked: {msg}"))
+        },
+    };
+    set_last_error(msg);
+    code
+}
+
+/// Message of the last error raised on the calling thread, or null if there was none.
+///
+/// The string is owned by the library and stays valid until the next failing call on the
+/// same thread.
+#[no_mangle]
+pub extern "C" fn polars_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|e| match e.borrow().as_ref() {
+        Some(msg) => msg.as_ptr(),
+        None => std::ptr::null(),
+    })
+}
@@ -0,0 +1,128 @@
+use arrow::array::StructArray;
+use arrow::ffi::{self, ArrowArray, ArrowSchema};
+use polars_core::prelude::*;
+
+use crate::*;
+
+/// Opaque handle to a `DataFrame`.
+pub struct PolarsDataFrame {
+    pub(crate) df: DataFrame,
+}
+
+/// Import a `DataFrame` from a struct array of the Arrow C Data Interface.
+///
+/// The fields of the struct become the columns. Ownership of `array` is moved into the
+/// `DataFrame` and `array` is left released; `schema` is only borrowed.
+///
+/// # Safety
+/// `array` and `schema` must be valid C Data Interface structures and `out` must be valid
+/// for writes.
+#[no_mangle]
+pub unsafe extern "C" fn polars_data_frame_from_arrow(
+    array: *mut ArrowArray,
+    schema: *const ArrowSchema,
+    out: *mut *mut PolarsDataFrame,
+) -> PolarsErrorCode {
+    ffi_call(|| {
+        let array = as_mut(array, "array")?;
+        let schema = as_ref(schema, "schema")?;
+        as_mut(out, "out")?;
+
+        let array = std::mem::replace(array, ArrowArray::empty());
+        let df = import_data_frame(array, schema)?;
+
+        write_handle(out, PolarsDataFrame { df });
+        Ok(())
+    })
+}
+
+unsafe fn import_data_frame(array: ArrowArray, schema: &ArrowSchema) -> PolarsResult<DataFrame> {
+    let field = ffi::import_field_from_c(schema)?;
+    polars_ensure!(
+        matches!(field.data_type.to_logical_type(), ArrowDataType::Struct(_)),
+        SchemaMismatch: "expected a struct array to import a DataFrame, got {:?}", field.data_type
+    );
+    let array = ffi::import_array_from_c(array, field.data_type)?;
+    let array = array
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap()
+        .clone();
+    DataFrame::try_from(array)
+}
+
+fn export_data_frame(df: &DataFrame) -> PolarsResult<(ArrowArray, ArrowSchema)> {
+    polars_ensure!(
+        df.width() > 0,
+        InvalidOperation: "cannot export a DataFrame without columns"
+    );
+
+    let mut df = df.clone();
+    df.as_single_chunk_par();
+    let fields = df.schema().to_arrow(false).fields;
+    let values = df
+        .iter_chunks(false, true)
+        .next()
+        .map(|batch| batch.into_arrays())
+        .unwrap_or_default();
+    let data_type = ArrowDataType::Struct(fields);
+    let array = StructArray::try_new(data_type.clone(), values, None)?;
+
+    Ok((
+        ffi::export_array_to_c(Box::new(array)),
+        ffi::export_field_to_c(&ArrowField::new("", data_type, false)),
+    ))
+}
+
+/// Export a `DataFrame` as a struct array of the Arrow C Data Interface.
+///
+/// The columns are rechunked into a single array each and exported with the standard Arrow
+/// types. `out_array` and `out_schema` may point to uninitialized memory; the caller owns
+/// the written structures and must call their `release` callbacks.
+///
+/// # Safety
+/// `df` must be a valid handle and `out_array` and `out_schema` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn polars_data_frame_to_arrow(
+    df: *const PolarsDataFrame,
+    out_array: *mut ArrowArray,
+    out_schema: *mut ArrowSchema,
+) -> PolarsErrorCode {
+    ffi_call(|| {
+        let df = &as_ref(df, "df")?.df;
+        as_mut(out_array, "out_array")?;
+        as_mut(out_schema, "out_schema")?;
+        let (array, schema) = export_data_frame(df)?;
+
+        out_array.write(array);
+        out_schema.write(schema);
+        Ok(())
+    })
+}
+
+/// Number of rows of the `DataFrame`, or 0 for a null handle.
+///
+/// # Safety
+/// `df` must be null or a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn polars_data_frame_height(df: *const PolarsDataFrame) -> usize {
+    df.as_ref().map_or(0, |df| df.df.height())
+}
+
+/// Number of columns of the `DataFrame`, or 0 for a null handle.
+///
+/// # Safety
+/// `df` must be null or a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn polars_data_frame_width(df: *const PolarsDataFrame) -> usize {
+    df.as_ref().map_or(0, |df| df.df.width())
+}
+
+/// Release a `DataFrame` handle. Null is ignored.
+///
+/// # Safety
+/// `df` must be null or a handle that was not released yet.
+#[no_mangle]
+pub unsafe extern "C" fn polars_data_frame_free(df: *mut PolarsDataFrame) {
+    free_handle(df)
+}
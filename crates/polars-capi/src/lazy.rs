@@ -0,0 +1,179 @@
+use std::ffi::{c_char, CString};
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::SQLContext;
+
+use crate::*;
+
+/// Opaque handle to a `LazyFrame`.
+pub struct PolarsLazyFrame {
+    pub(crate) lf: LazyFrame,
+}
+
+/// Opaque handle to a SQL context in which `LazyFrame`s are registered as tables.
+pub struct PolarsSQLContext {
+    pub(crate) ctx: SQLContext,
+}
+
+/// Create a `LazyFrame` that scans `df`. The `DataFrame` handle stays owned by the caller.
+///
+/// # Safety
+/// `df` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_from_data_frame(
+    df: *const PolarsDataFrame,
+    out: *mut *mut PolarsLazyFrame,
+) -> PolarsErrorCode {
+    ffi_call(|| {
+        let df = as_ref(df, "df")?;
+        as_mut(out, "out")?;
+        let lf = df.df.clone().lazy();
+        write_handle(out, PolarsLazyFrame { lf });
+        Ok(())
+    })
+}
+
+/// Create a `LazyFrame` from a plan serialized as JSON, as written by
+/// `polars_lazy_frame_serialize_json` or `LazyFrame.serialize` in Python.
+///
+/// # Safety
+/// `json` must be a nul terminated string and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_deserialize_json(
+    json: *const c_char,
+    out: *mut *mut PolarsLazyFrame,
+) -> PolarsErrorCode {
+    ffi_call(|| {
+        let json = as_str(json, "json")?;
+        as_mut(out, "out")?;
+
+        // SAFETY:
+        // We skipped the serializing/deserializing of the static in lifetime in `DataType`
+        // so we actually don't have a lifetime at all when serializing.
+        let json = std::mem::transmute::<&'_ str, &'static str>(json);
+        let plan = serde_json::from_str::<DslPlan>(json)
+            .map_err(|err| polars_err!(ComputeError: "could not deserialize plan: {err}"))?;
+
+        write_handle(out, PolarsLazyFrame { lf: plan.into() });
+        Ok(())
+    })
+}
+
+/// Serialize the plan of a `LazyFrame` as JSON. The string must be released with
+/// `polars_string_free`.
+///
+/// # Safety
+/// `lf` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_serialize_json(
+    lf: *const PolarsLazyFrame,
+    out: *mut *mut c_char,
+) -> PolarsErrorCode {
+    ffi_call(|| {
+        let lf = as_ref(lf, "lf")?;
+        as_mut(out, "out")?;
+
+        let json = serde_json::to_string(&lf.lf.logical_plan)
+            .map_err(|err| polars_err!(ComputeError: "could not serialize plan: {err}"))?;
+        // JSON escapes control characters, so the string has no interior nul bytes.
+        out.write(CString::new(json).unwrap().into_raw());
+        Ok(())
+    })
+}
+
+/// Run the query of a `LazyFrame` and write the result to `out`. The `LazyFrame` stays valid
+/// and can be collected again.
+///
+/// # Safety
+/// `lf` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_collect(
+    lf: *const PolarsLazyFrame,
+    out: *mut *mut PolarsDataFrame,
+) -> PolarsErrorCode {
+    ffi_call(|| {
+        let lf = as_ref(lf, "lf")?;
+        as_mut(out, "out")?;
+        let df = lf.lf.clone().collect()?;
+        write_handle(out, PolarsDataFrame { df });
+        Ok(())
+    })
+}
+
+/// Release a `LazyFrame` handle. Null is ignored.
+///
+/// # Safety
+/// `lf` must be null or a handle that was not released yet.
+#[no_mangle]
+pub unsafe extern "C" fn polars_lazy_frame_free(lf: *mut PolarsLazyFrame) {
+    free_handle(lf)
+}
+
+/// Create an empty SQL context.
+#[no_mangle]
+pub extern "C" fn polars_sql_context_new() -> *mut PolarsSQLContext {
+    Box::into_raw(Box::new(PolarsSQLContext {
+        ctx: SQLContext::new(),
+    }))
+}
+
+/// Register `lf` as the table `name`. The `LazyFrame` handle stays owned by the caller.
+///
+/// # Safety
+/// `ctx` and `lf` must be valid handles and `name` must be a nul terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn polars_sql_context_register(
+    ctx: *mut PolarsSQLContext,
+    name: *const c_char,
+    lf: *const PolarsLazyFrame,
+) -> PolarsErrorCode {
+    ffi_call(|| {
+        let ctx = as_mut(ctx, "ctx")?;
+        let name = as_str(name, "name")?;
+        let lf = as_ref(lf, "lf")?;
+        ctx.ctx.register(name, lf.lf.clone());
+        Ok(())
+    })
+}
+
+/// Translate a SQL query over the registered tables into a `LazyFrame`.
+///
+/// # Safety
+/// `ctx` must be a valid handle, `query` must be a nul terminated string and `out` must be
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn polars_sql_context_execute(
+    ctx: *mut PolarsSQLContext,
+    query: *const c_char,
+    out: *mut *mut PolarsLazyFrame,
+) -> PolarsErrorCode {
+    ffi_call(|| {
+        let ctx = as_mut(ctx, "ctx")?;
+        let query = as_str(query, "query")?;
+        as_mut(out, "out")?;
+        let lf = ctx.ctx.execute(query)?;
+        write_handle(out, PolarsLazyFrame { lf });
+        Ok(())
+    })
+}
+
+/// Release a SQL context. Null is ignored.
+///
+/// # Safety
+/// `ctx` must be null or a handle that was not released yet.
+#[no_mangle]
+pub unsafe extern "C" fn polars_sql_context_free(ctx: *mut PolarsSQLContext) {
+    free_handle(ctx)
+}
+
+/// Release a string returned by the library. Null is ignored.
+///
+/// # Safety
+/// `s` must be null or a string returned by the library that was not released yet.
+#[no_mangle]
+pub unsafe extern "C" fn polars_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s))
+    }
+}
@@ -0,0 +1,165 @@
+//! A C interface to embed Polars in other runtimes.
+//!
+//! Handles are opaque pointers owned by the caller, which must release them with the matching
+//! `*_free` function. Fallible functions return a [`PolarsErrorCode`] and write their result
+//! through an out pointer; the message of a failure is available through
+//! [`polars_last_error_message`].
+mod error;
+mod frame;
+mod lazy;
+
+use std::ffi::{c_char, CStr};
+
+pub use error::*;
+pub use frame::*;
+pub use lazy::*;
+
+/// # Safety
+/// `ptr` must be null or point to a valid `T`.
+unsafe fn as_ref<'a, T>(ptr: *const T, arg: &'static str) -> CApiResult<&'a T> {
+    ptr.as_ref().ok_or(CApiError::NullPointer(arg))
+}
+
+/// # Safety
+/// `ptr` must be null or point to a valid `T`.
+unsafe fn as_mut<'a, T>(ptr: *mut T, arg: &'static str) -> CApiResult<&'a mut T> {
+    ptr.as_mut().ok_or(CApiError::NullPointer(arg))
+}
+
+/// # Safety
+/// `ptr` must be null or point to a nul terminated string.
+unsafe fn as_str<'a>(ptr: *const c_char, arg: &'static str) -> CApiResult<&'a str> {
+    if ptr.is_null() {
+        return Err(CApiError::NullPointer(arg));
+    }
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map_err(|_| CApiError::InvalidUtf8(arg))
+}
+
+/// Box `value` into a handle and write it to `out`, which may point to uninitialized memory.
+///
+/// # Safety
+/// `out` must be valid for writes.
+unsafe fn write_handle<T>(out: *mut *mut T, value: T) {
+    out.write(Box::into_raw(Box::new(value)))
+}
+
+/// Release a handle created by [`write_handle`]. Null is ignored.
+///
+/// # Safety
+/// `ptr` must be null or a handle that was not released yet.
+unsafe fn free_handle<T>(ptr: *mut T) {
+    if !ptr.is_null() {
+        drop(Box::from_raw(ptr))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::CString;
+    use std::ptr;
+
+    use arrow::ffi::{ArrowArray, ArrowSchema};
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    fn data_frame_handle(df: DataFrame) -> *mut PolarsDataFrame {
+        let mut out = ptr::null_mut();
+        unsafe { write_handle(&mut out, PolarsDataFrame { df }) };
+        out
+    }
+
+    #[test]
+    fn test_arrow_roundtrip() {
+        let df = df!(
+            "a" => [1, 2, 3],
+            "b" => [Some("x"), None, Some("z")]
+        )
+        .unwrap();
+        let handle = data_frame_handle(df.clone());
+
+        let mut array = ArrowArray::empty();
+        let mut schema = ArrowSchema::empty();
+        let code = unsafe { polars_data_frame_to_arrow(handle, &mut array, &mut schema) };
+        assert_eq!(code, PolarsErrorCode::Ok);
+
+        let mut imported = ptr::null_mut();
+        let code = unsafe { polars_data_frame_from_arrow(&mut array, &schema, &mut imported) };
+        assert_eq!(code, PolarsErrorCode::Ok);
+        assert!(unsafe { &(*imported).df }.equals_missing(&df));
+
+        unsafe {
+            polars_data_frame_free(handle);
+            polars_data_frame_free(imported);
+        }
+    }
+
+    #[test]
+    fn test_sql_collect() {
+        let df = df!("a" => [1, 2, 3]).unwrap();
+        let handle = data_frame_handle(df);
+
+        unsafe {
+            let mut lf = ptr::null_mut();
+            assert_eq!(
+                polars_lazy_frame_from_data_frame(handle, &mut lf),
+                PolarsErrorCode::Ok
+            );
+
+            let ctx = polars_sql_context_new();
+            let name = CString::new("tbl").unwrap();
+            assert_eq!(
+                polars_sql_context_register(ctx, name.as_ptr(), lf),
+                PolarsErrorCode::Ok
+            );
+
+            let query = CString::new("SELECT a * 2 AS b FROM tbl WHERE a > 1").unwrap();
+            let mut result = ptr::null_mut();
+            assert_eq!(
+                polars_sql_context_execute(ctx, query.as_ptr(), &mut result),
+                PolarsErrorCode::Ok
+            );
+
+            // Round trip the plan through its serialized form before collecting.
+            let mut json = ptr::null_mut();
+            assert_eq!(
+                polars_lazy_frame_serialize_json(result, &mut json),
+                PolarsErrorCode::Ok
+            );
+            let mut deserialized = ptr::null_mut();
+            assert_eq!(
+                polars_lazy_frame_deserialize_json(json, &mut deserialized),
+                PolarsErrorCode::Ok
+            );
+
+            let mut out = ptr::null_mut();
+            assert_eq!(
+                polars_lazy_frame_collect(deserialized, &mut out),
+                PolarsErrorCode::Ok
+            );
+            assert_eq!((*out).df, df!("b" => [4, 6]).unwrap());
+
+            let bad = CString::new("SELECT * FROM missing").unwrap();
+            let mut unused = ptr::null_mut();
+            assert_eq!(
+                polars_sql_context_execute(ctx, bad.as_ptr(), &mut unused),
+                PolarsErrorCode::SQLInterface
+            );
+            assert!(!polars_last_error_message().is_null());
+            assert_eq!(
+                polars_lazy_frame_collect(ptr::null(), &mut out),
+                PolarsErrorCode::NullPointer
+            );
+
+            polars_string_free(json);
+            polars_data_frame_free(out);
+            polars_lazy_frame_free(deserialized);
+            polars_lazy_frame_free(result);
+            polars_sql_context_free(ctx);
+            polars_lazy_frame_free(lf);
+            polars_data_frame_free(handle);
+        }
+    }
+}
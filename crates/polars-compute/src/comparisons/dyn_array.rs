@@ -64,6 +64,9 @@ macro_rules! compare {
             PH::Struct => call_binary!(StructArray, lhs, rhs, $op),
             PH::Union => todo!("Comparison of UnionArrays is not yet supported"),
             PH::Map => todo!("Comparison of MapArrays is not yet supported"),
+            PH::RunEndEncoded => {
+                todo!("Comparison of RunEndEncodedArrays is not yet supported")
+            },
             PH::Dictionary(I::Int8) => call_binary!(DictionaryArray<i8>, lhs, rhs, $op),
             PH::Dictionary(I::Int16) => call_binary!(DictionaryArray<i16>, lhs, rhs, $op),
             PH::Dictionary(I::Int32) => call_binary!(DictionaryArray<i32>, lhs, rhs, $op),
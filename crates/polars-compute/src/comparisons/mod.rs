@@ -79,7 +79,7 @@ pub trait TotalOrdKernel: Sized + Array {
 mod binary;
 mod boolean;
 mod dictionary;
-mod dyn_array;
+pub(crate) mod dyn_array;
 mod list;
 mod null;
 mod scalar;
@@ -1,13 +1,16 @@
 //! Contains operators to filter arrays such as [`filter`].
 mod boolean;
 mod primitive;
+mod run_end_encoded;
 mod scalar;
 
 #[cfg(all(target_arch = "x86_64", feature = "simd"))]
 mod avx512;
 
 use arrow::array::growable::make_growable;
-use arrow::array::{new_empty_array, Array, BinaryViewArray, BooleanArray, PrimitiveArray};
+use arrow::array::{
+    new_empty_array, Array, BinaryViewArray, BooleanArray, PrimitiveArray, RunEndEncodedArray,
+};
 use arrow::bitmap::utils::SlicesIterator;
 use arrow::bitmap::Bitmap;
 use arrow::with_match_primitive_type_full;
@@ -15,12 +18,13 @@ use arrow::with_match_primitive_type_full;
 pub fn filter(array: &dyn Array, mask: &BooleanArray) -> Box<dyn Array> {
     assert_eq!(array.len(), mask.len());
 
-    // Treat null mask values as false.
-    if let Some(validities) = mask.validity() {
-        let combined_mask = mask.values() & validities;
-        filter_with_bitmap(array, &combined_mask)
-    } else {
-        filter_with_bitmap(array, mask.values())
+    // Treat null mask values as false, which only changes the mask if a null value is true.
+    match mask.validity() {
+        Some(validities) if !mask.values().is_subset_of(validities) => {
+            let combined_mask = mask.values() & validities;
+            filter_with_bitmap(array, &combined_mask)
+        },
+        _ => filter_with_bitmap(array, mask.values()),
     }
 }
 
@@ -67,6 +71,10 @@ pub fn filter_with_bitmap(array: &dyn Array, mask: &Bitmap) -> Box<dyn Array> {
         Utf8View => {
             unreachable!()
         },
+        RunEndEncoded => {
+            let array = array.as_any().downcast_ref::<RunEndEncodedArray>().unwrap();
+            run_end_encoded::filter_run_end_encoded(array, mask).boxed()
+        },
         _ => {
             let iter = SlicesIterator::new(mask);
             let mut mutable = make_growable(&[array], false, iter.slots());
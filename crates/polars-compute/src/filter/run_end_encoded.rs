@@ -0,0 +1,43 @@
+use arrow::array::growable::make_growable;
+use arrow::array::{Array, RunEndEncodedArray};
+use arrow::bitmap::utils::count_zeros;
+use arrow::bitmap::Bitmap;
+
+/// Filters the runs of `array`, of which the kept elements stay a run and the runs without
+/// kept elements are dropped, so the values are never decoded.
+pub fn filter_run_end_encoded(array: &RunEndEncodedArray, mask: &Bitmap) -> RunEndEncodedArray {
+    let (slice, offset, _) = mask.as_slice();
+    let values = array.values().as_ref();
+    let mut growable = make_growable(&[values], false, 0);
+    let mut run_ends = Vec::new();
+
+    let mut position = 0;
+    let mut kept = 0;
+    // The range of consecutive kept values that is not yet in the growable.
+    let mut pending = None;
+    for (value, length) in array.runs() {
+        let count = length - count_zeros(slice, offset + position, length);
+        position += length;
+        if count == 0 {
+            if let Some((start, end)) = pending.take() {
+                unsafe { growable.extend(0, start, end - start) };
+            }
+            continue;
+        }
+        kept += count;
+        run_ends.push(kept as i32);
+        pending = match pending {
+            Some((start, _)) => Some((start, value + 1)),
+            None => Some((value, value + 1)),
+        };
+    }
+    if let Some((start, end)) = pending {
+        unsafe { growable.extend(0, start, end - start) };
+    }
+
+    RunEndEncodedArray::new(
+        array.data_type().clone(),
+        run_ends.into(),
+        growable.as_box(),
+    )
+}
@@ -0,0 +1,109 @@
+use arrow::array::growable::{Growable, GrowableDictionary};
+use arrow::array::{DictionaryArray, DictionaryKey};
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::ArrowDataType;
+
+use super::{if_then_else_extend, IfThenElseKernel};
+
+impl<K: DictionaryKey> IfThenElseKernel for DictionaryArray<K> {
+    /// A unit length [`DictionaryArray`] holding the broadcast value.
+    type Scalar<'a> = &'a DictionaryArray<K>;
+
+    fn if_then_else(mask: &Bitmap, if_true: &Self, if_false: &Self) -> Self {
+        let mut growable = GrowableDictionary::new(&[if_true, if_false], false, mask.len());
+        unsafe {
+            if_then_else_extend(
+                &mut growable,
+                mask,
+                |g, off, len| g.extend(0, off, len),
+                |g, off, len| g.extend(1, off, len),
+            )
+        };
+        growable.into()
+    }
+
+    fn if_then_else_broadcast_true(
+        mask: &Bitmap,
+        if_true: Self::Scalar<'_>,
+        if_false: &Self,
+    ) -> Self {
+        let mut growable = GrowableDictionary::new(&[if_true, if_false], false, mask.len());
+        unsafe {
+            if_then_else_extend(
+                &mut growable,
+                mask,
+                |g, _, len| g.extend_copies(0, 0, 1, len),
+                |g, off, len| g.extend(1, off, len),
+            )
+        };
+        growable.into()
+    }
+
+    fn if_then_else_broadcast_false(
+        mask: &Bitmap,
+        if_true: &Self,
+        if_false: Self::Scalar<'_>,
+    ) -> Self {
+        let mut growable = GrowableDictionary::new(&[if_true, if_false], false, mask.len());
+        unsafe {
+            if_then_else_extend(
+                &mut growable,
+                mask,
+                |g, off, len| g.extend(0, off, len),
+                |g, _, len| g.extend_copies(1, 0, 1, len),
+            )
+        };
+        growable.into()
+    }
+
+    fn if_then_else_broadcast_both(
+        _dtype: ArrowDataType,
+        mask: &Bitmap,
+        if_true: Self::Scalar<'_>,
+        if_false: Self::Scalar<'_>,
+    ) -> Self {
+        let mut growable = GrowableDictionary::new(&[if_true, if_false], false, mask.len());
+        unsafe {
+            if_then_else_extend(
+                &mut growable,
+                mask,
+                |g, _, len| g.extend_copies(0, 0, 1, len),
+                |g, _, len| g.extend_copies(1, 0, 1, len),
+            )
+        };
+        growable.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::array::{PrimitiveArray, Utf8ViewArray};
+
+    use super::*;
+
+    #[test]
+    fn test_if_then_else_different_dictionaries() {
+        let dictionary = |keys: Vec<u32>, values: &[&str]| {
+            let values = Utf8ViewArray::from_slice_values(values).boxed();
+            DictionaryArray::try_from_keys(PrimitiveArray::from_vec(keys), values).unwrap()
+        };
+        let if_true = dictionary(vec![0, 1, 0], &["a", "b"]);
+        let if_false = dictionary(vec![1, 1, 0], &["c", "d"]);
+        let mask = Bitmap::from([true, false, false]);
+
+        let out = IfThenElseKernel::if_then_else(&mask, &if_true, &if_false);
+        let values = out
+            .iter_typed::<Utf8ViewArray>()
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(values, [Some("a"), Some("d"), Some("c")]);
+
+        let scalar = dictionary(vec![1], &["a", "b"]);
+        let out = IfThenElseKernel::if_then_else_broadcast_false(&mask, &if_true, &scalar);
+        let values = out
+            .iter_typed::<Utf8ViewArray>()
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(values, [Some("a"), Some("b"), Some("b")]);
+    }
+}
@@ -9,10 +9,12 @@ use crate::NotSimdPrimitive;
 
 mod array;
 mod boolean;
+mod dictionary;
 mod list;
 mod scalar;
 #[cfg(feature = "simd")]
 mod simd;
+mod structure;
 mod view;
 
 pub trait IfThenElseKernel: Sized + Array {
@@ -0,0 +1,76 @@
+use arrow::array::growable::{Growable, GrowableStruct};
+use arrow::array::StructArray;
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::ArrowDataType;
+
+use super::{if_then_else_extend, IfThenElseKernel};
+
+impl IfThenElseKernel for StructArray {
+    /// A unit length [`StructArray`] holding the broadcast value.
+    type Scalar<'a> = &'a StructArray;
+
+    fn if_then_else(mask: &Bitmap, if_true: &Self, if_false: &Self) -> Self {
+        let mut growable = GrowableStruct::new(vec![if_true, if_false], false, mask.len());
+        unsafe {
+            if_then_else_extend(
+                &mut growable,
+                mask,
+                |g, off, len| g.extend(0, off, len),
+                |g, off, len| g.extend(1, off, len),
+            )
+        };
+        growable.into()
+    }
+
+    fn if_then_else_broadcast_true(
+        mask: &Bitmap,
+        if_true: Self::Scalar<'_>,
+        if_false: &Self,
+    ) -> Self {
+        let mut growable = GrowableStruct::new(vec![if_true, if_false], false, mask.len());
+        unsafe {
+            if_then_else_extend(
+                &mut growable,
+                mask,
+                |g, _, len| g.extend_copies(0, 0, 1, len),
+                |g, off, len| g.extend(1, off, len),
+            )
+        };
+        growable.into()
+    }
+
+    fn if_then_else_broadcast_false(
+        mask: &Bitmap,
+        if_true: &Self,
+        if_false: Self::Scalar<'_>,
+    ) -> Self {
+        let mut growable = GrowableStruct::new(vec![if_true, if_false], false, mask.len());
+        unsafe {
+            if_then_else_extend(
+                &mut growable,
+                mask,
+                |g, off, len| g.extend(0, off, len),
+                |g, _, len| g.extend_copies(1, 0, 1, len),
+            )
+        };
+        growable.into()
+    }
+
+    fn if_then_else_broadcast_both(
+        _dtype: ArrowDataType,
+        mask: &Bitmap,
+        if_true: Self::Scalar<'_>,
+        if_false: Self::Scalar<'_>,
+    ) -> Self {
+        let mut growable = GrowableStruct::new(vec![if_true, if_false], false, mask.len());
+        unsafe {
+            if_then_else_extend(
+                &mut growable,
+                mask,
+                |g, _, len| g.extend_copies(0, 0, 1, len),
+                |g, _, len| g.extend_copies(1, 0, 1, len),
+            )
+        };
+        growable.into()
+    }
+}
@@ -14,6 +14,7 @@ pub mod filter;
 pub mod float_sum;
 pub mod if_then_else;
 pub mod min_max;
+pub mod run_end_encoded;
 
 pub mod arity;
 
@@ -0,0 +1,294 @@
+use core::arch::x86_64::*;
+
+use arrow::bitmap::bitmask::BitMask;
+use arrow::bitmap::Bitmap;
+use arrow::types::{NativeType, PrimitiveType};
+use bytemuck::{cast, cast_slice};
+
+// It's not possible to inline target_feature(enable = ...) functions into other
+// functions without that enabled, so we use macros for the shared parts of the kernels.
+
+// Expands the validity bits of 8 values into a mask of 32-bit lanes.
+macro_rules! lane_mask_8x32 {
+    ($bits:expr) => {{
+        let bit = _mm256_setr_epi32(1, 2, 4, 8, 16, 32, 64, 128);
+        _mm256_cmpeq_epi32(_mm256_and_si256(_mm256_set1_epi32($bits as i32), bit), bit)
+    }};
+}
+
+// Expands the validity bits of 4 values into a mask of 64-bit lanes.
+macro_rules! lane_mask_4x64 {
+    ($bits:expr) => {{
+        let bit = _mm256_setr_epi64x(1, 2, 4, 8);
+        _mm256_cmpeq_epi64(_mm256_and_si256(_mm256_set1_epi64x($bits as i64), bit), bit)
+    }};
+}
+
+// Iterates over the values in chunks of `$LANES`, with the bits of the lanes that are valid
+// and in bounds. Masked loads don't touch the other lanes, so the remainder needs no copy.
+macro_rules! for_each_chunk {
+    ($len:expr, $validity:expr, $LANES:literal, |$offset:ident, $bits:ident| $body:block) => {{
+        let len = $len;
+        let mask = $validity.map(BitMask::from_bitmap);
+        let mut $offset = 0;
+        while $offset < len {
+            let in_bounds = u32::MAX >> (32 - (len - $offset).min($LANES));
+            let $bits = match &mask {
+                Some(mask) => mask.get_u32($offset) & in_bounds,
+                None => in_bounds,
+            };
+            // Skip chunks that are completely null.
+            if $bits != 0 {
+                $body
+            }
+            $offset += $LANES;
+        }
+    }};
+}
+
+macro_rules! impl_float_kernel {
+    ($name:ident, $T:ty, $LANES:literal, $lane_mask:ident, $set1:ident, $maskload:ident,
+     $blendv:ident, $cast_mask:ident, $cmp:ident, $or:ident, $andnot:ident, $movemask:ident,
+     $storeu:ident, $min:ident, $max:ident) => {
+        /// # Safety
+        /// AVX2 must be enabled and at least one value must be valid.
+        #[target_feature(enable = "avx2")]
+        unsafe fn $name<const MAX: bool, const PROPAGATE_NAN: bool>(
+            values: &[$T],
+            validity: Option<&Bitmap>,
+        ) -> $T {
+            let identity = $set1(if MAX {
+                <$T>::NEG_INFINITY
+            } else {
+                <$T>::INFINITY
+            });
+            let mut state = identity;
+            // The lanes that loaded a NaN, and the lanes that loaded another valid value.
+            let mut nan = $set1(0.0);
+            let mut not_nan = $set1(0.0);
+            for_each_chunk!(values.len(), validity, $LANES, |offset, bits| {
+                let m = $lane_mask!(bits);
+                let x = $maskload(values.as_ptr().add(offset), m);
+                let x = $blendv(identity, x, $cast_mask(m));
+                let is_nan = $cmp::<_CMP_UNORD_Q>(x, x);
+                nan = $or(nan, is_nan);
+                not_nan = $or(not_nan, $andnot(is_nan, $cast_mask(m)));
+                // A NaN in `x` yields the second operand, so NaNs are ignored.
+                state = if MAX { $max(x, state) } else { $min(x, state) };
+            });
+
+            if (PROPAGATE_NAN && $movemask(nan) != 0) || $movemask(not_nan) == 0 {
+                return <$T>::NAN;
+            }
+            let mut lanes = [0.0; $LANES];
+            $storeu(lanes.as_mut_ptr(), state);
+            let lanes = lanes.into_iter();
+            if MAX {
+                lanes.reduce(<$T>::max).unwrap()
+            } else {
+                lanes.reduce(<$T>::min).unwrap()
+            }
+        }
+    };
+}
+
+impl_float_kernel!(
+    min_max_f32,
+    f32,
+    8,
+    lane_mask_8x32,
+    _mm256_set1_ps,
+    _mm256_maskload_ps,
+    _mm256_blendv_ps,
+    _mm256_castsi256_ps,
+    _mm256_cmp_ps,
+    _mm256_or_ps,
+    _mm256_andnot_ps,
+    _mm256_movemask_ps,
+    _mm256_storeu_ps,
+    _mm256_min_ps,
+    _mm256_max_ps
+);
+impl_float_kernel!(
+    min_max_f64,
+    f64,
+    4,
+    lane_mask_4x64,
+    _mm256_set1_pd,
+    _mm256_maskload_pd,
+    _mm256_blendv_pd,
+    _mm256_castsi256_pd,
+    _mm256_cmp_pd,
+    _mm256_or_pd,
+    _mm256_andnot_pd,
+    _mm256_movemask_pd,
+    _mm256_storeu_pd,
+    _mm256_min_pd,
+    _mm256_max_pd
+);
+
+macro_rules! impl_int32_kernel {
+    ($name:ident, $T:ty, $min:ident, $max:ident) => {
+        /// # Safety
+        /// AVX2 must be enabled and at least one value must be valid.
+        #[target_feature(enable = "avx2")]
+        unsafe fn $name<const MAX: bool>(values: &[$T], validity: Option<&Bitmap>) -> $T {
+            let identity = _mm256_set1_epi32((if MAX { <$T>::MIN } else { <$T>::MAX }) as i32);
+            let mut state = identity;
+            for_each_chunk!(values.len(), validity, 8, |offset, bits| {
+                let m = lane_mask_8x32!(bits);
+                let x = _mm256_maskload_epi32(values.as_ptr().add(offset) as *const i32, m);
+                let x = _mm256_blendv_epi8(identity, x, m);
+                state = if MAX { $max(x, state) } else { $min(x, state) };
+            });
+
+            let mut lanes: [$T; 8] = [0; 8];
+            _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, state);
+            let lanes = lanes.into_iter();
+            if MAX {
+                lanes.max().unwrap()
+            } else {
+                lanes.min().unwrap()
+            }
+        }
+    };
+}
+
+impl_int32_kernel!(min_max_i32, i32, _mm256_min_epi32, _mm256_max_epi32);
+impl_int32_kernel!(min_max_u32, u32, _mm256_min_epu32, _mm256_max_epu32);
+
+// AVX2 has no min/max of 64-bit integers, so we select with a comparison. Unsigned values are
+// compared as signed values after flipping their sign bit.
+macro_rules! impl_int64_kernel {
+    ($name:ident, $T:ty, $sign_flip:expr) => {
+        /// # Safety
+        /// AVX2 must be enabled and at least one value must be valid.
+        #[target_feature(enable = "avx2")]
+        unsafe fn $name<const MAX: bool>(values: &[$T], validity: Option<&Bitmap>) -> $T {
+            let identity = _mm256_set1_epi64x((if MAX { <$T>::MIN } else { <$T>::MAX }) as i64);
+            let sign_flip = _mm256_set1_epi64x($sign_flip);
+            let mut state = identity;
+            for_each_chunk!(values.len(), validity, 4, |offset, bits| {
+                let m = lane_mask_4x64!(bits);
+                let x = _mm256_maskload_epi64(values.as_ptr().add(offset) as *const i64, m);
+                let x = _mm256_blendv_epi8(identity, x, m);
+                let (a, b) = if MAX { (x, state) } else { (state, x) };
+                let a_gt_b = _mm256_cmpgt_epi64(
+                    _mm256_xor_si256(a, sign_flip),
+                    _mm256_xor_si256(b, sign_flip),
+                );
+                state = _mm256_blendv_epi8(state, x, a_gt_b);
+            });
+
+            let mut lanes: [$T; 4] = [0; 4];
+            _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, state);
+            let lanes = lanes.into_iter();
+            if MAX {
+                lanes.max().unwrap()
+            } else {
+                lanes.min().unwrap()
+            }
+        }
+    };
+}
+
+impl_int64_kernel!(min_max_i64, i64, 0);
+impl_int64_kernel!(min_max_u64, u64, i64::MIN);
+
+/// Computes the min or max with AVX2, if the CPU supports it and there is a kernel for `T`.
+///
+/// Returns `None` if no kernel applies, and `Some(None)` if there are no valid values.
+pub(super) fn min_max_avx2<T: NativeType, const MAX: bool, const PROPAGATE_NAN: bool>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+) -> Option<Option<T>> {
+    if !std::arch::is_x86_feature_detected!("avx2") {
+        return None;
+    }
+    if values.len() == validity.map_or(0, |v| v.unset_bits()) {
+        return Some(None);
+    }
+
+    // SAFETY: AVX2 is enabled, there is a valid value and `T` is the type of the kernel.
+    let out = unsafe {
+        match T::PRIMITIVE {
+            PrimitiveType::Float32 => cast(min_max_f32::<MAX, PROPAGATE_NAN>(
+                cast_slice(values),
+                validity,
+            )),
+            PrimitiveType::Float64 => cast(min_max_f64::<MAX, PROPAGATE_NAN>(
+                cast_slice(values),
+                validity,
+            )),
+            PrimitiveType::Int32 => cast(min_max_i32::<MAX>(cast_slice(values), validity)),
+            PrimitiveType::UInt32 => cast(min_max_u32::<MAX>(cast_slice(values), validity)),
+            PrimitiveType::Int64 => cast(min_max_i64::<MAX>(cast_slice(values), validity)),
+            PrimitiveType::UInt64 => cast(min_max_u64::<MAX>(cast_slice(values), validity)),
+            _ => return None,
+        }
+    };
+    Some(Some(out))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::array::PrimitiveArray;
+    use polars_utils::min_max::MinMax;
+    use rand::prelude::*;
+
+    use super::*;
+
+    fn check<T: NativeType + MinMax>(values: Vec<T>, validity: &[bool]) {
+        let arr = PrimitiveArray::from_vec(values).with_validity(Some(validity.iter().collect()));
+        let assert_agg = |avx2: Option<Option<T>>, f: fn(T, T) -> T| {
+            // Compare the debug representation, as NaN is not equal to itself.
+            let expected = arr.non_null_values_iter().reduce(f);
+            assert_eq!(format!("{:?}", avx2.unwrap()), format!("{expected:?}"));
+        };
+        let (values, validity) = (arr.values().as_slice(), arr.validity());
+        assert_agg(
+            min_max_avx2::<T, false, false>(values, validity),
+            MinMax::min_ignore_nan,
+        );
+        assert_agg(
+            min_max_avx2::<T, true, false>(values, validity),
+            MinMax::max_ignore_nan,
+        );
+        assert_agg(
+            min_max_avx2::<T, false, true>(values, validity),
+            MinMax::min_propagate_nan,
+        );
+        assert_agg(
+            min_max_avx2::<T, true, true>(values, validity),
+            MinMax::max_propagate_nan,
+        );
+    }
+
+    #[test]
+    fn test_min_max_avx2() {
+        if !std::arch::is_x86_feature_detected!("avx2") {
+            return;
+        }
+        let mut rng = StdRng::seed_from_u64(0xdeadbeef);
+        for len in [0, 1, 3, 4, 7, 8, 9, 31, 100] {
+            for null_fraction in [0.0, 0.5, 1.0] {
+                let validity: Vec<bool> = (0..len).map(|_| !rng.gen_bool(null_fraction)).collect();
+                for nan_fraction in [0.0, 0.2, 1.0] {
+                    let values: Vec<f64> = (0..len)
+                        .map(|_| match rng.gen_bool(nan_fraction) {
+                            true => f64::NAN,
+                            false => rng.gen_range(-1e12..1e12),
+                        })
+                        .collect();
+                    check(values.clone(), &validity);
+                    check(values.iter().map(|v| *v as f32).collect(), &validity);
+                }
+                let values: Vec<i64> = (0..len).map(|_| rng.gen()).collect();
+                check(values.clone(), &validity);
+                check(values.iter().map(|v| *v as u64).collect(), &validity);
+                check(values.iter().map(|v| *v as i32).collect(), &validity);
+                check(values.iter().map(|v| *v as u32).collect(), &validity);
+            }
+        }
+    }
+}
@@ -1,18 +1,30 @@
 use arrow::array::{Array, BinaryViewArray, BooleanArray, PrimitiveArray, Utf8ViewArray};
+use arrow::bitmap::Bitmap;
 use arrow::types::NativeType;
 use polars_utils::min_max::MinMax;
 
 use super::MinMaxKernel;
 
-fn reduce_vals<T, F>(v: &PrimitiveArray<T>, f: F) -> Option<T>
+fn reduce_vals<T, const MAX: bool, const PROPAGATE_NAN: bool>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+    f: fn(T, T) -> T,
+) -> Option<T>
 where
     T: NativeType,
-    F: Fn(T, T) -> T,
 {
-    if v.null_count() == 0 {
-        v.values_iter().copied().reduce(f)
-    } else {
-        v.non_null_values_iter().reduce(f)
+    #[cfg(target_arch = "x86_64")]
+    if let Some(out) = super::avx2::min_max_avx2::<T, MAX, PROPAGATE_NAN>(values, validity) {
+        return out;
+    }
+
+    match validity.filter(|v| v.unset_bits() > 0) {
+        None => values.iter().copied().reduce(f),
+        Some(validity) => values
+            .iter()
+            .zip(validity.iter())
+            .filter_map(|(v, valid)| valid.then_some(*v))
+            .reduce(f),
     }
 }
 
@@ -20,19 +32,19 @@ impl<T: NativeType + MinMax + super::NotSimdPrimitive> MinMaxKernel for Primitiv
     type Scalar<'a> = T;
 
     fn min_ignore_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
-        reduce_vals(self, MinMax::min_ignore_nan)
+        reduce_vals::<_, false, false>(self.values(), self.validity(), MinMax::min_ignore_nan)
     }
 
     fn max_ignore_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
-        reduce_vals(self, MinMax::max_ignore_nan)
+        reduce_vals::<_, true, false>(self.values(), self.validity(), MinMax::max_ignore_nan)
     }
 
     fn min_propagate_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
-        reduce_vals(self, MinMax::min_propagate_nan)
+        reduce_vals::<_, false, true>(self.values(), self.validity(), MinMax::min_propagate_nan)
     }
 
     fn max_propagate_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
-        reduce_vals(self, MinMax::max_propagate_nan)
+        reduce_vals::<_, true, true>(self.values(), self.validity(), MinMax::max_propagate_nan)
     }
 }
 
@@ -40,19 +52,19 @@ impl<T: NativeType + MinMax + super::NotSimdPrimitive> MinMaxKernel for [T] {
     type Scalar<'a> = T;
 
     fn min_ignore_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
-        self.iter().copied().reduce(MinMax::min_ignore_nan)
+        reduce_vals::<_, false, false>(self, None, MinMax::min_ignore_nan)
     }
 
     fn max_ignore_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
-        self.iter().copied().reduce(MinMax::max_ignore_nan)
+        reduce_vals::<_, true, false>(self, None, MinMax::max_ignore_nan)
     }
 
     fn min_propagate_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
-        self.iter().copied().reduce(MinMax::min_propagate_nan)
+        reduce_vals::<_, false, true>(self, None, MinMax::min_propagate_nan)
     }
 
     fn max_propagate_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
-        self.iter().copied().reduce(MinMax::max_propagate_nan)
+        reduce_vals::<_, true, true>(self, None, MinMax::max_propagate_nan)
     }
 }
 
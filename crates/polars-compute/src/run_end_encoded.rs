@@ -0,0 +1,97 @@
+//! Conversion of plain arrays to run-end encoded arrays, see [`RunEndEncodedArray`].
+use arrow::array::{new_empty_array, Array, RunEndEncodedArray};
+use arrow::bitmap::MutableBitmap;
+use arrow::datatypes::{ArrowDataType, Field};
+
+use crate::comparisons::dyn_array::array_tot_ne_missing_kernel;
+use crate::filter::filter_with_bitmap;
+
+/// Run-end encodes `array`, of which consecutive equal values (and nulls) form a run.
+pub fn run_end_encode(array: &dyn Array) -> RunEndEncodedArray {
+    let field = Field::new("values", array.data_type().clone(), true);
+    let data_type = ArrowDataType::RunEndEncoded(Box::new(field));
+    let len = array.len();
+    if len == 0 {
+        return RunEndEncodedArray::new(
+            data_type,
+            Default::default(),
+            new_empty_array(array.data_type().clone()),
+        );
+    }
+    assert!(
+        len <= i32::MAX as usize,
+        "run-end encoded arrays have Int32 run ends"
+    );
+
+    // A run ends at every element that differs from the next one, and at the last element.
+    let ne = array_tot_ne_missing_kernel(
+        array.sliced(0, len - 1).as_ref(),
+        array.sliced(1, len - 1).as_ref(),
+    );
+    let mut run_last = MutableBitmap::with_capacity(len);
+    run_last.extend_from_bitmap(&ne);
+    run_last.push(true);
+    let run_last = run_last.freeze();
+
+    let run_ends = run_last
+        .true_idx_iter()
+        .map(|i| i as i32 + 1)
+        .collect::<Vec<_>>();
+    let values = filter_with_bitmap(array, &run_last);
+    RunEndEncodedArray::new(data_type, run_ends.into(), values)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::array::{Int32Array, Utf8ViewArray};
+    use arrow::bitmap::Bitmap;
+
+    use super::*;
+
+    #[test]
+    fn test_run_end_encode() {
+        let array = Utf8ViewArray::from_slice([
+            Some("a"),
+            Some("a"),
+            None,
+            None,
+            Some("b"),
+            Some("a"),
+            Some("a"),
+        ]);
+        let ree = run_end_encode(&array);
+        assert_eq!(ree.run_ends().as_slice(), &[2, 4, 5, 7]);
+        assert_eq!(
+            ree.values().as_ref(),
+            &Utf8ViewArray::from_slice([Some("a"), None, Some("b"), Some("a")]) as &dyn Array
+        );
+        assert_eq!(ree.null_count(), 2);
+        assert_eq!(ree.decode().as_ref(), &array as &dyn Array);
+
+        // Slicing and filtering keep the runs.
+        let sliced = ree.clone().sliced(1, 5);
+        assert_eq!(
+            sliced.runs().collect::<Vec<_>>(),
+            [(0, 1), (1, 2), (2, 1), (3, 1)]
+        );
+        assert_eq!(
+            sliced.decode().as_ref(),
+            &array.clone().sliced(1, 5) as &dyn Array
+        );
+
+        let mask = Bitmap::from([true, false, false, false, true, true, false]);
+        let filtered = filter_with_bitmap(&ree, &mask);
+        let filtered = filtered
+            .as_any()
+            .downcast_ref::<RunEndEncodedArray>()
+            .unwrap();
+        assert_eq!(filtered.run_ends().as_slice(), &[1, 2, 3]);
+        assert_eq!(
+            filtered.decode().as_ref(),
+            filter_with_bitmap(&array, &mask).as_ref()
+        );
+
+        let empty = run_end_encode(&Int32Array::from_vec(vec![]));
+        assert_eq!(empty.len(), 0);
+    }
+}
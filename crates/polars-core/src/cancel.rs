@@ -0,0 +1,73 @@
+//! Cooperative cancellation of running queries.
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use polars_error::{polars_bail, PolarsResult};
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// A handle to cancel a running query from another thread.
+///
+/// Clones share the same state, so cancelling one clone cancels the query. The engines check
+/// the token between nodes and batches, upon which the query fails with
+/// [`PolarsError::Cancelled`](polars_error::PolarsError::Cancelled).
+///
+/// ```rust
+/// use polars_core::cancel::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let handle = token.clone();
+/// std::thread::spawn(move || handle.cancel()).join().unwrap();
+/// assert!(token.is_cancelled());
+/// assert!(token.check().is_err());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request the query to stop at the next opportunity.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Return a [`Cancelled`](polars_error::PolarsError::Cancelled) error if the token is
+    /// cancelled.
+    pub fn check(&self) -> PolarsResult<()> {
+        if self.is_cancelled() {
+            polars_bail!(Cancelled: "the query was cancelled before it completed");
+        }
+        Ok(())
+    }
+
+    /// Run `func` with this token as the [current](CancellationToken::current) token of this
+    /// thread. This passes the token to code that is called through a plain function, like a
+    /// streaming pipeline.
+    pub fn scope<T>(&self, func: impl FnOnce() -> T) -> T {
+        struct Reset(Option<CancellationToken>);
+        impl Drop for Reset {
+            fn drop(&mut self) {
+                CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+            }
+        }
+
+        let previous = CURRENT.with(|current| current.borrow_mut().replace(self.clone()));
+        let _reset = Reset(previous);
+        func()
+    }
+
+    /// The token set by the innermost [`CancellationToken::scope`] on this thread.
+    pub fn current() -> Option<Self> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+}
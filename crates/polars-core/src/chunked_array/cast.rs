@@ -76,6 +76,11 @@ fn cast_impl_inner(
     options: CastOptions,
 ) -> PolarsResult<Series> {
     let chunks = cast_chunks(chunks, &dtype.to_physical(), options)?;
+    // Int128 has no arrow counterpart and is stored as a decimal without scale.
+    #[cfg(feature = "dtype-i128")]
+    if matches!(dtype, DataType::Int128) {
+        return Ok(unsafe { Int128Chunked::from_chunks(name, chunks) }.into_series());
+    }
     let out = Series::try_from((name, chunks))?;
     use DataType::*;
     let out = match dtype {
@@ -157,7 +162,7 @@ where
             },
             #[cfg(feature = "dtype-categorical")]
             DataType::Enum(rev_map, ordering) => {
-                let ca = match self.dtype() {
+                let mut ca = match self.dtype() {
                     DataType::UInt32 => {
                         // SAFETY: we are guarded by the type system
                         unsafe { &*(self as *const ChunkedArray<T> as *const UInt32Chunked) }
@@ -179,7 +184,9 @@ where
                 // Check if indices are in bounds
                 if let Some(m) = ca.max() {
                     if m >= categories.len() as u32 {
-                        polars_bail!(OutOfBounds: "index {} is bigger than the number of categories {}",m,categories.len());
+                        polars_ensure!(!options.strict(), OutOfBounds: "index {} is bigger than the number of categories {}",m,categories.len());
+                        // Indices outside of the categories become null.
+                        ca = ca.set(&ca.gt_eq(categories.len() as u32), None)?;
                     }
                 }
                 // SAFETY: indices are in bound
@@ -720,4 +727,21 @@ mod test {
             .unwrap();
         assert!(matches!(out.dtype(), &DataType::Categorical(_, _)))
     }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_cast_to_enum_outside_categories() -> PolarsResult<()> {
+        let dtype = create_enum_data_type(Utf8ViewArray::from_slice_values(["a", "b"]));
+
+        let s = Series::new("foo", &["a", "c", "b"]);
+        assert!(s.strict_cast(&dtype).is_err());
+        let out = s.cast(&dtype)?;
+        assert_eq!(out.null_count(), 1);
+
+        let s = Series::new("foo", &[0u32, 2, 1]);
+        assert!(s.strict_cast(&dtype).is_err());
+        let out = s.cast(&dtype)?.cast(&DataType::String)?;
+        assert_eq!(Vec::from(out.str()?), &[Some("a"), None, Some("b")]);
+        Ok(())
+    }
 }
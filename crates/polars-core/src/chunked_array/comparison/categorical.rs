@@ -38,7 +38,7 @@ fn cat_compare_helper<'a, Compare, CompareString>(
     compare_str_function: CompareString,
 ) -> PolarsResult<BooleanChunked>
 where
-    Compare: Fn(&'a UInt32Chunked, &'a UInt32Chunked) -> BooleanChunked,
+    Compare: Fn(&UInt32Chunked, &UInt32Chunked) -> BooleanChunked,
     CompareString: Fn(&str, &str) -> bool,
 {
     let rev_map_l = lhs.get_rev_map();
@@ -47,6 +47,12 @@ where
 
     if lhs.is_enum() || !lhs.uses_lexical_ordering() {
         Ok(compare_function(lhs.physical(), rhs.physical()))
+    } else if rev_map_l.is_local() || Arc::ptr_eq(rev_map_l, rev_map_r) {
+        // Both sides have the same categories, so their lexical ranks can be compared.
+        Ok(compare_function(
+            &lhs.to_lexical_ranks(),
+            &rhs.to_lexical_ranks(),
+        ))
     } else {
         match (lhs.len(), rhs.len()) {
             (lhs_len, 1) => {
@@ -136,19 +142,19 @@ impl ChunkCompare<&CategoricalChunked> for CategoricalChunked {
     }
 
     fn gt(&self, rhs: &CategoricalChunked) -> Self::Item {
-        cat_compare_helper(self, rhs, UInt32Chunked::gt, |l, r| l > r)
+        cat_compare_helper(self, rhs, |l, r| UInt32Chunked::gt(l, r), |l, r| l > r)
     }
 
     fn gt_eq(&self, rhs: &CategoricalChunked) -> Self::Item {
-        cat_compare_helper(self, rhs, UInt32Chunked::gt_eq, |l, r| l >= r)
+        cat_compare_helper(self, rhs, |l, r| UInt32Chunked::gt_eq(l, r), |l, r| l >= r)
     }
 
     fn lt(&self, rhs: &CategoricalChunked) -> Self::Item {
-        cat_compare_helper(self, rhs, UInt32Chunked::lt, |l, r| l < r)
+        cat_compare_helper(self, rhs, |l, r| UInt32Chunked::lt(l, r), |l, r| l < r)
     }
 
     fn lt_eq(&self, rhs: &CategoricalChunked) -> Self::Item {
-        cat_compare_helper(self, rhs, UInt32Chunked::lt_eq, |l, r| l <= r)
+        cat_compare_helper(self, rhs, |l, r| UInt32Chunked::lt_eq(l, r), |l, r| l <= r)
     }
 }
 
@@ -364,15 +370,25 @@ where
         }
     } else {
         // Apply comparison on categories map and then do a lookup
-        let bitmap = str_single_compare_function(lhs.get_rev_map().get_categories(), rhs);
-
-        Ok(
-            BooleanChunked::from_iter_trusted_length(lhs.physical().into_iter().map(|opt_idx| {
-                // SAFETY: indexing into bitmap with same length as original array
-                opt_idx.map(|idx| unsafe { bitmap.get_bit_unchecked(idx as usize) })
-            }))
-            .with_name(lhs.name()),
-        )
+        let bitmap = str_single_compare_function(rev_map.get_categories(), rhs);
+
+        let out = match rev_map.as_ref() {
+            // The physicals index into the global cache, map them to the local categories.
+            RevMapping::Global(map, _, _) => {
+                BooleanChunked::from_iter_trusted_length(lhs.physical().into_iter().map(
+                    |opt_idx| opt_idx.map(|idx| bitmap.get_bit(*map.get(&idx).unwrap() as usize)),
+                ))
+            },
+            RevMapping::Local(_, _) => {
+                BooleanChunked::from_iter_trusted_length(lhs.physical().into_iter().map(
+                    |opt_idx| {
+                        // SAFETY: indexing into bitmap with same length as original array
+                        opt_idx.map(|idx| unsafe { bitmap.get_bit_unchecked(idx as usize) })
+                    },
+                ))
+            },
+        };
+        Ok(out.with_name(lhs.name()))
     }
 }
 
@@ -1,7 +1,9 @@
 use arrow::legacy::kernels::float::*;
 use arrow::legacy::kernels::set::set_at_nulls;
 use num_traits::Float;
-use polars_utils::total_ord::{canonical_f32, canonical_f64};
+use polars_utils::total_ord::{
+    canonical_f32, canonical_f64, total_order_key_f32, total_order_key_f64,
+};
 
 use crate::prelude::*;
 
@@ -60,3 +62,19 @@ where
         self.apply_values_generic(|v| v.canonical())
     }
 }
+
+impl Float32Chunked {
+    /// Maps the values to integer keys that order by IEEE 754 totalOrder, where -0 < 0 and all
+    /// `NaN`s are equal and greater than any other value.
+    pub fn total_order_keys(&self) -> Int32Chunked {
+        self.apply_values_generic(total_order_key_f32)
+    }
+}
+
+impl Float64Chunked {
+    /// Maps the values to integer keys that order by IEEE 754 totalOrder, where -0 < 0 and all
+    /// `NaN`s are equal and greater than any other value.
+    pub fn total_order_keys(&self) -> Int64Chunked {
+        self.apply_values_generic(total_order_key_f64)
+    }
+}
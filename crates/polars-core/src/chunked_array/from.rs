@@ -217,7 +217,14 @@ where
         #[cfg(debug_assertions)]
         {
             if !chunks.is_empty() && !chunks[0].is_empty() && dtype.is_primitive() {
-                assert_eq!(chunks[0].data_type(), &dtype.to_arrow(true))
+                match dtype {
+                    // The physical Int128 of a decimal keeps the scaled decimal dtype.
+                    DataType::Int128 => assert!(matches!(
+                        chunks[0].data_type(),
+                        ArrowDataType::Decimal(_, _)
+                    )),
+                    _ => assert_eq!(chunks[0].data_type(), &dtype.to_arrow(true)),
+                }
             }
         }
         let field = Arc::new(Field::new(name, dtype));
@@ -1,5 +1,4 @@
 use arrow::array::*;
-use arrow::legacy::trusted_len::TrustedLenPush;
 use hashbrown::hash_map::Entry;
 use polars_utils::iter::EnumerateIdxTrait;
 
@@ -137,14 +136,8 @@ impl CategoricalChunkedBuilder {
 
         // We will create a mapping from our local categoricals to global categoricals
         // and a mapping from global categoricals to our local categoricals.
-        let mut local_to_global: Vec<u32> = Vec::with_capacity(categories.len());
-        let (id, local_to_global) = crate::STRING_CACHE.apply(|cache| {
-            for (s, h) in categories.values_iter().zip(hashes) {
-                // SAFETY: we allocated enough.
-                unsafe { local_to_global.push_unchecked(cache.insert_from_hash(h, s)) }
-            }
-            local_to_global
-        });
+        let values = categories.values_iter().zip(hashes).collect::<Vec<_>>();
+        let (id, local_to_global) = crate::STRING_CACHE.insert_many(&values);
 
         // Change local indices inplace to their global counterparts.
         let update_cats = || {
@@ -260,7 +253,7 @@ impl CategoricalChunked {
             }
         }
 
-        let rev_map = RevMapping::Global(rev_map, str_values.into(), cache.uuid);
+        let rev_map = RevMapping::Global(rev_map, str_values.into(), cache.uuid());
 
         CategoricalChunked::from_cats_and_rev_map_unchecked(
             cats,
@@ -278,16 +271,14 @@ impl CategoricalChunked {
         ordering: CategoricalOrdering,
     ) -> Self {
         // Vec<u32> where the index is local and the value is the global index
-        let mut local_to_global: Vec<u32> = Vec::with_capacity(values.len());
-        let (id, local_to_global) = crate::STRING_CACHE.apply(|cache| {
-            // locally we don't need a hashmap because we all categories are 1 integer apart
-            // so the index is local, and the values is global
-            for s in values.values_iter() {
-                // SAFETY: we allocated enough
-                unsafe { local_to_global.push_unchecked(cache.insert(s)) }
-            }
-            local_to_global
-        });
+        // locally we don't need a hashmap because we all categories are 1 integer apart
+        // so the index is local, and the values is global
+        let hash_builder = StringCache::get_hash_builder();
+        let hashed_values = values
+            .values_iter()
+            .map(|s| (s, hash_builder.hash_one(s)))
+            .collect::<Vec<_>>();
+        let (id, local_to_global) = crate::STRING_CACHE.insert_many(&hashed_values);
 
         let compute_cats = || {
             let mut result = UInt32Vec::with_capacity(capacity);
@@ -462,4 +453,36 @@ mod test {
             assert_eq!(s.str_value(2).unwrap(), "world");
         }
     }
+
+    #[test]
+    fn test_categorical_builder_parallel() {
+        let _lock = SINGLE_LOCK.lock();
+        disable_string_cache();
+        enable_string_cache();
+
+        let values = (0..100).map(|i| format!("{}", i % 30)).collect::<Vec<_>>();
+        let out = std::thread::scope(|scope| {
+            let handles = (0..4)
+                .map(|i| {
+                    let values = &values;
+                    scope.spawn(move || {
+                        let iter = values.iter().skip(i * 10).map(|s| Some(s.as_str()));
+                        CategoricalChunkedBuilder::new("a", 100, Default::default())
+                            .drain_iter_and_finish(iter)
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        // Equal strings get the same global index in every thread.
+        for (i, ca) in out.iter().enumerate().skip(1) {
+            let expected = out[0].physical().slice(i as i64 * 10, ca.len());
+            assert_eq!(Vec::from(ca.physical()), Vec::from(&expected));
+        }
+        disable_string_cache();
+    }
 }
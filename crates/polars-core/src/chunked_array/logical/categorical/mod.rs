@@ -193,6 +193,41 @@ impl CategoricalChunked {
         self.get_ordering() == CategoricalOrdering::Lexical
     }
 
+    /// Map the physical representation to the position of each category in the
+    /// lexically sorted categories.
+    ///
+    /// Comparing the resulting integers gives the same result as comparing the
+    /// string values, so lexically ordered categoricals that share a [`RevMapping`]
+    /// can be compared and joined without materializing their strings.
+    pub fn to_lexical_ranks(&self) -> UInt32Chunked {
+        let rev_map = self.get_rev_map();
+        let categories = rev_map.get_categories();
+
+        let mut sorted_idx = (0..categories.len() as u32).collect::<Vec<_>>();
+        // SAFETY: indices are in bounds.
+        sorted_idx.sort_unstable_by(|a, b| unsafe {
+            categories
+                .value_unchecked(*a as usize)
+                .cmp(categories.value_unchecked(*b as usize))
+        });
+        let mut ranks = vec![0u32; sorted_idx.len()];
+        for (rank, idx) in sorted_idx.into_iter().enumerate() {
+            ranks[idx as usize] = rank as u32;
+        }
+
+        // Values under a null are not guaranteed to be valid categories.
+        let ranks = ranks.as_slice();
+        match rev_map.as_ref() {
+            RevMapping::Global(map, _, _) => self.physical().apply_values(|cat| {
+                map.get(&cat)
+                    .map_or(0, |idx| ranks.get(*idx as usize).copied().unwrap_or(0))
+            }),
+            RevMapping::Local(_, _) => self
+                .physical()
+                .apply_values(|cat| ranks.get(cat as usize).copied().unwrap_or(0)),
+        }
+    }
+
     pub(crate) fn get_ordering(&self) -> CategoricalOrdering {
         if let DataType::Categorical(_, ordering) | DataType::Enum(_, ordering) =
             &self.physical.2.as_ref().unwrap()
@@ -542,4 +577,35 @@ mod test {
         assert_eq!(vals, &["a", "b", "c"]);
         Ok(())
     }
+
+    #[test]
+    fn test_lexical_ranks_and_comparison() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        for use_string_cache in [true, false] {
+            disable_string_cache();
+            if use_string_cache {
+                enable_string_cache();
+            }
+
+            let dtype = DataType::Categorical(None, CategoricalOrdering::Lexical);
+            let s = Series::new("a", &[Some("c"), None, Some("a"), Some("b")]).cast(&dtype)?;
+            let ca = s.categorical()?;
+            assert_eq!(
+                Vec::from(&ca.to_lexical_ranks()),
+                &[Some(2), None, Some(0), Some(1)]
+            );
+
+            let other = s.reverse();
+            let other = other.categorical()?;
+            let out = ca.lt(other)?;
+            assert_eq!(Vec::from(&out), &[Some(false), None, None, Some(true)]);
+
+            let out = ca.gt("a")?;
+            assert_eq!(
+                Vec::from(&out),
+                &[Some(true), None, Some(false), Some(true)]
+            );
+        }
+        Ok(())
+    }
 }
@@ -47,8 +47,7 @@ impl Default for RevMapping {
         let slice: &[Option<&str>] = &[];
         let cats = Utf8ViewArray::from_slice(slice);
         if using_string_cache() {
-            let cache = &mut crate::STRING_CACHE.lock_map();
-            let id = cache.uuid;
+            let id = crate::STRING_CACHE.uuid();
             RevMapping::Global(Default::default(), cats, id)
         } else {
             RevMapping::build_local(cats)
@@ -152,7 +151,7 @@ impl RevMapping {
                 // fast path is check
                 if using_string_cache() {
                     let map = crate::STRING_CACHE.read_map();
-                    if map.uuid == *id {
+                    if map.uuid() == *id {
                         return map.get_cat(value);
                     }
                 }
@@ -1,10 +1,11 @@
 use std::hash::{Hash, Hasher};
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-use std::sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::sync::{Mutex, RwLock, RwLockReadGuard};
 
 use ahash::RandomState;
 use hashbrown::hash_map::RawEntryMut;
 use once_cell::sync::Lazy;
+use polars_utils::hashing::hash_to_partition;
 use smartstring::{LazyCompact, SmartString};
 
 use crate::datatypes::{InitHashMaps2, PlIdHashMap};
@@ -130,27 +131,23 @@ impl Hash for Key {
     }
 }
 
-pub(crate) struct SCacheInner {
+/// The number of shards of the [`StringCache`]. The values are distributed over the shards by
+/// their hash, so that threads that insert different values rarely wait on each other.
+const N_SHARDS: usize = 16;
+
+/// A part of the values of the [`StringCache`]. The keys of `map` index the local `payloads`.
+struct Shard {
     map: PlIdHashMap<Key, ()>,
-    pub(crate) uuid: u32,
     payloads: Vec<StrHashGlobal>,
+    // The global index of every local payload.
+    global_idx: Vec<u32>,
 }
 
-impl SCacheInner {
+impl Shard {
+    /// Get the index of a value in the shard, inserting it if it is not in the shard.
+    /// The global index of an inserted value has to be pushed afterwards.
     #[inline]
-    pub(crate) unsafe fn get_unchecked(&self, cat: u32) -> &str {
-        self.payloads.get_unchecked(cat as usize).as_str()
-    }
-
-    pub(crate) fn len(&self) -> usize {
-        self.map.len()
-    }
-
-    #[inline]
-    pub(crate) fn insert_from_hash(&mut self, h: u64, s: &str) -> u32 {
-        let mut global_idx = self.payloads.len() as u32;
-        // Note that we don't create the StrHashGlobal to search the key in the hashmap
-        // as StrHashGlobal may allocate a string
+    fn insert_local(&mut self, h: u64, s: &str) -> u32 {
         let entry = self.map.raw_entry_mut().from_hash(h, |key| {
             (key.hash == h) && {
                 let pos = key.idx as usize;
@@ -160,25 +157,19 @@ impl SCacheInner {
         });
 
         match entry {
-            RawEntryMut::Occupied(entry) => {
-                global_idx = entry.key().idx;
-            },
+            RawEntryMut::Occupied(entry) => entry.key().idx,
             RawEntryMut::Vacant(entry) => {
                 let idx = self.payloads.len() as u32;
-                let key = Key::new(h, idx);
-                entry.insert_hashed_nocheck(h, key, ());
-
+                entry.insert_hashed_nocheck(h, Key::new(h, idx), ());
                 // only just now we allocate the string
                 self.payloads.push(s.into());
+                idx
             },
         }
-        global_idx
     }
 
     #[inline]
-    pub(crate) fn get_cat(&self, s: &str) -> Option<u32> {
-        let h = StringCache::get_hash_builder().hash_one(s);
-        // as StrHashGlobal may allocate a string
+    fn get_cat_from_hash(&self, h: u64, s: &str) -> Option<u32> {
         self.map
             .raw_entry()
             .from_hash(h, |key| {
@@ -188,32 +179,105 @@ impl SCacheInner {
                     s == value.as_str()
                 }
             })
-            .map(|(k, _)| k.idx)
+            .map(|(k, _)| unsafe { *self.global_idx.get_unchecked(k.idx as usize) })
     }
+}
 
-    #[inline]
-    pub(crate) fn insert(&mut self, s: &str) -> u32 {
-        let h = StringCache::get_hash_builder().hash_one(s);
-        self.insert_from_hash(h, s)
+impl Default for Shard {
+    fn default() -> Self {
+        Self {
+            map: PlIdHashMap::with_capacity(_HASHMAP_INIT_SIZE / N_SHARDS),
+            payloads: Vec::with_capacity(_HASHMAP_INIT_SIZE / N_SHARDS),
+            global_idx: Vec::with_capacity(_HASHMAP_INIT_SIZE / N_SHARDS),
+        }
+    }
+}
+
+// The shard and the index in the shard of a global index.
+#[derive(Copy, Clone)]
+struct Location {
+    shard: u32,
+    idx: u32,
+}
+
+pub(crate) struct SCacheInner {
+    pub(crate) uuid: u32,
+    locations: Vec<Location>,
+}
+
+impl SCacheInner {
+    pub(crate) fn len(&self) -> usize {
+        self.locations.len()
     }
 }
 
 impl Default for SCacheInner {
     fn default() -> Self {
         Self {
-            map: PlIdHashMap::with_capacity(_HASHMAP_INIT_SIZE),
             uuid: STRING_CACHE_UUID_CTR.fetch_add(1, Ordering::AcqRel),
-            payloads: Vec::with_capacity(_HASHMAP_INIT_SIZE),
+            locations: Vec::with_capacity(_HASHMAP_INIT_SIZE),
         }
     }
 }
 
+/// A consistent view of the whole [`StringCache`].
+pub(crate) struct SCacheReadGuard<'a> {
+    shards: Vec<RwLockReadGuard<'a, Shard>>,
+    inner: RwLockReadGuard<'a, SCacheInner>,
+}
+
+impl SCacheReadGuard<'_> {
+    #[inline]
+    pub(crate) unsafe fn get_unchecked(&self, cat: u32) -> &str {
+        let location = self.inner.locations.get_unchecked(cat as usize);
+        self.shards
+            .get_unchecked(location.shard as usize)
+            .payloads
+            .get_unchecked(location.idx as usize)
+            .as_str()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub(crate) fn uuid(&self) -> u32 {
+        self.inner.uuid
+    }
+
+    #[inline]
+    pub(crate) fn get_cat(&self, s: &str) -> Option<u32> {
+        let h = StringCache::get_hash_builder().hash_one(s);
+        self.shards[shard_of(h)].get_cat_from_hash(h, s)
+    }
+}
+
+#[inline]
+fn shard_of(h: u64) -> usize {
+    hash_to_partition(h, N_SHARDS)
+}
+
 /// Used by categorical data that need to share global categories.
 /// In *eager* you need to specifically toggle global string cache to have a global effect.
 /// In *lazy* it is toggled on at the start of a computation run and turned of (deleted) when a
 /// result is produced.
-#[derive(Default)]
-pub(crate) struct StringCache(pub(crate) RwLock<SCacheInner>);
+///
+/// The values are sharded by their hash. A shard is locked for the lookup and insertion of its
+/// values and the global index of a new value is handed out under the lock of the `inner`
+/// locations. The locks are always taken in the order of the shards, and `inner` last.
+pub(crate) struct StringCache {
+    shards: Vec<RwLock<Shard>>,
+    inner: RwLock<SCacheInner>,
+}
+
+impl Default for StringCache {
+    fn default() -> Self {
+        Self {
+            shards: (0..N_SHARDS).map(|_| Default::default()).collect(),
+            inner: Default::default(),
+        }
+    }
+}
 
 impl StringCache {
     /// The global `StringCache` will always use a predictable seed. This allows local builders to mimic
@@ -223,33 +287,110 @@ impl StringCache {
         RandomState::with_seed(0)
     }
 
-    /// Lock the string cache
-    pub(crate) fn lock_map(&self) -> RwLockWriteGuard<SCacheInner> {
-        self.0.write().unwrap()
+    /// Lock the whole string cache for reading.
+    pub(crate) fn read_map(&self) -> SCacheReadGuard {
+        let shards = self.shards.iter().map(|s| s.read().unwrap()).collect();
+        SCacheReadGuard {
+            shards,
+            inner: self.inner.read().unwrap(),
+        }
     }
 
-    pub(crate) fn read_map(&self) -> RwLockReadGuard<SCacheInner> {
-        self.0.read().unwrap()
+    pub(crate) fn uuid(&self) -> u32 {
+        self.inner.read().unwrap().uuid
     }
 
     pub(crate) fn clear(&self) {
-        let mut lock = self.lock_map();
-        *lock = Default::default();
+        let mut shards = self
+            .shards
+            .iter()
+            .map(|s| s.write().unwrap())
+            .collect::<Vec<_>>();
+        let mut inner = self.inner.write().unwrap();
+        for shard in shards.iter_mut() {
+            **shard = Default::default();
+        }
+        *inner = Default::default();
     }
 
-    pub(crate) fn apply<F, T>(&self, fun: F) -> (u32, T)
-    where
-        F: FnOnce(&mut RwLockWriteGuard<SCacheInner>) -> T,
-    {
-        let cache = &mut crate::STRING_CACHE.lock_map();
-
-        let result = fun(cache);
+    /// Get the global index of every value, inserting the values that are not in the cache.
+    /// The values are given with their hash of the [`StringCache::get_hash_builder`].
+    ///
+    /// The values that are already in a shard are looked up under its shared lock. Only the
+    /// shards of the missing values are locked exclusively to insert them, so threads only wait
+    /// on each other for the shards they both insert in. New values get their global index in
+    /// the order in which they first appear.
+    pub(crate) fn insert_many(&self, values: &[(&str, u64)]) -> (u32, Vec<u32>) {
+        let mut per_shard = vec![vec![]; N_SHARDS];
+        for (i, (_, h)) in values.iter().enumerate() {
+            per_shard[shard_of(*h)].push(i);
+        }
+        let mut global_idx = vec![0; values.len()];
+        let mut missing = vec![];
+
+        loop {
+            let uuid = self.uuid();
+            missing.clear();
+            for (shard, idxs) in self.shards.iter().zip(&per_shard) {
+                if idxs.is_empty() {
+                    continue;
+                }
+                let shard = shard.read().unwrap();
+                for &i in idxs {
+                    let (s, h) = values[i];
+                    match shard.get_cat_from_hash(h, s) {
+                        Some(idx) => global_idx[i] = idx,
+                        None => missing.push(i),
+                    }
+                }
+            }
+            if missing.is_empty() {
+                // The cache can be cleared in between the shards, then all values must be
+                // looked up again.
+                if self.uuid() == uuid {
+                    return (uuid, global_idx);
+                }
+                continue;
+            }
 
-        if cache.len() > u32::MAX as usize {
-            panic!("not more than {} categories supported", u32::MAX)
-        };
+            let mut to_lock = [false; N_SHARDS];
+            for &i in &missing {
+                to_lock[shard_of(values[i].1)] = true;
+            }
+            let mut shards = self
+                .shards
+                .iter()
+                .zip(to_lock)
+                .map(|(shard, lock)| lock.then(|| shard.write().unwrap()))
+                .collect::<Vec<_>>();
+            let mut inner = self.inner.write().unwrap();
+            if inner.uuid != uuid {
+                continue;
+            }
 
-        (cache.uuid, result)
+            // Another thread may have inserted some of the values in the meantime, so the local
+            // index is looked up again before a new value gets its global index.
+            missing.sort_unstable();
+            for &i in &missing {
+                let (s, h) = values[i];
+                let shard_idx = shard_of(h);
+                let shard = shards[shard_idx].as_mut().unwrap();
+                let local_idx = shard.insert_local(h, s);
+                if local_idx as usize == shard.global_idx.len() {
+                    let idx = inner.locations.len();
+                    inner.locations.push(Location {
+                        shard: shard_idx as u32,
+                        idx: local_idx,
+                    });
+                    if inner.len() > u32::MAX as usize {
+                        panic!("not more than {} categories supported", u32::MAX)
+                    };
+                    shard.global_idx.push(idx as u32);
+                }
+                global_idx[i] = shard.global_idx[local_idx as usize];
+            }
+            return (uuid, global_idx);
+        }
     }
 }
 
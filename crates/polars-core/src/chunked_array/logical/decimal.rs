@@ -98,7 +98,14 @@ impl LogicalType for DecimalChunked {
             };
             if scale_src == scale_dst && is_widen {
                 let dtype = &DataType::Decimal(precision_dst, Some(scale_dst));
-                return self.0.cast_with_options(dtype, cast_options); // no conversion or checks needed
+                // no conversion or checks needed
+                return unsafe {
+                    Ok(Series::from_chunks_and_dtype_unchecked(
+                        self.name(),
+                        self.chunks.clone(),
+                        dtype,
+                    ))
+                };
             }
         }
         let chunks = cast_chunks(&self.chunks, dtype, cast_options)?;
@@ -127,6 +134,16 @@ impl DecimalChunked {
         }
     }
 
+    /// Compute the mean of the non-null values at the scale of this array.
+    /// The result is rounded half away from zero.
+    pub fn mean_decimal(&self) -> Option<i128> {
+        let count = (self.len() - self.null_count()) as i128;
+        if count == 0 {
+            return None;
+        }
+        self.0.sum().map(|sum| div_round_half_away(sum, count))
+    }
+
     pub(crate) fn to_scale(&self, scale: usize) -> PolarsResult<Cow<'_, Self>> {
         if self.scale() == scale {
             return Ok(Cow::Borrowed(self));
@@ -139,3 +156,14 @@ impl DecimalChunked {
         Ok(Cow::Owned(dt))
     }
 }
+
+/// Divide `lhs` by a positive `rhs`, rounding half away from zero.
+pub(crate) fn div_round_half_away(lhs: i128, rhs: i128) -> i128 {
+    let quotient = lhs / rhs;
+    let remainder = lhs % rhs;
+    if 2 * remainder.abs() >= rhs {
+        quotient + lhs.signum()
+    } else {
+        quotient
+    }
+}
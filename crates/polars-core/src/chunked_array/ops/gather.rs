@@ -3,11 +3,57 @@ use arrow::bitmap::Bitmap;
 use arrow::compute::take::take_unchecked;
 use polars_error::polars_ensure;
 use polars_utils::index::check_bounds;
+use rayon::prelude::*;
 
 use crate::prelude::*;
 use crate::series::IsSorted;
+use crate::utils::_split_offsets;
+use crate::POOL;
 
 const BINARY_SEARCH_LIMIT: usize = 8;
+/// Gathers of at least this many indices are split over the thread pool.
+const PARALLEL_GATHER_LIMIT: usize = 1 << 20;
+
+fn use_parallel_gather(n_indices: usize) -> bool {
+    // Don't split if we're already running on the thread pool, e.g. in a group-by.
+    n_indices >= PARALLEL_GATHER_LIMIT
+        && POOL.current_num_threads() > 1
+        && POOL.current_thread_index().is_none()
+}
+
+/// Gathers contiguous ranges of the output on the thread pool, so every thread writes its own
+/// output chunk. The result has a chunk per range.
+///
+/// # Safety
+/// The indices must be in bounds.
+unsafe fn parallel_gather_unchecked<T: PolarsDataType>(
+    ca: &ChunkedArray<T>,
+    indices: &IdxCa,
+) -> ChunkedArray<T>
+where
+    ChunkedArray<T>: ChunkTakeUnchecked<IdxCa>,
+{
+    let offsets = _split_offsets(indices.len(), POOL.current_num_threads());
+    let parts = POOL.install(|| {
+        offsets
+            .into_par_iter()
+            .map(|(offset, len)| {
+                let indices = indices.slice(offset as i64, len);
+                // SAFETY: the caller guarantees the indices are in bounds.
+                unsafe { ca.take_unchecked(&indices) }
+            })
+            .collect::<Vec<_>>()
+    });
+    let chunks = parts
+        .iter()
+        .flat_map(|part| part.chunks().iter().cloned())
+        .collect();
+
+    let mut out = ca.copy_with_chunks(chunks);
+    let sorted_flag = _update_gather_sorted_flag(ca.is_sorted_flag(), indices.is_sorted_flag());
+    out.set_sorted_flag(sorted_flag);
+    out
+}
 
 pub fn check_bounds_nulls(idx: &PrimitiveArray<IdxSize>, len: IdxSize) -> PolarsResult<()> {
     let mask = BitMask::from_bitmap(idx.validity().unwrap());
@@ -87,13 +133,32 @@ fn resolve_chunked_idx(idx: IdxSize, cumlens: &[IdxSize; BINARY_SEARCH_LIMIT]) -
     (chunk_idx, (idx - cumlens[chunk_idx]) as usize)
 }
 
+/// Computes the start offset of every chunk, for arrays with more chunks than the branchless
+/// search handles. Looking up a chunk is then a regular binary search, which is still much
+/// cheaper than rechunking the whole array for a gather.
+fn chunk_offsets<A: StaticArray>(arrs: &[&A]) -> Vec<IdxSize> {
+    let mut offset = 0;
+    arrs.iter()
+        .map(|arr| {
+            let start = offset;
+            offset += arr.len() as IdxSize;
+            start
+        })
+        .collect()
+}
+
+#[inline]
+fn resolve_chunk_offset(idx: IdxSize, offsets: &[IdxSize]) -> (usize, usize) {
+    // The last chunk starting at or before idx, which skips empty chunks.
+    let chunk_idx = offsets.partition_point(|&start| start <= idx) - 1;
+    (chunk_idx, (idx - offsets[chunk_idx]) as usize)
+}
+
 #[inline]
 unsafe fn target_value_unchecked<'a, A: StaticArray>(
     targets: &[&'a A],
-    cumlens: &[IdxSize; BINARY_SEARCH_LIMIT],
-    idx: IdxSize,
+    (chunk_idx, arr_idx): (usize, usize),
 ) -> A::ValueT<'a> {
-    let (chunk_idx, arr_idx) = resolve_chunked_idx(idx, cumlens);
     let arr = targets.get_unchecked(chunk_idx);
     arr.value_unchecked(arr_idx)
 }
@@ -101,14 +166,52 @@ unsafe fn target_value_unchecked<'a, A: StaticArray>(
 #[inline]
 unsafe fn target_get_unchecked<'a, A: StaticArray>(
     targets: &[&'a A],
-    cumlens: &[IdxSize; BINARY_SEARCH_LIMIT],
-    idx: IdxSize,
+    (chunk_idx, arr_idx): (usize, usize),
 ) -> Option<A::ValueT<'a>> {
-    let (chunk_idx, arr_idx) = resolve_chunked_idx(idx, cumlens);
     let arr = targets.get_unchecked(chunk_idx);
     arr.get_unchecked(arr_idx)
 }
 
+/// Gathers from multiple chunks, with `resolve` mapping an index to its chunk and the index
+/// within that chunk.
+unsafe fn gather_chunked_unchecked<A: StaticArray>(
+    dtype: ArrowDataType,
+    targets: &[&A],
+    has_nulls: bool,
+    indices: &[IdxSize],
+    resolve: impl Fn(IdxSize) -> (usize, usize),
+) -> A {
+    let it = indices.iter().copied();
+    if has_nulls {
+        it.map(|i| target_get_unchecked(targets, resolve(i)))
+            .collect_arr_trusted_with_dtype(dtype)
+    } else {
+        it.map(|i| target_value_unchecked(targets, resolve(i)))
+            .collect_arr_trusted_with_dtype(dtype)
+    }
+}
+
+/// Like [`gather_chunked_unchecked`], for indices with nulls.
+unsafe fn gather_chunked_nullable_idx_unchecked<A: StaticArray>(
+    dtype: ArrowDataType,
+    targets: &[&A],
+    has_nulls: bool,
+    idx_arr: &IdxArr,
+    resolve: impl Fn(IdxSize) -> (usize, usize),
+) -> A {
+    if has_nulls {
+        idx_arr
+            .iter()
+            .map(|i| target_get_unchecked(targets, resolve(*i?)))
+            .collect_arr_trusted_with_dtype(dtype)
+    } else {
+        idx_arr
+            .iter()
+            .map(|i| Some(target_value_unchecked(targets, resolve(*i?))))
+            .collect_arr_trusted_with_dtype(dtype)
+    }
+}
+
 unsafe fn gather_idx_array_unchecked<A: StaticArray>(
     dtype: ArrowDataType,
     targets: &[&A],
@@ -129,15 +232,16 @@ unsafe fn gather_idx_array_unchecked<A: StaticArray>(
             it.map(|i| target.value_unchecked(i as usize))
                 .collect_arr_trusted_with_dtype(dtype)
         }
-    } else {
+    } else if targets.len() <= BINARY_SEARCH_LIMIT {
         let cumlens = cumulative_lengths(targets);
-        if has_nulls {
-            it.map(|i| target_get_unchecked(targets, &cumlens, i))
-                .collect_arr_trusted_with_dtype(dtype)
-        } else {
-            it.map(|i| target_value_unchecked(targets, &cumlens, i))
-                .collect_arr_trusted_with_dtype(dtype)
-        }
+        gather_chunked_unchecked(dtype, targets, has_nulls, indices, |i| {
+            resolve_chunked_idx(i, &cumlens)
+        })
+    } else {
+        let offsets = chunk_offsets(targets);
+        gather_chunked_unchecked(dtype, targets, has_nulls, indices, |i| {
+            resolve_chunk_offset(i, &offsets)
+        })
     }
 }
 
@@ -147,20 +251,18 @@ where
 {
     /// Gather values from ChunkedArray by index.
     unsafe fn take_unchecked(&self, indices: &I) -> Self {
-        let rechunked;
-        let mut ca = self;
-        if self.chunks().len() > BINARY_SEARCH_LIMIT {
-            rechunked = self.rechunk();
-            ca = &rechunked;
+        if use_parallel_gather(indices.as_ref().len()) {
+            let indices = IdxCa::mmap_slice("", indices.as_ref());
+            return self.take_unchecked(&indices);
         }
-        let targets: Vec<_> = ca.downcast_iter().collect();
+        let targets: Vec<_> = self.downcast_iter().collect();
         let arr = gather_idx_array_unchecked(
-            ca.dtype().to_arrow(true),
+            self.dtype().to_arrow(true),
             &targets,
-            ca.null_count() > 0,
+            self.null_count() > 0,
             indices.as_ref(),
         );
-        ChunkedArray::from_chunk_iter_like(ca, [arr])
+        ChunkedArray::from_chunk_iter_like(self, [arr])
     }
 }
 
@@ -182,11 +284,9 @@ where
 {
     /// Gather values from ChunkedArray by index.
     unsafe fn take_unchecked(&self, indices: &IdxCa) -> Self {
-        let rechunked;
-        let mut ca = self;
-        if self.chunks().len() > BINARY_SEARCH_LIMIT {
-            rechunked = self.rechunk();
-            ca = &rechunked;
+        let ca = self;
+        if use_parallel_gather(indices.len()) {
+            return parallel_gather_unchecked(ca, indices);
         }
         let targets_have_nulls = ca.null_count() > 0;
         let targets: Vec<_> = ca.downcast_iter().collect();
@@ -208,19 +308,24 @@ where
                         .map(|i| Some(target.value_unchecked(*i? as usize)))
                         .collect_arr_trusted_with_dtype(dtype)
                 }
-            } else {
+            } else if targets.len() <= BINARY_SEARCH_LIMIT {
                 let cumlens = cumulative_lengths(&targets);
-                if targets_have_nulls {
-                    idx_arr
-                        .iter()
-                        .map(|i| target_get_unchecked(&targets, &cumlens, *i?))
-                        .collect_arr_trusted_with_dtype(dtype)
-                } else {
-                    idx_arr
-                        .iter()
-                        .map(|i| Some(target_value_unchecked(&targets, &cumlens, *i?)))
-                        .collect_arr_trusted_with_dtype(dtype)
-                }
+                gather_chunked_nullable_idx_unchecked(
+                    dtype,
+                    &targets,
+                    targets_have_nulls,
+                    idx_arr,
+                    |i| resolve_chunked_idx(i, &cumlens),
+                )
+            } else {
+                let offsets = chunk_offsets(&targets);
+                gather_chunked_nullable_idx_unchecked(
+                    dtype,
+                    &targets,
+                    targets_have_nulls,
+                    idx_arr,
+                    |i| resolve_chunk_offset(i, &offsets),
+                )
             }
         });
 
@@ -236,6 +341,9 @@ impl ChunkTakeUnchecked<IdxCa> for BinaryChunked {
     /// Gather values from ChunkedArray by index.
     unsafe fn take_unchecked(&self, indices: &IdxCa) -> Self {
         let rechunked = self.rechunk();
+        if use_parallel_gather(indices.len()) {
+            return parallel_gather_unchecked(&rechunked, indices);
+        }
         let indices = indices.rechunk();
         let indices_arr = indices.downcast_iter().next().unwrap();
         let chunks = rechunked
@@ -289,3 +397,35 @@ impl IdxCa {
         f(&ca)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gather_many_chunks() -> PolarsResult<()> {
+        // More chunks than the branchless search handles, including empty ones.
+        let arrs = (0..3 * BINARY_SEARCH_LIMIT as i32).flat_map(|i| {
+            [
+                PrimitiveArray::from([Some(2 * i), None]),
+                PrimitiveArray::from_slice([]),
+            ]
+        });
+        let ca = Int32Chunked::from_chunk_iter("a", arrs);
+        assert!(ca.chunks().len() > BINARY_SEARCH_LIMIT);
+        let rechunked = ca.rechunk();
+
+        let idx = IdxCa::new("", &[Some(47), None, Some(0), Some(1), Some(22), Some(47)]);
+        let out = ca.take(&idx)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[None, None, Some(0), None, Some(22), None]
+        );
+        assert_eq!(Vec::from(&out), Vec::from(&rechunked.take(&idx)?));
+
+        let idx: &[IdxSize] = &[46, 3, 22, 46];
+        let out = ca.take(idx)?;
+        assert_eq!(Vec::from(&out), &[Some(46), None, Some(22), Some(46)]);
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::BinaryHeap;
+
 use polars_utils::iter::EnumerateIdxTrait;
 
 use super::*;
@@ -10,13 +12,17 @@ struct CompareRow<'a> {
 
 impl PartialEq for CompareRow<'_> {
     fn eq(&self, other: &Self) -> bool {
-        self.bytes == other.bytes
+        self.bytes == other.bytes && self.idx == other.idx
     }
 }
 
+/// Equal rows are ordered by their index, so that the order of equal rows is maintained
+/// even if they are sorted with an unstable sort.
 impl Ord for CompareRow<'_> {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.bytes.cmp(other.bytes)
+        self.bytes
+            .cmp(other.bytes)
+            .then_with(|| self.idx.cmp(&other.idx))
     }
 }
 
@@ -44,34 +50,43 @@ pub fn _arg_bottom_k(
         &sort_options.nulls_last,
     )?;
     let arr = encoded.into_array();
-    let mut rows = arr
+    let rows_iter = arr
         .values_iter()
         .enumerate_idx()
-        .map(|(idx, bytes)| CompareRow { idx, bytes })
-        .collect::<Vec<_>>();
+        .map(|(idx, bytes)| CompareRow { idx, bytes });
 
-    let sorted = if k >= from_n_rows {
-        match (sort_options.multithreaded, sort_options.maintain_order) {
-            (true, true) => POOL.install(|| {
-                rows.par_sort();
-            }),
-            (true, false) => POOL.install(|| {
-                rows.par_sort_unstable();
-            }),
-            (false, true) => rows.sort(),
-            (false, false) => rows.sort_unstable(),
+    // For a small `k` we keep the bottom k rows in a bounded heap, so that we don't have to
+    // materialize and partition all rows.
+    if k < from_n_rows / 16 {
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        for row in rows_iter {
+            if heap.len() < k {
+                heap.push(row);
+            } else if let Some(mut top) = heap.peek_mut() {
+                if row < *top {
+                    *top = row;
+                }
+            }
         }
-        &rows
-    } else if sort_options.maintain_order {
-        // todo: maybe there is some more efficient method, comparable to select_nth_unstable
+        let idx: NoNull<IdxCa> = heap
+            .into_sorted_vec()
+            .iter()
+            .map(|cmp_row| cmp_row.idx)
+            .collect();
+        return Ok(idx);
+    }
+
+    let mut rows = rows_iter.collect::<Vec<_>>();
+    // Equal rows are ordered by their index, so an unstable sort maintains the order.
+    let sorted = if k >= from_n_rows {
         if sort_options.multithreaded {
             POOL.install(|| {
-                rows.par_sort();
+                rows.par_sort_unstable();
             })
         } else {
-            rows.sort();
+            rows.sort_unstable();
         }
-        &rows[..k]
+        &rows
     } else {
         // todo: possible multi threaded `select_nth_unstable`?
         let (lower, _el, _upper) = rows.select_nth_unstable(k);
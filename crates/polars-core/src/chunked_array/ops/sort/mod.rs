@@ -897,6 +897,23 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_sort_float_total_order() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [0.0, -0.0, f64::NAN, -f64::NAN, 1.0, -1.0],
+            "idx" => [0, 1, 2, 3, 4, 5]
+        )?;
+
+        let options = SortMultipleOptions::default().with_maintain_order(true);
+        let out = df.sort(["a"], options.clone())?;
+        assert_eq!(out.column("idx")?.i32()?.cont_slice()?, &[5, 0, 1, 4, 2, 3]);
+
+        let out = df.sort(["a"], options.with_float_total_order(true))?;
+        assert_eq!(out.column("idx")?.i32()?.cont_slice()?, &[5, 1, 0, 4, 2, 3]);
+        assert_eq!(out.column("a")?.dtype(), &DataType::Float64);
+        Ok(())
+    }
+
     #[test]
     fn test_sort_string() {
         let ca = StringChunked::new("a", &[Some("a"), None, Some("c"), None, Some("b")]);
@@ -96,6 +96,11 @@ pub struct SortMultipleOptions {
     pub multithreaded: bool,
     /// Whether maintain the order of equal elements. Default `false`.
     pub maintain_order: bool,
+    /// Order float columns by IEEE 754 `totalOrder`. Default `false`.
+    ///
+    /// When set, `-0.0` sorts before `0.0`; NaNs still sort after all other values.
+    #[cfg_attr(feature = "serde-lazy", serde(default))]
+    pub float_total_order: bool,
 }
 
 impl Default for SortOptions {
@@ -116,6 +121,7 @@ impl Default for SortMultipleOptions {
             nulls_last: vec![false],
             multithreaded: true,
             maintain_order: false,
+            float_total_order: false,
         }
     }
 }
@@ -173,6 +179,12 @@ impl SortMultipleOptions {
         self
     }
 
+    /// Whether to order floats by IEEE 754 `totalOrder`. Default `false`.
+    pub fn with_float_total_order(mut self, enabled: bool) -> Self {
+        self.float_total_order = enabled;
+        self
+    }
+
     /// Reverse the order of sorting for each column.
     pub fn with_order_reversed(mut self) -> Self {
         self.descending.iter_mut().for_each(|x| *x = !*x);
@@ -224,6 +236,7 @@ impl From<&SortOptions> for SortMultipleOptions {
             nulls_last: vec![value.nulls_last],
             multithreaded: value.multithreaded,
             maintain_order: value.maintain_order,
+            float_total_order: false,
         }
     }
 }
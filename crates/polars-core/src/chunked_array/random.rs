@@ -48,6 +48,121 @@ fn create_rand_index_no_replacement(
     IdxCa::new_vec("", buf)
 }
 
+/// Cast the sampling weights to `f64` and check that they are valid. Null weights are
+/// treated as zero.
+fn prepare_weights(weights: &Series, len: usize) -> PolarsResult<Vec<f64>> {
+    polars_ensure!(
+        weights.len() == len,
+        ShapeMismatch: "sampling weights have length {}, but the population has length {}",
+        weights.len(), len
+    );
+    polars_ensure!(
+        weights.dtype().is_numeric(),
+        InvalidOperation: "sampling weights must be numeric, got `{}`", weights.dtype()
+    );
+    let weights = weights.cast(&Float64)?;
+    let weights = weights
+        .f64()?
+        .iter()
+        .map(|w| w.unwrap_or(0.0))
+        .collect::<Vec<_>>();
+    polars_ensure!(
+        weights.iter().all(|w| w.is_finite() && *w >= 0.0),
+        ComputeError: "sampling weights must be finite and non-negative"
+    );
+    Ok(weights)
+}
+
+/// Draw `n` indices with replacement, where every index is drawn with a probability
+/// proportional to its weight. This builds an alias table (Vose's method), so that
+/// every draw takes constant time.
+fn create_weighted_index_with_replacement(
+    n: usize,
+    weights: &[f64],
+    seed: Option<u64>,
+) -> PolarsResult<IdxCa> {
+    let len = weights.len();
+    if n == 0 || len == 0 {
+        return Ok(IdxCa::new_vec("", vec![]));
+    }
+    let total: f64 = weights.iter().sum();
+    polars_ensure!(
+        total > 0.0,
+        ComputeError: "cannot sample with replacement if all weights are zero"
+    );
+
+    // Scale the weights such that their mean is 1, and pair every bucket that has a
+    // probability below 1 with a bucket that has a probability above 1.
+    let mut prob = weights
+        .iter()
+        .map(|w| w * len as f64 / total)
+        .collect::<Vec<_>>();
+    let mut alias = vec![0 as IdxSize; len];
+    let (mut small, mut large): (Vec<_>, Vec<_>) = (0..len).partition(|&i| prob[i] < 1.0);
+    while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+        small.pop();
+        alias[s] = l as IdxSize;
+        prob[l] = (prob[l] + prob[s]) - 1.0;
+        if prob[l] < 1.0 {
+            large.pop();
+            small.push(l);
+        }
+    }
+    // The remaining buckets are (up to rounding errors) full.
+    for i in small.into_iter().chain(large) {
+        prob[i] = 1.0;
+    }
+
+    let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+    let dist = Uniform::new(0, len);
+    Ok((0..n)
+        .map(|_| {
+            let i = dist.sample(&mut rng);
+            if rng.gen::<f64>() < prob[i] {
+                i as IdxSize
+            } else {
+                alias[i]
+            }
+        })
+        .collect_trusted::<NoNull<IdxCa>>()
+        .into_inner())
+}
+
+/// Draw `n` distinct indices, where at every step the next index is drawn with a
+/// probability proportional to its weight among the indices that were not drawn yet.
+/// This uses the keys of Efraimidis and Spirakis, `u^(1/w)`, and keeps the `n` indices
+/// with the largest keys, ordered by their keys.
+fn create_weighted_index_no_replacement(
+    n: usize,
+    weights: &[f64],
+    seed: Option<u64>,
+) -> PolarsResult<IdxCa> {
+    let n_positive = weights.iter().filter(|w| **w > 0.0).count();
+    polars_ensure!(
+        n <= n_positive,
+        ShapeMismatch: "cannot take a larger sample than the number of non-zero weights when `with_replacement=false`"
+    );
+
+    let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+    // Compare the logarithm of the keys, `ln(u) / w`, for numerical stability.
+    let mut keys = weights
+        .iter()
+        .enumerate()
+        .filter(|(_, w)| **w > 0.0)
+        .map(|(i, w)| (rng.gen::<f64>().ln() / w, i as IdxSize))
+        .collect::<Vec<_>>();
+    let by_key_desc = |a: &(f64, IdxSize), b: &(f64, IdxSize)| b.0.total_cmp(&a.0);
+    if n < keys.len() {
+        keys.select_nth_unstable_by(n, by_key_desc);
+        keys.truncate(n);
+    }
+    keys.sort_unstable_by(by_key_desc);
+    Ok(IdxCa::new_vec(
+        "",
+        keys.into_iter().map(|(_, i)| i).collect(),
+    ))
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -118,6 +233,37 @@ impl Series {
         self.sample_n(n, with_replacement, shuffle, seed)
     }
 
+    /// Sample `n` values of this [`Series`], where every value is sampled with a
+    /// probability proportional to its weight in `weights`.
+    pub fn sample_n_weighted(
+        &self,
+        n: usize,
+        weights: &Series,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        let weights = prepare_weights(weights, self.len())?;
+        let idx = match with_replacement {
+            true => create_weighted_index_with_replacement(n, &weights, seed)?,
+            false => create_weighted_index_no_replacement(n, &weights, seed)?,
+        };
+        // SAFETY: the indices are within bounds.
+        unsafe { Ok(self.take_unchecked(&idx)) }
+    }
+
+    /// Sample a fraction of this [`Series`], where every value is sampled with a
+    /// probability proportional to its weight in `weights`.
+    pub fn sample_frac_weighted(
+        &self,
+        frac: f64,
+        weights: &Series,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        let n = (self.len() as f64 * frac) as usize;
+        self.sample_n_weighted(n, weights, with_replacement, seed)
+    }
+
     pub fn shuffle(&self, seed: Option<u64>) -> Self {
         let len = self.len();
         let n = len;
@@ -182,20 +328,9 @@ impl DataFrame {
         shuffle: bool,
         seed: Option<u64>,
     ) -> PolarsResult<Self> {
-        polars_ensure!(
-        n.len() == 1,
-        ComputeError: "Sample size must be a single value."
-        );
-
-        let n = n.cast(&IDX_DTYPE)?;
-        let n = n.idx()?;
-
-        match n.get(0) {
-            Some(n) => self.sample_n_literal(n as usize, with_replacement, shuffle, seed),
-            None => {
-                let new_cols = self.columns.iter().map(Series::clear).collect_trusted();
-                Ok(unsafe { DataFrame::new_no_checks(new_cols) })
-            },
+        match sample_size(n)? {
+            Some(n) => self.sample_n_literal(n, with_replacement, shuffle, seed),
+            None => Ok(self.clear_sample()),
         }
     }
 
@@ -224,6 +359,61 @@ impl DataFrame {
         shuffle: bool,
         seed: Option<u64>,
     ) -> PolarsResult<Self> {
+        match self.sample_frac_size(frac)? {
+            Some(n) => self.sample_n_literal(n, with_replacement, shuffle, seed),
+            None => Ok(self.clear_sample()),
+        }
+    }
+
+    /// Sample n datapoints from this [`DataFrame`], every row with a probability
+    /// proportional to its weight in `weights`.
+    pub fn sample_n_weighted(
+        &self,
+        n: &Series,
+        weights: &Series,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        match sample_size(n)? {
+            Some(n) => self.sample_n_literal_weighted(n, weights, with_replacement, seed),
+            None => Ok(self.clear_sample()),
+        }
+    }
+
+    pub fn sample_n_literal_weighted(
+        &self,
+        n: usize,
+        weights: &Series,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        ensure_shape(n, self.height(), with_replacement)?;
+        let weights = prepare_weights(weights, self.height())?;
+        // All columns should used the same indices. So we first create the indices.
+        let idx = match with_replacement {
+            true => create_weighted_index_with_replacement(n, &weights, seed)?,
+            false => create_weighted_index_no_replacement(n, &weights, seed)?,
+        };
+        // SAFETY: the indices are within bounds.
+        Ok(unsafe { self.take_unchecked(&idx) })
+    }
+
+    /// Sample a fraction between 0.0-1.0 of this [`DataFrame`], every row with a
+    /// probability proportional to its weight in `weights`.
+    pub fn sample_frac_weighted(
+        &self,
+        frac: &Series,
+        weights: &Series,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        match self.sample_frac_size(frac)? {
+            Some(n) => self.sample_n_literal_weighted(n, weights, with_replacement, seed),
+            None => Ok(self.clear_sample()),
+        }
+    }
+
+    fn sample_frac_size(&self, frac: &Series) -> PolarsResult<Option<usize>> {
         polars_ensure!(
         frac.len() == 1,
         ComputeError: "Sample fraction must be a single value."
@@ -231,20 +421,28 @@ impl DataFrame {
 
         let frac = frac.cast(&Float64)?;
         let frac = frac.f64()?;
+        Ok(frac
+            .get(0)
+            .map(|frac| (self.height() as f64 * frac) as usize))
+    }
 
-        match frac.get(0) {
-            Some(frac) => {
-                let n = (self.height() as f64 * frac) as usize;
-                self.sample_n_literal(n, with_replacement, shuffle, seed)
-            },
-            None => {
-                let new_cols = self.columns.iter().map(Series::clear).collect_trusted();
-                Ok(unsafe { DataFrame::new_no_checks(new_cols) })
-            },
-        }
+    fn clear_sample(&self) -> Self {
+        let new_cols = self.columns.iter().map(Series::clear).collect_trusted();
+        unsafe { DataFrame::new_no_checks(new_cols) }
     }
 }
 
+fn sample_size(n: &Series) -> PolarsResult<Option<usize>> {
+    polars_ensure!(
+    n.len() == 1,
+    ComputeError: "Sample size must be a single value."
+    );
+
+    let n = n.cast(&IDX_DTYPE)?;
+    let n = n.idx()?;
+    Ok(n.get(0).map(|n| n as usize))
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -343,4 +541,37 @@ mod test {
             .sample_frac(&Series::new("frac", &[2.0]), true, false, Some(0))
             .is_ok());
     }
+
+    #[test]
+    fn test_sample_weighted() {
+        let df = df![
+            "foo" => &[1, 2, 3, 4, 5]
+        ]
+        .unwrap();
+        let weights = Series::new("w", &[Some(0.0), Some(1.0), None, Some(3.0), Some(0.0)]);
+
+        // Rows with a zero or missing weight are never sampled.
+        let out = df
+            .sample_n_literal_weighted(100, &weights, true, Some(0))
+            .unwrap();
+        let foo = out.column("foo").unwrap().i32().unwrap();
+        assert!(foo.into_no_null_iter().all(|v| v == 2 || v == 4));
+        let n_four = foo.into_no_null_iter().filter(|v| *v == 4).count();
+        assert!(n_four > 50);
+
+        let out = df
+            .sample_n_literal_weighted(2, &weights, false, Some(0))
+            .unwrap();
+        let mut foo = out.column("foo").unwrap().i32().unwrap().to_vec();
+        foo.sort();
+        assert_eq!(foo, &[Some(2), Some(4)]);
+        // Only two rows have a positive weight.
+        assert!(df
+            .sample_n_literal_weighted(3, &weights, false, Some(0))
+            .is_err());
+        let negative = Series::new("w", &[1.0, -1.0, 1.0, 1.0, 1.0]);
+        assert!(df
+            .sample_n_literal_weighted(1, &negative, true, Some(0))
+            .is_err());
+    }
 }
@@ -1,3 +1,7 @@
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+
+use polars_error::{polars_ensure, polars_err, PolarsResult};
+
 use crate::POOL;
 
 // Formatting environment variables (typically referenced/set from the python-side Config object)
@@ -29,8 +33,205 @@ pub(crate) const FMT_TABLE_INLINE_COLUMN_DATA_TYPE: &str =
 pub(crate) const FMT_TABLE_ROUNDED_CORNERS: &str = "POLARS_FMT_TABLE_ROUNDED_CORNERS";
 pub(crate) const FMT_TABLE_CELL_LIST_LEN: &str = "POLARS_FMT_TABLE_CELL_LIST_LEN";
 
+// Runtime overrides of the settings below, `0` means the setting is not set.
+static VERBOSE: AtomicU8 = AtomicU8::new(0);
+static MAX_THREADS: AtomicUsize = AtomicUsize::new(0);
+static STREAMING_CHUNK_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+/// Global engine settings that can be changed at runtime.
+///
+/// A setting takes precedence over its environment variable; unsetting it, by passing `None`,
+/// falls back to the environment variable again.
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::config::Config;
+///
+/// Config::set_verbose(Some(true));
+/// Config::set_streaming_chunk_size(Some(50_000));
+/// assert!(polars_core::config::verbose());
+///
+/// Config::set_verbose(None);
+/// Config::set_streaming_chunk_size(None);
+/// ```
+pub struct Config;
+
+impl Config {
+    /// Set the number of threads of the global thread pool, overriding `POLARS_MAX_THREADS`.
+    ///
+    /// The pool is created on first use and cannot be resized afterwards, so this errors if
+    /// the pool is already running.
+    pub fn set_max_threads(n: Option<usize>) -> PolarsResult<()> {
+        #[cfg(not(target_family = "wasm"))]
+        polars_ensure!(
+            !POOL.is_initialized(),
+            InvalidOperation: "the thread pool is already running with {} threads; \
+            `Config::set_max_threads` must be called before it is first used",
+            POOL.current_num_threads()
+        );
+        polars_ensure!(
+            n != Some(0),
+            InvalidOperation: "the thread pool needs at least one thread"
+        );
+        MAX_THREADS.store(n.unwrap_or(0), Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Run Polars on `pool` instead of creating its own global thread pool.
+    ///
+    /// Like [`Config::set_max_threads`], this errors if the global pool is already running. To
+    /// use a pool for a single query, see [`install_thread_pool`](crate::install_thread_pool).
+    #[cfg(not(target_family = "wasm"))]
+    pub fn set_thread_pool(pool: rayon::ThreadPool) -> PolarsResult<()> {
+        crate::thread_pool::set_global_thread_pool(pool)
+    }
+
+    /// Print logging info to stderr, overriding `POLARS_VERBOSE`.
+    pub fn set_verbose(verbose: Option<bool>) {
+        let v = match verbose {
+            None => 0,
+            Some(false) => 1,
+            Some(true) => 2,
+        };
+        VERBOSE.store(v, Ordering::Relaxed);
+    }
+
+    /// Set the chunk size of the streaming engine, overriding `POLARS_STREAMING_CHUNK_SIZE`.
+    /// `None` lets the engine pick a size based on the number of columns and threads.
+    pub fn set_streaming_chunk_size(size: Option<usize>) {
+        STREAMING_CHUNK_SIZE.store(size.unwrap_or(0), Ordering::Relaxed);
+    }
+}
+
 pub fn verbose() -> bool {
-    std::env::var("POLARS_VERBOSE").as_deref().unwrap_or("") == "1"
+    match VERBOSE.load(Ordering::Relaxed) {
+        0 => std::env::var("POLARS_VERBOSE").as_deref().unwrap_or("") == "1",
+        v => v == 2,
+    }
+}
+
+/// Emit a `tracing` debug event if the `tracing` feature of `polars-core` is enabled.
+///
+/// Fields are given before the message, separated by a `;`, and are recorded with their
+/// `Display` implementation.
+///
+/// ```rust
+/// # use polars_core::polars_trace;
+/// let column = "a";
+/// polars_trace!(target: "polars::optimizer", column = column; "pushed down predicate");
+/// polars_trace!(target: "polars::optimizer", "plan optimized");
+/// ```
+#[macro_export]
+macro_rules! polars_trace {
+    (target: $target:expr, $($key:ident = $value:expr),* ; $($arg:tt)+) => {
+        $crate::__polars_trace_event!($target, $($key = $value),* ; $($arg)+)
+    };
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::__polars_trace_event!($target, ; $($arg)+)
+    };
+}
+
+/// Like [`polars_trace!`], but also prints the message to stderr if [`verbose`] is set.
+#[macro_export]
+macro_rules! polars_event {
+    (target: $target:expr, $($key:ident = $value:expr),* ; $($arg:tt)+) => {{
+        if $crate::config::verbose() {
+            eprintln!($($arg)+);
+        }
+        $crate::__polars_trace_event!($target, $($key = $value),* ; $($arg)+)
+    }};
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::polars_event!(target: $target, ; $($arg)+)
+    };
+}
+
+/// Enter a `tracing` debug span for the rest of the scope, if the `tracing` feature of
+/// `polars-core` is enabled. The returned guard exits the span when dropped.
+///
+/// ```rust
+/// # use polars_core::polars_span;
+/// let _span = polars_span!(target: "polars::execute", "sort", rows = 10);
+/// ```
+#[macro_export]
+macro_rules! polars_span {
+    (target: $target:expr, $name:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        $crate::__polars_trace_span!($target, $name, $($key = $value),*)
+    };
+}
+
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __polars_trace_event {
+    ($target:expr, $($key:ident = $value:expr),* ; $($arg:tt)+) => {
+        $crate::export::tracing::debug!(target: $target, $($key = %$value,)* $($arg)+)
+    };
+}
+
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __polars_trace_span {
+    ($target:expr, $name:expr, $($key:ident = $value:expr),*) => {
+        $crate::export::tracing::debug_span!(target: $target, $name, $($key = %$value),*).entered()
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __polars_trace_event {
+    ($target:expr, $($key:ident = $value:expr),* ; $($arg:tt)+) => {
+        // Type check the arguments without evaluating them, so that disabling tracing
+        // doesn't cause unused warnings.
+        if false {
+            $(let _ = &$value;)*
+            let _ = format_args!($($arg)+);
+        }
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __polars_trace_span {
+    ($target:expr, $name:expr, $($key:ident = $value:expr),*) => {{
+        if false {
+            $(let _ = &$value;)*
+        }
+        $crate::config::NoopSpanGuard
+    }};
+}
+
+/// Stands in for an entered span if the `tracing` feature is disabled.
+#[doc(hidden)]
+pub struct NoopSpanGuard;
+
+/// The number of threads the global thread pool is created with, if configured.
+#[cfg(not(target_family = "wasm"))]
+pub(crate) fn get_max_threads() -> Option<usize> {
+    match MAX_THREADS.load(Ordering::Relaxed) {
+        0 => std::env::var("POLARS_MAX_THREADS")
+            .ok()
+            .map(|s| s.parse::<usize>().expect("integer")),
+        n => Some(n),
+    }
+}
+
+/// The chunk size of the streaming engine, if configured.
+pub fn get_streaming_chunk_size() -> PolarsResult<Option<usize>> {
+    match STREAMING_CHUNK_SIZE.load(Ordering::Relaxed) {
+        0 => std::env::var("POLARS_STREAMING_CHUNK_SIZE")
+            .ok()
+            .map(|s| {
+                s.parse().map_err(|_| {
+                    polars_err!(ComputeError: "could not parse 'POLARS_STREAMING_CHUNK_SIZE' env var")
+                })
+            })
+            .transpose(),
+        n => Ok(Some(n)),
+    }
 }
 
 pub fn get_file_prefetch_size() -> usize {
@@ -51,3 +252,26 @@ pub fn force_async() -> bool {
         .map(|value| value == "1")
         .unwrap_or_default()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_runtime_config() -> PolarsResult<()> {
+        Config::set_verbose(Some(true));
+        assert!(verbose());
+        Config::set_verbose(Some(false));
+        assert!(!verbose());
+        Config::set_verbose(None);
+
+        Config::set_streaming_chunk_size(Some(1234));
+        assert_eq!(get_streaming_chunk_size()?, Some(1234));
+        Config::set_streaming_chunk_size(None);
+
+        // The pool can't be resized once it runs.
+        POOL.install(|| ());
+        assert!(Config::set_max_threads(Some(2)).is_err());
+        Ok(())
+    }
+}
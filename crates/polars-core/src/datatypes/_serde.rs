@@ -114,6 +114,7 @@ enum SerializableDataType {
     Enum(Option<Wrap<Utf8ViewArray>>, CategoricalOrdering),
     #[cfg(feature = "object")]
     Object(String),
+    Int128,
 }
 
 impl From<&DataType> for SerializableDataType {
@@ -129,6 +130,7 @@ impl From<&DataType> for SerializableDataType {
             Int16 => Self::Int16,
             Int32 => Self::Int32,
             Int64 => Self::Int64,
+            Int128 => Self::Int128,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
@@ -173,6 +175,7 @@ impl From<SerializableDataType> for DataType {
             Int16 => Self::Int16,
             Int32 => Self::Int32,
             Int64 => Self::Int64,
+            Int128 => Self::Int128,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
@@ -82,6 +82,8 @@ pub enum AnyValue<'a> {
     Int32(i32),
     /// A 64-bit integer number.
     Int64(i64),
+    /// A 128-bit integer number.
+    Int128(i128),
     /// A 32-bit floating point number.
     Float32(f32),
     /// A 64-bit floating point number.
@@ -163,6 +165,7 @@ impl Serialize for AnyValue<'_> {
             AnyValue::BinaryOwned(v) => {
                 serializer.serialize_newtype_variant(name, 14, "BinaryOwned", v)
             },
+            AnyValue::Int128(v) => serializer.serialize_newtype_variant(name, 15, "Int128", v),
             _ => todo!(),
         }
     }
@@ -191,6 +194,7 @@ impl<'a> Deserialize<'a> for AnyValue<'static> {
             Bool,
             StringOwned,
             BinaryOwned,
+            Int128,
         }
         const VARIANTS: &[&str] = &[
             "Null",
@@ -208,8 +212,9 @@ impl<'a> Deserialize<'a> for AnyValue<'static> {
             "Boolean",
             "StringOwned",
             "BinaryOwned",
+            "Int128",
         ];
-        const LAST: u8 = unsafe { std::mem::transmute::<_, u8>(AvField::BinaryOwned) };
+        const LAST: u8 = unsafe { std::mem::transmute::<_, u8>(AvField::Int128) };
 
         struct FieldVisitor;
 
@@ -273,6 +278,7 @@ impl<'a> Deserialize<'a> for AnyValue<'static> {
                     b"Bool" => AvField::Bool,
                     b"StringOwned" | b"String" => AvField::StringOwned,
                     b"BinaryOwned" | b"Binary" => AvField::BinaryOwned,
+                    b"Int128" => AvField::Int128,
                     _ => {
                         return Err(serde::de::Error::unknown_variant(
                             &String::from_utf8_lossy(v),
@@ -364,6 +370,10 @@ impl<'a> Deserialize<'a> for AnyValue<'static> {
                         let value = variant.newtype_variant()?;
                         AnyValue::BinaryOwned(value)
                     },
+                    (AvField::Int128, variant) => {
+                        let value = variant.newtype_variant()?;
+                        AnyValue::Int128(value)
+                    },
                 };
                 Ok(out)
             }
@@ -403,6 +413,7 @@ impl<'a> AnyValue<'a> {
             Int16(_) => DataType::Int16,
             Int32(_) => DataType::Int32,
             Int64(_) => DataType::Int64,
+            Int128(_) => DataType::Int128,
             UInt8(_) => DataType::UInt8,
             UInt16(_) => DataType::UInt16,
             UInt32(_) => DataType::UInt32,
@@ -449,6 +460,7 @@ impl<'a> AnyValue<'a> {
             Int16(v) => NumCast::from(*v),
             Int32(v) => NumCast::from(*v),
             Int64(v) => NumCast::from(*v),
+            Int128(v) => NumCast::from(*v),
             UInt8(v) => NumCast::from(*v),
             UInt16(v) => NumCast::from(*v),
             UInt32(v) => NumCast::from(*v),
@@ -514,7 +526,11 @@ impl<'a> AnyValue<'a> {
     pub fn is_signed_integer(&self) -> bool {
         matches!(
             self,
-            AnyValue::Int8(_) | AnyValue::Int16(_) | AnyValue::Int32(_) | AnyValue::Int64(_)
+            AnyValue::Int8(_)
+                | AnyValue::Int16(_)
+                | AnyValue::Int32(_)
+                | AnyValue::Int64(_)
+                | AnyValue::Int128(_)
         )
     }
 
@@ -552,6 +568,7 @@ impl<'a> AnyValue<'a> {
             (av, DataType::Int16) => AnyValue::Int16(av.extract::<i16>()?),
             (av, DataType::Int32) => AnyValue::Int32(av.extract::<i32>()?),
             (av, DataType::Int64) => AnyValue::Int64(av.extract::<i64>()?),
+            (av, DataType::Int128) => AnyValue::Int128(av.extract::<i128>()?),
             (av, DataType::Float32) => AnyValue::Float32(av.extract::<f32>()?),
             (av, DataType::Float64) => AnyValue::Float64(av.extract::<f64>()?),
 
@@ -564,10 +581,14 @@ impl<'a> AnyValue<'a> {
             (AnyValue::Int16(v), DataType::Boolean) => AnyValue::Boolean(*v != i16::default()),
             (AnyValue::Int32(v), DataType::Boolean) => AnyValue::Boolean(*v != i32::default()),
             (AnyValue::Int64(v), DataType::Boolean) => AnyValue::Boolean(*v != i64::default()),
+            (AnyValue::Int128(v), DataType::Boolean) => AnyValue::Boolean(*v != i128::default()),
             (AnyValue::Float32(v), DataType::Boolean) => AnyValue::Boolean(*v != f32::default()),
             (AnyValue::Float64(v), DataType::Boolean) => AnyValue::Boolean(*v != f64::default()),
 
             // to string
+            (AnyValue::Int128(v), DataType::String) => {
+                AnyValue::StringOwned(format_smartstring!("{}", v))
+            },
             (av, DataType::String) => {
                 AnyValue::StringOwned(format_smartstring!("{}", av.extract::<i64>()?))
             },
@@ -728,6 +749,7 @@ impl AnyValue<'_> {
             Int16(v) => v.hash(state),
             Int32(v) => v.hash(state),
             Int64(v) => v.hash(state),
+            Int128(v) => v.hash(state),
             UInt8(v) => v.hash(state),
             UInt16(v) => v.hash(state),
             UInt32(v) => v.hash(state),
@@ -858,6 +880,7 @@ impl<'a> AnyValue<'a> {
             (_, Null) => Null,
             (Int32(l), Int32(r)) => Int32(l + r),
             (Int64(l), Int64(r)) => Int64(l + r),
+            (Int128(l), Int128(r)) => Int128(l + r),
             (UInt32(l), UInt32(r)) => UInt32(l + r),
             (UInt64(l), UInt64(r)) => UInt64(l + r),
             (Float32(l), Float32(r)) => Float32(l + r),
@@ -886,6 +909,7 @@ impl<'a> AnyValue<'a> {
             Int16(v) => Int16(v),
             Int32(v) => Int32(v),
             Int64(v) => Int64(v),
+            Int128(v) => Int128(v),
             UInt8(v) => UInt8(v),
             UInt16(v) => UInt16(v),
             UInt32(v) => UInt32(v),
@@ -974,6 +998,7 @@ impl AnyValue<'_> {
             (Int16(l), Int16(r)) => *l == *r,
             (Int32(l), Int32(r)) => *l == *r,
             (Int64(l), Int64(r)) => *l == *r,
+            (Int128(l), Int128(r)) => *l == *r,
             (Float32(l), Float32(r)) => l.to_total_ord() == r.to_total_ord(),
             (Float64(l), Float64(r)) => l.to_total_ord() == r.to_total_ord(),
             (String(l), String(r)) => l == r,
@@ -1061,6 +1086,7 @@ impl PartialOrd for AnyValue<'_> {
             (Int16(l), Int16(r)) => l.partial_cmp(r),
             (Int32(l), Int32(r)) => l.partial_cmp(r),
             (Int64(l), Int64(r)) => l.partial_cmp(r),
+            (Int128(l), Int128(r)) => l.partial_cmp(r),
             (Float32(l), Float32(r)) => l.to_total_ord().partial_cmp(&r.to_total_ord()),
             (Float64(l), Float64(r)) => l.to_total_ord().partial_cmp(&r.to_total_ord()),
             _ => match (self.as_borrowed(), other.as_borrowed()) {
@@ -1247,6 +1273,9 @@ impl<K: NumericNative> From<K> for AnyValue<'static> {
                 PrimitiveType::Int64 => {
                     AnyValue::Int64(NumCast::from(value).unwrap_unchecked_release())
                 },
+                PrimitiveType::Int128 => {
+                    AnyValue::Int128(NumCast::from(value).unwrap_unchecked_release())
+                },
                 PrimitiveType::UInt8 => {
                     AnyValue::UInt8(NumCast::from(value).unwrap_unchecked_release())
                 },
@@ -12,6 +12,9 @@ pub type TimeZone = String;
 
 pub static DTYPE_ENUM_KEY: &str = "POLARS.CATEGORICAL_TYPE";
 pub static DTYPE_ENUM_VALUE: &str = "ENUM";
+/// Metadata key that stores the categories of an Enum, so that the full category
+/// list survives a roundtrip through formats that only store the used values.
+pub static DTYPE_ENUM_VALUES: &str = "POLARS.ENUM_VALUES";
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(
@@ -51,6 +54,8 @@ pub enum DataType {
     Int16,
     Int32,
     Int64,
+    /// A 128-bit signed integer.
+    Int128,
     Float32,
     Float64,
     /// Fixed point decimal type optional precision and non-negative scale.
@@ -175,6 +180,8 @@ impl DataType {
             Int16 => other.extract::<i16>().is_some(),
             Int32 => other.extract::<i32>().is_some(),
             Int64 => other.extract::<i64>().is_some(),
+            #[cfg(feature = "dtype-i128")]
+            Int128 => other.extract::<i128>().is_some(),
             _ => false,
         }
     }
@@ -439,6 +446,7 @@ impl DataType {
                 | DataType::Int16
                 | DataType::Int32
                 | DataType::Int64
+                | DataType::Int128
                 | DataType::UInt8
                 | DataType::UInt16
                 | DataType::UInt32
@@ -455,6 +463,8 @@ impl DataType {
             DataType::Int8 => true,
             #[cfg(feature = "dtype-i16")]
             DataType::Int16 => true,
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => true,
             _ => false,
         }
     }
@@ -500,11 +510,19 @@ impl DataType {
     pub fn to_arrow_field(&self, name: &str, pl_flavor: bool) -> ArrowField {
         let metadata = match self {
             #[cfg(feature = "dtype-categorical")]
-            DataType::Enum(_, _) => Some(BTreeMap::from([(
-                DTYPE_ENUM_KEY.into(),
-                DTYPE_ENUM_VALUE.into(),
-            )])),
-            DataType::BinaryOffset => Some(BTreeMap::from([(
+            DataType::Enum(rev_map, _) => {
+                let mut metadata =
+                    BTreeMap::from([(DTYPE_ENUM_KEY.into(), DTYPE_ENUM_VALUE.into())]);
+                if let Some(rev_map) = rev_map {
+                    metadata.insert(
+                        DTYPE_ENUM_VALUES.into(),
+                        encode_enum_categories(rev_map.get_categories()),
+                    );
+                }
+                Some(metadata)
+            },
+            // Int128 is stored as a decimal without scale, mark it so that it's read back as Int128.
+            DataType::BinaryOffset | DataType::Int128 => Some(BTreeMap::from([(
                 "pl".to_string(),
                 "maintain_type".to_string(),
             )])),
@@ -539,6 +557,8 @@ impl DataType {
             Int16 => Ok(ArrowDataType::Int16),
             Int32 => Ok(ArrowDataType::Int32),
             Int64 => Ok(ArrowDataType::Int64),
+            // Arrow has no 128-bit integer type, a decimal without scale is the closest match.
+            Int128 => Ok(ArrowDataType::Decimal(38, 0)),
             Float32 => Ok(ArrowDataType::Float32),
             Float64 => Ok(ArrowDataType::Float64),
             #[cfg(feature = "dtype-decimal")]
@@ -680,6 +700,7 @@ impl Display for DataType {
             DataType::Int16 => "i16",
             DataType::Int32 => "i32",
             DataType::Int64 => "i64",
+            DataType::Int128 => "i128",
             DataType::Float32 => "f32",
             DataType::Float64 => "f64",
             #[cfg(feature = "dtype-decimal")]
@@ -784,3 +805,33 @@ pub fn create_enum_data_type(categories: Utf8ViewArray) -> DataType {
     let rev_map = RevMapping::build_local(categories);
     DataType::Enum(Some(Arc::new(rev_map)), Default::default())
 }
+
+/// Encode the categories of an Enum as length-prefixed strings.
+#[cfg(feature = "dtype-categorical")]
+pub(crate) fn encode_enum_categories(categories: &Utf8ViewArray) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::with_capacity(categories.total_bytes_len() + 4 * categories.len());
+    for cat in categories.values_iter() {
+        write!(out, "{};{}", cat.len(), cat).unwrap();
+    }
+    out
+}
+
+/// Decode categories that were encoded with [`encode_enum_categories`].
+#[cfg(feature = "dtype-categorical")]
+pub(crate) fn decode_enum_categories(mut encoded: &str) -> PolarsResult<Utf8ViewArray> {
+    let mut categories = vec![];
+    while !encoded.is_empty() {
+        let category = encoded.split_once(';').and_then(|(len, rest)| {
+            let len = len.parse::<usize>().ok()?;
+            Some((rest.get(..len)?, rest.get(len..)?))
+        });
+        let Some((category, rest)) = category else {
+            polars_bail!(ComputeError: "invalid Enum categories in field metadata");
+        };
+        categories.push(category);
+        encoded = rest;
+    }
+    Ok(Utf8ViewArray::from_slice_values(categories))
+}
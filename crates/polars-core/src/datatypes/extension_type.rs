@@ -0,0 +1,64 @@
+//! Registry of Arrow extension types that Polars reads as their storage data type.
+//!
+//! Arrow extension types, such as a "money" type stored as `Int64` or a "geometry" type
+//! stored as `Binary`, are not supported by Polars unless they are registered here. The values
+//! of a registered extension type are loaded as a column of its storage data type. The extension
+//! name and metadata are not kept, so writing such a column to IPC or Parquet stores it as its
+//! storage data type.
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+
+use crate::prelude::*;
+
+static EXTENSION_TYPES: Lazy<RwLock<PlHashMap<String, DataType>>> = Lazy::new(Default::default);
+
+/// Register the Arrow extension type `name`, of which the values are loaded as `storage`.
+pub fn register_extension_type(name: &str, storage: DataType) -> PolarsResult<()> {
+    #[cfg(feature = "object")]
+    polars_ensure!(
+        name != crate::chunked_array::object::extension::EXTENSION_NAME,
+        InvalidOperation: "cannot register the reserved extension type '{}'", name
+    );
+    polars_ensure!(
+        !storage.is_object() && storage.is_known(),
+        InvalidOperation: "cannot store extension type '{}' as '{}'", name, storage
+    );
+    EXTENSION_TYPES
+        .write()
+        .unwrap()
+        .insert(name.to_string(), storage);
+    Ok(())
+}
+
+/// Unregister the Arrow extension type `name`. Returns whether it was registered.
+pub fn unregister_extension_type(name: &str) -> bool {
+    EXTENSION_TYPES.write().unwrap().remove(name).is_some()
+}
+
+/// Get the storage data type of the registered Arrow extension type `name`.
+pub fn extension_type_storage(name: &str) -> Option<DataType> {
+    EXTENSION_TYPES.read().unwrap().get(name).cloned()
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::array::Int32Array;
+
+    use super::*;
+
+    #[test]
+    fn test_extension_type_storage() {
+        let dtype = ArrowDataType::Extension("money".into(), Box::new(ArrowDataType::Int32), None);
+        let arr = Int32Array::from(&[Some(1), None]).to(dtype.clone());
+        let field = ArrowField::new("a", dtype, true);
+
+        register_extension_type("money", DataType::Int64).unwrap();
+        assert_eq!(Field::from(&field).dtype, DataType::Int64);
+        let s = Series::try_from(("a", arr.boxed())).unwrap();
+        assert_eq!(Vec::from(s.i64().unwrap()), &[Some(1), None]);
+        assert!(unregister_extension_type("money"));
+
+        assert!(register_extension_type("money", DataType::Unknown(Default::default())).is_err());
+    }
+}
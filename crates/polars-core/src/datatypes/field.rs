@@ -173,6 +173,10 @@ impl DataType {
                     panic!("activate the 'object' feature to be able to load POLARS_EXTENSION_TYPE")
                 }
             }
+            ArrowDataType::Extension(name, _, _) => match extension_type_storage(name) {
+                Some(storage) => storage,
+                None => panic!("Arrow extension type '{name}' not supported by Polars. You probably need to register it."),
+            }
             #[cfg(feature = "dtype-decimal")]
             ArrowDataType::Decimal(precision, scale) => DataType::Decimal(Some(*precision), Some(*scale)),
             ArrowDataType::Utf8View |ArrowDataType::LargeUtf8 | ArrowDataType::Utf8 => DataType::String,
@@ -186,6 +190,7 @@ impl DataType {
                 }
             },
             ArrowDataType::FixedSizeBinary(_) => DataType::Binary,
+            ArrowDataType::RunEndEncoded(f) => DataType::from_arrow(f.data_type(), bin_to_view),
             dt => panic!("Arrow datatype {dt:?} not supported by Polars. You probably need to activate that data-type feature."),
         }
     }
@@ -199,6 +204,26 @@ impl From<&ArrowDataType> for DataType {
 
 impl From<&ArrowField> for Field {
     fn from(f: &ArrowField) -> Self {
-        Field::new(&f.name, f.data_type().into())
+        let dtype = match f.data_type() {
+            #[cfg(feature = "dtype-i128")]
+            ArrowDataType::Decimal(_, 0)
+                if f.metadata.get("pl").map(|s| s.as_str()) == Some("maintain_type") =>
+            {
+                DataType::Int128
+            },
+            #[cfg(feature = "dtype-categorical")]
+            ArrowDataType::Dictionary(_, _, _)
+                if f.metadata.get(DTYPE_ENUM_KEY).map(|s| s.as_str()) == Some(DTYPE_ENUM_VALUE) =>
+            {
+                match f.metadata.get(DTYPE_ENUM_VALUES) {
+                    Some(categories) => decode_enum_categories(categories)
+                        .map(create_enum_data_type)
+                        .unwrap_or(DataType::Enum(None, Default::default())),
+                    None => DataType::Enum(None, Default::default()),
+                }
+            },
+            dt => dt.into(),
+        };
+        Field::new(&f.name, dtype)
     }
 }
@@ -55,3 +55,8 @@ impl_into_scalar! {
     // #[cfg(feature = "dtype-struct")]
     // StructOwned(Box<(Vec<AnyValue<'a>>, Vec<Field>)>),
 }
+
+#[cfg(feature = "dtype-i128")]
+impl_into_scalar! {
+    i128: (T::Int128),
+}
@@ -4,6 +4,8 @@ pub use chrono;
 pub use regex;
 #[cfg(feature = "serde")]
 pub use serde;
+#[cfg(feature = "tracing")]
+pub use tracing;
 pub use {ahash, arrow, num_traits as num, once_cell, rayon};
 
 pub use crate::hashing::_boost_hash_combine;
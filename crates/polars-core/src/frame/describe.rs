@@ -0,0 +1,137 @@
+use super::*;
+
+const DEFAULT_PERCENTILES: [f64; 3] = [0.25, 0.5, 0.75];
+
+fn null_statistic() -> Series {
+    Series::full_null("", 1, &DataType::Null)
+}
+
+/// Get the `count`, `null_count`, `mean`, `std`, `min`, percentiles and `max` of `s`, each as a
+/// Series of length 1 that is null if the statistic doesn't apply to the dtype of `s`.
+fn describe_series(s: &Series, percentiles: &[f64]) -> PolarsResult<Vec<Series>> {
+    let dtype = s.dtype();
+    let is_numeric = dtype.is_numeric();
+    let is_temporal = !is_numeric && dtype.is_temporal();
+    let skip_min_max = dtype.is_nested()
+        || matches!(dtype, DataType::Null | DataType::Unknown(_))
+        || dtype.is_categorical()
+        || dtype.is_enum()
+        || dtype.is_object();
+
+    let mut out = Vec::with_capacity(percentiles.len() + 6);
+    let count = (s.len() - s.null_count()) as IdxSize;
+    out.push(IdxCa::from_slice("", &[count]).into_series());
+    out.push(IdxCa::from_slice("", &[s.null_count() as IdxSize]).into_series());
+    out.push(if is_numeric || is_temporal || dtype.is_bool() {
+        s.mean_reduce().into_series("")
+    } else {
+        null_statistic()
+    });
+    out.push(if is_numeric {
+        s.std_reduce(1)?.into_series("")
+    } else {
+        null_statistic()
+    });
+    out.push(if skip_min_max {
+        null_statistic()
+    } else {
+        s.min_reduce()?.into_series("")
+    });
+    for &p in percentiles {
+        out.push(if is_numeric {
+            s.quantile_reduce(p, QuantileInterpolOptions::Nearest)?
+                .into_series("")
+        } else if is_temporal {
+            let physical = s.to_physical_repr();
+            physical
+                .quantile_reduce(p, QuantileInterpolOptions::Nearest)?
+                .into_series("")
+                .cast(physical.dtype())?
+                .cast(dtype)?
+        } else {
+            null_statistic()
+        });
+    }
+    out.push(if skip_min_max {
+        null_statistic()
+    } else {
+        s.max_reduce()?.into_series("")
+    });
+    Ok(out)
+}
+
+/// Format a percentile as the name of its statistic, e.g. `0.25` as `"25%"`.
+fn percentile_name(p: f64) -> String {
+    // Round off the error of the multiplication, e.g. `0.3 * 100.0 = 30.000000000000004`.
+    let pct = (p * 100.0 * 1e6).round() / 1e6;
+    format!("{pct}%")
+}
+
+impl DataFrame {
+    /// Summary statistics of every column of the [`DataFrame`].
+    ///
+    /// The result has a `statistic` column with the names of the statistics: `count`,
+    /// `null_count`, `mean`, `std`, `min`, the given `percentiles` (by default 25%, 50% and 75%)
+    /// and `max`, followed by a column per column of `self`. The statistics of numeric, boolean
+    /// and null columns are `Float64`, those of other columns are formatted as `String`. A
+    /// statistic that doesn't apply to the dtype of a column is null.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => [1.0, 2.0, 3.0], "b" => ["x", "y", "z"])?;
+    /// let summary = df.describe(None)?;
+    /// assert_eq!(summary.shape(), (9, 3));
+    /// assert_eq!(summary.column("a")?.f64()?.get(2), Some(2.0));
+    /// assert_eq!(summary.column("b")?.str()?.get(4), Some("x"));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn describe(&self, percentiles: Option<&[f64]>) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            self.width() > 0,
+            InvalidOperation: "cannot describe a DataFrame that has no columns"
+        );
+        let percentiles = percentiles.unwrap_or(&DEFAULT_PERCENTILES);
+        for &p in percentiles {
+            polars_ensure!(
+                (0.0..=1.0).contains(&p),
+                ComputeError: "percentiles must all be in the range [0, 1], got {}", p
+            );
+        }
+
+        let mut statistics = ["count", "null_count", "mean", "std", "min"]
+            .map(String::from)
+            .to_vec();
+        statistics.extend(percentiles.iter().map(|&p| percentile_name(p)));
+        statistics.push("max".into());
+
+        let columns = POOL.install(|| {
+            self.columns
+                .par_iter()
+                .map(|s| {
+                    let dtype = s.dtype();
+                    let out_dtype = if dtype.is_numeric()
+                        || dtype.is_bool()
+                        || dtype.is_nested()
+                        || dtype == &DataType::Null
+                    {
+                        DataType::Float64
+                    } else {
+                        DataType::String
+                    };
+                    let mut out = Series::new_empty(s.name(), &out_dtype);
+                    for stat in describe_series(s, percentiles)? {
+                        out.append(&stat.cast(&out_dtype)?)?;
+                    }
+                    Ok(out)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let mut out = Vec::with_capacity(columns.len() + 1);
+        out.push(Series::new("statistic", statistics));
+        out.extend(columns);
+        DataFrame::new(out)
+    }
+}
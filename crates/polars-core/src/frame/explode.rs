@@ -74,17 +74,19 @@ impl DataFrame {
             } else {
                 polars_bail!(
                     ShapeMismatch: "exploded column(s) {:?} doesn't have the same length: {} \
-                    as the dataframe: {}", exploded.name(), exploded.name(), df.height(),
+                    as the dataframe: {}", exploded.name(), exploded.len(), df.height(),
                 );
             }
             Ok(())
         }
 
+        // The offsets of sliced lists don't start at zero, so we compare the list lengths.
         let check_offsets = || {
-            let first_offsets = exploded_columns[0].1.as_slice();
-            for (_, offsets) in &exploded_columns[1..] {
-                polars_ensure!(first_offsets == offsets.as_slice(),
-                    ShapeMismatch: "exploded columns must have matching element counts"
+            let (first, first_offsets) = &exploded_columns[0];
+            for (exploded, offsets) in &exploded_columns[1..] {
+                polars_ensure!(first_offsets.lengths().eq(offsets.lengths()),
+                    ShapeMismatch: "exploded columns must have matching element counts, \
+                    but {:?} and {:?} differ", first.name(), exploded.name()
                 )
             }
             Ok(())
@@ -412,6 +414,42 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_explode_multiple_sliced_cols() -> PolarsResult<()> {
+        let a = Series::new(
+            "a",
+            [
+                Series::new("", [1, 2]),
+                Series::new("", [3]),
+                Series::new("", [4, 5]),
+            ],
+        );
+        let b = Series::new(
+            "b",
+            [
+                Series::new("", ["x"]),
+                Series::new("", ["y", "z"]),
+                Series::new("", ["u"]),
+                Series::new("", ["v", "w"]),
+            ],
+        )
+        .slice(1, 3);
+        let df = DataFrame::new(vec![a.clone(), b])?;
+
+        let out = df.explode(["a", "b"])?;
+        let expected = df![
+            "a" => [1, 2, 3, 4, 5],
+            "b" => ["y", "z", "u", "v", "w"],
+        ]?;
+        assert!(out.equals(&expected));
+
+        let b = Series::new("b", vec![Series::new("", [1]); 3]);
+        let df = DataFrame::new(vec![a, b])?;
+        assert!(df.explode(["a", "b"]).is_err());
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_explode_single_col() -> PolarsResult<()> {
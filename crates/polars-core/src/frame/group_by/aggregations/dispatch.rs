@@ -1,4 +1,8 @@
 use super::*;
+#[cfg(feature = "dtype-decimal")]
+use crate::chunked_array::logical::div_round_half_away;
+#[cfg(feature = "dtype-decimal")]
+use crate::chunked_array::ops::arity::binary_elementwise;
 
 // implemented on the series because we don't need types
 impl Series {
@@ -175,6 +179,23 @@ impl Series {
                 * (MS_IN_DAY as f64))
                 .cast(&Datetime(TimeUnit::Milliseconds, None))
                 .unwrap(),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(precision, _) => {
+                let sum = s.agg_sum(groups);
+                let count = s.agg_valid_count(groups);
+                let sum = sum.decimal().unwrap();
+                let out: Int128Chunked =
+                    binary_elementwise(&sum.0, count.idx().unwrap(), |sum, count| {
+                        match (sum, count) {
+                            (Some(sum), Some(count)) if count > 0 => {
+                                Some(div_round_half_away(sum, count as i128))
+                            },
+                            _ => None,
+                        }
+                    });
+                out.into_decimal_unchecked(*precision, sum.scale())
+                    .into_series()
+            },
             _ => Series::full_null("", groups.len(), s.dtype()),
         }
     }
@@ -189,8 +189,8 @@ where
                 };
                 num_groups_proxy(ca, multithreaded, sorted)
             },
-            #[cfg(feature = "dtype-decimal")]
-            DataType::Decimal(_, _) => {
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => {
                 // convince the compiler that we are this type.
                 let ca: &Int128Chunked = unsafe {
                     &*(self as *const ChunkedArray<T> as *const ChunkedArray<Int128Type>)
@@ -16,16 +16,22 @@ use crate::utils::{slice_offsets, try_get_supertype, NoNull};
 #[cfg(feature = "dataframe_arithmetic")]
 mod arithmetic;
 mod chunks;
+#[cfg(feature = "describe")]
+mod describe;
 pub mod explode;
 mod from;
 #[cfg(feature = "algorithm_group_by")]
 pub mod group_by;
 #[cfg(any(feature = "rows", feature = "object"))]
 pub mod row;
+#[cfg(feature = "row_hash")]
+mod row_hash;
 mod top_k;
 mod upstream_traits;
 
 use arrow::record_batch::RecordBatch;
+#[cfg(feature = "row_hash")]
+pub use row_hash::RowHasher;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
@@ -35,6 +41,8 @@ use crate::chunked_array::cast::CastOptions;
 use crate::hashing::_df_rows_to_hashes_threaded_vertical;
 #[cfg(feature = "zip_with")]
 use crate::prelude::min_max_binary::min_max_binary_series;
+#[cfg(feature = "algorithm_group_by")]
+use crate::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca_unordered;
 use crate::prelude::sort::{argsort_multiple_row_fmt, prepare_arg_sort};
 use crate::series::IsSorted;
 use crate::POOL;
@@ -156,6 +164,54 @@ impl DataFrame {
         self.columns.iter().map(|s| s.estimated_size()).sum()
     }
 
+    /// Returns a breakdown of the estimated (heap) allocated size of every column in bytes.
+    ///
+    /// The output has a row per column with the size of its top-level validity bitmaps, of its
+    /// values (including offsets, string data and nested arrays), of the categories of a
+    /// categorical column, their total and the number of chunks of the column. The sizes are
+    /// estimated as in [`DataFrame::estimated_size`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => [1i64, 2, 3], "b" => ["x", "y", "z"])?;
+    /// let usage = df.memory_usage()?;
+    /// assert_eq!(usage.height(), 2);
+    /// assert_eq!(usage.column("n_chunks")?.u64()?.get(0), Some(1));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn memory_usage(&self) -> PolarsResult<DataFrame> {
+        let mut validity = Vec::with_capacity(self.width());
+        let mut values = Vec::with_capacity(self.width());
+        let mut dictionary = Vec::with_capacity(self.width());
+        let mut n_chunks = Vec::with_capacity(self.width());
+        for s in &self.columns {
+            let dictionary_size = s.estimated_dictionary_size();
+            let validity_size = s.estimated_validity_size();
+            validity.push(validity_size as u64);
+            let values_size = s.estimated_size() - dictionary_size;
+            values.push(values_size.saturating_sub(validity_size) as u64);
+            dictionary.push(dictionary_size as u64);
+            n_chunks.push(s.n_chunks() as u64);
+        }
+        let total = validity
+            .iter()
+            .zip(&values)
+            .zip(&dictionary)
+            .map(|((validity, values), dictionary)| validity + values + dictionary)
+            .collect::<Vec<_>>();
+
+        DataFrame::new(vec![
+            Series::new("column", self.get_column_names()),
+            Series::new("validity_bytes", validity),
+            Series::new("values_bytes", values),
+            Series::new("dictionary_bytes", dictionary),
+            Series::new("total_bytes", total),
+            Series::new("n_chunks", n_chunks),
+        ])
+    }
+
     // Reduce monomorphization.
     pub fn _apply_columns(&self, func: &(dyn Fn(&Series) -> Series)) -> Vec<Series> {
         self.columns.iter().map(func).collect()
@@ -1776,6 +1832,17 @@ impl DataFrame {
             set_sorted(&mut out);
             return Ok(out);
         }
+        // Sort floats on keys that order by IEEE 754 totalOrder. The keys are only used to
+        // compute the sort indices, the frame itself keeps the original values.
+        let float_total_order = sort_options.float_total_order;
+        let by_column = if float_total_order {
+            by_column
+                .iter()
+                .map(|s| s.float_total_order_keys())
+                .collect()
+        } else {
+            by_column
+        };
         if let Some((0, k)) = slice {
             return self.bottom_k_impl(k, by_column, sort_options);
         }
@@ -1804,7 +1871,7 @@ impl DataFrame {
                 // fast path for a frame with a single series
                 // no need to compute the sort indices and then take by these indices
                 // simply sort and return as frame
-                if df.width() == 1 && df.check_name_to_idx(s.name()).is_ok() {
+                if !float_total_order && df.width() == 1 && df.check_name_to_idx(s.name()).is_ok() {
                     let mut out = s.sort_with(options)?;
                     if let Some((offset, len)) = slice {
                         out = out.slice(offset, len);
@@ -2707,7 +2774,17 @@ impl DataFrame {
         df.as_single_chunk_par();
 
         let columns = match (keep, maintain_order) {
-            (UniqueKeepStrategy::First | UniqueKeepStrategy::Any, true) => {
+            (UniqueKeepStrategy::Any, true) => {
+                // Any row of the duplicates may be kept, so a hash set of the rows in order
+                // gives us the distinct rows without materializing the (sorted) groups.
+                let idx = df.arg_unique_rows(&names)?;
+                let idx = match slice {
+                    None => idx,
+                    Some((offset, len)) => idx.slice(offset, len),
+                };
+                return Ok(unsafe { df.take_unchecked(&idx) });
+            },
+            (UniqueKeepStrategy::First, true) => {
                 let gb = df.group_by_stable(names)?;
                 let groups = gb.get_groups();
                 let (offset, len) = slice.unwrap_or((0, groups.len()));
@@ -2763,6 +2840,25 @@ impl DataFrame {
         Ok(unsafe { DataFrame::new_no_checks(columns) })
     }
 
+    /// Get the index of the first occurrence of every distinct row of the `names` columns.
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique_rows(&self, names: &[&str]) -> PolarsResult<IdxCa> {
+        match names {
+            [] => polars_bail!(ComputeError: "at least one key is required in a unique operation"),
+            [name] => return self.column(name)?.arg_unique(),
+            _ => {},
+        }
+        let by = self.select_series(names)?;
+        let rows = _get_rows_encoded_ca_unordered("", &by)?;
+        let mut seen = PlHashSet::with_capacity(rows.len());
+        let idx = rows
+            .into_no_null_iter()
+            .enumerate()
+            .filter_map(|(idx, row)| seen.insert(row).then_some(idx as IdxSize))
+            .collect();
+        Ok(IdxCa::from_vec("", idx))
+    }
+
     /// Get a mask of all the unique rows in the [`DataFrame`].
     ///
     /// # Example
@@ -2824,7 +2920,10 @@ impl DataFrame {
         unsafe { Self::new_no_checks(cols) }
     }
 
-    /// Hash and combine the row values
+    /// Hash and combine the row values.
+    ///
+    /// The hashes are only stable within a process, use [`DataFrame::hash_rows_with`] for
+    /// hashes that are stable across processes and Polars versions.
     #[cfg(feature = "row_hash")]
     pub fn hash_rows(
         &mut self,
@@ -2957,6 +3056,44 @@ impl DataFrame {
         self._partition_by_impl(&cols, true, include_key)
     }
 
+    /// Split into multiple DataFrames partitioned by groups, yielding the partitions one
+    /// at a time. The groups are computed up front, but every partition is only gathered
+    /// when the iterator reaches it, so that not all partitions have to be kept in memory.
+    /// If `stable` is set, the order of the groups is maintained.
+    #[cfg(all(feature = "partition_by", feature = "algorithm_group_by"))]
+    pub fn partition_by_iter(
+        &self,
+        cols: impl IntoVec<String>,
+        stable: bool,
+        include_key: bool,
+    ) -> PolarsResult<impl Iterator<Item = DataFrame>> {
+        let cols = cols.into_vec();
+        let groups = if stable {
+            self.group_by_stable(&cols)?.take_groups()
+        } else {
+            self.group_by(&cols)?.take_groups()
+        };
+        let df = if include_key {
+            self.clone()
+        } else {
+            self.drop_many(&cols)
+        };
+
+        Ok(match groups {
+            GroupsProxy::Idx(idx) => {
+                either::Either::Left(idx.into_iter().map(move |(_, group)| {
+                    // groups are in bounds
+                    unsafe { df._take_unchecked_slice_sorted(&group, true, IsSorted::Ascending) }
+                }))
+            },
+            GroupsProxy::Slice { groups, .. } => either::Either::Right(
+                groups
+                    .into_iter()
+                    .map(move |[first, len]| df.slice(first as i64, len as usize)),
+            ),
+        })
+    }
+
     /// Unnest the given `Struct` columns. This means that the fields of the `Struct` type will be
     /// inserted as columns.
     #[cfg(feature = "dtype-struct")]
@@ -3289,4 +3426,24 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(all(feature = "partition_by", feature = "algorithm_group_by"))]
+    fn test_partition_by_iter() -> PolarsResult<()> {
+        let df = df!(
+            "g" => ["b", "a", "b", "c", "a"],
+            "v" => [1, 2, 3, 4, 5]
+        )?;
+        let out = df
+            .partition_by_iter(["g"], true, false)?
+            .collect::<Vec<_>>();
+        let expected = df.partition_by_stable(["g"], false)?;
+        assert_eq!(out.len(), 3);
+        for (a, b) in out.iter().zip(&expected) {
+            assert!(a.equals(b));
+        }
+        assert_eq!(out[0].get_column_names(), &["v"]);
+        assert!(out[0].column("v")?.equals(&Series::new("v", [1, 3])));
+        Ok(())
+    }
 }
@@ -87,6 +87,17 @@ impl DataFrame {
         &mut self,
         keep_names_as: Option<&str>,
         new_col_names: Option<Either<String, Vec<String>>>,
+    ) -> PolarsResult<DataFrame> {
+        self.transpose_with_dtype(keep_names_as, new_col_names, None)
+    }
+
+    /// Transpose a DataFrame, casting all values to `dtype`. If no `dtype` is given, the
+    /// supertype of the columns is used. This is a very expensive operation.
+    pub fn transpose_with_dtype(
+        &mut self,
+        keep_names_as: Option<&str>,
+        new_col_names: Option<Either<String, Vec<String>>>,
+        dtype: Option<&DataType>,
     ) -> PolarsResult<DataFrame> {
         // We must iterate columns as [`AnyValue`], so we must be contiguous.
         self.as_single_chunk_par();
@@ -119,7 +130,10 @@ impl DataFrame {
             df.height() != 0 && df.width() != 0,
             NoData: "unable to transpose an empty DataFrame"
         );
-        let dtype = df.get_supertype().unwrap()?;
+        let dtype = match dtype {
+            Some(dtype) => dtype.clone(),
+            None => df.get_supertype().unwrap()?,
+        };
         match dtype {
             #[cfg(feature = "dtype-categorical")]
             DataType::Categorical(_, _) | DataType::Enum(_, _) => {
@@ -303,4 +317,32 @@ mod test {
         assert!(out.equals_missing(&expected));
         Ok(())
     }
+
+    #[test]
+    fn test_transpose_with_dtype() -> PolarsResult<()> {
+        let mut df = df![
+            "name" => ["x", "y"],
+            "a" => [1, 2],
+            "b" => [1.5, 2.5],
+        ]?;
+        let out = df.transpose_with_dtype(
+            Some("column"),
+            Some(Either::Left("name".into())),
+            Some(&DataType::Int64),
+        )?;
+        let expected = df![
+            "column" => ["a", "b"],
+            "x" => [1i64, 1],
+            "y" => [2i64, 2],
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        let out = df.transpose_with_dtype(None, None, Some(&DataType::String))?;
+        let expected = df![
+            "column_0" => ["x", "1", "1.5"],
+            "column_1" => ["y", "2", "2.5"],
+        ]?;
+        assert!(out.equals_missing(&expected));
+        Ok(())
+    }
 }
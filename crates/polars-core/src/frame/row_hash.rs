@@ -0,0 +1,236 @@
+//! Stable hashing of the rows of a [`DataFrame`].
+use rayon::prelude::*;
+use xxhash_rust::xxh3::xxh3_64_with_seed;
+
+use crate::prelude::*;
+use crate::POOL;
+
+// Number of rows that are encoded and hashed at once.
+const BATCH_SIZE: usize = 1 << 14;
+
+const NULL_TAG: u8 = 0;
+const VALID_TAG: u8 = 1;
+
+/// The hash function of [`DataFrame::hash_rows_with`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RowHasher {
+    /// The 64-bit variant of [XXH3](https://github.com/Cyan4973/xxHash).
+    #[default]
+    Xxh3,
+}
+
+impl RowHasher {
+    fn hash(self, bytes: &[u8], seed: u64) -> u64 {
+        match self {
+            RowHasher::Xxh3 => xxh3_64_with_seed(bytes, seed),
+        }
+    }
+}
+
+fn encode_nullable<T>(
+    bufs: &mut [Vec<u8>],
+    values: impl Iterator<Item = Option<T>>,
+    mut encode: impl FnMut(&mut Vec<u8>, T),
+) {
+    for (buf, value) in bufs.iter_mut().zip(values) {
+        match value {
+            None => buf.push(NULL_TAG),
+            Some(value) => {
+                buf.push(VALID_TAG);
+                encode(buf, value)
+            },
+        }
+    }
+}
+
+fn encode_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+    buf.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+    buf.extend_from_slice(bytes);
+}
+
+fn encode_series(buf: &mut Vec<u8>, s: Series) -> PolarsResult<()> {
+    let mut values = vec![vec![]; s.len()];
+    encode_column(&s, &mut values)?;
+    buf.extend_from_slice(&(s.len() as u64).to_le_bytes());
+    for value in values {
+        buf.extend_from_slice(&value);
+    }
+    Ok(())
+}
+
+/// Append the canonical encoding of the values of `s` to the buffers of their rows.
+fn encode_column(s: &Series, bufs: &mut [Vec<u8>]) -> PolarsResult<()> {
+    macro_rules! encode_int {
+        ($ca:expr) => {
+            encode_nullable(bufs, $ca.iter(), |buf, v| {
+                buf.extend_from_slice(&v.to_le_bytes())
+            })
+        };
+    }
+
+    use DataType::*;
+    match s.dtype() {
+        Null => encode_nullable(bufs, std::iter::repeat(None::<()>), |_, _| {}),
+        Boolean => encode_nullable(bufs, s.bool()?.iter(), |buf, v| buf.push(v as u8)),
+        UInt8
+        | UInt16
+        | UInt32
+        | UInt64
+        | Int8
+        | Int16
+        | Int32
+        | Int64
+        | Date
+        | Datetime(_, _)
+        | Duration(_)
+        | Time => {
+            let s = s.to_physical_repr();
+            with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                encode_int!(ca)
+            })
+        },
+        // Normalize -0.0 to 0.0 and all NaNs to one NaN, so that equal values hash equally.
+        Float32 => encode_nullable(bufs, s.f32()?.iter(), |buf, v| {
+            let v = if v.is_nan() { f32::NAN } else { v + 0.0 };
+            buf.extend_from_slice(&v.to_bits().to_le_bytes())
+        }),
+        Float64 => encode_nullable(bufs, s.f64()?.iter(), |buf, v| {
+            let v = if v.is_nan() { f64::NAN } else { v + 0.0 };
+            buf.extend_from_slice(&v.to_bits().to_le_bytes())
+        }),
+        String => encode_nullable(bufs, s.str()?.iter(), |buf, v| {
+            encode_bytes(buf, v.as_bytes())
+        }),
+        Binary => encode_nullable(bufs, s.binary()?.iter(), encode_bytes),
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) | Enum(_, _) => encode_column(&s.cast(&String)?, bufs)?,
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => encode_int!(s.decimal()?),
+        List(_) => {
+            let mut result = Ok(());
+            encode_nullable(bufs, s.list()?.into_iter(), |buf, inner| {
+                if result.is_ok() {
+                    result = encode_series(buf, inner)
+                }
+            });
+            result?
+        },
+        #[cfg(feature = "dtype-array")]
+        Array(_, _) => {
+            let mut result = Ok(());
+            encode_nullable(bufs, s.array()?.into_iter(), |buf, inner| {
+                if result.is_ok() {
+                    result = encode_series(buf, inner)
+                }
+            });
+            result?
+        },
+        #[cfg(feature = "dtype-struct")]
+        Struct(_) => {
+            for field in s.struct_()?.fields() {
+                encode_column(field, bufs)?
+            }
+        },
+        dt => polars_bail!(opq = hash_rows_with, dt),
+    }
+    Ok(())
+}
+
+impl DataFrame {
+    /// Hash the rows with a documented algorithm, so that the hashes are the same across
+    /// processes, platforms, Polars versions and chunkings of the data. Use this e.g. to
+    /// assign rows to partitions or buckets that are written by different processes.
+    ///
+    /// Every value of a row is encoded as a null tag byte (`0` for null, `1` otherwise),
+    /// followed, if it is not null, by:
+    ///
+    /// * booleans: a single byte, `0` or `1`;
+    /// * integers, decimals and temporal types: the little-endian bytes of their physical
+    ///   integer;
+    /// * floats: the little-endian bytes of their IEEE 754 bits, after `-0.0` is normalized
+    ///   to `0.0` and every NaN to the canonical quiet NaN;
+    /// * strings and binary: the length as a little-endian `u64`, followed by the bytes,
+    ///   categoricals and enums are encoded as their strings;
+    /// * lists and arrays: the number of elements as a little-endian `u64`, followed by the
+    ///   encoded elements.
+    ///
+    /// A struct contributes the encoded values of its fields instead of a value of its own.
+    /// The hash of a row is `hasher` applied to the concatenated encodings of its values in
+    /// column order, seeded with `seed`. Column names don't affect the hash.
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => [1, 2, 1], "b" => ["x", "y", "x"])?;
+    /// let hashes = df.hash_rows_with(42, RowHasher::Xxh3)?;
+    /// assert_eq!(hashes.get(0), hashes.get(2));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn hash_rows_with(&self, seed: u64, hasher: RowHasher) -> PolarsResult<UInt64Chunked> {
+        let height = self.height();
+        let batches = POOL.install(|| {
+            (0..height)
+                .into_par_iter()
+                .step_by(BATCH_SIZE)
+                .map(|offset| {
+                    let batch = self.slice(offset as i64, BATCH_SIZE);
+                    let mut bufs = vec![vec![]; batch.height()];
+                    for s in batch.get_columns() {
+                        encode_column(s, &mut bufs)?;
+                    }
+                    Ok(bufs
+                        .iter()
+                        .map(|buf| hasher.hash(buf, seed))
+                        .collect::<Vec<_>>())
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        let hashes = batches.into_iter().flatten().collect::<Vec<_>>();
+        Ok(UInt64Chunked::from_vec("hash", hashes))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hash_rows_with_is_stable() -> PolarsResult<()> {
+        let df = df!(
+            "int" => [Some(1i64), None, Some(3)],
+            "float" => [Some(-0.0f64), Some(f64::NAN), None],
+            "str" => [Some("a"), Some("bc"), None],
+        )?;
+        let hash = |df: &DataFrame, seed| -> PolarsResult<Vec<Option<u64>>> {
+            Ok(df
+                .hash_rows_with(seed, RowHasher::Xxh3)?
+                .into_iter()
+                .collect())
+        };
+        let hashes = hash(&df, 0)?;
+
+        // Hashing doesn't depend on the chunks.
+        let mut chunked = df.slice(0, 1);
+        chunked.vstack_mut(&df.slice(1, 2))?;
+        assert_eq!(hash(&chunked, 0)?, hashes);
+
+        // The hash of a row is that of its documented encoding.
+        let mut row = vec![1];
+        row.extend_from_slice(&1i64.to_le_bytes());
+        row.push(1);
+        row.extend_from_slice(&0.0f64.to_bits().to_le_bytes());
+        row.push(1);
+        row.extend_from_slice(&1u64.to_le_bytes());
+        row.extend_from_slice(b"a");
+        assert_eq!(hashes[0], Some(xxh3_64_with_seed(&row, 0)));
+        let mut row = vec![1];
+        row.extend_from_slice(&3i64.to_le_bytes());
+        row.extend_from_slice(&[0, 0]);
+        assert_eq!(hashes[2], Some(xxh3_64_with_seed(&row, 0)));
+
+        // The seed changes the hashes.
+        assert_ne!(hash(&df, 1)?, hashes);
+        Ok(())
+    }
+}
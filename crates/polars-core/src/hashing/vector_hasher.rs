@@ -166,7 +166,7 @@ vec_hash_numeric!(UInt16Chunked);
 vec_hash_numeric!(UInt8Chunked);
 vec_hash_numeric!(Float64Chunked);
 vec_hash_numeric!(Float32Chunked);
-#[cfg(feature = "dtype-decimal")]
+#[cfg(feature = "dtype-i128")]
 vec_hash_numeric!(Int128Chunked);
 
 impl VecHash for StringChunked {
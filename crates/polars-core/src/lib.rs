@@ -6,6 +6,7 @@ extern crate core;
 
 #[macro_use]
 pub mod utils;
+pub mod cancel;
 pub mod chunked_array;
 pub mod config;
 pub mod datatypes;
@@ -26,17 +27,22 @@ pub mod series;
 pub mod testing;
 #[cfg(test)]
 mod tests;
+#[cfg(not(target_family = "wasm"))]
+mod thread_pool;
 
 use std::sync::Mutex;
+#[cfg(not(target_family = "wasm"))]
 use std::time::{SystemTime, UNIX_EPOCH};
 
 pub use hashing::IdBuildHasher;
 use once_cell::sync::Lazy;
-use rayon::{ThreadPool, ThreadPoolBuilder};
 
 #[cfg(feature = "dtype-categorical")]
 pub use crate::chunked_array::logical::categorical::string_cache::*;
+#[cfg(not(target_family = "wasm"))]
+pub use crate::thread_pool::{install_thread_pool, PolarsThreadPool};
 
+#[cfg(not(target_family = "wasm"))]
 pub static PROCESS_ID: Lazy<u128> = Lazy::new(|| {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -44,24 +50,13 @@ pub static PROCESS_ID: Lazy<u128> = Lazy::new(|| {
         .as_nanos()
 });
 
+// wasm32-unknown-unknown has no clock, and an instance never shares memory with another one
+#[cfg(target_family = "wasm")]
+pub static PROCESS_ID: Lazy<u128> = Lazy::new(|| 0);
+
 // this is re-exported in utils for polars child crates
 #[cfg(not(target_family = "wasm"))] // only use this on non wasm targets
-pub static POOL: Lazy<ThreadPool> = Lazy::new(|| {
-    let thread_name = std::env::var("POLARS_THREAD_NAME").unwrap_or_else(|_| "polars".to_string());
-    ThreadPoolBuilder::new()
-        .num_threads(
-            std::env::var("POLARS_MAX_THREADS")
-                .map(|s| s.parse::<usize>().expect("integer"))
-                .unwrap_or_else(|_| {
-                    std::thread::available_parallelism()
-                        .unwrap_or(std::num::NonZeroUsize::new(1).unwrap())
-                        .get()
-                }),
-        )
-        .thread_name(move |i| format!("{}-{}", thread_name, i))
-        .build()
-        .expect("could not spawn threads")
-});
+pub static POOL: PolarsThreadPool = PolarsThreadPool::new();
 
 #[cfg(target_family = "wasm")] // instead use this on wasm targets
 pub static POOL: Lazy<polars_utils::wasm::Pool> = Lazy::new(|| polars_utils::wasm::Pool);
@@ -38,6 +38,8 @@ impl_named_from_owned!(Vec<i8>, Int8Type);
 impl_named_from_owned!(Vec<i16>, Int16Type);
 impl_named_from_owned!(Vec<i32>, Int32Type);
 impl_named_from_owned!(Vec<i64>, Int64Type);
+#[cfg(feature = "dtype-i128")]
+impl_named_from_owned!(Vec<i128>, Int128Type);
 #[cfg(feature = "dtype-u8")]
 impl_named_from_owned!(Vec<u8>, UInt8Type);
 #[cfg(feature = "dtype-u16")]
@@ -77,6 +79,8 @@ impl_named_from!([i8], Int8Type, from_slice);
 impl_named_from!([i16], Int16Type, from_slice);
 impl_named_from!([i32], Int32Type, from_slice);
 impl_named_from!([i64], Int64Type, from_slice);
+#[cfg(feature = "dtype-i128")]
+impl_named_from!([i128], Int128Type, from_slice);
 impl_named_from!([f32], Float32Type, from_slice);
 impl_named_from!([f64], Float64Type, from_slice);
 impl_named_from!([Option<String>], StringType, from_slice_options);
@@ -94,6 +98,8 @@ impl_named_from!([Option<i8>], Int8Type, from_slice_options);
 impl_named_from!([Option<i16>], Int16Type, from_slice_options);
 impl_named_from!([Option<i32>], Int32Type, from_slice_options);
 impl_named_from!([Option<i64>], Int64Type, from_slice_options);
+#[cfg(feature = "dtype-i128")]
+impl_named_from!([Option<i128>], Int128Type, from_slice_options);
 impl_named_from!([Option<f32>], Float32Type, from_slice_options);
 impl_named_from!([Option<f64>], Float64Type, from_slice_options);
 
@@ -35,13 +35,15 @@ pub use crate::chunked_array::ChunkedArray;
 pub use crate::datatypes::string_cache::StringCacheHolder;
 pub use crate::datatypes::{ArrayCollectIterExt, *};
 pub use crate::error::{
-    polars_bail, polars_ensure, polars_err, polars_warn, PolarsError, PolarsResult,
+    polars_bail, polars_ensure, polars_err, polars_warn, ErrorCode, PolarsError, PolarsResult,
 };
 pub use crate::frame::explode::UnpivotArgs;
 #[cfg(feature = "algorithm_group_by")]
 pub(crate) use crate::frame::group_by::aggregations::*;
 #[cfg(feature = "algorithm_group_by")]
 pub use crate::frame::group_by::*;
+#[cfg(feature = "row_hash")]
+pub use crate::frame::RowHasher;
 pub use crate::frame::{DataFrame, UniqueKeepStrategy};
 pub use crate::hashing::VecHash;
 pub use crate::named_from::{NamedFrom, NamedFromOwned};
@@ -1,5 +1,6 @@
 use std::cell::RefCell;
 
+use arrow::bitmap::Bitmap;
 use serde::ser::SerializeMap;
 use serde::{Serialize, Serializer};
 
@@ -19,7 +20,7 @@ where
     I: IntoIterator,
     <I as IntoIterator>::Item: Serialize,
 {
-    fn new(iter: I) -> Self {
+    pub(super) fn new(iter: I) -> Self {
         IterSer {
             iter: RefCell::new(Some(iter)),
         }
@@ -43,6 +44,57 @@ where
     }
 }
 
+/// Bytes that are serialized with [`Serializer::serialize_bytes`] instead of as a sequence.
+pub(super) struct Bytes<'a>(pub(super) &'a [u8]);
+
+impl Serialize for Bytes<'_> {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// The compact representation of numeric values: the validity bitmap, if there are nulls,
+/// followed by the values buffer, both as their raw bytes in memory (little-endian).
+#[derive(Serialize)]
+#[serde(rename = "CompactValues")]
+struct CompactValuesRef<'a>(Option<Bytes<'a>>, Bytes<'a>);
+
+/// Serializes numeric values in the compact representation.
+struct CompactSer<'a, T: PolarsNumericType>(&'a ChunkedArray<T>);
+
+impl<T: PolarsNumericType> Serialize for CompactSer<'_, T> {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        let ca = self.0.rechunk();
+        let arr = ca.downcast_iter().next();
+        let values = arr.map_or(&[][..], |arr| arr.values().as_slice());
+        // The bitmap must start at the first bit of its first byte.
+        let validity = arr.and_then(|arr| arr.validity()).map(|bitmap| {
+            if bitmap.as_slice().1 == 0 {
+                bitmap.clone()
+            } else {
+                bitmap.iter().collect::<Bitmap>()
+            }
+        });
+        CompactValuesRef(
+            validity.as_ref().map(|bitmap| Bytes(bitmap.as_slice().0)),
+            Bytes(bytemuck::cast_slice(values)),
+        )
+        .serialize(serializer)
+    }
+}
+
 fn serialize_impl<T, S>(
     serializer: S,
     name: &str,
@@ -55,11 +107,16 @@ where
     T::Native: Serialize,
     S: Serializer,
 {
+    let human_readable = serializer.is_human_readable();
     let mut state = serializer.serialize_map(Some(4))?;
     state.serialize_entry("name", name)?;
     state.serialize_entry("datatype", dtype)?;
     state.serialize_entry("bit_settings", &bit_settings)?;
-    state.serialize_entry("values", &IterSer::new(ca.iter()))?;
+    if human_readable {
+        state.serialize_entry("values", &IterSer::new(ca.iter()))?;
+    } else {
+        state.serialize_entry("values", &CompactSer(ca))?;
+    }
     state.end()
 }
 
@@ -1,3 +1,10 @@
+//! [`Serialize`](serde::Serialize) and [`Deserialize`](serde::Deserialize) implementations of
+//! [`Series`](crate::prelude::Series) and [`DataFrame`](crate::prelude::DataFrame).
+//!
+//! A series is serialized as a map with its `name`, `datatype`, `bit_settings` and `values`.
+//! Human-readable formats, like JSON, get the values as a sequence in which nulls are `null`.
+//! Other formats, like bincode, get the values of numeric and temporal types as their raw
+//! validity and values buffers, which is much smaller and faster to read.
 pub mod chunked_array;
 mod df;
 pub mod series;
@@ -145,6 +152,85 @@ mod test {
         let out = serde_json::from_str::<DataFrame>(&df_str).unwrap();
         assert!(df.equals_missing(&out));
     }
+    fn all_dtypes_dataframe() -> PolarsResult<DataFrame> {
+        #[allow(unused_mut)]
+        let mut columns = vec![
+            Series::new("i32", &[Some(1i32), None, Some(-3)]),
+            Series::new("u64", &[Some(u64::MAX), Some(0), None]),
+            Series::new("f32", &[Some(1.5f32), None, Some(3.25)]),
+            Series::new("f64", &[None, Some(-0.5f64), Some(2.0)]),
+            Series::new("bool", &[Some(true), None, Some(false)]),
+            Series::new("str", &[Some("a"), Some(""), None]),
+            Series::new("binary", &[Some(&b"ab"[..]), None, Some(&b""[..])]),
+            Series::new_null("null", 3),
+            Series::new(
+                "list",
+                &[
+                    Series::new("", &[Some(1i64), None]),
+                    Series::new("", &[3i64]),
+                    Series::new("", Vec::<i64>::new()),
+                ],
+            ),
+        ];
+        #[cfg(feature = "dtype-datetime")]
+        columns.push(
+            Series::new("datetime", &[Some(1i64), None, Some(3)])
+                .cast(&DataType::Datetime(TimeUnit::Microseconds, None))?,
+        );
+        #[cfg(feature = "dtype-date")]
+        columns.push(Series::new("date", &[Some(1i32), Some(2), None]).cast(&DataType::Date)?);
+        #[cfg(feature = "dtype-decimal")]
+        columns.push(
+            Int128Chunked::new("decimal", &[Some(12345i128), None, Some(-1)])
+                .into_decimal(Some(10), 2)?
+                .into_series(),
+        );
+        #[cfg(feature = "dtype-categorical")]
+        columns.push(
+            Series::new("categorical", &[Some("x"), None, Some("y")])
+                .cast(&DataType::Categorical(None, CategoricalOrdering::Physical))?,
+        );
+        // Offset the numeric values, so that validity bitmaps don't start at a byte boundary.
+        let df = DataFrame::new(columns)?;
+        let mut sliced = df.slice(1, 2);
+        sliced.vstack_mut(&df.slice(0, 1))?;
+        Ok(sliced)
+    }
+
+    #[test]
+    fn test_serde_all_dtypes() -> PolarsResult<()> {
+        // Categoricals only compare equal to the deserialized ones with the global string cache.
+        #[cfg(feature = "dtype-categorical")]
+        let _lock = crate::SINGLE_LOCK.lock();
+        #[cfg(feature = "dtype-categorical")]
+        {
+            crate::disable_string_cache();
+            crate::enable_string_cache();
+        }
+        let df = all_dtypes_dataframe()?;
+
+        let json = serde_json::to_string(&df).unwrap();
+        let out = serde_json::from_str::<DataFrame>(&json).unwrap();
+        assert!(df.equals_missing(&out));
+        assert_eq!(df.schema(), out.schema());
+
+        let bytes = bincode::serialize(&df).unwrap();
+        let out = bincode::deserialize::<DataFrame>(&bytes).unwrap();
+        assert!(df.equals_missing(&out));
+        assert_eq!(df.schema(), out.schema());
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_bincode_is_compact() {
+        let s = Series::new("a", (0..1000i64).collect::<Vec<_>>());
+        let bytes = bincode::serialize(&s).unwrap();
+        // The values are written as one buffer, without a tag per value.
+        assert!(bytes.len() < 1000 * 8 + 100);
+        let out = bincode::deserialize::<Series>(&bytes).unwrap();
+        assert!(s.equals_missing(&out));
+    }
+
     /// test using the `DeserializedOwned` trait
     #[test]
     fn test_serde_df_owned_bincode() {
@@ -1,15 +1,98 @@
 use std::borrow::Cow;
 use std::fmt::Formatter;
 
-use serde::de::{MapAccess, Visitor};
-use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use arrow::bitmap::Bitmap;
+use serde::de::{MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
 
+use super::chunked_array::IterSer;
 #[cfg(feature = "dtype-array")]
 use crate::chunked_array::builder::get_fixed_size_list_builder;
 use crate::chunked_array::builder::AnonymousListBuilder;
 use crate::chunked_array::metadata::MetadataFlags;
 use crate::prelude::*;
 
+/// Bytes that were serialized with [`Serializer::serialize_bytes`], or as a sequence by
+/// formats without a bytes type.
+struct ByteBuf(Vec<u8>);
+
+impl<'de> Deserialize<'de> for ByteBuf {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ByteBufVisitor;
+
+        impl<'de> Visitor<'de> for ByteBufVisitor {
+            type Value = ByteBuf;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("bytes")
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> std::result::Result<ByteBuf, E> {
+                Ok(ByteBuf(v.to_vec()))
+            }
+
+            fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> std::result::Result<ByteBuf, E> {
+                Ok(ByteBuf(v))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> std::result::Result<ByteBuf, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                Ok(ByteBuf(bytes))
+            }
+        }
+
+        deserializer.deserialize_byte_buf(ByteBufVisitor)
+    }
+}
+
+/// The compact representation of numeric values, see `CompactValuesRef`.
+#[derive(Deserialize)]
+struct CompactValues(Option<ByteBuf>, ByteBuf);
+
+/// Deserialize the values of a numeric series, which are compact if the format isn't human
+/// readable.
+fn numeric_values<'de, A, T>(
+    map: &mut A,
+    name: &str,
+    compact: bool,
+) -> std::result::Result<ChunkedArray<T>, A::Error>
+where
+    A: MapAccess<'de>,
+    T: PolarsNumericType,
+    T::Native: Deserialize<'de>,
+{
+    if !compact {
+        let values: Vec<Option<T::Native>> = map.next_value()?;
+        return Ok(ChunkedArray::from_iter_options(name, values.into_iter()));
+    }
+
+    let CompactValues(validity, values) = map.next_value()?;
+    if values.0.len() % std::mem::size_of::<T::Native>() != 0 {
+        return Err(de::Error::invalid_length(
+            values.0.len(),
+            &"a multiple of the size of the values",
+        ));
+    }
+    let values: Vec<T::Native> = bytemuck::allocation::pod_collect_to_vec(&values.0);
+    let validity = validity
+        .map(|bytes| Bitmap::try_new(bytes.0, values.len()))
+        .transpose()
+        .map_err(de::Error::custom)?;
+    let arr = PrimitiveArray::try_new(T::get_dtype().to_arrow(true), values.into(), validity)
+        .map_err(de::Error::custom)?;
+    Ok(ChunkedArray::with_chunk(name, arr))
+}
+
 impl Serialize for Series {
     fn serialize<S>(
         &self,
@@ -70,12 +153,34 @@ impl Serialize for Series {
                 let ca = self.time().unwrap();
                 ca.serialize(serializer)
             },
-            dt => {
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => {
+                let ca = self.i128().unwrap();
+                ca.serialize(serializer)
+            },
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => {
+                let ca = self.decimal().unwrap();
+                ca.serialize(serializer)
+            },
+            DataType::Null => {
+                let mut state = serializer.serialize_map(Some(4))?;
+                state.serialize_entry("name", self.name())?;
+                state.serialize_entry("datatype", self.dtype())?;
+                state.serialize_entry("bit_settings", &self.get_flags())?;
+                let values = std::iter::repeat(None::<()>).take(self.len());
+                state.serialize_entry("values", &IterSer::new(values))?;
+                state.end()
+            },
+            dt if dt.is_numeric() => {
                 with_match_physical_numeric_polars_type!(dt, |$T| {
                 let ca: &ChunkedArray<$T> = self.as_ref().as_ref().as_ref();
                 ca.serialize(serializer)
                 })
             },
+            dt => Err(ser::Error::custom(format!(
+                "serialization of dtype {dt} is not supported"
+            ))),
         }
     }
 }
@@ -87,7 +192,9 @@ impl<'de> Deserialize<'de> for Series {
     {
         const FIELDS: &[&str] = &["name", "datatype", "bit_settings", "values"];
 
-        struct SeriesVisitor;
+        struct SeriesVisitor {
+            compact: bool,
+        }
 
         impl<'de> Visitor<'de> for SeriesVisitor {
             type Value = Series;
@@ -135,76 +242,82 @@ impl<'de> Deserialize<'de> for Series {
 
                 let mut s = match dtype {
                     #[cfg(feature = "dtype-i8")]
-                    DataType::Int8 => {
-                        let values: Vec<Option<i8>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
-                    },
+                    DataType::Int8 => numeric_values::<_, Int8Type>(&mut map, &name, self.compact)
+                        .map(|ca| ca.into_series()),
                     #[cfg(feature = "dtype-u8")]
                     DataType::UInt8 => {
-                        let values: Vec<Option<u8>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
+                        numeric_values::<_, UInt8Type>(&mut map, &name, self.compact)
+                            .map(|ca| ca.into_series())
                     },
                     #[cfg(feature = "dtype-i16")]
                     DataType::Int16 => {
-                        let values: Vec<Option<i16>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
+                        numeric_values::<_, Int16Type>(&mut map, &name, self.compact)
+                            .map(|ca| ca.into_series())
                     },
                     #[cfg(feature = "dtype-u16")]
                     DataType::UInt16 => {
-                        let values: Vec<Option<u16>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
+                        numeric_values::<_, UInt16Type>(&mut map, &name, self.compact)
+                            .map(|ca| ca.into_series())
                     },
                     DataType::Int32 => {
-                        let values: Vec<Option<i32>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
+                        numeric_values::<_, Int32Type>(&mut map, &name, self.compact)
+                            .map(|ca| ca.into_series())
                     },
                     DataType::UInt32 => {
-                        let values: Vec<Option<u32>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
+                        numeric_values::<_, UInt32Type>(&mut map, &name, self.compact)
+                            .map(|ca| ca.into_series())
                     },
                     DataType::Int64 => {
-                        let values: Vec<Option<i64>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
+                        numeric_values::<_, Int64Type>(&mut map, &name, self.compact)
+                            .map(|ca| ca.into_series())
+                    },
+                    #[cfg(feature = "dtype-i128")]
+                    DataType::Int128 => {
+                        numeric_values::<_, Int128Type>(&mut map, &name, self.compact)
+                            .map(|ca| ca.into_series())
                     },
                     DataType::UInt64 => {
-                        let values: Vec<Option<u64>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
+                        numeric_values::<_, UInt64Type>(&mut map, &name, self.compact)
+                            .map(|ca| ca.into_series())
                     },
                     #[cfg(feature = "dtype-date")]
-                    DataType::Date => {
-                        let values: Vec<Option<i32>> = map.next_value()?;
-                        Ok(Series::new(&name, values).cast(&DataType::Date).unwrap())
-                    },
+                    DataType::Date => numeric_values::<_, Int32Type>(&mut map, &name, self.compact)
+                        .map(|ca| ca.into_date().into_series()),
                     #[cfg(feature = "dtype-datetime")]
                     DataType::Datetime(tu, tz) => {
-                        let values: Vec<Option<i64>> = map.next_value()?;
-                        Ok(Series::new(&name, values)
-                            .cast(&DataType::Datetime(tu, tz))
-                            .unwrap())
+                        numeric_values::<_, Int64Type>(&mut map, &name, self.compact)
+                            .map(|ca| ca.into_datetime(tu, tz).into_series())
                     },
                     #[cfg(feature = "dtype-duration")]
                     DataType::Duration(tu) => {
-                        let values: Vec<Option<i64>> = map.next_value()?;
-                        Ok(Series::new(&name, values)
-                            .cast(&DataType::Duration(tu))
-                            .unwrap())
+                        numeric_values::<_, Int64Type>(&mut map, &name, self.compact)
+                            .map(|ca| ca.into_duration(tu).into_series())
                     },
                     #[cfg(feature = "dtype-time")]
-                    DataType::Time => {
-                        let values: Vec<Option<i64>> = map.next_value()?;
-                        Ok(Series::new(&name, values).cast(&DataType::Time).unwrap())
+                    DataType::Time => numeric_values::<_, Int64Type>(&mut map, &name, self.compact)
+                        .map(|ca| ca.into_time().into_series()),
+                    #[cfg(feature = "dtype-decimal")]
+                    DataType::Decimal(precision, scale) => {
+                        numeric_values::<_, Int128Type>(&mut map, &name, self.compact).map(|ca| {
+                            ca.into_decimal_unchecked(precision, scale.unwrap_or(0))
+                                .into_series()
+                        })
                     },
                     DataType::Boolean => {
                         let values: Vec<Option<bool>> = map.next_value()?;
                         Ok(Series::new(&name, values))
                     },
                     DataType::Float32 => {
-                        let values: Vec<Option<f32>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
+                        numeric_values::<_, Float32Type>(&mut map, &name, self.compact)
+                            .map(|ca| ca.into_series())
                     },
                     DataType::Float64 => {
-                        let values: Vec<Option<f64>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
+                        numeric_values::<_, Float64Type>(&mut map, &name, self.compact)
+                            .map(|ca| ca.into_series())
+                    },
+                    DataType::Null => {
+                        let values: Vec<Option<()>> = map.next_value()?;
+                        Ok(Series::new_null(&name, values.len()))
                     },
                     DataType::String => {
                         let values: Vec<Option<Cow<str>>> = map.next_value()?;
@@ -264,9 +377,9 @@ impl<'de> Deserialize<'de> for Series {
                         let values: Vec<Option<Cow<str>>> = map.next_value()?;
                         Ok(Series::new(&name, values).cast(&dt).unwrap())
                     },
-                    dt => {
-                        panic!("{dt:?} dtype deserialization not yet implemented")
-                    },
+                    dt => Err(de::Error::custom(format!(
+                        "deserialization of dtype {dt} is not supported"
+                    ))),
                 }?;
 
                 if let Some(f) = bit_settings {
@@ -276,6 +389,7 @@ impl<'de> Deserialize<'de> for Series {
             }
         }
 
-        deserializer.deserialize_map(SeriesVisitor)
+        let compact = !deserializer.is_human_readable();
+        deserializer.deserialize_map(SeriesVisitor { compact })
     }
 }
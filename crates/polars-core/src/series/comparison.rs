@@ -56,6 +56,8 @@ macro_rules! impl_compare {
             Int16 => lhs.i16().unwrap().$method(rhs.i16().unwrap()),
             Int32 => lhs.i32().unwrap().$method(rhs.i32().unwrap()),
             Int64 => lhs.i64().unwrap().$method(rhs.i64().unwrap()),
+            #[cfg(feature = "dtype-i128")]
+            Int128 => lhs.i128().unwrap().$method(rhs.i128().unwrap()),
             Float32 => lhs.f32().unwrap().$method(rhs.f32().unwrap()),
             Float64 => lhs.f64().unwrap().$method(rhs.f64().unwrap()),
             List(_) => lhs.list().unwrap().$method(rhs.list().unwrap()),
@@ -1,6 +1,6 @@
 use arrow::compute::cast::cast_unchecked as cast;
 use arrow::datatypes::Metadata;
-#[cfg(any(feature = "dtype-struct", feature = "dtype-categorical"))]
+#[cfg(feature = "dtype-struct")]
 use arrow::legacy::kernels::concatenate::concatenate_owned_unchecked;
 #[cfg(any(
     feature = "dtype-date",
@@ -9,6 +9,7 @@ use arrow::legacy::kernels::concatenate::concatenate_owned_unchecked;
     feature = "dtype-duration"
 ))]
 use arrow::temporal_conversions::*;
+use arrow::with_match_primitive_type_full;
 use polars_error::feature_gated;
 
 use crate::chunked_array::cast::{cast_chunks, CastOptions};
@@ -43,6 +44,8 @@ impl Series {
             Int16 => Int16Chunked::from_chunks(name, chunks).into_series(),
             Int32 => Int32Chunked::from_chunks(name, chunks).into_series(),
             Int64 => Int64Chunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-i128")]
+            Int128 => Int128Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-u8")]
             UInt8 => UInt8Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-u16")]
@@ -292,13 +295,8 @@ impl Series {
             },
             #[cfg(feature = "dtype-categorical")]
             ArrowDataType::Dictionary(key_type, value_type, _) => {
+                use arrow::compute::concatenate::concatenate_dictionaries;
                 use arrow::datatypes::IntegerType;
-                // don't spuriously call this; triggers a read on mmapped data
-                let arr = if chunks.len() > 1 {
-                    concatenate_owned_unchecked(&chunks)?
-                } else {
-                    chunks[0].clone()
-                };
 
                 if !matches!(
                     value_type.as_ref(),
@@ -312,14 +310,36 @@ impl Series {
                     );
                 }
 
+                // Multiple chunks are concatenated by merging their dictionaries, so a value that
+                // occurs in many chunks is stored once.
+                let data_type =
+                    ArrowDataType::Dictionary(*key_type, Box::new(ArrowDataType::Utf8View), false);
                 macro_rules! unpack_keys_values {
                     ($dt:ty) => {{
-                        let arr = arr.as_any().downcast_ref::<DictionaryArray<$dt>>().unwrap();
-                        let keys = arr.keys();
-                        let keys = cast(keys, &ArrowDataType::UInt32).unwrap();
-                        let values = arr.values();
-                        let values = cast(&**values, &ArrowDataType::Utf8View)?;
-                        (keys, values)
+                        let arrays = chunks
+                            .iter()
+                            .map(|arr| {
+                                let arr =
+                                    arr.as_any().downcast_ref::<DictionaryArray<$dt>>().unwrap();
+                                let values = cast(&**arr.values(), &ArrowDataType::Utf8View)?;
+                                // SAFETY: casting the values doesn't change their length.
+                                unsafe {
+                                    DictionaryArray::<$dt>::try_new_unchecked(
+                                        data_type.clone(),
+                                        arr.keys().clone(),
+                                        values,
+                                    )
+                                }
+                            })
+                            .collect::<PolarsResult<Vec<_>>>()?;
+                        // don't spuriously call this; triggers a read on mmapped data
+                        let arr = if arrays.len() > 1 {
+                            concatenate_dictionaries(&arrays.iter().collect::<Vec<_>>())?
+                        } else {
+                            arrays.into_iter().next().unwrap()
+                        };
+                        let keys = cast(arr.keys(), &ArrowDataType::UInt32).unwrap();
+                        (keys, arr.values().clone())
                     }};
                 }
 
@@ -354,11 +374,20 @@ impl Series {
 
                 if let Some(metadata) = md {
                     if metadata.get(DTYPE_ENUM_KEY) == Some(&DTYPE_ENUM_VALUE.into()) {
+                        let keys = UInt32Chunked::with_chunk(name, keys.clone());
+                        let (keys, categories) = match metadata.get(DTYPE_ENUM_VALUES) {
+                            Some(categories) => {
+                                let categories = decode_enum_categories(categories)?;
+                                let keys = remap_enum_keys(keys, values, &categories)?;
+                                (keys, categories)
+                            },
+                            None => (keys, values.clone()),
+                        };
                         // SAFETY:
-                        // the invariants of an Arrow Dictionary guarantee the keys are in bounds
+                        // the keys are in bounds of the categories
                         return Ok(CategoricalChunked::from_cats_and_rev_map_unchecked(
-                            UInt32Chunked::with_chunk(name, keys.clone()),
-                            Arc::new(RevMapping::build_local(values.clone())),
+                            keys,
+                            Arc::new(RevMapping::build_local(categories)),
                             true,
                             Default::default(),
                         )
@@ -396,6 +425,17 @@ impl Series {
                 };
                 Ok(s)
             },
+            ArrowDataType::Extension(ext_name, storage, _) => {
+                let Some(dtype) = extension_type_storage(ext_name) else {
+                    polars_bail!(ComputeError: "extension type '{}' is not registered", ext_name)
+                };
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| to_extension_storage(arr.as_ref(), storage))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let s = Self::_try_from_arrow_unchecked_with_md(name, chunks, storage, md)?;
+                s.strict_cast(&dtype)
+            },
             #[cfg(feature = "dtype-struct")]
             ArrowDataType::Struct(logical_fields) => {
                 // We don't have to convert inner types, as that already
@@ -459,6 +499,14 @@ impl Series {
             },
             ArrowDataType::Decimal(precision, scale)
             | ArrowDataType::Decimal256(precision, scale) => {
+                #[cfg(feature = "dtype-i128")]
+                if *scale == 0
+                    && md.and_then(|md| md.get("pl")).map(|s| s.as_str()) == Some("maintain_type")
+                {
+                    let chunks = cast_chunks(&chunks, &DataType::Int128, CastOptions::NonStrict)?;
+                    return Ok(Int128Chunked::from_chunks(name, chunks).into_series());
+                }
+
                 #[cfg(not(feature = "dtype-decimal"))]
                 {
                     panic!("activate 'dtype-decimal'")
@@ -497,11 +545,46 @@ impl Series {
                 }
             },
             ArrowDataType::Map(_, _) => map_arrays_to_series(name, chunks),
+            // Series hold plain arrays, so the runs are decoded.
+            ArrowDataType::RunEndEncoded(field) => {
+                let chunks = decode_run_end_encoded(&chunks);
+                Self::_try_from_arrow_unchecked_with_md(name, chunks, field.data_type(), md)
+            },
             dt => polars_bail!(ComputeError: "cannot create series from {:?}", dt),
         }
     }
 }
 
+/// Map the keys of a dictionary with `values` to the positions of those values in the
+/// declared Enum `categories`.
+#[cfg(feature = "dtype-categorical")]
+fn remap_enum_keys(
+    keys: UInt32Chunked,
+    values: &Utf8ViewArray,
+    categories: &Utf8ViewArray,
+) -> PolarsResult<UInt32Chunked> {
+    if values == categories {
+        return Ok(keys);
+    }
+
+    let positions = categories
+        .values_iter()
+        .enumerate()
+        .map(|(idx, cat)| (cat, idx as u32))
+        .collect::<PlHashMap<_, _>>();
+    let new_keys = values
+        .values_iter()
+        .map(|value| {
+            positions.get(value).copied().ok_or_else(
+                || polars_err!(ComputeError: "value '{}' is not one of the Enum categories", value),
+            )
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    // Values under a null are not guaranteed to be valid keys.
+    Ok(keys.apply_values(|key| new_keys.get(key as usize).copied().unwrap_or(0)))
+}
+
 fn map_arrays_to_series(name: &str, chunks: Vec<ArrayRef>) -> PolarsResult<Series> {
     let chunks = chunks
         .iter()
@@ -527,6 +610,101 @@ fn convert<F: Fn(&dyn Array) -> ArrayRef>(arr: &[ArrayRef], f: F) -> Vec<ArrayRe
     arr.iter().map(|arr| f(&**arr)).collect()
 }
 
+fn decode_run_end_encoded(arr: &[ArrayRef]) -> Vec<ArrayRef> {
+    convert(arr, |arr| {
+        let arr = arr.as_any().downcast_ref::<RunEndEncodedArray>().unwrap();
+        arr.decode()
+    })
+}
+
+/// Relabel an array of an Arrow extension type with the extension's `storage` data type.
+fn to_extension_storage(arr: &dyn Array, storage: &ArrowDataType) -> PolarsResult<ArrayRef> {
+    fn view<T: ViewType + ?Sized>(arr: &dyn Array, storage: &ArrowDataType) -> ArrayRef {
+        let arr = arr
+            .as_any()
+            .downcast_ref::<BinaryViewArrayGeneric<T>>()
+            .unwrap();
+        // SAFETY: only the data type changes, the views and buffers stay valid.
+        unsafe {
+            BinaryViewArrayGeneric::<T>::new_unchecked_unknown_md(
+                storage.clone(),
+                arr.views().clone(),
+                arr.data_buffers().clone(),
+                arr.validity().cloned(),
+                Some(arr.total_buffer_len()),
+            )
+        }
+        .boxed()
+    }
+
+    use arrow::datatypes::PhysicalType::*;
+    let out = match arr.data_type().to_physical_type() {
+        Boolean => {
+            let arr = arr.as_any().downcast_ref::<BooleanArray>().unwrap();
+            BooleanArray::new(
+                storage.clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )
+            .boxed()
+        },
+        Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            let arr = arr.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            arr.clone().to(storage.clone()).boxed()
+        }),
+        Binary => {
+            let arr = arr.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            BinaryArray::<i32>::new(
+                storage.clone(),
+                arr.offsets().clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )
+            .boxed()
+        },
+        LargeBinary => {
+            let arr = arr.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            BinaryArray::<i64>::new(
+                storage.clone(),
+                arr.offsets().clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )
+            .boxed()
+        },
+        Utf8 => {
+            let arr = arr.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            Utf8Array::<i32>::new(
+                storage.clone(),
+                arr.offsets().clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )
+            .boxed()
+        },
+        LargeUtf8 => {
+            let arr = arr.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            Utf8Array::<i64>::new(
+                storage.clone(),
+                arr.offsets().clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )
+            .boxed()
+        },
+        BinaryView => view::<[u8]>(arr, storage),
+        Utf8View => view::<str>(arr, storage),
+        FixedSizeBinary => {
+            let arr = arr.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+            arr.clone().to(storage.clone()).boxed()
+        },
+        dt => polars_bail!(
+            ComputeError: "cannot load an extension type stored as {:?}", dt
+        ),
+    };
+    Ok(out)
+}
+
 /// Converts to physical types and bubbles up the correct [`DataType`].
 #[allow(clippy::only_used_in_recursion)]
 unsafe fn to_physical_and_dtype(
@@ -654,6 +832,9 @@ unsafe fn to_physical_and_dtype(
                 (vec![arrow_array], DataType::Struct(polars_fields))
             })
         },
+        ArrowDataType::RunEndEncoded(_) => {
+            to_physical_and_dtype(decode_run_end_encoded(&arrays), md)
+        },
         // Use Series architecture to convert nested logical types to physical.
         dt @ (ArrowDataType::Duration(_)
         | ArrowDataType::Time32(_)
@@ -27,14 +27,13 @@ impl SeriesWrap<DecimalChunked> {
     fn agg_helper<F: Fn(&Int128Chunked) -> Series>(&self, f: F) -> Series {
         let agg_s = f(&self.0);
         match agg_s.dtype() {
-            DataType::Decimal(_, _) => {
-                let ca = agg_s.decimal().unwrap();
-                let ca = ca.as_ref().clone();
+            DataType::Int128 => {
+                let ca = agg_s.i128().unwrap().clone();
                 let precision = self.0.precision();
                 let scale = self.0.scale();
                 ca.into_decimal_unchecked(precision, scale).into_series()
             },
-            DataType::List(dtype) if dtype.is_decimal() => {
+            DataType::List(dtype) if matches!(dtype.as_ref(), DataType::Int128) => {
                 let dtype = self.0.dtype();
                 let ca = agg_s.list().unwrap();
                 let arr = ca.downcast_iter().next().unwrap();
@@ -64,19 +63,6 @@ impl SeriesWrap<DecimalChunked> {
     }
 }
 
-unsafe impl IntoSeries for Int128Chunked {
-    fn into_series(self) -> Series
-    where
-        Self: Sized,
-    {
-        // this is incorrect as it ignores the datatype
-        // the caller must correct this.
-        let mut ca = DecimalChunked::new_logical(self);
-        ca.2 = Some(DataType::Decimal(None, None));
-        ca.into_series()
-    }
-}
-
 impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
     fn compute_len(&mut self) {
         self.0.compute_len()
@@ -100,7 +86,7 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
     fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
         Ok(self
             .0
-            .zip_with(mask, other.as_ref().as_ref())?
+            .zip_with(mask, &other.decimal()?.0)?
             .into_decimal_unchecked(self.0.precision(), self.0.scale())
             .into_series())
     }
@@ -165,9 +151,8 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
     fn explode_by_offsets(&self, offsets: &[i64]) -> Series {
         self.0
             .explode_by_offsets(offsets)
-            .decimal()
+            .i128()
             .unwrap()
-            .as_ref()
             .clone()
             .into_decimal_unchecked(self.0.precision(), self.0.scale())
             .into_series()
@@ -341,6 +326,14 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
             Scalar::new(self.dtype().clone(), av)
         }))
     }
+    fn mean(&self) -> Option<f64> {
+        let sum = self.0.sum()? as f64;
+        let count = (self.0.len() - self.0.null_count()) as f64;
+        if count == 0.0 {
+            return None;
+        }
+        Some(sum / count / 10f64.powi(self.0.scale() as i32))
+    }
     fn min_reduce(&self) -> PolarsResult<Scalar> {
         Ok(self.apply_physical(|ca| {
             let min = ca.min();
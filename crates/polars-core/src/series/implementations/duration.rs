@@ -6,6 +6,35 @@ use crate::chunked_array::comparison::*;
 use crate::frame::group_by::*;
 use crate::prelude::*;
 
+fn nanoseconds_per_unit(tu: TimeUnit) -> f64 {
+    match tu {
+        TimeUnit::Nanoseconds => 1.0,
+        TimeUnit::Microseconds => 1_000.0,
+        TimeUnit::Milliseconds => 1_000_000.0,
+    }
+}
+
+impl SeriesWrap<DurationChunked> {
+    /// Multiply or divide the durations by a float `rhs`, rounding the result to the nearest
+    /// integer in the time unit of `self`.
+    fn scale_by_float<F>(&self, rhs: &Series, op: F) -> PolarsResult<Series>
+    where
+        F: Fn(&Series, &Series) -> PolarsResult<Series>,
+    {
+        let lhs = self.0 .0.cast(&DataType::Float64).unwrap();
+        let rhs = rhs.cast(&DataType::Float64)?;
+        let out = op(&lhs, &rhs)?;
+        let out = out.f64().unwrap().apply_values(|v| v.round());
+        let phys = out.cast(&DataType::Int64).unwrap();
+        Ok(phys
+            .i64()
+            .unwrap()
+            .clone()
+            .into_duration(self.0.time_unit())
+            .into_series())
+    }
+}
+
 unsafe impl IntoSeries for DurationChunked {
     fn into_series(self) -> Series {
         Series(Arc::new(SeriesWrap(self)))
@@ -180,15 +209,7 @@ impl private::PrivateSeries for SeriesWrap<DurationChunked> {
                 let rhs = rhs.cast(&DataType::Int64)?;
                 self.multiply(&rhs)
             },
-            dt if dt.is_float() => {
-                let phys = &self.0 .0;
-                let phys_float = phys.cast(dt).unwrap();
-                let out = std::ops::Mul::mul(&phys_float, rhs)?
-                    .cast(&DataType::Int64)
-                    .unwrap();
-                let phys = out.i64().unwrap().clone();
-                Ok(phys.into_duration(tul).into_series())
-            },
+            dt if dt.is_float() => self.scale_by_float(rhs, |a, b| a * b),
             _ => {
                 polars_bail!(opq = mul, self.dtype(), rhs.dtype());
             },
@@ -206,8 +227,13 @@ impl private::PrivateSeries for SeriesWrap<DurationChunked> {
                     )?
                     .into_series())
                 } else {
-                    let rhs = rhs.cast(self.dtype())?;
-                    self.divide(&rhs)
+                    // Express both in nanoseconds, as casting to the coarser unit would
+                    // truncate and casting to the finer unit could overflow.
+                    let lhs =
+                        self.0 .0.cast(&DataType::Float64).unwrap() * nanoseconds_per_unit(tul);
+                    let rhs = rhs.to_physical_repr().cast(&DataType::Float64).unwrap()
+                        * nanoseconds_per_unit(*tur);
+                    std::ops::Div::div(&lhs, &rhs)
                 }
             },
             DataType::Int64 => Ok((&self.0 .0 / rhs.i64().unwrap())
@@ -217,15 +243,7 @@ impl private::PrivateSeries for SeriesWrap<DurationChunked> {
                 let rhs = rhs.cast(&DataType::Int64)?;
                 self.divide(&rhs)
             },
-            dt if dt.is_float() => {
-                let phys = &self.0 .0;
-                let phys_float = phys.cast(dt).unwrap();
-                let out = std::ops::Div::div(&phys_float, rhs)?
-                    .cast(&DataType::Int64)
-                    .unwrap();
-                let phys = out.i64().unwrap().clone();
-                Ok(phys.into_duration(tul).into_series())
-            },
+            dt if dt.is_float() => self.scale_by_float(rhs, |a, b| a / b),
             _ => {
                 polars_bail!(opq = div, self.dtype(), rhs.dtype());
             },
@@ -113,7 +113,11 @@ impl SeriesTrait for SeriesWrap<StringChunked> {
         self.0.chunks_mut()
     }
     fn shrink_to_fit(&mut self) {
-        self.0.shrink_to_fit()
+        self.0.shrink_to_fit();
+        // Only keep the bytes of the data buffers that are still referenced by the views.
+        for arr in unsafe { self.0.downcast_iter_mut() } {
+            *arr = arr.clone().gc();
+        }
     }
 
     fn slice(&self, offset: i64, length: usize) -> Series {
@@ -531,6 +531,17 @@ impl Series {
         }
     }
 
+    /// Map float values to integer keys that order by IEEE 754 totalOrder, so sorting, grouping
+    /// or joining on them tells -0 and 0 apart. All `NaN`s are equal and greater than any other
+    /// value. Other types are returned as is.
+    pub fn float_total_order_keys(&self) -> Series {
+        match self.dtype() {
+            DataType::Float32 => self.f32().unwrap().total_order_keys().into_series(),
+            DataType::Float64 => self.f64().unwrap().total_order_keys().into_series(),
+            _ => self.clone(),
+        }
+    }
+
     /// Check if float value is NaN (note this is different than missing/ null)
     pub fn is_nan(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
@@ -641,7 +652,8 @@ impl Series {
     /// Returns a Series with a single zeroed entry if self is an empty numeric series.
     ///
     /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16}` the `Series` is
-    /// first cast to `Int64` to prevent overflow issues.
+    /// first cast to `Int64` to prevent overflow issues. Cast `Int64` to `Int128` first for a
+    /// sum that can't overflow.
     pub fn sum_reduce(&self) -> PolarsResult<Scalar> {
         use DataType::*;
         match self.dtype() {
@@ -841,6 +853,15 @@ impl Series {
                 let av: AnyValue = val.into();
                 Scalar::new(dt.clone(), av)
             },
+            #[cfg(feature = "dtype-decimal")]
+            dt @ DataType::Decimal(_, _) => {
+                let ca = self.decimal().unwrap();
+                let av = match ca.mean_decimal() {
+                    Some(mean) => AnyValue::Decimal(mean, ca.scale()),
+                    None => AnyValue::Null,
+                };
+                Scalar::new(dt.clone(), av)
+            },
             dt => Scalar::new(dt.clone(), AnyValue::Null),
         }
     }
@@ -877,25 +898,39 @@ impl Series {
     ///
     /// FFI buffers are included in this estimation.
     pub fn estimated_size(&self) -> usize {
-        #[allow(unused_mut)]
-        let mut size = self
+        let size: usize = self
             .chunks()
             .iter()
             .map(|arr| estimated_bytes_size(&**arr))
             .sum();
+        size + self.estimated_dictionary_size()
+    }
+
+    /// Returns an estimation of the (heap) allocated size of the top-level validity bitmaps of
+    /// the `Series` in bytes.
+    pub fn estimated_validity_size(&self) -> usize {
+        self.chunks()
+            .iter()
+            .map(|arr| {
+                arr.validity()
+                    .map_or(0, |validity| validity.as_slice().0.len())
+            })
+            .sum()
+    }
+
+    /// Returns an estimation of the (heap) allocated size of the categories of a categorical
+    /// `Series` in bytes, which is zero for other data types.
+    pub fn estimated_dictionary_size(&self) -> usize {
         match self.dtype() {
             #[cfg(feature = "dtype-categorical")]
             DataType::Categorical(Some(rv), _) | DataType::Enum(Some(rv), _) => match &**rv {
-                RevMapping::Local(arr, _) => size += estimated_bytes_size(arr),
+                RevMapping::Local(arr, _) => estimated_bytes_size(arr),
                 RevMapping::Global(map, arr, _) => {
-                    size +=
-                        map.capacity() * std::mem::size_of::<u32>() * 2 + estimated_bytes_size(arr);
+                    map.capacity() * std::mem::size_of::<u32>() * 2 + estimated_bytes_size(arr)
                 },
             },
-            _ => {},
+            _ => 0,
         }
-
-        size
     }
 
     /// Packs every element into a list.
@@ -1079,4 +1114,25 @@ mod test {
         let _ = series.slice(-6, 2);
         let _ = series.slice(4, 2);
     }
+
+    #[test]
+    fn shrink_to_fit_compacts_views() {
+        let values = (0..1000)
+            .map(|i| format!("a long string value {i}"))
+            .collect::<Vec<_>>();
+        let mut s = Series::new("a", values).slice(10, 2);
+        let buffer_len = |s: &Series| {
+            s.str()
+                .unwrap()
+                .downcast_iter()
+                .next()
+                .unwrap()
+                .total_buffer_len()
+        };
+        assert!(buffer_len(&s) > 10_000);
+
+        s.shrink_to_fit();
+        assert_eq!(buffer_len(&s), 44);
+        assert_eq!(s.str_value(0).unwrap(), "a long string value 10");
+    }
 }
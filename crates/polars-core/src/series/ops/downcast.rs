@@ -57,6 +57,12 @@ impl Series {
         unpack_chunked!(self, DataType::Int64 => Int64Chunked, "Int64")
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype `[DataType::Int128]`
+    #[cfg(feature = "dtype-i128")]
+    pub fn i128(&self) -> PolarsResult<&Int128Chunked> {
+        unpack_chunked!(self, DataType::Int128 => Int128Chunked, "Int128")
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype `[DataType::Float32]`
     pub fn f32(&self) -> PolarsResult<&Float32Chunked> {
         unpack_chunked!(self, DataType::Float32 => Float32Chunked, "Float32")
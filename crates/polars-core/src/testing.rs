@@ -1,6 +1,14 @@
 //! Testing utilities.
+#[cfg(feature = "testing")]
+mod asserts;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+
 use std::ops::Deref;
 
+#[cfg(feature = "testing")]
+pub use asserts::*;
+
 use crate::prelude::*;
 
 impl Series {
@@ -173,6 +181,40 @@ macro_rules! assert_df_eq {
     };
 }
 
+/// Asserts that two [`Series`] are equal according to
+/// [`check_series_equal`](crate::testing::check_series_equal), with the default
+/// [`EqualOptions`](crate::testing::EqualOptions) or the given ones. Panics with a description of
+/// the first differences otherwise.
+#[cfg(feature = "testing")]
+#[macro_export]
+macro_rules! assert_series_equal {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_series_equal!($left, $right, $crate::testing::EqualOptions::default())
+    };
+    ($left:expr, $right:expr, $options:expr $(,)?) => {
+        if let Err(e) = $crate::testing::check_series_equal(&$left, &$right, &$options) {
+            panic!("series are not equal: {e}")
+        }
+    };
+}
+
+/// Asserts that two [`DataFrame`]s are equal according to
+/// [`check_frame_equal`](crate::testing::check_frame_equal), with the default
+/// [`EqualOptions`](crate::testing::EqualOptions) or the given ones. Panics with a description of
+/// the first differences otherwise.
+#[cfg(feature = "testing")]
+#[macro_export]
+macro_rules! assert_frame_equal {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_frame_equal!($left, $right, $crate::testing::EqualOptions::default())
+    };
+    ($left:expr, $right:expr, $options:expr $(,)?) => {
+        if let Err(e) = $crate::testing::check_frame_equal(&$left, &$right, &$options) {
+            panic!("frames are not equal: {e}")
+        }
+    };
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -235,4 +277,55 @@ mod test {
         assert_eq!(df3, df3);
         assert_eq!(df4, df4);
     }
+
+    #[test]
+    #[cfg(feature = "testing")]
+    fn test_check_series_equal() {
+        use crate::testing::*;
+
+        let a = Series::new("a", [1.0, 2.0, f64::NAN]);
+        let b = Series::new("a", [1.0, 2.000001, f64::NAN]);
+        assert_series_equal!(a, b);
+        let options = EqualOptions::default().with_check_exact(true);
+        assert!(check_series_equal(&a, &b, &options).is_err());
+
+        let c = Series::new("a", [Some(1i32), None, Some(3)]);
+        let d = Series::new("a", [Some(3i32), Some(1), None]);
+        let err = check_series_equal(&c, &d, &EqualOptions::default()).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("differ in 3 of 3 rows"), "{msg}");
+        assert!(msg.contains("row 0: left = 1, right = 3"), "{msg}");
+        assert_series_equal!(c, d, EqualOptions::default().with_check_row_order(false));
+
+        let options = EqualOptions::default().with_nulls_equal(false);
+        assert!(check_series_equal(&c, &c, &options).is_err());
+        let e = c.cast(&DataType::Int64).unwrap();
+        assert!(check_series_equal(&c, &e, &EqualOptions::default()).is_err());
+        assert_series_equal!(c, e, EqualOptions::default().with_check_dtypes(false));
+    }
+
+    #[test]
+    #[cfg(feature = "testing")]
+    fn test_check_frame_equal() {
+        use crate::testing::*;
+
+        let a = df!("a" => [1, 2], "b" => ["x", "y"]).unwrap();
+        let b = df!("b" => ["y", "x"], "a" => [2, 1]).unwrap();
+        assert!(check_frame_equal(&a, &b, &EqualOptions::default()).is_err());
+        let options = EqualOptions::default()
+            .with_check_column_order(false)
+            .with_check_row_order(false);
+        assert_frame_equal!(a, b, options);
+
+        let c = df!("a" => [1, 2]).unwrap();
+        let err = check_frame_equal(&a, &c, &EqualOptions::default()).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::SchemaMismatch);
+    }
+
+    #[test]
+    #[cfg(feature = "testing")]
+    #[should_panic(expected = "values of 'a' differ in 1 of 1 rows")]
+    fn test_assert_frame_equal_panics() {
+        assert_frame_equal!(df!("a" => [1]).unwrap(), df!("a" => [2]).unwrap());
+    }
 }
@@ -0,0 +1,247 @@
+use std::fmt::Write;
+
+use crate::prelude::*;
+
+// Number of mismatching rows shown in an error message.
+const MAX_SHOWN_MISMATCHES: usize = 5;
+
+/// Options of [`check_series_equal`] and [`check_frame_equal`].
+///
+/// The defaults match those of `assert_frame_equal` in the Python package: names, data types
+/// and order must match, nulls compare equal and floats are compared with a tolerance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EqualOptions {
+    /// Require the data types to match. If `false`, numeric values of different types are
+    /// compared by value.
+    pub check_dtypes: bool,
+    /// Require the names of series to match.
+    pub check_names: bool,
+    /// Require the rows to be in the same order.
+    pub check_row_order: bool,
+    /// Require the columns of frames to be in the same order.
+    pub check_column_order: bool,
+    /// Compare floats exactly instead of with `rtol` and `atol`.
+    pub check_exact: bool,
+    /// Consider two nulls equal.
+    pub nulls_equal: bool,
+    /// Relative tolerance of float comparisons.
+    pub rtol: f64,
+    /// Absolute tolerance of float comparisons.
+    pub atol: f64,
+}
+
+impl Default for EqualOptions {
+    fn default() -> Self {
+        Self {
+            check_dtypes: true,
+            check_names: true,
+            check_row_order: true,
+            check_column_order: true,
+            check_exact: false,
+            nulls_equal: true,
+            rtol: 1e-5,
+            atol: 1e-8,
+        }
+    }
+}
+
+impl EqualOptions {
+    pub fn with_check_dtypes(mut self, toggle: bool) -> Self {
+        self.check_dtypes = toggle;
+        self
+    }
+
+    pub fn with_check_names(mut self, toggle: bool) -> Self {
+        self.check_names = toggle;
+        self
+    }
+
+    pub fn with_check_row_order(mut self, toggle: bool) -> Self {
+        self.check_row_order = toggle;
+        self
+    }
+
+    pub fn with_check_column_order(mut self, toggle: bool) -> Self {
+        self.check_column_order = toggle;
+        self
+    }
+
+    pub fn with_check_exact(mut self, toggle: bool) -> Self {
+        self.check_exact = toggle;
+        self
+    }
+
+    pub fn with_nulls_equal(mut self, toggle: bool) -> Self {
+        self.nulls_equal = toggle;
+        self
+    }
+
+    pub fn with_tolerance(mut self, rtol: f64, atol: f64) -> Self {
+        self.rtol = rtol;
+        self.atol = atol;
+        self
+    }
+}
+
+fn floats_equal(left: Option<f64>, right: Option<f64>, options: &EqualOptions) -> bool {
+    match (left, right) {
+        (None, None) => options.nulls_equal,
+        (Some(l), Some(r)) => {
+            if l.is_nan() || r.is_nan() {
+                l.is_nan() && r.is_nan()
+            } else if options.check_exact || l.is_infinite() || r.is_infinite() {
+                l == r
+            } else {
+                (l - r).abs() <= options.atol + options.rtol * r.abs()
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Indices of the rows in which `left` and `right` differ.
+fn mismatching_rows(
+    left: &Series,
+    right: &Series,
+    options: &EqualOptions,
+) -> PolarsResult<Vec<usize>> {
+    let (l_dtype, r_dtype) = (left.dtype(), right.dtype());
+    if (l_dtype.is_float() || r_dtype.is_float()) && l_dtype.is_numeric() && r_dtype.is_numeric() {
+        let left = left.cast(&DataType::Float64)?;
+        let right = right.cast(&DataType::Float64)?;
+        return Ok(left
+            .f64()?
+            .iter()
+            .zip(right.f64()?.iter())
+            .enumerate()
+            .filter(|(_, (l, r))| !floats_equal(*l, *r, options))
+            .map(|(i, _)| i)
+            .collect());
+    }
+
+    let eq = if options.nulls_equal {
+        left.equal_missing(right)
+    } else {
+        left.equal(right)
+    }
+    .map_err(|_| {
+        polars_err!(
+            SchemaMismatch: "cannot compare values of dtype {} and {}", l_dtype, r_dtype
+        )
+    })?;
+    Ok(eq
+        .iter()
+        .enumerate()
+        .filter(|(_, eq)| *eq != Some(true))
+        .map(|(i, _)| i)
+        .collect())
+}
+
+fn check_values_equal(left: &Series, right: &Series, options: &EqualOptions) -> PolarsResult<()> {
+    let mismatches = mismatching_rows(left, right, options)?;
+    if mismatches.is_empty() {
+        return Ok(());
+    }
+
+    let mut msg = format!(
+        "values of '{}' differ in {} of {} rows",
+        left.name(),
+        mismatches.len(),
+        left.len()
+    );
+    for &i in mismatches.iter().take(MAX_SHOWN_MISMATCHES) {
+        write!(
+            msg,
+            "\n  row {i}: left = {}, right = {}",
+            left.get(i)?,
+            right.get(i)?
+        )
+        .unwrap();
+    }
+    if mismatches.len() > MAX_SHOWN_MISMATCHES {
+        write!(
+            msg,
+            "\n  ... and {} more",
+            mismatches.len() - MAX_SHOWN_MISMATCHES
+        )
+        .unwrap();
+    }
+    Err(PolarsError::ComputeError(msg.into()))
+}
+
+/// Check that two [`Series`] are equal according to `options`, returning an error that
+/// describes the first differences otherwise.
+pub fn check_series_equal(
+    left: &Series,
+    right: &Series,
+    options: &EqualOptions,
+) -> PolarsResult<()> {
+    polars_ensure!(
+        !options.check_names || left.name() == right.name(),
+        SchemaMismatch: "series names differ: left = '{}', right = '{}'", left.name(), right.name()
+    );
+    polars_ensure!(
+        !options.check_dtypes || left.dtype() == right.dtype(),
+        SchemaMismatch: "dtypes of '{}' differ: left = {}, right = {}",
+        left.name(), left.dtype(), right.dtype()
+    );
+    polars_ensure!(
+        left.len() == right.len(),
+        ShapeMismatch: "lengths of '{}' differ: left = {}, right = {}",
+        left.name(), left.len(), right.len()
+    );
+
+    if options.check_row_order {
+        check_values_equal(left, right, options)
+    } else {
+        let left = left.sort(SortOptions::default())?;
+        let right = right.sort(SortOptions::default())?;
+        check_values_equal(&left, &right, options)
+    }
+}
+
+/// Check that two [`DataFrame`]s are equal according to `options`, returning an error that
+/// describes the first differences otherwise.
+pub fn check_frame_equal(
+    left: &DataFrame,
+    right: &DataFrame,
+    options: &EqualOptions,
+) -> PolarsResult<()> {
+    let (l_names, r_names) = (left.get_column_names(), right.get_column_names());
+    if let Some(name) = l_names.iter().find(|name| !r_names.contains(*name)) {
+        polars_bail!(SchemaMismatch: "column '{}' is missing from the right frame", name);
+    }
+    if let Some(name) = r_names.iter().find(|name| !l_names.contains(*name)) {
+        polars_bail!(SchemaMismatch: "column '{}' is missing from the left frame", name);
+    }
+    polars_ensure!(
+        !options.check_column_order || l_names == r_names,
+        SchemaMismatch: "columns are in a different order: left = {:?}, right = {:?}",
+        l_names, r_names
+    );
+    polars_ensure!(
+        left.height() == right.height(),
+        ShapeMismatch: "heights differ: left = {}, right = {}", left.height(), right.height()
+    );
+
+    let right = right.select(&l_names)?;
+    let (left, right) = if options.check_row_order || left.width() == 0 {
+        (left.clone(), right)
+    } else {
+        let sort_options = SortMultipleOptions::default();
+        (
+            left.sort(&l_names, sort_options.clone())?,
+            right.sort(&l_names, sort_options)?,
+        )
+    };
+
+    // The rows are already in a common order, and names are equal by construction.
+    let options = EqualOptions {
+        check_row_order: true,
+        ..options.clone()
+    };
+    for (l, r) in left.get_columns().iter().zip(right.get_columns()) {
+        check_series_equal(l, r, &options)?;
+    }
+    Ok(())
+}
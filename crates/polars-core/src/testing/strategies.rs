@@ -0,0 +1,175 @@
+//! [proptest](https://docs.rs/proptest/) strategies that generate arbitrary [`Series`] and
+//! [`DataFrame`]s, e.g. to check that a kernel gives the same result on chunked and contiguous
+//! data.
+//!
+//! ```rust
+//! use polars_core::testing::strategies::dataframes;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     #[test]
+//!     fn height_is_preserved(df in dataframes(1..4, 0..20)) {
+//!         prop_assert_eq!(df.head(Some(5)).height(), df.height().min(5));
+//!     }
+//! }
+//! ```
+use proptest::collection::{vec, SizeRange};
+use proptest::prelude::*;
+use proptest::sample::{select, Index};
+
+use crate::prelude::*;
+
+// Maximum number of chunks a generated series is split into.
+const MAX_CHUNKS: usize = 4;
+
+/// Data types, including [`List`](DataType::List) and [`Struct`](DataType::Struct) types
+/// nested up to `max_depth` levels.
+pub fn dtypes(max_depth: u32) -> BoxedStrategy<DataType> {
+    let leaf = select(vec![
+        DataType::Boolean,
+        DataType::UInt32,
+        DataType::UInt64,
+        DataType::Int32,
+        DataType::Int64,
+        DataType::Float32,
+        DataType::Float64,
+        DataType::String,
+        DataType::Binary,
+    ]);
+    leaf.prop_recursive(max_depth, 16, 4, |inner| {
+        let list = inner.clone().prop_map(|dt| DataType::List(Box::new(dt)));
+        #[cfg(feature = "dtype-struct")]
+        {
+            let strct = vec(inner, 1..4).prop_map(|dtypes| {
+                DataType::Struct(
+                    dtypes
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, dt)| Field::new(&format!("f{i}"), dt))
+                        .collect(),
+                )
+            });
+            prop_oneof![list, strct].boxed()
+        }
+        #[cfg(not(feature = "dtype-struct"))]
+        list.boxed()
+    })
+    .boxed()
+}
+
+/// Values of `dtype`, of which about one in five is null.
+fn any_values(dtype: &DataType) -> BoxedStrategy<AnyValue<'static>> {
+    let value = match dtype {
+        DataType::Boolean => any::<bool>().prop_map(AnyValue::Boolean).boxed(),
+        DataType::UInt32 => any::<u32>().prop_map(AnyValue::UInt32).boxed(),
+        DataType::UInt64 => any::<u64>().prop_map(AnyValue::UInt64).boxed(),
+        DataType::Int32 => any::<i32>().prop_map(AnyValue::Int32).boxed(),
+        DataType::Int64 => any::<i64>().prop_map(AnyValue::Int64).boxed(),
+        DataType::Float32 => any::<f32>().prop_map(AnyValue::Float32).boxed(),
+        DataType::Float64 => any::<f64>().prop_map(AnyValue::Float64).boxed(),
+        DataType::String => vec(any::<char>(), 0..8)
+            .prop_map(|chars| AnyValue::StringOwned(chars.into_iter().collect::<String>().into()))
+            .boxed(),
+        DataType::Binary => vec(any::<u8>(), 0..8)
+            .prop_map(AnyValue::BinaryOwned)
+            .boxed(),
+        DataType::List(inner) => series("", inner.as_ref().clone(), 0..4)
+            .prop_map(AnyValue::List)
+            .boxed(),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => {
+            let fields = fields.clone();
+            fields
+                .iter()
+                .map(|field| any_values(field.data_type()))
+                .collect::<Vec<_>>()
+                .prop_map(move |values| AnyValue::StructOwned(Box::new((values, fields.clone()))))
+                .boxed()
+        },
+        dt => panic!("cannot generate values of dtype {dt}"),
+    };
+    prop_oneof![1 => Just(AnyValue::Null), 4 => value].boxed()
+}
+
+/// Split `s` into chunks at the given positions.
+fn split_chunks(s: Series, splits: Vec<Index>) -> Series {
+    if s.is_empty() {
+        return s;
+    }
+    let mut offsets = splits
+        .iter()
+        .map(|idx| idx.index(s.len()))
+        .filter(|&offset| offset > 0)
+        .collect::<Vec<_>>();
+    offsets.sort_unstable();
+    offsets.dedup();
+    offsets.push(s.len());
+
+    let mut out = s.slice(0, offsets[0]);
+    for window in offsets.windows(2) {
+        out.append(&s.slice(window[0] as i64, window[1] - window[0]))
+            .unwrap();
+    }
+    out
+}
+
+/// Series of `dtype` with a length in `len`, containing nulls and split into a random number
+/// of chunks.
+///
+/// Supports the data types generated by [`dtypes`].
+pub fn series(name: &str, dtype: DataType, len: impl Into<SizeRange>) -> BoxedStrategy<Series> {
+    let name = name.to_string();
+    (
+        vec(any_values(&dtype), len),
+        vec(any::<Index>(), 0..MAX_CHUNKS),
+    )
+        .prop_map(move |(values, splits)| {
+            let s = Series::from_any_values_and_dtype(&name, &values, &dtype, true).unwrap();
+            split_chunks(s, splits)
+        })
+        .boxed()
+}
+
+/// Series of an arbitrary data type, see [`dtypes`] and [`series`].
+pub fn any_series(len: impl Into<SizeRange>) -> BoxedStrategy<Series> {
+    let len = len.into();
+    dtypes(2)
+        .prop_flat_map(move |dtype| series("s", dtype, len.clone()))
+        .boxed()
+}
+
+/// DataFrames with a number of columns in `width` and a height in `height`. The columns are
+/// named `col_0`, `col_1`, ... and are chunked independently of each other.
+pub fn dataframes(
+    width: impl Into<SizeRange>,
+    height: impl Into<SizeRange>,
+) -> BoxedStrategy<DataFrame> {
+    let height = height.into();
+    (vec(dtypes(2), width), height.start()..=height.end_incl())
+        .prop_flat_map(|(dtypes, height)| {
+            dtypes
+                .into_iter()
+                .enumerate()
+                .map(|(i, dtype)| series(&format!("col_{i}"), dtype, height))
+                .collect::<Vec<_>>()
+                .prop_map(|columns| DataFrame::new(columns).unwrap())
+        })
+        .boxed()
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod test {
+    use super::*;
+    use crate::testing::{check_frame_equal, EqualOptions};
+
+    proptest! {
+        #[test]
+        fn test_chunked_equals_contiguous(df in dataframes(0..4, 0..20)) {
+            let mut contiguous = df.clone();
+            contiguous.as_single_chunk();
+            prop_assert!(contiguous.get_columns().iter().all(|s| s.n_chunks() == 1));
+            let result = check_frame_equal(&df, &contiguous, &EqualOptions::default());
+            prop_assert!(result.is_ok(), "{}", result.unwrap_err());
+        }
+    }
+}
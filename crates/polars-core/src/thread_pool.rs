@@ -0,0 +1,178 @@
+use std::sync::{Arc, Mutex, RwLock};
+
+use once_cell::sync::Lazy;
+use polars_error::{polars_ensure, PolarsResult};
+use rayon::{Scope, ThreadPool, ThreadPoolBuilder};
+
+use crate::config;
+
+// A pool given to `Config::set_thread_pool`, taken when the global pool is initialized.
+static CUSTOM_GLOBAL_POOL: Mutex<Option<ThreadPool>> = Mutex::new(None);
+
+// Pools that currently run a closure given to `install_thread_pool`.
+static SCOPED_POOLS: RwLock<Vec<Arc<ThreadPool>>> = RwLock::new(Vec::new());
+
+fn build_global_pool() -> ThreadPool {
+    if let Some(pool) = CUSTOM_GLOBAL_POOL.lock().unwrap().take() {
+        return pool;
+    }
+    let thread_name = std::env::var("POLARS_THREAD_NAME").unwrap_or_else(|_| "polars".to_string());
+    ThreadPoolBuilder::new()
+        .num_threads(config::get_max_threads().unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .unwrap_or(std::num::NonZeroUsize::new(1).unwrap())
+                .get()
+        }))
+        .thread_name(move |i| format!("{}-{}", thread_name, i))
+        .build()
+        .expect("could not spawn threads")
+}
+
+/// The thread pool Polars runs its parallel work on, see [`POOL`](crate::POOL).
+///
+/// This is the global rayon pool, unless the work is started from a closure given to
+/// [`install_thread_pool`], in which case it runs on that pool instead. The methods mirror
+/// those of [`ThreadPool`].
+pub struct PolarsThreadPool {
+    global: Lazy<ThreadPool, fn() -> ThreadPool>,
+}
+
+impl PolarsThreadPool {
+    pub(crate) const fn new() -> Self {
+        Self {
+            global: Lazy::new(build_global_pool as fn() -> ThreadPool),
+        }
+    }
+
+    /// Whether the global pool was created yet.
+    pub fn is_initialized(&self) -> bool {
+        Lazy::get(&self.global).is_some()
+    }
+
+    /// Call `f` with the pool work should run on from the current thread.
+    pub fn with_pool<R>(&self, f: impl FnOnce(&ThreadPool) -> R) -> R {
+        // Only look for a scoped pool on rayon threads that are not part of the global pool.
+        // The global pool isn't forced here, so it is never created if only scoped pools are
+        // used.
+        let on_global =
+            Lazy::get(&self.global).map_or(false, |pool| pool.current_thread_index().is_some());
+        if !on_global && rayon::current_thread_index().is_some() {
+            let scoped = SCOPED_POOLS
+                .read()
+                .unwrap()
+                .iter()
+                .find(|pool| pool.current_thread_index().is_some())
+                .cloned();
+            if let Some(pool) = scoped {
+                return f(&pool);
+            }
+        }
+        f(&self.global)
+    }
+
+    pub fn current_num_threads(&self) -> usize {
+        self.with_pool(|pool| pool.current_num_threads())
+    }
+
+    pub fn current_thread_index(&self) -> Option<usize> {
+        self.with_pool(|pool| pool.current_thread_index())
+    }
+
+    pub fn current_thread_has_pending_tasks(&self) -> Option<bool> {
+        self.with_pool(|pool| pool.current_thread_has_pending_tasks())
+    }
+
+    pub fn install<OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        self.with_pool(|pool| pool.install(op))
+    }
+
+    pub fn join<A, B, RA, RB>(&self, oper_a: A, oper_b: B) -> (RA, RB)
+    where
+        A: FnOnce() -> RA + Send,
+        B: FnOnce() -> RB + Send,
+        RA: Send,
+        RB: Send,
+    {
+        self.with_pool(|pool| pool.join(oper_a, oper_b))
+    }
+
+    pub fn scope<'scope, OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce(&Scope<'scope>) -> R + Send,
+        R: Send,
+    {
+        self.with_pool(|pool| pool.scope(op))
+    }
+
+    pub fn spawn<OP>(&self, op: OP)
+    where
+        OP: FnOnce() + Send + 'static,
+    {
+        self.with_pool(|pool| pool.spawn(op))
+    }
+
+    pub fn spawn_fifo<OP>(&self, op: OP)
+    where
+        OP: FnOnce() + Send + 'static,
+    {
+        self.with_pool(|pool| pool.spawn_fifo(op))
+    }
+}
+
+/// Use `pool` instead of the global thread pool. Must be called before the global pool is
+/// first used.
+pub(crate) fn set_global_thread_pool(pool: ThreadPool) -> PolarsResult<()> {
+    polars_ensure!(
+        !crate::POOL.is_initialized(),
+        InvalidOperation: "the global thread pool is already running; \
+        `Config::set_thread_pool` must be called before it is first used"
+    );
+    *CUSTOM_GLOBAL_POOL.lock().unwrap() = Some(pool);
+    Ok(())
+}
+
+struct ScopedPoolGuard(Arc<ThreadPool>);
+
+impl Drop for ScopedPoolGuard {
+    fn drop(&mut self) {
+        let mut pools = SCOPED_POOLS.write().unwrap();
+        if let Some(i) = pools.iter().position(|pool| Arc::ptr_eq(pool, &self.0)) {
+            pools.swap_remove(i);
+        }
+    }
+}
+
+/// Run `op` in `pool`, so that all parallel work Polars does from within `op`, such as
+/// evaluating expressions or reading files, runs on `pool` instead of the global thread pool.
+///
+/// This lets applications that run other parallel workloads next to Polars decide how many
+/// cores a query may use.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// use polars_core::prelude::*;
+/// use polars_core::{install_thread_pool, POOL};
+///
+/// let pool = Arc::new(rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap());
+/// let out = install_thread_pool(&pool, || {
+///     assert_eq!(POOL.current_num_threads(), 2);
+///     Series::new("a", [3, 1, 2]).sort(Default::default())
+/// })
+/// .unwrap();
+/// assert_eq!(out, Series::new("a", [1, 2, 3]));
+/// ```
+pub fn install_thread_pool<OP, R>(pool: &Arc<ThreadPool>, op: OP) -> R
+where
+    OP: FnOnce() -> R + Send,
+    R: Send,
+{
+    SCOPED_POOLS.write().unwrap().push(pool.clone());
+    let _guard = ScopedPoolGuard(pool.clone());
+    pool.install(op)
+}
@@ -103,6 +103,11 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
             (Int64, Float32) => Some(Float64), // Follow numpy
             (Int64, Float64) => Some(Float64),
 
+            #[cfg(feature = "dtype-i128")]
+            (Int128, dt) if dt.is_integer() || dt.is_bool() => Some(Int128),
+            #[cfg(feature = "dtype-i128")]
+            (Int128, Float32 | Float64) => Some(Float64),
+
             #[cfg(all(feature = "dtype-u16", feature = "dtype-u8"))]
             (UInt16, UInt8) => Some(UInt16),
             #[cfg(feature = "dtype-u16")]
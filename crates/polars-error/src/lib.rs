@@ -83,11 +83,80 @@ pub enum PolarsError {
     StringCacheMismatch(ErrString),
     #[error("field not found: {0}")]
     StructFieldNotFound(ErrString),
+    /// The query was cancelled before it completed.
+    #[error("query cancelled: {0}")]
+    Cancelled(ErrString),
     #[error("{error}: {msg}")]
     Context {
+        #[source]
         error: Box<PolarsError>,
         msg: ErrString,
     },
+    /// An error raised while evaluating an expression.
+    #[error("{error}\n\nError originated in expression: '{expr}'")]
+    Expression {
+        #[source]
+        error: Box<PolarsError>,
+        /// The rendered expression.
+        expr: ErrString,
+        /// The output column the expression computes, if known.
+        column: Option<ErrString>,
+    },
+}
+
+/// Stable identifier of the category of a [`PolarsError`].
+///
+/// Applications can match on the code instead of on the error message, which may change
+/// between releases. The numeric values are stable, new codes are only ever appended.
+#[repr(u16)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    ColumnNotFound = 1,
+    ComputeError = 2,
+    Duplicate = 3,
+    InvalidOperation = 4,
+    IO = 5,
+    NoData = 6,
+    OutOfBounds = 7,
+    SchemaFieldNotFound = 8,
+    SchemaMismatch = 9,
+    ShapeMismatch = 10,
+    SQLInterface = 11,
+    SQLSyntax = 12,
+    StringCacheMismatch = 13,
+    StructFieldNotFound = 14,
+    Cancelled = 15,
+}
+
+impl ErrorCode {
+    /// The code as a string, e.g. `"COLUMN_NOT_FOUND"`.
+    pub fn as_str(&self) -> &'static str {
+        use ErrorCode::*;
+        match self {
+            ColumnNotFound => "COLUMN_NOT_FOUND",
+            ComputeError => "COMPUTE_ERROR",
+            Duplicate => "DUPLICATE",
+            InvalidOperation => "INVALID_OPERATION",
+            IO => "IO",
+            NoData => "NO_DATA",
+            OutOfBounds => "OUT_OF_BOUNDS",
+            SchemaFieldNotFound => "SCHEMA_FIELD_NOT_FOUND",
+            SchemaMismatch => "SCHEMA_MISMATCH",
+            ShapeMismatch => "SHAPE_MISMATCH",
+            SQLInterface => "SQL_INTERFACE",
+            SQLSyntax => "SQL_SYNTAX",
+            StringCacheMismatch => "STRING_CACHE_MISMATCH",
+            StructFieldNotFound => "STRUCT_FIELD_NOT_FOUND",
+            Cancelled => "CANCELLED",
+        }
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl From<io::Error> for PolarsError {
@@ -148,6 +217,9 @@ impl PolarsError {
     pub fn context_trace(self) -> Self {
         use PolarsError::*;
         match self {
+            Expression { error, expr, .. } => (*error)
+                .context_trace()
+                .wrap_msg(|msg| format!("{msg}\n\nError originated in expression: '{expr}'")),
             Context { error, msg } => {
                 // If context is 1 level deep, just return error.
                 if !matches!(&*error, PolarsError::Context { .. }) {
@@ -202,9 +274,22 @@ impl PolarsError {
             ShapeMismatch(msg) => ShapeMismatch(func(msg).into()),
             StringCacheMismatch(msg) => StringCacheMismatch(func(msg).into()),
             StructFieldNotFound(msg) => StructFieldNotFound(func(msg).into()),
+            Cancelled(msg) => Cancelled(func(msg).into()),
             SQLInterface(msg) => SQLInterface(func(msg).into()),
             SQLSyntax(msg) => SQLSyntax(func(msg).into()),
-            _ => unreachable!(),
+            Expression {
+                error,
+                expr,
+                column,
+            } => Expression {
+                error: Box::new(error.wrap_msg(func)),
+                expr: expr.to_string().into(),
+                column: column.as_ref().map(|c| c.to_string().into()),
+            },
+            Context { error, msg } => Context {
+                error: Box::new(error.wrap_msg(func)),
+                msg: msg.to_string().into(),
+            },
         }
     }
 
@@ -222,6 +307,62 @@ impl PolarsError {
             error: Box::new(self),
         }
     }
+
+    /// Record the expression this error originated in and the column it computes.
+    ///
+    /// An error that already carries an expression is returned as is, as the innermost
+    /// expression is the most precise.
+    pub fn with_expr(self, expr: impl Into<ErrString>, column: Option<ErrString>) -> Self {
+        match self {
+            err @ PolarsError::Expression { .. } => err,
+            err => PolarsError::Expression {
+                error: Box::new(err),
+                expr: expr.into(),
+                column,
+            },
+        }
+    }
+
+    /// The category of this error, looking through any context.
+    pub fn code(&self) -> ErrorCode {
+        use PolarsError::*;
+        match self {
+            ColumnNotFound(_) => ErrorCode::ColumnNotFound,
+            ComputeError(_) => ErrorCode::ComputeError,
+            Duplicate(_) => ErrorCode::Duplicate,
+            InvalidOperation(_) => ErrorCode::InvalidOperation,
+            IO { .. } => ErrorCode::IO,
+            NoData(_) => ErrorCode::NoData,
+            OutOfBounds(_) => ErrorCode::OutOfBounds,
+            SchemaFieldNotFound(_) => ErrorCode::SchemaFieldNotFound,
+            SchemaMismatch(_) => ErrorCode::SchemaMismatch,
+            ShapeMismatch(_) => ErrorCode::ShapeMismatch,
+            SQLInterface(_) => ErrorCode::SQLInterface,
+            SQLSyntax(_) => ErrorCode::SQLSyntax,
+            StringCacheMismatch(_) => ErrorCode::StringCacheMismatch,
+            StructFieldNotFound(_) => ErrorCode::StructFieldNotFound,
+            Cancelled(_) => ErrorCode::Cancelled,
+            Context { error, .. } | Expression { error, .. } => error.code(),
+        }
+    }
+
+    /// The rendered expression this error originated in, if known.
+    pub fn expr(&self) -> Option<&str> {
+        match self {
+            PolarsError::Expression { expr, .. } => Some(expr.as_ref()),
+            PolarsError::Context { error, .. } => error.expr(),
+            _ => None,
+        }
+    }
+
+    /// The output column of the expression this error originated in, if known.
+    pub fn column(&self) -> Option<&str> {
+        match self {
+            PolarsError::Expression { column, .. } => column.as_deref(),
+            PolarsError::Context { error, .. } => error.column(),
+            _ => None,
+        }
+    }
 }
 
 pub fn map_err<E: Error>(error: E) -> PolarsError {
@@ -242,9 +383,7 @@ macro_rules! polars_err {
     };
     (expr = $expr:expr, $variant:ident: $err:expr $(,)?) => {
         $crate::__private::must_use(
-            $crate::PolarsError::$variant(
-                format!("{}\n\nError originated in expression: '{:?}'", $err, $expr).into()
-            )
+            $crate::PolarsError::$variant($err.into()).with_expr(format!("{:?}", $expr), None)
         )
     };
     (expr = $expr:expr, $variant:ident: $fmt:literal, $($arg:tt)+) => {
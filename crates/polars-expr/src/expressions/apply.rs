@@ -26,6 +26,7 @@ pub struct ApplyExpr {
     check_lengths: bool,
     allow_group_aware: bool,
     output_dtype: Option<DataType>,
+    groups_udf: Option<GroupsUdf>,
 }
 
 impl ApplyExpr {
@@ -56,9 +57,16 @@ impl ApplyExpr {
             check_lengths: options.check_lengths(),
             allow_group_aware: options.allow_group_aware,
             output_dtype,
+            groups_udf: None,
         }
     }
 
+    /// Use `groups_udf` to apply the function on all groups at once.
+    pub(crate) fn with_groups_udf(mut self, groups_udf: Option<GroupsUdf>) -> Self {
+        self.groups_udf = groups_udf;
+        self
+    }
+
     pub(crate) fn new_minimal(
         inputs: Vec<Arc<dyn PhysicalExpr>>,
         function: SpecialEq<Arc<dyn SeriesUdf>>,
@@ -78,6 +86,7 @@ impl ApplyExpr {
             check_lengths: true,
             allow_group_aware: true,
             output_dtype: None,
+            groups_udf: None,
         }
     }
 
@@ -157,6 +166,11 @@ impl ApplyExpr {
             return self.finish_apply_groups(ac, ca);
         }
 
+        if let Some(groups_udf) = &self.groups_udf {
+            let ca = groups_udf(agg.list().unwrap())?;
+            return self.finish_apply_groups(ac, ca.with_name(&name));
+        }
+
         let f = |opt_s: Option<Series>| match opt_s {
             None => Ok(None),
             Some(mut s) => {
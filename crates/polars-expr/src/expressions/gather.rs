@@ -122,10 +122,14 @@ impl GatherExpr {
             // Determine the gather indices.
             let idx: IdxCa = match groups.as_ref() {
                 GroupsProxy::Idx(groups) => {
-                    if groups.all().iter().zip(idx).any(|(g, idx)| match idx {
-                        None => true,
-                        Some(idx) => idx >= g.len() as IdxSize,
-                    }) {
+                    // A null index gathers a null, e.g. the `arg_max` of a group without
+                    // valid values.
+                    if groups
+                        .all()
+                        .iter()
+                        .zip(idx)
+                        .any(|(g, idx)| matches!(idx, Some(idx) if idx >= g.len() as IdxSize))
+                    {
                         self.oob_err()?;
                     }
 
@@ -143,10 +147,11 @@ impl GatherExpr {
                         .collect_trusted()
                 },
                 GroupsProxy::Slice { groups, .. } => {
-                    if groups.iter().zip(idx).any(|(g, idx)| match idx {
-                        None => true,
-                        Some(idx) => idx >= g[1],
-                    }) {
+                    if groups
+                        .iter()
+                        .zip(idx)
+                        .any(|(g, idx)| matches!(idx, Some(idx) if idx >= g[1]))
+                    {
                         self.oob_err()?;
                     }
 
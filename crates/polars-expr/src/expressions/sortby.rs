@@ -154,6 +154,7 @@ fn sort_by_groups_multiple_by(
                 nulls_last: vec![false; descending.len()],
                 multithreaded,
                 maintain_order,
+                ..Default::default()
             };
 
             let sorted_idx = groups[0].arg_sort_multiple(&groups[1..], &options).unwrap();
@@ -170,6 +171,7 @@ fn sort_by_groups_multiple_by(
                 nulls_last: vec![false; descending.len()],
                 multithreaded,
                 maintain_order,
+                ..Default::default()
             };
             let sorted_idx = groups[0].arg_sort_multiple(&groups[1..], &options).unwrap();
             map_sorted_indices_to_group_slice(&sorted_idx, first)
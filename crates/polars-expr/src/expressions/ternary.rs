@@ -1,4 +1,5 @@
 use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
 use polars_core::POOL;
 use polars_plan::prelude::*;
 
@@ -355,3 +356,174 @@ impl PartitionedAggregation for TernaryExpr {
         Ok(partitioned)
     }
 }
+
+/// A chain of `when/then` branches with a final `otherwise`, of which all expressions are
+/// elementwise.
+///
+/// Instead of evaluating every branch over all rows and zipping the results, the branches are
+/// evaluated in order on only the rows that are still undecided, i.e. that no earlier
+/// predicate was true for. The values of every row are then gathered from the branch that
+/// decided it in a single pass.
+pub struct TernaryChainExpr {
+    branches: Vec<(Arc<dyn PhysicalExpr>, Arc<dyn PhysicalExpr>)>,
+    otherwise: Arc<dyn PhysicalExpr>,
+    /// The chain as nested [`TernaryExpr`]s, which is evaluated on groups.
+    nested: Arc<dyn PhysicalExpr>,
+    /// The columns the chain depends on, which are the only ones that have to be gathered for
+    /// the undecided rows.
+    columns: Vec<Arc<str>>,
+    expr: Expr,
+}
+
+impl TernaryChainExpr {
+    pub fn new(
+        branches: Vec<(Arc<dyn PhysicalExpr>, Arc<dyn PhysicalExpr>)>,
+        otherwise: Arc<dyn PhysicalExpr>,
+        nested: Arc<dyn PhysicalExpr>,
+        columns: Vec<Arc<str>>,
+        expr: Expr,
+    ) -> Self {
+        Self {
+            branches,
+            otherwise,
+            nested,
+            columns,
+            expr,
+        }
+    }
+}
+
+/// Evaluate `e` on `df`, broadcasting a unit length result to the height of `df`.
+fn evaluate_broadcast(
+    e: &dyn PhysicalExpr,
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<Series> {
+    let s = e.evaluate(df, state)?;
+    let height = df.height();
+    if s.len() == height {
+        Ok(s)
+    } else {
+        polars_ensure!(
+            s.len() == 1,
+            ShapeMismatch: "shapes of `self`, `mask` and `other` are not suitable for `zip_with` operation"
+        );
+        Ok(s.new_from_index(0, height))
+    }
+}
+
+impl PhysicalExpr for TernaryChainExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        Some(&self.expr)
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        let height = df.height();
+        if height == 0 {
+            return self.nested.evaluate(df, state);
+        }
+        let mut state = state.split();
+        // Don't cache window functions as they run in parallel.
+        state.remove_cache_window_flag();
+        let field = self.to_field(&df.schema())?;
+        let df = &df.select(self.columns.iter().map(|name| name.as_ref()))?;
+
+        // The values of every branch that decided any rows, and for every row its position
+        // in the concatenation of these values.
+        let mut values = Series::new_empty(field.name(), field.data_type());
+        let mut positions = vec![0 as IdxSize; height];
+        // The rows of `df` that are still undecided, `None` if that are all rows.
+        let mut undecided: Option<IdxCa> = None;
+
+        for (predicate, truthy) in &self.branches {
+            // SAFETY: the undecided rows are in bounds.
+            let df_undecided = match &undecided {
+                Some(rows) => unsafe { df.take_unchecked(rows) },
+                None => df.clone(),
+            };
+            let mask = evaluate_broadcast(predicate.as_ref(), &df_undecided, &state)?;
+            // A null predicate is not true, so that row is left undecided.
+            let mask = mask.bool()?.fill_null_with_values(false)?;
+            let n_true = mask.sum().unwrap_or(0) as usize;
+            if n_true == 0 {
+                continue;
+            }
+
+            let rows = match &undecided {
+                Some(rows) => rows.clone(),
+                None => IdxCa::from_vec("", (0..height as IdxSize).collect()),
+            };
+            let decided = rows.filter(&mask)?;
+            let df_decided = df_undecided.filter(&mask)?;
+            let branch = evaluate_broadcast(truthy.as_ref(), &df_decided, &state)?;
+            decide(&mut values, &mut positions, Some(&decided), branch)?;
+
+            if n_true == df_undecided.height() {
+                return finish(values, positions);
+            }
+            undecided = Some(rows.filter(&!&mask)?);
+        }
+
+        let df_undecided = match &undecided {
+            // SAFETY: the undecided rows are in bounds.
+            Some(rows) => unsafe { df.take_unchecked(rows) },
+            None => df.clone(),
+        };
+        let branch = evaluate_broadcast(self.otherwise.as_ref(), &df_undecided, &state)?;
+        decide(&mut values, &mut positions, undecided.as_ref(), branch)?;
+        finish(values, positions)
+    }
+
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        self.nested.evaluate_on_groups(df, groups, state)
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.nested.to_field(input_schema)
+    }
+
+    fn as_partitioned_aggregator(&self) -> Option<&dyn PartitionedAggregation> {
+        self.nested.as_partitioned_aggregator()
+    }
+}
+
+/// Append the values of a branch, which decided the given `rows`, or all rows if `None`.
+fn decide(
+    values: &mut Series,
+    positions: &mut [IdxSize],
+    rows: Option<&IdxCa>,
+    mut branch: Series,
+) -> PolarsResult<()> {
+    let offset = values.len() as IdxSize;
+    match rows {
+        Some(rows) => {
+            for (i, row) in rows.into_no_null_iter().enumerate() {
+                positions[row as usize] = offset + i as IdxSize;
+            }
+        },
+        None => {
+            for (i, position) in positions.iter_mut().enumerate() {
+                *position = offset + i as IdxSize;
+            }
+        },
+    }
+    if branch.dtype() != values.dtype() {
+        let dtype = try_get_supertype(values.dtype(), branch.dtype())?;
+        *values = values.cast(&dtype)?;
+        branch = branch.cast(&dtype)?;
+    }
+    values.append(&branch)?;
+    Ok(())
+}
+
+/// Gather the value of every row from the concatenated branch values.
+fn finish(values: Series, positions: Vec<IdxSize>) -> PolarsResult<Series> {
+    let positions = IdxCa::from_vec("", positions);
+    // SAFETY: every row is decided by a branch, so its position is in bounds.
+    Ok(unsafe { values.take_unchecked(&positions) })
+}
@@ -113,7 +113,7 @@ impl WindowExpr {
         cache_gb(gb, state, cache_key);
         // SAFETY:
         // we only have unique indices ranging from 0..len
-        unsafe { perfect_sort(&POOL, &idx_mapping, &mut take_idx) };
+        POOL.with_pool(|pool| unsafe { perfect_sort(pool, &idx_mapping, &mut take_idx) });
         let idx = IdxCa::from_vec("", take_idx);
 
         // SAFETY:
@@ -335,7 +335,9 @@ impl WindowExpr {
             // no explicit aggregations, map over the groups
             //`(col("x").sum() * col("y")).over("groups")`
             (WindowMapping::GroupsToRows, AggState::AggregatedList(_)) => {
-                if sorted_keys {
+                // Groups that are ordered by `order_by` are no longer in row order, so their
+                // values can't be exploded in place.
+                if sorted_keys && self.order_by.is_none() {
                     if let GroupsProxy::Idx(g) = gb.get_groups() {
                         debug_assert!(g.is_sorted_flag())
                     }
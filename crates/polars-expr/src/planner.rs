@@ -458,6 +458,13 @@ fn create_physical_expr_inner(
                 options: *options,
             }))
         },
+        Ternary { falsy, .. }
+            if matches!(ctxt, Context::Default)
+                && matches!(expr_arena.get(*falsy), Ternary { .. })
+                && is_row_separable(expression, expr_arena) =>
+        {
+            create_ternary_chain(expression, ctxt, expr_arena, schema, state)
+        },
         Ternary {
             predicate,
             truthy,
@@ -549,15 +556,18 @@ fn create_physical_expr_inner(
                 },
             )?;
 
-            Ok(Arc::new(ApplyExpr::new(
-                input,
-                function.clone().into(),
-                node_to_expr(expression, expr_arena),
-                *options,
-                true,
-                schema.cloned(),
-                output_dtype,
-            )))
+            Ok(Arc::new(
+                ApplyExpr::new(
+                    input,
+                    function.clone().into(),
+                    node_to_expr(expression, expr_arena),
+                    *options,
+                    true,
+                    schema.cloned(),
+                    output_dtype,
+                )
+                .with_groups_udf(function.groups_udf()),
+            ))
         },
         Slice {
             input,
@@ -603,3 +613,84 @@ fn create_physical_expr_inner(
         },
     }
 }
+
+/// Whether evaluating the expression on a subset of the rows gives the values of those rows,
+/// so that the branches of a `when/then` chain can be evaluated on only the rows they decide.
+fn is_row_separable(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    let mut has_column = false;
+    let separable = expr_arena.iter(node).all(|(_, ae)| match ae {
+        AExpr::Column(_) => {
+            has_column = true;
+            true
+        },
+        AExpr::Alias(_, _)
+        | AExpr::Cast { .. }
+        | AExpr::BinaryExpr { .. }
+        | AExpr::Ternary { .. } => true,
+        AExpr::Literal(lv) => !matches!(lv, LiteralValue::Series(_) | LiteralValue::Range { .. }),
+        AExpr::Function { options, .. } | AExpr::AnonymousFunction { options, .. } => {
+            matches!(options.collect_groups, ApplyOptions::ElementWise) && !options.changes_length
+        },
+        _ => false,
+    });
+    // Without columns there are no rows to evaluate a subset of.
+    separable && has_column
+}
+
+/// Create a [`TernaryChainExpr`] for a chain of `when/then` branches, of which the `falsy` of
+/// every branch but the last is the next branch.
+fn create_ternary_chain(
+    expression: Node,
+    ctxt: Context,
+    expr_arena: &Arena<AExpr>,
+    schema: Option<&SchemaRef>,
+    state: &mut ExpressionConversionState,
+) -> PolarsResult<Arc<dyn PhysicalExpr>> {
+    let mut branches = vec![];
+    let mut nodes = vec![];
+    let mut node = expression;
+    while let AExpr::Ternary {
+        predicate,
+        truthy,
+        falsy,
+    } = expr_arena.get(node)
+    {
+        let mut lit_count = 0u8;
+        state.reset();
+        let predicate = create_physical_expr_inner(*predicate, ctxt, expr_arena, schema, state)?;
+        lit_count += state.local.has_lit as u8;
+        state.reset();
+        let truthy = create_physical_expr_inner(*truthy, ctxt, expr_arena, schema, state)?;
+        lit_count += state.local.has_lit as u8;
+        branches.push((predicate, truthy));
+        nodes.push((node, lit_count));
+        node = *falsy;
+    }
+    state.reset();
+    let otherwise = create_physical_expr_inner(node, ctxt, expr_arena, schema, state)?;
+    let otherwise_lit = state.local.has_lit as u8;
+
+    // The chain as nested ternaries, to evaluate on groups.
+    let mut nested = otherwise.clone();
+    for ((predicate, truthy), (node, lit_count)) in branches.iter().zip(&nodes).rev() {
+        nested = Arc::new(TernaryExpr::new(
+            predicate.clone(),
+            truthy.clone(),
+            nested,
+            node_to_expr(*node, expr_arena),
+            lit_count + otherwise_lit < 2,
+        ));
+    }
+
+    let mut columns = PlHashSet::new();
+    let columns = aexpr_to_leaf_names_iter(expression, expr_arena)
+        .filter(|name| columns.insert(name.clone()))
+        .collect();
+    Ok(Arc::new(TernaryChainExpr::new(
+        branches,
+        otherwise,
+        nested,
+        columns,
+        node_to_expr(expression, expr_arena),
+    )))
+}
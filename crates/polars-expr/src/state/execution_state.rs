@@ -1,14 +1,16 @@
 use std::borrow::Cow;
-use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU8, Ordering};
+use std::sync::atomic::{AtomicI64, AtomicU8, Ordering};
 use std::sync::{Mutex, RwLock};
 
 use bitflags::bitflags;
 use once_cell::sync::OnceCell;
+use polars_core::cancel::CancellationToken;
 use polars_core::config::verbose;
+use polars_core::polars_span;
 use polars_core::prelude::*;
 use polars_ops::prelude::ChunkJoinOptIds;
 
-use super::NodeTimer;
+use super::{MemoryLimit, MemoryReservation, NodeTimer};
 
 pub type JoinTuplesCache = Arc<Mutex<PlHashMap<String, ChunkJoinOptIds>>>;
 pub type GroupsProxyCache = Arc<RwLock<PlHashMap<String, GroupsProxy>>>;
@@ -71,10 +73,13 @@ pub struct ExecutionState {
     pub flags: AtomicU8,
     pub ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
-    stop: Arc<AtomicBool>,
+    stop: CancellationToken,
+    memory_limit: Option<MemoryLimit>,
 }
 
 impl ExecutionState {
+    /// The state picks up the [current](CancellationToken::current) cancellation token and
+    /// [current](MemoryLimit::current) memory limit of this thread.
     pub fn new() -> Self {
         let mut flags: StateFlags = Default::default();
         if verbose() {
@@ -89,7 +94,8 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
-            stop: Arc::new(AtomicBool::new(false)),
+            stop: CancellationToken::current().unwrap_or_default(),
+            memory_limit: MemoryLimit::current(),
         }
     }
 
@@ -97,7 +103,16 @@ impl ExecutionState {
     pub fn time_nodes(&mut self) {
         self.node_timer = Some(NodeTimer::new())
     }
+    /// Whether nodes should be wrapped in [`ExecutionState::record`], either to time them or
+    /// to trace their execution.
     pub fn has_node_timer(&self) -> bool {
+        #[cfg(feature = "tracing")]
+        if polars_core::export::tracing::enabled!(
+            target: "polars::execute",
+            polars_core::export::tracing::Level::DEBUG
+        ) {
+            return true;
+        }
         self.node_timer.is_some()
     }
 
@@ -105,17 +120,54 @@ impl ExecutionState {
         self.node_timer.unwrap().finish()
     }
 
-    // This is wrong when the U64 overflows which will never happen.
+    /// Return a [`PolarsError::Cancelled`] error if the query was cancelled.
     pub fn should_stop(&self) -> PolarsResult<()> {
-        polars_ensure!(!self.stop.load(Ordering::Relaxed), ComputeError: "query interrupted");
-        Ok(())
+        self.stop.check()
     }
 
-    pub fn cancel_token(&self) -> Arc<AtomicBool> {
+    pub fn cancel_token(&self) -> CancellationToken {
         self.stop.clone()
     }
 
+    /// Cancel the query when `token` is cancelled.
+    pub fn set_cancel_token(&mut self, token: CancellationToken) {
+        self.stop = token;
+    }
+
+    pub fn memory_limit(&self) -> Option<&MemoryLimit> {
+        self.memory_limit.as_ref()
+    }
+
+    /// Fail the query if its intermediate results exceed `limit`, see [`MemoryLimit`].
+    pub fn set_memory_limit(&mut self, limit: MemoryLimit) {
+        self.memory_limit = Some(limit);
+    }
+
+    /// Reserve the memory of `df`, an intermediate result of `what`, if the query has a
+    /// memory limit.
+    pub fn reserve_memory(
+        &self,
+        df: &DataFrame,
+        what: &str,
+    ) -> PolarsResult<Option<MemoryReservation>> {
+        self.reserve_bytes(|| df.estimated_size(), what)
+    }
+
+    /// Like [`ExecutionState::reserve_memory`], for an intermediate result that isn't a
+    /// [`DataFrame`]. `bytes` is only computed if the query has a memory limit.
+    pub fn reserve_bytes(
+        &self,
+        bytes: impl FnOnce() -> usize,
+        what: &str,
+    ) -> PolarsResult<Option<MemoryReservation>> {
+        self.memory_limit
+            .as_ref()
+            .map(|limit| limit.reserve(bytes(), what))
+            .transpose()
+    }
+
     pub fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
+        let _span = polars_span!(target: "polars::execute", "execute", node = name);
         match &self.node_timer {
             None => func(),
             Some(timer) => {
@@ -142,6 +194,7 @@ impl ExecutionState {
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
+            memory_limit: self.memory_limit.clone(),
         }
     }
 
@@ -264,6 +317,7 @@ impl Clone for ExecutionState {
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
+            memory_limit: self.memory_limit.clone(),
         }
     }
 }
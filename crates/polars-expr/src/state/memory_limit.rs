@@ -0,0 +1,107 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+
+thread_local! {
+    static CURRENT: RefCell<Option<MemoryLimit>> = const { RefCell::new(None) };
+}
+
+/// The memory budget of the intermediate results of a single query.
+///
+/// Nodes that materialize large intermediate results, like the build side of a join, the
+/// groups of a group by or the buffers of a sort, reserve their memory before they continue.
+/// If a reservation exceeds the limit, the query fails instead of running the process out of
+/// memory. Clones share the same budget.
+#[derive(Clone, Debug)]
+pub struct MemoryLimit {
+    limit: usize,
+    in_use: Arc<AtomicUsize>,
+}
+
+impl MemoryLimit {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            in_use: Default::default(),
+        }
+    }
+
+    /// The limit in bytes.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// The bytes currently reserved.
+    pub fn in_use(&self) -> usize {
+        self.in_use.load(Ordering::Relaxed)
+    }
+
+    /// Reserve `bytes` for `what`, e.g. `"the join"`. The bytes are released when the returned
+    /// reservation is dropped.
+    pub fn reserve(&self, bytes: usize, what: &str) -> PolarsResult<MemoryReservation> {
+        let previous = self.in_use.fetch_add(bytes, Ordering::Relaxed);
+        let reservation = MemoryReservation {
+            in_use: self.in_use.clone(),
+            bytes,
+        };
+        if previous + bytes > self.limit {
+            polars_bail!(
+                ComputeError: "{} needs {} bytes, but {} of the {} bytes of the query's memory \
+                limit are already in use; consider running the query with streaming, or raising \
+                the limit",
+                what, bytes, previous, self.limit
+            );
+        }
+        Ok(reservation)
+    }
+
+    /// Account `bytes` that are held by a node that spills to disk once the limit is exceeded,
+    /// instead of failing like a [reservation](MemoryLimit::reserve).
+    pub fn track(&self, bytes: usize) {
+        self.in_use.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// Stop accounting `bytes` that were [tracked](MemoryLimit::track).
+    pub fn untrack(&self, bytes: usize) {
+        self.in_use.fetch_sub(bytes, Ordering::Relaxed);
+    }
+
+    /// Whether the bytes in use exceed the limit.
+    pub fn is_exceeded(&self) -> bool {
+        self.in_use() > self.limit
+    }
+
+    /// Run `func` with this limit as the [current](MemoryLimit::current) limit of this thread.
+    pub fn scope<T>(&self, func: impl FnOnce() -> T) -> T {
+        struct Reset(Option<MemoryLimit>);
+        impl Drop for Reset {
+            fn drop(&mut self) {
+                CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+            }
+        }
+
+        let previous = CURRENT.with(|current| current.borrow_mut().replace(self.clone()));
+        let _reset = Reset(previous);
+        func()
+    }
+
+    /// The limit set by the innermost [`MemoryLimit::scope`] on this thread.
+    pub fn current() -> Option<Self> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+}
+
+/// Memory reserved in a [`MemoryLimit`], which is released on drop.
+#[must_use]
+pub struct MemoryReservation {
+    in_use: Arc<AtomicUsize>,
+    bytes: usize,
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.in_use.fetch_sub(self.bytes, Ordering::Relaxed);
+    }
+}
@@ -1,5 +1,7 @@
 mod execution_state;
+mod memory_limit;
 mod node_timer;
 
 pub use execution_state::*;
+pub use memory_limit::*;
 use node_timer::*;
@@ -1,4 +1,5 @@
 mod read;
+mod resolve;
 mod write;
 
 pub use read::*;
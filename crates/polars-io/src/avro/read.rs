@@ -1,10 +1,12 @@
 use std::io::{Read, Seek};
 
+use arrow::io::avro::avro_schema::file::FileMetadata;
 use arrow::io::avro::{self, read};
 use arrow::record_batch::RecordBatch;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
 
+use super::resolve::SchemaResolution;
 use crate::prelude::*;
 use crate::shared::{finish_reader, ArrowReader};
 
@@ -33,6 +35,7 @@ pub struct AvroReader<R> {
     n_rows: Option<usize>,
     columns: Option<Vec<String>>,
     projection: Option<Vec<usize>>,
+    reader_schema: Option<String>,
 }
 
 impl<R: Read + Seek> AvroReader<R> {
@@ -46,8 +49,12 @@ impl<R: Read + Seek> AvroReader<R> {
     pub fn arrow_schema(&mut self) -> PolarsResult<ArrowSchema> {
         let metadata =
             avro::avro_schema::read::read_metadata(&mut self.reader).map_err(to_compute_err)?;
-        let schema = read::infer_schema(&metadata.record)?;
-        Ok(schema)
+        match &self.reader_schema {
+            Some(reader_schema) => {
+                Ok(SchemaResolution::new(reader_schema, &metadata.record)?.schema)
+            },
+            None => read::infer_schema(&metadata.record),
+        }
     }
 
     /// Stop reading when `n` rows are read.
@@ -68,6 +75,39 @@ impl<R: Read + Seek> AvroReader<R> {
         self.columns = columns;
         self
     }
+
+    /// Read the data with this schema instead of the schema the file was written with. The
+    /// schema is given as the JSON representation of an Avro record and is resolved against the
+    /// schema of the file following Avro's schema resolution rules: fields are matched by name
+    /// or alias, fields missing in the file are filled with their default value and numeric
+    /// types may be promoted. Projections refer to the fields of this schema.
+    pub fn with_reader_schema(mut self, reader_schema: Option<String>) -> Self {
+        self.reader_schema = reader_schema;
+        self
+    }
+
+    fn read(self, metadata: FileMetadata, schema: ArrowSchema) -> PolarsResult<DataFrame> {
+        let (projection, projected_schema) = if let Some(projection) = self.projection {
+            let mut prj = vec![false; schema.fields.len()];
+            for &index in projection.iter() {
+                prj[index] = true;
+            }
+            (Some(prj), apply_projection(&schema, &projection))
+        } else {
+            (None, schema.clone())
+        };
+
+        let avro_reader = avro::read::Reader::new(self.reader, metadata, schema.fields, projection);
+
+        finish_reader(
+            avro_reader,
+            self.rechunk,
+            self.n_rows,
+            None,
+            &projected_schema,
+            None,
+        )
+    }
 }
 
 impl<R> ArrowReader for read::Reader<R>
@@ -90,6 +130,7 @@ where
             n_rows: None,
             columns: None,
             projection: None,
+            reader_schema: None,
         }
     }
 
@@ -99,35 +140,44 @@ where
     }
 
     fn finish(mut self) -> PolarsResult<DataFrame> {
-        let rechunk = self.rechunk;
         let metadata =
             avro::avro_schema::read::read_metadata(&mut self.reader).map_err(to_compute_err)?;
         let schema = read::infer_schema(&metadata.record)?;
 
-        if let Some(columns) = &self.columns {
-            self.projection = Some(columns_to_projection(columns, &schema)?);
-        }
-
-        let (projection, projected_schema) = if let Some(projection) = self.projection {
-            let mut prj = vec![false; schema.fields.len()];
-            for &index in projection.iter() {
-                prj[index] = true;
+        let Some(reader_schema) = self.reader_schema.take() else {
+            if let Some(columns) = &self.columns {
+                self.projection = Some(columns_to_projection(columns, &schema)?);
             }
-            (Some(prj), apply_projection(&schema, &projection))
-        } else {
-            (None, schema.clone())
+            return self.read(metadata, schema);
         };
 
-        let avro_reader =
-            avro::read::Reader::new(&mut self.reader, metadata, schema.fields, projection);
+        let resolution = SchemaResolution::new(&reader_schema, &metadata.record)?;
+        let projection = match (&self.columns, self.projection.take()) {
+            (Some(columns), _) => columns_to_projection(columns, &resolution.schema)?,
+            (None, Some(projection)) => projection,
+            (None, None) => (0..resolution.schema.fields.len()).collect(),
+        };
 
-        finish_reader(
-            avro_reader,
-            rechunk,
-            self.n_rows,
-            None,
-            &projected_schema,
-            None,
-        )
+        let mut writer_projection = resolution
+            .writer_columns(&projection)
+            .into_iter()
+            .map(|name| {
+                schema
+                    .fields
+                    .iter()
+                    .position(|field| field.name == name)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        // Read at least one column to know the number of rows.
+        if writer_projection.is_empty() && !schema.fields.is_empty() {
+            writer_projection.push(0);
+        }
+        writer_projection.sort_unstable();
+        writer_projection.dedup();
+        self.projection = Some(writer_projection);
+
+        let df = self.read(metadata, schema)?;
+        resolution.apply(&df, &projection)
     }
 }
@@ -0,0 +1,177 @@
+//! Resolution of the schema an Avro file was written with against the schema it is read with,
+//! following the [schema resolution] rules of the Avro specification.
+//!
+//! [schema resolution]: https://avro.apache.org/docs/current/specification/#schema-resolution
+use arrow::io::avro::avro_schema::schema::{Record, Schema as AvroSchema};
+use arrow::io::avro::read;
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use serde_json::Value;
+
+/// Where the values of a field of the reader schema come from.
+enum ResolvedField {
+    /// The field of the writer schema with this name.
+    Writer(String),
+    /// The default value of the field, the writer schema doesn't contain the field.
+    Default(Value),
+}
+
+pub(super) struct SchemaResolution {
+    /// The schema the data is read as.
+    pub(super) schema: ArrowSchema,
+    fields: Vec<ResolvedField>,
+}
+
+impl SchemaResolution {
+    /// Resolves the `writer` schema of a file against the `reader` schema, given as the JSON
+    /// representation of an Avro record.
+    pub(super) fn new(reader: &str, writer: &Record) -> PolarsResult<Self> {
+        let (reader, defaults) = parse_reader_schema(reader)?;
+        let schema = read::infer_schema(&reader)?;
+        let writer_schema = read::infer_schema(writer)?;
+
+        let fields = reader
+            .fields
+            .iter()
+            .zip(defaults)
+            .zip(&schema.fields)
+            .map(|((reader_field, default), field)| {
+                let writer_field = writer.fields.iter().zip(&writer_schema.fields).find(
+                    |(writer_field, _)| {
+                        writer_field.name == reader_field.name
+                            || reader_field.aliases.contains(&writer_field.name)
+                    },
+                );
+                match writer_field {
+                    Some((writer_field, writer_arrow_field)) => {
+                        let (writer_type, reader_type) =
+                            (non_null(&writer_field.schema), non_null(&reader_field.schema));
+                        polars_ensure!(
+                            writer_arrow_field.data_type == field.data_type
+                                || can_promote(writer_type, reader_type),
+                            SchemaMismatch: "cannot read Avro field '{}' of type {:?} as {:?}",
+                            writer_field.name, writer_type, reader_type
+                        );
+                        Ok(ResolvedField::Writer(writer_field.name.clone()))
+                    },
+                    None => default.map(ResolvedField::Default).ok_or_else(|| {
+                        polars_err!(
+                            SchemaMismatch: "Avro field '{}' of the reader schema is not in the file and has no default",
+                            reader_field.name
+                        )
+                    }),
+                }
+            })
+            .collect::<PolarsResult<_>>()?;
+
+        Ok(Self { schema, fields })
+    }
+
+    /// Returns the names of the columns that have to be read from the file for the fields at
+    /// `projection`.
+    pub(super) fn writer_columns(&self, projection: &[usize]) -> Vec<&str> {
+        projection
+            .iter()
+            .filter_map(|&i| match &self.fields[i] {
+                ResolvedField::Writer(name) => Some(name.as_str()),
+                ResolvedField::Default(_) => None,
+            })
+            .collect()
+    }
+
+    /// Converts the columns read from the file to the fields at `projection`.
+    pub(super) fn apply(&self, df: &DataFrame, projection: &[usize]) -> PolarsResult<DataFrame> {
+        let columns = projection
+            .iter()
+            .map(|&i| {
+                let field = &self.schema.fields[i];
+                let dtype = DataType::from_arrow(&field.data_type, true);
+                let mut s = match &self.fields[i] {
+                    ResolvedField::Writer(name) => df.column(name)?.strict_cast(&dtype)?,
+                    ResolvedField::Default(value) => {
+                        default_series(value, &dtype)?.new_from_index(0, df.height())
+                    },
+                };
+                s.rename(&field.name);
+                Ok(s)
+            })
+            .collect::<PolarsResult<_>>()?;
+        DataFrame::new(columns)
+    }
+}
+
+/// Parses the reader schema. The default values are returned separately, as they are not
+/// part of the parsed schema.
+fn parse_reader_schema(reader: &str) -> PolarsResult<(Record, Vec<Option<Value>>)> {
+    let mut value: Value = serde_json::from_str(reader).map_err(to_compute_err)?;
+    let defaults = match value.get_mut("fields") {
+        Some(Value::Array(fields)) => fields
+            .iter_mut()
+            .map(|field| {
+                field
+                    .as_object_mut()
+                    .and_then(|field| field.remove("default"))
+            })
+            .collect(),
+        _ => vec![],
+    };
+    remove_defaults(&mut value);
+
+    match serde_json::from_value(value).map_err(to_compute_err)? {
+        AvroSchema::Record(record) => Ok((record, defaults)),
+        schema => {
+            polars_bail!(ComputeError: "Avro reader schema must be a record, got {:?}", schema)
+        },
+    }
+}
+
+/// Removes the default values of nested fields, they are not used in the resolution.
+fn remove_defaults(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.remove("default");
+            map.values_mut().for_each(remove_defaults);
+        },
+        Value::Array(values) => values.iter_mut().for_each(remove_defaults),
+        _ => {},
+    }
+}
+
+/// Returns the non-null type of a nullable union.
+fn non_null(schema: &AvroSchema) -> &AvroSchema {
+    match schema {
+        AvroSchema::Union(schemas) if schemas.len() == 2 && schemas[0] == AvroSchema::Null => {
+            &schemas[1]
+        },
+        AvroSchema::Union(schemas) if schemas.len() == 2 && schemas[1] == AvroSchema::Null => {
+            &schemas[0]
+        },
+        _ => schema,
+    }
+}
+
+fn can_promote(writer: &AvroSchema, reader: &AvroSchema) -> bool {
+    use AvroSchema::*;
+    matches!(
+        (writer, reader),
+        (Int(None), Long(None) | Float | Double)
+            | (Long(None), Float | Double)
+            | (Float, Double)
+            | (String(None), Bytes(None))
+            | (Bytes(None), String(None))
+    )
+}
+
+fn default_series(value: &Value, dtype: &DataType) -> PolarsResult<Series> {
+    let s = match value {
+        Value::Null => return Ok(Series::full_null("", 1, dtype)),
+        Value::Bool(v) => Series::new("", [*v]),
+        Value::Number(v) => match v.as_i64() {
+            Some(v) => Series::new("", [v]),
+            None => Series::new("", [v.as_f64().unwrap()]),
+        },
+        Value::String(v) => Series::new("", [v.as_str()]),
+        _ => polars_bail!(nyi = "Avro default value {value}"),
+    };
+    s.strict_cast(dtype)
+}
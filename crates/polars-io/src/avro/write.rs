@@ -45,6 +45,7 @@ where
         self
     }
 
+    /// Set the name of the Avro record schema. Defaults to `"record"`.
     pub fn with_name(mut self, name: String) -> Self {
         self.name = name;
         self
@@ -59,7 +60,7 @@ where
         Self {
             writer,
             compression: None,
-            name: "".to_string(),
+            name: "record".to_string(),
         }
     }
 
@@ -67,6 +68,11 @@ where
         let schema = schema_to_arrow_checked(&df.schema(), false, "avro")?;
         let record = write::to_record(&schema, self.name.clone())?;
 
+        // The header (schema and codec) must be written exactly once, every chunk is
+        // appended as a separate data block.
+        avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
+            .map_err(to_compute_err)?;
+
         let mut data = vec![];
         let mut compressed_block = avro_schema::file::CompressedBlock::default();
         for chunk in df.iter_chunks(false, true) {
@@ -83,9 +89,6 @@ where
                 avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
                     .map_err(to_compute_err)?;
 
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
-
             avro_schema::write::write_block(&mut self.writer, &compressed_block)
                 .map_err(to_compute_err)?;
             // reuse block for next iteration.
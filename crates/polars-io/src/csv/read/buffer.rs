@@ -1,11 +1,12 @@
-use arrow::array::MutableBinaryViewArray;
+use arrow::array::{MutableBinaryViewArray, View, INLINE_VIEW_SIZE};
+use arrow::buffer::Buffer as ArrowBuffer;
 use polars_core::prelude::*;
 use polars_error::to_compute_err;
 #[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
 use polars_time::chunkedarray::string::Pattern;
 #[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
 use polars_time::prelude::string::infer::{
-    infer_pattern_single, DatetimeInfer, StrpTimeParser, TryFromWithUnit,
+    infer_pattern_single, DatetimeInfer, FormatParser, StrpTimeParser, TryFromWithUnit,
 };
 use polars_utils::vec::PushUnchecked;
 
@@ -152,17 +153,71 @@ pub(crate) struct Utf8Field {
     scratch: Vec<u8>,
     quote_char: u8,
     encoding: CsvEncoding,
+    shared: Option<SharedBytes>,
+}
+
+/// The parsed bytes, if values may refer to them instead of being copied.
+struct SharedBytes {
+    bytes: ArrowBuffer<u8>,
+    /// The buffer index and the start in `bytes` of the last window of `bytes` that was added
+    /// to the array. Views have `u32` offsets, so they can't address all of a large file.
+    window: Option<(u32, usize)>,
 }
 
 impl Utf8Field {
-    fn new(name: &str, capacity: usize, quote_char: Option<u8>, encoding: CsvEncoding) -> Self {
+    fn new(
+        name: &str,
+        capacity: usize,
+        quote_char: Option<u8>,
+        encoding: CsvEncoding,
+        shared_bytes: Option<&ArrowBuffer<u8>>,
+    ) -> Self {
         Self {
             name: name.to_string(),
             mutable: MutableBinaryViewArray::with_capacity(capacity),
             scratch: vec![],
             quote_char: quote_char.unwrap_or(b'"'),
             encoding,
+            shared: shared_bytes.map(|bytes| SharedBytes {
+                bytes: bytes.clone(),
+                window: None,
+            }),
+        }
+    }
+
+    /// Push `value`, which must be valid UTF-8, as a view into the shared bytes. Returns false
+    /// if `value` is not a part of them.
+    fn push_shared(&mut self, value: &[u8]) -> bool {
+        let Some(shared) = &mut self.shared else {
+            return false;
+        };
+        let Some(start) = (value.as_ptr() as usize).checked_sub(shared.bytes.as_ptr() as usize)
+        else {
+            return false;
+        };
+        let end = start + value.len();
+        if end > shared.bytes.len() {
+            return false;
         }
+
+        let (buffer_idx, window_start) = match shared.window {
+            Some((buffer_idx, window_start))
+                if start >= window_start && end - window_start <= u32::MAX as usize =>
+            {
+                (buffer_idx, window_start)
+            },
+            _ => {
+                let len = (shared.bytes.len() - start).min(u32::MAX as usize);
+                let window = shared.bytes.clone().sliced(start, len);
+                let buffer_idx = self.mutable.push_buffer(window);
+                shared.window = Some((buffer_idx, start));
+                (buffer_idx, start)
+            },
+        };
+        let view = View::new_from_bytes(value, buffer_idx, (start - window_start) as u32);
+        // SAFETY: the view is in bounds of its window and the caller validated the UTF-8.
+        unsafe { self.mutable.push_view_unchecked(view) };
+        true
     }
 }
 
@@ -214,8 +269,15 @@ impl ParsedBuffer for Utf8Field {
 
         match parse_result {
             true => {
-                let value = unsafe { std::str::from_utf8_unchecked(escaped_bytes) };
-                self.mutable.push_value(value)
+                // Short values are inlined into their view, there's nothing to share. Escaped
+                // values are in the scratch buffer, so only unescaped values can be shared.
+                if needs_escaping || escaped_bytes.len() <= INLINE_VIEW_SIZE as usize {
+                    let value = unsafe { std::str::from_utf8_unchecked(escaped_bytes) };
+                    self.mutable.push_value(value)
+                } else if !self.push_shared(bytes) {
+                    let value = unsafe { std::str::from_utf8_unchecked(bytes) };
+                    self.mutable.push_value(value)
+                }
             },
             false => {
                 if matches!(self.encoding, CsvEncoding::LossyUtf8) {
@@ -354,19 +416,45 @@ impl ParsedBuffer for BooleanChunkedBuilder {
 pub(crate) struct DatetimeField<T: PolarsNumericType> {
     compiled: Option<DatetimeInfer<T>>,
     builder: PrimitiveChunkedBuilder<T>,
+    /// User provided formats, tried in order before the inferred pattern.
+    formats: Option<Arc<[String]>>,
+    /// Index of the format that parsed the latest value.
+    latest_format: usize,
 }
 
 #[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
 impl<T: PolarsNumericType> DatetimeField<T> {
-    fn new(name: &str, capacity: usize) -> Self {
+    fn new(name: &str, capacity: usize, formats: Option<Arc<[String]>>) -> Self {
         let builder = PrimitiveChunkedBuilder::<T>::new(name, capacity);
         Self {
             compiled: None,
             builder,
+            formats,
+            latest_format: 0,
         }
     }
 }
 
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
+impl<T: FormatParser> DatetimeField<T> {
+    /// Parse with the user provided formats, starting with the one that matched last.
+    fn parse_with_formats(
+        &mut self,
+        bytes: &[u8],
+        time_unit: Option<TimeUnit>,
+    ) -> Option<T::Native> {
+        let formats = self.formats.as_deref()?;
+        let val = std::str::from_utf8(bytes).ok()?;
+        let n = formats.len();
+        let start = self.latest_format;
+        (0..n).map(|i| (start + i) % n).find_map(|i| {
+            let parsed = T::parse_with_format(val, &formats[i], time_unit)?;
+            self.latest_format = i;
+            Some(parsed)
+        })
+    }
+}
+
 #[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
 fn slow_datetime_parser<T>(
     buf: &mut DatetimeField<T>,
@@ -440,7 +528,7 @@ where
 #[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
 impl<T> ParsedBuffer for DatetimeField<T>
 where
-    T: PolarsNumericType,
+    T: FormatParser,
     DatetimeInfer<T>: TryFromWithUnit<Pattern> + StrpTimeParser<T::Native>,
 {
     #[inline]
@@ -462,6 +550,11 @@ where
             return Ok(());
         }
 
+        if let Some(parsed) = self.parse_with_formats(bytes, time_unit) {
+            self.builder.append_value(parsed);
+            return Ok(());
+        }
+
         match &mut self.compiled {
             None => slow_datetime_parser(self, bytes, time_unit, ignore_errors),
             Some(compiled) => {
@@ -480,6 +573,7 @@ where
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn init_buffers(
     projection: &[usize],
     capacity: usize,
@@ -487,7 +581,11 @@ pub(crate) fn init_buffers(
     quote_char: Option<u8>,
     encoding: CsvEncoding,
     decimal_comma: bool,
+    datetime_formats: Option<&Arc<[String]>>,
+    shared_bytes: Option<&ArrowBuffer<u8>>,
 ) -> PolarsResult<Vec<Buffer>> {
+    #[cfg(not(any(feature = "dtype-datetime", feature = "dtype-date")))]
+    let _ = datetime_formats;
     projection
         .iter()
         .map(|&i| {
@@ -526,17 +624,25 @@ pub(crate) fn init_buffers(
                         Buffer::Float64(PrimitiveChunkedBuilder::new(name, capacity))
                     }
                 },
-                &DataType::String => {
-                    Buffer::Utf8(Utf8Field::new(name, capacity, quote_char, encoding))
-                },
+                &DataType::String => Buffer::Utf8(Utf8Field::new(
+                    name,
+                    capacity,
+                    quote_char,
+                    encoding,
+                    shared_bytes,
+                )),
                 #[cfg(feature = "dtype-datetime")]
                 DataType::Datetime(time_unit, time_zone) => Buffer::Datetime {
-                    buf: DatetimeField::new(name, capacity),
+                    buf: DatetimeField::new(name, capacity, datetime_formats.cloned()),
                     time_unit: *time_unit,
                     time_zone: time_zone.clone(),
                 },
                 #[cfg(feature = "dtype-date")]
-                &DataType::Date => Buffer::Date(DatetimeField::new(name, capacity)),
+                &DataType::Date => Buffer::Date(DatetimeField::new(
+                    name,
+                    capacity,
+                    datetime_formats.cloned(),
+                )),
                 #[cfg(feature = "dtype-categorical")]
                 DataType::Categorical(_, ordering) => Buffer::Categorical(CategoricalField::new(
                     name, capacity, quote_char, *ordering,
@@ -17,6 +17,7 @@ pub struct CsvReadOptions {
     pub rechunk: bool,
     pub n_threads: Option<usize>,
     pub low_memory: bool,
+    pub zero_copy_strings: bool,
     // Row-wise options
     pub n_rows: Option<usize>,
     pub row_index: Option<RowIndex>,
@@ -47,9 +48,12 @@ pub struct CsvParseOptions {
     pub encoding: CsvEncoding,
     pub null_values: Option<NullValues>,
     pub missing_is_null: bool,
+    #[deprecated(note = "use `ragged_lines` instead")]
     pub truncate_ragged_lines: bool,
+    pub ragged_lines: RaggedLines,
     pub comment_prefix: Option<CommentPrefix>,
     pub try_parse_dates: bool,
+    pub datetime_formats: Option<Arc<[String]>>,
     pub decimal_comma: bool,
 }
 
@@ -61,6 +65,7 @@ impl Default for CsvReadOptions {
             rechunk: true,
             n_threads: None,
             low_memory: false,
+            zero_copy_strings: false,
 
             n_rows: None,
             row_index: None,
@@ -86,6 +91,7 @@ impl Default for CsvReadOptions {
 
 /// Options related to parsing the CSV format.
 impl Default for CsvParseOptions {
+    #[allow(deprecated)]
     fn default() -> Self {
         Self {
             separator: b',',
@@ -96,8 +102,10 @@ impl Default for CsvParseOptions {
             missing_is_null: true,
 
             truncate_ragged_lines: false,
+            ragged_lines: RaggedLines::default(),
             comment_prefix: None,
             try_parse_dates: false,
+            datetime_formats: None,
             decimal_comma: false,
         }
     }
@@ -132,6 +140,15 @@ impl CsvReadOptions {
         self
     }
 
+    /// Let string values refer to the memory mapped or in-memory file instead of copying
+    /// them, if they don't need unescaping. This saves copying most of the file for large,
+    /// mostly string files, but keeps the whole file in memory as long as such a value is
+    /// alive.
+    pub fn with_zero_copy_strings(mut self, zero_copy_strings: bool) -> Self {
+        self.zero_copy_strings = zero_copy_strings;
+        self
+    }
+
     /// Limits the number of rows to read.
     pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
         self.n_rows = n_rows;
@@ -289,11 +306,33 @@ impl CsvParseOptions {
     }
 
     /// Truncate lines that are longer than the schema.
-    pub fn with_truncate_ragged_lines(mut self, truncate_ragged_lines: bool) -> Self {
-        self.truncate_ragged_lines = truncate_ragged_lines;
+    pub fn with_truncate_ragged_lines(self, truncate_ragged_lines: bool) -> Self {
+        self.with_ragged_lines(if truncate_ragged_lines {
+            RaggedLines::Truncate
+        } else {
+            RaggedLines::Pad
+        })
+    }
+
+    /// Set how lines with more or fewer fields than the schema are handled.
+    #[allow(deprecated)]
+    pub fn with_ragged_lines(mut self, ragged_lines: RaggedLines) -> Self {
+        self.truncate_ragged_lines = ragged_lines == RaggedLines::Truncate;
+        self.ragged_lines = ragged_lines;
         self
     }
 
+    /// The policy for ragged lines, where a `truncate_ragged_lines` that is set directly
+    /// takes precedence over `ragged_lines`.
+    #[allow(deprecated)]
+    pub fn get_ragged_lines(&self) -> RaggedLines {
+        if self.truncate_ragged_lines {
+            RaggedLines::Truncate
+        } else {
+            self.ragged_lines
+        }
+    }
+
     /// Sets the comment prefix for this instance. Lines starting with this
     /// prefix will be ignored.
     pub fn with_comment_prefix<T: Into<CommentPrefix>>(
@@ -311,6 +350,16 @@ impl CsvParseOptions {
         self
     }
 
+    /// Formats to try, in order of priority, when parsing dates/datetimes. Values
+    /// that match none of them fall back to the inferred pattern.
+    ///
+    /// During schema inference, string columns whose values all match one of
+    /// these formats are read as [`DataType::Date`] or [`DataType::Datetime`].
+    pub fn with_datetime_formats(mut self, datetime_formats: Option<Arc<[String]>>) -> Self {
+        self.datetime_formats = datetime_formats;
+        self
+    }
+
     /// Parse floats with a comma as decimal separator.
     pub fn with_decimal_comma(mut self, decimal_comma: bool) -> Self {
         self.decimal_comma = decimal_comma;
@@ -318,6 +367,23 @@ impl CsvParseOptions {
     }
 }
 
+/// How to handle lines that have a different number of fields than the schema.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RaggedLines {
+    /// Pad lines with too few fields with nulls and raise on lines with too many fields.
+    #[default]
+    Pad,
+    /// Pad lines with too few fields with nulls and drop the surplus fields of lines
+    /// with too many fields.
+    Truncate,
+    /// Raise on lines with too few or too many fields.
+    ///
+    /// When only a subset of the columns is read, the fields after the last projected
+    /// column are not inspected, so only missing projected fields are detected.
+    Raise,
+}
+
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CsvEncoding {
@@ -373,7 +439,8 @@ pub enum NullValues {
     AllColumnsSingle(String),
     /// Multiple values that are used for all columns
     AllColumns(Vec<String>),
-    /// Tuples that map column names to null value of that column
+    /// Tuples that map column names to null value of that column. A column can be
+    /// listed multiple times to use multiple null values for it.
     Named(Vec<(String, String)>),
 }
 
@@ -383,10 +450,15 @@ impl NullValues {
             NullValues::AllColumnsSingle(v) => NullValuesCompiled::AllColumnsSingle(v),
             NullValues::AllColumns(v) => NullValuesCompiled::AllColumns(v),
             NullValues::Named(v) => {
-                let mut null_values = vec!["".to_string(); schema.len()];
+                let mut null_values = vec![vec![]; schema.len()];
                 for (name, null_value) in v {
                     let i = schema.try_index_of(&name)?;
-                    null_values[i] = null_value;
+                    null_values[i].push(null_value);
+                }
+                for column_null_values in null_values.iter_mut() {
+                    if column_null_values.is_empty() {
+                        column_null_values.push("".to_string());
+                    }
                 }
                 NullValuesCompiled::Columns(null_values)
             },
@@ -400,8 +472,8 @@ pub(super) enum NullValuesCompiled {
     AllColumnsSingle(String),
     // Multiple null values that are null for all columns
     AllColumns(Vec<String>),
-    /// A different null value per column, computed from `NullValues::Named`
-    Columns(Vec<String>),
+    /// Different null values per column, computed from `NullValues::Named`
+    Columns(Vec<Vec<String>>),
 }
 
 impl NullValuesCompiled {
@@ -415,7 +487,7 @@ impl NullValuesCompiled {
             AllColumns(v) => v.iter().any(|v| v.as_bytes() == field),
             Columns(v) => {
                 debug_assert!(index < v.len());
-                v.get_unchecked(index).as_bytes() == field
+                v.get_unchecked(index).iter().any(|v| v.as_bytes() == field)
             },
         }
     }
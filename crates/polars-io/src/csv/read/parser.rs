@@ -9,7 +9,7 @@ use polars_utils::slice::GetSaferUnchecked;
 use rayon::prelude::*;
 
 use super::buffer::Buffer;
-use super::options::{CommentPrefix, NullValuesCompiled};
+use super::options::{CommentPrefix, NullValuesCompiled, RaggedLines};
 use super::splitfields::SplitFields;
 use super::utils::get_file_chunks;
 use crate::prelude::is_cloud_url;
@@ -403,6 +403,28 @@ fn find_quoted(bytes: &[u8], quote_char: u8, needle: u8) -> Option<usize> {
     None
 }
 
+/// Get the position after the line ending of the last complete line in `bytes`, which must
+/// start at the start of a line.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+pub(super) fn last_line_end(bytes: &[u8], quote_char: Option<u8>, eol_char: u8) -> Option<usize> {
+    let Some(quote_char) = quote_char else {
+        return bytes
+            .iter()
+            .rposition(|&c| c == eol_char)
+            .map(|pos| pos + 1);
+    };
+    let mut in_field = false;
+    let mut end = None;
+    for (pos, &c) in bytes.iter().enumerate() {
+        if c == quote_char {
+            in_field = !in_field;
+        } else if c == eol_char && !in_field {
+            end = Some(pos + 1);
+        }
+    }
+    end
+}
+
 #[inline]
 pub(super) fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
     let pos = match quote {
@@ -434,7 +456,7 @@ pub(super) fn parse_lines(
     eol_char: u8,
     missing_is_null: bool,
     ignore_errors: bool,
-    mut truncate_ragged_lines: bool,
+    ragged_lines: RaggedLines,
     null_values: Option<&NullValuesCompiled>,
     projection: &[usize],
     buffers: &mut [Buffer],
@@ -450,9 +472,8 @@ pub(super) fn parse_lines(
     // During projection pushdown we are not checking other csv fields.
     // This would be very expensive and we don't care as we only want
     // the projected columns.
-    if projection.len() != schema_len {
-        truncate_ragged_lines = true
-    }
+    let truncate_ragged_lines =
+        ragged_lines == RaggedLines::Truncate || projection.len() != schema_len;
 
     // we use the pointers to track the no of bytes read.
     let start = bytes.as_ptr() as usize;
@@ -591,6 +612,11 @@ Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
         // there can be lines that miss fields (also the comma values)
         // this means the splitter won't process them.
         // We traverse them to read them as null values.
+        if processed_fields < projection.len() && ragged_lines == RaggedLines::Raise {
+            polars_bail!(ComputeError: r#"found fewer fields than defined in 'Schema'
+
+Consider setting 'ragged_lines' to pad missing fields with nulls."#)
+        }
         while processed_fields < projection.len() {
             debug_assert!(processed_fields < buffers.len());
             let buf = unsafe {
@@ -1,7 +1,10 @@
 pub(super) mod batched;
 
 use std::fmt;
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use std::io::Read;
 
+use arrow::buffer::Buffer as ArrowBuffer;
 use polars_core::config::verbose;
 use polars_core::prelude::*;
 use polars_core::utils::{accumulate_dataframes_vertical, handle_casting_failures};
@@ -12,22 +15,52 @@ use polars_utils::flatten;
 use rayon::prelude::*;
 
 use super::buffer::init_buffers;
-use super::options::{CommentPrefix, CsvEncoding, NullValues, NullValuesCompiled};
+use super::options::{CommentPrefix, CsvEncoding, NullValues, NullValuesCompiled, RaggedLines};
 use super::parser::{
     get_line_stats, is_comment_line, next_line_position, next_line_position_naive, parse_lines,
     skip_bom, skip_line_ending, skip_this_line, skip_whitespace_exclude,
 };
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use super::parser::{last_line_end, SplitLines};
 use super::schema_inference::{check_decimal_comma, infer_file_schema};
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
-use super::utils::decompress;
-use super::utils::get_file_chunks;
-#[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
-use super::utils::is_compressed;
+use super::utils::{decoder, decompress};
+use super::utils::{get_file_chunks, is_compressed};
 use crate::mmap::ReaderBytes;
 use crate::predicates::PhysicalIoExpr;
 use crate::utils::update_row_counts;
 use crate::RowIndex;
 
+/// The number of decompressed bytes that are parsed at once when reading a compressed file.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+const DECOMPRESSED_CHUNK_SIZE: usize = 1 << 26;
+
+/// Decompress about `DECOMPRESSED_CHUNK_SIZE` bytes at a time from `decoder` into `buf`, until
+/// the complete lines in `buf` satisfy `is_enough` or the decoder is depleted. Returns the end of
+/// the complete lines, or of `buf` if the decoder is depleted, and whether it is depleted.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+fn fill_decompressed(
+    decoder: &mut dyn Read,
+    buf: &mut Vec<u8>,
+    quote_char: Option<u8>,
+    eol_char: u8,
+    is_enough: impl Fn(&[u8]) -> bool,
+) -> PolarsResult<(usize, bool)> {
+    loop {
+        if (&mut *decoder)
+            .take(DECOMPRESSED_CHUNK_SIZE as u64)
+            .read_to_end(buf)?
+            == 0
+        {
+            return Ok((buf.len(), true));
+        }
+        match last_line_end(buf, quote_char, eol_char) {
+            Some(end) if is_enough(&buf[..end]) => return Ok((end, false)),
+            _ => {},
+        }
+    }
+}
+
 pub(crate) fn cast_columns(
     df: &mut DataFrame,
     to_cast: &[Field],
@@ -92,6 +125,9 @@ pub(crate) fn cast_columns(
 /// CSV file reader
 pub(crate) struct CoreReader<'a> {
     reader_bytes: Option<ReaderBytes<'a>>,
+    /// Whether the bytes to parse start at the start of the file, rather than at a later chunk
+    /// of a compressed file.
+    at_start_of_file: bool,
     /// Explicit schema for the CSV file
     schema: SchemaRef,
     /// Optional projection for which columns to load (zero-based column indices)
@@ -110,7 +146,9 @@ pub(crate) struct CoreReader<'a> {
     sample_size: usize,
     chunk_size: usize,
     low_memory: bool,
+    zero_copy_strings: bool,
     decimal_comma: bool,
+    datetime_formats: Option<Arc<[String]>>,
     comment_prefix: Option<CommentPrefix>,
     quote_char: Option<u8>,
     eol_char: u8,
@@ -119,7 +157,7 @@ pub(crate) struct CoreReader<'a> {
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     to_cast: Vec<Field>,
     row_index: Option<RowIndex>,
-    truncate_ragged_lines: bool,
+    ragged_lines: RaggedLines,
 }
 
 impl<'a> fmt::Debug for CoreReader<'a> {
@@ -152,6 +190,7 @@ impl<'a> CoreReader<'a> {
         sample_size: usize,
         chunk_size: usize,
         low_memory: bool,
+        zero_copy_strings: bool,
         comment_prefix: Option<CommentPrefix>,
         quote_char: Option<u8>,
         eol_char: u8,
@@ -162,8 +201,9 @@ impl<'a> CoreReader<'a> {
         skip_rows_after_header: usize,
         row_index: Option<RowIndex>,
         try_parse_dates: bool,
+        datetime_formats: Option<Arc<[String]>>,
         raise_if_empty: bool,
-        truncate_ragged_lines: bool,
+        ragged_lines: RaggedLines,
         decimal_comma: bool,
     ) -> PolarsResult<CoreReader<'a>> {
         let separator = separator.unwrap_or(b',');
@@ -179,25 +219,33 @@ impl<'a> CoreReader<'a> {
                 compile with feature 'decompress' or 'decompress-fast'"
             );
         }
-        // We keep track of the inferred schema bool
-        // In case the file is compressed this schema inference is wrong and has to be done
-        // again after decompression.
+        // Compressed files are decompressed in chunks while they are parsed, so we only
+        // decompress the rows that the schema is inferred from. If it is inferred from all
+        // rows, we decompress the whole file once.
         #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
-        {
-            let total_n_rows =
-                n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
-            if let Some(b) =
-                decompress(&reader_bytes, total_n_rows, separator, quote_char, eol_char)
-            {
-                reader_bytes = ReaderBytes::Owned(b);
+        let inference_bytes = if schema.is_none() && is_compressed(&reader_bytes) {
+            let n_rows =
+                max_records.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
+            match decompress(&reader_bytes, n_rows, separator, quote_char, eol_char) {
+                Some(b) if n_rows.is_none() => {
+                    reader_bytes = ReaderBytes::Owned(b);
+                    None
+                },
+                b => b,
             }
-        }
+        } else {
+            None
+        };
+        #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+        let inference_bytes: Option<Vec<u8>> = None;
+
+        let inference_bytes = inference_bytes.as_deref().map(ReaderBytes::Borrowed);
 
         let mut schema = match schema {
             Some(schema) => schema,
             None => {
                 let (inferred_schema, _, _) = infer_file_schema(
-                    &reader_bytes,
+                    inference_bytes.as_ref().unwrap_or(&reader_bytes),
                     separator,
                     max_records,
                     has_header,
@@ -209,6 +257,7 @@ impl<'a> CoreReader<'a> {
                     eol_char,
                     null_values.as_ref(),
                     try_parse_dates,
+                    datetime_formats.as_deref(),
                     raise_if_empty,
                     &mut n_threads,
                     decimal_comma,
@@ -237,6 +286,7 @@ impl<'a> CoreReader<'a> {
 
         Ok(CoreReader {
             reader_bytes: Some(reader_bytes),
+            at_start_of_file: true,
             schema,
             projection,
             current_line: usize::from(has_header),
@@ -251,6 +301,7 @@ impl<'a> CoreReader<'a> {
             sample_size,
             chunk_size,
             low_memory,
+            zero_copy_strings,
             comment_prefix,
             quote_char,
             eol_char,
@@ -259,8 +310,9 @@ impl<'a> CoreReader<'a> {
             predicate,
             to_cast,
             row_index,
-            truncate_ragged_lines,
+            ragged_lines,
             decimal_comma,
+            datetime_formats,
         })
     }
 
@@ -273,7 +325,9 @@ impl<'a> CoreReader<'a> {
         let starting_point_offset = bytes.as_ptr() as usize;
 
         // Skip all leading white space and the occasional utf8-bom
-        bytes = skip_whitespace_exclude(skip_bom(bytes), self.separator);
+        if self.at_start_of_file {
+            bytes = skip_whitespace_exclude(skip_bom(bytes), self.separator);
+        }
         // \n\n can be a empty string row of a single column
         // in other cases we skip it.
         if self.schema.len() > 1 {
@@ -466,6 +520,7 @@ impl<'a> CoreReader<'a> {
         mut n_threads: usize,
         bytes: &[u8],
         predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+        shared_bytes: Option<&ArrowBuffer<u8>>,
     ) -> PolarsResult<DataFrame> {
         let logging = verbose();
         let (file_chunks, chunk_size, total_rows, starting_point_offset, bytes, remaining_bytes) =
@@ -521,6 +576,8 @@ impl<'a> CoreReader<'a> {
                                 self.quote_char,
                                 self.encoding,
                                 self.decimal_comma,
+                                self.datetime_formats.as_ref(),
+                                shared_bytes,
                             )?;
 
                             let local_bytes = &bytes[read..stop_at_nbytes];
@@ -536,7 +593,7 @@ impl<'a> CoreReader<'a> {
                                 self.eol_char,
                                 self.missing_is_null,
                                 ignore_errors,
-                                self.truncate_ragged_lines,
+                                self.ragged_lines,
                                 self.null_values.as_ref(),
                                 projection,
                                 &mut buffers,
@@ -601,11 +658,13 @@ impl<'a> CoreReader<'a> {
                             self.encoding,
                             self.null_values.as_ref(),
                             self.missing_is_null,
-                            self.truncate_ragged_lines,
+                            self.ragged_lines,
                             usize::MAX,
                             stop_at_nbytes,
                             starting_point_offset,
                             self.decimal_comma,
+                            self.datetime_formats.as_ref(),
+                            shared_bytes,
                         )?;
 
                         cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
@@ -630,6 +689,8 @@ impl<'a> CoreReader<'a> {
                                 self.quote_char,
                                 self.encoding,
                                 self.decimal_comma,
+                                self.datetime_formats.as_ref(),
+                                shared_bytes,
                             )?;
 
                             parse_lines(
@@ -641,7 +702,7 @@ impl<'a> CoreReader<'a> {
                                 self.eol_char,
                                 self.missing_is_null,
                                 self.ignore_errors,
-                                self.truncate_ragged_lines,
+                                self.ragged_lines,
                                 self.null_values.as_ref(),
                                 &projection,
                                 &mut buffers,
@@ -673,14 +734,100 @@ impl<'a> CoreReader<'a> {
         }
     }
 
+    /// Decompress and parse a compressed file in chunks of rows, so that it is never in memory
+    /// as a whole.
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    fn parse_compressed(
+        &mut self,
+        n_threads: usize,
+        reader_bytes: &[u8],
+        predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+    ) -> PolarsResult<DataFrame> {
+        let mut decoder = decoder(reader_bytes)
+            .ok_or_else(|| polars_err!(ComputeError: "could not decompress the CSV file"))?;
+        let projection = self.projection.clone();
+        let (quote_char, eol_char) = (self.quote_char, self.eol_char);
+        // The rows that are skipped must be in the first chunk.
+        let rows_to_skip =
+            self.skip_rows_before_header + self.has_header as usize + self.skip_rows_after_header;
+        let has_rows_to_skip = |bytes: &[u8]| {
+            SplitLines::new(bytes, quote_char.unwrap_or(b'"'), eol_char).count() > rows_to_skip
+        };
+
+        let mut dfs = vec![];
+        let mut buf = vec![];
+        loop {
+            let at_start_of_file = self.at_start_of_file;
+            let (end, depleted) =
+                fill_decompressed(&mut *decoder, &mut buf, quote_char, eol_char, |bytes| {
+                    !at_start_of_file || has_rows_to_skip(bytes)
+                })?;
+
+            // `parse_csv` takes the projection.
+            self.projection.clone_from(&projection);
+            let mut df = if self.zero_copy_strings {
+                let bytes: ArrowBuffer<u8> = std::mem::take(&mut buf).into();
+                buf.extend_from_slice(&bytes[end..]);
+                self.parse_csv(n_threads, &bytes[..end], None, Some(&bytes))?
+            } else {
+                let df = self.parse_csv(n_threads, &buf[..end], None, None)?;
+                buf.drain(..end);
+                df
+            };
+
+            // The following chunks continue after the skipped rows and the header.
+            self.at_start_of_file = false;
+            self.has_header = false;
+            self.skip_rows_before_header = 0;
+            self.skip_rows_after_header = 0;
+
+            if let Some(n_rows) = self.n_rows {
+                if n_rows < df.height() {
+                    df = df.slice(0, n_rows)
+                }
+                self.n_rows = Some(n_rows - df.height());
+            }
+            if let Some(rc) = &mut self.row_index {
+                rc.offset += df.height() as IdxSize;
+            }
+            // The predicate is applied after the rows are counted, as `n_rows` and the row
+            // index refer to the rows in the file.
+            if let Some(predicate) = predicate {
+                let s = predicate.evaluate_io(&df)?;
+                df = df.filter(s.bool()?)?;
+            }
+            dfs.push(df);
+
+            if depleted || self.n_rows == Some(0) {
+                break;
+            }
+        }
+        accumulate_dataframes_vertical(dfs)
+    }
+
     /// Read the csv into a DataFrame. The predicate can come from a lazy physical plan.
     pub fn as_df(&mut self) -> PolarsResult<DataFrame> {
         let predicate = self.predicate.take();
         let n_threads = self.n_threads.unwrap_or_else(|| POOL.current_num_threads());
 
-        let reader_bytes = self.reader_bytes.take().unwrap();
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        if self.reader_bytes.as_deref().is_some_and(is_compressed) {
+            let reader_bytes = self.reader_bytes.take().unwrap();
+            return self.parse_compressed(n_threads, &reader_bytes, predicate.as_ref());
+        }
 
-        let mut df = self.parse_csv(n_threads, &reader_bytes, predicate.as_ref())?;
+        let mut reader_bytes = self.reader_bytes.take();
+        let shared_bytes = if self.zero_copy_strings {
+            into_shared_bytes(&mut reader_bytes)
+        } else {
+            None
+        };
+        let bytes = match &shared_bytes {
+            Some(shared_bytes) => shared_bytes.as_slice(),
+            None => reader_bytes.as_deref().unwrap(),
+        };
+
+        let mut df = self.parse_csv(n_threads, bytes, predicate.as_ref(), shared_bytes.as_ref())?;
 
         // if multi-threaded the n_rows was probabilistically determined.
         // Let's slice to correct number of rows if possible.
@@ -693,6 +840,20 @@ impl<'a> CoreReader<'a> {
     }
 }
 
+/// Take the bytes if we own them, so that string values can refer to them instead of copying
+/// them.
+fn into_shared_bytes(reader_bytes: &mut Option<ReaderBytes>) -> Option<ArrowBuffer<u8>> {
+    match reader_bytes.take()? {
+        ReaderBytes::Owned(bytes) => Some(bytes.into()),
+        #[cfg(not(target_family = "wasm"))]
+        ReaderBytes::Mapped(mmap, _) => Some(ArrowBuffer::from_owner(mmap)),
+        bytes => {
+            *reader_bytes = Some(bytes);
+            None
+        },
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn read_chunk(
     bytes: &[u8],
@@ -708,11 +869,13 @@ fn read_chunk(
     encoding: CsvEncoding,
     null_values: Option<&NullValuesCompiled>,
     missing_is_null: bool,
-    truncate_ragged_lines: bool,
+    ragged_lines: RaggedLines,
     chunk_size: usize,
     stop_at_nbytes: usize,
     starting_point_offset: Option<usize>,
     decimal_comma: bool,
+    datetime_formats: Option<&Arc<[String]>>,
+    shared_bytes: Option<&ArrowBuffer<u8>>,
 ) -> PolarsResult<DataFrame> {
     let mut read = bytes_offset_thread;
     // There's an off-by-one error somewhere in the reading code, where it reads
@@ -727,6 +890,8 @@ fn read_chunk(
         quote_char,
         encoding,
         decimal_comma,
+        datetime_formats,
+        shared_bytes,
     )?;
 
     let mut last_read = usize::MAX;
@@ -747,7 +912,7 @@ fn read_chunk(
             eol_char,
             missing_is_null,
             ignore_errors,
-            truncate_ragged_lines,
+            ragged_lines,
             null_values,
             projection,
             &mut buffers,
@@ -1,5 +1,8 @@
 use std::collections::VecDeque;
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use std::io::Read;
 use std::ops::Deref;
+use std::sync::Arc;
 
 use polars_core::datatypes::Field;
 use polars_core::frame::DataFrame;
@@ -9,9 +12,15 @@ use polars_error::PolarsResult;
 use polars_utils::IdxSize;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use super::fill_decompressed;
 use super::{cast_columns, read_chunk, CoreReader};
-use crate::csv::read::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
+use crate::csv::read::options::{CommentPrefix, CsvEncoding, NullValuesCompiled, RaggedLines};
 use crate::csv::read::parser::next_line_position;
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use crate::csv::read::parser::SplitLines;
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use crate::csv::read::utils::decoder;
 use crate::csv::read::CsvReader;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::update_row_counts2;
@@ -60,6 +69,8 @@ struct ChunkOffsetIter<'a> {
     n_chunks: usize,
     // not a promise, but something we want
     rows_per_batch: usize,
+    // if set, the chunks are byte ranges of about this size instead of `rows_per_batch` rows
+    morsel_size: Option<usize>,
     expected_fields: usize,
     separator: u8,
     quote_char: Option<u8>,
@@ -76,24 +87,30 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
                 if self.last_offset == self.bytes.len() {
                     return None;
                 }
-                let bytes_first_row = if self.rows_per_batch > 1 {
-                    let bytes_first_row = next_line_position(
-                        &self.bytes[self.last_offset + 2..],
-                        Some(self.expected_fields),
-                        self.separator,
-                        self.quote_char,
-                        self.eol_char,
-                    )
-                    .unwrap_or(1);
-                    bytes_first_row + 2
-                } else {
-                    1
+                let chunk_size = match self.morsel_size {
+                    Some(morsel_size) => morsel_size,
+                    None => {
+                        let bytes_first_row = if self.rows_per_batch > 1 {
+                            let bytes_first_row = next_line_position(
+                                &self.bytes[self.last_offset + 2..],
+                                Some(self.expected_fields),
+                                self.separator,
+                                self.quote_char,
+                                self.eol_char,
+                            )
+                            .unwrap_or(1);
+                            bytes_first_row + 2
+                        } else {
+                            1
+                        };
+                        self.rows_per_batch * bytes_first_row
+                    },
                 };
                 get_file_chunks_iterator(
                     &mut self.offsets,
                     &mut self.last_offset,
                     self.n_chunks,
-                    self.rows_per_batch * bytes_first_row,
+                    chunk_size,
                     self.bytes,
                     self.expected_fields,
                     self.separator,
@@ -118,6 +135,43 @@ impl<'a> CoreReader<'a> {
     /// Create a batched csv reader that uses mmap to load data.
     pub fn batched(mut self, _has_cat: bool) -> PolarsResult<BatchedCsvReader<'a>> {
         let reader_bytes = self.reader_bytes.take().unwrap();
+        // The batches are slices of the bytes, so a compressed file is decompressed into windows
+        // of complete lines that are batched one after the other. The first window holds the
+        // rows that are skipped and the header.
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        let (reader_bytes, decompressed) = {
+            // SAFETY: the compressed bytes are kept alive next to the decoder and their heap
+            // allocation or mapping doesn't move with them.
+            let compressed =
+                unsafe { std::mem::transmute::<&[u8], &'static [u8]>(reader_bytes.deref()) };
+            match decoder(compressed) {
+                Some(mut decoder) => {
+                    let (quote_char, eol_char) = (self.quote_char, self.eol_char);
+                    let rows_to_skip = self.skip_rows_before_header
+                        + self.has_header as usize
+                        + self.skip_rows_after_header;
+                    let mut buf = vec![];
+                    let (end, depleted) = fill_decompressed(
+                        &mut *decoder,
+                        &mut buf,
+                        quote_char,
+                        eol_char,
+                        |bytes| {
+                            SplitLines::new(bytes, quote_char.unwrap_or(b'"'), eol_char).count()
+                                > rows_to_skip
+                        },
+                    )?;
+                    let remainder = buf.split_off(end);
+                    let decompressed = Decompressed {
+                        decoder: (!depleted).then_some(decoder),
+                        remainder,
+                        _compressed: reader_bytes,
+                    };
+                    (ReaderBytes::Owned(buf), Some(decompressed))
+                },
+                None => (reader_bytes, None),
+            }
+        };
         let bytes = reader_bytes.as_ref();
         let (bytes, starting_point_offset) =
             self.find_starting_point(bytes, self.quote_char, self.eol_char)?;
@@ -135,6 +189,7 @@ impl<'a> CoreReader<'a> {
             last_offset: 0,
             n_chunks: offset_batch_size,
             rows_per_batch: self.chunk_size,
+            morsel_size: None,
             expected_fields: self.schema.len(),
             separator: self.separator,
             quote_char: self.quote_char,
@@ -156,6 +211,8 @@ impl<'a> CoreReader<'a> {
 
         Ok(BatchedCsvReader {
             reader_bytes,
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            decompressed,
             chunk_size: self.chunk_size,
             file_chunks_iter: file_chunks,
             file_chunks: vec![],
@@ -169,7 +226,7 @@ impl<'a> CoreReader<'a> {
             missing_is_null: self.missing_is_null,
             to_cast: self.to_cast,
             ignore_errors: self.ignore_errors,
-            truncate_ragged_lines: self.truncate_ragged_lines,
+            ragged_lines: self.ragged_lines,
             remaining: self.n_rows.unwrap_or(usize::MAX),
             encoding: self.encoding,
             separator: self.separator,
@@ -177,12 +234,25 @@ impl<'a> CoreReader<'a> {
             rows_read: 0,
             _cat_lock,
             decimal_comma: self.decimal_comma,
+            datetime_formats: self.datetime_formats,
         })
     }
 }
 
+/// The state of decompressing a compressed file into windows of complete lines.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+struct Decompressed<'a> {
+    // Safety: `decoder` borrows `_compressed` (so it has to be ordered first)
+    decoder: Option<Box<dyn Read + Send + Sync + 'static>>,
+    // The decompressed bytes after the last complete line of the current window.
+    remainder: Vec<u8>,
+    _compressed: ReaderBytes<'a>,
+}
+
 pub struct BatchedCsvReader<'a> {
     reader_bytes: ReaderBytes<'a>,
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    decompressed: Option<Decompressed<'a>>,
     chunk_size: usize,
     file_chunks_iter: ChunkOffsetIter<'a>,
     file_chunks: Vec<(usize, usize)>,
@@ -194,7 +264,7 @@ pub struct BatchedCsvReader<'a> {
     eol_char: u8,
     null_values: Option<NullValuesCompiled>,
     missing_is_null: bool,
-    truncate_ragged_lines: bool,
+    ragged_lines: RaggedLines,
     to_cast: Vec<Field>,
     ignore_errors: bool,
     remaining: usize,
@@ -207,9 +277,60 @@ pub struct BatchedCsvReader<'a> {
     #[cfg(not(feature = "dtype-categorical"))]
     _cat_lock: Option<u8>,
     decimal_comma: bool,
+    datetime_formats: Option<Arc<[String]>>,
 }
 
 impl<'a> BatchedCsvReader<'a> {
+    /// Decompress the next window of complete lines of a compressed file and batch it instead of
+    /// the current one. Returns false if the file is depleted.
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    fn next_window(&mut self) -> PolarsResult<bool> {
+        let Some(decompressed) = &mut self.decompressed else {
+            return Ok(false);
+        };
+        let Some(decoder) = &mut decompressed.decoder else {
+            return Ok(false);
+        };
+        let mut buf = std::mem::take(&mut decompressed.remainder);
+        let (end, depleted) = fill_decompressed(
+            &mut **decoder,
+            &mut buf,
+            self.quote_char,
+            self.eol_char,
+            |_| true,
+        )?;
+        decompressed.remainder = buf.split_off(end);
+        if depleted {
+            decompressed.decoder = None;
+        }
+        if buf.is_empty() {
+            return Ok(false);
+        }
+
+        // The skipped rows and the header are in the first window.
+        self.starting_point_offset = None;
+        // extend lifetime. It is bound to `reader_bytes`, which we replace after the offsets
+        // iterator, so this is sound.
+        let bytes = unsafe { std::mem::transmute::<&[u8], &'static [u8]>(buf.as_slice()) };
+        self.file_chunks_iter.bytes = bytes;
+        self.file_chunks_iter.offsets.clear();
+        self.file_chunks_iter.last_offset = 0;
+        self.reader_bytes = ReaderBytes::Owned(buf);
+        Ok(true)
+    }
+
+    /// The number of bytes of the file that are parsed into batches.
+    pub fn n_bytes(&self) -> usize {
+        self.file_chunks_iter.bytes.len()
+    }
+
+    /// Split the file into byte ranges of about `n_bytes`, aligned to the row boundaries,
+    /// instead of into batches of about `chunk_size` rows that are estimated from the size of
+    /// the first row. Only applies to the batches that are not yet read.
+    pub fn with_morsel_size(&mut self, n_bytes: usize) {
+        self.file_chunks_iter.morsel_size = Some(n_bytes.max(1));
+    }
+
     pub fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
         if n == 0 || self.remaining == 0 {
             return Ok(None);
@@ -218,8 +339,13 @@ impl<'a> BatchedCsvReader<'a> {
         // get next `n` offset positions.
         let file_chunks_iter = (&mut self.file_chunks_iter).take(n);
         self.file_chunks.extend(file_chunks_iter);
-        // depleted the offsets iterator, we are done as well.
+        // depleted the offsets iterator, we are done as well, unless there is another window of a
+        // compressed file.
         if self.file_chunks.is_empty() {
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            if self.next_window()? {
+                return self.next_batches(n);
+            }
             return Ok(None);
         }
         let chunks = &self.file_chunks;
@@ -248,11 +374,13 @@ impl<'a> BatchedCsvReader<'a> {
                         self.encoding,
                         self.null_values.as_ref(),
                         self.missing_is_null,
-                        self.truncate_ragged_lines,
+                        self.ragged_lines,
                         self.chunk_size,
                         stop_at_nbytes,
                         self.starting_point_offset,
                         self.decimal_comma,
+                        self.datetime_formats.as_ref(),
+                        None,
                     )?;
 
                     cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
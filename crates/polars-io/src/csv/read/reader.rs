@@ -140,6 +140,7 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.sample_size,
             self.options.chunk_size,
             self.options.low_memory,
+            self.options.zero_copy_strings,
             parse_options.comment_prefix.clone(),
             parse_options.quote_char,
             parse_options.eol_char,
@@ -150,8 +151,9 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.skip_rows_after_header,
             self.options.row_index.clone(),
             parse_options.try_parse_dates,
+            parse_options.datetime_formats.clone(),
             self.options.raise_if_empty,
-            parse_options.truncate_ragged_lines,
+            parse_options.get_ragged_lines(),
             parse_options.decimal_comma,
         )
     }
@@ -248,6 +250,7 @@ impl CsvReader<Box<dyn MmapBytesReader>> {
                     parse_options.eol_char,
                     parse_options.null_values.as_ref(),
                     parse_options.try_parse_dates,
+                    parse_options.datetime_formats.as_deref(),
                     self.options.raise_if_empty,
                     &mut self.options.n_threads,
                     parse_options.decimal_comma,
@@ -43,12 +43,25 @@ impl SchemaInferenceResult {
         let eol_char = parse_options.eol_char;
         let null_values = parse_options.null_values.clone();
         let try_parse_dates = parse_options.try_parse_dates;
+        let datetime_formats = parse_options.datetime_formats.as_deref();
         let raise_if_empty = options.raise_if_empty;
         let mut n_threads = options.n_threads;
         let decimal_comma = parse_options.decimal_comma;
 
         let bytes_total = reader_bytes.len();
 
+        // Compressed files only need to be decompressed up to the rows used for inference.
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        let decompressed = infer_schema_length.and_then(|n| {
+            let n_rows = skip_rows + has_header as usize + skip_rows_after_header + n;
+            super::utils::decompress(reader_bytes, Some(n_rows), separator, quote_char, eol_char)
+        });
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        let reader_bytes = &match decompressed {
+            Some(bytes) => ReaderBytes::Owned(bytes),
+            None => ReaderBytes::Borrowed(reader_bytes),
+        };
+
         let (inferred_schema, rows_read, bytes_read) = infer_file_schema(
             reader_bytes,
             separator,
@@ -62,6 +75,7 @@ impl SchemaInferenceResult {
             eol_char,
             null_values.as_ref(),
             try_parse_dates,
+            datetime_formats,
             raise_if_empty,
             &mut n_threads,
             decimal_comma,
@@ -204,6 +218,7 @@ fn infer_file_schema_inner(
     eol_char: u8,
     null_values: Option<&NullValues>,
     try_parse_dates: bool,
+    datetime_formats: Option<&[String]>,
     recursion_count: u8,
     raise_if_empty: bool,
     n_threads: &mut Option<usize>,
@@ -305,6 +320,7 @@ fn infer_file_schema_inner(
             eol_char,
             null_values,
             try_parse_dates,
+            datetime_formats,
             recursion_count + 1,
             raise_if_empty,
             n_threads,
@@ -333,6 +349,17 @@ fn infer_file_schema_inner(
     // needed to prevent ownership going into the iterator loop
     let records_ref = &mut lines;
 
+    let infer_field = |s: &str| {
+        // explicit formats take precedence over the inferred patterns
+        #[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+        if let Some(dtype) =
+            datetime_formats.and_then(|formats| date_infer::infer_dtype_with_formats(s, formats))
+        {
+            return dtype;
+        }
+        infer_field_schema(s, try_parse_dates, decimal_comma)
+    };
+
     let mut end_ptr = start_ptr;
     for mut line in records_ref
         .take(match max_read_rows {
@@ -386,17 +413,17 @@ fn infer_file_schema_inner(
                     };
                     let s = parse_bytes_with_encoding(slice_escaped, encoding)?;
                     let dtype = match &null_values {
-                        None => Some(infer_field_schema(&s, try_parse_dates, decimal_comma)),
+                        None => Some(infer_field(&s)),
                         Some(NullValues::AllColumns(names)) => {
                             if !names.iter().any(|nv| nv == s.as_ref()) {
-                                Some(infer_field_schema(&s, try_parse_dates, decimal_comma))
+                                Some(infer_field(&s))
                             } else {
                                 None
                             }
                         },
                         Some(NullValues::AllColumnsSingle(name)) => {
                             if s.as_ref() != name {
-                                Some(infer_field_schema(&s, try_parse_dates, decimal_comma))
+                                Some(infer_field(&s))
                             } else {
                                 None
                             }
@@ -405,16 +432,14 @@ fn infer_file_schema_inner(
                             // SAFETY:
                             // we iterate over headers length.
                             let current_name = unsafe { headers.get_unchecked_release(i) };
-                            let null_name = &names.iter().find(|name| &name.0 == current_name);
+                            let is_null = names
+                                .iter()
+                                .any(|name| &name.0 == current_name && name.1 == s.as_ref());
 
-                            if let Some(null_name) = null_name {
-                                if null_name.1 != s.as_ref() {
-                                    Some(infer_field_schema(&s, try_parse_dates, decimal_comma))
-                                } else {
-                                    None
-                                }
+                            if !is_null {
+                                Some(infer_field(&s))
                             } else {
-                                Some(infer_field_schema(&s, try_parse_dates, decimal_comma))
+                                None
                             }
                         },
                     };
@@ -463,7 +488,20 @@ fn infer_file_schema_inner(
         }
 
         let possibilities = &column_types[i];
-        let dtype = finish_infer_field_schema(possibilities);
+        let dtype = match finish_infer_field_schema(possibilities) {
+            // a column that mixes date-only and datetime formats is read as datetime
+            #[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+            DataType::String
+                if datetime_formats.is_some()
+                    && possibilities.len() == 2
+                    && possibilities.contains(&DataType::Date)
+                    && possibilities
+                        .contains(&DataType::Datetime(TimeUnit::Microseconds, None)) =>
+            {
+                DataType::Datetime(TimeUnit::Microseconds, None)
+            },
+            dtype => dtype,
+        };
         fields.push(Field::new(field_name, dtype));
     }
     // if there is a single line after the header without an eol
@@ -490,6 +528,7 @@ fn infer_file_schema_inner(
             eol_char,
             null_values,
             try_parse_dates,
+            datetime_formats,
             recursion_count + 1,
             raise_if_empty,
             n_threads,
@@ -532,6 +571,7 @@ pub fn infer_file_schema(
     eol_char: u8,
     null_values: Option<&NullValues>,
     try_parse_dates: bool,
+    datetime_formats: Option<&[String]>,
     raise_if_empty: bool,
     n_threads: &mut Option<usize>,
     decimal_comma: bool,
@@ -550,6 +590,7 @@ pub fn infer_file_schema(
         eol_char,
         null_values,
         try_parse_dates,
+        datetime_formats,
         0,
         raise_if_empty,
         n_threads,
@@ -51,6 +51,7 @@ const ZLIB0: [u8; 2] = [0x78, 0x01];
 const ZLIB1: [u8; 2] = [0x78, 0x9C];
 const ZLIB2: [u8; 2] = [0x78, 0xDA];
 const ZSTD: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+const BZIP2: [u8; 3] = [0x42, 0x5A, 0x68];
 
 /// check if csv file is compressed
 pub fn is_compressed(bytes: &[u8]) -> bool {
@@ -59,6 +60,7 @@ pub fn is_compressed(bytes: &[u8]) -> bool {
         || bytes.starts_with(&ZLIB2)
         || bytes.starts_with(&GZIP)
         || bytes.starts_with(&ZSTD)
+        || bytes.starts_with(&BZIP2)
 }
 
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
@@ -137,6 +139,22 @@ fn decompress_impl<R: Read>(
     })
 }
 
+/// Get a reader of the decompressed bytes, if `bytes` are compressed.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+pub(crate) fn decoder(bytes: &[u8]) -> Option<Box<dyn Read + Send + Sync + '_>> {
+    if bytes.starts_with(&GZIP) {
+        Some(Box::new(flate2::read::MultiGzDecoder::new(bytes)))
+    } else if bytes.starts_with(&ZLIB0) || bytes.starts_with(&ZLIB1) || bytes.starts_with(&ZLIB2) {
+        Some(Box::new(flate2::read::ZlibDecoder::new(bytes)))
+    } else if bytes.starts_with(&ZSTD) {
+        Some(Box::new(zstd::Decoder::new(bytes).ok()?))
+    } else if bytes.starts_with(&BZIP2) {
+        Some(Box::new(bzip2::read::MultiBzDecoder::new(bytes)))
+    } else {
+        None
+    }
+}
+
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 pub(crate) fn decompress(
     bytes: &[u8],
@@ -145,18 +163,8 @@ pub(crate) fn decompress(
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
-    if bytes.starts_with(&GZIP) {
-        let mut decoder = flate2::read::MultiGzDecoder::new(bytes);
-        decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-    } else if bytes.starts_with(&ZLIB0) || bytes.starts_with(&ZLIB1) || bytes.starts_with(&ZLIB2) {
-        let mut decoder = flate2::read::ZlibDecoder::new(bytes);
-        decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-    } else if bytes.starts_with(&ZSTD) {
-        let mut decoder = zstd::Decoder::new(bytes).ok()?;
-        decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-    } else {
-        None
-    }
+    let mut decoder = decoder(bytes)?;
+    decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
 }
 
 /// replace double quotes by single ones
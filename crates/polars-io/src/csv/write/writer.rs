@@ -1,5 +1,6 @@
 use std::io::Write;
 use std::num::NonZeroUsize;
+use std::path::PathBuf;
 
 use polars_core::frame::DataFrame;
 use polars_core::schema::{IndexOfSchema, Schema};
@@ -7,8 +8,8 @@ use polars_core::POOL;
 use polars_error::PolarsResult;
 
 use super::write_impl::{write, write_bom, write_header};
-use super::{QuoteStyle, SerializeOptions};
-use crate::shared::SerWriter;
+use super::{CsvWriterOptions, QuoteStyle, SerializeOptions};
+use crate::shared::{SerWriter, WriterFactory};
 
 /// Write a DataFrame to csv.
 ///
@@ -215,3 +216,28 @@ impl<W: Write> BatchedWriter<W> {
         Ok(())
     }
 }
+
+impl WriterFactory for CsvWriterOptions {
+    fn create_writer<W: Write + 'static>(&self, writer: W) -> Box<dyn SerWriter<W>> {
+        let options = self.serialize_options.clone();
+        Box::new(
+            CsvWriter::new(writer)
+                .include_bom(self.include_bom)
+                .include_header(self.include_header)
+                .with_batch_size(self.batch_size)
+                .with_separator(options.separator)
+                .with_line_terminator(options.line_terminator)
+                .with_quote_char(options.quote_char)
+                .with_datetime_format(options.datetime_format)
+                .with_date_format(options.date_format)
+                .with_time_format(options.time_format)
+                .with_float_precision(options.float_precision)
+                .with_null_value(options.null)
+                .with_quote_style(options.quote_style),
+        )
+    }
+
+    fn extension(&self) -> PathBuf {
+        PathBuf::from("csv")
+    }
+}
@@ -0,0 +1,8 @@
+//! Read the result of a SQL query into a [`DataFrame`](polars_core::frame::DataFrame).
+//!
+//! Polars does not ship database drivers. Instead, a connection only needs to return the
+//! query result through the [Arrow C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html),
+//! which is what ADBC drivers (and ODBC bridges such as `arrow-odbc`) produce.
+mod read;
+
+pub use read::*;
@@ -0,0 +1,162 @@
+use std::num::NonZeroUsize;
+
+use arrow::array::StructArray;
+use arrow::datatypes::ArrowDataType;
+use arrow::ffi::{ArrowArrayStream, ArrowArrayStreamReader};
+use polars_core::prelude::*;
+
+/// A database connection that executes SQL queries.
+///
+/// # Safety
+/// The stream returned by [`DatabaseConnection::execute`] must fulfill the invariants of
+/// the Arrow C stream interface and produce struct arrays, one per record batch.
+pub unsafe trait DatabaseConnection {
+    /// Execute `query` and return its result as an Arrow C stream.
+    fn execute(&mut self, query: &str) -> PolarsResult<Box<ArrowArrayStream>>;
+}
+
+/// Execute `query` on `connection` and return a reader over its result.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::num::NonZeroUsize;
+/// use polars_core::prelude::*;
+/// use polars_io::database::{read_database, DatabaseConnection};
+///
+/// fn example(connection: &mut impl DatabaseConnection) -> PolarsResult<()> {
+///     let reader = read_database(connection, "SELECT * FROM sales")?
+///         .with_batch_size(Some(NonZeroUsize::new(10_000).unwrap()));
+///
+///     for df in reader {
+///         println!("{}", df?.height());
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn read_database<C: DatabaseConnection + ?Sized>(
+    connection: &mut C,
+    query: &str,
+) -> PolarsResult<DatabaseReader> {
+    let stream = connection.execute(query)?;
+    // SAFETY: guaranteed by the implementor of `DatabaseConnection`.
+    unsafe { DatabaseReader::try_new(stream) }
+}
+
+/// Read the record batches of an Arrow C stream into DataFrames of at most
+/// `batch_size` rows.
+#[must_use]
+pub struct DatabaseReader {
+    stream: ArrowArrayStreamReader<Box<ArrowArrayStream>>,
+    schema: SchemaRef,
+    batch_size: Option<NonZeroUsize>,
+    /// Rows of the last record batch that did not fit in the previous batch.
+    pending: Option<DataFrame>,
+    finished: bool,
+}
+
+impl DatabaseReader {
+    /// Create a reader from an Arrow C stream of struct arrays.
+    ///
+    /// # Safety
+    /// The stream must fulfill the invariants of the Arrow C stream interface.
+    pub unsafe fn try_new(stream: Box<ArrowArrayStream>) -> PolarsResult<Self> {
+        let stream = ArrowArrayStreamReader::try_new(stream)?;
+        let schema = match stream.field().data_type().to_logical_type() {
+            ArrowDataType::Struct(fields) => Arc::new(Schema::from_iter(fields)),
+            dt => polars_bail!(ComputeError: "expected a stream of struct arrays, got {:?}", dt),
+        };
+        Ok(DatabaseReader {
+            stream,
+            schema,
+            batch_size: None,
+            pending: None,
+            finished: false,
+        })
+    }
+
+    /// Set the maximum number of rows in a batch. If `None`, the record batches of the
+    /// stream are returned as they are produced by the driver. Defaults to `None`.
+    pub fn with_batch_size(mut self, batch_size: Option<NonZeroUsize>) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Get the schema of the query result.
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn next_record_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        if self.finished {
+            return Ok(None);
+        }
+        // SAFETY: the stream was checked when the reader was created.
+        match unsafe { self.stream.next() } {
+            Some(array) => {
+                let array = array?;
+                let array = array
+                    .as_any()
+                    .downcast_ref::<StructArray>()
+                    .ok_or_else(|| polars_err!(ComputeError: "expected a struct array"))?;
+                DataFrame::try_from(array.clone()).map(Some)
+            },
+            None => {
+                self.finished = true;
+                Ok(None)
+            },
+        }
+    }
+
+    /// Read the next batch. Returns `None` if the query result is exhausted.
+    pub fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let Some(batch_size) = self.batch_size else {
+            return match self.pending.take() {
+                Some(df) => Ok(Some(df)),
+                None => self.next_record_batch(),
+            };
+        };
+        let batch_size = batch_size.get();
+
+        let mut out = self.pending.take();
+        while out.as_ref().map_or(0, |df| df.height()) < batch_size {
+            match self.next_record_batch()? {
+                Some(df) => match out.as_mut() {
+                    Some(out) => {
+                        out.vstack_mut(&df)?;
+                    },
+                    None => out = Some(df),
+                },
+                None => break,
+            }
+        }
+        Ok(out.map(|df| {
+            if df.height() > batch_size {
+                let (df, pending) = df.split_at(batch_size as i64);
+                self.pending = Some(pending);
+                df
+            } else {
+                df
+            }
+        }))
+    }
+
+    /// Read all remaining batches into a single DataFrame.
+    pub fn finish(mut self) -> PolarsResult<DataFrame> {
+        self.batch_size = None;
+        let mut out = DataFrame::from(self.schema.as_ref());
+        while let Some(df) = self.next_batch()? {
+            out.vstack_mut(&df)?;
+        }
+        out.as_single_chunk_par();
+        Ok(out)
+    }
+}
+
+impl Iterator for DatabaseReader {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_batch().transpose()
+    }
+}
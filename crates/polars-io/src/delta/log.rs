@@ -0,0 +1,494 @@
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use percent_encoding::percent_decode_str;
+use polars_core::prelude::*;
+use polars_utils::aliases::PlIndexMap;
+use serde_json::{json, Map, Value};
+
+use super::schema::parse_schema;
+use crate::parquet::read::ParquetReader;
+use crate::SerReader;
+
+/// Reader features of the Delta protocol that don't change how a snapshot is read, or
+/// that are checked per table/ file.
+const SUPPORTED_READER_FEATURES: [&str; 4] = [
+    "columnMapping",
+    "deletionVectors",
+    "timestampNtz",
+    "vacuumProtocolCheck",
+];
+
+/// The snapshot of a Delta table to read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DeltaVersion {
+    /// The latest version of the table.
+    #[default]
+    Latest,
+    /// A specific version of the table.
+    Version(i64),
+    /// The latest version committed at or before the given timestamp, in milliseconds
+    /// since the Unix epoch.
+    Timestamp(i64),
+}
+
+/// A data file of a Delta table snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeltaFile {
+    /// Path of the parquet file.
+    pub path: PathBuf,
+    /// Values of the partition columns in the order of [`DeltaTable::partition_columns`],
+    /// as stored in the log. `None` denotes a null value.
+    pub partition_values: Vec<Option<String>>,
+}
+
+/// A snapshot of a Delta Lake table, resolved from its transaction log.
+///
+/// The log is replayed from the latest checkpoint at or before the requested version,
+/// so that only the files that are part of the snapshot are returned.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::delta::{DeltaTable, DeltaVersion};
+///
+/// fn example() -> PolarsResult<()> {
+///     let table = DeltaTable::load("path/to/table", DeltaVersion::Version(3))?;
+///     for file in table.files() {
+///         println!("{:?} {:?}", file.path, file.partition_values);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeltaTable {
+    version: i64,
+    schema: Schema,
+    partition_columns: Vec<String>,
+    files: Vec<DeltaFile>,
+}
+
+impl DeltaTable {
+    /// Load the given snapshot of the Delta table at `root`.
+    pub fn load(root: impl AsRef<Path>, version: DeltaVersion) -> PolarsResult<Self> {
+        let root = root.as_ref();
+        let log = LogFiles::list(&root.join("_delta_log"))?;
+
+        let latest = log
+            .latest_version()
+            .ok_or_else(|| polars_err!(ComputeError: "Delta table at {:?} has no commits", root))?;
+        let version = match version {
+            DeltaVersion::Latest => latest,
+            DeltaVersion::Version(version) => {
+                polars_ensure!(
+                    (0..=latest).contains(&version),
+                    ComputeError: "Delta table at {:?} has no version {}; latest version is {}", root, version, latest
+                );
+                version
+            },
+            DeltaVersion::Timestamp(timestamp) => log.version_at(timestamp)?.ok_or_else(
+                || polars_err!(ComputeError: "Delta table at {:?} has no commit at or before timestamp {}", root, timestamp),
+            )?,
+        };
+
+        let mut replay = LogReplay::default();
+        let start = match log.checkpoints.range(..=version).next_back() {
+            Some((checkpoint_version, parts)) => {
+                for part in parts {
+                    replay.apply_checkpoint(part)?;
+                }
+                checkpoint_version + 1
+            },
+            None => 0,
+        };
+        for v in start..=version {
+            let path = log.commits.get(&v).ok_or_else(
+                || polars_err!(ComputeError: "Delta log of {:?} is missing the commit of version {}", root, v),
+            )?;
+            replay.apply_commit(path)?;
+        }
+        replay.finish(root, version)
+    }
+
+    /// The version of the snapshot.
+    pub fn version(&self) -> i64 {
+        self.version
+    }
+
+    /// The schema of the table, including the partition columns.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// The names of the partition columns.
+    pub fn partition_columns(&self) -> &[String] {
+        &self.partition_columns
+    }
+
+    /// The schema of the partition columns.
+    pub fn partition_schema(&self) -> PolarsResult<Schema> {
+        self.partition_columns
+            .iter()
+            .map(|name| {
+                let dtype = self.schema.try_get(name)?;
+                Ok(Field::new(name, dtype.clone()))
+            })
+            .collect()
+    }
+
+    /// The data files of the snapshot.
+    pub fn files(&self) -> &[DeltaFile] {
+        &self.files
+    }
+}
+
+/// The commits and checkpoints in the `_delta_log` directory of a table.
+#[derive(Default)]
+struct LogFiles {
+    commits: BTreeMap<i64, PathBuf>,
+    /// Complete checkpoints, a checkpoint may be split in multiple parts.
+    checkpoints: BTreeMap<i64, Vec<PathBuf>>,
+}
+
+impl LogFiles {
+    fn list(log_dir: &Path) -> PolarsResult<Self> {
+        let entries = std::fs::read_dir(log_dir).map_err(
+            |_| polars_err!(ComputeError: "not a Delta table: {:?} does not exist", log_dir),
+        )?;
+
+        let mut log = LogFiles::default();
+        // version -> (expected number of parts, parts)
+        let mut checkpoint_parts = BTreeMap::<i64, (usize, Vec<PathBuf>)>::new();
+        for entry in entries {
+            let path = entry?.path();
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            let Some((version, suffix)) = name.split_once('.') else {
+                continue;
+            };
+            let Ok(version) = version.parse::<i64>() else {
+                continue;
+            };
+            let suffix = suffix.split('.').collect::<Vec<_>>();
+            match suffix.as_slice() {
+                ["json"] => {
+                    log.commits.insert(version, path);
+                },
+                ["checkpoint", "parquet"] => {
+                    checkpoint_parts.insert(version, (1, vec![path]));
+                },
+                ["checkpoint", _, n_parts, "parquet"] => {
+                    let Ok(n_parts) = n_parts.parse::<usize>() else {
+                        continue;
+                    };
+                    let parts = checkpoint_parts.entry(version).or_insert((n_parts, vec![]));
+                    parts.1.push(path);
+                },
+                // Other files, like v2 checkpoints, are not used.
+                _ => {},
+            }
+        }
+        log.checkpoints = checkpoint_parts
+            .into_iter()
+            .filter(|(_, (n_parts, parts))| *n_parts == parts.len())
+            .map(|(version, (_, mut parts))| {
+                parts.sort();
+                (version, parts)
+            })
+            .collect();
+        Ok(log)
+    }
+
+    fn latest_version(&self) -> Option<i64> {
+        let commit = self.commits.keys().next_back();
+        let checkpoint = self.checkpoints.keys().next_back();
+        commit.max(checkpoint).copied()
+    }
+
+    /// The latest version committed at or before `timestamp`.
+    fn version_at(&self, timestamp: i64) -> PolarsResult<Option<i64>> {
+        for (version, path) in self.commits.iter().rev() {
+            if commit_timestamp(path)? <= timestamp {
+                return Ok(Some(*version));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// The timestamp of a commit in milliseconds since the Unix epoch. This is the timestamp
+/// in the commit info of the commit if present, otherwise the modification time of the
+/// commit file.
+fn commit_timestamp(path: &Path) -> PolarsResult<i64> {
+    for action in read_actions(path)? {
+        if let Some(commit_info) = action.get("commitInfo") {
+            let timestamp = commit_info
+                .get("inCommitTimestamp")
+                .or_else(|| commit_info.get("timestamp"))
+                .and_then(Value::as_i64);
+            if let Some(timestamp) = timestamp {
+                return Ok(timestamp);
+            }
+        }
+    }
+    let modified = std::fs::metadata(path)?.modified()?;
+    Ok(modified
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as i64))
+}
+
+fn read_actions(path: &Path) -> PolarsResult<Vec<Value>> {
+    let file = polars_utils::open_file(path)?;
+    BufReader::new(file)
+        .lines()
+        .filter(|line| !line.as_ref().is_ok_and(|line| line.trim().is_empty()))
+        .map(|line| {
+            serde_json::from_str(&line?).map_err(
+                |err| polars_err!(ComputeError: "invalid action in Delta log {:?}: {}", path, err),
+            )
+        })
+        .collect()
+}
+
+/// The state of a table while replaying its log.
+#[derive(Default)]
+struct LogReplay {
+    /// Path of the data files in the log -> partition values.
+    files: PlIndexMap<String, Map<String, Value>>,
+    metadata: Option<Value>,
+    protocol: Option<Value>,
+}
+
+impl LogReplay {
+    fn apply_commit(&mut self, path: &Path) -> PolarsResult<()> {
+        for action in read_actions(path)? {
+            self.apply(action)?;
+        }
+        Ok(())
+    }
+
+    fn apply(&mut self, mut action: Value) -> PolarsResult<()> {
+        if let Some(add) = action.get_mut("add") {
+            let path = add
+                .get("path")
+                .and_then(Value::as_str)
+                .ok_or_else(|| polars_err!(ComputeError: "Delta add action without path"))?
+                .to_string();
+            polars_ensure!(
+                add.get("deletionVector").map_or(true, Value::is_null),
+                ComputeError: "Delta data file {} has a deletion vector; deletion vectors are not supported", path
+            );
+            let partition_values = match add.get_mut("partitionValues").map(Value::take) {
+                Some(Value::Object(values)) => values,
+                _ => Map::new(),
+            };
+            self.files.insert(path, partition_values);
+        } else if let Some(remove) = action.get("remove") {
+            if let Some(path) = remove.get("path").and_then(Value::as_str) {
+                self.files.shift_remove(path);
+            }
+        } else if let Some(metadata) = action.get_mut("metaData") {
+            self.metadata = Some(metadata.take());
+        } else if let Some(protocol) = action.get_mut("protocol") {
+            self.protocol = Some(protocol.take());
+        }
+        Ok(())
+    }
+
+    /// Apply the `add`, `metaData` and `protocol` actions stored in a checkpoint file.
+    fn apply_checkpoint(&mut self, path: &Path) -> PolarsResult<()> {
+        let file = polars_utils::open_file(path)?;
+        let df = ParquetReader::new(file).finish()?;
+        let column = |name: &str| {
+            df.column(name)
+                .ok()
+                .map(|s| s.struct_().cloned())
+                .transpose()
+        };
+
+        if let Some(add) = column("add")? {
+            let paths = add.field_by_name("path")?;
+            let partition_values = add.field_by_name("partitionValues")?;
+            let paths = paths.str()?;
+            let partition_values = partition_values.list()?;
+            for (i, path) in paths.into_iter().enumerate() {
+                let Some(path) = path else {
+                    continue;
+                };
+                let values = match partition_values.get_as_series(i) {
+                    Some(s) => map_to_json(&s)?,
+                    None => Value::Object(Map::new()),
+                };
+                self.apply(json!({"add": {"path": path, "partitionValues": values}}))?;
+            }
+        }
+        if let Some(metadata) = column("metaData")? {
+            let schema_string = metadata.field_by_name("schemaString")?;
+            let partition_columns = metadata.field_by_name("partitionColumns")?;
+            let configuration = metadata.field_by_name("configuration")?;
+            for (i, schema_string) in schema_string.str()?.into_iter().enumerate() {
+                let Some(schema_string) = schema_string else {
+                    continue;
+                };
+                let partition_columns = partition_columns
+                    .list()?
+                    .get_as_series(i)
+                    .map(|s| {
+                        s.str().map(|ca| {
+                            ca.into_iter()
+                                .flatten()
+                                .map(String::from)
+                                .collect::<Vec<_>>()
+                        })
+                    })
+                    .transpose()?
+                    .unwrap_or_default();
+                let configuration = match configuration.list()?.get_as_series(i) {
+                    Some(s) => map_to_json(&s)?,
+                    None => Value::Object(Map::new()),
+                };
+                self.apply(json!({"metaData": {
+                    "schemaString": schema_string,
+                    "partitionColumns": partition_columns,
+                    "configuration": configuration,
+                }}))?;
+            }
+        }
+        if let Some(protocol) = column("protocol")? {
+            let min_reader_version = protocol.field_by_name("minReaderVersion")?;
+            let min_reader_version = min_reader_version.cast(&DataType::Int64)?;
+            let reader_features = protocol.field_by_name("readerFeatures").ok();
+            for (i, min_reader_version) in min_reader_version.i64()?.into_iter().enumerate() {
+                let Some(min_reader_version) = min_reader_version else {
+                    continue;
+                };
+                let reader_features = match &reader_features {
+                    Some(features) => features
+                        .list()?
+                        .get_as_series(i)
+                        .map(|s| {
+                            s.str()
+                                .map(|ca| ca.into_iter().flatten().map(String::from).collect())
+                        })
+                        .transpose()?,
+                    None => None,
+                };
+                self.apply(json!({"protocol": {
+                    "minReaderVersion": min_reader_version,
+                    "readerFeatures": reader_features.unwrap_or_else(Vec::<String>::new),
+                }}))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self, root: &Path, version: i64) -> PolarsResult<DeltaTable> {
+        if let Some(protocol) = &self.protocol {
+            let min_reader_version = protocol
+                .get("minReaderVersion")
+                .and_then(Value::as_i64)
+                .unwrap_or(1);
+            polars_ensure!(
+                min_reader_version <= 3,
+                ComputeError: "unsupported Delta reader version {}", min_reader_version
+            );
+            let features = protocol.get("readerFeatures").and_then(Value::as_array);
+            for feature in features.into_iter().flatten() {
+                let feature = feature.as_str().unwrap_or_default();
+                polars_ensure!(
+                    SUPPORTED_READER_FEATURES.contains(&feature),
+                    ComputeError: "unsupported Delta reader feature: {}", feature
+                );
+            }
+        }
+
+        let metadata = self
+            .metadata
+            .ok_or_else(|| polars_err!(ComputeError: "Delta log of {:?} has no metadata", root))?;
+        let column_mapping = metadata
+            .pointer("/configuration/delta.columnMapping.mode")
+            .and_then(Value::as_str)
+            .unwrap_or("none");
+        polars_ensure!(
+            column_mapping == "none",
+            ComputeError: "Delta column mapping mode '{}' is not supported", column_mapping
+        );
+        let schema_string = metadata
+            .get("schemaString")
+            .and_then(Value::as_str)
+            .ok_or_else(|| polars_err!(ComputeError: "Delta metadata has no schema"))?;
+        let schema = parse_schema(schema_string)?;
+        let partition_columns = metadata
+            .get("partitionColumns")
+            .and_then(Value::as_array)
+            .map(|columns| {
+                columns
+                    .iter()
+                    .filter_map(|c| c.as_str().map(String::from))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let files = self
+            .files
+            .into_iter()
+            .map(|(path, mut values)| {
+                let partition_values = partition_columns
+                    .iter()
+                    .map(|name| match values.remove(name) {
+                        Some(Value::String(value)) => Some(value),
+                        _ => None,
+                    })
+                    .collect();
+                Ok(DeltaFile {
+                    path: resolve_path(root, &path)?,
+                    partition_values,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(DeltaTable {
+            version,
+            schema,
+            partition_columns,
+            files,
+        })
+    }
+}
+
+/// Convert a map, read from parquet as a list of key/ value structs, to a JSON object.
+fn map_to_json(s: &Series) -> PolarsResult<Value> {
+    let entries = s.struct_()?;
+    let keys = entries.field_by_name("key")?;
+    let values = entries.field_by_name("value")?;
+    let map = keys
+        .str()?
+        .into_iter()
+        .zip(values.str()?)
+        .filter_map(|(key, value)| Some((key?.to_string(), value.map_or(Value::Null, Value::from))))
+        .collect();
+    Ok(Value::Object(map))
+}
+
+/// Resolve the (URI encoded) path of a data file relative to the table root.
+fn resolve_path(root: &Path, path: &str) -> PolarsResult<PathBuf> {
+    let (path, is_absolute) = match path.split_once("://") {
+        Some(("file", path)) => (path, true),
+        Some(_) => {
+            polars_bail!(ComputeError: "Delta data files outside of the local file system are not supported: {}", path)
+        },
+        None => (path, false),
+    };
+    let path = percent_decode_str(path)
+        .decode_utf8()
+        .map_err(|_| polars_err!(ComputeError: "invalid path in Delta log: {}", path))?;
+    Ok(if is_absolute {
+        PathBuf::from(path.as_ref())
+    } else {
+        root.join(path.as_ref())
+    })
+}
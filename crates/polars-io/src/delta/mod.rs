@@ -0,0 +1,8 @@
+//! Read the snapshot of a [Delta Lake](https://delta.io) table from its transaction log.
+//!
+//! Only the transaction log is handled here; the data files of the snapshot are regular
+//! parquet files that are read with the parquet readers.
+mod log;
+mod schema;
+
+pub use log::*;
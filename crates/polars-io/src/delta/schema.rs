@@ -0,0 +1,84 @@
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use serde_json::Value;
+
+/// Convert the schema of a Delta table, as stored in the `schemaString` of its metadata.
+pub(super) fn parse_schema(schema_string: &str) -> PolarsResult<Schema> {
+    let value: Value = serde_json::from_str(schema_string).map_err(to_compute_err)?;
+    match parse_type(&value)? {
+        DataType::Struct(fields) => Ok(Schema::from_iter(fields)),
+        dt => polars_bail!(ComputeError: "expected a struct as Delta table schema, got {}", dt),
+    }
+}
+
+fn parse_type(value: &Value) -> PolarsResult<DataType> {
+    let dtype = match value {
+        Value::String(name) => match name.as_str() {
+            "string" => DataType::String,
+            "long" => DataType::Int64,
+            "integer" => DataType::Int32,
+            #[cfg(feature = "dtype-i16")]
+            "short" => DataType::Int16,
+            #[cfg(feature = "dtype-i8")]
+            "byte" => DataType::Int8,
+            "float" => DataType::Float32,
+            "double" => DataType::Float64,
+            "boolean" => DataType::Boolean,
+            "binary" => DataType::Binary,
+            #[cfg(feature = "dtype-date")]
+            "date" => DataType::Date,
+            #[cfg(feature = "dtype-datetime")]
+            "timestamp" => DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+            #[cfg(feature = "dtype-datetime")]
+            "timestamp_ntz" => DataType::Datetime(TimeUnit::Microseconds, None),
+            #[cfg(feature = "dtype-decimal")]
+            name if name.starts_with("decimal(") => {
+                let parse_err = || polars_err!(ComputeError: "invalid Delta type: {}", name);
+                let (precision, scale) = name["decimal(".len()..]
+                    .trim_end_matches(')')
+                    .split_once(',')
+                    .ok_or_else(parse_err)?;
+                DataType::Decimal(
+                    Some(precision.trim().parse().map_err(|_| parse_err())?),
+                    Some(scale.trim().parse().map_err(|_| parse_err())?),
+                )
+            },
+            name => polars_bail!(ComputeError: "unsupported Delta type: {}", name),
+        },
+        Value::Object(object) => match object.get("type").and_then(Value::as_str) {
+            Some("struct") => {
+                let fields = object
+                    .get("fields")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| polars_err!(ComputeError: "Delta struct type without fields"))?
+                    .iter()
+                    .map(|field| {
+                        let name = field.get("name").and_then(Value::as_str).ok_or_else(
+                            || polars_err!(ComputeError: "Delta struct field without name"),
+                        )?;
+                        let dtype = parse_type(field.get("type").unwrap_or(&Value::Null))?;
+                        Ok(Field::new(name, dtype))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                DataType::Struct(fields)
+            },
+            Some("array") => DataType::List(Box::new(parse_type(
+                object.get("elementType").unwrap_or(&Value::Null),
+            )?)),
+            // Maps are read from parquet as lists of key/ value structs.
+            Some("map") => DataType::List(Box::new(DataType::Struct(vec![
+                Field::new(
+                    "key",
+                    parse_type(object.get("keyType").unwrap_or(&Value::Null))?,
+                ),
+                Field::new(
+                    "value",
+                    parse_type(object.get("valueType").unwrap_or(&Value::Null))?,
+                ),
+            ]))),
+            _ => polars_bail!(ComputeError: "unsupported Delta type: {}", value),
+        },
+        _ => polars_bail!(ComputeError: "unsupported Delta type: {}", value),
+    };
+    Ok(dtype)
+}
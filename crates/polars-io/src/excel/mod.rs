@@ -0,0 +1,4 @@
+//! Read Excel (.xlsx) workbooks into a [`DataFrame`](polars_core::frame::DataFrame).
+mod read;
+
+pub use read::*;
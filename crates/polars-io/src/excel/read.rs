@@ -0,0 +1,255 @@
+use std::io::{BufReader, Read, Seek};
+use std::path::Path;
+
+use calamine::{Data, DataType as _, Range, Reader, Xlsx};
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+
+use crate::prelude::*;
+
+/// Select the worksheet of a workbook that should be read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExcelSheet {
+    /// Zero-based position of the sheet in the workbook.
+    Index(usize),
+    /// Name of the sheet.
+    Name(String),
+}
+
+impl Default for ExcelSheet {
+    fn default() -> Self {
+        ExcelSheet::Index(0)
+    }
+}
+
+/// Read a worksheet of an Excel (.xlsx) workbook into a [`DataFrame`].
+///
+/// Column types are inferred from the cell types of the first `infer_schema_length`
+/// data rows. Date and datetime formatted cells are read as `Date` and `Datetime("ms")`
+/// respectively, empty and error cells are read as nulls.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::excel::{ExcelReader, ExcelSheet};
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("file.xlsx").expect("file not found");
+///
+///     ExcelReader::new(file)
+///         .with_sheet(ExcelSheet::Name("sales".into()))
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct ExcelReader<R> {
+    reader: R,
+    sheet: ExcelSheet,
+    header_row: Option<usize>,
+    n_rows: Option<usize>,
+    infer_schema_length: Option<usize>,
+    schema_overwrite: Option<SchemaRef>,
+    columns: Option<Vec<String>>,
+}
+
+impl<R: Read + Seek> ExcelReader<R> {
+    /// Set the worksheet to read. Defaults to the first sheet.
+    pub fn with_sheet(mut self, sheet: ExcelSheet) -> Self {
+        self.sheet = sheet;
+        self
+    }
+
+    /// Set the (zero-based) row of the sheet that contains the column names. Rows
+    /// above it are skipped. If `None`, all rows are data and the columns are named
+    /// `column_1`, `column_2`, etc. Defaults to `Some(0)`.
+    pub fn with_header_row(mut self, header_row: Option<usize>) -> Self {
+        self.header_row = header_row;
+        self
+    }
+
+    /// Stop reading when `n` data rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Set the number of rows used to infer the column types. If `None`, all rows
+    /// are used. Defaults to `Some(100)`.
+    pub fn with_infer_schema_length(mut self, infer_schema_length: Option<usize>) -> Self {
+        self.infer_schema_length = infer_schema_length;
+        self
+    }
+
+    /// Overwrite the inferred dtypes of the given columns. Cells that cannot be
+    /// converted to the requested dtype are read as null.
+    pub fn with_schema_overwrite(mut self, schema_overwrite: Option<SchemaRef>) -> Self {
+        self.schema_overwrite = schema_overwrite;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Get the names of the worksheets in the workbook.
+    pub fn sheet_names(self) -> PolarsResult<Vec<String>> {
+        let workbook: Xlsx<R> = Xlsx::new(self.reader).map_err(to_compute_err)?;
+        Ok(workbook.sheet_names())
+    }
+}
+
+impl<R> SerReader<R> for ExcelReader<R>
+where
+    R: Read + Seek,
+{
+    fn new(reader: R) -> Self {
+        ExcelReader {
+            reader,
+            sheet: ExcelSheet::default(),
+            header_row: Some(0),
+            n_rows: None,
+            infer_schema_length: Some(100),
+            schema_overwrite: None,
+            columns: None,
+        }
+    }
+
+    fn finish(self) -> PolarsResult<DataFrame> {
+        let mut workbook: Xlsx<R> = Xlsx::new(self.reader).map_err(to_compute_err)?;
+        let range = match &self.sheet {
+            ExcelSheet::Index(idx) => workbook.worksheet_range_at(*idx).ok_or_else(
+                || polars_err!(ComputeError: "workbook has no sheet at index {}", idx),
+            )?,
+            ExcelSheet::Name(name) => {
+                polars_ensure!(
+                    workbook.sheet_names().iter().any(|n| n == name),
+                    ComputeError: "workbook has no sheet named '{}'", name
+                );
+                workbook.worksheet_range(name)
+            },
+        }
+        .map_err(to_compute_err)?;
+
+        let df = range_to_df(
+            &range,
+            self.header_row,
+            self.n_rows,
+            self.infer_schema_length,
+            self.schema_overwrite.as_deref(),
+        )?;
+        match &self.columns {
+            Some(columns) => df.select(columns),
+            None => Ok(df),
+        }
+    }
+}
+
+/// Read the first worksheet of the `.xlsx` file at `path` with the default options.
+///
+/// Use [`ExcelReader`] for control over the sheet, header and dtypes.
+pub fn read_excel<P: AsRef<Path>>(path: P) -> PolarsResult<DataFrame> {
+    let file = polars_utils::open_file(path)?;
+    ExcelReader::new(BufReader::new(file)).finish()
+}
+
+fn range_to_df(
+    range: &Range<Data>,
+    header_row: Option<usize>,
+    n_rows: Option<usize>,
+    infer_schema_length: Option<usize>,
+    schema_overwrite: Option<&Schema>,
+) -> PolarsResult<DataFrame> {
+    // The range starts at the first non-empty cell, make the header row relative to it.
+    let first_row = range.start().map(|(row, _)| row as usize).unwrap_or(0);
+    let width = range.width();
+    let mut rows = range.rows();
+
+    let names: Vec<String> = match header_row {
+        Some(header_row) => {
+            let skip = header_row.saturating_sub(first_row);
+            let header = rows.nth(skip);
+            (0..width)
+                .map(|i| match header.and_then(|h| h.get(i)) {
+                    Some(cell) if !cell.is_empty() => cell.to_string(),
+                    _ => format!("column_{}", i + 1),
+                })
+                .collect()
+        },
+        None => (0..width).map(|i| format!("column_{}", i + 1)).collect(),
+    };
+    let data: Vec<&[Data]> = rows.take(n_rows.unwrap_or(usize::MAX)).collect();
+    let n_infer = infer_schema_length.unwrap_or(usize::MAX);
+
+    let columns = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let cells = data.iter().map(|row| row.get(i).unwrap_or(&Data::Empty));
+            let dtype = match schema_overwrite.and_then(|s| s.get(name)) {
+                Some(dtype) => dtype.clone(),
+                None => infer_dtype(cells.clone().take(n_infer))?,
+            };
+            let values = cells.map(cell_to_any_value).collect::<Vec<_>>();
+            Series::from_any_values_and_dtype(name, &values, &dtype, false)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    DataFrame::new(columns)
+}
+
+fn cell_dtype(cell: &Data) -> Option<DataType> {
+    let dtype = match cell {
+        Data::Int(_) => DataType::Int64,
+        Data::Float(_) => DataType::Float64,
+        Data::Bool(_) => DataType::Boolean,
+        Data::String(_) | Data::DateTimeIso(_) | Data::DurationIso(_) => DataType::String,
+        Data::DateTime(dt) if dt.is_duration() => DataType::Duration(TimeUnit::Milliseconds),
+        Data::DateTime(dt) if dt.as_f64().fract() == 0.0 => DataType::Date,
+        Data::DateTime(_) => DataType::Datetime(TimeUnit::Milliseconds, None),
+        Data::Empty | Data::Error(_) => return None,
+    };
+    Some(dtype)
+}
+
+fn infer_dtype<'a>(cells: impl Iterator<Item = &'a Data>) -> PolarsResult<DataType> {
+    let mut dtype = DataType::Null;
+    for cell_dtype in cells.filter_map(cell_dtype) {
+        // Columns mixing incompatible cell types are read as strings.
+        dtype = try_get_supertype(&dtype, &cell_dtype).unwrap_or(DataType::String);
+    }
+    if matches!(dtype, DataType::Null) {
+        dtype = DataType::String;
+    }
+    Ok(dtype)
+}
+
+fn cell_to_any_value(cell: &Data) -> AnyValue<'_> {
+    match cell {
+        Data::Int(v) => AnyValue::Int64(*v),
+        Data::Float(v) => AnyValue::Float64(*v),
+        Data::Bool(v) => AnyValue::Boolean(*v),
+        Data::String(v) | Data::DateTimeIso(v) | Data::DurationIso(v) => AnyValue::String(v),
+        Data::DateTime(dt) if dt.is_duration() => match dt.as_duration() {
+            Some(d) => AnyValue::Duration(d.num_milliseconds(), TimeUnit::Milliseconds),
+            None => AnyValue::Null,
+        },
+        Data::DateTime(dt) => match dt.as_datetime() {
+            Some(dt) if dt.time() == chrono::NaiveTime::MIN => {
+                AnyValue::Date(dt.and_utc().timestamp().div_euclid(86_400) as i32)
+            },
+            Some(dt) => AnyValue::Datetime(
+                dt.and_utc().timestamp_millis(),
+                TimeUnit::Milliseconds,
+                &None,
+            ),
+            None => AnyValue::Null,
+        },
+        Data::Empty | Data::Error(_) => AnyValue::Null,
+    }
+}
@@ -0,0 +1,4 @@
+//! Read fixed-width text files into a [`DataFrame`](polars_core::frame::DataFrame).
+mod read;
+
+pub use read::*;
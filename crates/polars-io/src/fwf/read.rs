@@ -0,0 +1,280 @@
+use std::io::Read;
+use std::ops::Range;
+
+use polars_core::prelude::*;
+
+use crate::csv::read::schema_inference::{finish_infer_field_schema, infer_field_schema};
+use crate::prelude::*;
+
+/// Read a fixed-width text file into a [`DataFrame`].
+///
+/// Every line holds one record and every column occupies a fixed byte range of the
+/// line. The ranges are given with [`FwfReader::with_column_ranges`] or, for
+/// contiguous columns, with [`FwfReader::with_column_widths`]. By default fields are
+/// stripped of their padding spaces and empty fields are read as null.
+///
+/// Column types are inferred from the first `infer_schema_length` records, in the
+/// same way as for CSV files.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::fwf::FwfReader;
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("file.txt").expect("file not found");
+///
+///     FwfReader::new(file)
+///         .with_column_widths(&[10, 4, 8])
+///         .with_has_header(true)
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct FwfReader<R> {
+    reader: R,
+    column_ranges: Vec<Range<usize>>,
+    column_names: Option<Vec<String>>,
+    has_header: bool,
+    skip_rows: usize,
+    n_rows: Option<usize>,
+    infer_schema_length: Option<usize>,
+    schema_overwrite: Option<SchemaRef>,
+    try_parse_dates: bool,
+    trim: bool,
+    pad_char: u8,
+    comment_prefix: Option<u8>,
+}
+
+impl<R: Read> FwfReader<R> {
+    /// Set the byte range of every column in a line. Ranges may not overlap and are
+    /// cut off at the end of shorter lines.
+    pub fn with_column_ranges(mut self, column_ranges: Vec<Range<usize>>) -> Self {
+        self.column_ranges = column_ranges;
+        self
+    }
+
+    /// Set the width in bytes of every column. The columns are assumed to be
+    /// contiguous and to start at the beginning of the line.
+    pub fn with_column_widths(mut self, column_widths: &[usize]) -> Self {
+        let mut start = 0;
+        self.column_ranges = column_widths
+            .iter()
+            .map(|width| {
+                let range = start..start + width;
+                start = range.end;
+                range
+            })
+            .collect();
+        self
+    }
+
+    /// Set the column names. If the file has a header, these names replace the names
+    /// in the header.
+    pub fn with_column_names(mut self, column_names: Option<Vec<String>>) -> Self {
+        self.column_names = column_names;
+        self
+    }
+
+    /// Set whether the first (non-skipped) line holds the column names.
+    pub fn with_has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Skip the first `n` lines of the file, before the header.
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Stop reading when `n` records are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Set the number of records used to infer the column types. If `None`, all
+    /// records are used. Defaults to `Some(100)`.
+    pub fn with_infer_schema_length(mut self, infer_schema_length: Option<usize>) -> Self {
+        self.infer_schema_length = infer_schema_length;
+        self
+    }
+
+    /// Overwrite the inferred dtypes of the given columns. Fields that cannot be
+    /// parsed as the requested dtype are read as null.
+    pub fn with_schema_overwrite(mut self, schema_overwrite: Option<SchemaRef>) -> Self {
+        self.schema_overwrite = schema_overwrite;
+        self
+    }
+
+    /// Automatically try to parse dates/ datetimes and time.
+    pub fn with_try_parse_dates(mut self, try_parse_dates: bool) -> Self {
+        self.try_parse_dates = try_parse_dates;
+        self
+    }
+
+    /// Strip the padding character from both sides of every field. Defaults to `true`.
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Set the character that fields are padded with. Defaults to a space.
+    pub fn with_pad_char(mut self, pad_char: u8) -> Self {
+        self.pad_char = pad_char;
+        self
+    }
+
+    /// Skip lines that start with this character.
+    pub fn with_comment_prefix(mut self, comment_prefix: Option<u8>) -> Self {
+        self.comment_prefix = comment_prefix;
+        self
+    }
+
+    fn field<'a>(
+        &self,
+        line: &'a [u8],
+        range: &Range<usize>,
+        line_nr: usize,
+    ) -> PolarsResult<&'a str> {
+        let start = std::cmp::min(range.start, line.len());
+        let end = std::cmp::min(range.end, line.len());
+        let mut field = &line[start..end];
+        if self.trim {
+            let pad_char = self.pad_char;
+            let first = field.iter().position(|b| *b != pad_char);
+            let last = field.iter().rposition(|b| *b != pad_char);
+            field = match (first, last) {
+                (Some(first), Some(last)) => &field[first..=last],
+                _ => &[],
+            };
+        }
+        simdutf8::basic::from_utf8(field).map_err(
+            |_| polars_err!(ComputeError: "invalid utf-8 in line {} at bytes {:?}", line_nr, range),
+        )
+    }
+
+    fn infer_dtype(&self, values: &[Option<&str>]) -> DataType {
+        let n = self.infer_schema_length.unwrap_or(usize::MAX);
+        let possibilities = values
+            .iter()
+            .take(n)
+            .flatten()
+            .map(|v| infer_field_schema(v, self.try_parse_dates, false))
+            .collect::<PlHashSet<_>>();
+        if possibilities.is_empty() {
+            DataType::String
+        } else {
+            finish_infer_field_schema(&possibilities)
+        }
+    }
+}
+
+impl<R> SerReader<R> for FwfReader<R>
+where
+    R: Read,
+{
+    fn new(reader: R) -> Self {
+        FwfReader {
+            reader,
+            column_ranges: vec![],
+            column_names: None,
+            has_header: false,
+            skip_rows: 0,
+            n_rows: None,
+            infer_schema_length: Some(100),
+            schema_overwrite: None,
+            try_parse_dates: false,
+            trim: true,
+            pad_char: b' ',
+            comment_prefix: None,
+        }
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            !self.column_ranges.is_empty(),
+            InvalidOperation: "no column ranges or widths given for fixed-width file"
+        );
+        let mut ranges = self.column_ranges.iter().collect::<Vec<_>>();
+        ranges.sort_by_key(|r| r.start);
+        for w in ranges.windows(2) {
+            polars_ensure!(
+                w[0].end <= w[1].start,
+                InvalidOperation: "column ranges {:?} and {:?} overlap", w[0], w[1]
+            );
+        }
+
+        let mut bytes = vec![];
+        self.reader.read_to_end(&mut bytes)?;
+        let mut lines = bytes
+            .split(|b| *b == b'\n')
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+            .enumerate()
+            .skip(self.skip_rows)
+            .filter(|(_, line)| {
+                !line.is_empty() && self.comment_prefix.map_or(true, |c| line[0] != c)
+            });
+
+        let n_columns = self.column_ranges.len();
+        let header = if self.has_header { lines.next() } else { None };
+        let mut names = match header {
+            Some((line_nr, line)) => self
+                .column_ranges
+                .iter()
+                .map(|range| self.field(line, range, line_nr).map(|s| s.to_string()))
+                .collect::<PolarsResult<Vec<_>>>()?,
+            None => (1..=n_columns).map(|i| format!("column_{i}")).collect(),
+        };
+        if let Some(column_names) = self.column_names.take() {
+            polars_ensure!(
+                column_names.len() == n_columns,
+                ShapeMismatch: "got {} column names for {} column ranges", column_names.len(), n_columns
+            );
+            names = column_names;
+        }
+
+        let n_rows = self.n_rows.unwrap_or(usize::MAX);
+        let mut columns = vec![Vec::<Option<&str>>::new(); n_columns];
+        for (line_nr, line) in lines.take(n_rows) {
+            for (column, range) in columns.iter_mut().zip(&self.column_ranges) {
+                let field = self.field(line, range, line_nr)?;
+                column.push((!field.is_empty()).then_some(field));
+            }
+        }
+
+        let columns = columns
+            .into_iter()
+            .zip(names)
+            .map(|(values, name)| {
+                let dtype = match self.schema_overwrite.as_deref().and_then(|s| s.get(&name)) {
+                    Some(dtype) => dtype.clone(),
+                    None => self.infer_dtype(&values),
+                };
+                let s = StringChunked::from_iter_options(&name, values.into_iter()).into_series();
+                match dtype {
+                    DataType::String => Ok(s),
+                    DataType::Boolean => Ok(s
+                        .str()?
+                        .into_iter()
+                        .map(|opt_v| {
+                            opt_v.and_then(|v| match v.to_ascii_lowercase().as_str() {
+                                "true" => Some(true),
+                                "false" => Some(false),
+                                _ => None,
+                            })
+                        })
+                        .collect::<BooleanChunked>()
+                        .with_name(&name)
+                        .into_series()),
+                    dtype => s.cast(&dtype),
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+}
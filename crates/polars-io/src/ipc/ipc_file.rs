@@ -42,6 +42,7 @@ use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::statistics::prune_record_batches;
 use crate::mmap::MmapBytesReader;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
@@ -86,6 +87,7 @@ pub struct IpcReader<R: MmapBytesReader> {
     schema: Option<ArrowSchemaRef>,
 }
 
+#[cfg(not(target_family = "wasm"))]
 fn check_mmap_err(err: PolarsError) -> PolarsResult<()> {
     if let PolarsError::ComputeError(s) = &err {
         if s.as_ref() == "memory_map can only be done on uncompressed IPC files" {
@@ -109,6 +111,24 @@ impl<R: MmapBytesReader> IpcReader<R> {
         Ok(self.metadata.as_ref().unwrap())
     }
 
+    /// Skip the record batches whose statistics show that they can't satisfy the predicate.
+    ///
+    /// This is only done without a row index or row limit, as these depend on all rows.
+    pub(super) fn prune_record_batches(
+        &self,
+        metadata: &mut read::FileMetadata,
+        predicate: Option<&dyn PhysicalIoExpr>,
+    ) -> PolarsResult<()> {
+        if let (Some(predicate), None, None) = (predicate, &self.row_index, self.n_rows) {
+            let n_blocks = metadata.blocks.len();
+            let n_pruned = prune_record_batches(metadata, predicate)?;
+            if n_pruned > 0 && polars_core::config::verbose() {
+                eprintln!("skipping {n_pruned} of {n_blocks} record batches based on statistics");
+            }
+        }
+        Ok(())
+    }
+
     /// Get arrow schema of the Ipc File.
     pub fn schema(&mut self) -> PolarsResult<ArrowSchemaRef> {
         self.get_metadata()?;
@@ -139,7 +159,8 @@ impl<R: MmapBytesReader> IpcReader<R> {
         self
     }
 
-    /// Set if the file is to be memory_mapped. Only works with uncompressed files.
+    /// Set if the file is to be memory_mapped. Only works with uncompressed files. Ignored on wasm,
+    /// where the file is read instead.
     /// The file name must be passed to register the memory mapped file.
     pub fn memory_mapped(mut self, path_buf: Option<PathBuf>) -> Self {
         self.memory_map = path_buf;
@@ -148,11 +169,13 @@ impl<R: MmapBytesReader> IpcReader<R> {
 
     // todo! hoist to lazy crate
     #[cfg(feature = "lazy")]
+    #[cfg_attr(target_family = "wasm", allow(unused_variables))]
     pub fn finish_with_scan_ops(
         mut self,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         verbose: bool,
     ) -> PolarsResult<DataFrame> {
+        #[cfg(not(target_family = "wasm"))]
         if self.memory_map.is_some() && self.reader.to_file().is_some() {
             if verbose {
                 eprintln!("memory map ipc file")
@@ -163,7 +186,8 @@ impl<R: MmapBytesReader> IpcReader<R> {
             }
         }
         let rechunk = self.rechunk;
-        let metadata = read::read_file_metadata(&mut self.reader)?;
+        let mut metadata = read::read_file_metadata(&mut self.reader)?;
+        self.prune_record_batches(&mut metadata, predicate.as_deref())?;
 
         // NOTE: For some code paths this already happened. See
         // https://github.com/pola-rs/polars/pull/14984#discussion_r1520125000
@@ -214,6 +238,7 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
     }
 
     fn finish(mut self) -> PolarsResult<DataFrame> {
+        #[cfg(not(target_family = "wasm"))]
         if self.memory_map.is_some() && self.reader.to_file().is_some() {
             match self.finish_memmapped(None) {
                 Ok(df) => return Ok(df),
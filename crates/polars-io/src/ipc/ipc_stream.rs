@@ -208,6 +208,7 @@ pub struct IpcStreamWriter<W> {
     writer: W,
     compression: Option<IpcCompression>,
     pl_flavor: bool,
+    delta_dictionaries: bool,
 }
 
 use arrow::record_batch::RecordBatch;
@@ -225,6 +226,13 @@ impl<W> IpcStreamWriter<W> {
         self.pl_flavor = pl_flavor;
         self
     }
+
+    /// Write dictionaries that only grew between batches as delta dictionary batches.
+    /// Defaults to false.
+    pub fn with_delta_dictionaries(mut self, delta_dictionaries: bool) -> Self {
+        self.delta_dictionaries = delta_dictionaries;
+        self
+    }
 }
 
 impl<W> SerWriter<W> for IpcStreamWriter<W>
@@ -236,6 +244,7 @@ where
             writer,
             compression: None,
             pl_flavor: false,
+            delta_dictionaries: false,
         }
     }
 
@@ -244,6 +253,7 @@ where
             &mut self.writer,
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
+                delta_dictionaries: self.delta_dictionaries,
             },
         );
 
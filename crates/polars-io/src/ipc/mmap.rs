@@ -17,11 +17,12 @@ impl<R: MmapBytesReader> IpcReader<R> {
     ) -> PolarsResult<DataFrame> {
         match self.reader.to_file() {
             Some(file) => {
-                let mmap = unsafe { memmap::Mmap::map(file).unwrap() };
+                let mmap = unsafe { memmap::Mmap::map(file)? };
                 let mmap_key = self.memory_map.take().unwrap();
                 let semaphore = MMapSemaphore::new(mmap_key, mmap);
-                let metadata =
+                let mut metadata =
                     read::read_file_metadata(&mut std::io::Cursor::new(semaphore.as_ref()))?;
+                self.prune_record_batches(&mut metadata, predicate.as_deref())?;
 
                 if let Some(columns) = &self.columns {
                     let schema = &metadata.schema;
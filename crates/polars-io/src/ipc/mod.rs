@@ -4,8 +4,9 @@ mod ipc_file;
 mod ipc_reader_async;
 #[cfg(feature = "ipc_streaming")]
 mod ipc_stream;
-#[cfg(feature = "ipc")]
+#[cfg(all(feature = "ipc", not(target_family = "wasm")))]
 mod mmap;
+mod statistics;
 mod write;
 #[cfg(all(feature = "async", feature = "ipc"))]
 mod write_async;
@@ -16,4 +17,5 @@ pub use ipc_file::{IpcReader, IpcScanOptions};
 pub use ipc_reader_async::*;
 #[cfg(feature = "ipc_streaming")]
 pub use ipc_stream::*;
+pub use statistics::read_record_batch_lengths;
 pub use write::{BatchedWriter, IpcCompression, IpcWriter, IpcWriterOption, IpcWriterOptions};
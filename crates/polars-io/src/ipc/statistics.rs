@@ -0,0 +1,218 @@
+//! Record batch statistics stored in the footer of IPC files written by Polars.
+//!
+//! The footer's custom metadata holds the length of every record batch and, optionally, the
+//! minimum, maximum and null count of every numeric column per record batch. Readers use these
+//! to count rows and to skip record batches that cannot satisfy a predicate without reading
+//! the message bodies.
+use arrow::datatypes::Metadata;
+use arrow::io::ipc::read::FileMetadata;
+use arrow::record_batch::RecordBatch;
+use polars_core::prelude::*;
+
+#[cfg(feature = "ipc")]
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+
+const RECORD_BATCH_LENGTHS_KEY: &str = "polars:record_batch_lengths";
+const RECORD_BATCH_STATS_PREFIX: &str = "polars:record_batch_stats:";
+
+/// Collects the statistics of the record batches written to an IPC file.
+pub(super) struct RecordBatchStatsCollector {
+    schema: SchemaRef,
+    lengths: Vec<usize>,
+    /// Per column the serialized `min,max,null_count` of every record batch. `None` for
+    /// columns that don't get min/max statistics.
+    columns: Vec<Option<Vec<String>>>,
+}
+
+impl RecordBatchStatsCollector {
+    pub(super) fn new(schema: SchemaRef, min_max: bool) -> Self {
+        let columns = schema
+            .iter_dtypes()
+            .map(|dtype| (min_max && supports_min_max(dtype)).then(Vec::new))
+            .collect();
+        Self {
+            schema,
+            lengths: vec![],
+            columns,
+        }
+    }
+
+    pub(super) fn update(&mut self, batch: &RecordBatch) -> PolarsResult<()> {
+        self.lengths.push(batch.len());
+
+        for ((column, (name, dtype)), array) in self
+            .columns
+            .iter_mut()
+            .zip(self.schema.iter())
+            .zip(batch.arrays())
+        {
+            let Some(column) = column else {
+                continue;
+            };
+            // SAFETY: the array was created from a column of this dtype.
+            let s = unsafe {
+                Series::from_chunks_and_dtype_unchecked(name, vec![array.clone()], dtype)
+            };
+            let min = s.min_reduce()?;
+            let max = s.max_reduce()?;
+            column.push(format!(
+                "{},{},{}",
+                serialize_value(min.value()),
+                serialize_value(max.value()),
+                s.null_count()
+            ));
+        }
+        Ok(())
+    }
+
+    pub(super) fn finish(self) -> Metadata {
+        let mut metadata = Metadata::new();
+        metadata.insert(
+            RECORD_BATCH_LENGTHS_KEY.to_string(),
+            self.lengths
+                .iter()
+                .map(|len| len.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        for (i, column) in self.columns.into_iter().enumerate() {
+            if let Some(column) = column {
+                metadata.insert(format!("{RECORD_BATCH_STATS_PREFIX}{i}"), column.join(";"));
+            }
+        }
+        metadata
+    }
+}
+
+fn supports_min_max(dtype: &DataType) -> bool {
+    dtype.is_integer() || dtype.is_float()
+}
+
+fn serialize_value(value: &AnyValue) -> String {
+    if value.is_null() {
+        String::new()
+    } else if value.is_float() {
+        value.extract::<f64>().unwrap().to_string()
+    } else if value.is_unsigned_integer() {
+        value.extract::<u64>().unwrap().to_string()
+    } else {
+        value.extract::<i64>().unwrap().to_string()
+    }
+}
+
+#[cfg(feature = "ipc")]
+fn deserialize_value(value: &str, name: &str, dtype: &DataType) -> Option<Series> {
+    let s = if value.is_empty() {
+        Series::full_null(name, 1, dtype)
+    } else if dtype.is_float() {
+        Series::new(name, [value.parse::<f64>().ok()?])
+    } else if dtype.is_unsigned_integer() {
+        Series::new(name, [value.parse::<u64>().ok()?])
+    } else {
+        Series::new(name, [value.parse::<i64>().ok()?])
+    };
+    s.cast(dtype).ok()
+}
+
+/// Returns the lengths of the record batches of the file, if they were recorded when writing.
+pub fn read_record_batch_lengths(metadata: &FileMetadata) -> Option<Vec<usize>> {
+    let lengths = metadata
+        .custom_metadata
+        .as_ref()?
+        .get(RECORD_BATCH_LENGTHS_KEY)?;
+    let lengths = if lengths.is_empty() {
+        vec![]
+    } else {
+        lengths
+            .split(',')
+            .map(|len| len.parse().ok())
+            .collect::<Option<Vec<usize>>>()?
+    };
+    // Record batches appended by other writers are not accounted for.
+    (lengths.len() == metadata.blocks.len()).then_some(lengths)
+}
+
+/// Returns the statistics of every record batch of the file, if they were recorded when writing.
+#[cfg(feature = "ipc")]
+pub(crate) fn read_record_batch_stats(metadata: &FileMetadata) -> Option<Vec<BatchStats>> {
+    let lengths = read_record_batch_lengths(metadata)?;
+    let custom_metadata = metadata.custom_metadata.as_ref()?;
+    let schema = Arc::new(Schema::from(metadata.schema.as_ref()));
+
+    let columns = schema
+        .iter()
+        .enumerate()
+        .map(|(i, (name, dtype))| {
+            let Some(column) = custom_metadata.get(&format!("{RECORD_BATCH_STATS_PREFIX}{i}"))
+            else {
+                return Some(None);
+            };
+            let batches = column
+                .split(';')
+                .map(|batch| {
+                    let mut fields = batch.split(',');
+                    let min = deserialize_value(fields.next()?, name, dtype)?;
+                    let max = deserialize_value(fields.next()?, name, dtype)?;
+                    let null_count = fields.next()?.parse::<IdxSize>().ok()?;
+                    Some((min, max, null_count))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            (batches.len() == lengths.len()).then_some(Some(batches))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let stats = lengths
+        .iter()
+        .enumerate()
+        .map(|(batch_idx, &len)| {
+            let column_stats = schema
+                .iter_fields()
+                .zip(&columns)
+                .map(|(field, column)| match column {
+                    Some(batches) => {
+                        let (min, max, null_count) = &batches[batch_idx];
+                        ColumnStats::new(
+                            field.clone(),
+                            Some(Series::new(field.name(), [*null_count])),
+                            Some(min.clone()),
+                            Some(max.clone()),
+                        )
+                    },
+                    None => ColumnStats::from_field(field),
+                })
+                .collect();
+            BatchStats::new(schema.clone(), column_stats, Some(len))
+        })
+        .collect();
+    Some(stats)
+}
+
+/// Removes the record batches that cannot satisfy `predicate` from `metadata`, based on the
+/// statistics recorded when writing. Returns the number of removed record batches.
+#[cfg(feature = "ipc")]
+pub(super) fn prune_record_batches(
+    metadata: &mut FileMetadata,
+    predicate: &dyn PhysicalIoExpr,
+) -> PolarsResult<usize> {
+    let Some(evaluator) = predicate.as_stats_evaluator() else {
+        return Ok(0);
+    };
+    let Some(stats) = read_record_batch_stats(metadata) else {
+        return Ok(0);
+    };
+
+    let mut keep = Vec::with_capacity(stats.len());
+    for stats in &stats {
+        keep.push(match evaluator.should_read(stats) {
+            Ok(should_read) => should_read,
+            // the predicate may refer to columns that are added after reading
+            Err(PolarsError::ColumnNotFound(_)) => true,
+            Err(err) => return Err(err),
+        });
+    }
+
+    let n_blocks = metadata.blocks.len();
+    let mut keep = keep.into_iter();
+    metadata.blocks.retain(|_| keep.next().unwrap());
+    Ok(n_blocks - metadata.blocks.len())
+}
@@ -7,6 +7,7 @@ use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::statistics::RecordBatchStatsCollector;
 use crate::prelude::*;
 use crate::shared::{schema_to_arrow_checked, WriterFactory};
 
@@ -43,6 +44,8 @@ pub struct IpcWriter<W> {
     pub(super) compression: Option<IpcCompression>,
     /// Polars' flavor of arrow. This might be temporary.
     pub(super) pl_flavor: bool,
+    /// Write min/max statistics of the numeric columns of every record batch.
+    pub(super) statistics: bool,
 }
 
 impl<W: Write> IpcWriter<W> {
@@ -57,7 +60,18 @@ impl<W: Write> IpcWriter<W> {
         self
     }
 
+    /// Write the minimum, maximum and null count of the numeric columns of every record batch
+    /// to the footer of the file. Readers use these to skip record batches based on predicates.
+    /// Defaults to `false`.
+    ///
+    /// The lengths of the record batches are always written.
+    pub fn with_statistics(mut self, statistics: bool) -> Self {
+        self.statistics = statistics;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        let stats = RecordBatchStatsCollector::new(Arc::new(schema.clone()), self.statistics);
         let schema = schema_to_arrow_checked(schema, self.pl_flavor, "ipc")?;
         let mut writer = write::FileWriter::new(
             self.writer,
@@ -65,6 +79,7 @@ impl<W: Write> IpcWriter<W> {
             None,
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
+                delta_dictionaries: false,
             },
         );
         writer.start()?;
@@ -72,6 +87,7 @@ impl<W: Write> IpcWriter<W> {
         Ok(BatchedWriter {
             writer,
             pl_flavor: self.pl_flavor,
+            stats: Some(stats),
         })
     }
 }
@@ -85,10 +101,12 @@ where
             writer,
             compression: None,
             pl_flavor: true,
+            statistics: false,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let mut stats = RecordBatchStatsCollector::new(Arc::new(df.schema()), self.statistics);
         let schema = schema_to_arrow_checked(&df.schema(), self.pl_flavor, "ipc")?;
         let mut ipc_writer = write::FileWriter::try_new(
             &mut self.writer,
@@ -96,14 +114,17 @@ where
             None,
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
+                delta_dictionaries: false,
             },
         )?;
         df.align_chunks();
         let iter = df.iter_chunks(self.pl_flavor, true);
 
         for batch in iter {
+            stats.update(&batch)?;
             ipc_writer.write(&batch, None)?
         }
+        ipc_writer.set_custom_metadata(stats.finish());
         ipc_writer.finish()?;
         Ok(())
     }
@@ -112,6 +133,7 @@ where
 pub struct BatchedWriter<W: Write> {
     writer: write::FileWriter<W>,
     pl_flavor: bool,
+    stats: Option<RecordBatchStatsCollector>,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -122,6 +144,9 @@ impl<W: Write> BatchedWriter<W> {
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         let iter = df.iter_chunks(self.pl_flavor, true);
         for batch in iter {
+            if let Some(stats) = &mut self.stats {
+                stats.update(&batch)?;
+            }
             self.writer.write(&batch, None)?
         }
         Ok(())
@@ -129,6 +154,9 @@ impl<W: Write> BatchedWriter<W> {
 
     /// Writes the footer of the IPC file.
     pub fn finish(&mut self) -> PolarsResult<()> {
+        if let Some(stats) = self.stats.take() {
+            self.writer.set_custom_metadata(stats.finish());
+        }
         self.writer.finish()?;
         Ok(())
     }
@@ -11,6 +11,7 @@ impl<W: AsyncWrite + Unpin + Send> IpcWriter<W> {
             writer,
             compression: None,
             pl_flavor: false,
+            statistics: false,
         }
     }
 
@@ -21,6 +22,7 @@ impl<W: AsyncWrite + Unpin + Send> IpcWriter<W> {
             None,
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
+                delta_dictionaries: false,
             },
         );
 
@@ -7,8 +7,16 @@ pub mod avro;
 pub mod cloud;
 #[cfg(any(feature = "csv", feature = "json"))]
 pub mod csv;
+#[cfg(feature = "database")]
+pub mod database;
+#[cfg(feature = "delta")]
+pub mod delta;
+#[cfg(feature = "excel")]
+pub mod excel;
 #[cfg(feature = "file_cache")]
 pub mod file_cache;
+#[cfg(feature = "fwf")]
+pub mod fwf;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
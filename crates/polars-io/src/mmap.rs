@@ -1,10 +1,11 @@
 use std::collections::btree_map::Entry;
 use std::collections::BTreeMap;
 use std::fs::File;
-use std::io::{BufReader, Cursor, Read, Seek};
+use std::io::{BufReader, Cursor, Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
+#[cfg(not(target_family = "wasm"))]
 use memmap::Mmap;
 use once_cell::sync::Lazy;
 use polars_core::config::verbose;
@@ -16,19 +17,24 @@ use polars_utils::create_file;
 static MEMORY_MAPPED_FILES: Lazy<Mutex<BTreeMap<PathBuf, u32>>> =
     Lazy::new(|| Mutex::new(Default::default()));
 
+#[cfg(not(target_family = "wasm"))]
 pub(crate) struct MMapSemaphore {
     path: PathBuf,
     mmap: Mmap,
 }
 
+#[cfg(not(target_family = "wasm"))]
 impl MMapSemaphore {
     pub(super) fn new(path: PathBuf, mmap: Mmap) -> Self {
         let mut guard = MEMORY_MAPPED_FILES.lock().unwrap();
-        guard.insert(path.clone(), 1);
+        // the same file can be mapped multiple times, the entry is removed when the last
+        // mapping is dropped
+        *guard.entry(path.clone()).or_insert(0) += 1;
         Self { path, mmap }
     }
 }
 
+#[cfg(not(target_family = "wasm"))]
 impl AsRef<[u8]> for MMapSemaphore {
     #[inline]
     fn as_ref(&self) -> &[u8] {
@@ -36,6 +42,7 @@ impl AsRef<[u8]> for MMapSemaphore {
     }
 }
 
+#[cfg(not(target_family = "wasm"))]
 impl Drop for MMapSemaphore {
     fn drop(&mut self) {
         let mut guard = MEMORY_MAPPED_FILES.lock().unwrap();
@@ -93,6 +100,72 @@ where
     }
 }
 
+/// Wraps a reader that cannot seek, e.g. [`std::io::Stdin`] or the output of another process,
+/// so it can be passed to readers that take a [`MmapBytesReader`]. The input is read once from
+/// the start; seeking other than to the current position returns an error.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::mmap::NonSeekableReader;
+/// use polars_io::prelude::*;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     CsvReader::new(NonSeekableReader::new(std::io::stdin())).finish()
+/// }
+/// ```
+pub struct NonSeekableReader<R> {
+    reader: R,
+    position: u64,
+}
+
+impl<R: Read> NonSeekableReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            position: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Read for NonSeekableReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.reader.read(buf)?;
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl<R: Read> Seek for NonSeekableReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        match pos {
+            SeekFrom::Current(0) => Ok(self.position),
+            SeekFrom::Start(offset) if offset == self.position => Ok(self.position),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "cannot seek in a non-seekable reader",
+            )),
+        }
+    }
+}
+
+impl<R: Read + Send + Sync> MmapBytesReader for NonSeekableReader<R> {}
+
+/// Memory maps `file` if it is a regular file. Pipes and other special files can't be mapped
+/// and have to be read instead.
+#[cfg(not(target_family = "wasm"))]
+pub(crate) fn map_regular_file(file: &File) -> PolarsResult<Option<Mmap>> {
+    if !file.metadata()?.is_file() {
+        return Ok(None);
+    }
+    Ok(Some(unsafe { Mmap::map(file)? }))
+}
+
 impl<T: MmapBytesReader + ?Sized> MmapBytesReader for Box<T> {
     fn to_file(&self) -> Option<&File> {
         T::to_file(self)
@@ -117,6 +190,7 @@ impl<T: MmapBytesReader> MmapBytesReader for &mut T {
 pub enum ReaderBytes<'a> {
     Borrowed(&'a [u8]),
     Owned(Vec<u8>),
+    #[cfg(not(target_family = "wasm"))]
     Mapped(memmap::Mmap, &'a File),
 }
 
@@ -126,6 +200,7 @@ impl std::ops::Deref for ReaderBytes<'_> {
         match self {
             Self::Borrowed(ref_bytes) => ref_bytes,
             Self::Owned(vec) => vec,
+            #[cfg(not(target_family = "wasm"))]
             Self::Mapped(mmap, _) => mmap,
         }
     }
@@ -140,18 +215,20 @@ impl<'a, T: 'a + MmapBytesReader> From<&'a mut T> for ReaderBytes<'a> {
                 ReaderBytes::Borrowed(s)
             },
             None => {
-                if let Some(f) = m.to_file() {
+                #[cfg(not(target_family = "wasm"))]
+                if let Some((mmap, f)) = m
+                    .to_file()
+                    .and_then(|f| Some((map_regular_file(f).unwrap()?, f)))
+                {
                     let f = unsafe { std::mem::transmute::<&File, &'a File>(f) };
-                    let mmap = unsafe { memmap::Mmap::map(f).unwrap() };
-                    ReaderBytes::Mapped(mmap, f)
-                } else {
-                    if verbose() {
-                        eprintln!("could not memory map file; read to buffer.")
-                    }
-                    let mut buf = vec![];
-                    m.read_to_end(&mut buf).expect("could not read");
-                    ReaderBytes::Owned(buf)
+                    return ReaderBytes::Mapped(mmap, f);
+                }
+                if verbose() {
+                    eprintln!("could not memory map file; read to buffer.")
                 }
+                let mut buf = vec![];
+                m.read_to_end(&mut buf).expect("could not read");
+                ReaderBytes::Owned(buf)
             },
         }
     }
@@ -0,0 +1,209 @@
+use std::collections::VecDeque;
+use std::io::{BufRead, Cursor};
+use std::num::NonZeroUsize;
+
+use polars_core::prelude::*;
+
+use crate::ndjson::buffer::*;
+use crate::ndjson::core::parse_impl;
+use crate::utils::overwrite_schema;
+
+/// Read newline-delimited JSON in batches of at most `batch_size` rows.
+///
+/// Contrary to [`JsonLineReader`](crate::ndjson::core::JsonLineReader), which needs the
+/// whole input in memory, this reader pulls lines from a [`BufRead`] on demand. Memory
+/// usage is therefore bounded by the batch size and the lines held back for schema
+/// inference.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::File;
+/// use std::io::BufReader;
+/// use std::num::NonZeroUsize;
+/// use polars_core::prelude::*;
+/// use polars_io::ndjson::BatchedJsonLineReader;
+///
+/// fn example() -> PolarsResult<()> {
+///     let file = File::open("file.jsonl").expect("file not found");
+///     let mut reader = BatchedJsonLineReader::new(BufReader::new(file))
+///         .with_batch_size(NonZeroUsize::new(10_000).unwrap())
+///         .with_ignore_errors(true);
+///
+///     while let Some(df) = reader.next_batch()? {
+///         println!("{}", df.height());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[must_use]
+pub struct BatchedJsonLineReader<'a, R: BufRead> {
+    reader: R,
+    batch_size: NonZeroUsize,
+    n_rows: Option<usize>,
+    infer_schema_len: Option<NonZeroUsize>,
+    schema: Option<SchemaRef>,
+    schema_overwrite: Option<&'a Schema>,
+    ignore_errors: bool,
+    /// Lines that were read to infer the schema, but not yet parsed.
+    pending: VecDeque<Vec<u8>>,
+    rows_read: usize,
+    finished: bool,
+}
+
+impl<'a, R: BufRead> BatchedJsonLineReader<'a, R> {
+    pub fn new(reader: R) -> Self {
+        BatchedJsonLineReader {
+            reader,
+            batch_size: NonZeroUsize::new(50_000).unwrap(),
+            n_rows: None,
+            infer_schema_len: Some(NonZeroUsize::new(100).unwrap()),
+            schema: None,
+            schema_overwrite: None,
+            ignore_errors: false,
+            pending: VecDeque::new(),
+            rows_read: 0,
+            finished: false,
+        }
+    }
+
+    /// Set the maximum number of rows in a batch. Defaults to 50 000.
+    pub fn with_batch_size(mut self, batch_size: NonZeroUsize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Set the schema and skip schema inference.
+    pub fn with_schema(mut self, schema: SchemaRef) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Overwrite the dtypes of the given columns of the (inferred) schema.
+    pub fn with_schema_overwrite(mut self, schema: &'a Schema) -> Self {
+        self.schema_overwrite = Some(schema);
+        self
+    }
+
+    /// Set the number of lines used to infer the schema. If `None`, the whole input is
+    /// held in memory for inference. Defaults to 100.
+    pub fn infer_schema_len(mut self, infer_schema_len: Option<NonZeroUsize>) -> Self {
+        self.infer_schema_len = infer_schema_len;
+        self
+    }
+
+    /// Set values as `Null` if parsing fails because of schema mismatches, and read
+    /// lines that are not valid JSON as a row of nulls.
+    pub fn with_ignore_errors(mut self, ignore_errors: bool) -> Self {
+        self.ignore_errors = ignore_errors;
+        self
+    }
+
+    /// Get the schema of the batches, inferring it from the first lines if it was not
+    /// given.
+    pub fn schema(&mut self) -> PolarsResult<SchemaRef> {
+        if self.schema.is_none() {
+            let max_lines = self.infer_schema_len.map_or(usize::MAX, |n| n.get());
+            let mut line = Vec::new();
+            while self.pending.len() < max_lines && self.read_line(&mut line)? {
+                self.pending.push_back(std::mem::take(&mut line));
+            }
+            let bytes = self.pending.iter().fold(Vec::new(), |mut acc, line| {
+                acc.extend_from_slice(line);
+                acc.push(b'\n');
+                acc
+            });
+            let schema =
+                crate::ndjson::infer_schema(&mut Cursor::new(bytes), self.infer_schema_len)?;
+            self.schema = Some(Arc::new(schema));
+        }
+        let schema = self.schema.as_mut().unwrap();
+        if let Some(overwriting_schema) = self.schema_overwrite.take() {
+            overwrite_schema(Arc::make_mut(schema), overwriting_schema)?;
+        }
+        Ok(schema.clone())
+    }
+
+    /// Read the next non-empty line into `line`. Returns `false` at the end of the input.
+    fn read_line(&mut self, line: &mut Vec<u8>) -> PolarsResult<bool> {
+        loop {
+            line.clear();
+            if self.reader.read_until(b'\n', line)? == 0 {
+                return Ok(false);
+            }
+            if !line.iter().all(u8::is_ascii_whitespace) {
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Parse the next batch of at most `batch_size` rows. Returns `None` if the input is
+    /// exhausted.
+    pub fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let schema = self.schema()?;
+        if self.finished {
+            return Ok(None);
+        }
+        let mut batch_size = self.batch_size.get();
+        if let Some(n_rows) = self.n_rows {
+            batch_size = std::cmp::min(batch_size, n_rows - self.rows_read);
+        }
+
+        let mut buffers = init_buffers(&schema, batch_size, self.ignore_errors)?;
+        let mut scratch = Vec::new();
+        let mut line = Vec::new();
+        let mut n_parsed = 0;
+        while n_parsed < batch_size {
+            match self.pending.pop_front() {
+                Some(pending) => line = pending,
+                None => {
+                    if !self.read_line(&mut line)? {
+                        self.finished = true;
+                        break;
+                    }
+                },
+            }
+            let end = line
+                .iter()
+                .rposition(|b| !b.is_ascii_whitespace())
+                .map_or(0, |i| i + 1);
+            if let Err(e) = parse_impl(&line[..end], &mut buffers, &mut scratch) {
+                polars_ensure!(
+                    self.ignore_errors,
+                    ComputeError: "error parsing ndjson line {}: {}", self.rows_read + n_parsed, e
+                );
+                buffers.values_mut().for_each(|buf| buf.add_null());
+            }
+            n_parsed += 1;
+        }
+        self.rows_read += n_parsed;
+        if self.n_rows.is_some_and(|n_rows| self.rows_read >= n_rows) {
+            self.finished = true;
+        }
+
+        if n_parsed == 0 {
+            return Ok(None);
+        }
+        DataFrame::new(
+            buffers
+                .into_values()
+                .map(|buf| buf.into_series())
+                .collect::<_>(),
+        )
+        .map(Some)
+    }
+}
+
+impl<'a, R: BufRead> Iterator for BatchedJsonLineReader<'a, R> {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_batch().transpose()
+    }
+}
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::fs::File;
 use std::io::Cursor;
 use std::num::NonZeroUsize;
@@ -301,12 +302,24 @@ impl<'a> CoreJsonReader<'a> {
         };
         let file_chunks = get_file_chunks_json(bytes, n_threads);
 
+        // Only create buffers for the projected columns, so that unused keys are
+        // skipped during parsing.
+        let schema = match &self.projection {
+            Some(projection) => Cow::Owned(
+                projection
+                    .iter()
+                    .filter_map(|name| Some(Field::new(name, self.schema.get(name)?.clone())))
+                    .collect::<Schema>(),
+            ),
+            None => Cow::Borrowed(self.schema.as_ref()),
+        };
+
         let row_index = self.row_index.as_ref().map(|ri| ri as &RowIndex);
         let (mut dfs, prepredicate_heights) = POOL.install(|| {
             file_chunks
                 .into_par_iter()
                 .map(|(start_pos, stop_at_nbytes)| {
-                    let mut buffers = init_buffers(&self.schema, capacity, self.ignore_errors)?;
+                    let mut buffers = init_buffers(&schema, capacity, self.ignore_errors)?;
                     parse_lines(&bytes[start_pos..stop_at_nbytes], &mut buffers)?;
                     let mut local_df = DataFrame::new(
                         buffers
@@ -322,7 +335,16 @@ impl<'a> CoreJsonReader<'a> {
                     }
 
                     if let Some(projection) = &self.projection {
-                        local_df = local_df.select(projection.as_ref())?;
+                        // The row index is not part of the projection, but must be kept.
+                        let row_index_name = row_index.map(|ri| ri.name.as_ref());
+                        local_df = local_df.select(
+                            row_index_name.into_iter().chain(
+                                projection
+                                    .iter()
+                                    .map(|name| name.as_str())
+                                    .filter(|name| Some(*name) != row_index_name),
+                            ),
+                        )?;
                     }
 
                     if let Some(predicate) = &self.predicate {
@@ -363,7 +385,7 @@ impl<'a> CoreJsonReader<'a> {
 }
 
 #[inline(always)]
-fn parse_impl(
+pub(crate) fn parse_impl(
     bytes: &[u8],
     buffers: &mut PlIndexMap<BufferKey, Buffer>,
     scratch: &mut Vec<u8>,
@@ -3,9 +3,12 @@ use std::num::NonZeroUsize;
 use arrow::array::StructArray;
 use polars_core::prelude::*;
 
+mod batched;
 pub(crate) mod buffer;
 pub mod core;
 
+pub use batched::BatchedJsonLineReader;
+
 pub fn infer_schema<R: std::io::BufRead>(
     reader: &mut R,
     infer_schema_len: Option<NonZeroUsize>,
@@ -1,4 +1,5 @@
 use std::io::Write;
+use std::path::PathBuf;
 use std::sync::Mutex;
 
 use arrow::datatypes::PhysicalType;
@@ -9,9 +10,9 @@ use polars_parquet::write::{
 };
 
 use super::batched_writer::BatchedWriter;
-use super::options::ParquetCompression;
+use super::options::{ParquetCompression, ParquetWriteOptions};
 use crate::prelude::chunk_df_for_writing;
-use crate::shared::schema_to_arrow_checked;
+use crate::shared::{schema_to_arrow_checked, SerWriter, WriterFactory};
 
 /// Write a DataFrame to Parquet format.
 #[must_use]
@@ -116,6 +117,44 @@ where
     }
 }
 
+/// Exposes the consuming [`ParquetWriter::finish`] as a [`SerWriter`].
+struct ParquetSerWriter<W> {
+    writer: Option<ParquetWriter<W>>,
+}
+
+impl<W: Write> SerWriter<W> for ParquetSerWriter<W> {
+    fn new(writer: W) -> Self {
+        Self {
+            writer: Some(ParquetWriter::new(writer)),
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let writer = self.writer.take().ok_or_else(
+            || polars_err!(ComputeError: "parquet writer can only write a single DataFrame"),
+        )?;
+        writer.finish(df)?;
+        Ok(())
+    }
+}
+
+impl WriterFactory for ParquetWriteOptions {
+    fn create_writer<W: Write + 'static>(&self, writer: W) -> Box<dyn SerWriter<W>> {
+        let writer = ParquetWriter::new(writer)
+            .with_compression(self.compression)
+            .with_statistics(self.statistics)
+            .with_row_group_size(self.row_group_size)
+            .with_data_page_size(self.data_pagesize_limit);
+        Box::new(ParquetSerWriter {
+            writer: Some(writer),
+        })
+    }
+
+    fn extension(&self) -> PathBuf {
+        PathBuf::from("parquet")
+    }
+}
+
 fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
     schema
         .fields
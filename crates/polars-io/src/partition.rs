@@ -14,6 +14,12 @@ use crate::WriterFactory;
 
 /// Write a DataFrame with disk partitioning
 ///
+/// Every group of the `by` columns is written to its own directory
+/// `rootdir/key1=value1/key2=value2/` as a file of the format given by the
+/// [`WriterFactory`], e.g. [`IpcWriterOption`](crate::ipc::IpcWriterOption),
+/// [`CsvWriterOptions`](crate::csv::write::CsvWriterOptions) or
+/// [`ParquetWriteOptions`](crate::parquet::write::ParquetWriteOptions).
+///
 /// # Example
 /// ```
 /// use polars_core::prelude::*;
@@ -32,8 +38,11 @@ pub struct PartitionedWriter<F> {
     rootdir: PathBuf,
     by: Vec<String>,
     parallel: bool,
+    file_name: Option<FileNameFn>,
 }
 
+type FileNameFn = Box<dyn Fn(usize) -> String + Send + Sync>;
+
 impl<F> PartitionedWriter<F>
 where
     F: WriterFactory + Send + Sync,
@@ -49,22 +58,37 @@ where
             rootdir: rootdir.into(),
             by: by.into_iter().map(|s| s.as_ref().to_string()).collect(),
             parallel: true,
+            file_name: None,
         }
     }
 
-    /// Write the parquet file in parallel (default).
+    /// Write the partitions in parallel (default).
     pub fn with_parallel(mut self, parallel: bool) -> Self {
         self.parallel = parallel;
         self
     }
 
+    /// Name the file of each partition. The function gets the index of the partition and
+    /// returns the file name without extension. Defaults to `data-{index:04}`.
+    pub fn with_file_name<G>(mut self, file_name: G) -> Self
+    where
+        G: Fn(usize) -> String + Send + Sync + 'static,
+    {
+        self.file_name = Some(Box::new(file_name));
+        self
+    }
+
     fn write_partition_df(&self, partition_df: &mut DataFrame, i: usize) -> PolarsResult<()> {
         let mut path = resolve_partition_dir(&self.rootdir, &self.by, partition_df);
         std::fs::create_dir_all(&path)?;
 
+        let file_name = match &self.file_name {
+            Some(file_name) => file_name(i),
+            None => format!("data-{:04}", i),
+        };
         path.push(format!(
-            "data-{:04}.{}",
-            i,
+            "{}.{}",
+            file_name,
             self.option.extension().display()
         ));
 
@@ -77,41 +101,58 @@ where
     }
 
     pub fn finish(self, df: &DataFrame) -> PolarsResult<()> {
+        polars_ensure!(
+            !self.by.is_empty(),
+            ComputeError: "at least one column is needed to partition by"
+        );
         let groups = df.group_by(self.by.clone())?;
         let groups = groups.get_groups();
 
+        let write_partition = |i: usize| {
+            let mut part_df = match groups {
+                // groups are in bounds
+                // and sorted
+                GroupsProxy::Idx(idx) => unsafe {
+                    df._take_unchecked_slice_sorted(&idx.all()[i], false, IsSorted::Ascending)
+                },
+                GroupsProxy::Slice { groups, .. } => {
+                    let [first, len] = groups[i];
+                    df.slice(first as i64, len as usize)
+                },
+            };
+            self.write_partition_df(&mut part_df, i)
+        };
+
         // don't parallelize this
         // there is a lot of parallelization in take and this may easily SO
-        POOL.install(|| {
-            match groups {
-                GroupsProxy::Idx(idx) => {
-                    idx.par_iter()
-                        .enumerate()
-                        .map(|(i, (_, group))| {
-                            // groups are in bounds
-                            // and sorted
-                            let mut part_df = unsafe {
-                                df._take_unchecked_slice_sorted(group, false, IsSorted::Ascending)
-                            };
-                            self.write_partition_df(&mut part_df, i)
-                        })
-                        .collect::<PolarsResult<Vec<_>>>()
-                },
-                GroupsProxy::Slice { groups, .. } => groups
-                    .par_iter()
-                    .enumerate()
-                    .map(|(i, [first, len])| {
-                        let mut part_df = df.slice(*first as i64, *len as usize);
-                        self.write_partition_df(&mut part_df, i)
-                    })
-                    .collect::<PolarsResult<Vec<_>>>(),
-            }
-        })?;
-
-        Ok(())
+        if self.parallel {
+            POOL.install(|| {
+                (0..groups.len())
+                    .into_par_iter()
+                    .try_for_each(write_partition)
+            })
+        } else {
+            (0..groups.len()).try_for_each(write_partition)
+        }
     }
 }
 
+/// Write a DataFrame with disk partitioning, see [`PartitionedWriter`].
+pub fn write_partitioned<F, P, I, S>(
+    df: &DataFrame,
+    option: F,
+    rootdir: P,
+    by: I,
+) -> PolarsResult<()>
+where
+    F: WriterFactory + Send + Sync,
+    P: Into<PathBuf>,
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    PartitionedWriter::new(option, rootdir, by).finish(df)
+}
+
 /// `partition_df` must be created in the same way as `partition_by`.
 fn resolve_partition_dir<I, S>(rootdir: &Path, by: I, partition_df: &DataFrame) -> PathBuf
 where
@@ -122,7 +163,12 @@ where
     path.push(resolve_homedir(rootdir));
 
     for key in by.into_iter() {
-        let value = partition_df[key.as_ref()].get(0).unwrap().to_string();
+        let value = partition_df[key.as_ref()].get(0).unwrap();
+        // Strings are written without the quotes of their `Display` implementation.
+        let value = match value.get_str() {
+            Some(s) => s.to_string(),
+            None => value.to_string(),
+        };
         path.push(format!("{}={}", key.as_ref(), value))
     }
     path
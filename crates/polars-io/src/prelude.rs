@@ -7,6 +7,8 @@ pub use crate::ipc::*;
 pub use crate::json::*;
 #[cfg(feature = "json")]
 pub use crate::ndjson::core::*;
+#[cfg(feature = "json")]
+pub use crate::ndjson::BatchedJsonLineReader;
 #[cfg(feature = "parquet")]
 pub use crate::parquet::{metadata::*, read::*, write::*};
 pub use crate::shared::{SerReader, SerWriter};
@@ -9,6 +9,8 @@ use polars_core::prelude::*;
 use polars_core::utils::{accumulate_dataframes_vertical_unchecked, split_df_as_ref};
 use regex::{Regex, RegexBuilder};
 
+#[cfg(not(target_family = "wasm"))]
+use crate::mmap::map_regular_file;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 
 pub static POLARS_TEMP_DIR_BASE_PATH: Lazy<Box<Path>> = Lazy::new(|| {
@@ -47,27 +49,31 @@ pub(crate) fn ensure_directory_init(path: &Path) -> std::io::Result<()> {
 pub fn get_reader_bytes<'a, R: Read + MmapBytesReader + ?Sized>(
     reader: &'a mut R,
 ) -> PolarsResult<ReaderBytes<'a>> {
-    // we have a file so we can mmap
-    if let Some(file) = reader.to_file() {
-        let mmap = unsafe { memmap::Mmap::map(file)? };
-
-        // somehow bck thinks borrows alias
-        // this is sound as file was already bound to 'a
-        use std::fs::File;
-        let file = unsafe { std::mem::transmute::<&File, &'a File>(file) };
-        Ok(ReaderBytes::Mapped(mmap, file))
-    } else {
-        // we can get the bytes for free
-        if reader.to_bytes().is_some() {
-            // duplicate .to_bytes() is necessary to satisfy the borrow checker
-            Ok(ReaderBytes::Borrowed((*reader).to_bytes().unwrap()))
-        } else {
-            // we have to read to an owned buffer to get the bytes.
-            let mut bytes = Vec::with_capacity(1024 * 128);
-            reader.read_to_end(&mut bytes)?;
-            Ok(ReaderBytes::Owned(bytes))
+    // we have a regular file so we can mmap
+    #[cfg(not(target_family = "wasm"))]
+    {
+        let mapped = match reader.to_file() {
+            Some(file) => map_regular_file(file)?.map(|mmap| (mmap, file)),
+            None => None,
+        };
+        if let Some((mmap, file)) = mapped {
+            // somehow bck thinks borrows alias
+            // this is sound as file was already bound to 'a
+            use std::fs::File;
+            let file = unsafe { std::mem::transmute::<&File, &'a File>(file) };
+            return Ok(ReaderBytes::Mapped(mmap, file));
         }
     }
+    // we can get the bytes for free
+    if reader.to_bytes().is_some() {
+        // duplicate .to_bytes() is necessary to satisfy the borrow checker
+        Ok(ReaderBytes::Borrowed((*reader).to_bytes().unwrap()))
+    } else {
+        // we have to read to an owned buffer to get the bytes.
+        let mut bytes = Vec::with_capacity(1024 * 128);
+        reader.read_to_end(&mut bytes)?;
+        Ok(ReaderBytes::Owned(bytes))
+    }
 }
 
 // used by python polars
@@ -177,29 +177,28 @@ fn struct_serializer<'a>(
         .map(|arr| new_serializer(arr, offset, take))
         .collect::<Vec<_>>();
 
-    Box::new(BufStreamingIterator::new(
-        ZipValidity::new_with_validity(0..array.len(), array.validity()),
-        move |maybe, buf| {
-            if maybe.is_some() {
-                let names = array.fields().iter().map(|f| f.name.as_str());
-                serialize_item(
-                    buf,
-                    names.zip(
-                        serializers
-                            .iter_mut()
-                            .map(|serializer| serializer.next().unwrap()),
-                    ),
-                    true,
-                );
-            } else {
-                serializers.iter_mut().for_each(|iter| {
-                    let _ = iter.next();
-                });
-                buf.extend(b"null");
-            }
-        },
-        vec![],
-    ))
+    let f = move |maybe: Option<usize>, buf: &mut Vec<u8>| {
+        if maybe.is_some() {
+            let names = array.fields().iter().map(|f| f.name.as_str());
+            serialize_item(
+                buf,
+                names.zip(
+                    serializers
+                        .iter_mut()
+                        .map(|serializer| serializer.next().unwrap()),
+                ),
+                true,
+            );
+        } else {
+            serializers.iter_mut().for_each(|iter| {
+                let _ = iter.next();
+            });
+            buf.extend(b"null");
+        }
+    };
+
+    let iter = ZipValidity::new_with_validity(0..array.len(), array.validity());
+    materialize_serializer(f, iter, offset, take)
 }
 
 fn list_serializer<'a, O: Offset>(
@@ -213,9 +212,13 @@ fn list_serializer<'a, O: Offset>(
     //  [3]
     // ]
     //
+    // Only serialize the values of the lists in `offset..offset + take`, as the
+    // serializer of the values isn't advanced for the skipped lists.
     let offsets = array.offsets().as_slice();
-    let start = offsets[0].to_usize();
-    let end = offsets.last().unwrap().to_usize();
+    let first = offset.min(array.len());
+    let last = offset.saturating_add(take).min(array.len());
+    let start = offsets[first].to_usize();
+    let end = offsets[last].to_usize();
     let mut serializer = new_serializer(array.values().as_ref(), start, end - start);
 
     let f = move |offset: Option<&[O]>, buf: &mut Vec<u8>| {
@@ -246,29 +249,36 @@ fn fixed_size_list_serializer<'a>(
     offset: usize,
     take: usize,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
-    let mut serializer = new_serializer(array.values().as_ref(), offset, take);
-
-    Box::new(BufStreamingIterator::new(
-        ZipValidity::new(0..array.len(), array.validity().map(|x| x.iter())),
-        move |ix, buf| {
-            if ix.is_some() {
-                let length = array.size();
-                buf.push(b'[');
-                let mut is_first_row = true;
-                for _ in 0..length {
-                    if !is_first_row {
-                        buf.push(b',');
-                    }
-                    is_first_row = false;
-                    buf.extend(serializer.next().unwrap());
+    let size = array.size();
+    let mut serializer = new_serializer(
+        array.values().as_ref(),
+        offset.saturating_mul(size),
+        take.saturating_mul(size),
+    );
+
+    let f = move |ix: Option<usize>, buf: &mut Vec<u8>| {
+        if ix.is_some() {
+            buf.push(b'[');
+            let mut is_first_row = true;
+            for _ in 0..size {
+                if !is_first_row {
+                    buf.push(b',');
                 }
-                buf.push(b']');
-            } else {
-                buf.extend(b"null");
+                is_first_row = false;
+                buf.extend(serializer.next().unwrap());
             }
-        },
-        vec![],
-    ))
+            buf.push(b']');
+        } else {
+            // The values of null lists are still stored.
+            for _ in 0..size {
+                let _ = serializer.next();
+            }
+            buf.extend(b"null");
+        }
+    };
+
+    let iter = ZipValidity::new(0..array.len(), array.validity().map(|x| x.iter()));
+    materialize_serializer(f, iter, offset, take)
 }
 
 fn date_serializer<'a, T, F>(
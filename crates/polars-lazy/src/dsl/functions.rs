@@ -3,6 +3,8 @@
 //! Function on multiple expressions.
 //!
 use polars_core::prelude::*;
+#[cfg(feature = "cse")]
+use polars_mem_engine::create_physical_plan;
 pub use polars_plan::dsl::functions::*;
 use polars_plan::prelude::UnionArgs;
 use rayon::prelude::*;
@@ -83,13 +85,66 @@ pub fn concat<L: AsRef<[LazyFrame]>>(inputs: L, args: UnionArgs) -> PolarsResult
 }
 
 /// Collect all [`LazyFrame`] computations.
+///
+/// The queries are optimized together and run in parallel. Subplans that the optimized queries
+/// have in common, like scans of the same files, are computed once and shared, unless one of the
+/// queries disables common subplan elimination or runs on the streaming engine.
 pub fn collect_all<I>(lfs: I) -> PolarsResult<Vec<DataFrame>>
 where
     I: IntoParallelIterator<Item = LazyFrame>,
 {
-    let iter = lfs.into_par_iter();
+    let lfs: Vec<_> = lfs.into_par_iter().collect();
+
+    #[cfg(feature = "cse")]
+    if lfs.len() > 1
+        && lfs.iter().all(|lf| {
+            lf.opt_state.comm_subplan_elim && !lf.opt_state.streaming && !lf.opt_state.new_streaming
+        })
+    {
+        return collect_all_shared(lfs);
+    }
+
+    polars_core::POOL.install(|| lfs.into_par_iter().map(|lf| lf.collect()).collect())
+}
 
-    polars_core::POOL.install(|| iter.map(|lf| lf.collect()).collect())
+#[cfg(feature = "cse")]
+fn collect_all_shared(lfs: Vec<LazyFrame>) -> PolarsResult<Vec<DataFrame>> {
+    let mut lp_arena = Arena::with_capacity(64);
+    let mut expr_arena = Arena::with_capacity(64);
+    let mut scratch = vec![];
+
+    // The queries share their subplans below, the caches of eliminating them per query would
+    // have conflicting ids.
+    let mut roots = lfs
+        .into_iter()
+        .map(|lf| {
+            lf.with_comm_subplan_elim(false).optimize_with_scratch(
+                &mut lp_arena,
+                &mut expr_arena,
+                &mut scratch,
+                false,
+            )
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    share_common_subplans(&mut roots, &mut lp_arena, &mut expr_arena);
+
+    let plans = roots
+        .into_iter()
+        .map(|root| create_physical_plan(root, &mut lp_arena, &mut expr_arena))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    // Split states share the caches of the common subplans.
+    let state = ExecutionState::new();
+    let plans = plans
+        .into_iter()
+        .map(|plan| (plan, state.split()))
+        .collect::<Vec<_>>();
+    polars_core::POOL.install(|| {
+        plans
+            .into_par_iter()
+            .map(|(mut plan, mut state)| plan.execute(&mut state))
+            .collect()
+    })
 }
 
 #[cfg(test)]
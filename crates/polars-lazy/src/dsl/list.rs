@@ -1,9 +1,8 @@
-use std::sync::Mutex;
-
 use arrow::array::ValueSize;
 use arrow::legacy::utils::CustomIterTools;
-use polars_core::chunked_array::from_iterator_par::ChunkedCollectParIterExt;
 use polars_core::prelude::*;
+use polars_core::utils::_split_offsets;
+use polars_core::POOL;
 use polars_plan::constants::MAP_LIST_NAME;
 use polars_plan::dsl::*;
 use rayon::prelude::*;
@@ -43,6 +42,45 @@ fn offsets_to_groups(offsets: &[i64]) -> Option<GroupsProxy> {
     })
 }
 
+/// Minimal number of lists before `list.eval` evaluates sublists in parallel.
+const PARALLEL_SUBLIST_THRESHOLD: usize = 256;
+
+/// Evaluate `phys_expr` on every sublist of `lst`.
+///
+/// The sublists are visited with an amortized iterator, so the inner `Series`
+/// and the `DataFrame` they are evaluated in are allocated only once.
+fn eval_sublists(
+    lst: &ListChunked,
+    phys_expr: &dyn PhysicalExpr,
+    state: &ExecutionState,
+) -> PolarsResult<ListChunked> {
+    let mut err = None;
+    let mut df_container = DataFrame::empty();
+
+    let ca: ListChunked = lst
+        .amortized_iter()
+        .map(|s| {
+            s.and_then(|s| unsafe {
+                df_container.get_columns_mut().push(s.as_ref().clone());
+                let out = phys_expr.evaluate(&df_container, state);
+                df_container.get_columns_mut().clear();
+                match out {
+                    Ok(s) => Some(s),
+                    Err(e) => {
+                        err = Some(e);
+                        None
+                    },
+                }
+            })
+        })
+        .collect_trusted();
+
+    match err {
+        Some(err) => Err(err),
+        None => Ok(ca),
+    }
+}
+
 fn run_per_sublist(
     s: Series,
     lst: &ListChunked,
@@ -54,50 +92,36 @@ fn run_per_sublist(
 
     let state = ExecutionState::new();
 
-    let mut err = None;
-    let mut ca: ListChunked = if parallel {
-        let m_err = Mutex::new(None);
-        let ca: ListChunked = lst
-            .par_iter()
-            .map(|opt_s| {
-                opt_s.and_then(|s| {
-                    let df = s.into_frame();
-                    let out = phys_expr.evaluate(&df, &state);
-                    match out {
-                        Ok(s) => Some(s),
-                        Err(e) => {
-                            *m_err.lock().unwrap() = Some(e);
-                            None
-                        },
+    let mut ca = if parallel && lst.len() >= PARALLEL_SUBLIST_THRESHOLD {
+        let n_threads = POOL.current_num_threads();
+        let offsets = _split_offsets(lst.len(), n_threads);
+        let parts = POOL.install(|| {
+            offsets
+                .into_par_iter()
+                .map(|(offset, len)| {
+                    let part = lst.slice(offset as i64, len);
+                    let out = eval_sublists(&part, phys_expr.as_ref(), &state)?;
+                    // Parts may have inferred different inner types, e.g. when
+                    // all their outputs were null.
+                    if out.dtype() != output_field.data_type() {
+                        out.cast(output_field.data_type())
+                            .map(|s| s.list().unwrap().clone())
+                    } else {
+                        Ok(out)
                     }
                 })
-            })
-            .collect_ca_with_dtype("", output_field.dtype.clone());
-        err = m_err.into_inner().unwrap();
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let mut parts = parts.into_iter();
+        let mut ca = parts.next().unwrap();
+        for part in parts {
+            ca.append(&part)?;
+        }
         ca
     } else {
-        let mut df_container = DataFrame::empty();
-
-        lst.into_iter()
-            .map(|s| {
-                s.and_then(|s| unsafe {
-                    df_container.get_columns_mut().push(s);
-                    let out = phys_expr.evaluate(&df_container, &state);
-                    df_container.get_columns_mut().clear();
-                    match out {
-                        Ok(s) => Some(s),
-                        Err(e) => {
-                            err = Some(e);
-                            None
-                        },
-                    }
-                })
-            })
-            .collect_trusted()
+        eval_sublists(lst, phys_expr.as_ref(), &state)?
     };
-    if let Some(err) = err {
-        return Err(err);
-    }
 
     ca.rename(s.name());
 
@@ -1,7 +1,16 @@
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::mpsc::{channel, Receiver};
+use std::sync::mpsc::{channel, Receiver, Sender};
 use std::sync::Mutex;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
 
+#[cfg(feature = "async")]
+use futures::channel::oneshot;
+use polars_core::cancel::CancellationToken;
 use polars_core::POOL;
 
 use super::*;
@@ -10,30 +19,86 @@ impl LazyFrame {
     pub fn collect_concurrently(self) -> PolarsResult<InProcessQuery> {
         let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
 
-        let (tx, rx) = channel();
-        let token = state.cancel_token();
+        let (query, tx, finished) = InProcessQuery::new(state.cancel_token());
         POOL.spawn_fifo(move || {
             let result = physical_plan.execute(&mut state);
-            tx.send(result).unwrap();
+            finish(&tx, &finished, result);
         });
+        Ok(query)
+    }
 
-        Ok(InProcessQuery {
-            rx: Arc::new(Mutex::new(rx)),
-            token,
-        })
+    /// Run the query, including its optimization, on the thread pool of Polars and return a
+    /// handle to its result right away.
+    ///
+    /// This lets an application start several independent queries and gather their results as
+    /// they finish, without managing threads itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(reports: Vec<LazyFrame>) -> PolarsResult<Vec<DataFrame>> {
+    ///     let queries: Vec<_> = reports
+    ///         .into_iter()
+    ///         .map(|lf| lf.collect_in_background())
+    ///         .collect();
+    ///     queries.into_iter().map(|q| q.join()).collect()
+    /// }
+    /// ```
+    pub fn collect_in_background(self) -> InProcessQuery {
+        let token = CancellationToken::new();
+        let (query, tx, finished) = InProcessQuery::new(token.clone());
+        POOL.spawn_fifo(move || {
+            let result = self.collect_with_cancel_token(&token);
+            finish(&tx, &finished, result);
+        });
+        query
     }
 }
 
+fn finish(
+    tx: &Sender<PolarsResult<DataFrame>>,
+    finished: &AtomicBool,
+    result: PolarsResult<DataFrame>,
+) {
+    // The receiver is gone if the handle was dropped.
+    let _ = tx.send(result);
+    finished.store(true, Ordering::Release);
+}
+
+/// Handle to a query that runs in the background, see [`LazyFrame::collect_in_background`].
+///
+/// Dropping the handle, or any of its clones, cancels the query.
 #[derive(Clone)]
 pub struct InProcessQuery {
     rx: Arc<Mutex<Receiver<PolarsResult<DataFrame>>>>,
-    token: Arc<AtomicBool>,
+    token: CancellationToken,
+    finished: Arc<AtomicBool>,
 }
 
 impl InProcessQuery {
+    fn new(token: CancellationToken) -> (Self, Sender<PolarsResult<DataFrame>>, Arc<AtomicBool>) {
+        let (tx, rx) = channel();
+        let finished = Arc::new(AtomicBool::new(false));
+        let query = Self {
+            rx: Arc::new(Mutex::new(rx)),
+            token,
+            finished: finished.clone(),
+        };
+        (query, tx, finished)
+    }
+
     /// Cancel the query at earliest convenience.
     pub fn cancel(&self) {
-        self.token.store(true, Ordering::Relaxed)
+        self.token.cancel()
+    }
+
+    /// Whether the query has finished, i.e. whether [`InProcessQuery::fetch`] returns its
+    /// result.
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Acquire)
     }
 
     /// Fetch the result.
@@ -48,12 +113,85 @@ impl InProcessQuery {
     /// Await the result synchronously.
     pub fn fetch_blocking(&self) -> PolarsResult<DataFrame> {
         let rx = self.rx.lock().unwrap();
-        rx.recv().unwrap()
+        let result = rx.recv().map_err(
+            |_| polars_err!(ComputeError: "the result of the query was already fetched"),
+        )?;
+        // The sender may not have marked the query as finished yet.
+        self.finished.store(true, Ordering::Release);
+        result
+    }
+
+    /// Wait for the query to finish and return its result.
+    pub fn join(self) -> PolarsResult<DataFrame> {
+        self.fetch_blocking()
     }
 }
 
 impl Drop for InProcessQuery {
     fn drop(&mut self) {
-        self.token.store(true, Ordering::Relaxed);
+        self.token.cancel();
+    }
+}
+
+#[cfg(feature = "async")]
+impl LazyFrame {
+    /// Execute the query on the thread pool of Polars and return a future that resolves to the
+    /// result.
+    ///
+    /// Awaiting the future doesn't block the async runtime, so async services can run queries
+    /// without wrapping them in `spawn_blocking`. The future is runtime agnostic; scans of
+    /// cloud storage run on the async runtime of Polars. Dropping the future cancels the query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// async fn example(df: DataFrame) -> PolarsResult<DataFrame> {
+    ///     df.lazy()
+    ///       .group_by([col("foo")])
+    ///       .agg([col("bar").sum()])
+    ///       .collect_async()
+    ///       .await
+    /// }
+    /// ```
+    pub fn collect_async(self) -> CollectFuture {
+        let (tx, rx) = oneshot::channel();
+        let token = CancellationToken::new();
+        let query_token = token.clone();
+        POOL.spawn_fifo(move || {
+            // The receiver is gone if the future was dropped.
+            let _ = tx.send(self.collect_with_cancel_token(&query_token));
+        });
+        CollectFuture { rx, token }
+    }
+}
+
+/// The result of [`LazyFrame::collect_async`].
+#[cfg(feature = "async")]
+#[must_use = "the query is cancelled if the future is dropped"]
+pub struct CollectFuture {
+    rx: oneshot::Receiver<PolarsResult<DataFrame>>,
+    token: CancellationToken,
+}
+
+#[cfg(feature = "async")]
+impl Future for CollectFuture {
+    type Output = PolarsResult<DataFrame>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.rx).poll(cx).map(|result| {
+            result.unwrap_or_else(|_| {
+                Err(polars_err!(ComputeError: "the query stopped without producing a result"))
+            })
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+impl Drop for CollectFuture {
+    fn drop(&mut self) {
+        self.token.cancel();
     }
 }
@@ -0,0 +1,113 @@
+use super::*;
+
+/// How [`LazyFrame::match_to_schema`] may change the output to make it match the target schema.
+///
+/// Columns are always reordered to the order of the target schema. Everything else is
+/// disallowed by default.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MatchSchemaPolicy {
+    /// Strictly cast columns whose data type differs from the target schema.
+    pub cast: bool,
+    /// Add columns that are missing from the output as nulls.
+    pub fill_missing: bool,
+    /// Drop columns of the output that are not in the target schema.
+    pub drop_extra: bool,
+}
+
+impl MatchSchemaPolicy {
+    pub fn with_cast(mut self, toggle: bool) -> Self {
+        self.cast = toggle;
+        self
+    }
+
+    pub fn with_fill_missing(mut self, toggle: bool) -> Self {
+        self.fill_missing = toggle;
+        self
+    }
+
+    pub fn with_drop_extra(mut self, toggle: bool) -> Self {
+        self.drop_extra = toggle;
+        self
+    }
+}
+
+impl LazyFrame {
+    /// Check that the output of the query has exactly the `expected` schema, including the
+    /// order of the columns.
+    ///
+    /// The check runs on the plan, so it fails before any data is read.
+    pub fn assert_schema(mut self, expected: &Schema) -> PolarsResult<Self> {
+        let schema = self.schema()?;
+        if schema.as_ref() == expected {
+            return Ok(self);
+        }
+
+        for (name, dtype) in expected.iter() {
+            match schema.get(name) {
+                None => polars_bail!(
+                    SchemaMismatch: "expected column '{}' is missing from the output", name
+                ),
+                Some(got) => polars_ensure!(
+                    got == dtype,
+                    SchemaMismatch: "column '{}' has dtype {}, expected {}", name, got, dtype
+                ),
+            }
+        }
+        if let Some(name) = schema.iter_names().find(|name| !expected.contains(name)) {
+            polars_bail!(SchemaMismatch: "output has unexpected column '{}'", name);
+        }
+        polars_bail!(
+            SchemaMismatch: "columns are in the wrong order: got {:?}, expected {:?}",
+            schema.iter_names().collect::<Vec<_>>(),
+            expected.iter_names().collect::<Vec<_>>()
+        )
+    }
+
+    /// Make the output of the query match `schema`, changing it as far as `policy` allows.
+    ///
+    /// The mismatches are determined on the plan, so this fails before any data is read if
+    /// the output can't be made to match. Casts are strict and fail on collection if a value
+    /// can't be converted.
+    pub fn match_to_schema(
+        mut self,
+        schema: &Schema,
+        policy: MatchSchemaPolicy,
+    ) -> PolarsResult<Self> {
+        let current = self.schema()?;
+        if current.as_ref() == schema {
+            return Ok(self);
+        }
+
+        if !policy.drop_extra {
+            if let Some(name) = current.iter_names().find(|name| !schema.contains(name)) {
+                polars_bail!(
+                    SchemaMismatch: "output has column '{}' that is not in the target schema", name
+                );
+            }
+        }
+
+        let exprs = schema
+            .iter()
+            .map(|(name, dtype)| match current.get(name) {
+                Some(got) if got == dtype => Ok(col(name)),
+                Some(got) => {
+                    polars_ensure!(
+                        policy.cast,
+                        SchemaMismatch: "column '{}' has dtype {}, expected {}", name, got, dtype
+                    );
+                    Ok(col(name).strict_cast(dtype.clone()))
+                },
+                None => {
+                    polars_ensure!(
+                        policy.fill_missing,
+                        ColumnNotFound: "column '{}' of the target schema is missing from the output",
+                        name
+                    );
+                    Ok(lit(Null {}).cast(dtype.clone()).alias(name))
+                },
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(self.select(exprs))
+    }
+}
@@ -6,6 +6,9 @@ mod cached_arenas;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+mod match_schema;
+#[cfg(feature = "partition_by")]
+pub mod partition_by;
 #[cfg(feature = "pivot")]
 pub mod pivot;
 
@@ -21,11 +24,16 @@ use std::sync::{Arc, Mutex};
 pub use anonymous_scan::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
+#[cfg(feature = "delta")]
+pub use delta::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
+#[cfg(feature = "ipc_streaming")]
+pub use ipc_stream::*;
+pub use match_schema::MatchSchemaPolicy;
 #[cfg(feature = "json")]
 pub use ndjson::*;
 #[cfg(feature = "parquet")]
@@ -717,6 +725,9 @@ impl LazyFrame {
         #[cfg(feature = "new_streaming")]
         {
             if self.opt_state.new_streaming {
+                if let Some(token) = CancellationToken::current() {
+                    token.check()?;
+                }
                 let alp_plan = self.to_alp_optimized()?;
                 let lp_top = alp_plan.lp_top;
                 let mut ir_arena = alp_plan.lp_arena;
@@ -733,6 +744,66 @@ impl LazyFrame {
         self._collect_post_opt(|_, _, _| Ok(()))
     }
 
+    /// Execute the query like [`LazyFrame::collect`], but allow it to be aborted from another
+    /// thread by cancelling `token`.
+    ///
+    /// The token is checked before every node of the plan and between the batches of the
+    /// streaming engine. Once it is cancelled, the query stops at the next check and a
+    /// [`PolarsError::Cancelled`] error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> PolarsResult<DataFrame> {
+    ///     let token = CancellationToken::new();
+    ///     let handle = token.clone();
+    ///     // E.g. cancel after a timeout.
+    ///     std::thread::spawn(move || {
+    ///         std::thread::sleep(std::time::Duration::from_secs(10));
+    ///         handle.cancel();
+    ///     });
+    ///     df.lazy().sort(["foo"], Default::default()).collect_with_cancel_token(&token)
+    /// }
+    /// ```
+    pub fn collect_with_cancel_token(self, token: &CancellationToken) -> PolarsResult<DataFrame> {
+        self.collect_in_scope(Some(token), None)
+    }
+
+    /// Execute the query like [`LazyFrame::collect`], but fail with a descriptive error instead
+    /// of running out of memory if its intermediate results exceed `limit` bytes.
+    ///
+    /// The in-memory engine accounts the large intermediate results, i.e. the inputs and output
+    /// of joins, the groups of group bys and the buffers of sorts. The streaming engine instead
+    /// spills these nodes to disk once the memory it holds exceeds the limit. The sizes are
+    /// estimates and the memory of other nodes is not tracked, so the process may still use
+    /// somewhat more than `limit`.
+    pub fn collect_with_memory_limit(self, limit: usize) -> PolarsResult<DataFrame> {
+        self.collect_in_scope(None, Some(MemoryLimit::new(limit)))
+    }
+
+    /// [`LazyFrame::collect`] with `token` and `memory_limit` as the current ones of this
+    /// thread, which the execution state of the query picks up.
+    fn collect_in_scope(
+        self,
+        token: Option<&CancellationToken>,
+        memory_limit: Option<MemoryLimit>,
+    ) -> PolarsResult<DataFrame> {
+        let collect = || match &memory_limit {
+            Some(limit) => limit.scope(|| self.collect()),
+            None => self.collect(),
+        };
+        match token {
+            Some(token) => {
+                token.check()?;
+                token.scope(collect)
+            },
+            None => collect(),
+        }
+    }
+
     /// Profile a LazyFrame.
     ///
     /// This will run the query and return a tuple
@@ -757,6 +828,7 @@ impl LazyFrame {
             SinkType::File {
                 path: Arc::new(path),
                 file_type: FileType::Parquet(options),
+                checkpoint: false,
             },
             "collect().write_parquet()",
         )
@@ -792,6 +864,7 @@ impl LazyFrame {
             SinkType::File {
                 path: Arc::new(path),
                 file_type: FileType::Ipc(options),
+                checkpoint: false,
             },
             "collect().write_ipc()",
         )
@@ -837,6 +910,7 @@ impl LazyFrame {
             SinkType::File {
                 path: Arc::new(path),
                 file_type: FileType::Csv(options),
+                checkpoint: false,
             },
             "collect().write_csv()",
         )
@@ -851,11 +925,33 @@ impl LazyFrame {
             SinkType::File {
                 path: Arc::new(path),
                 file_type: FileType::Json(options),
+                checkpoint: false,
             },
             "collect().write_ndjson()` or `collect().write_json()",
         )
     }
 
+    /// Stream a query result into a directory of parquet, ipc or csv files, of which the progress
+    /// is recorded in a checkpoint manifest. This is useful for long-running queries, as a run
+    /// that is interrupted can be resumed by running the query again with the same `path`.
+    ///
+    /// The result is split in part files that are committed to the manifest once they are
+    /// durably written. When resumed, the parts that were not committed are removed and the rows
+    /// of the committed parts are skipped, so the query must produce its rows in the same order.
+    /// This methods will return an error if the query cannot be completely done in a streaming
+    /// fashion.
+    #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
+    pub fn sink_checkpointed(self, path: PathBuf, file_type: FileType) -> PolarsResult<()> {
+        self.sink(
+            SinkType::File {
+                path: Arc::new(path),
+                file_type,
+                checkpoint: true,
+            },
+            "collect().write_*()",
+        )
+    }
+
     #[cfg(any(
         feature = "ipc",
         feature = "parquet",
@@ -1159,6 +1255,38 @@ impl LazyFrame {
         )
     }
 
+    /// Join this query with another lazy query on predicates between the columns of both
+    /// frames, e.g. to find the interval of `other` that contains a timestamp.
+    ///
+    /// Columns of `other` whose name also occurs in this frame are referred to with the
+    /// `suffix`. Up to two inequalities between a column of either frame are evaluated
+    /// with an inequality join, the remaining predicates filter the joined rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn events_in_windows(events: LazyFrame, windows: LazyFrame) -> LazyFrame {
+    ///         events
+    ///         .join_where(windows, [col("ts").gt_eq(col("start")), col("ts").lt(col("end"))], None)
+    /// }
+    /// ```
+    #[cfg(feature = "iejoin")]
+    pub fn join_where<E: AsRef<[Expr]>>(
+        self,
+        other: LazyFrame,
+        predicates: E,
+        suffix: Option<String>,
+    ) -> LazyFrame {
+        // The predicate pushdown turns the filtered cross join into an inequality join.
+        let joined = self.cross_join(other, suffix);
+        match predicates.as_ref().iter().cloned().reduce(Expr::and) {
+            Some(predicate) => joined.filter(predicate),
+            None => joined,
+        }
+    }
+
     /// Left outer join this query with another lazy query.
     ///
     /// Matches on the values of the expressions `left_on` and `right_on`. For more
@@ -1211,8 +1339,9 @@ impl LazyFrame {
 
     /// Full outer join this query with another lazy query.
     ///
-    /// Matches on the values of the expressions `left_on` and `right_on`. For more
-    /// flexible join logic, see [`join`](LazyFrame::join) or
+    /// Matches on the values of the expressions `left_on` and `right_on`. The key columns
+    /// of both sides are kept; to merge them into a single key column, pass
+    /// [`JoinCoalesce::CoalesceColumns`] to [`join`](LazyFrame::join) or
     /// [`join_builder`](LazyFrame::join_builder).
     ///
     /// # Example
@@ -1302,7 +1431,8 @@ impl LazyFrame {
             .how(args.how)
             .validate(args.validation)
             .coalesce(args.coalesce)
-            .join_nulls(args.join_nulls);
+            .join_nulls(args.join_nulls)
+            .float_total_order(args.float_total_order);
 
         if let Some(suffix) = args.suffix {
             builder = builder.suffix(suffix);
@@ -1810,30 +1940,85 @@ impl LazyGroupBy {
 
     /// Return first n rows of each group
     pub fn head(self, n: Option<usize>) -> LazyFrame {
-        let keys = self
-            .keys
-            .iter()
-            .filter_map(|expr| expr_output_name(expr).ok())
-            .collect::<Vec<_>>();
-
-        self.agg([col("*").exclude(&keys).head(n)])
-            .explode([col("*").exclude(&keys)])
+        let n = n.unwrap_or(10);
+        self.slice(0, n)
     }
 
     /// Return last n rows of each group
     pub fn tail(self, n: Option<usize>) -> LazyFrame {
+        let n = n.unwrap_or(10);
+        self.slice(-(n as i64), n)
+    }
+
+    /// Return the rows `offset..offset + len` of each group, one row per output row.
+    ///
+    /// A negative `offset` counts from the end of the group. Groups that have no rows in the
+    /// slice don't appear in the output.
+    pub fn slice(self, offset: i64, len: usize) -> LazyFrame {
+        // Sliced alongside the values to tell groups with an empty slice, which explode to a
+        // null row, apart from rows of nulls.
+        const IN_SLICE: &str = "__POLARS_GB_IN_SLICE";
+
         let keys = self
             .keys
             .iter()
             .filter_map(|expr| expr_output_name(expr).ok())
             .collect::<Vec<_>>();
+        let Some(key) = self.keys.first().cloned() else {
+            return self
+                .agg([col("*").exclude(&keys).slice(lit(offset), lit(len as u64))])
+                .explode([col("*").exclude(&keys)]);
+        };
 
-        self.agg([col("*").exclude(&keys).tail(n)])
-            .explode([col("*").exclude(&keys)])
+        self.agg([
+            col("*").exclude(&keys).slice(lit(offset), lit(len as u64)),
+            key.is_null()
+                .slice(lit(offset), lit(len as u64))
+                .alias(IN_SLICE),
+        ])
+        .explode([col("*").exclude(&keys)])
+        .filter(col(IN_SLICE).is_not_null())
+        .drop([IN_SLICE])
     }
 
     /// Apply a function over the groups as a new DataFrame.
     ///
+    /// The function gets a group, including its key columns, and may return any number of
+    /// rows. The outputs of the groups are stacked in group order. Every output must have the
+    /// column names of `schema`; its columns are cast to the dtypes of `schema`.
+    ///
+    /// **It is not recommended that you use this as materializing the DataFrame is very
+    /// expensive.**
+    pub fn map_groups<F>(self, f: F, schema: SchemaRef) -> LazyFrame
+    where
+        F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
+    {
+        let output_schema = schema.clone();
+        let f = move |df: DataFrame| {
+            let out = f(df)?;
+            polars_ensure!(
+                out.get_column_names()
+                    .into_iter()
+                    .eq(output_schema.iter_names().map(|name| name.as_str())),
+                SchemaMismatch: "the output of `map_groups` has columns {:?}, but its schema has {:?}",
+                out.get_column_names(), output_schema.iter_names().collect::<Vec<_>>()
+            );
+            let columns = out
+                .get_columns()
+                .iter()
+                .zip(output_schema.iter_dtypes())
+                .map(|(s, dtype)| s.strict_cast(dtype))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            Ok(unsafe { DataFrame::new_no_checks(columns) })
+        };
+        self.apply(f, schema)
+    }
+
+    /// Apply a function over the groups as a new DataFrame.
+    ///
+    /// Unlike [`map_groups`](LazyGroupBy::map_groups), the outputs are not checked against
+    /// `schema`.
+    ///
     /// **It is not recommended that you use this as materializing the DataFrame is very
     /// expensive.**
     pub fn apply<F>(self, f: F, schema: SchemaRef) -> LazyFrame
@@ -1875,6 +2060,7 @@ pub struct JoinBuilder {
     validation: JoinValidation,
     coalesce: JoinCoalesce,
     join_nulls: bool,
+    float_total_order: bool,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -1888,6 +2074,7 @@ impl JoinBuilder {
             allow_parallel: true,
             force_parallel: false,
             join_nulls: false,
+            float_total_order: false,
             suffix: None,
             validation: Default::default(),
             coalesce: Default::default(),
@@ -1955,6 +2142,12 @@ impl JoinBuilder {
         self
     }
 
+    /// Match float keys by IEEE 754 totalOrder, so -0 and 0 don't match.
+    pub fn float_total_order(mut self, float_total_order: bool) -> Self {
+        self.float_total_order = float_total_order;
+        self
+    }
+
     /// Suffix to add duplicate column names in join.
     /// Defaults to `"_right"` if this method is never called.
     pub fn suffix<S: AsRef<str>>(mut self, suffix: S) -> Self {
@@ -1983,6 +2176,7 @@ impl JoinBuilder {
             slice: None,
             join_nulls: self.join_nulls,
             coalesce: self.coalesce,
+            float_total_order: self.float_total_order,
         };
 
         let lp = self
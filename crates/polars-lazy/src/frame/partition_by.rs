@@ -0,0 +1,49 @@
+//! Partition a materialized `DataFrame` into a `LazyFrame` per group.
+//!
+//! The partitions share the columns of the `DataFrame` and filter on their key values, so a
+//! partition is only gathered when it's collected and not all partitions have to be kept in
+//! memory at once. See [`DataFrame::partition_by_iter`] for an eager iterator over the groups.
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// Split into a `LazyFrame` per group of the `cols` columns. If `stable` is set, the partitions
+/// are in the order in which their keys first occur.
+pub fn partition_by_lazy(
+    df: &DataFrame,
+    cols: impl IntoVec<String>,
+    stable: bool,
+    include_key: bool,
+) -> PolarsResult<Vec<LazyFrame>> {
+    let cols = cols.into_vec();
+    polars_ensure!(
+        !cols.is_empty(),
+        ComputeError: "at least one key is required in a partition_by operation"
+    );
+    let keys = df.select(&cols)?;
+    let keys = if stable {
+        keys.unique_stable(None, UniqueKeepStrategy::First, None)?
+    } else {
+        keys.unique(None, UniqueKeepStrategy::First, None)?
+    };
+
+    (0..keys.height())
+        .map(|idx| {
+            let mut predicate: Option<Expr> = None;
+            for s in keys.get_columns() {
+                let key = col(s.name());
+                let matches = match s.get(idx)? {
+                    AnyValue::Null => key.is_null(),
+                    av => key.eq(Expr::Literal(LiteralValue::try_from(av)?)),
+                };
+                predicate = Some(match predicate {
+                    None => matches,
+                    Some(predicate) => predicate.and(matches),
+                });
+            }
+            let lf = df.clone().lazy().filter(predicate.unwrap());
+            Ok(if include_key { lf } else { lf.drop(&cols) })
+        })
+        .collect()
+}
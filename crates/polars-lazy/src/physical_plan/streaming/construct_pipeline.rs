@@ -247,6 +247,8 @@ fn get_pipeline_node(
     IR::MapFunction {
         function: FunctionNode::Pipeline {
             function: Arc::new(move |_df: DataFrame| {
+                // Picks up the cancellation token and the memory limit of the query from the
+                // scope.
                 let mut state = ExecutionState::new();
                 if state.verbose() {
                     eprintln!("RUN STREAMING PIPELINE");
@@ -234,7 +234,11 @@ pub(crate) fn insert_streaming_nodes(
                     )
                 }
             },
-            Scan { scan_type, .. } if scan_type.streamable() => {
+            Scan {
+                scan_type,
+                file_options,
+                ..
+            } if scan_type.streamable() && file_options.include_file_paths.is_none() => {
                 if state.streamable {
                     state.sources.push(root);
                     pipeline_trees[current_idx].push(state)
@@ -330,7 +334,6 @@ pub(crate) fn insert_streaming_nodes(
                 input,
                 keys,
                 aggs,
-                maintain_order: false,
                 apply: None,
                 schema: output_schema,
                 options,
@@ -1,3 +1,4 @@
+pub use polars_core::cancel::CancellationToken;
 pub(crate) use polars_expr::prelude::*;
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
@@ -10,11 +11,15 @@ pub use polars_io::parquet::write::ParquetWriteOptions;
 pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
+#[cfg(feature = "streaming")]
+pub use polars_pipe::pipeline::{
+    set_streaming_metrics_subscriber, StreamingEvent, StreamingMetricsSubscriber,
+};
 pub use polars_plan::plans::{
     AnonymousScan, AnonymousScanArgs, AnonymousScanOptions, DslPlan, Literal, LiteralValue, Null,
     NULL,
 };
-pub use polars_plan::prelude::UnionArgs;
+pub use polars_plan::prelude::{FileType, UnionArgs};
 pub(crate) use polars_plan::prelude::*;
 #[cfg(feature = "rolling_window_by")]
 pub use polars_time::Duration;
@@ -4,6 +4,7 @@ use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::csv::read::{
     infer_file_schema, CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues,
+    RaggedLines,
 };
 use polars_io::utils::get_reader_bytes;
 use polars_io::RowIndex;
@@ -18,6 +19,7 @@ pub struct LazyCsvReader {
     cache: bool,
     read_options: CsvReadOptions,
     cloud_options: Option<CloudOptions>,
+    include_file_paths: Option<Arc<str>>,
 }
 
 #[cfg(feature = "csv")]
@@ -39,6 +41,7 @@ impl LazyCsvReader {
             cache: true,
             read_options: Default::default(),
             cloud_options: Default::default(),
+            include_file_paths: None,
         }
     }
 
@@ -166,6 +169,14 @@ impl LazyCsvReader {
         self
     }
 
+    /// Let string values refer to the file instead of copying them, see
+    /// [`CsvReadOptions::with_zero_copy_strings`].
+    #[must_use]
+    pub fn with_zero_copy_strings(mut self, zero_copy_strings: bool) -> Self {
+        self.read_options.zero_copy_strings = zero_copy_strings;
+        self
+    }
+
     /// Set  [`CsvEncoding`]
     #[must_use]
     pub fn with_encoding(self, encoding: CsvEncoding) -> Self {
@@ -179,6 +190,12 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_try_parse_dates(try_parse_dates))
     }
 
+    /// Formats to try, in order of priority, when parsing dates/datetimes.
+    #[cfg(feature = "temporal")]
+    pub fn with_datetime_formats(self, datetime_formats: Option<Arc<[String]>>) -> Self {
+        self.map_parse_options(|opts| opts.with_datetime_formats(datetime_formats.clone()))
+    }
+
     /// Raise an error if CSV is empty (otherwise return an empty frame)
     #[must_use]
     pub fn with_raise_if_empty(mut self, raise_if_empty: bool) -> Self {
@@ -192,6 +209,12 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_truncate_ragged_lines(truncate_ragged_lines))
     }
 
+    /// Set how lines with more or fewer fields than the schema are handled.
+    #[must_use]
+    pub fn with_ragged_lines(self, ragged_lines: RaggedLines) -> Self {
+        self.map_parse_options(|opts| opts.with_ragged_lines(ragged_lines))
+    }
+
     #[must_use]
     pub fn with_decimal_comma(self, decimal_comma: bool) -> Self {
         self.map_parse_options(|opts| opts.with_decimal_comma(decimal_comma))
@@ -209,6 +232,13 @@ impl LazyCsvReader {
         self
     }
 
+    /// Add a column with this name holding the path of the file each row was read from.
+    #[must_use]
+    pub fn with_include_file_paths(mut self, include_file_paths: Option<Arc<str>>) -> Self {
+        self.include_file_paths = include_file_paths;
+        self
+    }
+
     /// Modify a schema before we run the lazy scanning.
     ///
     /// Important! Run this function latest in the builder!
@@ -241,6 +271,7 @@ impl LazyCsvReader {
             parse_options.eol_char,
             None,
             parse_options.try_parse_dates,
+            parse_options.datetime_formats.as_deref(),
             self.read_options.raise_if_empty,
             &mut self.read_options.n_threads,
             parse_options.decimal_comma,
@@ -264,10 +295,15 @@ impl LazyFileListReader for LazyCsvReader {
         // `expand_paths` respects globs
         let paths = self.expand_paths(false)?.0;
 
-        let mut lf: LazyFrame =
-            DslBuilder::scan_csv(paths, self.read_options, self.cache, self.cloud_options)?
-                .build()
-                .into();
+        let mut lf: LazyFrame = DslBuilder::scan_csv(
+            paths,
+            self.read_options,
+            self.cache,
+            self.cloud_options,
+            self.include_file_paths,
+        )?
+        .build()
+        .into();
         lf.opt_state.file_caching = true;
         Ok(lf)
     }
@@ -0,0 +1,131 @@
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_io::delta::{DeltaTable, DeltaVersion};
+use polars_io::parquet::read::ParallelStrategy;
+use polars_io::{HiveOptions, RowIndex};
+use polars_plan::plans::hive::HivePartitions;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsDelta {
+    /// The snapshot of the table to read.
+    pub version: DeltaVersion,
+    pub n_rows: Option<usize>,
+    pub parallel: ParallelStrategy,
+    pub row_index: Option<RowIndex>,
+    pub use_statistics: bool,
+    pub low_memory: bool,
+    pub rechunk: bool,
+    pub cache: bool,
+}
+
+impl Default for ScanArgsDelta {
+    fn default() -> Self {
+        Self {
+            version: DeltaVersion::Latest,
+            n_rows: None,
+            parallel: Default::default(),
+            row_index: None,
+            use_statistics: true,
+            low_memory: false,
+            rechunk: false,
+            cache: true,
+        }
+    }
+}
+
+/// Convert a partition value, as serialized in the Delta log, to a single value Series.
+fn partition_value_to_series(
+    name: &str,
+    value: Option<&str>,
+    dtype: &DataType,
+) -> PolarsResult<Series> {
+    match dtype {
+        DataType::Boolean => Ok(Series::new(name, [value.map(|v| v == "true")])),
+        _ => Series::new(name, [value]).cast(dtype),
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a snapshot of the Delta Lake table at `path`.
+    ///
+    /// The data files of the snapshot are resolved from the transaction log of the table
+    /// and read with a parquet scan. The partition values of the files are used to skip
+    /// files that can't match the predicates of the query.
+    pub fn scan_delta(path: impl AsRef<Path>, args: ScanArgsDelta) -> PolarsResult<Self> {
+        let table = DeltaTable::load(path, args.version)?;
+        if table.files().is_empty() {
+            let mut lf = DataFrame::from(table.schema()).lazy();
+            if let Some(row_index) = args.row_index {
+                lf = lf.with_row_index(&row_index.name, Some(row_index.offset))
+            }
+            return Ok(lf);
+        }
+
+        let partition_schema = Arc::new(table.partition_schema()?);
+        let hive_parts = if partition_schema.is_empty() {
+            None
+        } else {
+            let hive_parts = table
+                .files()
+                .iter()
+                .map(|file| {
+                    let values = partition_schema
+                        .iter()
+                        .zip(&file.partition_values)
+                        .map(|((name, dtype), value)| {
+                            partition_value_to_series(name, value.as_deref(), dtype)
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    Ok(Arc::new(HivePartitions::from_partition_values(
+                        partition_schema.clone(),
+                        values,
+                    )))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            Some(hive_parts)
+        };
+        let hive_options = HiveOptions {
+            enabled: Some(hive_parts.is_some()),
+            hive_start_idx: 0,
+            schema: Some(partition_schema),
+        };
+
+        let paths = table
+            .files()
+            .iter()
+            .map(|file| file.path.clone())
+            .collect::<Arc<[PathBuf]>>();
+        let mut plan = DslBuilder::scan_parquet(
+            paths,
+            args.n_rows,
+            args.cache,
+            args.parallel,
+            None,
+            args.rechunk,
+            args.low_memory,
+            None,
+            args.use_statistics,
+            hive_options,
+            None,
+        )?
+        .build();
+        // The partitions are given by the log, not by the paths of the files.
+        if let DslPlan::Scan {
+            hive_parts: scan_hive_parts,
+            ..
+        } = &mut plan
+        {
+            *scan_hive_parts = hive_parts;
+        }
+
+        let mut lf: LazyFrame = plan.into();
+        if let Some(row_index) = args.row_index {
+            lf = lf.with_row_index(&row_index.name, Some(row_index.offset))
+        }
+        lf.opt_state.file_caching = true;
+        Ok(lf)
+    }
+}
@@ -0,0 +1,97 @@
+use std::any::Any;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+use polars_io::ipc::IpcStreamReader;
+use polars_io::{RowIndex, SerReader};
+
+use crate::prelude::*;
+
+#[derive(Clone, Default)]
+pub struct ScanArgsIpcStream {
+    pub n_rows: Option<usize>,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+}
+
+/// Scans a file in the Arrow IPC streaming format.
+///
+/// The stream is opened once to read the schema when the scan is created and the opened
+/// reader is reused for the scan, so that non-seekable sources like named pipes can be
+/// scanned as well.
+struct IpcStreamScan {
+    path: PathBuf,
+    rechunk: bool,
+    schema: SchemaRef,
+    reader: Mutex<Option<IpcStreamReader<BufReader<File>>>>,
+}
+
+impl IpcStreamScan {
+    fn new(path: PathBuf, rechunk: bool) -> PolarsResult<Self> {
+        let mut reader = open(&path)?;
+        let schema = Arc::new(reader.schema()?);
+        Ok(Self {
+            path,
+            rechunk,
+            schema,
+            reader: Mutex::new(Some(reader)),
+        })
+    }
+}
+
+fn open(path: &Path) -> PolarsResult<IpcStreamReader<BufReader<File>>> {
+    let file = polars_utils::open_file(path)?;
+    Ok(IpcStreamReader::new(BufReader::new(file)))
+}
+
+impl AnonymousScan for IpcStreamScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let reader = match self.reader.lock().unwrap().take() {
+            Some(reader) => reader,
+            None => open(&self.path)?,
+        };
+        reader
+            .with_columns(scan_opts.with_columns.map(|columns| columns.to_vec()))
+            .with_n_rows(scan_opts.n_rows)
+            .set_rechunk(self.rechunk)
+            .finish()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a file in the Arrow IPC streaming format.
+    ///
+    /// The path may also point to a named pipe that another process writes an Arrow
+    /// stream to. As such a stream can only be consumed once, the resulting LazyFrame
+    /// can only be collected once as well.
+    pub fn scan_ipc_stream(path: impl AsRef<Path>, args: ScanArgsIpcStream) -> PolarsResult<Self> {
+        let function = IpcStreamScan::new(path.as_ref().to_path_buf(), args.rechunk)?;
+        let args = ScanArgsAnonymous {
+            schema: Some(function.schema.clone()),
+            n_rows: args.n_rows,
+            row_index: args.row_index,
+            name: "IPC STREAM SCAN",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(Arc::new(function), args)
+    }
+}
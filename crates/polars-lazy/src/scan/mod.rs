@@ -1,9 +1,13 @@
 pub(super) mod anonymous_scan;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
+#[cfg(feature = "delta")]
+pub(super) mod delta;
 pub(super) mod file_list_reader;
 #[cfg(feature = "ipc")]
 pub(super) mod ipc;
+#[cfg(feature = "ipc_streaming")]
+pub(super) mod ipc_stream;
 #[cfg(feature = "json")]
 pub(super) mod ndjson;
 #[cfg(feature = "parquet")]
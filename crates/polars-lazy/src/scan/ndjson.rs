@@ -2,6 +2,7 @@ use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
 use polars_io::RowIndex;
 use polars_plan::plans::{DslPlan, FileScan};
 use polars_plan::prelude::{FileScanOptions, NDJsonReadOptions};
@@ -20,6 +21,8 @@ pub struct LazyJsonLineReader {
     pub(crate) infer_schema_length: Option<NonZeroUsize>,
     pub(crate) n_rows: Option<usize>,
     pub(crate) ignore_errors: bool,
+    pub(crate) cloud_options: Option<CloudOptions>,
+    pub(crate) include_file_paths: Option<Arc<str>>,
 }
 
 impl LazyJsonLineReader {
@@ -38,6 +41,8 @@ impl LazyJsonLineReader {
             infer_schema_length: NonZeroUsize::new(100),
             ignore_errors: false,
             n_rows: None,
+            cloud_options: None,
+            include_file_paths: None,
         }
     }
     /// Add a row index column.
@@ -83,6 +88,19 @@ impl LazyJsonLineReader {
         self
     }
 
+    #[must_use]
+    pub fn with_cloud_options(mut self, cloud_options: Option<CloudOptions>) -> Self {
+        self.cloud_options = cloud_options;
+        self
+    }
+
+    /// Add a column with this name holding the path of the file each row was read from.
+    #[must_use]
+    pub fn with_include_file_paths(mut self, include_file_paths: Option<Arc<str>>) -> Self {
+        self.include_file_paths = include_file_paths;
+        self
+    }
+
     #[must_use]
     pub fn with_batch_size(mut self, batch_size: Option<NonZeroUsize>) -> Self {
         self.batch_size = batch_size;
@@ -106,6 +124,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             rechunk: self.rechunk,
             file_counter: 0,
             hive_options: Default::default(),
+            include_file_paths: self.include_file_paths,
         };
 
         let options = NDJsonReadOptions {
@@ -117,7 +136,10 @@ impl LazyFileListReader for LazyJsonLineReader {
             schema: self.schema,
         };
 
-        let scan_type = FileScan::NDJson { options };
+        let scan_type = FileScan::NDJson {
+            options,
+            cloud_options: self.cloud_options,
+        };
 
         Ok(LazyFrame::from(DslPlan::Scan {
             paths,
@@ -138,6 +160,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             rechunk: self.rechunk,
             file_counter: 0,
             hive_options: Default::default(),
+            include_file_paths: self.include_file_paths,
         };
 
         let options = NDJsonReadOptions {
@@ -149,7 +172,10 @@ impl LazyFileListReader for LazyJsonLineReader {
             schema: self.schema,
         };
 
-        let scan_type = FileScan::NDJson { options };
+        let scan_type = FileScan::NDJson {
+            options,
+            cloud_options: self.cloud_options,
+        };
 
         Ok(LazyFrame::from(DslPlan::Scan {
             paths: self.paths,
@@ -201,4 +227,9 @@ impl LazyFileListReader for LazyJsonLineReader {
     fn row_index(&self) -> Option<&RowIndex> {
         self.row_index.as_ref()
     }
+
+    /// [CloudOptions] used to list files.
+    fn cloud_options(&self) -> Option<&CloudOptions> {
+        self.cloud_options.as_ref()
+    }
 }
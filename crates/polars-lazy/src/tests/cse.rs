@@ -353,3 +353,38 @@ fn test_cse_prune_scan_filter_difference() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_collect_all_shares_subplans() -> PolarsResult<()> {
+    let base = fruits_cars()
+        .lazy()
+        .with_column((col("A") * lit(2)).alias("C"));
+    let queries = vec![
+        base.clone().sort(["C"], Default::default()),
+        base.clone().sort(["B"], Default::default()),
+        base.unique_stable(None, UniqueKeepStrategy::First),
+    ];
+
+    // The scan and `with_column` are computed once for all queries.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let mut roots = queries
+        .iter()
+        .map(|q| {
+            q.clone()
+                .with_comm_subplan_elim(false)
+                .optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut vec![], false)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert!(share_common_subplans(
+        &mut roots,
+        &mut lp_arena,
+        &mut expr_arena
+    ));
+
+    let expected = queries
+        .iter()
+        .map(|q| q.clone().collect())
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(collect_all(queries)?, expected);
+    Ok(())
+}
@@ -419,6 +419,7 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             row_index: None,
             memory_map: true,
             cloud_options: None,
+            include_file_paths: None,
         },
     )?
     .collect()?;
@@ -430,6 +431,81 @@ fn test_ipc_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "ipc", feature = "json"))]
+fn test_scan_include_file_paths() -> PolarsResult<()> {
+    // for side effects
+    init_files();
+    let name: Option<Arc<str>> = Some(Arc::from("file_path"));
+    let scans = [
+        LazyCsvReader::new("../../examples/datasets/foods[12].csv")
+            .with_include_file_paths(name.clone())
+            .finish()?,
+        LazyFrame::scan_parquet(
+            "../../examples/datasets/foods*.parquet",
+            ScanArgsParquet {
+                include_file_paths: name.clone(),
+                ..Default::default()
+            },
+        )?,
+        LazyFrame::scan_ipc(
+            "../../examples/datasets/foods*.ipc",
+            ScanArgsIpc {
+                include_file_paths: name.clone(),
+                ..Default::default()
+            },
+        )?,
+        LazyJsonLineReader::new("../../examples/datasets/foods*.ndjson")
+            .with_include_file_paths(name.clone())
+            .finish()?,
+    ];
+
+    for lf in scans {
+        let df = lf.clone().collect()?;
+        assert_eq!(df.shape(), (54, 5));
+        assert_eq!(df.get_column_names()[4], "file_path");
+        let paths = df.column("file_path")?.str()?;
+        let first = paths.get(0).unwrap();
+        let second = paths.get(53).unwrap();
+        assert!(first.contains("foods1."));
+        assert!(second.contains("foods2."));
+        assert_eq!(paths.n_unique()?, 2);
+
+        let out = lf
+            .clone()
+            .filter(col("file_path").eq(lit(second)))
+            .collect()?;
+        assert_eq!(out.height(), 27);
+        assert!(out.equals(&df.slice(27, 27)));
+
+        let out = lf
+            .clone()
+            .select([col("calories"), col("file_path")])
+            .collect()?;
+        assert!(out.equals(&df.select(["calories", "file_path"])?));
+
+        let out = lf.clone().select([col("file_path")]).collect()?;
+        assert!(out.equals(&df.select(["file_path"])?));
+
+        let out = lf.clone().select([col("calories")]).collect()?;
+        assert!(out.equals(&df.select(["calories"])?));
+
+        let out = lf.clone().slice(0, 30).collect()?;
+        assert!(out.equals(&df.slice(0, 30)));
+
+        let out = lf.clone().with_streaming(true).collect()?;
+        assert!(out.equals(&df));
+    }
+
+    let err = LazyCsvReader::new(FOODS_CSV)
+        .with_include_file_paths(Some(Arc::from("calories")))
+        .finish()?
+        .collect();
+    assert!(matches!(err, Err(PolarsError::Duplicate(_))));
+
+    Ok(())
+}
+
 fn slice_at_union(lp_arena: &Arena<IR>, lp: Node) -> bool {
     (&lp_arena).iter(lp).all(|(_, lp)| {
         if let IR::Union { options, .. } = lp {
@@ -489,6 +565,70 @@ fn test_ndjson_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "json")]
+fn test_ndjson_projection_and_predicate_pushdown() -> PolarsResult<()> {
+    // for side effects
+    init_files();
+    let row_index = Some(RowIndex {
+        name: Arc::from("idx"),
+        offset: 0,
+    });
+    let q = |lf: LazyFrame| {
+        lf.filter(col("calories").gt(lit(100)))
+            .select([col("idx"), col("category")])
+    };
+
+    let lf = q(
+        LazyJsonLineReader::new("../../examples/datasets/foods1.ndjson")
+            .with_row_index(row_index.clone())
+            .finish()?,
+    );
+    let IRPlan {
+        lp_top, lp_arena, ..
+    } = lf.clone().to_alp_optimized()?;
+    assert!((&lp_arena).iter(lp_top).any(|(_, lp)| matches!(
+        lp,
+        IR::Scan {
+            predicate: Some(_),
+            file_options,
+            ..
+        } if file_options.with_columns.as_deref().is_some_and(|cols| cols.len() == 2)
+    )));
+
+    let expected = q(LazyCsvReader::new(FOODS_CSV)
+        .with_row_index(row_index)
+        .finish()?)
+    .collect()?;
+    let out = lf.collect()?;
+    assert_eq!(out.shape(), (11, 2));
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "json", feature = "cloud"))]
+fn test_ndjson_object_store() -> PolarsResult<()> {
+    let path = std::fs::canonicalize("../../examples/datasets/foods1.ndjson")?;
+    let uri = format!("file://{}", path.display());
+
+    let expected = LazyJsonLineReader::new(&path).finish()?.collect()?;
+    let out = LazyJsonLineReader::new(&uri).finish()?.collect()?;
+    assert!(out.equals(&expected));
+
+    let count = LazyJsonLineReader::new(&uri)
+        .finish()?
+        .select([len()])
+        .collect()?;
+    assert_eq!(
+        count.column("len")?.idx()?.get(0),
+        Some(expected.height() as IdxSize)
+    );
+
+    Ok(())
+}
+
 #[test]
 pub fn test_simple_slice() -> PolarsResult<()> {
     let _guard = SINGLE_LOCK.lock().unwrap();
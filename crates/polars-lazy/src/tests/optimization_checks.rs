@@ -548,6 +548,51 @@ fn test_flatten_unions() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "iejoin")]
+fn test_join_where_to_iejoin() -> PolarsResult<()> {
+    let events = df![
+        "ts" => [1, 4, 7, 10],
+        "id" => [1, 2, 3, 4],
+    ]?
+    .lazy();
+    let windows = df![
+        "start" => [0, 3, 5],
+        "end" => [2, 6, 9],
+        "id" => [10, 20, 30],
+    ]?
+    .lazy();
+
+    let q = events.join_where(
+        windows,
+        [
+            col("ts").gt_eq(col("start")),
+            col("end").gt(col("ts")),
+            col("id_right").neq(lit(20)),
+        ],
+        None,
+    );
+
+    // The inequalities are evaluated by the join instead of filtering a cross join.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(root).any(|(_, lp)| matches!(
+        lp,
+        IR::Join { options, .. } if matches!(options.args.how, JoinType::IEJoin(_))
+    )));
+
+    let out = q.collect()?;
+    let expected = df![
+        "ts" => [1, 7],
+        "id" => [1, 3],
+        "start" => [0, 5],
+        "end" => [2, 9],
+        "id_right" => [10, 30],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
 fn num_occurrences(s: &str, needle: &str) -> usize {
     let mut i = 0;
     let mut num = 0;
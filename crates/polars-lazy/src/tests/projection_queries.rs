@@ -170,3 +170,46 @@ fn test_coalesce_toggle_projection_pushdown() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_unnest_projection_pushdown() -> PolarsResult<()> {
+    let q = df![
+        "id" => [1, 2],
+        "a" => [1, 2],
+        "b" => ["x", "y"],
+        "c" => [1.0, 2.0],
+    ]?
+    .lazy()
+    .select([
+        col("id"),
+        as_struct(vec![col("a"), col("b"), col("c")]).alias("s"),
+    ])
+    .unnest(["s"])
+    .select([col("c"), col("a")]);
+
+    // Only the selected fields of the struct are unnested.
+    let plan = q.clone().to_alp_optimized()?;
+    let lp_arena = &plan.lp_arena;
+    let expected = DataType::Struct(vec![
+        Field::new("a", DataType::Int32),
+        Field::new("c", DataType::Float64),
+    ]);
+    assert!(lp_arena.iter(plan.lp_top).any(|(_, lp)| match lp {
+        IR::MapFunction {
+            input,
+            function: FunctionNode::Unnest { .. },
+        } => {
+            let schema = lp_arena.get(*input).schema(lp_arena);
+            schema.get("s") == Some(&expected) && !schema.contains("id")
+        },
+        _ => false,
+    }));
+
+    let out = q.collect()?;
+    assert!(out.equals(&df![
+        "c" => [1.0, 2.0],
+        "a" => [1, 2],
+    ]?));
+    Ok(())
+}
@@ -997,6 +997,65 @@ fn test_group_by_sort_slice() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_group_by_slice_and_map_groups() -> PolarsResult<()> {
+    let df = df![
+        "groups" => [1, 2, 2, 3, 3, 3],
+        "vals" => [Some(1), None, Some(5), Some(3), Some(9), Some(8)]
+    ]?;
+
+    // Groups without rows in the slice are dropped, rows of nulls are kept.
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("groups")])
+        .slice(1, 5)
+        .collect()?;
+    let expected = df![
+        "groups" => [2, 3, 3],
+        "vals" => [5, 9, 8]
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("groups")])
+        .head(Some(1))
+        .collect()?;
+    let expected = df![
+        "groups" => [1, 2, 3],
+        "vals" => [Some(1), None, Some(3)]
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    // The groups may map to any number of rows.
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("groups", DataType::Int32),
+        Field::new("vals", DataType::Int64),
+    ]));
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("groups")])
+        .map_groups(|df| Ok(df.tail(Some(2))), schema.clone())
+        .collect()?;
+    let expected = df![
+        "groups" => [1, 2, 2, 3, 3],
+        "vals" => [Some(1i64), None, Some(5), Some(9), Some(8)]
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    // Outputs that don't match the schema are an error.
+    let out = df
+        .lazy()
+        .group_by_stable([col("groups")])
+        .map_groups(|df| df.select(["vals"]), schema)
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "cum_agg")]
 fn test_group_by_cum_sum() -> PolarsResult<()> {
@@ -1394,6 +1453,113 @@ fn test_error_duplicate_names() {
     assert!(df.lazy().select([col("*"), col("*"),]).collect().is_err());
 }
 
+#[test]
+fn test_error_expression_context() {
+    let df = fruits_cars();
+    let err = df
+        .lazy()
+        .select([col("fruits").strict_cast(DataType::Int64).alias("out")])
+        .collect()
+        .unwrap_err();
+
+    assert_eq!(err.code(), ErrorCode::InvalidOperation);
+    assert_eq!(err.column(), Some("out"));
+    assert!(err.expr().unwrap().contains("fruits"));
+    assert!(std::error::Error::source(&err).is_some());
+    assert!(err
+        .to_string()
+        .contains("Error originated in expression: '"));
+}
+
+#[test]
+fn test_collect_with_cancel_token() -> PolarsResult<()> {
+    let df = fruits_cars();
+
+    let token = CancellationToken::new();
+    token.cancel();
+    let err = df
+        .clone()
+        .lazy()
+        .collect_with_cancel_token(&token)
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::Cancelled);
+
+    // Cancel while the first input of a sequential union runs; the second input must not run.
+    let token = CancellationToken::new();
+    let handle = token.clone();
+    let first = df.clone().lazy().map(
+        move |df| {
+            handle.cancel();
+            Ok(df)
+        },
+        AllowedOptimizations::default(),
+        None,
+        None,
+    );
+    let second = df.lazy().sort(["A"], Default::default());
+    let args = UnionArgs {
+        parallel: false,
+        ..Default::default()
+    };
+    let err = concat([first, second], args)?
+        .collect_with_cancel_token(&token)
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::Cancelled);
+    Ok(())
+}
+
+#[test]
+fn test_collect_with_memory_limit() -> PolarsResult<()> {
+    let df = fruits_cars();
+    let q = df
+        .clone()
+        .lazy()
+        .join(
+            df.lazy(),
+            [col("fruits")],
+            [col("fruits")],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .sort(["A"], Default::default());
+
+    let err = q.clone().collect_with_memory_limit(64).unwrap_err();
+    assert!(err.to_string().contains("memory limit"));
+
+    let out = q.clone().collect_with_memory_limit(1 << 30)?;
+    assert_eq!(out, q.collect()?);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn test_collect_async() -> PolarsResult<()> {
+    let q = fruits_cars()
+        .lazy()
+        .group_by_stable([col("fruits")])
+        .agg([col("A").sum()]);
+    let out = futures::executor::block_on(q.clone().collect_async())?;
+    assert_eq!(out, q.collect()?);
+    Ok(())
+}
+
+#[test]
+fn test_collect_in_background() -> PolarsResult<()> {
+    let q = fruits_cars().lazy().sort(["B"], Default::default());
+    let expected = q.clone().collect()?;
+
+    let queries: Vec<_> = (0..3).map(|_| q.clone().collect_in_background()).collect();
+    for query in queries {
+        assert_eq!(query.join()?, expected);
+    }
+
+    let query = q.collect_in_background();
+    let out = query.fetch_blocking()?;
+    assert!(query.is_finished());
+    assert_eq!(out, expected);
+    assert!(query.fetch().is_none());
+    Ok(())
+}
+
 #[test]
 fn test_filter_count() -> PolarsResult<()> {
     let df = fruits_cars();
@@ -1703,6 +1869,45 @@ fn empty_df() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "diff", feature = "pct_change"))]
+fn test_diff_expr_periods() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b"],
+        "v" => [1, 2, 4, 8, 16],
+        "n" => [Some(1), Some(2), None, Some(1), Some(3)],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("v")
+                .diff(col("n"), NullBehavior::Ignore)
+                .alias("per_row"),
+            col("v")
+                .diff(1, NullBehavior::Ignore)
+                .over([col("g")])
+                .alias("grouped"),
+        ])
+        .collect()?;
+    assert!(out.equals_missing(&df![
+        "per_row" => [None, None, None, Some(4), Some(14)],
+        "grouped" => [None, Some(1), Some(2), None, Some(8)],
+    ]?));
+
+    let out = df
+        .lazy()
+        .select([col("v").pct_change(lit(1), NullBehavior::Drop)])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.f64()?),
+        &[Some(1.0), Some(1.0), Some(1.0), Some(1.0)]
+    );
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "abs")]
 fn test_apply_flatten() -> PolarsResult<()> {
@@ -1921,3 +2126,221 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "to_dummies", feature = "dtype-categorical"))]
+fn test_to_dummies_expr() -> PolarsResult<()> {
+    let categories = arrow::array::Utf8ViewArray::from_slice_values(["a", "b", "c"]);
+    let df = df!["x" => [Some("b"), None, Some("a")]]?;
+
+    // The fields of an enum's dummies are known before the query runs.
+    let mut q = df
+        .clone()
+        .lazy()
+        .select([col("x")
+            .cast(create_enum_data_type(categories))
+            .to_dummies(None, false)])
+        .unnest(["x"]);
+    let schema = q.schema()?;
+    assert_eq!(
+        schema.iter_names().collect::<Vec<_>>(),
+        &["x_a", "x_b", "x_c"]
+    );
+    let out = q.select([all().cast(DataType::Int32)]).collect()?;
+    assert!(out.equals(&df![
+        "x_a" => [0, 0, 1],
+        "x_b" => [1, 0, 0],
+        "x_c" => [0, 0, 0],
+    ]?));
+
+    // Categoricals get a field per value, like other types.
+    let out = df
+        .lazy()
+        .select([col("x")
+            .cast(DataType::Categorical(None, Default::default()))
+            .to_dummies(Some(":"), true)])
+        .collect()?;
+    let out = out.column("x")?.struct_()?.clone().unnest();
+    let out = out.lazy().select([all().cast(DataType::Int32)]).collect()?;
+    assert!(out.equals(&df![
+        "x:a" => [0, 0, 1],
+        "x:null" => [0, 1, 0],
+    ]?));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "list_to_struct")]
+fn test_list_to_struct_fixed_width() -> PolarsResult<()> {
+    use polars_ops::prelude::{ListToStructWidthStrategy, NameGenerator};
+
+    let lists = Series::new(
+        "a",
+        &[
+            Series::new("", &[1, 2, 3]),
+            Series::new("", &[4]),
+            Series::new("", Vec::<i32>::new()),
+        ],
+    );
+    let name_generator: NameGenerator = Arc::new(|i| format!("x{i}").into());
+    let mut q = DataFrame::new(vec![lists])?
+        .lazy()
+        .select([col("a").list().to_struct(
+            ListToStructWidthStrategy::Fixed(2),
+            Some(name_generator),
+            0,
+        )])
+        .unnest(["a"]);
+
+    // The fields are known in the plan, with the names of the generator.
+    let schema = q.schema()?;
+    assert_eq!(schema.iter_names().collect::<Vec<_>>(), &["x0", "x1"]);
+
+    // Short lists are padded with nulls and long lists are truncated.
+    let out = q.collect()?;
+    assert!(out.equals_missing(&df![
+        "x0" => [Some(1), Some(4), None],
+        "x1" => [Some(2), None, None],
+    ]?));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "range", feature = "temporal", feature = "dtype-datetime"))]
+fn test_date_range_expr() -> PolarsResult<()> {
+    use polars_ops::prelude::ListNameSpaceImpl;
+    use polars_time::ClosedWindow;
+
+    let df = df![
+        "g" => ["a", "a", "b"],
+        "start" => [0, 1, 10],
+        "end" => [2, 3, 11],
+    ]?
+    .lazy()
+    .with_columns([cols(["start", "end"]).cast(DataType::Date)]);
+
+    let out = df
+        .clone()
+        .select([date_range(
+            col("start").min(),
+            col("end").max(),
+            Duration::parse("1d"),
+            ClosedWindow::Both,
+        )])
+        .collect()?;
+    // The range spans the whole column, from day 0 to day 11.
+    let days = out.column("start")?.to_physical_repr();
+    assert_eq!(
+        Vec::from(days.i32()?),
+        (0..12).map(Some).collect::<Vec<_>>()
+    );
+
+    // In an aggregation, every group gets its own range.
+    let out = df
+        .group_by_stable([col("g")])
+        .agg([
+            date_range(
+                col("start").min(),
+                col("end").max(),
+                Duration::parse("1d"),
+                ClosedWindow::Left,
+            )
+            .alias("days"),
+            datetime_range(
+                col("start").min(),
+                col("end").max(),
+                Duration::parse("12h"),
+                ClosedWindow::None,
+                Some(TimeUnit::Milliseconds),
+                None,
+            )
+            .alias("half_days"),
+        ])
+        .collect()?;
+    let days = out.column("days")?.list()?;
+    assert_eq!(days.inner_dtype(), &DataType::Date);
+    assert_eq!(Vec::from(&days.lst_lengths()), &[Some(3), Some(1)]);
+    let half_days = out.column("half_days")?.list()?;
+    assert_eq!(
+        half_days.inner_dtype(),
+        &DataType::Datetime(TimeUnit::Milliseconds, None)
+    );
+    assert_eq!(Vec::from(&half_days.lst_lengths()), &[Some(5), Some(1)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "temporal")]
+fn test_week_start() -> PolarsResult<()> {
+    use polars_time::WeekStart;
+
+    // 2023-01-01 and 2023-01-08 are Sundays.
+    let df = df!["date" => [19358, 19364, 19365]]?
+        .lazy()
+        .select([col("date").cast(DataType::Date)]);
+
+    let out = df
+        .select([
+            col("date").dt().week().alias("iso_week"),
+            col("date")
+                .dt()
+                .week_with_start(WeekStart::Sunday)
+                .alias("week"),
+            col("date")
+                .dt()
+                .weekday_with_start(WeekStart::Sunday)
+                .alias("weekday"),
+            col("date")
+                .dt()
+                .truncate_with_week_start(lit("1w"), WeekStart::Sunday)
+                .cast(DataType::Int32)
+                .alias("truncated"),
+        ])
+        .collect()?;
+    assert!(out.equals(&df![
+        "iso_week" => [52i8, 1, 1],
+        "week" => [1i8, 1, 2],
+        "weekday" => [1i8, 7, 1],
+        "truncated" => [19358, 19358, 19365],
+    ]?));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "index_of", feature = "arg_where"))]
+fn test_index_of_and_arg_where() -> PolarsResult<()> {
+    let df = df![
+        "groups" => [1, 1, 2, 2, 2],
+        "vals" => [3, 5, 5, 7, 5]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("vals").index_of(lit(5)),
+            col("vals").index_of(lit(4)).alias("missing"),
+        ])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("vals")?.idx()?), &[Some(1)]);
+    assert_eq!(Vec::from(out.column("missing")?.idx()?), &[None]);
+
+    // The index is relative to the group.
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("groups")])
+        .agg([col("vals").index_of(lit(5))])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("vals")?.idx()?), &[Some(1), Some(0)]);
+
+    let out = df
+        .lazy()
+        .select([arg_where(col("vals").eq(lit(5)))])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("vals")?.idx()?),
+        &[Some(1), Some(2), Some(4)]
+    );
+    Ok(())
+}
@@ -40,3 +40,48 @@ fn test_schema_update_after_projection_pd() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_assert_and_match_schema() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2],
+        "b" => ["x", "y"],
+    ]?;
+    let schema = Schema::from_iter([
+        Field::new("b", DataType::String),
+        Field::new("a", DataType::Int64),
+        Field::new("c", DataType::Boolean),
+    ]);
+
+    assert!(df.clone().lazy().assert_schema(&df.schema()).is_ok());
+    let err = df.clone().lazy().assert_schema(&schema).err().unwrap();
+    assert_eq!(err.code(), ErrorCode::SchemaMismatch);
+
+    // Casting and filling in missing columns must be allowed explicitly.
+    let policy = MatchSchemaPolicy::default();
+    assert!(df.clone().lazy().match_to_schema(&schema, policy).is_err());
+    let policy = policy.with_cast(true);
+    assert!(df.clone().lazy().match_to_schema(&schema, policy).is_err());
+
+    let policy = policy.with_fill_missing(true);
+    let out = df
+        .clone()
+        .lazy()
+        .match_to_schema(&schema, policy)?
+        .assert_schema(&schema)?
+        .collect()?;
+    let expected = df![
+        "b" => ["x", "y"],
+        "a" => [1i64, 2],
+        "c" => [None::<bool>, None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    // Extra columns are only dropped if the policy allows it.
+    let schema = Schema::from_iter([Field::new("a", DataType::Int32)]);
+    assert!(df.clone().lazy().match_to_schema(&schema, policy).is_err());
+    let policy = policy.with_drop_extra(true);
+    let out = df.lazy().match_to_schema(&schema, policy)?.collect()?;
+    assert_eq!(out.get_column_names(), &["a"]);
+    Ok(())
+}
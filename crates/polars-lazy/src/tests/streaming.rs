@@ -428,3 +428,18 @@ fn test_streaming_full_outer_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_streaming_unpivot() -> PolarsResult<()> {
+    let q = get_csv_file()
+        .unpivot(UnpivotArgs {
+            on: vec!["calories".into(), "fats_g".into()],
+            index: vec!["category".into()],
+            streamable: true,
+            ..Default::default()
+        })
+        .sort_by_exprs([all()], SortMultipleOptions::default());
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
@@ -71,6 +71,17 @@ pub(super) fn group_by_helper(
     }
 
     let mut groups = gb.get_groups();
+    let _memory = state.reserve_bytes(
+        || {
+            let idx_size = std::mem::size_of::<IdxSize>();
+            let group_size = match groups {
+                GroupsProxy::Idx(_) => df.height() * idx_size + groups.len() * 2 * idx_size,
+                GroupsProxy::Slice { .. } => groups.len() * 2 * idx_size,
+            };
+            df.estimated_size() + group_size
+        },
+        "the groups of the group by",
+    )?;
 
     #[allow(unused_assignments)]
     // it is unused because we only use it to keep the lifetime of sliced_group valid
@@ -70,6 +70,9 @@ impl Executor for JoinExec {
 
         let df_left = df_left?;
         let df_right = df_right?;
+        // Both inputs are held until the join is done, one of them as the hashed build side.
+        let _left_memory = state.reserve_memory(&df_left, "the left input of the join")?;
+        let _right_memory = state.reserve_memory(&df_right, "the right input of the join")?;
 
         let profile_name = if state.has_node_timer() {
             let by = self
@@ -149,7 +152,10 @@ impl Executor for JoinExec {
             if state.verbose() {
                 eprintln!("{:?} join dataframes finished", self.args.how);
             };
-            df
+            let df = df?;
+            // The output must fit next to the inputs.
+            drop(state.reserve_memory(&df, "the output of the join")?);
+            Ok(df)
 
         }, profile_name)
     }
@@ -14,6 +14,27 @@ pub(super) fn profile_name(
 
 type IdAndExpression = (u32, Arc<dyn PhysicalExpr>);
 
+/// Evaluate `expr`, recording the expression and the column it computes on failure.
+fn evaluate_expr(
+    expr: &dyn PhysicalExpr,
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<Series> {
+    expr.evaluate(df, state)
+        .map_err(|err| match expr.as_expression() {
+            Some(e) => {
+                // The field of the physical expression has the name of its alias, which the
+                // expression of an alias doesn't hold.
+                let column = expr
+                    .to_field(&df.schema())
+                    .ok()
+                    .map(|field| field.name().to_string().into());
+                err.with_expr(format!("{e:?}"), column)
+            },
+            None => err,
+        })
+}
+
 #[cfg(feature = "dynamic_group_by")]
 fn rolling_evaluate(
     df: &DataFrame,
@@ -39,7 +60,7 @@ fn rolling_evaluate(
                 }
                 partition
                     .par_iter()
-                    .map(|(idx, expr)| expr.evaluate(df, &state).map(|s| (*idx, s)))
+                    .map(|(idx, expr)| evaluate_expr(expr.as_ref(), df, &state).map(|s| (*idx, s)))
                     .collect::<PolarsResult<Vec<_>>>()
             })
             .collect()
@@ -85,7 +106,7 @@ fn window_evaluate(
                         state.remove_cache_window_flag();
                     }
 
-                    let s = e.evaluate(df, &state)?;
+                    let s = evaluate_expr(e.as_ref(), df, &state)?;
                     out.push((*index, s));
                 }
                 Ok(out)
@@ -156,7 +177,7 @@ fn execute_projection_cached_window_fns(
     let mut selected_columns = POOL.install(|| {
         other
             .par_iter()
-            .map(|(idx, expr)| expr.evaluate(df, state).map(|s| (*idx, s)))
+            .map(|(idx, expr)| evaluate_expr(*expr, df, state).map(|s| (*idx, s)))
             .collect::<PolarsResult<Vec<_>>>()
     })?;
 
@@ -200,7 +221,7 @@ fn run_exprs_par(
     POOL.install(|| {
         exprs
             .par_iter()
-            .map(|expr| expr.evaluate(df, state))
+            .map(|expr| evaluate_expr(expr.as_ref(), df, state))
             .collect()
     })
 }
@@ -210,7 +231,10 @@ fn run_exprs_seq(
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
 ) -> PolarsResult<Vec<Series>> {
-    exprs.iter().map(|expr| expr.evaluate(df, state)).collect()
+    exprs
+        .iter()
+        .map(|expr| evaluate_expr(expr.as_ref(), df, state))
+        .collect()
 }
 
 pub(super) fn evaluate_physical_expressions(
@@ -61,7 +61,7 @@ impl CsvExec {
 
         let finish_read =
             |i: usize, options: CsvReadOptions, predicate: Option<Arc<dyn PhysicalIoExpr>>| {
-                if run_async {
+                let mut df = if run_async {
                     #[cfg(feature = "cloud")]
                     {
                         options
@@ -85,7 +85,13 @@ impl CsvExec {
                         .unwrap()
                         ._with_predicate(predicate.clone())
                         .finish()
+                }?;
+
+                if let Some(file_path_col) = &self.file_options.include_file_paths {
+                    add_file_path_column(&mut df, file_path_col, &self.paths[i])?;
                 }
+
+                Ok(df)
             };
 
         let mut df = if n_rows.is_some()
@@ -41,7 +41,7 @@ impl IpcExec {
                     .block_on_potential_spawn(self.read_async(verbose))?
             }
         } else {
-            self.read_sync()?
+            self.read_sync(verbose)?
         };
 
         if self.file_options.rechunk {
@@ -51,8 +51,8 @@ impl IpcExec {
         Ok(out)
     }
 
-    fn read_sync(&mut self) -> PolarsResult<DataFrame> {
-        if config::verbose() {
+    fn read_sync(&mut self, verbose: bool) -> PolarsResult<DataFrame> {
+        if verbose {
             eprintln!("executing ipc read sync with row_index = {:?}, n_rows = {:?}, predicate = {:?} for paths {:?}",
                 self.file_options.row_index.as_ref(),
                 self.file_options.n_rows.as_ref(),
@@ -75,6 +75,17 @@ impl IpcExec {
 
         let row_limit = n_rows.unwrap_or(IdxSize::MAX);
 
+        // The predicate can only be applied while reading (and used to skip record batches) if
+        // the rows of a file don't depend on the other files.
+        let predicate_in_reader = self.file_options.row_index.is_none()
+            && n_rows.is_none()
+            && self.file_options.include_file_paths.is_none();
+        let reader_predicate = if predicate_in_reader {
+            self.predicate.clone().map(phys_expr_to_io_expr)
+        } else {
+            None
+        };
+
         // Used to determine the next file to open. This guarantees the order.
         let path_index = AtomicUsize::new(0);
         let row_counter = RwLock::new(ConsecutiveCountState::new(self.paths.len()));
@@ -98,7 +109,7 @@ impl IpcExec {
                     None
                 };
 
-                let df = IpcReader::new(file)
+                let mut df = IpcReader::new(file)
                     .with_n_rows(
                         // NOTE: If there is any file that by itself exceeds the
                         // row limit, passing the total row limit to each
@@ -112,7 +123,11 @@ impl IpcExec {
                     .with_row_index(self.file_options.row_index.clone())
                     .with_projection(projection.clone())
                     .memory_mapped(memory_mapped)
-                    .finish()?;
+                    .finish_with_scan_ops(reader_predicate.clone(), verbose)?;
+
+                if let Some(file_path_col) = &self.file_options.include_file_paths {
+                    add_file_path_column(&mut df, file_path_col, path)?;
+                }
 
                 row_counter
                     .write()
@@ -128,7 +143,11 @@ impl IpcExec {
             row_counter.into_inner().unwrap(),
             self.file_options.row_index.as_ref(),
             row_limit,
-            self.predicate.as_ref(),
+            if predicate_in_reader {
+                None
+            } else {
+                self.predicate.as_ref()
+            },
         )
     }
 
@@ -174,7 +193,7 @@ impl IpcExec {
                         this.cloud_options.as_ref(),
                     )
                     .await?;
-                    let df = reader
+                    let mut df = reader
                         .data(
                             this.metadata.as_ref(),
                             IpcReadOptions::default()
@@ -195,6 +214,10 @@ impl IpcExec {
                         )
                         .await?;
 
+                    if let Some(file_path_col) = &this.file_options.include_file_paths {
+                        add_file_path_column(&mut df, file_path_col, path)?;
+                    }
+
                     row_counter
                         .write()
                         .unwrap()
@@ -10,6 +10,7 @@ mod parquet;
 #[cfg(feature = "ipc")]
 mod support;
 use std::mem;
+use std::path::Path;
 
 #[cfg(feature = "csv")]
 pub(crate) use csv::CsvExec;
@@ -58,6 +59,19 @@ fn prepare_scan_args(
     (projection, predicate)
 }
 
+/// Add a column holding the path of the file `df` was read from.
+#[cfg(any(
+    feature = "parquet",
+    feature = "csv",
+    feature = "ipc",
+    feature = "json"
+))]
+fn add_file_path_column(df: &mut DataFrame, name: &str, path: &Path) -> PolarsResult<()> {
+    let path = path.to_string_lossy();
+    df.with_column(StringChunked::full(name, path.as_ref(), df.height()).into_series())?;
+    Ok(())
+}
+
 /// Producer of an in memory DataFrame
 pub struct DataFrameExec {
     pub(crate) df: Arc<DataFrame>,
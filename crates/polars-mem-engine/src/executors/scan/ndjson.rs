@@ -1,6 +1,8 @@
 use std::path::PathBuf;
 
+use polars_core::config;
 use polars_core::utils::accumulate_dataframes_vertical;
+use polars_io::utils::is_cloud_url;
 
 use super::*;
 
@@ -38,6 +40,14 @@ impl JsonExec {
             .as_ref()
             .unwrap_right();
 
+        let verbose = config::verbose();
+        let force_async = config::force_async();
+        let run_async = force_async || is_cloud_url(self.paths.first().unwrap());
+
+        if force_async && verbose {
+            eprintln!("ASYNC READING FORCED");
+        }
+
         let mut n_rows = self.file_scan_options.n_rows;
 
         let dfs = self
@@ -48,11 +58,32 @@ impl JsonExec {
                     return None;
                 }
 
-                let reader = match JsonLineReader::from_path(p) {
-                    Ok(r) => r,
-                    Err(e) => return Some(Err(e)),
+                let file = if run_async {
+                    #[cfg(feature = "cloud")]
+                    {
+                        match polars_io::file_cache::FILE_CACHE
+                            .get_entry(p.to_str().unwrap())
+                            // Safety: This was initialized by schema inference.
+                            .unwrap()
+                            .try_open_assume_latest()
+                        {
+                            Ok(f) => f,
+                            Err(e) => return Some(Err(e)),
+                        }
+                    }
+                    #[cfg(not(feature = "cloud"))]
+                    {
+                        panic!("required feature `cloud` is not enabled")
+                    }
+                } else {
+                    match polars_utils::open_file(p) {
+                        Ok(f) => f,
+                        Err(e) => return Some(Err(e)),
+                    }
                 };
 
+                let reader = JsonLineReader::new(file);
+
                 let row_index = self.file_scan_options.row_index.as_mut();
 
                 let df = reader
@@ -67,11 +98,17 @@ impl JsonExec {
                     .with_ignore_errors(self.options.ignore_errors)
                     .finish();
 
-                let df = match df {
+                let mut df = match df {
                     Ok(df) => df,
                     Err(e) => return Some(Err(e)),
                 };
 
+                if let Some(file_path_col) = &self.file_scan_options.include_file_paths {
+                    if let Err(e) = add_file_path_column(&mut df, file_path_col, p) {
+                        return Some(Err(e));
+                    }
+                }
+
                 if let Some(ref mut n_rows) = n_rows {
                     *n_rows -= df.height();
                 }
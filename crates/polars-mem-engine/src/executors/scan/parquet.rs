@@ -339,7 +339,7 @@ impl ParquetExec {
                     self.file_options.row_index.is_some(),
                     hive_partitions.as_deref(),
                 );
-                return Ok(materialize_empty_df(
+                let mut df = materialize_empty_df(
                     projection.as_deref(),
                     self.file_info
                         .reader_schema
@@ -349,7 +349,11 @@ impl ParquetExec {
                         .unwrap_left(),
                     hive_partitions.as_deref(),
                     self.file_options.row_index.as_ref(),
-                ));
+                );
+                if let Some(file_path_col) = &self.file_options.include_file_paths {
+                    add_file_path_column(&mut df, file_path_col, Path::new(""))?;
+                }
+                return Ok(df);
             },
         };
         let force_async = config::force_async();
@@ -372,7 +376,19 @@ impl ParquetExec {
             self.read_par()?
         };
 
-        let mut out = accumulate_dataframes_vertical(out)?;
+        let mut out = match &self.file_options.include_file_paths {
+            // The readers return the files in order, so the dataframes line up with the paths.
+            Some(file_path_col) => accumulate_dataframes_vertical(
+                out.into_iter()
+                    .zip(self.paths.iter())
+                    .map(|(mut df, path)| {
+                        add_file_path_column(&mut df, file_path_col, path)?;
+                        Ok(df)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?,
+            )?,
+            None => accumulate_dataframes_vertical(out)?,
+        };
 
         polars_io::predicates::apply_predicate(&mut out, post_predicate.as_deref(), true)?;
 
@@ -15,6 +15,7 @@ impl SortExec {
     ) -> PolarsResult<DataFrame> {
         state.should_stop()?;
         df.as_single_chunk_par();
+        let _memory = state.reserve_memory(&df, "the sort buffer")?;
 
         let by_columns = self
             .by_column
@@ -33,7 +34,10 @@ impl SortExec {
             })
             .collect::<PolarsResult<Vec<_>>>()?;
 
-        df.sort_impl(by_columns, self.sort_options.clone(), self.slice)
+        let out = df.sort_impl(by_columns, self.sort_options.clone(), self.slice)?;
+        // The sorted output must fit next to the input.
+        drop(state.reserve_memory(&out, "the output of the sort")?);
+        Ok(out)
     }
 }
 
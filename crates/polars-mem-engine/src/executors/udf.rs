@@ -21,6 +21,18 @@ impl Executor for UdfExec {
         } else {
             Cow::Borrowed("")
         };
-        state.record(|| self.function.evaluate(df), profile_name)
+        // Streaming pipelines run behind a plain function and pick up the token and the memory
+        // limit from the scope.
+        let token = state.cancel_token();
+        let memory_limit = state.memory_limit().cloned();
+        state.record(
+            || {
+                token.scope(|| match &memory_limit {
+                    Some(limit) => limit.scope(|| self.function.evaluate(df)),
+                    None => self.function.evaluate(df),
+                })
+            },
+            profile_name,
+        )
     }
 }
@@ -240,6 +240,39 @@ fn create_physical_plan_impl(
             mut file_options,
         } => {
             file_options.n_rows = _set_n_rows_for_scan(file_options.n_rows);
+            // The file path column doesn't exist in the files, so it must not be projected by
+            // the readers. It is only materialized if it was projected.
+            let mut restore_projection = None;
+            if let (Some(file_path_col), Some(with_columns)) = (
+                file_options.include_file_paths.clone(),
+                file_options.with_columns.clone(),
+            ) {
+                if with_columns
+                    .iter()
+                    .any(|c| c.as_str() == file_path_col.as_ref())
+                {
+                    let mut with_columns = with_columns
+                        .iter()
+                        .filter(|c| c.as_str() != file_path_col.as_ref())
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    if with_columns.is_empty() {
+                        // Read a single column to know the number of rows and drop it again
+                        // afterwards.
+                        let first_column = file_info.reader_schema.as_ref().and_then(|schema| {
+                            schema.as_ref().either(
+                                |schema| schema.fields.first().map(|field| field.name.clone()),
+                                |schema| schema.get_at_index(0).map(|(name, _)| name.to_string()),
+                            )
+                        });
+                        with_columns.extend(first_column);
+                        restore_projection.clone_from(&output_schema);
+                    }
+                    file_options.with_columns = Some(Arc::from(with_columns));
+                } else {
+                    file_options.include_file_paths = None;
+                }
+            }
             let mut state = ExpressionConversionState::new(true, state.expr_depth);
             let predicate = predicate
                 .map(|pred| {
@@ -253,7 +286,7 @@ fn create_physical_plan_impl(
                 })
                 .map_or(Ok(None), |v| v.map(Some))?;
 
-            match scan_type {
+            let exec: PolarsResult<Box<dyn Executor>> = match scan_type {
                 #[cfg(feature = "csv")]
                 FileScan::Csv { options, .. } => Ok(Box::new(executors::CsvExec {
                     paths,
@@ -292,7 +325,10 @@ fn create_physical_plan_impl(
                     metadata,
                 ))),
                 #[cfg(feature = "json")]
-                FileScan::NDJson { options } => Ok(Box::new(executors::JsonExec::new(
+                FileScan::NDJson {
+                    options,
+                    cloud_options: _,
+                } => Ok(Box::new(executors::JsonExec::new(
                     paths,
                     options,
                     file_options,
@@ -309,6 +345,14 @@ fn create_physical_plan_impl(
                         predicate_has_windows: state.has_windows,
                     }))
                 },
+            };
+
+            match restore_projection {
+                Some(columns) => Ok(Box::new(executors::ProjectionSimple {
+                    input: exec?,
+                    columns,
+                })),
+                None => exec,
             }
         },
         Select {
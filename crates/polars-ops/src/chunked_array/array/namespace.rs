@@ -3,12 +3,12 @@ use super::*;
 #[cfg(feature = "array_count")]
 use crate::chunked_array::array::count::array_count_matches;
 use crate::chunked_array::array::count::count_boolean_bits;
-use crate::chunked_array::array::sum_mean::sum_with_nulls;
+use crate::chunked_array::array::sum_mean::{mean_with_nulls, sum_with_nulls};
 #[cfg(feature = "array_any_all")]
 use crate::prelude::array::any_all::{array_all, array_any};
 use crate::prelude::array::get::array_get;
 use crate::prelude::array::join::array_join;
-use crate::prelude::array::sum_mean::sum_array_numerical;
+use crate::prelude::array::sum_mean::{mean_array_numerical, sum_array_numerical};
 use crate::series::ArgAgg;
 
 pub fn has_inner_nulls(ca: &ArrayChunked) -> bool {
@@ -51,6 +51,19 @@ pub trait ArrayNameSpace: AsArray {
         }
     }
 
+    fn array_mean(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+
+        if has_inner_nulls(ca) {
+            return mean_with_nulls(ca);
+        };
+
+        match ca.inner_dtype() {
+            dt if dt.is_numeric() => Ok(mean_array_numerical(ca, dt)),
+            _ => mean_with_nulls(ca),
+        }
+    }
+
     fn array_median(&self) -> PolarsResult<Series> {
         let ca = self.as_array();
         dispersion::median_with_nulls(ca)
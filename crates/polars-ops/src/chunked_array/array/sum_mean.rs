@@ -42,6 +42,8 @@ pub(super) fn sum_array_numerical(ca: &ArrayChunked, inner_type: &DataType) -> S
                 Int16 => dispatch_sum::<i16, i64>(values, width, arr.validity()),
                 Int32 => dispatch_sum::<i32, i32>(values, width, arr.validity()),
                 Int64 => dispatch_sum::<i64, i64>(values, width, arr.validity()),
+                #[cfg(feature = "dtype-i128")]
+                Int128 => dispatch_sum::<i128, i128>(values, width, arr.validity()),
                 UInt8 => dispatch_sum::<u8, i64>(values, width, arr.validity()),
                 UInt16 => dispatch_sum::<u16, i64>(values, width, arr.validity()),
                 UInt32 => dispatch_sum::<u32, u32>(values, width, arr.validity()),
@@ -56,6 +58,45 @@ pub(super) fn sum_array_numerical(ca: &ArrayChunked, inner_type: &DataType) -> S
     Series::try_from((ca.name(), chunks)).unwrap()
 }
 
+pub(super) fn mean_array_numerical(ca: &ArrayChunked, inner_type: &DataType) -> Series {
+    let width = ca.width();
+    use DataType::*;
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| {
+            let values = arr.values().as_ref();
+
+            match inner_type {
+                Int8 => dispatch_sum::<i8, f64>(values, width, arr.validity()),
+                Int16 => dispatch_sum::<i16, f64>(values, width, arr.validity()),
+                Int32 => dispatch_sum::<i32, f64>(values, width, arr.validity()),
+                Int64 => dispatch_sum::<i64, f64>(values, width, arr.validity()),
+                #[cfg(feature = "dtype-i128")]
+                Int128 => dispatch_sum::<i128, f64>(values, width, arr.validity()),
+                UInt8 => dispatch_sum::<u8, f64>(values, width, arr.validity()),
+                UInt16 => dispatch_sum::<u16, f64>(values, width, arr.validity()),
+                UInt32 => dispatch_sum::<u32, f64>(values, width, arr.validity()),
+                UInt64 => dispatch_sum::<u64, f64>(values, width, arr.validity()),
+                Float32 => dispatch_sum::<f32, f64>(values, width, arr.validity()),
+                Float64 => dispatch_sum::<f64, f64>(values, width, arr.validity()),
+                _ => unimplemented!(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let summed = Series::try_from((ca.name(), chunks)).unwrap();
+    &summed / width as f64
+}
+
+pub(super) fn mean_with_nulls(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let mut out: Float64Chunked = ca
+        .amortized_iter()
+        .map(|s| s.and_then(|s| s.as_ref().mean()))
+        .collect();
+    out.rename(ca.name());
+    Ok(out.into_series())
+}
+
 pub(super) fn sum_with_nulls(ca: &ArrayChunked, inner_dtype: &DataType) -> PolarsResult<Series> {
     use DataType::*;
     // TODO: add fast path for smaller ints?
@@ -12,6 +12,7 @@ fn compute_hist<T>(
     bins: Option<&[f64]>,
     include_category: bool,
     include_breakpoint: bool,
+    normalize: bool,
 ) -> Series
 where
     T: PolarsNumericType,
@@ -138,7 +139,17 @@ where
         fields.insert(0, Series::new("breakpoint", breaks))
     }
 
-    let count = Series::new("count", count);
+    let count = if normalize {
+        // Relative to the number of non-null values.
+        let total = count.iter().sum::<IdxSize>() as f64;
+        let proportion = count
+            .iter()
+            .map(|c| if total > 0.0 { *c as f64 / total } else { 0.0 })
+            .collect::<Vec<_>>();
+        Series::new("proportion", proportion)
+    } else {
+        Series::new("count", count)
+    };
     fields.push(count);
 
     if fields.len() == 1 {
@@ -157,6 +168,7 @@ pub fn hist_series(
     bins: Option<Series>,
     include_category: bool,
     include_breakpoint: bool,
+    normalize: bool,
 ) -> PolarsResult<Series> {
     let mut bins_arg = None;
 
@@ -174,7 +186,7 @@ pub fn hist_series(
 
     let out = with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
          let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-         compute_hist(ca, bin_count, bins_arg, include_category, include_breakpoint)
+         compute_hist(ca, bin_count, bins_arg, include_category, include_breakpoint, normalize)
     });
     Ok(out)
 }
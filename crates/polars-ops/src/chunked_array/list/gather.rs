@@ -0,0 +1,112 @@
+#[cfg(feature = "list_gather")]
+use std::ops::Range;
+
+use arrow::array::{Array, MutableArray, MutablePrimitiveArray, PrimitiveArray};
+use arrow::bitmap::MutableBitmap;
+use arrow::offset::OffsetsBuffer;
+use polars_core::utils::slice_offsets;
+
+use super::*;
+
+/// Build a list of which every sublist consists of the values of `ca` at the indices that
+/// `gather` pushes for it. `gather` is called for every row with the offset and length of its
+/// sublist in the values of `ca`, or `None` if it is null, and returns whether the output row
+/// is valid.
+///
+/// Only the indices are built per row, the values are gathered at once, so that the sublists
+/// don't have to be materialized as separate `Series`.
+fn gather_sublists<F>(ca: &ListChunked, mut gather: F) -> PolarsResult<ListChunked>
+where
+    F: FnMut(Option<(usize, usize)>, &mut MutablePrimitiveArray<IdxSize>) -> PolarsResult<bool>,
+{
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let offsets = arr.offsets().as_slice();
+
+    let mut idx = MutablePrimitiveArray::<IdxSize>::with_capacity(arr.values().len());
+    let mut new_offsets = Vec::with_capacity(arr.len() + 1);
+    let mut validity = MutableBitmap::with_capacity(arr.len());
+    new_offsets.push(0i64);
+    for i in 0..arr.len() {
+        let sublist = arr.is_valid(i).then(|| {
+            let start = offsets[i] as usize;
+            (start, offsets[i + 1] as usize - start)
+        });
+        validity.push(gather(sublist, &mut idx)?);
+        new_offsets.push(idx.len() as i64);
+    }
+
+    let idx: PrimitiveArray<IdxSize> = idx.into();
+    let idx = IdxCa::with_chunk("", idx);
+    // SAFETY: the indices are within the sublists.
+    let values = unsafe { ca.get_inner().take_unchecked(&idx) }.rechunk();
+    let values_arr = values.chunks()[0].clone();
+
+    // SAFETY: monotonically increasing
+    let new_offsets = unsafe { OffsetsBuffer::new_unchecked(new_offsets.into()) };
+    let data_type = LargeListArray::default_datatype(values_arr.data_type().clone());
+    let arr = LargeListArray::new(data_type, new_offsets, values_arr, validity.into());
+
+    // SAFETY: arr's inner dtype is derived from the values dtype.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype(
+            ca.name(),
+            vec![Box::new(arr)],
+            DataType::List(Box::new(values.dtype().clone())),
+        )
+    })
+}
+
+/// Slice every sublist with the `(offset, length)` given for its row, or set the row to null
+/// if that is `None`.
+pub(super) fn slice_sublists<I>(ca: &ListChunked, slices: I) -> PolarsResult<ListChunked>
+where
+    I: IntoIterator<Item = Option<(i64, usize)>>,
+{
+    let mut slices = slices.into_iter();
+    gather_sublists(ca, |sublist, idx| {
+        match (sublist, slices.next().flatten()) {
+            (Some((start, len)), Some((offset, length))) => {
+                let (offset, length) = slice_offsets(offset, length, len);
+                let start = start + offset;
+                idx.extend_trusted_len_values(start as IdxSize..(start + length) as IdxSize);
+                Ok(true)
+            },
+            _ => Ok(false),
+        }
+    })
+}
+
+/// Gather the values at the indices `idx[range]` from the sublist of every row, or set the row
+/// to null if its `range` is `None`. Negative indices count from the end of the sublist. A null
+/// index gives a null value, and so does an out of bounds index if `null_on_oob` is set.
+#[cfg(feature = "list_gather")]
+pub(super) fn gather_sublists_by_index<I>(
+    ca: &ListChunked,
+    idx: &[Option<i64>],
+    ranges: I,
+    null_on_oob: bool,
+) -> PolarsResult<ListChunked>
+where
+    I: IntoIterator<Item = Option<Range<usize>>>,
+{
+    let mut ranges = ranges.into_iter();
+    gather_sublists(ca, |sublist, out| {
+        let (Some((start, len)), Some(range)) = (sublist, ranges.next().flatten()) else {
+            return Ok(false);
+        };
+        for i in &idx[range] {
+            match i.map(|i| if i < 0 { i + len as i64 } else { i }) {
+                Some(i) if i >= 0 && (i as usize) < len => {
+                    out.push(Some((start + i as usize) as IdxSize))
+                },
+                Some(_) => {
+                    polars_ensure!(null_on_oob, OutOfBounds: "gather indices are out of bounds");
+                    out.push_null()
+                },
+                None => out.push_null(),
+            }
+        }
+        Ok(true)
+    })
+}
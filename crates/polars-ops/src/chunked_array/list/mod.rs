@@ -4,6 +4,7 @@ use polars_core::prelude::*;
 mod any_all;
 mod count;
 mod dispersion;
+mod gather;
 #[cfg(feature = "hash")]
 pub(crate) mod hash;
 mod min_max;
@@ -1,12 +1,8 @@
 use std::fmt::Write;
 
-use arrow::array::ValueSize;
+use arrow::array::{Array, ValueSize};
 use arrow::legacy::kernels::list::{index_is_oob, sublist_get};
 use polars_core::chunked_array::builder::get_list_builder;
-#[cfg(feature = "list_gather")]
-use polars_core::export::num::ToPrimitive;
-#[cfg(feature = "list_gather")]
-use polars_core::export::num::{NumCast, Signed, Zero};
 #[cfg(feature = "diff")]
 use polars_core::series::ops::NullBehavior;
 use polars_core::utils::try_get_supertype;
@@ -14,6 +10,9 @@ use polars_core::utils::try_get_supertype;
 use super::*;
 #[cfg(feature = "list_any_all")]
 use crate::chunked_array::list::any_all::*;
+#[cfg(feature = "list_gather")]
+use crate::chunked_array::list::gather::gather_sublists_by_index;
+use crate::chunked_array::list::gather::slice_sublists;
 use crate::chunked_array::list::min_max::{list_max_function, list_min_function};
 use crate::chunked_array::list::sum_mean::sum_with_nulls;
 #[cfg(feature = "diff")]
@@ -318,8 +317,17 @@ pub trait ListNameSpaceImpl: AsList {
 
     fn lst_slice(&self, offset: i64, length: usize) -> ListChunked {
         let ca = self.as_list();
-        let out = ca.apply_amortized(|s| s.as_ref().slice(offset, length));
-        self.same_type(out)
+        slice_sublists(ca, std::iter::repeat(Some((offset, length)))).unwrap()
+    }
+
+    /// Slice every sublist with the offset and length given for its row, where a `None` gives
+    /// a null row.
+    fn lst_slice_by<I>(&self, slices: I) -> ListChunked
+    where
+        I: IntoIterator<Item = Option<(i64, usize)>>,
+    {
+        let ca = self.as_list();
+        slice_sublists(ca, slices).unwrap()
     }
 
     fn lst_lengths(&self) -> IdxCa {
@@ -425,76 +433,51 @@ pub trait ListNameSpaceImpl: AsList {
     fn lst_gather(&self, idx: &Series, null_on_oob: bool) -> PolarsResult<Series> {
         let list_ca = self.as_list();
 
-        let index_typed_index = |idx: &Series| {
-            let idx = idx.cast(&IDX_DTYPE).unwrap();
-            {
-                list_ca
-                    .amortized_iter()
-                    .map(|s| {
-                        s.map(|s| {
-                            let s = s.as_ref();
-                            take_series(s, idx.clone(), null_on_oob)
-                        })
-                        .transpose()
-                    })
-                    .collect::<PolarsResult<ListChunked>>()
-                    .map(|mut ca| {
-                        ca.rename(list_ca.name());
-                        ca.into_series()
-                    })
-            }
+        let check_index_dtype = |dtype: &DataType| {
+            polars_ensure!(
+                dtype.is_integer(),
+                ComputeError: "cannot use dtype `{}` as an index", dtype
+            );
+            Ok(())
         };
 
-        use DataType::*;
-        match idx.dtype() {
-            List(_) => {
-                let idx_ca = idx.list().unwrap();
-                let mut out = {
-                    list_ca
-                        .amortized_iter()
-                        .zip(idx_ca)
-                        .map(|(opt_s, opt_idx)| {
-                            {
-                                match (opt_s, opt_idx) {
-                                    (Some(s), Some(idx)) => {
-                                        Some(take_series(s.as_ref(), idx, null_on_oob))
-                                    },
-                                    _ => None,
-                                }
-                            }
-                            .transpose()
-                        })
-                        .collect::<PolarsResult<ListChunked>>()?
+        let out = match idx.dtype() {
+            DataType::List(inner) => {
+                check_index_dtype(inner)?;
+                let idx_ca = idx.cast(&DataType::List(Box::new(DataType::Int64)))?;
+                let idx_ca = idx_ca.list().unwrap().rechunk();
+                let idx_arr = idx_ca.downcast_iter().next().unwrap();
+                polars_ensure!(
+                    idx_arr.len() == 1 || idx_arr.len() == list_ca.len(),
+                    ShapeMismatch: "the indices of `gather` should have the same length as the list, \
+                    got {} and {}", idx_arr.len(), list_ca.len()
+                );
+                let values = idx_ca.get_inner();
+                let values = values.i64().unwrap().iter().collect::<Vec<_>>();
+                let offsets = idx_arr.offsets();
+                let range = |i: usize| {
+                    idx_arr
+                        .is_valid(i)
+                        .then(|| offsets.start_end(i))
+                        .map(|(start, end)| start..end)
                 };
-                out.rename(list_ca.name());
-
-                Ok(out.into_series())
-            },
-            UInt32 | UInt64 => index_typed_index(idx),
-            dt if dt.is_signed_integer() => {
-                if let Some(min) = idx.min::<i64>().unwrap() {
-                    if min >= 0 {
-                        index_typed_index(idx)
-                    } else {
-                        let mut out = {
-                            list_ca
-                                .amortized_iter()
-                                .map(|opt_s| {
-                                    opt_s
-                                        .map(|s| take_series(s.as_ref(), idx.clone(), null_on_oob))
-                                        .transpose()
-                                })
-                                .collect::<PolarsResult<ListChunked>>()?
-                        };
-                        out.rename(list_ca.name());
-                        Ok(out.into_series())
-                    }
+                if idx_arr.len() == 1 {
+                    let ranges = std::iter::repeat(range(0));
+                    gather_sublists_by_index(list_ca, &values, ranges, null_on_oob)?
                 } else {
-                    polars_bail!(ComputeError: "all indices are null");
+                    let ranges = (0..idx_arr.len()).map(range);
+                    gather_sublists_by_index(list_ca, &values, ranges, null_on_oob)?
                 }
             },
-            dt => polars_bail!(ComputeError: "cannot use dtype `{}` as an index", dt),
-        }
+            dtype => {
+                check_index_dtype(dtype)?;
+                let idx = idx.cast(&DataType::Int64)?;
+                let values = idx.i64().unwrap().iter().collect::<Vec<_>>();
+                let ranges = std::iter::repeat(Some(0..values.len()));
+                gather_sublists_by_index(list_ca, &values, ranges, null_on_oob)?
+            },
+        };
+        Ok(out.into_series())
     }
 
     #[cfg(feature = "list_drop_nulls")]
@@ -738,112 +721,4 @@ pub trait ListNameSpaceImpl: AsList {
 
 impl ListNameSpaceImpl for ListChunked {}
 
-#[cfg(feature = "list_gather")]
-fn take_series(s: &Series, idx: Series, null_on_oob: bool) -> PolarsResult<Series> {
-    let len = s.len();
-    let idx = cast_index(idx, len, null_on_oob)?;
-    let idx = idx.idx().unwrap();
-    s.take(idx)
-}
-
-#[cfg(feature = "list_gather")]
-fn cast_signed_index_ca<T: PolarsNumericType>(idx: &ChunkedArray<T>, len: usize) -> Series
-where
-    T::Native: Copy + PartialOrd + PartialEq + NumCast + Signed + Zero,
-{
-    idx.iter()
-        .map(|opt_idx| opt_idx.and_then(|idx| idx.negative_to_usize(len).map(|idx| idx as IdxSize)))
-        .collect::<IdxCa>()
-        .into_series()
-}
-
-#[cfg(feature = "list_gather")]
-fn cast_unsigned_index_ca<T: PolarsNumericType>(idx: &ChunkedArray<T>, len: usize) -> Series
-where
-    T::Native: Copy + PartialOrd + ToPrimitive,
-{
-    idx.iter()
-        .map(|opt_idx| {
-            opt_idx.and_then(|idx| {
-                let idx = idx.to_usize().unwrap();
-                if idx >= len {
-                    None
-                } else {
-                    Some(idx as IdxSize)
-                }
-            })
-        })
-        .collect::<IdxCa>()
-        .into_series()
-}
-
-#[cfg(feature = "list_gather")]
-fn cast_index(idx: Series, len: usize, null_on_oob: bool) -> PolarsResult<Series> {
-    let idx_null_count = idx.null_count();
-    use DataType::*;
-    let out = match idx.dtype() {
-        #[cfg(feature = "big_idx")]
-        UInt32 => {
-            if null_on_oob {
-                let a = idx.u32().unwrap();
-                cast_unsigned_index_ca(a, len)
-            } else {
-                idx.cast(&IDX_DTYPE).unwrap()
-            }
-        },
-        #[cfg(feature = "big_idx")]
-        UInt64 => {
-            if null_on_oob {
-                let a = idx.u64().unwrap();
-                cast_unsigned_index_ca(a, len)
-            } else {
-                idx
-            }
-        },
-        #[cfg(not(feature = "big_idx"))]
-        UInt64 => {
-            if null_on_oob {
-                let a = idx.u64().unwrap();
-                cast_unsigned_index_ca(a, len)
-            } else {
-                idx.cast(&IDX_DTYPE).unwrap()
-            }
-        },
-        #[cfg(not(feature = "big_idx"))]
-        UInt32 => {
-            if null_on_oob {
-                let a = idx.u32().unwrap();
-                cast_unsigned_index_ca(a, len)
-            } else {
-                idx
-            }
-        },
-        dt if dt.is_unsigned_integer() => idx.cast(&IDX_DTYPE).unwrap(),
-        Int8 => {
-            let a = idx.i8().unwrap();
-            cast_signed_index_ca(a, len)
-        },
-        Int16 => {
-            let a = idx.i16().unwrap();
-            cast_signed_index_ca(a, len)
-        },
-        Int32 => {
-            let a = idx.i32().unwrap();
-            cast_signed_index_ca(a, len)
-        },
-        Int64 => {
-            let a = idx.i64().unwrap();
-            cast_signed_index_ca(a, len)
-        },
-        _ => {
-            unreachable!()
-        },
-    };
-    polars_ensure!(
-        out.null_count() == idx_null_count || null_on_oob,
-        OutOfBounds: "gather indices are out of bounds"
-    );
-    Ok(out)
-}
-
 // TODO: implement the above for ArrayChunked as well?
@@ -6,6 +6,7 @@ use arrow::array::{
     PrimitiveArray, Utf8ViewArray,
 };
 use arrow::bitmap::Bitmap;
+use arrow::compute::cast::cast_unchecked;
 use arrow::compute::utils::combine_validities_and;
 use arrow::offset::OffsetsBuffer;
 use arrow::types::NativeType;
@@ -390,7 +391,20 @@ fn array_set_operation(
             binary(a, b, offsets_a, offsets_b, set_op, validity, false)
         },
         ArrowDataType::Boolean => {
-            polars_bail!(InvalidOperation: "boolean type not yet supported in list 'set' operations")
+            // Booleans are hashed as bytes and converted back afterwards.
+            let a = cast_unchecked(values_a.as_ref(), &ArrowDataType::UInt8)?;
+            let b = cast_unchecked(values_b.as_ref(), &ArrowDataType::UInt8)?;
+            let a = a.as_any().downcast_ref::<PrimitiveArray<u8>>().unwrap();
+            let b = b.as_any().downcast_ref::<PrimitiveArray<u8>>().unwrap();
+
+            let out = primitive(a, b, offsets_a, offsets_b, set_op, validity)?;
+            let values = cast_unchecked(out.values().as_ref(), &ArrowDataType::Boolean)?;
+            Ok(ListArray::new(
+                ListArray::<i64>::default_datatype(ArrowDataType::Boolean),
+                out.offsets().clone(),
+                values,
+                out.validity().cloned(),
+            ))
         },
         _ => {
             with_match_physical_numeric_type!(dtype.into(), |$T| {
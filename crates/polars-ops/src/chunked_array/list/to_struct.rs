@@ -5,10 +5,18 @@ use smartstring::alias::String as SmartString;
 
 use super::*;
 
+/// How [`ToStruct::to_struct`] determines the number of fields.
+///
+/// Lists that are shorter than the number of fields are padded with nulls, longer lists are
+/// truncated.
 #[derive(Copy, Clone, Debug)]
 pub enum ListToStructWidthStrategy {
+    /// The length of the first non-empty list.
     FirstNonNull,
+    /// The length of the longest list.
     MaxWidth,
+    /// A fixed number of fields, so that the struct type doesn't depend on the data.
+    Fixed(usize),
 }
 
 fn det_n_fields(ca: &ListChunked, n_fields: ListToStructWidthStrategy) -> usize {
@@ -27,6 +35,7 @@ fn det_n_fields(ca: &ListChunked, n_fields: ListToStructWidthStrategy) -> usize
             });
             max
         },
+        ListToStructWidthStrategy::Fixed(n) => n,
         ListToStructWidthStrategy::FirstNonNull => {
             let mut len = 0;
             for arr in ca.downcast_iter() {
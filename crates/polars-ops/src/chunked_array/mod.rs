@@ -35,6 +35,10 @@ pub use gather::*;
 #[cfg(feature = "hist")]
 pub use hist::*;
 pub use list::*;
+#[cfg(feature = "mode")]
+pub use mode::ModeTieBreak;
+#[cfg(feature = "peaks")]
+pub use peaks::PeakPlateau;
 #[allow(unused_imports)]
 use polars_core::prelude::*;
 #[cfg(feature = "repeat_by")]
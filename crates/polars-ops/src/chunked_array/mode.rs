@@ -1,6 +1,24 @@
 use arrow::legacy::utils::CustomIterTools;
+use arrow::offset::Offsets;
 use polars_core::prelude::*;
-use polars_core::{with_match_physical_integer_polars_type, POOL};
+use polars_core::{
+    with_match_physical_integer_polars_type, with_match_physical_numeric_polars_type, POOL,
+};
+use polars_utils::total_ord::{TotalEq, TotalHash, TotalOrd, TotalOrdWrap};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How to choose a single mode if several values occur most often.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ModeTieBreak {
+    /// The mode that occurs first.
+    First,
+    /// The smallest mode.
+    Min,
+    /// The largest mode.
+    Max,
+}
 
 fn mode_primitive<T: PolarsDataType>(ca: &ChunkedArray<T>) -> PolarsResult<ChunkedArray<T>>
 where
@@ -81,11 +99,150 @@ pub fn mode(s: &Series) -> PolarsResult<Series> {
     unsafe { out.cast_unchecked(s.dtype()) }
 }
 
+/// Find the indices of the modes of every group, in the order of their first occurrence.
+/// With a `tie_break` every group gets a single index, which is `None` for empty groups.
+fn mode_groups_idx<K>(
+    values: &[Option<K>],
+    offsets: &[i64],
+    tie_break: Option<ModeTieBreak>,
+) -> (Vec<Option<IdxSize>>, Vec<i64>)
+where
+    K: TotalHash + TotalEq + TotalOrd + Copy,
+{
+    // Maps every value of a group to its count and the index of its first occurrence.
+    let mut counts = PlHashMap::<TotalOrdWrap<Option<K>>, (IdxSize, IdxSize)>::new();
+    let mut modes = vec![];
+    let mut idx = Vec::with_capacity(offsets.len().saturating_sub(1));
+    let mut out_offsets = Vec::with_capacity(offsets.len());
+    out_offsets.push(0);
+
+    for w in offsets.windows(2) {
+        let (start, end) = (w[0] as usize, w[1] as usize);
+        counts.clear();
+        let mut max_count = 0;
+        for (i, v) in values[start..end].iter().enumerate() {
+            let (count, _) = counts
+                .entry(TotalOrdWrap(*v))
+                .or_insert((0, (start + i) as IdxSize));
+            *count += 1;
+            max_count = max_count.max(*count);
+        }
+
+        modes.clear();
+        modes.extend(
+            counts
+                .values()
+                .filter(|(count, _)| *count == max_count)
+                .map(|(_, first)| *first),
+        );
+        modes.sort_unstable();
+
+        let value = |i: &IdxSize| &values[*i as usize];
+        match tie_break {
+            None => idx.extend(modes.iter().map(|i| Some(*i))),
+            Some(ModeTieBreak::First) => idx.push(modes.first().copied()),
+            // A null only wins a tie if there is no valid mode.
+            Some(ModeTieBreak::Min) => idx.push(
+                modes
+                    .iter()
+                    .min_by(|a, b| match (value(a), value(b)) {
+                        (Some(a), Some(b)) => a.tot_cmp(b),
+                        (a, b) => a.is_none().cmp(&b.is_none()),
+                    })
+                    .copied(),
+            ),
+            Some(ModeTieBreak::Max) => idx.push(
+                modes
+                    .iter()
+                    .max_by(|a, b| value(a).tot_cmp(value(b)))
+                    .copied(),
+            ),
+        }
+        out_offsets.push(idx.len() as i64);
+    }
+    (idx, out_offsets)
+}
+
+fn mode_groups_impl(
+    values: &Series,
+    offsets: &[i64],
+    tie_break: Option<ModeTieBreak>,
+) -> PolarsResult<(Series, Vec<i64>)> {
+    // The ties of lexically ordered categoricals are broken on their strings.
+    let keys = match values.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, CategoricalOrdering::Lexical)
+            if matches!(tie_break, Some(ModeTieBreak::Min | ModeTieBreak::Max)) =>
+        {
+            values.cast(&DataType::String)?
+        },
+        _ => values.to_physical_repr().into_owned(),
+    };
+
+    let (idx, offsets) = match keys.dtype() {
+        DataType::Boolean => {
+            let keys = keys.bool().unwrap().iter().collect::<Vec<_>>();
+            mode_groups_idx(&keys, offsets, tie_break)
+        },
+        DataType::String => {
+            let keys = keys.str().unwrap().iter().collect::<Vec<_>>();
+            mode_groups_idx(&keys, offsets, tie_break)
+        },
+        DataType::Binary => {
+            let keys = keys.binary().unwrap().iter().collect::<Vec<_>>();
+            mode_groups_idx(&keys, offsets, tie_break)
+        },
+        dt if dt.is_numeric() => {
+            with_match_physical_numeric_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = keys.as_ref().as_ref().as_ref();
+                let keys = ca.iter().collect::<Vec<_>>();
+                mode_groups_idx(&keys, offsets, tie_break)
+            })
+        },
+        _ => polars_bail!(opq = mode, values.dtype()),
+    };
+    let idx = IdxCa::from_iter_options("", idx.into_iter());
+    Ok((values.take(&idx)?, offsets))
+}
+
+/// Compute the modes of all lists of `ca` in a single pass, counting the values of each list
+/// in a hash table. Without a `tie_break` every list gets all of its modes, in the order of
+/// their first occurrence.
+pub fn mode_groups(ca: &ListChunked, tie_break: Option<ModeTieBreak>) -> PolarsResult<ListChunked> {
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    // SAFETY: the values of the list have its inner dtype.
+    let values = unsafe {
+        Series::from_chunks_and_dtype_unchecked("", vec![arr.values().clone()], ca.inner_dtype())
+    };
+    let (out, offsets) = mode_groups_impl(&values, arr.offsets().as_slice(), tie_break)?;
+
+    let out = out.rechunk();
+    // SAFETY: the offsets are monotonically increasing and end at the length of `out`.
+    let offsets = unsafe { Offsets::new_unchecked(offsets) };
+    let data_type = LargeListArray::default_datatype(out.dtype().to_physical().to_arrow(true));
+    let arr = LargeListArray::new(
+        data_type,
+        offsets.into(),
+        out.chunks()[0].clone(),
+        arr.validity().cloned(),
+    );
+    let mut out = ListChunked::with_chunk(ca.name(), arr);
+    out.set_inner_dtype(values.dtype().clone());
+    Ok(out)
+}
+
+/// Compute the single mode of `s` that is chosen by `tie_break`.
+pub fn mode_tie_break(s: &Series, tie_break: ModeTieBreak) -> PolarsResult<Series> {
+    let (out, _) = mode_groups_impl(s, &[0, s.len() as i64], Some(tie_break))?;
+    Ok(out.with_name(s.name()))
+}
+
 #[cfg(test)]
 mod test {
     use polars_core::prelude::*;
 
-    use super::{mode, mode_primitive};
+    use super::{mode, mode_groups, mode_primitive, mode_tie_break, ModeTieBreak};
 
     #[test]
     fn mode_test() {
@@ -124,4 +281,43 @@ mod test {
         assert_eq!(result.str_value(0).unwrap(), "test2");
         assert_eq!(result.len(), 1);
     }
+
+    #[test]
+    fn test_mode_groups() {
+        let s = Series::new(
+            "a",
+            &[Some(3), Some(1), Some(1), Some(3), None, None, Some(2)],
+        );
+        let offsets = [0i64, 4, 6, 7, 7];
+        let lst = (0..4)
+            .map(|i| {
+                let (start, end) = (offsets[i], offsets[i + 1]);
+                Some(s.slice(start, (end - start) as usize))
+            })
+            .collect::<ListChunked>();
+
+        let out = mode_groups(&lst, None).unwrap();
+        let expected = [vec![Some(3), Some(1)], vec![None], vec![Some(2)], vec![]];
+        for (opt_s, expected) in out.into_iter().zip(expected) {
+            let v = opt_s.unwrap().i32().unwrap().to_vec();
+            assert_eq!(v, expected);
+        }
+
+        let out = mode_groups(&lst, Some(ModeTieBreak::Min)).unwrap();
+        assert_eq!(
+            out.explode().unwrap().i32().unwrap().to_vec(),
+            &[Some(1), None, Some(2), None]
+        );
+        let out = mode_groups(&lst, Some(ModeTieBreak::Max)).unwrap();
+        assert_eq!(
+            out.explode().unwrap().i32().unwrap().to_vec(),
+            &[Some(3), None, Some(2), None]
+        );
+
+        let s = Series::new("a", &["b", "a", "b", "a", "c"]);
+        let out = mode_tie_break(&s, ModeTieBreak::First).unwrap();
+        assert_eq!(out.str().unwrap().get(0), Some("b"));
+        let out = mode_tie_break(&s, ModeTieBreak::Min).unwrap();
+        assert_eq!(out.str().unwrap().get(0), Some("a"));
+    }
 }
@@ -1,22 +1,107 @@
-use num_traits::Zero;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-/// Get a boolean mask of the local maximum peaks.
-pub fn peak_max<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> BooleanChunked
+/// Which values of a plateau, a run of equal values, are peaks if the plateau as a whole is.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PeakPlateau {
+    /// None of them; a peak must differ from both its neighbors.
+    #[default]
+    Strict,
+    /// The first value of the plateau.
+    First,
+    /// The last value of the plateau.
+    Last,
+    /// All values of the plateau.
+    All,
+}
+
+/// Mark the values that are beyond both their neighbors according to `beyond`.
+///
+/// Values at the edges only need to be beyond their single neighbor. Null values are null and
+/// values next to a null are no peaks.
+fn peaks<T, F>(ca: &ChunkedArray<T>, plateau: PeakPlateau, beyond: F) -> BooleanChunked
 where
-    ChunkedArray<T>: for<'a> ChunkCompare<&'a ChunkedArray<T>, Item = BooleanChunked>,
+    T: PolarsNumericType,
+    F: Fn(T::Native, T::Native) -> bool,
 {
-    let shift_left = ca.shift_and_fill(1, Some(Zero::zero()));
-    let shift_right = ca.shift_and_fill(-1, Some(Zero::zero()));
-    ChunkedArray::lt(&shift_left, ca) & ChunkedArray::lt(&shift_right, ca)
+    let values = ca.iter().collect::<Vec<_>>();
+    let mut out = values.iter().map(|v| v.map(|_| false)).collect::<Vec<_>>();
+    let is_beyond = |v, neighbor: Option<&Option<T::Native>>| match neighbor {
+        // The edges.
+        None => true,
+        Some(neighbor) => neighbor.is_some_and(|n| beyond(v, n)),
+    };
+
+    let mut start = 0;
+    while start < values.len() {
+        let mut end = start + 1;
+        let Some(v) = values[start] else {
+            start = end;
+            continue;
+        };
+        while end < values.len() && values[end] == Some(v) {
+            end += 1;
+        }
+        let left = start.checked_sub(1).map(|i| &values[i]);
+        if is_beyond(v, left) && is_beyond(v, values.get(end)) {
+            let marked = match (end - start, plateau) {
+                (1, _) | (_, PeakPlateau::All) => start..end,
+                (_, PeakPlateau::Strict) => start..start,
+                (_, PeakPlateau::First) => start..start + 1,
+                (_, PeakPlateau::Last) => end - 1..end,
+            };
+            for i in marked {
+                out[i] = Some(true);
+            }
+        }
+        start = end;
+    }
+    BooleanChunked::from_iter_options(ca.name(), out.into_iter())
+}
+
+/// Get a boolean mask of the local maximum peaks.
+pub fn peak_max<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    plateau: PeakPlateau,
+) -> BooleanChunked {
+    peaks(ca, plateau, |v, neighbor| v > neighbor)
 }
 
 /// Get a boolean mask of the local minimum peaks.
-pub fn peak_min<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> BooleanChunked
-where
-    ChunkedArray<T>: for<'a> ChunkCompare<&'a ChunkedArray<T>, Item = BooleanChunked>,
-{
-    let shift_left = ca.shift_and_fill(1, Some(Zero::zero()));
-    let shift_right = ca.shift_and_fill(-1, Some(Zero::zero()));
-    ChunkedArray::gt(&shift_left, ca) & ChunkedArray::gt(&shift_right, ca)
+pub fn peak_min<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    plateau: PeakPlateau,
+) -> BooleanChunked {
+    peaks(ca, plateau, |v, neighbor| v < neighbor)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_peaks() {
+        let ca = Int32Chunked::new(
+            "",
+            &[Some(4), Some(1), Some(3), Some(3), Some(2), None, Some(5)],
+        );
+        // 'x' marks a peak and '-' a null.
+        let mask = |out: BooleanChunked| {
+            out.into_iter()
+                .map(|v| match v {
+                    Some(true) => 'x',
+                    Some(false) => '.',
+                    None => '-',
+                })
+                .collect::<String>()
+        };
+
+        assert_eq!(mask(peak_max(&ca, PeakPlateau::Strict)), "x....-.");
+        assert_eq!(mask(peak_max(&ca, PeakPlateau::All)), "x.xx.-.");
+        assert_eq!(mask(peak_max(&ca, PeakPlateau::First)), "x.x..-.");
+        assert_eq!(mask(peak_max(&ca, PeakPlateau::Last)), "x..x.-.");
+        assert_eq!(mask(peak_min(&ca, PeakPlateau::Strict)), ".x...-.");
+    }
 }
@@ -163,4 +163,20 @@ mod test {
             &[Some("foo_spam_literal"), Some("bar_ham_literal")]
         );
     }
+
+    #[test]
+    fn test_hor_str_concat_nulls() {
+        let a = StringChunked::new("a", &[Some("foo"), None, None]);
+        let b = StringChunked::new("b", &[Some("spam"), Some("ham"), None]);
+        let c = StringChunked::new("c", &["literal"]);
+
+        let out = hor_str_concat(&[&a, &b, &c], "-", false).unwrap();
+        assert_eq!(Vec::from(&out), &[Some("foo-spam-literal"), None, None]);
+
+        let out = hor_str_concat(&[&a, &b, &c], "-", true).unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[Some("foo-spam-literal"), Some("ham-literal"), Some("literal")]
+        );
+    }
 }
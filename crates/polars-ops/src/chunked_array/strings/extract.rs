@@ -1,8 +1,10 @@
 use std::iter::zip;
 
 #[cfg(feature = "extract_groups")]
-use arrow::array::{Array, StructArray};
+use arrow::array::{Array, ListArray, StructArray};
 use arrow::array::{MutableBinaryViewArray, Utf8ViewArray};
+#[cfg(feature = "extract_groups")]
+use arrow::offset::Offsets;
 use polars_core::export::regex::Regex;
 use polars_core::prelude::arity::{try_binary_mut_with_options, try_unary_mut_with_options};
 
@@ -69,6 +71,94 @@ pub(super) fn extract_groups(
     Series::try_from((ca.name(), chunks))
 }
 
+#[cfg(feature = "extract_groups")]
+fn extract_all_groups_array(
+    arr: &Utf8ViewArray,
+    reg: &Regex,
+    n_groups: usize,
+    data_type: ArrowDataType,
+) -> PolarsResult<ArrayRef> {
+    let mut builders = (0..n_groups)
+        .map(|_| MutableBinaryViewArray::<str>::with_capacity(arr.len()))
+        .collect::<Vec<_>>();
+    let mut offsets = Offsets::<i64>::with_capacity(arr.len());
+
+    for opt_v in arr {
+        let mut n_matches = 0;
+        if let Some(s) = opt_v {
+            for caps in reg.captures_iter(s) {
+                for (i, builder) in builders.iter_mut().enumerate() {
+                    builder.push(caps.get(i + 1).map(|m| m.as_str()));
+                }
+                n_matches += 1;
+            }
+        }
+        // Null strings get an empty list, we copy arr's validity mask later.
+        offsets.try_push(n_matches)?;
+    }
+
+    let ArrowDataType::LargeList(inner) = &data_type else {
+        unreachable!() // Implementation error if it isn't a list.
+    };
+    let values = builders.into_iter().map(|a| a.freeze().boxed()).collect();
+    let values = StructArray::new(inner.data_type().clone(), values, None).boxed();
+    Ok(ListArray::<i64>::new(data_type, offsets.into(), values, arr.validity().cloned()).boxed())
+}
+
+#[cfg(feature = "extract_groups")]
+pub(super) fn extract_all_groups(
+    ca: &StringChunked,
+    pat: &str,
+    dtype: &DataType,
+) -> PolarsResult<ListChunked> {
+    let reg = Regex::new(pat)?;
+    let n_groups = reg.captures_len() - 1;
+    polars_ensure!(
+        n_groups > 0,
+        ComputeError: "regex pattern '{}' has no capture groups", pat
+    );
+
+    let data_type = dtype.try_to_arrow(true)?;
+    let chunks = ca
+        .downcast_iter()
+        .map(|array| extract_all_groups_array(array, &reg, n_groups, data_type.clone()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    // SAFETY: the chunks have the arrow type of `dtype`.
+    unsafe {
+        Ok(ListChunked::from_chunks_and_dtype(
+            ca.name(),
+            chunks,
+            dtype.clone(),
+        ))
+    }
+}
+
+/// A capture group of a regex, selected by its index or its name.
+#[derive(Clone, Copy)]
+pub(super) enum CaptureGroup<'a> {
+    Index(usize),
+    Name(&'a str),
+}
+
+impl CaptureGroup<'_> {
+    /// Resolve the group to its index in `reg`.
+    fn index(self, reg: &Regex) -> PolarsResult<usize> {
+        match self {
+            CaptureGroup::Index(idx) => Ok(idx),
+            CaptureGroup::Name(name) => reg
+                .capture_names()
+                .position(|opt_name| opt_name == Some(name))
+                .ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "regex pattern '{}' has no capture group named '{}'",
+                        reg.as_str(), name
+                    )
+                }),
+        }
+    }
+}
+
 fn extract_group_reg_lit(
     arr: &Utf8ViewArray,
     reg: &Regex,
@@ -95,13 +185,14 @@ fn extract_group_reg_lit(
 fn extract_group_array_lit(
     s: &str,
     pat: &Utf8ViewArray,
-    group_index: usize,
+    group: CaptureGroup,
 ) -> PolarsResult<Utf8ViewArray> {
     let mut builder = MutableBinaryViewArray::<str>::with_capacity(pat.len());
 
     for opt_pat in pat {
         if let Some(pat) = opt_pat {
             let reg = Regex::new(pat)?;
+            let group_index = group.index(&reg)?;
             let mut locs = reg.capture_locations();
             if reg.captures_read(&mut locs, s).is_some() {
                 builder.push(locs.get(group_index).map(|(start, stop)| &s[start..stop]));
@@ -119,7 +210,7 @@ fn extract_group_array_lit(
 fn extract_group_binary(
     arr: &Utf8ViewArray,
     pat: &Utf8ViewArray,
-    group_index: usize,
+    group: CaptureGroup,
 ) -> PolarsResult<Utf8ViewArray> {
     let mut builder = MutableBinaryViewArray::<str>::with_capacity(arr.len());
 
@@ -127,6 +218,7 @@ fn extract_group_binary(
         match (opt_s, opt_pat) {
             (Some(s), Some(pat)) => {
                 let reg = Regex::new(pat)?;
+                let group_index = group.index(&reg)?;
                 let mut locs = reg.capture_locations();
                 if reg.captures_read(&mut locs, s).is_some() {
                     builder.push(locs.get(group_index).map(|(start, stop)| &s[start..stop]));
@@ -145,12 +237,13 @@ fn extract_group_binary(
 pub(super) fn extract_group(
     ca: &StringChunked,
     pat: &StringChunked,
-    group_index: usize,
+    group: CaptureGroup,
 ) -> PolarsResult<StringChunked> {
     match (ca.len(), pat.len()) {
         (_, 1) => {
             if let Some(pat) = pat.get(0) {
                 let reg = Regex::new(pat)?;
+                let group_index = group.index(&reg)?;
                 try_unary_mut_with_options(ca, |arr| extract_group_reg_lit(arr, &reg, group_index))
             } else {
                 Ok(StringChunked::full_null(ca.name(), ca.len()))
@@ -158,7 +251,7 @@ pub(super) fn extract_group(
         },
         (1, _) => {
             if let Some(s) = ca.get(0) {
-                try_unary_mut_with_options(pat, |pat| extract_group_array_lit(s, pat, group_index))
+                try_unary_mut_with_options(pat, |pat| extract_group_array_lit(s, pat, group))
             } else {
                 Ok(StringChunked::full_null(ca.name(), pat.len()))
             }
@@ -166,7 +259,7 @@ pub(super) fn extract_group(
         (len_ca, len_pat) if len_ca == len_pat => try_binary_mut_with_options(
             ca,
             pat,
-            |ca, pat| extract_group_binary(ca, pat, group_index),
+            |ca, pat| extract_group_binary(ca, pat, group),
             ca.name(),
         ),
         _ => {
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use arrow::array::ValueSize;
 use jsonpath_lib::PathCompiled;
-use polars_core::prelude::arity::{broadcast_try_binary_elementwise, unary_elementwise};
+use polars_core::prelude::arity::unary_elementwise;
 use serde_json::Value;
 
 use super::*;
@@ -39,6 +39,11 @@ pub fn select_json<'a>(expr: &PathCompiled, json_str: &'a str) -> Option<Cow<'a,
     })
 }
 
+fn compile_json_path(path: &str) -> PolarsResult<PathCompiled> {
+    PathCompiled::compile(path)
+        .map_err(|e| polars_err!(ComputeError: "error compiling JSON path expression {}", e))
+}
+
 pub trait Utf8JsonPathImpl: AsString {
     /// Extract json path, first match
     /// Refer to <https://goessner.net/articles/JsonPath/>
@@ -49,9 +54,7 @@ pub trait Utf8JsonPathImpl: AsString {
                 // SAFETY: `json_path` was verified to have exactly 1 element.
                 let opt_path = unsafe { json_path.get_unchecked(0) };
                 let out = if let Some(path) = opt_path {
-                    let pat = PathCompiled::compile(path).map_err(
-                        |e| polars_err!(ComputeError: "error compiling JSON path expression {}", e),
-                    )?;
+                    let pat = compile_json_path(path)?;
                     unary_elementwise(ca, |opt_s| opt_s.and_then(|s| extract_json(&pat, s)))
                 } else {
                     StringChunked::full_null(ca.name(), ca.len())
@@ -59,16 +62,26 @@ pub trait Utf8JsonPathImpl: AsString {
                 Ok(out)
             },
             (len_ca, len_path) if len_ca == 1 || len_ca == len_path => {
-                broadcast_try_binary_elementwise(ca, json_path, |opt_str, opt_path| {
-                    match (opt_str, opt_path) {
-                    (Some(str_val), Some(path)) => {
-                        PathCompiled::compile(path)
-                            .map_err(|e| polars_err!(ComputeError: "error compiling JSON path expression {}", e))
-                            .map(|path| extract_json(&path, str_val))
-                    },
-                    _ => Ok(None),
-                }
-                })
+                let docs: Box<dyn Iterator<Item = Option<&str>>> = if len_ca == 1 {
+                    Box::new(std::iter::repeat(ca.get(0)))
+                } else {
+                    Box::new(ca.iter())
+                };
+                // Paths usually repeat, so every distinct path is only compiled once.
+                let mut compiled = PlHashMap::new();
+                let out = docs
+                    .zip(json_path.iter())
+                    .map(|(opt_str, opt_path)| match (opt_str, opt_path) {
+                        (Some(str_val), Some(path)) => {
+                            if !compiled.contains_key(path) {
+                                compiled.insert(path, compile_json_path(path)?);
+                            }
+                            Ok(extract_json(&compiled[path], str_val))
+                        },
+                        _ => Ok(None),
+                    })
+                    .collect::<PolarsResult<StringChunked>>()?;
+                Ok(out.with_name(ca.name()))
             },
             (len_ca, len_path) => {
                 polars_bail!(ComputeError: "The length of `ca` and `json_path` should either 1 or the same, but `{}`, `{}` founded", len_ca, len_path)
@@ -91,7 +104,10 @@ pub trait Utf8JsonPathImpl: AsString {
             .map_err(|e| polars_err!(ComputeError: "error inferring JSON: {}", e))
     }
 
-    /// Extracts a typed-JSON value for each row in the StringChunked
+    /// Extracts a typed-JSON value for each row in the StringChunked.
+    ///
+    /// If `dtype` is `None`, it is inferred from the first `infer_schema_len` rows, in which
+    /// the fields of objects are unified. Fields missing from a document are null.
     fn json_decode(
         &self,
         dtype: Option<DataType>,
@@ -112,7 +128,7 @@ pub trait Utf8JsonPathImpl: AsString {
             ca.len(),
         )
         .map_err(|e| polars_err!(ComputeError: "error deserializing JSON: {}", e))?;
-        Series::try_from(("", array))
+        Series::try_from((ca.name(), array))
     }
 
     fn json_path_select(&self, json_path: &str) -> PolarsResult<StringChunked> {
@@ -164,6 +180,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_json_path_match_per_row() {
+        let docs = StringChunked::new(
+            "json",
+            [
+                Some(r#"{"a":{"b":[1,2]},"c":"x"}"#),
+                Some(r#"{"a":{"b":[3]},"c":"y"}"#),
+                Some(r#"{"c":null}"#),
+                None,
+            ],
+        );
+        let paths = StringChunked::new(
+            "path",
+            [Some("$.a.b[0]"), Some("$.c"), Some("$.c"), Some("$.c")],
+        );
+        let out = docs.json_path_match(&paths).unwrap();
+        assert_eq!(out.name(), "json");
+        assert_eq!(
+            out.into_iter().collect::<Vec<_>>(),
+            &[Some("1"), Some("y"), None, None]
+        );
+
+        let invalid = StringChunked::new("path", [Some("$.c"), Some("$["), None, None]);
+        assert!(docs.json_path_match(&invalid).is_err());
+    }
+
     #[test]
     fn test_json_infer() {
         let s = Series::new(
@@ -221,6 +263,7 @@ mod tests {
             .json_decode(Some(expected_dtype), None)
             .unwrap()
             .equals_missing(&expected_series));
+        assert_eq!(ca.json_decode(None, None).unwrap().name(), "json");
     }
 
     #[test]
@@ -11,6 +11,7 @@ use polars_core::prelude::arity::*;
 use polars_utils::cache::FastFixedCache;
 use regex::escape;
 
+use super::extract::CaptureGroup;
 use super::*;
 #[cfg(feature = "binary_encoding")]
 use crate::chunked_array::binary::BinaryNameSpaceImpl;
@@ -396,7 +397,13 @@ pub trait StringNameSpaceImpl: AsString {
     /// Extract the nth capture group from pattern.
     fn extract(&self, pat: &StringChunked, group_index: usize) -> PolarsResult<StringChunked> {
         let ca = self.as_string();
-        super::extract::extract_group(ca, pat, group_index)
+        super::extract::extract_group(ca, pat, CaptureGroup::Index(group_index))
+    }
+
+    /// Extract the capture group named `group_name` from pattern.
+    fn extract_named(&self, pat: &StringChunked, group_name: &str) -> PolarsResult<StringChunked> {
+        let ca = self.as_string();
+        super::extract::extract_group(ca, pat, CaptureGroup::Name(group_name))
     }
 
     /// Extract each successive non-overlapping regex match in an individual string as an array.
@@ -515,6 +522,13 @@ pub trait StringNameSpaceImpl: AsString {
         super::extract::extract_groups(ca, pat, dtype)
     }
 
+    #[cfg(feature = "extract_groups")]
+    /// Extract the capture groups of every successive non-overlapping match as a list of structs.
+    fn extract_all_groups(&self, pat: &str, dtype: &DataType) -> PolarsResult<ListChunked> {
+        let ca = self.as_string();
+        super::extract::extract_all_groups(ca, pat, dtype)
+    }
+
     /// Count all successive non-overlapping regex matches.
     fn count_matches(&self, pat: &str, literal: bool) -> PolarsResult<UInt32Chunked> {
         let ca = self.as_string();
@@ -600,6 +614,38 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// Bring the string values into the Unicode normalization `form`, optionally folding
+    /// their case first.
+    #[must_use]
+    #[cfg(feature = "string_normalize")]
+    fn str_normalize(&self, form: UnicodeForm, case_fold: bool) -> StringChunked {
+        let ca = self.as_string();
+        normalize::normalize(ca, form, case_fold)
+    }
+
+    /// Levenshtein (edit) distance, in characters, to the values of `other`.
+    #[cfg(feature = "string_similarity")]
+    fn levenshtein_distance(&self, other: &StringChunked) -> PolarsResult<UInt32Chunked> {
+        let ca = self.as_string();
+        similarity::levenshtein_distance(ca, other)
+    }
+
+    /// Hamming distance, in characters, to the values of `other`.
+    ///
+    /// Pairs of strings with a different number of characters yield null.
+    #[cfg(feature = "string_similarity")]
+    fn hamming_distance(&self, other: &StringChunked) -> PolarsResult<UInt32Chunked> {
+        let ca = self.as_string();
+        similarity::hamming_distance(ca, other)
+    }
+
+    /// Jaro-Winkler similarity to the values of `other`, between 0 (no similarity) and 1 (equal).
+    #[cfg(feature = "string_similarity")]
+    fn jaro_winkler(&self, other: &StringChunked) -> PolarsResult<Float64Chunked> {
+        let ca = self.as_string();
+        similarity::jaro_winkler_similarity(ca, other)
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use unicode_normalization::{is_nfc, is_nfd, is_nfkc, is_nfkd, UnicodeNormalization};
+
+/// A Unicode normalization form.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UnicodeForm {
+    NFC,
+    NFKC,
+    NFD,
+    NFKD,
+}
+
+impl UnicodeForm {
+    fn is_normalized(self, s: &str) -> bool {
+        match self {
+            UnicodeForm::NFC => is_nfc(s),
+            UnicodeForm::NFKC => is_nfkc(s),
+            UnicodeForm::NFD => is_nfd(s),
+            UnicodeForm::NFKD => is_nfkd(s),
+        }
+    }
+
+    fn normalize(self, s: &str) -> String {
+        match self {
+            UnicodeForm::NFC => s.nfc().collect(),
+            UnicodeForm::NFKC => s.nfkc().collect(),
+            UnicodeForm::NFD => s.nfd().collect(),
+            UnicodeForm::NFKD => s.nfkd().collect(),
+        }
+    }
+}
+
+fn normalize_str(s: &str, form: UnicodeForm, case_fold: bool) -> Cow<'_, str> {
+    // Folding maps to lowercase first, as that may produce decomposable characters.
+    let s = if case_fold && s.chars().any(|c| c.is_uppercase()) {
+        Cow::Owned(s.to_lowercase())
+    } else {
+        Cow::Borrowed(s)
+    };
+    if form.is_normalized(&s) {
+        s
+    } else {
+        Cow::Owned(form.normalize(&s))
+    }
+}
+
+pub(super) fn normalize(ca: &StringChunked, form: UnicodeForm, case_fold: bool) -> StringChunked {
+    ca.apply_values(|s| normalize_str(s, form, case_fold))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        // "é" composed and decomposed, and the "ﬁ" ligature.
+        let ca = StringChunked::new(
+            "a",
+            &[Some("e\u{301}"), Some("\u{e9}"), None, Some("\u{fb01}")],
+        );
+
+        let out = normalize(&ca, UnicodeForm::NFC, false);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some("\u{e9}"), Some("\u{e9}"), None, Some("\u{fb01}")]
+        );
+        let out = normalize(&ca, UnicodeForm::NFKD, false);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some("e\u{301}"), Some("e\u{301}"), None, Some("fi")]
+        );
+
+        let ca = StringChunked::new("a", &["\u{c9}COLE", "e\u{301}cole"]);
+        let out = normalize(&ca, UnicodeForm::NFC, true);
+        assert_eq!(Vec::from(&out), &[Some("\u{e9}cole"), Some("\u{e9}cole")]);
+    }
+}
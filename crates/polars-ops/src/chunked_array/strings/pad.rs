@@ -54,7 +54,9 @@ pub(super) fn pad_start<'a>(
 fn zfill_fn<'a>(s: Option<&'a str>, len: Option<u64>, buf: &mut String) -> Option<&'a str> {
     match (s, len) {
         (Some(s), Some(length)) => {
-            let length = length.saturating_sub(s.len() as u64);
+            // Count codepoints, not bytes, so that non-ASCII strings are padded to `length`
+            // characters like in `pad_start`.
+            let length = length.saturating_sub(s.chars().count() as u64);
             if length == 0 {
                 return Some(s);
             }
@@ -99,3 +101,39 @@ pub(super) fn zfill<'a>(ca: &'a StringChunked, length: &'a UInt64Chunked) -> Str
         infer(|opt_s, opt_len| zfill_fn(opt_s, opt_len, &mut buf)),
     )
 }
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_pad_multibyte() {
+        let ca = StringChunked::new("a", &[Some("\u{e9}t\u{e9}"), None, Some("-\u{e9}")]);
+
+        let out = pad_start(&ca, 5, '\u{2219}');
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some("\u{2219}\u{2219}\u{e9}t\u{e9}"),
+                None,
+                Some("\u{2219}\u{2219}\u{2219}-\u{e9}")
+            ]
+        );
+        let out = pad_end(&ca, 4, '\u{1f600}');
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some("\u{e9}t\u{e9}\u{1f600}"),
+                None,
+                Some("-\u{e9}\u{1f600}\u{1f600}")
+            ]
+        );
+        let out = zfill(&ca, &UInt64Chunked::new("", &[4]));
+        assert_eq!(
+            Vec::from(&out),
+            &[Some("0\u{e9}t\u{e9}"), None, Some("-00\u{e9}")]
+        );
+    }
+}
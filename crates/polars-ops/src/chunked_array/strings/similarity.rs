@@ -0,0 +1,225 @@
+use polars_core::prelude::arity::{
+    broadcast_binary_elementwise, broadcast_binary_elementwise_values,
+};
+use polars_core::prelude::*;
+
+/// Buffers that are reused between the string pairs of a column.
+#[derive(Default)]
+struct Scratch {
+    a: Vec<char>,
+    b: Vec<char>,
+    row: Vec<u32>,
+    flags: Vec<bool>,
+}
+
+impl Scratch {
+    /// Call `f_bytes` on the bytes of `a` and `b` if both are ASCII, so that they don't
+    /// have to be decoded, and `f_chars` on their characters otherwise.
+    fn with_chars<R>(
+        &mut self,
+        a: &str,
+        b: &str,
+        f_bytes: impl FnOnce(&[u8], &[u8], &mut Vec<u32>, &mut Vec<bool>) -> R,
+        f_chars: impl FnOnce(&[char], &[char], &mut Vec<u32>, &mut Vec<bool>) -> R,
+    ) -> R {
+        if a.is_ascii() && b.is_ascii() {
+            f_bytes(a.as_bytes(), b.as_bytes(), &mut self.row, &mut self.flags)
+        } else {
+            self.a.clear();
+            self.a.extend(a.chars());
+            self.b.clear();
+            self.b.extend(b.chars());
+            f_chars(&self.a, &self.b, &mut self.row, &mut self.flags)
+        }
+    }
+}
+
+fn levenshtein<T: PartialEq>(a: &[T], b: &[T], row: &mut Vec<u32>) -> u32 {
+    if a.is_empty() {
+        return b.len() as u32;
+    }
+    // `row[j]` holds the distance between the processed prefix of `a` and `b[..j]`.
+    row.clear();
+    row.extend(0..=b.len() as u32);
+
+    for (i, x) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i as u32 + 1;
+        for (j, y) in b.iter().enumerate() {
+            let substitution = diagonal + (x != y) as u32;
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+fn hamming<T: PartialEq>(a: &[T], b: &[T]) -> Option<u32> {
+    (a.len() == b.len()).then(|| a.iter().zip(b).filter(|(x, y)| x != y).count() as u32)
+}
+
+fn jaro<T: PartialEq>(a: &[T], b: &[T], flags: &mut Vec<bool>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    flags.clear();
+    flags.resize(a.len() + b.len(), false);
+    let (a_matched, b_matched) = flags.split_at_mut(a.len());
+
+    // Characters only match if they are not further apart than `window`.
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut matches = 0;
+    for (i, x) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *x {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let mut transpositions = 0;
+    let mut j = 0;
+    for (x, _) in a.iter().zip(a_matched.iter()).filter(|(_, m)| **m) {
+        while !b_matched[j] {
+            j += 1;
+        }
+        if b[j] != *x {
+            transpositions += 1;
+        }
+        j += 1;
+    }
+
+    let m = matches as f64;
+    let t = transpositions as f64 / 2.0;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - t) / m) / 3.0
+}
+
+fn jaro_winkler<T: PartialEq>(a: &[T], b: &[T], flags: &mut Vec<bool>) -> f64 {
+    const PREFIX_SCALE: f64 = 0.1;
+    const MAX_PREFIX: usize = 4;
+
+    let sim = jaro(a, b, flags);
+    let prefix = a
+        .iter()
+        .zip(b)
+        .take(MAX_PREFIX)
+        .take_while(|(x, y)| x == y)
+        .count();
+    sim + prefix as f64 * PREFIX_SCALE * (1.0 - sim)
+}
+
+fn check_lengths(ca: &StringChunked, other: &StringChunked) -> PolarsResult<()> {
+    polars_ensure!(
+        ca.len() == other.len() || ca.len() == 1 || other.len() == 1,
+        ShapeMismatch: "cannot compare strings of length {} with strings of length {}",
+        ca.len(), other.len()
+    );
+    Ok(())
+}
+
+pub(super) fn levenshtein_distance(
+    ca: &StringChunked,
+    other: &StringChunked,
+) -> PolarsResult<UInt32Chunked> {
+    check_lengths(ca, other)?;
+    let mut scratch = Scratch::default();
+    Ok(broadcast_binary_elementwise_values(
+        ca,
+        other,
+        |a: &str, b: &str| {
+            scratch.with_chars(
+                a,
+                b,
+                |a, b, row, _| levenshtein(a, b, row),
+                |a, b, row, _| levenshtein(a, b, row),
+            )
+        },
+    ))
+}
+
+pub(super) fn hamming_distance(
+    ca: &StringChunked,
+    other: &StringChunked,
+) -> PolarsResult<UInt32Chunked> {
+    check_lengths(ca, other)?;
+    let mut scratch = Scratch::default();
+    Ok(broadcast_binary_elementwise(
+        ca,
+        other,
+        |a: Option<&str>, b: Option<&str>| match (a, b) {
+            // Strings of different lengths have no Hamming distance.
+            (Some(a), Some(b)) => {
+                scratch.with_chars(a, b, |a, b, _, _| hamming(a, b), |a, b, _, _| hamming(a, b))
+            },
+            _ => None,
+        },
+    ))
+}
+
+pub(super) fn jaro_winkler_similarity(
+    ca: &StringChunked,
+    other: &StringChunked,
+) -> PolarsResult<Float64Chunked> {
+    check_lengths(ca, other)?;
+    let mut scratch = Scratch::default();
+    Ok(broadcast_binary_elementwise_values(
+        ca,
+        other,
+        |a: &str, b: &str| {
+            scratch.with_chars(
+                a,
+                b,
+                |a, b, _, flags| jaro_winkler(a, b, flags),
+                |a, b, _, flags| jaro_winkler(a, b, flags),
+            )
+        },
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_string_distances() {
+        let mut row = vec![];
+        assert_eq!(levenshtein(b"kitten", b"sitting", &mut row), 3);
+        assert_eq!(levenshtein(b"", b"abc", &mut row), 3);
+        assert_eq!(levenshtein(b"abc", b"", &mut row), 3);
+        assert_eq!(hamming(b"karolin", b"kathrin"), Some(3));
+        assert_eq!(hamming(b"abc", b"ab"), None);
+
+        let mut flags = vec![];
+        let sim = jaro_winkler(b"MARTHA", b"MARHTA", &mut flags);
+        assert!((sim - 0.961).abs() < 1e-3);
+        let sim = jaro_winkler(b"DIXON", b"DICKSONX", &mut flags);
+        assert!((sim - 0.813).abs() < 1e-3);
+        assert_eq!(jaro_winkler(b"abc", b"xyz", &mut flags), 0.0);
+    }
+
+    #[test]
+    fn test_string_distances_broadcast() {
+        let ca = StringChunked::new("a", &[Some("héllo"), None, Some("hallo")]);
+        let other = StringChunked::new("b", &["hello"]);
+
+        let out = levenshtein_distance(&ca, &other).unwrap();
+        assert_eq!(Vec::from(&out), &[Some(1), None, Some(1)]);
+        let out = hamming_distance(&ca, &other).unwrap();
+        assert_eq!(Vec::from(&out), &[Some(1), None, Some(1)]);
+
+        let other = StringChunked::new("b", &["a", "b"]);
+        assert!(levenshtein_distance(&ca, &other).is_err());
+    }
+}
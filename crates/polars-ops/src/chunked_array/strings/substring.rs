@@ -207,3 +207,32 @@ pub(super) fn tail(ca: &StringChunked, n: &Int64Chunked) -> StringChunked {
         _ => binary_elementwise(ca, n, tail_binary),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_slice_codepoints() {
+        // Every character is more than one byte.
+        let ca = StringChunked::new("a", &["\u{e9}\u{1f600}\u{4e2d}\u{e9}"]);
+        let n = |n: i64| Int64Chunked::new("", &[n]);
+
+        let out = substring(&ca, &n(1), &UInt64Chunked::new("", &[2]));
+        assert_eq!(Vec::from(&out), &[Some("\u{1f600}\u{4e2d}")]);
+        let out = substring(&ca, &n(-2), &UInt64Chunked::new("", &[5]));
+        assert_eq!(Vec::from(&out), &[Some("\u{4e2d}\u{e9}")]);
+        assert_eq!(
+            Vec::from(&head(&ca, &n(3))),
+            &[Some("\u{e9}\u{1f600}\u{4e2d}")]
+        );
+        assert_eq!(Vec::from(&head(&ca, &n(-3))), &[Some("\u{e9}")]);
+        assert_eq!(Vec::from(&tail(&ca, &n(1))), &[Some("\u{e9}")]);
+        assert_eq!(
+            Vec::from(&tail(&ca, &n(-1))),
+            &[Some("\u{1f600}\u{4e2d}\u{e9}")]
+        );
+    }
+}
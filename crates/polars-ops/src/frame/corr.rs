@@ -0,0 +1,179 @@
+use polars_core::export::rayon::prelude::*;
+use polars_core::prelude::*;
+#[cfg(feature = "rank")]
+use polars_core::utils::coalesce_nulls_series;
+use polars_core::POOL;
+
+use crate::chunked_array::cov::{cov, pearson_corr};
+#[cfg(feature = "rank")]
+use crate::series::{RankMethod, RankOptions, SeriesRank};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CorrelationMatrixMethod {
+    #[default]
+    Pearson,
+    /// The Pearson correlation of the average ranks of the values.
+    #[cfg(feature = "rank")]
+    Spearman,
+}
+
+#[cfg(feature = "rank")]
+fn average_rank(s: &Series) -> Series {
+    let options = RankOptions {
+        method: RankMethod::Average,
+        ..Default::default()
+    };
+    s.rank(options, None).cast(&DataType::Float64).unwrap()
+}
+
+/// Correlate two `Float64` columns, of which the values at the rows where either is null are
+/// excluded. `ranks` are the ranks of the columns if these don't contain nulls, otherwise the
+/// values of the other rows have to be ranked again.
+fn correlate_pair(
+    method: CorrelationMatrixMethod,
+    a: &Series,
+    b: &Series,
+    #[allow(unused_variables)] ranks: Option<(&Series, &Series)>,
+) -> Option<f64> {
+    match method {
+        CorrelationMatrixMethod::Pearson => pearson_corr(a.f64().unwrap(), b.f64().unwrap(), 1),
+        #[cfg(feature = "rank")]
+        CorrelationMatrixMethod::Spearman => {
+            let (a, b) = match ranks {
+                Some((a, b)) => (a.clone(), b.clone()),
+                None => {
+                    let (a, b) = coalesce_nulls_series(a, b);
+                    (average_rank(&a.drop_nulls()), average_rank(&b.drop_nulls()))
+                },
+            };
+            pearson_corr(a.f64().unwrap(), b.f64().unwrap(), 1)
+        },
+    }
+}
+
+/// Get the numeric columns of `df` cast to `Float64`.
+fn numeric_columns(df: &DataFrame, matrix: &str) -> PolarsResult<Vec<Series>> {
+    let columns = df
+        .get_columns()
+        .iter()
+        .filter(|s| s.dtype().is_numeric())
+        .map(|s| Ok(s.cast(&DataType::Float64)?.rechunk()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    polars_ensure!(
+        !columns.is_empty(),
+        InvalidOperation: "cannot compute the {} matrix of a DataFrame without numeric columns", matrix
+    );
+    Ok(columns)
+}
+
+/// Build the symmetric matrix of `f(i, j)` for every pair of `columns`, with a `column` column
+/// that labels the rows. Only the upper triangle is computed, in parallel.
+fn pairwise_matrix<F>(columns: &[Series], f: F) -> PolarsResult<DataFrame>
+where
+    F: Fn(usize, usize) -> Option<f64> + Sync,
+{
+    let n = columns.len();
+    let pairs = (0..n)
+        .flat_map(|i| (i..n).map(move |j| (i, j)))
+        .collect::<Vec<_>>();
+    let values = POOL.install(|| pairs.par_iter().map(|&(i, j)| f(i, j)).collect::<Vec<_>>());
+
+    let mut matrix = vec![vec![None; n]; n];
+    for (&(i, j), value) in pairs.iter().zip(values) {
+        matrix[i][j] = value;
+        matrix[j][i] = value;
+    }
+
+    let names = columns.iter().map(|s| s.name()).collect::<Vec<_>>();
+    let mut out = Vec::with_capacity(n + 1);
+    out.push(Series::new("column", &names));
+    out.extend(
+        names
+            .iter()
+            .zip(matrix)
+            .map(|(name, values)| Series::new(name, values)),
+    );
+    DataFrame::new(out)
+}
+
+/// Compute the correlation matrix of the numeric columns of `df`.
+///
+/// Every pair of columns is correlated in parallel, in a single pass over the values that
+/// updates the means and co-moments of both columns at once. Rows where either column of a
+/// pair is null are excluded from the correlation of that pair.
+pub(super) fn correlation_matrix(
+    df: &DataFrame,
+    method: CorrelationMatrixMethod,
+) -> PolarsResult<DataFrame> {
+    let columns = numeric_columns(df, "correlation")?;
+
+    #[cfg(feature = "rank")]
+    let ranks = match method {
+        CorrelationMatrixMethod::Spearman => POOL.install(|| {
+            columns
+                .par_iter()
+                .map(|s| (s.null_count() == 0).then(|| average_rank(s)))
+                .collect::<Vec<_>>()
+        }),
+        CorrelationMatrixMethod::Pearson => vec![None; columns.len()],
+    };
+    #[cfg(not(feature = "rank"))]
+    let ranks: Vec<Option<Series>> = vec![None; columns.len()];
+
+    pairwise_matrix(&columns, |i, j| {
+        let pair_ranks = ranks[i].as_ref().zip(ranks[j].as_ref());
+        correlate_pair(method, &columns[i], &columns[j], pair_ranks)
+    })
+}
+
+/// Compute the covariance matrix of the numeric columns of `df`, with the same co-moment
+/// kernel as the correlation matrix. Rows where either column of a pair is null are excluded
+/// from the covariance of that pair.
+pub(super) fn covariance_matrix(df: &DataFrame, ddof: u8) -> PolarsResult<DataFrame> {
+    let columns = numeric_columns(df, "covariance")?;
+    pairwise_matrix(&columns, |i, j| {
+        cov(columns[i].f64().unwrap(), columns[j].f64().unwrap(), ddof)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_correlation_matrix() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1, 2, 3, 4],
+            "b" => [Some(2.0), Some(4.0), None, Some(8.0)],
+            "c" => [4, 3, 2, 1],
+            "d" => ["w", "x", "y", "z"],
+        ]?;
+
+        let out = correlation_matrix(&df, CorrelationMatrixMethod::Pearson)?;
+        assert_eq!(out.get_column_names(), &["column", "a", "b", "c"]);
+        let a = out.column("a")?.f64()?;
+        assert!((a.get(0).unwrap() - 1.0).abs() < 1e-12);
+        assert!((a.get(1).unwrap() - 1.0).abs() < 1e-12);
+        assert!((a.get(2).unwrap() + 1.0).abs() < 1e-12);
+        assert_eq!(out.column("b")?.f64()?.get(0), a.get(1));
+
+        #[cfg(feature = "rank")]
+        {
+            let out = correlation_matrix(&df, CorrelationMatrixMethod::Spearman)?;
+            let b = out.column("b")?.f64()?;
+            assert!((b.get(0).unwrap() - 1.0).abs() < 1e-12);
+            assert!((b.get(2).unwrap() + 1.0).abs() < 1e-12);
+        }
+
+        let out = covariance_matrix(&df, 1)?;
+        assert_eq!(out.get_column_names(), &["column", "a", "b", "c"]);
+        let a = out.column("a")?.f64()?;
+        for (value, expected) in a
+            .into_no_null_iter()
+            .zip([5.0 / 3.0, 14.0 / 3.0, -5.0 / 3.0])
+        {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        Ok(())
+    }
+}
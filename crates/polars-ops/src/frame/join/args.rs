@@ -27,6 +27,8 @@ pub struct JoinArgs {
     pub slice: Option<(i64, usize)>,
     pub join_nulls: bool,
     pub coalesce: JoinCoalesce,
+    /// Match float keys by IEEE 754 totalOrder, so -0 and 0 don't match.
+    pub float_total_order: bool,
 }
 
 impl JoinArgs {
@@ -58,6 +60,8 @@ impl JoinCoalesce {
             #[cfg(feature = "asof_join")]
             AsOf(_) => matches!(self, JoinSpecific | CoalesceColumns),
             Cross => false,
+            #[cfg(feature = "iejoin")]
+            IEJoin(_) => false,
             #[cfg(feature = "semi_anti_join")]
             Semi | Anti => false,
         }
@@ -73,6 +77,7 @@ impl Default for JoinArgs {
             slice: None,
             join_nulls: false,
             coalesce: Default::default(),
+            float_total_order: false,
         }
     }
 }
@@ -86,6 +91,7 @@ impl JoinArgs {
             slice: None,
             join_nulls: false,
             coalesce: Default::default(),
+            float_total_order: false,
         }
     }
 
@@ -94,6 +100,11 @@ impl JoinArgs {
         self
     }
 
+    pub fn with_float_total_order(mut self, float_total_order: bool) -> Self {
+        self.float_total_order = float_total_order;
+        self
+    }
+
     pub fn with_suffix(mut self, suffix: Option<String>) -> Self {
         self.suffix = suffix;
         self
@@ -113,6 +124,8 @@ pub enum JoinType {
     #[cfg(feature = "asof_join")]
     AsOf(AsOfOptions),
     Cross,
+    #[cfg(feature = "iejoin")]
+    IEJoin(IEJoinOptions),
     #[cfg(feature = "semi_anti_join")]
     Semi,
     #[cfg(feature = "semi_anti_join")]
@@ -135,6 +148,8 @@ impl Display for JoinType {
             #[cfg(feature = "asof_join")]
             AsOf(_) => "ASOF",
             Cross => "CROSS",
+            #[cfg(feature = "iejoin")]
+            IEJoin(_) => "IEJOIN",
             #[cfg(feature = "semi_anti_join")]
             Semi => "SEMI",
             #[cfg(feature = "semi_anti_join")]
@@ -140,7 +140,10 @@ where
         AsofStrategy::Backward => {
             join_asof_impl::<T, AsofJoinBackwardState, _>(left, right, filter)
         },
-        AsofStrategy::Nearest => unimplemented!(),
+        AsofStrategy::Nearest => polars_bail!(
+            InvalidOperation:
+            "asof join with 'nearest' strategy is only supported on numeric/temporal keys"
+        ),
     })
 }
 
@@ -197,6 +200,17 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_asof_nearest_tolerance() {
+        let a = PrimitiveArray::from_slice([-1, 12, 16, 24, 30, 40]);
+        let b = PrimitiveArray::from_slice([10, 20, 30, 30]);
+        let tuples = join_asof_nearest::<Int32Type, _>(&a, &b, |l, r| l.abs_diff(r) <= 4u32);
+        assert_eq!(
+            tuples.to_vec(),
+            &[None, Some(0), Some(1), Some(1), Some(3), None]
+        );
+    }
+
     #[test]
     fn test_asof_forward() {
         let a = PrimitiveArray::from_slice([-1, 1, 2, 4, 6]);
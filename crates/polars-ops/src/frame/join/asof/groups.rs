@@ -484,7 +484,10 @@ where
         AsofStrategy::Forward => dispatch_join_by_type::<T, AsofJoinForwardState, _>(
             left_asof, right_asof, left_by, right_by, filter,
         ),
-        AsofStrategy::Nearest => unimplemented!(),
+        AsofStrategy::Nearest => polars_bail!(
+            InvalidOperation:
+            "asof join with 'nearest' strategy is only supported on numeric/temporal keys"
+        ),
     }
 }
 
@@ -626,8 +629,7 @@ pub trait AsofJoinBy: IntoDf {
             right_key = right_on;
         }
 
-        let left_asof = left_key.to_physical_repr();
-        let right_asof = right_key.to_physical_repr();
+        let (left_asof, right_asof) = asof_physical_keys(left_key, right_key)?;
         let right_asof_name = right_asof.name();
         let left_asof_name = left_asof.name();
         check_asof_columns(
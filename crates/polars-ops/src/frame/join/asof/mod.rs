@@ -190,6 +190,24 @@ fn check_asof_columns(
     Ok(())
 }
 
+/// Get the physical representation of the asof keys. Lexically ordered categoricals are
+/// mapped to the rank of their categories, so that the search follows the string order.
+fn asof_physical_keys<'a>(
+    left_key: &'a Series,
+    right_key: &'a Series,
+) -> PolarsResult<(Cow<'a, Series>, Cow<'a, Series>)> {
+    #[cfg(feature = "dtype-categorical")]
+    if let DataType::Categorical(_, CategoricalOrdering::Lexical) = left_key.dtype() {
+        let (left, right) =
+            make_categoricals_compatible(left_key.categorical()?, right_key.categorical()?)?;
+        return Ok((
+            Cow::Owned(left.to_lexical_ranks().into_series()),
+            Cow::Owned(right.to_lexical_ranks().into_series()),
+        ));
+    }
+    Ok((left_key.to_physical_repr(), right_key.to_physical_repr()))
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AsofStrategy {
@@ -219,8 +237,7 @@ pub trait AsofJoin: IntoDf {
         let self_df = self.to_df();
 
         check_asof_columns(left_key, right_key, tolerance.is_some(), true)?;
-        let left_key = left_key.to_physical_repr();
-        let right_key = right_key.to_physical_repr();
+        let (left_key, right_key) = asof_physical_keys(left_key, right_key)?;
 
         let mut take_idx = match left_key.dtype() {
             DataType::Int64 => {
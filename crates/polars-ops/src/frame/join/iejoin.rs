@@ -0,0 +1,230 @@
+use std::ops::Range;
+
+use polars_core::utils::try_get_supertype;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::total_ord::TotalOrd;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// An inequality that has to hold between a left and a right join column, as in
+/// `left <op> right`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InequalityOperator {
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+impl Display for InequalityOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let op = match self {
+            InequalityOperator::Lt => "<",
+            InequalityOperator::LtEq => "<=",
+            InequalityOperator::Gt => ">",
+            InequalityOperator::GtEq => ">=",
+        };
+        write!(f, "{op}")
+    }
+}
+
+impl InequalityOperator {
+    /// The operator that holds with swapped operands, e.g. `a < b` is `b > a`.
+    pub fn flip(self) -> Self {
+        use InequalityOperator::*;
+        match self {
+            Lt => Gt,
+            LtEq => GtEq,
+            Gt => Lt,
+            GtEq => LtEq,
+        }
+    }
+
+    /// The range of the ascending sorted `right` values for which `left <op> right` holds.
+    fn matching_range<T: TotalOrd>(self, left: &T, right: &[T]) -> Range<usize> {
+        use InequalityOperator::*;
+        match self {
+            Lt => right.partition_point(|r| r.tot_le(left))..right.len(),
+            LtEq => right.partition_point(|r| r.tot_lt(left))..right.len(),
+            Gt => 0..right.partition_point(|r| r.tot_lt(left)),
+            GtEq => 0..right.partition_point(|r| r.tot_le(left)),
+        }
+    }
+
+    fn compare(self, left: &Series, right: &Series) -> PolarsResult<BooleanChunked> {
+        match self {
+            InequalityOperator::Lt => left.lt(right),
+            InequalityOperator::LtEq => left.lt_eq(right),
+            InequalityOperator::Gt => left.gt(right),
+            InequalityOperator::GtEq => left.gt_eq(right),
+        }
+    }
+}
+
+/// Options of an inequality join. The first operator relates the first pair of join
+/// columns, the optional second operator the second pair.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IEJoinOptions {
+    pub operator1: InequalityOperator,
+    pub operator2: Option<InequalityOperator>,
+}
+
+/// Find all pairs of rows for which `left <op> right` holds.
+///
+/// The right values are sorted once, so that the matches of every left value form a
+/// contiguous range that is found with a binary search. The output has the order of a
+/// cross join, i.e. it is ordered by the left rows and then by the right rows.
+fn range_join<T: PolarsNumericType>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    op: InequalityOperator,
+) -> (Vec<IdxSize>, Vec<IdxSize>) {
+    let n_valid = right.len() - right.null_count();
+    let sorted_idx = right.arg_sort(SortOptions {
+        nulls_last: true,
+        ..Default::default()
+    });
+    let sorted_idx = sorted_idx
+        .into_no_null_iter()
+        .take(n_valid)
+        .collect::<Vec<_>>();
+    let right = right.rechunk();
+    let right_values = right.downcast_iter().next().unwrap().values();
+    let sorted = sorted_idx
+        .iter()
+        // SAFETY: arg_sort only returns valid indices.
+        .map(|&i| unsafe { *right_values.get_unchecked(i as usize) })
+        .collect::<Vec<_>>();
+
+    let mut left_idx = vec![];
+    let mut right_idx = vec![];
+    for (i, opt_l) in left.iter().enumerate() {
+        // Null values never match.
+        if let Some(l) = opt_l {
+            let range = op.matching_range(&l, &sorted);
+            left_idx.extend(std::iter::repeat(i as IdxSize).take(range.len()));
+            let offset = right_idx.len();
+            right_idx.extend_from_slice(&sorted_idx[range]);
+            right_idx[offset..].sort_unstable();
+        }
+    }
+    (left_idx, right_idx)
+}
+
+fn cast_to_common_physical(left: &Series, right: &Series) -> PolarsResult<(Series, Series)> {
+    let dtype = try_get_supertype(left.dtype(), right.dtype())?;
+    polars_ensure!(
+        (dtype.is_numeric() || dtype.is_temporal()) && !matches!(dtype, DataType::Int128),
+        InvalidOperation: "inequality join is only supported on numeric/temporal keys, got `{}` and `{}`",
+        left.dtype(), right.dtype()
+    );
+    let left = left.cast(&dtype)?.to_physical_repr().into_owned();
+    let right = right.cast(&dtype)?.to_physical_repr().into_owned();
+    Ok((left, right))
+}
+
+/// Join the rows of `left` and `right` for which the inequalities in `options` hold
+/// between the `selected_left` and `selected_right` join columns.
+pub(super) fn iejoin(
+    left: &DataFrame,
+    right: &DataFrame,
+    selected_left: Vec<Series>,
+    selected_right: Vec<Series>,
+    options: &IEJoinOptions,
+    suffix: Option<&str>,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    let n_ops = 1 + options.operator2.is_some() as usize;
+    polars_ensure!(
+        selected_left.len() == n_ops && selected_right.len() == n_ops,
+        ComputeError: "inequality join with {} operator(s) expects as many join columns on both sides, got {} and {}",
+        n_ops, selected_left.len(), selected_right.len()
+    );
+
+    let (l1, r1) = cast_to_common_physical(&selected_left[0], &selected_right[0])?;
+    let (left_idx, right_idx) = with_match_physical_numeric_polars_type!(l1.dtype(), |$T| {
+        let l1: &ChunkedArray<$T> = l1.as_ref().as_ref().as_ref();
+        let r1: &ChunkedArray<$T> = r1.as_ref().as_ref().as_ref();
+        range_join(l1, r1, options.operator1)
+    });
+    let mut left_idx = IdxCa::from_vec("", left_idx);
+    let mut right_idx = IdxCa::from_vec("", right_idx);
+
+    // The candidates of the first inequality are filtered on the second one.
+    if let Some(op) = options.operator2 {
+        // SAFETY: the indices were created from the join columns.
+        let l2 = unsafe { selected_left[1].take_unchecked(&left_idx) };
+        let r2 = unsafe { selected_right[1].take_unchecked(&right_idx) };
+        let mask = op.compare(&l2, &r2)?;
+        left_idx = left_idx.filter(&mask)?;
+        right_idx = right_idx.filter(&mask)?;
+    }
+
+    if let Some((offset, len)) = slice {
+        left_idx = left_idx.slice(offset, len);
+        right_idx = right_idx.slice(offset, len);
+    }
+
+    // SAFETY: the indices were created from the join columns.
+    let (df_left, df_right) = POOL.install(|| {
+        rayon::join(
+            || unsafe { left.take_unchecked(&left_idx) },
+            || unsafe { right.take_unchecked(&right_idx) },
+        )
+    });
+    _finish_join(df_left, df_right, suffix)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_range_join() {
+        let left = Int32Chunked::new("a", &[Some(2), None, Some(5)]);
+        let right = Int32Chunked::new("b", &[Some(5), Some(1), None, Some(2)]);
+
+        let (l, r) = range_join(&left, &right, InequalityOperator::Lt);
+        assert_eq!((l, r), (vec![0], vec![0]));
+        let (l, r) = range_join(&left, &right, InequalityOperator::LtEq);
+        assert_eq!((l, r), (vec![0, 0, 2], vec![0, 3, 0]));
+        let (l, r) = range_join(&left, &right, InequalityOperator::Gt);
+        assert_eq!((l, r), (vec![0, 2, 2], vec![1, 1, 3]));
+        let (l, r) = range_join(&left, &right, InequalityOperator::GtEq);
+        assert_eq!((l, r), (vec![0, 0, 2, 2, 2], vec![1, 3, 0, 1, 3]));
+    }
+
+    #[test]
+    fn test_iejoin_between() -> PolarsResult<()> {
+        let left = df!("t" => [1, 4, 7])?;
+        let right = df!(
+            "start" => [0, 3, 5],
+            "end" => [2, 6, 9]
+        )?;
+        // start <= t and t < end
+        let options = IEJoinOptions {
+            operator1: InequalityOperator::GtEq,
+            operator2: Some(InequalityOperator::Lt),
+        };
+        let out = iejoin(
+            &left,
+            &right,
+            vec![left.column("t")?.clone(), left.column("t")?.clone()],
+            vec![right.column("start")?.clone(), right.column("end")?.clone()],
+            &options,
+            None,
+            None,
+        )?;
+        let expected = df!(
+            "t" => [1, 4, 7],
+            "start" => [0, 3, 5],
+            "end" => [2, 6, 9]
+        )?;
+        assert!(out.equals(&expected));
+        Ok(())
+    }
+}
@@ -6,8 +6,12 @@ mod checks;
 mod cross_join;
 mod general;
 mod hash_join;
+#[cfg(feature = "iejoin")]
+mod iejoin;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
+#[cfg(feature = "upsert")]
+mod upsert;
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
@@ -28,6 +32,8 @@ use general::create_chunked_index_mapping;
 pub use general::{_coalesce_full_join, _finish_join, _join_suffix_name};
 pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
+#[cfg(feature = "iejoin")]
+pub use iejoin::{IEJoinOptions, InequalityOperator};
 #[cfg(feature = "merge_sorted")]
 pub use merge_sorted::_merge_sorted_dfs;
 use polars_core::hashing::_HASHMAP_INIT_SIZE;
@@ -43,6 +49,8 @@ use polars_core::utils::slice_slice;
 use polars_core::POOL;
 use polars_utils::hashing::BytesHash;
 use rayon::prelude::*;
+#[cfg(feature = "upsert")]
+pub use upsert::UpsertStrategy;
 
 use super::IntoDf;
 
@@ -117,6 +125,19 @@ pub trait DataFrameJoinOps: IntoDf {
             return left_df.cross_join(other, args.suffix.as_deref(), args.slice);
         }
 
+        #[cfg(feature = "iejoin")]
+        if let JoinType::IEJoin(options) = &args.how {
+            return iejoin::iejoin(
+                left_df,
+                other,
+                selected_left,
+                selected_right,
+                options,
+                args.suffix.as_deref(),
+                args.slice,
+            );
+        }
+
         // Clear literals if a frame is empty. Otherwise we could get an oob
         fn clear(s: &mut [Series]) {
             for s in s.iter_mut() {
@@ -201,6 +222,17 @@ pub trait DataFrameJoinOps: IntoDf {
             }
         }
 
+        // Asof joins compare the keys with a tolerance, which the order keys don't keep.
+        #[cfg(feature = "asof_join")]
+        let float_total_order = args.float_total_order && !matches!(args.how, JoinType::AsOf(_));
+        #[cfg(not(feature = "asof_join"))]
+        let float_total_order = args.float_total_order;
+        if float_total_order {
+            for s in selected_left.iter_mut().chain(selected_right.iter_mut()) {
+                *s = s.float_total_order_keys();
+            }
+        }
+
         // Single keys.
         if selected_left.len() == 1 {
             let s_left = &selected_left[0];
@@ -259,6 +291,10 @@ pub trait DataFrameJoinOps: IntoDf {
                 JoinType::Cross => {
                     unreachable!()
                 },
+                #[cfg(feature = "iejoin")]
+                JoinType::IEJoin(_) => {
+                    unreachable!()
+                },
             };
         }
 
@@ -280,6 +316,10 @@ pub trait DataFrameJoinOps: IntoDf {
             JoinType::Cross => {
                 unreachable!()
             },
+            #[cfg(feature = "iejoin")]
+            JoinType::IEJoin(_) => {
+                unreachable!()
+            },
             JoinType::Full => {
                 let names_left = selected_left.iter().map(|s| s.name()).collect::<Vec<_>>();
                 args.coalesce = JoinCoalesce::KeepColumns;
@@ -394,6 +434,10 @@ pub trait DataFrameJoinOps: IntoDf {
     }
 
     /// Perform a full outer join on two DataFrames
+    ///
+    /// The key columns of both DataFrames are kept. Use [`join`](DataFrameJoinOps::join)
+    /// with [`JoinCoalesce::CoalesceColumns`] to merge them into a single key column.
+    ///
     /// # Example
     ///
     /// ```
@@ -410,6 +454,40 @@ pub trait DataFrameJoinOps: IntoDf {
     {
         self.join(other, left_on, right_on, JoinArgs::new(JoinType::Full))
     }
+
+    /// Update the rows that match a row of `other` on the `on` columns and append the rows
+    /// of `other` that don't match any row.
+    ///
+    /// Columns of `other` are cast to the dtypes of the [`DataFrame`] and columns that `other`
+    /// lacks are kept as is for updated rows and null for appended rows. The keys of `other`
+    /// must be unique.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn upsert_prices(prices: &DataFrame, updates: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     prices.upsert(updates, ["product"], UpsertStrategy::Coalesce)
+    /// }
+    /// ```
+    #[cfg(feature = "upsert")]
+    fn upsert<I, S>(
+        &self,
+        other: &DataFrame,
+        on: I,
+        strategy: UpsertStrategy,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let on = on
+            .into_iter()
+            .map(|s| s.as_ref().to_string())
+            .collect::<Vec<_>>();
+        upsert::upsert(self.to_df(), other, &on, strategy)
+    }
 }
 
 trait DataFrameJoinOpsPrivate: IntoDf {
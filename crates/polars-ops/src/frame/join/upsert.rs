@@ -0,0 +1,135 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+const LEFT_INDEX: &str = "__POLARS_UPSERT_LEFT_INDEX";
+const RIGHT_INDEX: &str = "__POLARS_UPSERT_RIGHT_INDEX";
+
+/// How the values of a row are updated by a matching row in an upsert.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UpsertStrategy {
+    /// Replace the values by those of the matching row, including its nulls.
+    #[default]
+    Overwrite,
+    /// Replace the values by the non-null values of the matching row.
+    Coalesce,
+}
+
+pub(super) fn upsert(
+    left: &DataFrame,
+    other: &DataFrame,
+    on: &[String],
+    strategy: UpsertStrategy,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(!on.is_empty(), InvalidOperation: "upsert needs at least one key column");
+    let schema = left.schema();
+    for name in other.get_column_names() {
+        polars_ensure!(
+            schema.contains(name),
+            ColumnNotFound: "column '{}' of the upserted DataFrame is not in the DataFrame", name
+        );
+    }
+
+    // Match the rows on their keys. The keys of `other` must be unique, so that every row of
+    // `left` is updated by at most one row.
+    let left_keys = left.select(on)?.with_row_index(LEFT_INDEX, None)?;
+    let right_keys = other.select(on)?.with_row_index(RIGHT_INDEX, None)?;
+    let mut args = JoinArgs::new(JoinType::Inner);
+    args.validation = JoinValidation::ManyToOne;
+    let matches = left_keys.join(&right_keys, on, on, args)?;
+
+    let mut right_idx = vec![None; left.height()];
+    let mut matched = vec![false; other.height()];
+    let left_matches = matches.column(LEFT_INDEX)?.idx()?;
+    let right_matches = matches.column(RIGHT_INDEX)?.idx()?;
+    for (l, r) in left_matches
+        .into_no_null_iter()
+        .zip(right_matches.into_no_null_iter())
+    {
+        right_idx[l as usize] = Some(r);
+        matched[r as usize] = true;
+    }
+    let right_idx = IdxCa::from_iter_options("", right_idx.into_iter());
+    let is_match = right_idx.is_not_null();
+
+    let columns = left
+        .get_columns()
+        .iter()
+        .map(|s| {
+            let name = s.name();
+            if on.iter().any(|key| key == name) {
+                return Ok(s.clone());
+            }
+            let Ok(update) = other.column(name) else {
+                return Ok(s.clone());
+            };
+            let update = update.strict_cast(s.dtype())?.take(&right_idx)?;
+            let mask = match strategy {
+                UpsertStrategy::Overwrite => is_match.clone(),
+                UpsertStrategy::Coalesce => update.is_not_null(),
+            };
+            update.zip_with(&mask, s)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let mut out = unsafe { DataFrame::new_no_checks(columns) };
+
+    // Append the rows of `other` that didn't match, with nulls for the columns it lacks.
+    let unmatched = (0..other.height() as IdxSize)
+        .filter(|&i| !matched[i as usize])
+        .collect::<Vec<_>>();
+    if !unmatched.is_empty() {
+        let rows = other.take(&IdxCa::from_vec("", unmatched))?;
+        let columns = schema
+            .iter()
+            .map(|(name, dtype)| match rows.column(name.as_str()) {
+                Ok(s) => s.strict_cast(dtype),
+                Err(_) => Ok(Series::full_null(name.as_str(), rows.height(), dtype)),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        out.vstack_mut(&unsafe { DataFrame::new_no_checks(columns) })?;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_upsert() -> PolarsResult<()> {
+        let df = df!(
+            "id" => [1, 2, 3],
+            "a" => [Some(10), Some(20), Some(30)],
+            "b" => ["x", "y", "z"]
+        )?;
+        let other = df!(
+            "id" => [4, 2, 3],
+            "a" => [Some(40), None, Some(33)]
+        )?;
+
+        let out = df.upsert(&other, ["id"], UpsertStrategy::Overwrite)?;
+        let expected = df!(
+            "id" => [1, 2, 3, 4],
+            "a" => [Some(10), None, Some(33), Some(40)],
+            "b" => [Some("x"), Some("y"), Some("z"), None]
+        )?;
+        assert!(out.equals_missing(&expected));
+
+        let out = df.upsert(&other, ["id"], UpsertStrategy::Coalesce)?;
+        let expected = df!(
+            "id" => [1, 2, 3, 4],
+            "a" => [Some(10), Some(20), Some(33), Some(40)],
+            "b" => [Some("x"), Some("y"), Some("z"), None]
+        )?;
+        assert!(out.equals_missing(&expected));
+
+        // The keys of `other` must be unique.
+        let other = df!("id" => [2, 2], "a" => [1, 2])?;
+        assert!(df
+            .upsert(&other, ["id"], UpsertStrategy::Overwrite)
+            .is_err());
+        Ok(())
+    }
+}
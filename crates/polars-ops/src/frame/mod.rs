@@ -1,7 +1,11 @@
+#[cfg(feature = "cov")]
+mod corr;
 pub mod join;
 #[cfg(feature = "pivot")]
 pub mod pivot;
 
+#[cfg(feature = "cov")]
+pub use corr::CorrelationMatrixMethod;
 pub use join::*;
 #[cfg(feature = "to_dummies")]
 use polars_core::export::rayon::prelude::*;
@@ -111,4 +115,20 @@ pub trait DataFrameOps: IntoDf {
 
         accumulate_dataframes_horizontal(cols)
     }
+
+    /// Compute the correlation matrix of the numeric columns.
+    ///
+    /// The result has a `column` column with the names of the numeric columns, followed by a
+    /// `Float64` column per numeric column with its correlation to every other one.
+    #[cfg(feature = "cov")]
+    fn corr(&self, method: CorrelationMatrixMethod) -> PolarsResult<DataFrame> {
+        corr::correlation_matrix(self.to_df(), method)
+    }
+
+    /// Compute the covariance matrix of the numeric columns, laid out like the correlation
+    /// matrix of [`DataFrameOps::corr`].
+    #[cfg(feature = "cov")]
+    fn cov(&self, ddof: u8) -> PolarsResult<DataFrame> {
+        corr::covariance_matrix(self.to_df(), ddof)
+    }
 }
@@ -22,20 +22,24 @@ use std::marker::PhantomData;
 
 use polars_core::export::ahash::RandomState;
 
-/// The greater is P, the smaller the error.
-const HLL_P: usize = 14_usize;
-/// The number of bits of the hash value used determining the number of leading zeros
-const HLL_Q: usize = 64_usize - HLL_P;
-const NUM_REGISTERS: usize = 1_usize << HLL_P;
-/// Mask to obtain index into the registers
-const HLL_P_MASK: u64 = (NUM_REGISTERS as u64) - 1;
+/// The default precision, i.e. the base-2 logarithm of the number of registers. The
+/// greater the precision, the smaller the error.
+pub const HLL_DEFAULT_PRECISION: u8 = 14;
+/// The smallest supported precision.
+pub const HLL_MIN_PRECISION: u8 = 4;
+/// The largest supported precision.
+pub const HLL_MAX_PRECISION: u8 = 18;
+#[cfg(test)]
+const NUM_REGISTERS: usize = 1_usize << HLL_DEFAULT_PRECISION;
 
 #[derive(Clone, Debug)]
 pub struct HyperLogLog<T>
 where
     T: Hash + ?Sized,
 {
-    registers: [u8; NUM_REGISTERS],
+    registers: Vec<u8>,
+    /// The number of bits of the hash value used to index into the registers.
+    precision: u8,
     phantom: PhantomData<T>,
 }
 
@@ -67,20 +71,37 @@ where
 {
     /// Creates a new, empty HyperLogLog.
     pub fn new() -> Self {
-        let registers = [0; NUM_REGISTERS];
-        Self::new_with_registers(registers)
+        Self::with_precision(HLL_DEFAULT_PRECISION)
     }
 
-    /// Creates a HyperLogLog from already populated registers
-    /// note that this method should not be invoked in untrusted environment
-    /// because the internal structure of registers are not examined.
-    pub(crate) fn new_with_registers(registers: [u8; NUM_REGISTERS]) -> Self {
+    /// Creates a new, empty HyperLogLog with `2^precision` registers. Its relative error
+    /// is about `1.04 / sqrt(2^precision)`.
+    ///
+    /// # Panics
+    /// Panics if the precision is not between [`HLL_MIN_PRECISION`] and
+    /// [`HLL_MAX_PRECISION`].
+    pub fn with_precision(precision: u8) -> Self {
+        assert!(
+            (HLL_MIN_PRECISION..=HLL_MAX_PRECISION).contains(&precision),
+            "precision must be between {HLL_MIN_PRECISION} and {HLL_MAX_PRECISION}, got {precision}"
+        );
         Self {
-            registers,
+            registers: vec![0; 1 << precision],
+            precision,
             phantom: PhantomData,
         }
     }
 
+    /// The base-2 logarithm of the number of registers.
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Resets the HyperLogLog to be empty, keeping its registers allocated.
+    pub fn clear(&mut self) {
+        self.registers.fill(0);
+    }
+
     /// choice of hash function: ahash is already an dependency
     /// and it fits the requirements of being a 64bit hash with
     /// reasonable performance.
@@ -92,19 +113,25 @@ where
     /// Adds an element to the HyperLogLog.
     pub fn add(&mut self, obj: &T) {
         let hash = self.hash_value(obj);
-        let index = (hash & HLL_P_MASK) as usize;
-        let p = ((hash >> HLL_P) | (1_u64 << HLL_Q)).trailing_zeros() + 1;
+        let mask = (1_u64 << self.precision) - 1;
+        let index = (hash & mask) as usize;
+        let p = ((hash >> self.precision) | (1_u64 << self.q())).trailing_zeros() + 1;
         self.registers[index] = self.registers[index].max(p as u8);
     }
 
+    /// The number of bits of the hash value used determining the number of leading zeros.
+    #[inline]
+    fn q(&self) -> usize {
+        64 - self.precision as usize
+    }
+
     /// Get the register histogram (each value in register index into
-    /// the histogram; u32 is enough because we only have 2**14=16384 registers
+    /// the histogram; u32 is enough because we have at most 2**18 registers
     #[inline]
-    fn get_histogram(&self) -> [u32; HLL_Q + 2] {
-        let mut histogram = [0; HLL_Q + 2];
-        // hopefully this can be unrolled
-        for r in self.registers {
-            histogram[r as usize] += 1;
+    fn get_histogram(&self) -> Vec<u32> {
+        let mut histogram = vec![0; self.q() + 2];
+        for r in &self.registers {
+            histogram[*r as usize] += 1;
         }
         histogram
     }
@@ -125,9 +152,10 @@ where
     /// Guess the number of unique elements seen by the HyperLogLog.
     pub fn count(&self) -> usize {
         let histogram = self.get_histogram();
-        let m = NUM_REGISTERS as f64;
-        let mut z = m * hll_tau((m - histogram[HLL_Q + 1] as f64) / m);
-        for i in histogram[1..=HLL_Q].iter().rev() {
+        let q = self.q();
+        let m = self.registers.len() as f64;
+        let mut z = m * hll_tau((m - histogram[q + 1] as f64) / m);
+        for i in histogram[1..=q].iter().rev() {
             z += *i as f64;
             z *= 0.5;
         }
@@ -217,7 +245,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{HyperLogLog, NUM_REGISTERS};
+    use super::{HyperLogLog, HLL_MAX_PRECISION, HLL_MIN_PRECISION, NUM_REGISTERS};
 
     fn compare_with_delta(got: usize, expected: usize) {
         let expected = expected as f64;
@@ -342,6 +370,24 @@ mod tests {
         compare_with_delta(hll.count(), 1000);
     }
 
+    #[test]
+    fn test_precision() {
+        for precision in [HLL_MIN_PRECISION, 10, HLL_MAX_PRECISION] {
+            let mut hll = HyperLogLog::<u32>::with_precision(precision);
+            hll.extend(0..10_000);
+            let margin = 1.04 / ((1_u32 << precision) as f64).sqrt() * 6.0;
+            let diff = (hll.count() as f64 - 10_000.0).abs() / 10_000.0;
+            assert!(
+                diff <= margin,
+                "precision: {precision}, count: {}",
+                hll.count()
+            );
+
+            hll.clear();
+            assert_eq!(hll.count(), 0);
+        }
+    }
+
     #[test]
     fn test_repetition() {
         let mut hll = HyperLogLog::<u32>::new();
@@ -0,0 +1,201 @@
+//! # TDigest
+//!
+//! `tdigest` module contains an implementation of the merging t-digest of Dunning and Ertl,
+//! so that [`crate::series::quantile_approx`] can be computed in a single pass.
+//!
+//! A t-digest summarizes a distribution in a bounded number of weighted centroids. The
+//! centroids are small at the tails of the distribution, which keeps extreme quantiles
+//! accurate. Digests of parts of the data can be merged into a digest of all of it.
+//!
+//! # Examples
+//!
+//! ```
+//!     # use polars_ops::prelude::*;
+//!     let digest = TDigest::from_values((1..=100).map(|v| v as f64));
+//!     let other = TDigest::from_values((101..=200).map(|v| v as f64));
+//!     let digest = TDigest::merge([digest, other]);
+//!
+//!     assert_eq!(digest.quantile(0.0), Some(1.0));
+//!     assert_eq!(digest.quantile(1.0), Some(200.0));
+//! ```
+
+use std::f64::consts::PI;
+
+/// The greater the compression, the more centroids are kept and the smaller the error.
+const COMPRESSION: f64 = 200.0;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+impl Centroid {
+    fn add(&mut self, other: &Centroid) {
+        self.weight += other.weight;
+        self.mean += (other.mean - self.mean) * other.weight / self.weight;
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TDigest {
+    /// Centroids ordered by their mean.
+    centroids: Vec<Centroid>,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+/// The scale function that bounds the size of the centroids, which maps a quantile to an
+/// index such that a centroid spans at most one unit of index.
+fn scale(q: f64) -> f64 {
+    COMPRESSION / (2.0 * PI) * (2.0 * q - 1.0).asin()
+}
+
+fn scale_inverse(k: f64) -> f64 {
+    let k = k.min(COMPRESSION / 4.0);
+    ((k * 2.0 * PI / COMPRESSION).sin() + 1.0) / 2.0
+}
+
+impl TDigest {
+    /// Creates a digest of `values`. NaN values are ignored.
+    pub fn from_values<I: IntoIterator<Item = f64>>(values: I) -> Self {
+        let mut centroids = values
+            .into_iter()
+            .filter(|v| !v.is_nan())
+            .map(|mean| Centroid { mean, weight: 1.0 })
+            .collect::<Vec<_>>();
+        centroids.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+        Self::from_sorted_centroids(centroids)
+    }
+
+    /// Merges `digests` into a digest of all of their values.
+    pub fn merge<I: IntoIterator<Item = TDigest>>(digests: I) -> Self {
+        let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+        let mut centroids = vec![];
+        for digest in digests.into_iter().filter(|d| d.count > 0.0) {
+            min = min.min(digest.min);
+            max = max.max(digest.max);
+            centroids.extend(digest.centroids);
+        }
+        centroids.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let mut out = Self::from_sorted_centroids(centroids);
+        // The centroids at the extremes may have absorbed the minimum and maximum.
+        if out.count > 0.0 {
+            out.min = min;
+            out.max = max;
+        }
+        out
+    }
+
+    fn from_sorted_centroids(centroids: Vec<Centroid>) -> Self {
+        let Some(first) = centroids.first() else {
+            return Self::default();
+        };
+        let min = first.mean;
+        let max = centroids.last().unwrap().mean;
+        let count = centroids.iter().map(|c| c.weight).sum::<f64>();
+
+        let mut out = Vec::with_capacity((COMPRESSION as usize).min(centroids.len()));
+        let mut current = *first;
+        let mut weight_so_far = 0.0;
+        let mut weight_limit = count * scale_inverse(scale(0.0) + 1.0);
+        for c in &centroids[1..] {
+            if weight_so_far + current.weight + c.weight <= weight_limit {
+                current.add(c);
+            } else {
+                weight_so_far += current.weight;
+                out.push(current);
+                weight_limit = count * scale_inverse(scale(weight_so_far / count) + 1.0);
+                current = *c;
+            }
+        }
+        out.push(current);
+
+        Self {
+            centroids: out,
+            count,
+            min,
+            max,
+        }
+    }
+
+    /// The number of values in the digest.
+    pub fn count(&self) -> f64 {
+        self.count
+    }
+
+    /// Estimates the `q`-quantile of the values, interpolating linearly between the
+    /// centroids. Returns `None` if the digest is empty.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        let centroids = &self.centroids;
+        let first = centroids.first()?;
+        if centroids.len() == 1 {
+            return Some(first.mean);
+        }
+
+        let target = q.clamp(0.0, 1.0) * self.count;
+        // The values of a centroid are assumed to be centered around its mean, so below the
+        // center of the first centroid we interpolate towards the minimum.
+        if target < first.weight / 2.0 {
+            let fraction = target / (first.weight / 2.0);
+            return Some(self.min + (first.mean - self.min) * fraction);
+        }
+
+        let mut weight_so_far = 0.0;
+        for w in centroids.windows(2) {
+            let (left, right) = (&w[0], &w[1]);
+            let left_center = weight_so_far + left.weight / 2.0;
+            let right_center = weight_so_far + left.weight + right.weight / 2.0;
+            if target <= right_center {
+                let fraction = (target - left_center) / (right_center - left_center);
+                return Some(left.mean + (right.mean - left.mean) * fraction);
+            }
+            weight_so_far += left.weight;
+        }
+
+        let last = centroids.last().unwrap();
+        let last_center = self.count - last.weight / 2.0;
+        let fraction = ((target - last_center) / (last.weight / 2.0)).min(1.0);
+        Some(last.mean + (self.max - last.mean) * fraction)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tdigest_small() {
+        let digest = TDigest::from_values([5.0, 1.0, 3.0, f64::NAN, 2.0, 4.0]);
+        assert_eq!(digest.count(), 5.0);
+        assert_eq!(digest.quantile(0.0), Some(1.0));
+        assert_eq!(digest.quantile(0.5), Some(3.0));
+        assert_eq!(digest.quantile(1.0), Some(5.0));
+        assert_eq!(TDigest::from_values([]).quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_tdigest_merge() {
+        let n = 100_000;
+        let digests = (0..10).map(|part| {
+            // Every part holds every tenth value.
+            TDigest::from_values((0..n / 10).map(|i| (i * 10 + part) as f64))
+        });
+        let digest = TDigest::merge(digests);
+        assert_eq!(digest.count(), n as f64);
+        assert!(digest.centroids.len() <= COMPRESSION as usize);
+        assert_eq!(digest.quantile(0.0), Some(0.0));
+        assert_eq!(digest.quantile(1.0), Some((n - 1) as f64));
+
+        for q in [0.001, 0.01, 0.25, 0.5, 0.75, 0.99, 0.999] {
+            let expected = q * (n - 1) as f64;
+            let estimate = digest.quantile(q).unwrap();
+            assert!(
+                (estimate - expected).abs() <= 0.005 * n as f64,
+                "q: {q}, estimate: {estimate}, expected: {expected}"
+            );
+        }
+    }
+}
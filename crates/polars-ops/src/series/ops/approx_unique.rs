@@ -5,45 +5,66 @@ use polars_core::with_match_physical_integer_polars_type;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
 
 #[cfg(feature = "approx_unique")]
-use crate::series::ops::approx_algo::HyperLogLog;
+use crate::series::ops::approx_algo::{
+    HyperLogLog, HLL_DEFAULT_PRECISION, HLL_MAX_PRECISION, HLL_MIN_PRECISION,
+};
 
-fn approx_n_unique_ca<'a, T>(ca: &'a ChunkedArray<T>) -> PolarsResult<Series>
+fn approx_n_unique_ca<'a, T>(ca: &'a ChunkedArray<T>, offsets: &[i64], precision: u8) -> IdxCa
 where
     T: PolarsDataType,
     T::Physical<'a>: TotalHash + TotalEq + Copy + ToTotalOrd,
     <Option<T::Physical<'a>> as ToTotalOrd>::TotalOrdItem: Hash + Eq,
 {
-    let mut hllp = HyperLogLog::new();
-    ca.iter().for_each(|item| hllp.add(&item.to_total_ord()));
-    let c = hllp.count() as IdxSize;
-
-    Ok(Series::new(ca.name(), &[c]))
+    // The registers are reused for all groups.
+    let mut hllp = HyperLogLog::with_precision(precision);
+    let mut iter = ca.iter().skip(offsets.first().map_or(0, |o| *o as usize));
+    let counts = offsets
+        .windows(2)
+        .map(|w| {
+            hllp.clear();
+            (&mut iter)
+                .take((w[1] - w[0]) as usize)
+                .for_each(|item| hllp.add(&item.to_total_ord()));
+            hllp.count() as IdxSize
+        })
+        .collect::<Vec<_>>();
+    IdxCa::from_vec(ca.name(), counts)
 }
 
-fn dispatcher(s: &Series) -> PolarsResult<Series> {
+fn dispatcher(s: &Series, offsets: &[i64], precision: u8) -> PolarsResult<Series> {
+    polars_ensure!(
+        (HLL_MIN_PRECISION..=HLL_MAX_PRECISION).contains(&precision),
+        ComputeError: "`precision` should be between {} and {}, got {}",
+        HLL_MIN_PRECISION, HLL_MAX_PRECISION, precision
+    );
     let s = s.to_physical_repr();
     use DataType::*;
-    match s.dtype() {
-        Boolean => s.bool().and_then(approx_n_unique_ca),
-        Binary => s.binary().and_then(approx_n_unique_ca),
+    let out = match s.dtype() {
+        Boolean => approx_n_unique_ca(s.bool().unwrap(), offsets, precision),
+        Binary => approx_n_unique_ca(s.binary().unwrap(), offsets, precision),
         String => {
             let ca = s.str().unwrap().as_binary();
-            approx_n_unique_ca(&ca)
+            approx_n_unique_ca(&ca, offsets, precision)
         },
-        Float32 => approx_n_unique_ca(AsRef::<ChunkedArray<Float32Type>>::as_ref(
-            s.as_ref().as_ref(),
-        )),
-        Float64 => approx_n_unique_ca(AsRef::<ChunkedArray<Float64Type>>::as_ref(
-            s.as_ref().as_ref(),
-        )),
+        Float32 => approx_n_unique_ca(
+            AsRef::<ChunkedArray<Float32Type>>::as_ref(s.as_ref().as_ref()),
+            offsets,
+            precision,
+        ),
+        Float64 => approx_n_unique_ca(
+            AsRef::<ChunkedArray<Float64Type>>::as_ref(s.as_ref().as_ref()),
+            offsets,
+            precision,
+        ),
         dt if dt.is_numeric() => {
             with_match_physical_integer_polars_type!(s.dtype(), |$T| {
                 let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-                approx_n_unique_ca(ca)
+                approx_n_unique_ca(ca, offsets, precision)
             })
         },
         dt => polars_bail!(opq = approx_n_unique, dt),
-    }
+    };
+    Ok(out.into_series())
 }
 
 /// Approx count unique values.
@@ -74,5 +95,34 @@ fn dispatcher(s: &Series) -> PolarsResult<Series> {
 /// ]
 /// ```
 pub fn approx_n_unique(s: &Series) -> PolarsResult<Series> {
-    dispatcher(s)
+    approx_n_unique_with_precision(s, HLL_DEFAULT_PRECISION)
+}
+
+/// Approx count unique values with a HyperLogLog of `2^precision` registers.
+///
+/// The relative error is about `1.04 / sqrt(2^precision)`, so a lower precision trades
+/// accuracy for memory and speed.
+pub fn approx_n_unique_with_precision(s: &Series, precision: u8) -> PolarsResult<Series> {
+    dispatcher(s, &[0, s.len() as i64], precision)
+}
+
+/// Approx count unique values of every group of `s` in a single pass. The groups are given
+/// by the `offsets` into the values of `s`.
+pub fn approx_n_unique_groups(s: &Series, offsets: &[i64], precision: u8) -> PolarsResult<Series> {
+    dispatcher(s, offsets, precision)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_approx_n_unique_groups() {
+        let s = Series::new("a", &[Some(1), Some(2), Some(1), None, Some(3), Some(3)]);
+        let out = approx_n_unique_groups(&s, &[0, 3, 3, 6], 10).unwrap();
+        assert_eq!(out.dtype(), &IDX_DTYPE);
+        assert_eq!(out.idx().unwrap().to_vec(), &[Some(2), Some(0), Some(2)]);
+
+        assert!(approx_n_unique_with_precision(&s, 3).is_err());
+    }
 }
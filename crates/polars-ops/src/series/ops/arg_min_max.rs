@@ -112,6 +112,83 @@ impl ArgAgg for Series {
     }
 }
 
+/// Get the index of the minimal value of every group of `s`, relative to the start of the
+/// group. The groups are given by the `offsets` into the values of `s`. Groups without valid
+/// values get a null index.
+pub fn arg_min_groups(s: &Series, offsets: &[i64]) -> IdxCa {
+    arg_min_max_groups(s, offsets, false)
+}
+
+/// Get the index of the maximal value of every group of `s`, relative to the start of the
+/// group. The groups are given by the `offsets` into the values of `s`. Groups without valid
+/// values get a null index.
+pub fn arg_max_groups(s: &Series, offsets: &[i64]) -> IdxCa {
+    arg_min_max_groups(s, offsets, true)
+}
+
+fn arg_min_max_groups(s: &Series, offsets: &[i64], max: bool) -> IdxCa {
+    let dtype = s.dtype();
+    let phys = s.to_physical_repr();
+    let out = if (dtype.is_numeric() || dtype.is_temporal())
+        && !matches!(phys.dtype(), DataType::Int128)
+    {
+        with_match_physical_numeric_polars_type!(phys.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = phys.as_ref().as_ref().as_ref();
+            arg_min_max_groups_numeric(ca, offsets, max)
+        })
+    } else {
+        // Other types dispatch to the kernels of a whole series per group.
+        offsets
+            .windows(2)
+            .map(|w| {
+                let group = s.slice(w[0], (w[1] - w[0]) as usize);
+                let idx = if max {
+                    group.arg_max()
+                } else {
+                    group.arg_min()
+                };
+                idx.map(|idx| idx as IdxSize)
+            })
+            .collect()
+    };
+    out.with_name(s.name())
+}
+
+fn arg_min_max_groups_numeric<T>(ca: &ChunkedArray<T>, offsets: &[i64], max: bool) -> IdxCa
+where
+    T: PolarsNumericType,
+    for<'b> &'b [T::Native]: ArgMinMax,
+{
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let values = arr.values().as_slice();
+    let is_better = |acc: T::Native, v: T::Native| if max { acc < v } else { acc > v };
+
+    offsets
+        .windows(2)
+        .map(|w| {
+            let (start, end) = (w[0] as usize, w[1] as usize);
+            let idx = match arr.validity().filter(|_| arr.null_count() > 0) {
+                _ if start == end => None,
+                None if max => Some((&values[start..end]).argmax()),
+                None => Some((&values[start..end]).argmin()),
+                // On ties the first index wins, as with the kernels of a whole series.
+                Some(validity) => (start..end)
+                    .filter(|i| validity.get_bit(*i))
+                    .reduce(|acc, i| {
+                        if is_better(values[acc], values[i]) {
+                            i
+                        } else {
+                            acc
+                        }
+                    })
+                    .map(|i| i - start),
+            };
+            idx.map(|idx| idx as IdxSize)
+        })
+        .collect()
+}
+
 fn arg_max_numeric_dispatch<T>(ca: &ChunkedArray<T>) -> Option<usize>
 where
     T: PolarsNumericType,
@@ -352,3 +429,24 @@ where
         IsSorted::Not => Some(vals.argmax()), // assumes not empty
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arg_min_max_groups() {
+        let s = Series::new("a", &[Some(3), None, Some(1), Some(3), None, None, Some(2)]);
+        let offsets = [0, 4, 6, 6, 7];
+        let expected = [Some(2), None, None, Some(0)];
+        assert_eq!(Vec::from(&arg_min_groups(&s, &offsets)), expected);
+        let expected = [Some(0), None, None, Some(0)];
+        assert_eq!(Vec::from(&arg_max_groups(&s, &offsets)), expected);
+
+        let s = s.cast(&DataType::String).unwrap();
+        let expected = [Some(2), None, None, Some(0)];
+        assert_eq!(Vec::from(&arg_min_groups(&s, &offsets)), expected);
+        let expected = [Some(0), None, None, Some(0)];
+        assert_eq!(Vec::from(&arg_max_groups(&s, &offsets)), expected);
+    }
+}
@@ -27,20 +27,45 @@ pub enum Roll {
 /// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
 /// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
 ///   days since the UNIX epoch.
+/// - `row_holidays`: optional `List(Date)` Series with further holidays for every row.
 pub fn business_day_count(
     start: &Series,
     end: &Series,
     week_mask: [bool; 7],
     holidays: &[i32],
+    row_holidays: Option<&Series>,
 ) -> PolarsResult<Series> {
     if !week_mask.iter().any(|&x| x) {
         polars_bail!(ComputeError:"`week_mask` must have at least one business day");
     }
+    let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
+
+    if let Some(row_holidays) = row_holidays {
+        let len = broadcast_len(&[start, end, row_holidays])?;
+        let start = broadcast(start, len);
+        let end = broadcast(end, len);
+        let holidays = holidays_per_row(holidays, &broadcast(row_holidays, len), &week_mask)?;
+        let out: Int32Chunked = start
+            .date()?
+            .into_iter()
+            .zip(&end.date()?.0)
+            .zip(holidays.iter())
+            .map(|((start_date, end_date), holidays)| {
+                Some(business_day_count_impl(
+                    start_date?,
+                    end_date?,
+                    &week_mask,
+                    n_business_days_in_week_mask,
+                    holidays,
+                ))
+            })
+            .collect();
+        return Ok(out.with_name(start.name()).into_series());
+    }
 
     let holidays = normalise_holidays(holidays, &week_mask);
     let start_dates = start.date()?;
     let end_dates = end.date()?;
-    let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
 
     let out = match (start_dates.len(), end_dates.len()) {
         (_, 1) => {
@@ -137,12 +162,14 @@ fn business_day_count_impl(
 ///   - `Roll::Forward`: roll forward to the next business day.
 ///   - `Roll::Backward`: roll backward to the previous business day.
 ///   - `Roll::Raise`: raise an error.
+/// - `row_holidays`: optional `List(Date)` Series with further holidays for every row.
 pub fn add_business_days(
     start: &Series,
     n: &Series,
     week_mask: [bool; 7],
     holidays: &[i32],
     roll: Roll,
+    row_holidays: Option<&Series>,
 ) -> PolarsResult<Series> {
     if !week_mask.iter().any(|&x| x) {
         polars_bail!(ComputeError:"`week_mask` must have at least one business day");
@@ -152,8 +179,14 @@ pub fn add_business_days(
         DataType::Date => {},
         #[cfg(feature = "dtype-datetime")]
         DataType::Datetime(time_unit, None) => {
-            let result_date =
-                add_business_days(&start.cast(&DataType::Date)?, n, week_mask, holidays, roll)?;
+            let result_date = add_business_days(
+                &start.cast(&DataType::Date)?,
+                n,
+                week_mask,
+                holidays,
+                roll,
+                row_holidays,
+            )?;
             let start_time = start
                 .cast(&DataType::Time)?
                 .cast(&DataType::Duration(*time_unit))?;
@@ -176,6 +209,7 @@ pub fn add_business_days(
                 week_mask,
                 holidays,
                 roll,
+                row_holidays,
             )?;
             let start_time = start_naive
                 .cast(&DataType::Time)?
@@ -195,8 +229,6 @@ pub fn add_business_days(
         _ => polars_bail!(InvalidOperation: "expected date or datetime, got {}", start.dtype()),
     }
 
-    let holidays = normalise_holidays(holidays, &week_mask);
-    let start_dates = start.date()?;
     let n = match &n.dtype() {
         DataType::Int64 | DataType::UInt64 | DataType::UInt32 => n.cast(&DataType::Int32)?,
         DataType::Int32 => n.clone(),
@@ -204,9 +236,41 @@ pub fn add_business_days(
             polars_bail!(InvalidOperation: "expected Int64, Int32, UInt64, or UInt32, got {}", n.dtype())
         },
     };
-    let n = n.i32()?;
     let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
 
+    if let Some(row_holidays) = row_holidays {
+        let len = broadcast_len(&[start, &n, row_holidays])?;
+        let start = broadcast(start, len);
+        let n = broadcast(&n, len);
+        let holidays = holidays_per_row(holidays, &broadcast(row_holidays, len), &week_mask)?;
+        let out = start
+            .date()?
+            .into_iter()
+            .zip(n.i32()?.into_iter())
+            .zip(holidays.iter())
+            .map(|((start_date, n), holidays)| match (start_date, n) {
+                (Some(start_date), Some(n)) => {
+                    let (start_date, day_of_week) =
+                        roll_start_date(start_date, roll, &week_mask, holidays)?;
+                    Ok(Some(add_business_days_impl(
+                        start_date,
+                        day_of_week,
+                        n,
+                        &week_mask,
+                        n_business_days_in_week_mask,
+                        holidays,
+                    )))
+                },
+                _ => Ok(None),
+            })
+            .collect::<PolarsResult<Int32Chunked>>()?;
+        return Ok(out.with_name(start.name()).into_date().into_series());
+    }
+
+    let holidays = normalise_holidays(holidays, &week_mask);
+    let start_dates = start.date()?;
+    let n = n.i32()?;
+
     let out: Int32Chunked = match (start_dates.len(), n.len()) {
         (_, 1) => {
             if let Some(n) = n.get(0) {
@@ -353,6 +417,50 @@ fn roll_start_date(
     Ok((date, day_of_week))
 }
 
+/// Get the length that `columns` are broadcast to, each of them must have that length or a
+/// single row.
+fn broadcast_len(columns: &[&Series]) -> PolarsResult<usize> {
+    let len = columns.iter().map(|s| s.len()).max().unwrap_or(0);
+    for s in columns {
+        polars_ensure!(
+            s.len() == len || s.len() == 1,
+            ShapeMismatch: "expected all inputs to have length {} or 1, got {}", len, s.len()
+        );
+    }
+    Ok(len)
+}
+
+fn broadcast(s: &Series, len: usize) -> Series {
+    if s.len() == len {
+        s.clone()
+    } else {
+        s.new_from_index(0, len)
+    }
+}
+
+/// Get the normalised holidays of every row, which are `holidays` together with the dates in
+/// the sublist of the row in `row_holidays`.
+fn holidays_per_row(
+    holidays: &[i32],
+    row_holidays: &Series,
+    week_mask: &[bool; 7],
+) -> PolarsResult<Vec<Vec<i32>>> {
+    let row_holidays = row_holidays
+        .cast(&DataType::List(Box::new(DataType::Date)))?
+        .cast(&DataType::List(Box::new(DataType::Int32)))?;
+    row_holidays
+        .list()?
+        .into_iter()
+        .map(|opt_s| {
+            let mut row = holidays.to_vec();
+            if let Some(s) = opt_s {
+                row.extend(s.i32()?.into_iter().flatten());
+            }
+            Ok(normalise_holidays(&row, week_mask))
+        })
+        .collect()
+}
+
 /// Sort and deduplicate holidays and remove holidays that are not business days.
 fn normalise_holidays(holidays: &[i32], week_mask: &[bool; 7]) -> Vec<i32> {
     let mut holidays: Vec<i32> = holidays.to_vec();
@@ -62,55 +62,107 @@ where
     }
 }
 
-fn cum_max_numeric<T>(ca: &ChunkedArray<T>, reverse: bool) -> ChunkedArray<T>
+/// Scan every group `ca[offsets[i]..offsets[i + 1]]`, starting from `init` in every group.
+/// Values that are not part of any group are set to null.
+fn scan_groups<T, F>(
+    ca: &ChunkedArray<T>,
+    offsets: &[i64],
+    reverse: bool,
+    init: T::Native,
+    mut f: F,
+) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    F: FnMut(&mut T::Native, Option<T::Native>) -> Option<Option<T::Native>>,
+{
+    let ca = ca.rechunk();
+    let mut out = Vec::with_capacity(ca.len());
+    out.resize(offsets.first().map_or(0, |o| *o as usize), None);
+    for w in offsets.windows(2) {
+        let (start, end) = (w[0] as usize, w[1] as usize);
+        let mut state = init;
+        if reverse {
+            out.resize(end, None);
+            for i in (start..end).rev() {
+                out[i] = f(&mut state, ca.get(i)).unwrap();
+            }
+        } else {
+            out.extend((start..end).map(|i| f(&mut state, ca.get(i)).unwrap()));
+        }
+    }
+    out.resize(ca.len(), None);
+    ChunkedArray::from_iter_options(ca.name(), out.into_iter())
+}
+
+fn cum_max_numeric<T>(
+    ca: &ChunkedArray<T>,
+    reverse: bool,
+    offsets: Option<&[i64]>,
+) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
 {
     let init = Bounded::min_value();
 
-    let out: ChunkedArray<T> = match reverse {
-        false => ca.iter().scan(init, det_max).collect_trusted(),
-        true => ca.iter().rev().scan(init, det_max).collect_reversed(),
+    let out: ChunkedArray<T> = match (offsets, reverse) {
+        (Some(offsets), _) => scan_groups(ca, offsets, reverse, init, det_max),
+        (None, false) => ca.iter().scan(init, det_max).collect_trusted(),
+        (None, true) => ca.iter().rev().scan(init, det_max).collect_reversed(),
     };
     out.with_name(ca.name())
 }
 
-fn cum_min_numeric<T>(ca: &ChunkedArray<T>, reverse: bool) -> ChunkedArray<T>
+fn cum_min_numeric<T>(
+    ca: &ChunkedArray<T>,
+    reverse: bool,
+    offsets: Option<&[i64]>,
+) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
 {
     let init = Bounded::max_value();
-    let out: ChunkedArray<T> = match reverse {
-        false => ca.iter().scan(init, det_min).collect_trusted(),
-        true => ca.iter().rev().scan(init, det_min).collect_reversed(),
+    let out: ChunkedArray<T> = match (offsets, reverse) {
+        (Some(offsets), _) => scan_groups(ca, offsets, reverse, init, det_min),
+        (None, false) => ca.iter().scan(init, det_min).collect_trusted(),
+        (None, true) => ca.iter().rev().scan(init, det_min).collect_reversed(),
     };
     out.with_name(ca.name())
 }
 
-fn cum_sum_numeric<T>(ca: &ChunkedArray<T>, reverse: bool) -> ChunkedArray<T>
+fn cum_sum_numeric<T>(
+    ca: &ChunkedArray<T>,
+    reverse: bool,
+    offsets: Option<&[i64]>,
+) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
 {
     let init = T::Native::zero();
-    let out: ChunkedArray<T> = match reverse {
-        false => ca.iter().scan(init, det_sum).collect_trusted(),
-        true => ca.iter().rev().scan(init, det_sum).collect_reversed(),
+    let out: ChunkedArray<T> = match (offsets, reverse) {
+        (Some(offsets), _) => scan_groups(ca, offsets, reverse, init, det_sum),
+        (None, false) => ca.iter().scan(init, det_sum).collect_trusted(),
+        (None, true) => ca.iter().rev().scan(init, det_sum).collect_reversed(),
     };
     out.with_name(ca.name())
 }
 
-fn cum_prod_numeric<T>(ca: &ChunkedArray<T>, reverse: bool) -> ChunkedArray<T>
+fn cum_prod_numeric<T>(
+    ca: &ChunkedArray<T>,
+    reverse: bool,
+    offsets: Option<&[i64]>,
+) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
 {
     let init = T::Native::one();
-    let out: ChunkedArray<T> = match reverse {
-        false => ca.iter().scan(init, det_prod).collect_trusted(),
-        true => ca.iter().rev().scan(init, det_prod).collect_reversed(),
+    let out: ChunkedArray<T> = match (offsets, reverse) {
+        (Some(offsets), _) => scan_groups(ca, offsets, reverse, init, det_prod),
+        (None, false) => ca.iter().scan(init, det_prod).collect_trusted(),
+        (None, true) => ca.iter().rev().scan(init, det_prod).collect_reversed(),
     };
     out.with_name(ca.name())
 }
@@ -120,16 +172,27 @@ where
 /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16, Int32, UInt32}` the `Series` is
 /// first cast to `Int64` to prevent overflow issues.
 pub fn cum_prod(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    cum_prod_impl(s, reverse, None)
+}
+
+/// Get the cumulative prod of every group `s[offsets[i]..offsets[i + 1]]` in a single pass.
+pub fn cum_prod_groups(s: &Series, offsets: &[i64], reverse: bool) -> PolarsResult<Series> {
+    cum_prod_impl(s, reverse, Some(offsets))
+}
+
+fn cum_prod_impl(s: &Series, reverse: bool, offsets: Option<&[i64]>) -> PolarsResult<Series> {
     use DataType::*;
     let out = match s.dtype() {
         Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 => {
             let s = s.cast(&Int64)?;
-            cum_prod_numeric(s.i64()?, reverse).into_series()
+            cum_prod_numeric(s.i64()?, reverse, offsets).into_series()
         },
-        Int64 => cum_prod_numeric(s.i64()?, reverse).into_series(),
-        UInt64 => cum_prod_numeric(s.u64()?, reverse).into_series(),
-        Float32 => cum_prod_numeric(s.f32()?, reverse).into_series(),
-        Float64 => cum_prod_numeric(s.f64()?, reverse).into_series(),
+        Int64 => cum_prod_numeric(s.i64()?, reverse, offsets).into_series(),
+        #[cfg(feature = "dtype-i128")]
+        Int128 => cum_prod_numeric(s.i128()?, reverse, offsets).into_series(),
+        UInt64 => cum_prod_numeric(s.u64()?, reverse, offsets).into_series(),
+        Float32 => cum_prod_numeric(s.f32()?, reverse, offsets).into_series(),
+        Float64 => cum_prod_numeric(s.f64()?, reverse, offsets).into_series(),
         dt => polars_bail!(opq = cum_prod, dt),
     };
     Ok(out)
@@ -140,27 +203,45 @@ pub fn cum_prod(s: &Series, reverse: bool) -> PolarsResult<Series> {
 /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16}` the `Series` is
 /// first cast to `Int64` to prevent overflow issues.
 pub fn cum_sum(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    cum_sum_impl(s, reverse, None)
+}
+
+/// Get the cumulative sum of every group `s[offsets[i]..offsets[i + 1]]` in a single pass.
+pub fn cum_sum_groups(s: &Series, offsets: &[i64], reverse: bool) -> PolarsResult<Series> {
+    cum_sum_impl(s, reverse, Some(offsets))
+}
+
+fn cum_sum_impl(s: &Series, reverse: bool, offsets: Option<&[i64]>) -> PolarsResult<Series> {
     use DataType::*;
     let out = match s.dtype() {
         Boolean => {
             let s = s.cast(&UInt32)?;
-            cum_sum_numeric(s.u32()?, reverse).into_series()
+            cum_sum_numeric(s.u32()?, reverse, offsets).into_series()
         },
         Int8 | UInt8 | Int16 | UInt16 => {
             let s = s.cast(&Int64)?;
-            cum_sum_numeric(s.i64()?, reverse).into_series()
+            cum_sum_numeric(s.i64()?, reverse, offsets).into_series()
         },
-        Int32 => cum_sum_numeric(s.i32()?, reverse).into_series(),
-        UInt32 => cum_sum_numeric(s.u32()?, reverse).into_series(),
-        Int64 => cum_sum_numeric(s.i64()?, reverse).into_series(),
-        UInt64 => cum_sum_numeric(s.u64()?, reverse).into_series(),
-        Float32 => cum_sum_numeric(s.f32()?, reverse).into_series(),
-        Float64 => cum_sum_numeric(s.f64()?, reverse).into_series(),
+        Int32 => cum_sum_numeric(s.i32()?, reverse, offsets).into_series(),
+        UInt32 => cum_sum_numeric(s.u32()?, reverse, offsets).into_series(),
+        Int64 => cum_sum_numeric(s.i64()?, reverse, offsets).into_series(),
+        #[cfg(feature = "dtype-i128")]
+        Int128 => cum_sum_numeric(s.i128()?, reverse, offsets).into_series(),
+        UInt64 => cum_sum_numeric(s.u64()?, reverse, offsets).into_series(),
+        Float32 => cum_sum_numeric(s.f32()?, reverse, offsets).into_series(),
+        Float64 => cum_sum_numeric(s.f64()?, reverse, offsets).into_series(),
         #[cfg(feature = "dtype-duration")]
         Duration(tu) => {
             let s = s.to_physical_repr();
             let ca = s.i64()?;
-            cum_sum_numeric(ca, reverse).cast(&Duration(*tu))?
+            cum_sum_numeric(ca, reverse, offsets).cast(&Duration(*tu))?
+        },
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => {
+            let ca = s.decimal()?;
+            cum_sum_numeric(&ca.0, reverse, offsets)
+                .into_decimal_unchecked(None, ca.scale())
+                .into_series()
         },
         dt => polars_bail!(opq = cum_sum, dt),
     };
@@ -169,13 +250,22 @@ pub fn cum_sum(s: &Series, reverse: bool) -> PolarsResult<Series> {
 
 /// Get an array with the cumulative min computed at every element.
 pub fn cum_min(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    cum_min_impl(s, reverse, None)
+}
+
+/// Get the cumulative min of every group `s[offsets[i]..offsets[i + 1]]` in a single pass.
+pub fn cum_min_groups(s: &Series, offsets: &[i64], reverse: bool) -> PolarsResult<Series> {
+    cum_min_impl(s, reverse, Some(offsets))
+}
+
+fn cum_min_impl(s: &Series, reverse: bool, offsets: Option<&[i64]>) -> PolarsResult<Series> {
     let original_type = s.dtype();
     let s = s.to_physical_repr();
     match s.dtype() {
         dt if dt.is_numeric() => {
             with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
                 let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-                let out = cum_min_numeric(ca, reverse).into_series();
+                let out = cum_min_numeric(ca, reverse, offsets).into_series();
                 if original_type.is_logical(){
                     out.cast(original_type)
                 }else{
@@ -183,19 +273,35 @@ pub fn cum_min(s: &Series, reverse: bool) -> PolarsResult<Series> {
                 }
             })
         },
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(precision, _) => {
+            let ca = s.decimal()?;
+            Ok(cum_min_numeric(&ca.0, reverse, offsets)
+                .into_decimal_unchecked(*precision, ca.scale())
+                .into_series())
+        },
         dt => polars_bail!(opq = cum_min, dt),
     }
 }
 
 /// Get an array with the cumulative max computed at every element.
 pub fn cum_max(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    cum_max_impl(s, reverse, None)
+}
+
+/// Get the cumulative max of every group `s[offsets[i]..offsets[i + 1]]` in a single pass.
+pub fn cum_max_groups(s: &Series, offsets: &[i64], reverse: bool) -> PolarsResult<Series> {
+    cum_max_impl(s, reverse, Some(offsets))
+}
+
+fn cum_max_impl(s: &Series, reverse: bool, offsets: Option<&[i64]>) -> PolarsResult<Series> {
     let original_type = s.dtype();
     let s = s.to_physical_repr();
     match s.dtype() {
         dt if dt.is_numeric() => {
             with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
                 let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-                let out = cum_max_numeric(ca, reverse).into_series();
+                let out = cum_max_numeric(ca, reverse, offsets).into_series();
                 if original_type.is_logical(){
                     out.cast(original_type)
                 }else{
@@ -203,7 +309,14 @@ pub fn cum_max(s: &Series, reverse: bool) -> PolarsResult<Series> {
                 }
             })
         },
-        dt => polars_bail!(opq = cum_min, dt),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(precision, _) => {
+            let ca = s.decimal()?;
+            Ok(cum_max_numeric(&ca.0, reverse, offsets)
+                .into_decimal_unchecked(*precision, ca.scale())
+                .into_series())
+        },
+        dt => polars_bail!(opq = cum_max, dt),
     }
 }
 
@@ -241,6 +354,27 @@ pub fn cum_count(s: &Series, reverse: bool) -> PolarsResult<Series> {
     Ok(out)
 }
 
+/// Get the cumulative count of the non-null values of every group `s[offsets[i]..offsets[i + 1]]`
+/// in a single pass.
+pub fn cum_count_groups(s: &Series, offsets: &[i64], reverse: bool) -> PolarsResult<Series> {
+    let is_valid = s.is_not_null().into_no_null_iter().collect::<Vec<_>>();
+    let mut out = vec![0 as IdxSize; s.len()];
+    for w in offsets.windows(2) {
+        let (start, end) = (w[0] as usize, w[1] as usize);
+        let mut count = 0 as IdxSize;
+        let mut update = |i: usize| {
+            count += is_valid[i] as IdxSize;
+            out[i] = count;
+        };
+        if reverse {
+            (start..end).rev().for_each(&mut update)
+        } else {
+            (start..end).for_each(&mut update)
+        }
+    }
+    Ok(IdxCa::from_vec(s.name(), out).into_series())
+}
+
 fn cum_count_no_nulls(name: &str, len: usize, reverse: bool) -> Series {
     let start = 1 as IdxSize;
     let end = len as IdxSize + 1;
@@ -1,14 +1,18 @@
 use polars_core::prelude::*;
 use polars_core::series::ops::NullBehavior;
 
-pub fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Series> {
+fn cast_for_diff(s: &Series) -> PolarsResult<Series> {
     use DataType::*;
-    let s = match s.dtype() {
+    Ok(match s.dtype() {
         UInt8 => s.cast(&Int16)?,
         UInt16 => s.cast(&Int32)?,
         UInt32 | UInt64 => s.cast(&Int64)?,
         _ => s.clone(),
-    };
+    })
+}
+
+pub fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Series> {
+    let s = cast_for_diff(s)?;
 
     match null_behavior {
         NullBehavior::Ignore => &s - &s.shift(n),
@@ -20,3 +24,32 @@ pub fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Ser
         },
     }
 }
+
+/// Take for every row the value `n` rows before it, where `n` is given per row. Rows for which
+/// `n` is null or points outside of `s` are null.
+pub(super) fn shift_by_periods(s: &Series, n: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        n.len() == s.len(),
+        ShapeMismatch: "the number of periods must be a scalar or have the length of the series ({}), got length {}",
+        s.len(), n.len()
+    );
+    let n = n.strict_cast(&DataType::Int64)?;
+    let len = s.len() as i64;
+    let idx: IdxCa = n
+        .i64()?
+        .iter()
+        .enumerate()
+        .map(|(i, n)| {
+            let j = i as i64 - n?;
+            (0..len).contains(&j).then_some(j as IdxSize)
+        })
+        .collect();
+    s.take(&idx)
+}
+
+/// Like [`diff`], but with the number of periods `n` given per row, e.g. computed from other
+/// columns. Rows for which `n` is null or points outside of `s` are null.
+pub fn diff_by_periods(s: &Series, n: &Series) -> PolarsResult<Series> {
+    let s = cast_for_diff(s)?;
+    &s - &shift_by_periods(&s, n)?
+}
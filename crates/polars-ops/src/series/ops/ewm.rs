@@ -1,6 +1,7 @@
 pub use arrow::legacy::kernels::ewm::EWMOptions;
 use arrow::legacy::kernels::ewm::{
-    ewm_mean as kernel_ewm_mean, ewm_std as kernel_ewm_std, ewm_var as kernel_ewm_var,
+    ewm_corr as kernel_ewm_corr, ewm_cov as kernel_ewm_cov, ewm_mean as kernel_ewm_mean,
+    ewm_std as kernel_ewm_std, ewm_var as kernel_ewm_var,
 };
 use polars_core::prelude::*;
 
@@ -99,3 +100,71 @@ pub fn ewm_var(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
         _ => ewm_var(&s.cast(&DataType::Float64)?, options),
     }
 }
+
+/// Cast `x` and `y` to the float type of the pairwise ewm functions.
+fn cast_pair(x: &Series, y: &Series) -> PolarsResult<(Series, Series)> {
+    polars_ensure!(
+        x.len() == y.len(),
+        ShapeMismatch: "ewm of two series needs series of the same length, got {} and {}",
+        x.len(), y.len()
+    );
+    let dtype = match (x.dtype(), y.dtype()) {
+        (DataType::Float32, DataType::Float32) => DataType::Float32,
+        _ => DataType::Float64,
+    };
+    Ok((x.cast(&dtype)?, y.cast(&dtype)?))
+}
+
+/// The exponentially weighted covariance of `x` and `y`. Rows in which one of them is null are
+/// null and skipped like nulls are in [`ewm_var`].
+pub fn ewm_cov(x: &Series, y: &Series, options: EWMOptions) -> PolarsResult<Series> {
+    check_alpha(options.alpha)?;
+    let (x, y) = cast_pair(x, y)?;
+    let result: ArrayRef = match x.dtype() {
+        DataType::Float32 => Box::new(kernel_ewm_cov(
+            x.f32().unwrap(),
+            y.f32().unwrap(),
+            options.alpha as f32,
+            options.adjust,
+            options.bias,
+            options.min_periods,
+            options.ignore_nulls,
+        )),
+        _ => Box::new(kernel_ewm_cov(
+            x.f64().unwrap(),
+            y.f64().unwrap(),
+            options.alpha,
+            options.adjust,
+            options.bias,
+            options.min_periods,
+            options.ignore_nulls,
+        )),
+    };
+    Series::try_from((x.name(), result))
+}
+
+/// The exponentially weighted correlation of `x` and `y`. Like in [`ewm_cov`], only the rows in
+/// which both are valid are used; `options.bias` has no effect.
+pub fn ewm_corr(x: &Series, y: &Series, options: EWMOptions) -> PolarsResult<Series> {
+    check_alpha(options.alpha)?;
+    let (x, y) = cast_pair(x, y)?;
+    let result: ArrayRef = match x.dtype() {
+        DataType::Float32 => Box::new(kernel_ewm_corr(
+            x.f32().unwrap(),
+            y.f32().unwrap(),
+            options.alpha as f32,
+            options.adjust,
+            options.min_periods,
+            options.ignore_nulls,
+        )),
+        _ => Box::new(kernel_ewm_corr(
+            x.f64().unwrap(),
+            y.f64().unwrap(),
+            options.alpha,
+            options.adjust,
+            options.min_periods,
+            options.ignore_nulls,
+        )),
+    };
+    Series::try_from((x.name(), result))
+}
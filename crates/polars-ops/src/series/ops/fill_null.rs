@@ -0,0 +1,96 @@
+use polars_core::prelude::*;
+
+/// Get for every value the index of the value it is filled with, which is null if it stays
+/// null. A null value is filled with the closest valid value in the fill direction in the
+/// same group, if that is at most `limit` values away.
+fn fill_indices(s: &Series, offsets: &[i64], limit: FillNullLimit, forward: bool) -> IdxCa {
+    let mask = s.is_not_null().rechunk();
+    let valid = mask.downcast_iter().next().unwrap().values();
+    let limit = limit.map_or(usize::MAX, |limit| limit as usize);
+
+    let mut out = vec![None; s.len()];
+    let mut fill = |i: usize, last_valid: &mut Option<usize>| {
+        if valid.get_bit(i) {
+            *last_valid = Some(i);
+            out[i] = Some(i as IdxSize);
+        } else if let Some(j) = *last_valid {
+            if i.abs_diff(j) <= limit {
+                out[i] = Some(j as IdxSize);
+            }
+        }
+    };
+    for w in offsets.windows(2) {
+        let (start, end) = (w[0] as usize, w[1] as usize);
+        let mut last_valid = None;
+        if forward {
+            (start..end).for_each(|i| fill(i, &mut last_valid));
+        } else {
+            (start..end).rev().for_each(|i| fill(i, &mut last_valid));
+        }
+    }
+    IdxCa::from_iter_options(s.name(), out.into_iter())
+}
+
+fn fill_null_groups(
+    s: &Series,
+    offsets: &[i64],
+    limit: FillNullLimit,
+    forward: bool,
+) -> PolarsResult<Series> {
+    if s.null_count() == 0 {
+        return Ok(s.clone());
+    }
+    s.take(&fill_indices(s, offsets, limit, forward))
+}
+
+/// Fill the null values of every group `s[offsets[i]..offsets[i + 1]]` with the previous
+/// valid value in that group, filling at most `limit` consecutive nulls.
+pub fn forward_fill_groups(
+    s: &Series,
+    offsets: &[i64],
+    limit: FillNullLimit,
+) -> PolarsResult<Series> {
+    fill_null_groups(s, offsets, limit, true)
+}
+
+/// Fill the null values of every group `s[offsets[i]..offsets[i + 1]]` with the next valid
+/// value in that group, filling at most `limit` consecutive nulls.
+pub fn backward_fill_groups(
+    s: &Series,
+    offsets: &[i64],
+    limit: FillNullLimit,
+) -> PolarsResult<Series> {
+    fill_null_groups(s, offsets, limit, false)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fill_null_groups() {
+        let s = Series::new("a", &[None, Some(1), None, Some(2), None, None]);
+        let offsets = [0, 3, 6];
+
+        let out = forward_fill_groups(&s, &offsets, None).unwrap();
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[None, Some(1), Some(1), Some(2), Some(2), Some(2)]
+        );
+        let out = forward_fill_groups(&s, &offsets, Some(1)).unwrap();
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[None, Some(1), Some(1), Some(2), Some(2), None]
+        );
+
+        let out = backward_fill_groups(&s, &offsets, None).unwrap();
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[Some(1), Some(1), None, Some(2), None, None]
+        );
+
+        let s = Series::new("a", &[None, Some("x"), None]);
+        let out = backward_fill_groups(&s, &[0, 3], Some(1)).unwrap();
+        assert_eq!(Vec::from(out.str().unwrap()), &[Some("x"), Some("x"), None]);
+    }
+}
@@ -1,27 +1,60 @@
 use polars_core::frame::NullStrategy;
 use polars_core::prelude::*;
 
-pub fn max_horizontal(s: &[Series]) -> PolarsResult<Option<Series>> {
+fn null_strategy(ignore_nulls: bool) -> NullStrategy {
+    if ignore_nulls {
+        NullStrategy::Ignore
+    } else {
+        NullStrategy::Propagate
+    }
+}
+
+/// Set the rows of `out` in which any of `s` is null to null.
+fn propagate_nulls(s: &[Series], out: Series) -> PolarsResult<Series> {
+    let mut valid: Option<BooleanChunked> = None;
+    for s in s.iter().filter(|s| s.null_count() > 0) {
+        let is_valid = s.is_not_null();
+        valid = Some(match valid {
+            Some(valid) => &valid & &is_valid,
+            None => is_valid,
+        });
+    }
+    match valid {
+        Some(valid) => {
+            let nulls = Series::full_null(out.name(), out.len(), out.dtype());
+            out.zip_with_same_type(&valid, &nulls)
+        },
+        None => Ok(out),
+    }
+}
+
+pub fn max_horizontal(s: &[Series], ignore_nulls: bool) -> PolarsResult<Option<Series>> {
     let df = unsafe { DataFrame::new_no_checks(Vec::from(s)) };
-    df.max_horizontal()
-        .map(|opt_s| opt_s.map(|res| res.with_name(s[0].name())))
+    let out = df.max_horizontal()?.map(|res| res.with_name(s[0].name()));
+    match out {
+        Some(out) if !ignore_nulls => propagate_nulls(s, out).map(Some),
+        out => Ok(out),
+    }
 }
 
-pub fn min_horizontal(s: &[Series]) -> PolarsResult<Option<Series>> {
+pub fn min_horizontal(s: &[Series], ignore_nulls: bool) -> PolarsResult<Option<Series>> {
     let df = unsafe { DataFrame::new_no_checks(Vec::from(s)) };
-    df.min_horizontal()
-        .map(|opt_s| opt_s.map(|res| res.with_name(s[0].name())))
+    let out = df.min_horizontal()?.map(|res| res.with_name(s[0].name()));
+    match out {
+        Some(out) if !ignore_nulls => propagate_nulls(s, out).map(Some),
+        out => Ok(out),
+    }
 }
 
-pub fn sum_horizontal(s: &[Series]) -> PolarsResult<Option<Series>> {
+pub fn sum_horizontal(s: &[Series], ignore_nulls: bool) -> PolarsResult<Option<Series>> {
     let df = unsafe { DataFrame::new_no_checks(Vec::from(s)) };
-    df.sum_horizontal(NullStrategy::Ignore)
+    df.sum_horizontal(null_strategy(ignore_nulls))
         .map(|opt_s| opt_s.map(|res| res.with_name(s[0].name())))
 }
 
-pub fn mean_horizontal(s: &[Series]) -> PolarsResult<Option<Series>> {
+pub fn mean_horizontal(s: &[Series], ignore_nulls: bool) -> PolarsResult<Option<Series>> {
     let df = unsafe { DataFrame::new_no_checks(Vec::from(s)) };
-    df.mean_horizontal(NullStrategy::Ignore)
+    df.mean_horizontal(null_strategy(ignore_nulls))
         .map(|opt_s| opt_s.map(|res| res.with_name(s[0].name())))
 }
 
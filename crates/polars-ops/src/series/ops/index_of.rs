@@ -0,0 +1,67 @@
+use polars_core::chunked_array::ops::search_sorted::SearchSortedSide;
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_core::utils::try_get_supertype;
+
+use super::search_sorted;
+
+/// Find the index of the first value of `s` that equals the single value of `value`.
+///
+/// A null `value` finds the first null. Sorted data without nulls is binary searched, other
+/// data is scanned.
+pub fn index_of(s: &Series, value: &Series) -> PolarsResult<Option<usize>> {
+    polars_ensure!(
+        value.len() == 1,
+        ShapeMismatch: "`index_of` expects a single value, got {} values", value.len()
+    );
+    if value.null_count() == 1 {
+        return Ok(if s.null_count() == 0 {
+            None
+        } else {
+            s.is_null().iter().position(|is_null| is_null == Some(true))
+        });
+    }
+
+    let dtype = try_get_supertype(s.dtype(), value.dtype())?;
+    let s = s.cast(&dtype)?;
+    let value = value.cast(&dtype)?;
+
+    let descending = match s.is_sorted_flag() {
+        IsSorted::Ascending => Some(false),
+        IsSorted::Descending => Some(true),
+        IsSorted::Not => None,
+    };
+    if let (Some(descending), 0) = (descending, s.null_count()) {
+        if s.dtype().is_numeric() || matches!(s.dtype(), DataType::String) {
+            let idx = search_sorted(&s, &value, SearchSortedSide::Left, descending)?;
+            let idx = idx.get(0).unwrap() as usize;
+            let found = idx < s.len() && s.slice(idx as i64, 1).equal(&value)?.all();
+            return Ok(found.then_some(idx));
+        }
+    }
+
+    Ok(s.equal(&value)?
+        .iter()
+        .position(|is_equal| is_equal == Some(true)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_index_of() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(3), None, Some(1), Some(3)]);
+        assert_eq!(index_of(&s, &Series::new("", &[3i64]))?, Some(0));
+        assert_eq!(index_of(&s, &Series::new("", &[2]))?, None);
+        assert_eq!(index_of(&s, &Series::new("", &[None::<i32>]))?, Some(1));
+
+        // Sorted data is binary searched.
+        let mut s = Series::new("a", &[5, 4, 4, 2, 1]);
+        s.set_sorted_flag(IsSorted::Descending);
+        assert_eq!(index_of(&s, &Series::new("", &[4]))?, Some(1));
+        assert_eq!(index_of(&s, &Series::new("", &[3]))?, None);
+        assert_eq!(index_of(&s, &Series::new("", &[0]))?, None);
+        Ok(())
+    }
+}
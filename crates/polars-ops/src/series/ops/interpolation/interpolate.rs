@@ -5,10 +5,8 @@ use arrow::bitmap::MutableBitmap;
 use polars_core::downcast_as_macro_arg_physical;
 use polars_core::export::num::{NumCast, Zero};
 use polars_core::prelude::*;
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
 
-use super::{linear_itp, nearest_itp};
+use super::{cubic_interp, linear_itp, nearest_itp, InterpolationMethod};
 
 fn near_interp<T>(low: T, high: T, steps: IdxSize, steps_n: T, out: &mut Vec<T>)
 where
@@ -191,17 +189,30 @@ where
     interpolate_impl(ca, signed_interp::<T::Native>).into_series()
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub enum InterpolationMethod {
-    Linear,
-    Nearest,
+fn interpolate_cubic(s: &Series) -> Series {
+    let logical = s.dtype();
+    if !(logical.is_numeric() || logical.is_temporal()) {
+        return s.clone();
+    }
+
+    let y = s.to_physical_repr().cast(&DataType::Float64).unwrap();
+    let x = (0..s.len()).map(|i| i as f64).collect::<Vec<_>>();
+    // The positions are distinct, so this can't fail.
+    let out = cubic_interp(y.f64().unwrap(), &x, None)
+        .unwrap()
+        .into_series();
+    match logical {
+        DataType::Float32 => out.cast(logical).unwrap(),
+        dt if dt.is_temporal() => out.cast(&dt.to_physical()).unwrap().cast(dt).unwrap(),
+        _ => out,
+    }
 }
 
 pub fn interpolate(s: &Series, method: InterpolationMethod) -> Series {
     match method {
         InterpolationMethod::Linear => interpolate_linear(s),
         InterpolationMethod::Nearest => interpolate_nearest(s),
+        InterpolationMethod::Cubic => interpolate_cubic(s),
     }
 }
 
@@ -251,6 +262,27 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_interpolate_cubic() {
+        // The natural cubic spline through (1, 0), (3, 2) and (4, 0).
+        let ca = Int32Chunked::new("", &[None, Some(0), None, Some(2), Some(0), None]);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Cubic);
+        let out = out.f64().unwrap();
+        assert_eq!(
+            Vec::from(out),
+            &[None, Some(0.0), Some(1.75), Some(2.0), Some(0.0), None]
+        );
+
+        // Through two points the spline is a line.
+        let ca = Float32Chunked::new("", &[Some(1.0), None, None, Some(4.0)]);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Cubic);
+        let out = out.f32().unwrap();
+        assert_eq!(
+            Vec::from(out),
+            &[Some(1.0), Some(2.0), Some(3.0), Some(4.0)]
+        );
+    }
+
     #[test]
     fn test_interpolate_decreasing_unsigned() {
         let ca = UInt32Chunked::new("", &[Some(4), None, None, Some(1)]);
@@ -7,7 +7,7 @@ use polars_core::export::num::{NumCast, Zero};
 use polars_core::prelude::*;
 use polars_utils::slice::SliceAble;
 
-use super::linear_itp;
+use super::{cubic_interp, knots_and_gaps, linear_itp, InterpolationMethod};
 
 /// # Safety
 /// - `x` must be non-empty.
@@ -263,7 +263,7 @@ where
     }
 }
 
-pub fn interpolate_by(s: &Series, by: &Series, by_is_sorted: bool) -> PolarsResult<Series> {
+fn interpolate_by_linear(s: &Series, by: &Series, by_is_sorted: bool) -> PolarsResult<Series> {
     polars_ensure!(s.len() == by.len(), InvalidOperation: "`by` column must be the same length as Series ({}), got {}", s.len(), by.len());
 
     fn func<T, F>(
@@ -315,13 +315,15 @@ pub fn interpolate_by(s: &Series, by: &Series, by_is_sorted: bool) -> PolarsResu
             func(s.f32().unwrap(), by.u32().unwrap(), by_is_sorted)
         },
         #[cfg(feature = "dtype-date")]
-        (_, DataType::Date) => interpolate_by(s, &by.cast(&DataType::Int32).unwrap(), by_is_sorted),
+        (_, DataType::Date) => {
+            interpolate_by_linear(s, &by.cast(&DataType::Int32).unwrap(), by_is_sorted)
+        },
         #[cfg(feature = "dtype-datetime")]
         (_, DataType::Datetime(_, _)) => {
-            interpolate_by(s, &by.cast(&DataType::Int64).unwrap(), by_is_sorted)
+            interpolate_by_linear(s, &by.cast(&DataType::Int64).unwrap(), by_is_sorted)
         },
         (DataType::UInt64 | DataType::UInt32 | DataType::Int64 | DataType::Int32, _) => {
-            interpolate_by(&s.cast(&DataType::Float64).unwrap(), by, by_is_sorted)
+            interpolate_by_linear(&s.cast(&DataType::Float64).unwrap(), by, by_is_sorted)
         },
         _ => {
             polars_bail!(InvalidOperation: "expected series to be Float64, Float32, \
@@ -330,3 +332,101 @@ pub fn interpolate_by(s: &Series, by: &Series, by_is_sorted: bool) -> PolarsResu
         },
     }
 }
+
+/// The x-coordinates of the values and, if `by` is not sorted, their ascending order.
+fn x_coordinates(
+    s: &Series,
+    by: &Series,
+    by_is_sorted: bool,
+) -> PolarsResult<(Vec<f64>, Option<IdxCa>)> {
+    polars_ensure!(s.len() == by.len(), InvalidOperation: "`by` column must be the same length as Series ({}), got {}", s.len(), by.len());
+    let dtype = by.dtype();
+    polars_ensure!(
+        dtype.is_numeric() || matches!(dtype, DataType::Date | DataType::Datetime(_, _)),
+        InvalidOperation: "expected `by` to be numeric, Date, or Datetime, got {}", dtype
+    );
+    polars_ensure!(by.null_count() == 0, InvalidOperation: "null values in `by` column are not yet supported in 'interpolate_by' expression");
+
+    let x = by.to_physical_repr().cast(&DataType::Float64)?;
+    let x = x.f64().unwrap().into_no_null_iter().collect();
+    let order = (!by_is_sorted).then(|| by.arg_sort(Default::default()));
+    Ok((x, order))
+}
+
+fn interpolate_by_nearest(s: &Series, by: &Series, by_is_sorted: bool) -> PolarsResult<Series> {
+    let (x, order) = x_coordinates(s, by, by_is_sorted)?;
+    let order = order.as_ref().map(|order| order.cont_slice().unwrap());
+    let valid = s.is_not_null().into_no_null_iter().collect::<Vec<_>>();
+    let (knots, gaps) = knots_and_gaps(&valid, order);
+
+    let mut idx = (0..s.len())
+        .map(|p| valid[p].then_some(p as IdxSize))
+        .collect::<Vec<_>>();
+    for (k, p) in gaps {
+        let (low, high) = (knots[k], knots[k + 1]);
+        // On ties the next value is taken, as in `interpolate`.
+        let nearest = if x[high] - x[p] > x[p] - x[low] {
+            low
+        } else {
+            high
+        };
+        idx[p] = Some(nearest as IdxSize);
+    }
+    s.take(&IdxCa::from_iter_options("", idx.into_iter()))
+}
+
+fn interpolate_by_cubic(s: &Series, by: &Series, by_is_sorted: bool) -> PolarsResult<Series> {
+    polars_ensure!(s.dtype().is_numeric(), InvalidOperation: "expected series to be numeric, got {}", s.dtype());
+    let (x, order) = x_coordinates(s, by, by_is_sorted)?;
+    let order = order.as_ref().map(|order| order.cont_slice().unwrap());
+
+    let y = s.cast(&DataType::Float64)?;
+    let out = cubic_interp(y.f64().unwrap(), &x, order)?.into_series();
+    match s.dtype() {
+        DataType::Float32 => out.cast(&DataType::Float32),
+        _ => Ok(out),
+    }
+}
+
+/// Fill the null values of `s` by interpolating between the valid values, where `by` holds
+/// the x-coordinates of the values.
+pub fn interpolate_by(
+    s: &Series,
+    by: &Series,
+    by_is_sorted: bool,
+    method: InterpolationMethod,
+) -> PolarsResult<Series> {
+    match method {
+        InterpolationMethod::Linear => interpolate_by_linear(s, by, by_is_sorted),
+        InterpolationMethod::Nearest => interpolate_by_nearest(s, by, by_is_sorted),
+        InterpolationMethod::Cubic => interpolate_by_cubic(s, by, by_is_sorted),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interpolate_by_methods() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(0), None, None, Some(2), Some(0), None]);
+        let by = Series::new("b", &[1, 3, 2, 4, 5, 0]);
+
+        let out = interpolate_by(&s, &by, false, InterpolationMethod::Nearest)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(0), Some(2), Some(0), Some(2), Some(0), None]
+        );
+
+        // The natural cubic spline through (1, 0), (4, 2) and (5, 0) is 14/9 at 2 and 22/9 at 3.
+        let out = interpolate_by(&s, &by, false, InterpolationMethod::Cubic)?;
+        let out = Vec::from(out.f64()?);
+        assert_eq!(out[1].map(|v| (v * 9.0).round()), Some(22.0));
+        assert_eq!(out[2].map(|v| (v * 9.0).round()), Some(14.0));
+        assert_eq!(out[5], None);
+
+        let by = Series::new("b", &[1, 3, 2, 1, 5, 0]);
+        assert!(interpolate_by(&s, &by, false, InterpolationMethod::Cubic).is_err());
+        Ok(())
+    }
+}
@@ -1,9 +1,23 @@
 use std::ops::{Add, Div, Mul, Sub};
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 #[cfg(feature = "interpolate")]
 pub mod interpolate;
 #[cfg(feature = "interpolate_by")]
 pub mod interpolate_by;
 
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InterpolationMethod {
+    Linear,
+    Nearest,
+    /// A natural cubic spline through all valid values.
+    Cubic,
+}
+
 fn linear_itp<T>(low: T, step: T, slope: T) -> T
 where
     T: Sub<Output = T> + Mul<Output = T> + Add<Output = T> + Div<Output = T>,
@@ -24,3 +38,90 @@ where
         low + diff
     }
 }
+
+/// The second derivatives of the natural cubic spline through the points `x`, `y`.
+fn natural_spline_second_derivatives(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    let mut m = vec![0.0; n];
+    if n < 3 {
+        return m;
+    }
+
+    // The second derivatives at the ends are zero, the others solve a tridiagonal system that
+    // we solve with the Thomas algorithm.
+    let mut c = vec![0.0; n];
+    let mut d = vec![0.0; n];
+    for i in 1..n - 1 {
+        let h0 = x[i] - x[i - 1];
+        let h1 = x[i + 1] - x[i];
+        let rhs = 6.0 * ((y[i + 1] - y[i]) / h1 - (y[i] - y[i - 1]) / h0);
+        let denom = 2.0 * (h0 + h1) - h0 * c[i - 1];
+        c[i] = h1 / denom;
+        d[i] = (rhs - h0 * d[i - 1]) / denom;
+    }
+    for i in (1..n - 1).rev() {
+        m[i] = d[i] - c[i] * m[i + 1];
+    }
+    m
+}
+
+fn cubic_itp(x: [f64; 2], y: [f64; 2], m: [f64; 2], t: f64) -> f64 {
+    let h = x[1] - x[0];
+    let a = (x[1] - t) / h;
+    let b = (t - x[0]) / h;
+    a * y[0] + b * y[1] + ((a * a * a - a) * m[0] + (b * b * b - b) * m[1]) * h * h / 6.0
+}
+
+/// The positions of the valid values in the `order` of their x-coordinates, and for every
+/// null value between two valid values its position and the index of the preceding valid
+/// value. Without an `order` the values are visited in the order of the positions.
+fn knots_and_gaps(valid: &[bool], order: Option<&[IdxSize]>) -> (Vec<usize>, Vec<(usize, usize)>) {
+    let mut knots = vec![];
+    let mut gaps = vec![];
+    let mut pending = vec![];
+    for i in 0..valid.len() {
+        let p = order.map_or(i, |order| order[i] as usize);
+        if valid[p] {
+            gaps.append(&mut pending);
+            knots.push(p);
+        } else if !knots.is_empty() {
+            pending.push((knots.len() - 1, p));
+        }
+    }
+    (knots, gaps)
+}
+
+/// Fill the nulls of `y` that lie between valid values with a natural cubic spline through
+/// the valid values, where `x` holds the x-coordinates of the values and `order` their
+/// ascending order. Without an `order` the x-coordinates must be sorted.
+fn cubic_interp(
+    y: &Float64Chunked,
+    x: &[f64],
+    order: Option<&[IdxSize]>,
+) -> PolarsResult<Float64Chunked> {
+    let valid = y.is_not_null().into_no_null_iter().collect::<Vec<_>>();
+    let (knots, gaps) = knots_and_gaps(&valid, order);
+    if gaps.is_empty() {
+        return Ok(y.clone());
+    }
+
+    let mut out = y.iter().collect::<Vec<_>>();
+    let knot_x = knots.iter().map(|&p| x[p]).collect::<Vec<_>>();
+    let knot_y = knots.iter().map(|&p| out[p].unwrap()).collect::<Vec<_>>();
+    polars_ensure!(
+        knot_x.windows(2).all(|w| w[0] < w[1]),
+        InvalidOperation: "cubic interpolation requires the valid values to have distinct x-coordinates"
+    );
+    let m = natural_spline_second_derivatives(&knot_x, &knot_y);
+
+    for (k, p) in gaps {
+        let v = cubic_itp(
+            [knot_x[k], knot_x[k + 1]],
+            [knot_y[k], knot_y[k + 1]],
+            [m[k], m[k + 1]],
+            x[p],
+        );
+        out[p] = Some(v);
+    }
+    Ok(Float64Chunked::from_iter_options(y.name(), out.into_iter()))
+}
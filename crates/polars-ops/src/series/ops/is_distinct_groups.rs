@@ -0,0 +1,69 @@
+use arrow::array::BooleanArray;
+use arrow::bitmap::MutableBitmap;
+use polars_core::prelude::*;
+
+/// Group the values of `s` by their group and value, so that every group of the result
+/// holds the occurrences of a distinct value within a group `s[offsets[i]..offsets[i + 1]]`.
+fn distinct_groups(s: &Series, offsets: &[i64]) -> PolarsResult<GroupsProxy> {
+    let mut group_index = Vec::with_capacity(s.len());
+    for (i, w) in offsets.windows(2).enumerate() {
+        group_index.extend(std::iter::repeat(i as IdxSize).take((w[1] - w[0]) as usize));
+    }
+    let group_index = IdxCa::from_vec("", group_index).into_series();
+    let df = DataFrame::empty();
+    let gb = df.group_by_with_series(vec![group_index, s.clone()], true, false)?;
+    Ok(gb.take_groups())
+}
+
+fn mark(name: &str, len: usize, idx: Vec<IdxSize>) -> BooleanChunked {
+    let mut out = MutableBitmap::with_capacity(len);
+    out.extend_constant(len, false);
+    for idx in idx {
+        // Group tuples are always in bounds
+        unsafe { out.set_unchecked(idx as usize, true) }
+    }
+    let arr = BooleanArray::new(ArrowDataType::Boolean, out.into(), None);
+    BooleanChunked::with_chunk(name, arr)
+}
+
+/// Get a mask of the first occurrence of every distinct value within every group
+/// `s[offsets[i]..offsets[i + 1]]`, hashing all groups in a single pass.
+#[cfg(feature = "is_first_distinct")]
+pub fn is_first_distinct_groups(s: &Series, offsets: &[i64]) -> PolarsResult<BooleanChunked> {
+    if offsets.len() <= 2 {
+        return super::is_first_distinct(s);
+    }
+    let first = distinct_groups(s, offsets)?.take_group_firsts();
+    Ok(mark(s.name(), s.len(), first))
+}
+
+/// Get a mask of the last occurrence of every distinct value within every group
+/// `s[offsets[i]..offsets[i + 1]]`, hashing all groups in a single pass.
+#[cfg(feature = "is_last_distinct")]
+pub fn is_last_distinct_groups(s: &Series, offsets: &[i64]) -> PolarsResult<BooleanChunked> {
+    if offsets.len() <= 2 {
+        return super::is_last_distinct(s);
+    }
+    // SAFETY: all groups have at least a single member
+    let last = unsafe { distinct_groups(s, offsets)?.take_group_lasts() };
+    Ok(mark(s.name(), s.len(), last))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "is_first_distinct", feature = "is_last_distinct"))]
+    fn test_is_distinct_groups() {
+        let s = Series::new("a", &[Some(1), Some(1), None, Some(1), None, None]);
+        let offsets = [0, 3, 3, 6];
+
+        let out = is_first_distinct_groups(&s, &offsets).unwrap();
+        let out = out.into_no_null_iter().collect::<Vec<_>>();
+        assert_eq!(out, &[true, false, true, true, true, false]);
+        let out = is_last_distinct_groups(&s, &offsets).unwrap();
+        let out = out.into_no_null_iter().collect::<Vec<_>>();
+        assert_eq!(out, &[false, true, true, true, false, true]);
+    }
+}
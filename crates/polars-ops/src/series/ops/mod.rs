@@ -1,6 +1,6 @@
 #[cfg(feature = "abs")]
 mod abs;
-#[cfg(feature = "approx_unique")]
+#[cfg(any(feature = "approx_unique", feature = "approx_quantile"))]
 mod approx_algo;
 #[cfg(feature = "approx_unique")]
 mod approx_unique;
@@ -18,17 +18,22 @@ mod diff;
 mod ewm;
 #[cfg(feature = "ewma_by")]
 mod ewm_by;
+mod fill_null;
 #[cfg(feature = "round_series")]
 mod floor_divide;
 #[cfg(feature = "fused")]
 mod fused;
 mod horizontal;
 mod index;
+#[cfg(feature = "index_of")]
+mod index_of;
 mod int_range;
 #[cfg(any(feature = "interpolate_by", feature = "interpolate"))]
 mod interpolation;
 #[cfg(feature = "is_between")]
 mod is_between;
+#[cfg(any(feature = "is_first_distinct", feature = "is_last_distinct"))]
+mod is_distinct_groups;
 #[cfg(feature = "is_first_distinct")]
 mod is_first_distinct;
 #[cfg(feature = "is_in")]
@@ -44,6 +49,9 @@ mod moment;
 mod negate;
 #[cfg(feature = "pct_change")]
 mod pct_change;
+mod product;
+#[cfg(feature = "approx_quantile")]
+mod quantile_approx;
 #[cfg(feature = "rank")]
 mod rank;
 #[cfg(feature = "reinterpret")]
@@ -66,11 +74,11 @@ mod various;
 
 #[cfg(feature = "abs")]
 pub use abs::*;
-#[cfg(feature = "approx_unique")]
+#[cfg(any(feature = "approx_unique", feature = "approx_quantile"))]
 pub use approx_algo::*;
 #[cfg(feature = "approx_unique")]
 pub use approx_unique::*;
-pub use arg_min_max::ArgAgg;
+pub use arg_min_max::{arg_max_groups, arg_min_groups, ArgAgg};
 #[cfg(feature = "business")]
 pub use business::*;
 pub use clip::*;
@@ -84,12 +92,15 @@ pub use diff::*;
 pub use ewm::*;
 #[cfg(feature = "ewma_by")]
 pub use ewm_by::*;
+pub use fill_null::*;
 #[cfg(feature = "round_series")]
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
 pub use fused::*;
 pub use horizontal::*;
 pub use index::*;
+#[cfg(feature = "index_of")]
+pub use index_of::*;
 pub use int_range::*;
 #[cfg(feature = "interpolate")]
 pub use interpolation::interpolate::*;
@@ -99,6 +110,8 @@ pub use interpolation::interpolate_by::*;
 pub use interpolation::*;
 #[cfg(feature = "is_between")]
 pub use is_between::*;
+#[cfg(any(feature = "is_first_distinct", feature = "is_last_distinct"))]
+pub use is_distinct_groups::*;
 #[cfg(feature = "is_first_distinct")]
 pub use is_first_distinct::*;
 #[cfg(feature = "is_in")]
@@ -114,6 +127,9 @@ pub use moment::*;
 pub use negate::*;
 #[cfg(feature = "pct_change")]
 pub use pct_change::*;
+pub use product::*;
+#[cfg(feature = "approx_quantile")]
+pub use quantile_approx::*;
 pub use polars_core::chunked_array::ops::search_sorted::SearchSortedSide;
 use polars_core::prelude::*;
 #[cfg(feature = "rank")]
@@ -1,25 +1,41 @@
 use polars_core::prelude::*;
 use polars_core::series::ops::NullBehavior;
 
+use super::diff::shift_by_periods;
 use crate::prelude::diff;
 
-pub fn pct_change(s: &Series, n: &Series) -> PolarsResult<Series> {
-    polars_ensure!(
-        n.len() == 1,
-        ComputeError: "n must be a single value."
-    );
-
+/// The percentage change of every value to the value `n` rows before it, where nulls are
+/// filled with the last valid value.
+///
+/// `n` is either a single value or a value per row. With [`NullBehavior::Drop`], the first
+/// `n` rows, which have no value to compare to, are dropped; this needs a single positive `n`.
+pub fn pct_change(s: &Series, n: &Series, null_behavior: NullBehavior) -> PolarsResult<Series> {
     match s.dtype() {
         DataType::Float64 | DataType::Float32 => {},
-        _ => return pct_change(&s.cast(&DataType::Float64)?, n),
+        _ => return pct_change(&s.cast(&DataType::Float64)?, n, null_behavior),
     }
 
     let fill_null_s = s.fill_null(FillNullStrategy::Forward(None))?;
 
+    if n.len() != 1 {
+        polars_ensure!(
+            null_behavior == NullBehavior::Ignore,
+            InvalidOperation: "nulls can only be dropped in 'pct_change' with a single number of periods"
+        );
+        let shifted = shift_by_periods(&fill_null_s, n)?;
+        return (&fill_null_s - &shifted)?.divide(&shifted);
+    }
+
     let n_s = n.cast(&DataType::Int64)?;
-    if let Some(n) = n_s.i64()?.get(0) {
-        diff(&fill_null_s, n, NullBehavior::Ignore)?.divide(&fill_null_s.shift(n))
-    } else {
-        Ok(Series::full_null(s.name(), s.len(), s.dtype()))
+    let Some(n) = n_s.i64()?.get(0) else {
+        return Ok(Series::full_null(s.name(), s.len(), s.dtype()));
+    };
+    let out = diff(&fill_null_s, n, NullBehavior::Ignore)?.divide(&fill_null_s.shift(n))?;
+    match null_behavior {
+        NullBehavior::Ignore => Ok(out),
+        NullBehavior::Drop => {
+            polars_ensure!(n > 0, InvalidOperation: "only positive integer allowed if nulls are dropped in 'pct_change' operation");
+            Ok(out.slice(n, out.len().saturating_sub(n as usize)))
+        },
     }
 }
@@ -0,0 +1,163 @@
+use arrow::array::Array;
+use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How the product of integers deals with results that don't fit in 64 bits.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ProductOverflow {
+    /// Multiply in `Int64`, or `UInt64` for `UInt64` values, and wrap around on overflow.
+    #[default]
+    Wrap,
+    /// Multiply in `Float64`, which loses precision instead of overflowing.
+    Float64,
+    /// Multiply in `Int128`, and wrap around if even that overflows.
+    #[cfg(feature = "dtype-i128")]
+    Int128,
+}
+
+impl ProductOverflow {
+    /// The data type of the product of values of `dtype`.
+    pub fn product_dtype(self, dtype: &DataType) -> DataType {
+        use DataType::*;
+        match (self, dtype) {
+            (ProductOverflow::Float64, _) => Float64,
+            (_, Float32) => Float32,
+            (_, Float64) => Float64,
+            #[cfg(feature = "dtype-i128")]
+            (ProductOverflow::Int128, _) | (_, Int128) => Int128,
+            (_, UInt64) => UInt64,
+            _ => Int64,
+        }
+    }
+}
+
+trait WrappingProduct: Copy {
+    const ONE: Self;
+
+    fn wrapping_product(self, other: Self) -> Self;
+}
+
+macro_rules! impl_wrapping_product {
+    ($one:expr, $($t:ty),*) => {
+        $(
+            impl WrappingProduct for $t {
+                const ONE: Self = $one;
+
+                #[inline]
+                fn wrapping_product(self, other: Self) -> Self {
+                    self.wrapping_mul(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_wrapping_product!(1, i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl WrappingProduct for f32 {
+    const ONE: Self = 1.0;
+
+    #[inline]
+    fn wrapping_product(self, other: Self) -> Self {
+        self * other
+    }
+}
+
+impl WrappingProduct for f64 {
+    const ONE: Self = 1.0;
+
+    #[inline]
+    fn wrapping_product(self, other: Self) -> Self {
+        self * other
+    }
+}
+
+fn product_groups_numeric<T>(ca: &ChunkedArray<T>, offsets: &[i64]) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: WrappingProduct,
+{
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let values = arr.values().as_slice();
+
+    let out = offsets
+        .windows(2)
+        .map(|w| {
+            let (start, end) = (w[0] as usize, w[1] as usize);
+            // Null values are skipped, so a group without valid values has a product of one.
+            match arr.validity().filter(|_| arr.null_count() > 0) {
+                None => values[start..end]
+                    .iter()
+                    .fold(T::Native::ONE, |acc, v| acc.wrapping_product(*v)),
+                Some(validity) => (start..end)
+                    .filter(|i| validity.get_bit(*i))
+                    .fold(T::Native::ONE, |acc, i| acc.wrapping_product(values[i])),
+            }
+        })
+        .collect::<Vec<_>>();
+    ChunkedArray::from_vec(ca.name(), out)
+}
+
+/// Compute the product of every group of `s` in a single pass. The groups are given by the
+/// `offsets` into the values of `s`.
+pub fn product_groups(
+    s: &Series,
+    offsets: &[i64],
+    overflow: ProductOverflow,
+) -> PolarsResult<Series> {
+    let dtype = s.dtype();
+    polars_ensure!(
+        dtype.is_numeric() || dtype.is_bool(),
+        InvalidOperation: "`product` operation not supported for dtype `{dtype}`"
+    );
+    let s = s.cast(&overflow.product_dtype(dtype))?;
+    let out = match s.dtype() {
+        #[cfg(feature = "dtype-i128")]
+        DataType::Int128 => product_groups_numeric(s.i128().unwrap(), offsets).into_series(),
+        dt => with_match_physical_numeric_polars_type!(dt, |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            product_groups_numeric(ca, offsets).into_series()
+        }),
+    };
+    Ok(out)
+}
+
+/// Compute the product of `s`.
+pub fn product(s: &Series, overflow: ProductOverflow) -> PolarsResult<Series> {
+    product_groups(s, &[0, s.len() as i64], overflow)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_product_groups() {
+        let s = Series::new("a", &[Some(2i32), None, Some(3), Some(4), None]);
+        let out = product_groups(&s, &[0, 3, 3, 4, 5], ProductOverflow::Wrap).unwrap();
+        assert_eq!(out.dtype(), &DataType::Int64);
+        assert_eq!(
+            Vec::from(out.i64().unwrap()),
+            &[Some(6), Some(1), Some(4), Some(1)]
+        );
+
+        // 2^64 overflows 64 bits.
+        let s = Series::new("a", &[1i64 << 32, 1i64 << 32]);
+        let out = product(&s, ProductOverflow::Wrap).unwrap();
+        assert_eq!(out.i64().unwrap().get(0), Some(0));
+        let out = product(&s, ProductOverflow::Float64).unwrap();
+        assert_eq!(out.f64().unwrap().get(0), Some(2f64.powi(64)));
+        #[cfg(feature = "dtype-i128")]
+        {
+            let out = product(&s, ProductOverflow::Int128).unwrap();
+            assert_eq!(out.i128().unwrap().get(0), Some(1i128 << 64));
+        }
+
+        let s = Series::new("a", &["a"]);
+        assert!(product(&s, ProductOverflow::Wrap).is_err());
+    }
+}
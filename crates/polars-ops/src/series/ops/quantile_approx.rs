@@ -0,0 +1,82 @@
+use arrow::array::{Array, PrimitiveArray};
+use polars_core::prelude::*;
+use polars_core::POOL;
+use rayon::prelude::*;
+
+use crate::series::ops::approx_algo::TDigest;
+
+fn digest(arr: &PrimitiveArray<f64>, start: usize, end: usize) -> TDigest {
+    let values = &arr.values()[start..end];
+    match arr.validity().filter(|_| arr.null_count() > 0) {
+        None => TDigest::from_values(values.iter().copied()),
+        Some(validity) => TDigest::from_values(
+            (start..end)
+                .filter(|i| validity.get_bit(*i))
+                .map(|i| values[i - start]),
+        ),
+    }
+}
+
+fn check_quantile_approx(s: &Series, quantile: f64) -> PolarsResult<Series> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "`quantile` should be between 0.0 and 1.0, got {quantile}"
+    );
+    let dtype = s.dtype();
+    polars_ensure!(
+        dtype.is_numeric() || dtype.is_bool(),
+        InvalidOperation: "`quantile_approx` operation not supported for dtype `{dtype}`"
+    );
+    s.cast(&DataType::Float64)
+}
+
+/// Approximate the `quantile` of the values of `s`.
+///
+/// The chunks of `s` are summarized in parallel into t-digests, which are merged into a
+/// single digest that estimates the quantile. Null and NaN values are ignored.
+pub fn quantile_approx(s: &Series, quantile: f64) -> PolarsResult<Series> {
+    let s = check_quantile_approx(s, quantile)?;
+    let ca = s.f64().unwrap();
+    let digests = POOL.install(|| {
+        ca.downcast_iter()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|arr| digest(arr, 0, arr.len()))
+            .collect::<Vec<_>>()
+    });
+    let out = TDigest::merge(digests).quantile(quantile);
+    Ok(Series::new(s.name(), &[out]))
+}
+
+/// Approximate the `quantile` of every group of `s`. The groups are given by the `offsets`
+/// into the values of `s`.
+pub fn quantile_approx_groups(s: &Series, offsets: &[i64], quantile: f64) -> PolarsResult<Series> {
+    let s = check_quantile_approx(s, quantile)?.rechunk();
+    let ca = s.f64().unwrap();
+    let arr = ca.downcast_iter().next().unwrap();
+    let out: Float64Chunked = POOL.install(|| {
+        offsets
+            .par_windows(2)
+            .map(|w| digest(arr, w[0] as usize, w[1] as usize).quantile(quantile))
+            .collect()
+    });
+    Ok(out.with_name(s.name()).into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_quantile_approx() {
+        let s = Series::new("a", &[Some(4), None, Some(1), Some(3), Some(2), Some(5)]);
+        let out = quantile_approx(&s, 0.5).unwrap();
+        assert_eq!(out.f64().unwrap().get(0), Some(3.0));
+
+        let out = quantile_approx_groups(&s, &[0, 3, 3, 6], 1.0).unwrap();
+        assert_eq!(Vec::from(out.f64().unwrap()), &[Some(4.0), None, Some(5.0)]);
+
+        assert!(quantile_approx(&s, 1.5).is_err());
+        assert!(quantile_approx(&Series::new("a", &["a"]), 0.5).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use arrow::array::BooleanArray;
+use arrow::array::{Array, BooleanArray};
 use arrow::compute::concatenate::concatenate_validities;
 use polars_core::prelude::*;
 use rand::prelude::*;
@@ -182,6 +182,151 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
     }
 }
 
+/// Call `flush_ties` on every run of equal non-null values of every group, in sorted order.
+/// Besides the indices of the tied values, it receives the number of values and the number
+/// of distinct values that precede them in their group.
+fn rank_groups_impl<F: FnMut(&mut [IdxSize], IdxSize, IdxSize)>(
+    sorted_idx: &[IdxSize],
+    offsets: &[i64],
+    neq: &BooleanArray,
+    values: &dyn Array,
+    mut flush_ties: F,
+) {
+    let mut ties = Vec::with_capacity(128);
+    for w in offsets.windows(2) {
+        let (start, end) = (w[0] as usize, w[1] as usize);
+        let mut n_before = 0;
+        let mut n_distinct = 0;
+        for (j, &idx) in (start..end).zip(&sorted_idx[start..end]) {
+            // Nulls are sorted last within every group and are not ranked.
+            if !values.is_valid(idx as usize) {
+                break;
+            }
+            if j > start && neq.value(j - 1) {
+                flush_ties(&mut ties, n_before, n_distinct);
+                n_before += ties.len() as IdxSize;
+                n_distinct += 1;
+                ties.clear();
+            }
+            ties.push(idx);
+        }
+        if !ties.is_empty() {
+            flush_ties(&mut ties, n_before, n_distinct);
+            ties.clear();
+        }
+    }
+}
+
+/// Rank the values of every group, where group `i` consists of `values[offsets[i]..offsets[i + 1]]`.
+///
+/// Instead of ranking every group on its own, all values are sorted at once, after which the
+/// sorted indices are distributed over their groups. This keeps them sorted within every group,
+/// so that all groups can be ranked in a single pass.
+pub fn rank_groups(
+    values: &Series,
+    offsets: &[i64],
+    options: RankOptions,
+    seed: Option<u64>,
+) -> Series {
+    let values = values.rechunk();
+    let len = values.len();
+    if len == 0 {
+        return rank(&values, options.method, options.descending, seed);
+    }
+    let n_groups = offsets.len().saturating_sub(1);
+
+    // Values that are not part of any group are not ranked.
+    let mut group_of = vec![IdxSize::MAX; len];
+    for (g, w) in offsets.windows(2).enumerate() {
+        group_of[w[0] as usize..w[1] as usize].fill(g as IdxSize);
+    }
+    let sort_idx = values.arg_sort(SortOptions {
+        descending: options.descending,
+        nulls_last: true,
+        maintain_order: true,
+        ..Default::default()
+    });
+    let mut sorted_idx = vec![0 as IdxSize; len];
+    let mut next_pos = offsets[..n_groups]
+        .iter()
+        .map(|o| *o as usize)
+        .collect::<Vec<_>>();
+    for i in sort_idx.into_no_null_iter() {
+        let g = group_of[i as usize];
+        if g != IdxSize::MAX {
+            let pos = &mut next_pos[g as usize];
+            sorted_idx[*pos] = i;
+            *pos += 1;
+        }
+    }
+
+    let sorted_idx = IdxCa::from_vec("", sorted_idx);
+    // SAFETY: the indices come from an arg sort of the values.
+    let sorted_values = unsafe { values.take_unchecked(&sorted_idx) };
+    let neq = if len > 1 {
+        sorted_values
+            .slice(1, len - 1)
+            .not_equal_missing(&sorted_values.slice(0, len - 1))
+            .unwrap()
+            .rechunk()
+    } else {
+        BooleanChunked::full("", false, 1)
+    };
+    let neq = neq.downcast_iter().next().unwrap();
+    let sorted_idx = sorted_idx.cont_slice().unwrap();
+    let arr = &*values.chunks()[0];
+    let validity = arr.validity().cloned();
+
+    use RankMethod::*;
+    match options.method {
+        Average => {
+            let mut out = vec![0.0; len];
+            rank_groups_impl(sorted_idx, offsets, neq, arr, |ties, n_before, _| {
+                let avg = n_before as f64 + 0.5 * (ties.len() as f64 + 1.0);
+                for i in ties {
+                    out[*i as usize] = avg;
+                }
+            });
+            Float64Chunked::from_vec_validity(values.name(), out, validity).into_series()
+        },
+        method => {
+            #[cfg(feature = "random")]
+            let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_random_seed));
+            #[cfg(not(feature = "random"))]
+            let _ = seed;
+
+            let mut out = vec![0 as IdxSize; len];
+            rank_groups_impl(
+                sorted_idx,
+                offsets,
+                neq,
+                arr,
+                |ties, n_before, n_distinct| match method {
+                    Min => ties.iter().for_each(|i| out[*i as usize] = n_before + 1),
+                    Max => ties
+                        .iter()
+                        .for_each(|i| out[*i as usize] = n_before + ties.len() as IdxSize),
+                    Dense => ties.iter().for_each(|i| out[*i as usize] = n_distinct + 1),
+                    Ordinal => {
+                        for (rank, i) in (n_before + 1..).zip(ties.iter()) {
+                            out[*i as usize] = rank;
+                        }
+                    },
+                    #[cfg(feature = "random")]
+                    Random => {
+                        ties.shuffle(&mut rng);
+                        for (rank, i) in (n_before + 1..).zip(ties.iter()) {
+                            out[*i as usize] = rank;
+                        }
+                    },
+                    Average => unreachable!(),
+                },
+            );
+            IdxCa::from_vec_validity(values.name(), out, validity).into_series()
+        },
+    }
+}
+
 pub trait SeriesRank: SeriesSealed {
     fn rank(&self, options: RankOptions, seed: Option<u64>) -> Series {
         rank(self.as_series(), options.method, options.descending, seed)
@@ -299,6 +444,82 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_rank_groups() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            &[
+                Some(3),
+                Some(1),
+                Some(3),
+                None,
+                Some(2),
+                Some(2),
+                Some(2),
+                Some(5),
+                None,
+                Some(1),
+            ],
+        );
+        let offsets = [0, 4, 4, 8, 10];
+
+        for method in [RankMethod::Min, RankMethod::Max, RankMethod::Dense] {
+            for descending in [false, true] {
+                let options = RankOptions { method, descending };
+                let out = rank_groups(&s, &offsets, options, None);
+                for w in offsets.windows(2) {
+                    let (start, len) = (w[0], (w[1] - w[0]) as usize);
+                    let expected = rank(&s.slice(start, len), method, descending, None);
+                    assert!(out.slice(start, len).equals_missing(&expected));
+                }
+            }
+        }
+
+        // Ties are ranked in the order of appearance.
+        let options = RankOptions {
+            method: RankMethod::Ordinal,
+            descending: false,
+        };
+        let out = rank_groups(&s, &offsets, options, None);
+        assert_eq!(
+            Vec::from(out.idx()?),
+            &[
+                Some(2),
+                Some(1),
+                Some(3),
+                None,
+                Some(1),
+                Some(2),
+                Some(3),
+                Some(4),
+                None,
+                Some(1)
+            ]
+        );
+
+        let options = RankOptions {
+            method: RankMethod::Average,
+            descending: false,
+        };
+        let out = rank_groups(&s, &offsets, options, None);
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[
+                Some(2.5),
+                Some(1.0),
+                Some(2.5),
+                None,
+                Some(2.0),
+                Some(2.0),
+                Some(2.0),
+                Some(4.0),
+                None,
+                Some(1.0)
+            ]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_rank_all_null() -> PolarsResult<()> {
         let s = UInt32Chunked::new("", &[None, None, None]).into_series();
@@ -4,19 +4,58 @@ use super::*;
 type DummyType = u8;
 #[cfg(feature = "dtype-u8")]
 type DummyCa = UInt8Chunked;
+#[cfg(feature = "dtype-u8")]
+const DUMMY_DTYPE: DataType = DataType::UInt8;
 
 #[cfg(not(feature = "dtype-u8"))]
 type DummyType = i32;
 #[cfg(not(feature = "dtype-u8"))]
 type DummyCa = Int32Chunked;
+#[cfg(not(feature = "dtype-u8"))]
+const DUMMY_DTYPE: DataType = DataType::Int32;
 
 pub trait ToDummies {
     fn to_dummies(&self, separator: Option<&str>, drop_first: bool) -> PolarsResult<DataFrame>;
 }
 
+/// The fields of the dummies of a series, if they don't depend on its values.
+///
+/// This is the case for enums, which get a column per category in the order of the
+/// categories.
+pub fn to_dummies_fields(
+    name: &str,
+    dtype: &DataType,
+    separator: Option<&str>,
+    drop_first: bool,
+) -> Option<Vec<Field>> {
+    match dtype {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Enum(Some(rev_map), _) => {
+            let sep = separator.unwrap_or("_");
+            let fields = rev_map
+                .get_categories()
+                .values_iter()
+                .skip(drop_first as usize)
+                .map(|cat| Field::new(&format!("{name}{sep}{cat}"), DUMMY_DTYPE))
+                .collect();
+            Some(fields)
+        },
+        _ => None,
+    }
+}
+
 impl ToDummies for Series {
     fn to_dummies(&self, separator: Option<&str>, drop_first: bool) -> PolarsResult<DataFrame> {
         let sep = separator.unwrap_or("_");
+        #[cfg(feature = "dtype-categorical")]
+        if let DataType::Categorical(_, _) | DataType::Enum(_, _) = self.dtype() {
+            return Ok(categorical_to_dummies(
+                self.categorical().unwrap(),
+                sep,
+                drop_first,
+            ));
+        }
+
         let col_name = self.name();
         let groups = self.group_tuples(true, drop_first)?;
 
@@ -50,6 +89,59 @@ impl ToDummies for Series {
     }
 }
 
+/// Create the dummies from the physical codes, without grouping the values.
+///
+/// Enums get a column per category, in the order of the categories, in which null rows are
+/// zero. Categoricals get a column per value like any other type.
+#[cfg(feature = "dtype-categorical")]
+fn categorical_to_dummies(ca: &CategoricalChunked, sep: &str, drop_first: bool) -> DataFrame {
+    let col_name = ca.name();
+    let rev_map = ca.get_rev_map();
+    let len = ca.len();
+
+    if ca.is_enum() {
+        let categories = rev_map.get_categories();
+        let mut columns = vec![vec![0 as DummyType; len]; categories.len()];
+        for (row, code) in ca.physical().iter().enumerate() {
+            if let Some(code) = code {
+                columns[code as usize][row] = 1;
+            }
+        }
+        let columns = columns
+            .into_iter()
+            .zip(categories.values_iter())
+            .skip(drop_first as usize)
+            .map(|(values, cat)| {
+                DummyCa::from_vec(&format!("{col_name}{sep}{cat}"), values).into_series()
+            })
+            .collect();
+        return unsafe { DataFrame::new_no_checks(columns) };
+    }
+
+    // The values in order of their first appearance, like the groups of the general path.
+    let mut column_idx = PlHashMap::new();
+    let mut columns: Vec<(Option<u32>, Vec<DummyType>)> = vec![];
+    for (row, code) in ca.physical().iter().enumerate() {
+        let idx = *column_idx.entry(code).or_insert_with(|| {
+            columns.push((code, vec![0 as DummyType; len]));
+            columns.len() - 1
+        });
+        columns[idx].1[row] = 1;
+    }
+    let columns = columns
+        .into_iter()
+        .skip(drop_first as usize)
+        .map(|(code, values)| {
+            let name = match code {
+                Some(code) => format!("{col_name}{sep}{}", rev_map.get(code)),
+                None => format!("{col_name}{sep}null"),
+            };
+            DummyCa::from_vec(&name, values).into_series()
+        })
+        .collect();
+    unsafe { DataFrame::new_no_checks(sort_columns(columns)) }
+}
+
 fn dummies_helper_idx(groups: &[IdxSize], len: usize, name: &str) -> DummyCa {
     let mut av = vec![0 as DummyType; len];
 
@@ -66,6 +66,13 @@ pub trait SeriesMethods: SeriesSealed {
         }
     }
 
+    /// Get the index of the first value that equals the single value of `value`, see
+    /// [`index_of`](crate::series::index_of).
+    #[cfg(feature = "index_of")]
+    fn index_of(&self, value: &Series) -> PolarsResult<Option<usize>> {
+        crate::series::index_of(self.as_series(), value)
+    }
+
     fn ensure_sorted_arg(&self, operation: &str) -> PolarsResult<()> {
         polars_ensure!(self.is_sorted(Default::default())?, InvalidOperation: "argument in operation '{}' is not sorted, please sort the 'expr/series/column' first", operation);
         Ok(())
@@ -994,7 +994,7 @@ fn transverse_recursive<T, F: Fn(&ArrowDataType) -> T + Clone>(
                 unreachable!()
             }
         },
-        Union => todo!(),
+        Union | RunEndEncoded => todo!(),
     }
 }
 
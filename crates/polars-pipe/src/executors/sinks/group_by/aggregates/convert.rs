@@ -20,9 +20,12 @@ use crate::executors::sinks::group_by::aggregates::last::LastAgg;
 use crate::executors::sinks::group_by::aggregates::mean::MeanAgg;
 use crate::executors::sinks::group_by::aggregates::min_max::{new_max, new_min};
 use crate::executors::sinks::group_by::aggregates::null::NullAgg;
+use crate::executors::sinks::group_by::aggregates::quantile::QuantileAgg;
 use crate::executors::sinks::group_by::aggregates::{AggregateFunction, SumAgg};
+use crate::executors::sinks::group_by::FIRST_SEEN_NAME;
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::DataChunk;
+use crate::pipeline::approx_quantiles;
 
 struct Len {}
 
@@ -47,6 +50,58 @@ impl PhysicalPipedExpr for Len {
     }
 }
 
+/// Reads the position at which the rows were seen in the stream, which is added to the chunks
+/// by the [`OrderedGroupBySink`](crate::executors::sinks::group_by::OrderedGroupBySink).
+struct FirstSeen {}
+
+impl PhysicalIoExpr for FirstSeen {
+    fn evaluate_io(&self, _df: &DataFrame) -> PolarsResult<Series> {
+        unimplemented!()
+    }
+}
+impl PhysicalPipedExpr for FirstSeen {
+    fn evaluate(&self, chunk: &DataChunk, _lazy_state: &ExecutionState) -> PolarsResult<Series> {
+        chunk.data.column(FIRST_SEEN_NAME).cloned()
+    }
+
+    fn field(&self, _input_schema: &Schema) -> PolarsResult<Field> {
+        Ok(Field::new(FIRST_SEEN_NAME, DataType::UInt64))
+    }
+
+    fn expression(&self) -> Expr {
+        Expr::Column(Arc::from(FIRST_SEEN_NAME))
+    }
+}
+
+/// The aggregation that finds the position of the first row of every group in the stream.
+pub(crate) fn first_seen_agg() -> (DataType, Arc<dyn PhysicalPipedExpr>, AggregateFunction) {
+    (
+        DataType::UInt64,
+        Arc::new(FirstSeen {}),
+        AggregateFunction::MinMaxU64(new_min()),
+    )
+}
+
+/// The quantile of a quantile aggregation, which has to be a literal for it to be estimated.
+fn literal_quantile(node: Node, expr_arena: &Arena<AExpr>) -> Option<f64> {
+    match expr_arena.get(node) {
+        AExpr::Literal(lv) => lv
+            .to_any_value()?
+            .extract::<f64>()
+            .filter(|q| (0.0..=1.0).contains(q)),
+        _ => None,
+    }
+}
+
+/// Whether the median and quantiles of the numeric expression at `node` may be estimated.
+fn can_approx_quantile(node: Node, expr_arena: &Arena<AExpr>, input_schema: &Schema) -> bool {
+    approx_quantiles()
+        && expr_arena
+            .get(node)
+            .to_field(input_schema, Context::Default, expr_arena)
+            .map_or(false, |field| field.dtype.is_numeric())
+}
+
 pub fn can_convert_to_hash_agg(
     mut node: Node,
     expr_arena: &Arena<AExpr>,
@@ -90,7 +145,16 @@ pub fn can_convert_to_hash_agg(
                         | IRAggExpr::Last(_)
                         | IRAggExpr::Mean(_)
                         | IRAggExpr::Count(_, false)
-                ) || (matches!(
+                ) || match agg_fn {
+                    IRAggExpr::Median(input) => {
+                        can_approx_quantile(*input, expr_arena, input_schema)
+                    },
+                    IRAggExpr::Quantile { expr, quantile, .. } => {
+                        literal_quantile(*quantile, expr_arena).is_some()
+                            && can_approx_quantile(*expr, expr_arena, input_schema)
+                    },
+                    _ => false,
+                } || (matches!(
                     agg_fn,
                     IRAggExpr::Max {
                         propagate_nans: false,
@@ -264,6 +328,30 @@ where
                 };
                 (logical_dtype, phys_expr, agg_fn)
             },
+            IRAggExpr::Median(input) | IRAggExpr::Quantile { expr: input, .. } => {
+                let quantile = match agg {
+                    IRAggExpr::Quantile { quantile, .. } => {
+                        literal_quantile(*quantile, expr_arena).unwrap()
+                    },
+                    _ => 0.5,
+                };
+                let phys_expr = to_physical(
+                    &ExprIR::from_node(*input, expr_arena),
+                    expr_arena,
+                    Some(schema),
+                )
+                .unwrap();
+                let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+                let dtype = match logical_dtype {
+                    DataType::Float32 => DataType::Float32,
+                    _ => DataType::Float64,
+                };
+                (
+                    logical_dtype,
+                    phys_expr,
+                    AggregateFunction::Quantile(QuantileAgg::new(quantile, dtype)),
+                )
+            },
             IRAggExpr::First(input) => {
                 let phys_expr = to_physical(
                     &ExprIR::from_node(*input, expr_arena),
@@ -11,6 +11,7 @@ use crate::executors::sinks::group_by::aggregates::last::LastAgg;
 use crate::executors::sinks::group_by::aggregates::mean::MeanAgg;
 use crate::executors::sinks::group_by::aggregates::min_max::MinMaxAgg;
 use crate::executors::sinks::group_by::aggregates::null::NullAgg;
+use crate::executors::sinks::group_by::aggregates::quantile::QuantileAgg;
 use crate::executors::sinks::group_by::aggregates::SumAgg;
 use crate::operators::IdxSize;
 
@@ -56,6 +57,7 @@ pub(crate) enum AggregateFunction {
     SumI64(SumAgg<i64>),
     MeanF32(MeanAgg<f32>),
     MeanF64(MeanAgg<f64>),
+    Quantile(QuantileAgg),
     Null(NullAgg),
     MinMaxF32(MinMaxAgg<f32, fn(f32, f32) -> f32>),
     MinMaxF64(MinMaxAgg<f64, fn(f64, f64) -> f64>),
@@ -83,6 +85,7 @@ impl AggregateFunction {
             SumI64(_) => SumI64(SumAgg::new()),
             MeanF32(_) => MeanF32(MeanAgg::new()),
             MeanF64(_) => MeanF64(MeanAgg::new()),
+            Quantile(agg) => Quantile(agg.split()),
             Count(_) => Count(CountAgg::new()),
             Len(_) => Len(CountAgg::new()),
             Null(a) => Null(a.clone()),
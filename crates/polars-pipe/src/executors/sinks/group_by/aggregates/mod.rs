@@ -6,6 +6,7 @@ mod last;
 mod mean;
 mod min_max;
 mod null;
+mod quantile;
 mod sum;
 
 pub use convert::*;
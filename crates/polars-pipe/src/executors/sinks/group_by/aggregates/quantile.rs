@@ -0,0 +1,217 @@
+use std::any::Any;
+
+use polars_core::export::num::NumCast;
+use polars_core::prelude::*;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+
+// The larger, the more accurate and the larger the digest.
+const COMPRESSION: f64 = 100.0;
+const BUFFER_SIZE: usize = 256;
+
+/// A merging t-digest (Dunning, 2019): a sketch of a distribution from which quantiles can be
+/// estimated. Values are summarized by centroids that are smaller near the tails, where the
+/// estimates need to be more precise. Digests of different parts of the data can be merged.
+#[derive(Clone, Default)]
+struct TDigest {
+    // (mean, weight), sorted by mean
+    centroids: Vec<(f64, f64)>,
+    // values that are not yet merged into the centroids
+    buffer: Vec<f64>,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.buffer.push(value);
+        if self.buffer.len() == BUFFER_SIZE {
+            self.compress()
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if other.centroids.is_empty() && other.buffer.is_empty() {
+            return;
+        }
+        self.centroids.extend_from_slice(&other.centroids);
+        self.buffer.extend_from_slice(&other.buffer);
+        self.compress()
+    }
+
+    /// The scale function `k1`, which limits the weight of the centroids by their quantile.
+    fn scale(q: f64) -> f64 {
+        COMPRESSION / (2.0 * std::f64::consts::PI) * (2.0 * q - 1.0).asin()
+    }
+
+    fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let mut points = std::mem::take(&mut self.centroids);
+        points.extend(self.buffer.drain(..).map(|v| (v, 1.0)));
+        points.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+        self.min = points[0].0;
+        self.max = points[points.len() - 1].0;
+
+        let total = points.iter().map(|(_, w)| w).sum::<f64>();
+        let mut centroids = Vec::with_capacity(COMPRESSION as usize);
+        let mut weight_before = 0.0;
+        let mut current = points[0];
+        for &(mean, weight) in &points[1..] {
+            let q_left = weight_before / total;
+            let q_right = (weight_before + current.1 + weight) / total;
+            if Self::scale(q_right) - Self::scale(q_left) <= 1.0 {
+                current.1 += weight;
+                current.0 += (mean - current.0) * weight / current.1;
+            } else {
+                weight_before += current.1;
+                centroids.push(current);
+                current = (mean, weight);
+            }
+        }
+        centroids.push(current);
+        self.centroids = centroids;
+    }
+
+    /// Estimate the quantile by interpolating between the centers of the centroids.
+    fn quantile(&mut self, quantile: f64) -> Option<f64> {
+        self.compress();
+        let total = self.centroids.iter().map(|(_, w)| w).sum::<f64>();
+        if total == 0.0 {
+            return None;
+        }
+        let target = quantile * total;
+        let mut weight_before = 0.0;
+        let (mut prev_center, mut prev_mean) = (0.0, self.min);
+        for &(mean, weight) in &self.centroids {
+            let center = weight_before + weight / 2.0;
+            if target < center {
+                let t = (target - prev_center) / (center - prev_center);
+                return Some(prev_mean + t * (mean - prev_mean));
+            }
+            (prev_center, prev_mean) = (center, mean);
+            weight_before += weight;
+        }
+        if total == prev_center {
+            return Some(self.max);
+        }
+        let t = (target - prev_center) / (total - prev_center);
+        Some(prev_mean + t * (self.max - prev_mean))
+    }
+}
+
+/// An approximate quantile, estimated with a t-digest.
+pub struct QuantileAgg {
+    digest: TDigest,
+    quantile: f64,
+    dtype: DataType,
+}
+
+impl QuantileAgg {
+    /// `dtype` is the output dtype, `Float32` or `Float64`.
+    pub(crate) fn new(quantile: f64, dtype: DataType) -> Self {
+        QuantileAgg {
+            digest: Default::default(),
+            quantile,
+            dtype,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.quantile, self.dtype.clone())
+    }
+}
+
+impl AggregateFn for QuantileAgg {
+    fn has_physical_agg(&self) -> bool {
+        true
+    }
+
+    fn pre_agg_primitive<T: NumCast>(&mut self, _chunk_idx: IdxSize, item: Option<T>) {
+        if let Some(v) = item.and_then(|v| v.to_f64()) {
+            self.digest.add(v)
+        }
+    }
+
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        if let Some(v) = item.extract::<f64>() {
+            self.digest.add(v)
+        }
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        let values = values
+            .slice(offset as i64, length as usize)
+            .cast(&DataType::Float64)
+            .unwrap();
+        for v in values.f64().unwrap().into_iter().flatten() {
+            self.digest.add(v)
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        self.dtype.clone()
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        self.digest.merge(&other.digest)
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        match (self.digest.quantile(self.quantile), &self.dtype) {
+            (Some(v), DataType::Float32) => AnyValue::Float32(v as f32),
+            (Some(v), _) => AnyValue::Float64(v),
+            (None, _) => AnyValue::Null,
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tdigest_quantile() {
+        let mut a = TDigest::default();
+        let mut b = TDigest::default();
+        for i in 0..10_000 {
+            if i % 3 == 0 {
+                a.add(i as f64)
+            } else {
+                b.add(i as f64)
+            }
+        }
+        a.merge(&b);
+        assert!(a.centroids.len() < 2 * COMPRESSION as usize);
+        for q in [0.01, 0.25, 0.5, 0.75, 0.99] {
+            let estimate = a.quantile(q).unwrap();
+            assert!((estimate - q * 10_000.0).abs() < 20.0, "{q}: {estimate}");
+        }
+        assert_eq!(a.quantile(0.0), Some(0.0));
+        assert_eq!(a.quantile(1.0), Some(9_999.0));
+
+        let mut small = TDigest::default();
+        for v in [4.0, 1.0, 3.0, 2.0] {
+            small.add(v)
+        }
+        assert_eq!(small.quantile(0.5), Some(2.5));
+        assert_eq!(TDigest::default().quantile(0.5), None);
+    }
+}
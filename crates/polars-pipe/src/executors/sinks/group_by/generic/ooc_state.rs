@@ -1,4 +1,4 @@
-use polars_core::config::verbose;
+use polars_core::polars_event;
 
 use super::*;
 use crate::executors::sinks::memory::MemTracker;
@@ -51,9 +51,7 @@ pub(super) enum SpillAction {
 
 impl OocState {
     fn init_ooc(&mut self, spill_schema: Schema) -> PolarsResult<()> {
-        if verbose() {
-            eprintln!("OOC group_by started");
-        }
+        polars_event!(target: "polars::streaming::spill", "OOC group_by started");
         self.ooc = true;
 
         // start IO thread
@@ -1,6 +1,6 @@
 use std::sync::Mutex;
 
-use polars_core::config::verbose;
+use polars_core::polars_event;
 use polars_core::prelude::*;
 
 use crate::executors::sinks::io::IOThread;
@@ -32,9 +32,7 @@ impl OocState {
     }
 
     pub(super) fn init_ooc(&mut self, input_schema: SchemaRef) -> PolarsResult<()> {
-        if verbose() {
-            eprintln!("OOC group_by started");
-        }
+        polars_event!(target: "polars::streaming::spill", "OOC group_by started");
         self.ooc = true;
 
         // start IO thread
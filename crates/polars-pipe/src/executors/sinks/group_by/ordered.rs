@@ -0,0 +1,110 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked_optional;
+
+use crate::operators::{
+    DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult, SourceResult,
+};
+
+/// The name of the hidden aggregation that holds the position at which every group was first
+/// seen in the stream.
+pub(crate) const FIRST_SEEN_NAME: &str = "__POLARS_GB_FIRST_SEEN";
+
+/// A group by sink that returns the groups in the order in which they were first seen.
+///
+/// Every row is tagged with its position in the stream in the [`FIRST_SEEN_NAME`] column,
+/// of which the wrapped sink aggregates the minimum along with the other aggregations. Only
+/// the aggregated output, which has a row per group, has to be sorted on that column, so the
+/// input can still be streamed. The column is part of the input of the wrapped sink, so the
+/// positions survive if that sink spills to disk.
+pub(crate) struct OrderedGroupBySink {
+    sink: Box<dyn Sink>,
+    /// The output schema of the wrapped sink.
+    schema: SchemaRef,
+    slice: Option<(i64, usize)>,
+}
+
+impl OrderedGroupBySink {
+    /// The `slice` is applied after the groups are ordered, so the wrapped sink should not
+    /// slice its output itself.
+    pub(crate) fn new(sink: Box<dyn Sink>, schema: SchemaRef, slice: Option<(i64, usize)>) -> Self {
+        Self {
+            sink,
+            schema,
+            slice,
+        }
+    }
+
+    /// The chunk index is stored in the upper and the row in the chunk in the lower 32 bits,
+    /// so that the positions increase in the order the rows were read.
+    fn tag(chunk: &mut DataChunk) -> PolarsResult<()> {
+        #[allow(clippy::unnecessary_cast)]
+        let offset = (chunk.chunk_index as u64) << 32;
+        let positions = (0..chunk.data.height() as u64)
+            .map(|i| offset | i)
+            .collect::<Vec<_>>();
+        chunk
+            .data
+            .with_column(UInt64Chunked::from_vec(FIRST_SEEN_NAME, positions))?;
+        Ok(())
+    }
+
+    fn order(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        let df = df.sort([FIRST_SEEN_NAME], SortMultipleOptions::default())?;
+        let df = df.drop(FIRST_SEEN_NAME)?;
+        Ok(match self.slice {
+            Some((offset, len)) => df.slice(offset, len),
+            None => df,
+        })
+    }
+}
+
+impl Sink for OrderedGroupBySink {
+    fn sink(
+        &mut self,
+        context: &PExecutionContext,
+        mut chunk: DataChunk,
+    ) -> PolarsResult<SinkResult> {
+        Self::tag(&mut chunk)?;
+        self.sink.sink(context, chunk)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        self.sink.combine(other.sink.as_mut())
+    }
+
+    fn split(&self, thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self {
+            sink: self.sink.split(thread_no),
+            schema: self.schema.clone(),
+            slice: self.slice,
+        })
+    }
+
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        let df = match self.sink.finalize(context)? {
+            FinalizedSink::Finished(df) => df,
+            // The groups were spilled to disk, we load them to order them.
+            FinalizedSink::Source(mut source) => {
+                let mut frames = vec![];
+                while let SourceResult::GotMoreData(chunks) = source.get_batches(context)? {
+                    frames.extend(chunks.into_iter().map(|chunk| chunk.data));
+                }
+                accumulate_dataframes_vertical_unchecked_optional(frames)
+                    .unwrap_or_else(|| DataFrame::from(self.schema.as_ref()))
+            },
+            _ => unimplemented!(),
+        };
+        Ok(FinalizedSink::Finished(self.order(df)?))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "ordered_group_by"
+    }
+}
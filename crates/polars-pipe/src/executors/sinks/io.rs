@@ -6,12 +6,13 @@ use std::time::{Duration, SystemTime};
 
 use crossbeam_channel::{bounded, unbounded, Receiver, Sender};
 use polars_core::error::ErrString;
+use polars_core::polars_trace;
 use polars_core::prelude::*;
 use polars_core::utils::arrow::temporal_conversions::SECONDS_IN_DAY;
 use polars_io::prelude::*;
 
 use crate::executors::sinks::get_base_temp_dir;
-use crate::pipeline::morsels_per_sink;
+use crate::pipeline::{morsels_per_sink, report, StreamingEvent};
 
 pub(in crate::executors::sinks) type DfIter =
     Box<dyn ExactSizeIterator<Item = DataFrame> + Sync + Send>;
@@ -27,7 +28,7 @@ pub(crate) struct IOThread {
     pub(in crate::executors::sinks) sent: Arc<AtomicUsize>,
     pub(in crate::executors::sinks) total: Arc<AtomicUsize>,
     pub(in crate::executors::sinks) thread_local_count: Arc<AtomicUsize>,
-    schema: SchemaRef,
+    pub(in crate::executors::sinks) schema: SchemaRef,
 }
 
 fn get_lockfile_path(dir: &Path) -> PathBuf {
@@ -132,6 +133,15 @@ impl IOThread {
         operation_name: &'static str,
     ) -> PolarsResult<Self> {
         let dir = get_spill_dir(operation_name)?;
+        polars_trace!(
+            target: "polars::streaming::spill",
+            operation = operation_name,
+            dir = dir.display();
+            "started spilling to disk"
+        );
+        report(StreamingEvent::Spill {
+            operation: operation_name,
+        });
 
         // make sure we create lockfile before we GC
         let lockfile_path = get_lockfile_path(&dir);
@@ -176,26 +186,35 @@ impl IOThread {
                         let _ = std::fs::create_dir(&path);
                         path.push(format!("{count}.ipc"));
 
-                        let file = File::create(path).unwrap();
+                        let file = File::create(&path).unwrap();
                         let writer = IpcWriter::new(file).with_pl_flavor(true);
                         let mut writer = writer.batched(&schema).unwrap();
                         writer.write_batch(&df).unwrap();
                         writer.finish().unwrap();
+                        polars_trace!(
+                            target: "polars::streaming::spill",
+                            path = path.display(),
+                            rows = df.height();
+                            "spilled partition"
+                        );
                         count += 1;
                     }
                 } else {
                     let mut path = dir2.clone();
                     path.push(format!("{count}_0_pass.ipc"));
 
-                    let file = File::create(path).unwrap();
+                    let file = File::create(&path).unwrap();
                     let writer = IpcWriter::new(file).with_pl_flavor(true);
                     let mut writer = writer.batched(&schema).unwrap();
 
+                    let mut rows = 0;
                     for mut df in iter {
                         df.shrink_to_fit();
+                        rows += df.height();
                         writer.write_batch(&df).unwrap();
                     }
                     writer.finish().unwrap();
+                    polars_trace!(target: "polars::streaming::spill", path = path.display(), rows = rows; "spilled chunks");
 
                     count += 1;
                 }
@@ -226,9 +245,10 @@ impl IOThread {
             // duplicates
             path.push(format!("_{count}_full.ipc"));
 
-            let file = File::create(path).unwrap();
+            let file = File::create(&path).unwrap();
             let mut writer = IpcWriter::new(file).with_pl_flavor(true);
             writer.finish(&mut df).unwrap();
+            polars_trace!(target: "polars::streaming::spill", path = path.display(), rows = df.height(); "spilled chunk");
         } else {
             let iter = Box::new(std::iter::once(df));
             self.dump_iter(None, iter)
@@ -259,11 +279,12 @@ impl IOThread {
         // thread local name we start with an underscore to ensure we don't get
         // duplicates
         path.push(format!("_{count}.ipc"));
-        let file = File::create(path).unwrap();
+        let file = File::create(&path).unwrap();
         let writer = IpcWriter::new(file).with_pl_flavor(true);
         let mut writer = writer.batched(&self.schema).unwrap();
         writer.write_batch(&df).unwrap();
         writer.finish().unwrap();
+        polars_trace!(target: "polars::streaming::spill", path = path.display(), rows = df.height(); "spilled partition");
     }
 
     pub(in crate::executors::sinks) fn dump_iter(&self, partition: Option<IdxCa>, iter: DfIter) {
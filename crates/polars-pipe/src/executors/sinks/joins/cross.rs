@@ -1,28 +1,39 @@
 use std::any::Any;
 use std::iter::StepBy;
 use std::ops::Range;
-use std::sync::Arc;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 use std::vec;
 
 use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
+use polars_core::polars_event;
+use polars_io::ipc::IpcReader;
+use polars_io::SerReader;
 use polars_ops::prelude::CrossJoin as CrossJoinTrait;
 use polars_utils::arena::Node;
 use smartstring::alias::String as SmartString;
 
 use crate::executors::operators::PlaceHolder;
+use crate::executors::sinks::io::IOThread;
+use crate::executors::sinks::memory::MemTracker;
 use crate::operators::{
     chunks_to_df_unchecked, DataChunk, FinalizedSink, Operator, OperatorResult, PExecutionContext,
     Sink, SinkResult,
 };
+use crate::pipeline::morsels_per_sink;
 
-#[derive(Default)]
 pub struct CrossJoin {
     chunks: Vec<DataChunk>,
     suffix: SmartString,
     swapped: bool,
     node: Node,
     placeholder: PlaceHolder,
+    mem_track: MemTracker,
+    // whether the chunks are spilled to disk
+    ooc: bool,
+    // shared by all threads, created by the first that goes out-of-core
+    io_thread: Arc<Mutex<Option<IOThread>>>,
 }
 
 impl CrossJoin {
@@ -38,6 +49,34 @@ impl CrossJoin {
             swapped,
             node,
             placeholder,
+            mem_track: MemTracker::new(morsels_per_sink()),
+            ooc: false,
+            io_thread: Default::default(),
+        }
+    }
+
+    fn init_ooc(&mut self) -> PolarsResult<()> {
+        polars_event!(target: "polars::streaming::spill", "OOC cross join started");
+        self.ooc = true;
+        {
+            let mut iot = self.io_thread.lock().unwrap();
+            if iot.is_none() {
+                let schema = Arc::new(self.chunks[0].data.schema());
+                *iot = Some(IOThread::try_new(schema, "cross_join")?)
+            }
+        }
+        self.spill_all();
+        self.mem_track.release();
+        Ok(())
+    }
+
+    fn spill_all(&mut self) {
+        let iot = self.io_thread.lock().unwrap();
+        let iot = iot.as_ref().unwrap();
+        for chunk in self.chunks.drain(..) {
+            if !chunk.is_empty() {
+                iot.dump_partition_local(0, chunk.data)
+            }
         }
     }
 }
@@ -50,29 +89,72 @@ impl Sink for CrossJoin {
         true
     }
 
-    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        if self.ooc {
+            if !chunk.is_empty() {
+                let iot = self.io_thread.lock().unwrap();
+                iot.as_ref().unwrap().dump_partition_local(0, chunk.data);
+            }
+            return Ok(SinkResult::CanHaveMoreInput);
+        }
+        let used = self.mem_track.fetch_add(chunk.data.estimated_size());
+        let is_empty = chunk.is_empty();
         self.chunks.push(chunk);
+        // the whole buffered side is joined with every chunk of the other side, so we keep
+        // 3x its size free before we go out of core
+        if !is_empty && self.mem_track.should_spill(used, context) {
+            self.init_ooc()?;
+        }
         Ok(SinkResult::CanHaveMoreInput)
     }
 
     fn combine(&mut self, other: &mut dyn Sink) {
         let other = other.as_any().downcast_mut::<Self>().unwrap();
+        if self.ooc || other.ooc {
+            if !other.ooc {
+                other.spill_all();
+            }
+            if !self.ooc {
+                self.ooc = true;
+                self.spill_all();
+            }
+            self.mem_track.release();
+            return;
+        }
         let other_chunks = std::mem::take(&mut other.chunks);
         self.chunks.extend(other_chunks);
     }
 
     fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
         Box::new(Self {
+            chunks: vec![],
             suffix: self.suffix.clone(),
             swapped: self.swapped,
+            node: self.node,
             placeholder: self.placeholder.clone(),
-            ..Default::default()
+            mem_track: self.mem_track.clone(),
+            ooc: false,
+            io_thread: self.io_thread.clone(),
         })
     }
 
     fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        let build = if self.ooc {
+            let io_thread = self.io_thread.lock().unwrap().take().unwrap();
+            let mut dir = io_thread.dir.clone();
+            dir.push("0");
+            let files = std::fs::read_dir(&dir)?
+                .map(|entry| Ok(entry?.path()))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            BuildSide::Spilled { io_thread, files }
+        } else {
+            BuildSide::InMemory(chunks_to_df_unchecked(std::mem::take(&mut self.chunks)))
+        };
+        let part = build.part(0)?;
         let op = Box::new(CrossJoinProbe {
-            df: Arc::new(chunks_to_df_unchecked(std::mem::take(&mut self.chunks))),
+            build: Arc::new(build),
+            part_idx: 0,
+            part,
             suffix: Arc::from(self.suffix.as_ref()),
             in_process_left: None,
             in_process_right: None,
@@ -94,9 +176,44 @@ impl Sink for CrossJoin {
     }
 }
 
+/// The buffered side of a cross join, which is joined with the other side in parts.
+enum BuildSide {
+    InMemory(DataFrame),
+    // the IO thread is kept, as its spill directory is removed when it is dropped
+    Spilled {
+        io_thread: IOThread,
+        files: Vec<PathBuf>,
+    },
+}
+
+impl BuildSide {
+    fn n_parts(&self) -> usize {
+        match self {
+            BuildSide::InMemory(_) => 1,
+            BuildSide::Spilled { files, .. } => files.len(),
+        }
+    }
+
+    fn part(&self, i: usize) -> PolarsResult<DataFrame> {
+        match self {
+            BuildSide::InMemory(df) => Ok(df.clone()),
+            BuildSide::Spilled { io_thread, files } => match files.get(i) {
+                Some(path) => {
+                    let file = polars_utils::open_file(path)?;
+                    IpcReader::new(file).finish()
+                },
+                None => Ok(DataFrame::from(io_thread.schema.as_ref())),
+            },
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct CrossJoinProbe {
-    df: Arc<DataFrame>,
+    build: Arc<BuildSide>,
+    // the part of the build side that is joined with the current chunk
+    part_idx: usize,
+    part: DataFrame,
     suffix: Arc<str>,
     in_process_left: Option<StepBy<Range<usize>>>,
     in_process_right: Option<StepBy<Range<usize>>>,
@@ -105,12 +222,8 @@ pub struct CrossJoinProbe {
     swapped: bool,
 }
 
-impl Operator for CrossJoinProbe {
-    fn execute(
-        &mut self,
-        _context: &PExecutionContext,
-        chunk: &DataChunk,
-    ) -> PolarsResult<OperatorResult> {
+impl CrossJoinProbe {
+    fn slice_size(&self, chunk: &DataChunk) -> usize {
         // Expected output is size**2, so this needs to be a a small number.
         // However, if one of the DataFrames is much smaller than 250, we want
         // to take rather more from the other DataFrame so we don't end up with
@@ -119,35 +232,69 @@ impl Operator for CrossJoinProbe {
         if chunk.data.height() > 0 {
             size *= (250 / chunk.data.height()).max(1);
         }
-        if self.df.height() > 0 {
-            size *= (250 / self.df.height()).max(1);
+        if self.part.height() > 0 {
+            size *= (250 / self.part.height()).max(1);
         }
+        size
+    }
 
+    /// Move on to the next part of the build side and return the offset of its first slice,
+    /// or `None` if all parts are joined with the current chunk.
+    fn next_part(&mut self, chunk: &DataChunk) -> PolarsResult<Option<usize>> {
+        if self.part_idx + 1 >= self.build.n_parts() {
+            return Ok(None);
+        }
+        self.part_idx += 1;
+        self.part = self.build.part(self.part_idx)?;
+        let mut iter_left = (0..self.part.height()).step_by(self.slice_size(chunk));
+        let offset = iter_left.next().unwrap_or(0);
+        self.in_process_left = Some(iter_left);
+        Ok(Some(offset))
+    }
+}
+
+impl Operator for CrossJoinProbe {
+    fn execute(
+        &mut self,
+        _context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
         if self.in_process_left.is_none() {
-            let mut iter_left = (0..self.df.height()).step_by(size);
+            // a new chunk is joined with all parts, starting at the first
+            if self.part_idx != 0 {
+                self.part_idx = 0;
+                self.part = self.build.part(0)?;
+            }
+            let size = self.slice_size(chunk);
+            let mut iter_left = (0..self.part.height()).step_by(size);
             let offset = iter_left.next().unwrap_or(0);
-            self.in_process_left_df = self.df.slice(offset as i64, size);
+            self.in_process_left_df = self.part.slice(offset as i64, size);
             self.in_process_left = Some(iter_left);
         }
+        let size = self.slice_size(chunk);
         if self.in_process_right.is_none() {
             self.in_process_right = Some((0..chunk.data.height()).step_by(size));
         }
         // output size is large we process in chunks
-        let iter_left = self.in_process_left.as_mut().unwrap();
         let iter_right = self.in_process_right.as_mut().unwrap();
 
         match iter_right.next() {
             None => {
                 self.in_process_right = None;
 
-                // if right is depleted take the next left chunk
-                match iter_left.next() {
+                // if right is depleted take the next left chunk, or the first of the next part
+                let next_left = match self.in_process_left.as_mut().unwrap().next() {
+                    Some(offset) => Some(offset),
+                    None => self.next_part(chunk)?,
+                };
+                match next_left {
                     None => {
                         self.in_process_left = None;
                         Ok(OperatorResult::NeedsNewData)
                     },
                     Some(offset) => {
-                        self.in_process_left_df = self.df.slice(offset as i64, size);
+                        let size = self.slice_size(chunk);
+                        self.in_process_left_df = self.part.slice(offset as i64, size);
                         self.in_process_right = Some((0..chunk.data.height()).step_by(size));
                         let iter_right = self.in_process_right.as_mut().unwrap();
                         let offset = iter_right.next().unwrap_or(0);
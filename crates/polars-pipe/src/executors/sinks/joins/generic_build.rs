@@ -1,8 +1,10 @@
 use std::any::Any;
+use std::sync::RwLock;
 
 use arrow::array::BinaryArray;
 use hashbrown::hash_map::RawEntryMut;
 use polars_core::export::ahash::RandomState;
+use polars_core::polars_event;
 use polars_core::prelude::*;
 use polars_core::utils::{_set_partition_size, accumulate_dataframes_vertical_unchecked};
 use polars_ops::prelude::JoinArgs;
@@ -13,12 +15,16 @@ use smartstring::alias::String as SmartString;
 
 use super::*;
 use crate::executors::operators::PlaceHolder;
+use crate::executors::sinks::io::IOThread;
 use crate::executors::sinks::joins::generic_probe_inner_left::GenericJoinProbe;
 use crate::executors::sinks::joins::generic_probe_outer::GenericFullOuterJoinProbe;
+use crate::executors::sinks::joins::ooc::{self, spill_partitioned, GenericJoinProbeOoc};
+use crate::executors::sinks::memory::MemTracker;
 use crate::executors::sinks::utils::{hash_rows, load_vec};
 use crate::executors::sinks::HASHMAP_INIT_SIZE;
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+use crate::pipeline::morsels_per_sink;
 
 pub(super) type ChunkIdx = IdxSize;
 pub(super) type DfIdx = IdxSize;
@@ -54,6 +60,14 @@ pub struct GenericBuild<K: ExtraPayload> {
     key_names_left: Arc<[SmartString]>,
     key_names_right: Arc<[SmartString]>,
     placeholder: PlaceHolder,
+    // Stores available memory in the system at the start of this sink,
+    // and the memory used by the build table.
+    mem_track: MemTracker,
+    // whether the build table may be spilled to disk, which is supported for inner and left joins
+    can_spill: bool,
+    // whether the build table is spilled to disk
+    ooc: bool,
+    io_thread: Arc<RwLock<Option<IOThread>>>,
 }
 
 impl<K: ExtraPayload> GenericBuild<K> {
@@ -71,6 +85,7 @@ impl<K: ExtraPayload> GenericBuild<K> {
         placeholder: PlaceHolder,
     ) -> Self {
         let hb: RandomState = Default::default();
+        let can_spill = matches!(join_args.how, JoinType::Inner | JoinType::Left);
         let partitions = _set_partition_size();
         let hash_tables = PartitionedHashMap::new(load_vec(partitions, || {
             PlIdHashMap::with_capacity(HASHMAP_INIT_SIZE)
@@ -92,6 +107,10 @@ impl<K: ExtraPayload> GenericBuild<K> {
             key_names_left,
             key_names_right,
             placeholder,
+            mem_track: MemTracker::new(morsels_per_sink()),
+            can_spill,
+            ooc: false,
+            io_thread: Default::default(),
         }
     }
 }
@@ -152,6 +171,106 @@ impl<K: ExtraPayload> GenericBuild<K> {
             .get_unchecked_release(chunk_idx as usize)
             .value_unchecked(df_idx as usize)
     }
+
+    /// An empty build sink with the same join, that doesn't spill to disk.
+    pub(super) fn partition_build(&self) -> Self {
+        let mut new = Self::new(
+            self.suffix.clone(),
+            self.join_args.clone(),
+            self.swapped,
+            self.join_columns_left.clone(),
+            self.join_columns_right.clone(),
+            self.join_nulls,
+            self.node,
+            self.key_names_left.clone(),
+            self.key_names_right.clone(),
+            self.placeholder.clone(),
+        );
+        new.hb = self.hb.clone();
+        new.can_spill = false;
+        new
+    }
+
+    fn init_ooc(&mut self) -> PolarsResult<()> {
+        polars_event!(target: "polars::streaming::spill", "OOC join started");
+        self.ooc = true;
+        {
+            let mut iot = self.io_thread.write().unwrap();
+            if iot.is_none() {
+                let schema = Arc::new(self.chunks[0].data.schema());
+                *iot = Some(IOThread::try_new(schema, "join_build")?)
+            }
+        }
+        self.spill_all();
+        Ok(())
+    }
+
+    /// Spill the chunks of the in-memory build table to disk, partitioned by the hash of their
+    /// keys.
+    fn spill_all(&mut self) {
+        let iot = self.io_thread.read().unwrap();
+        let iot = iot.as_ref().unwrap();
+        // empty chunks have no materialized join columns
+        let chunks = self.chunks.drain(..).filter(|chunk| !chunk.is_empty());
+        for (chunk, rows) in chunks.zip(self.materialized_join_cols.drain(..)) {
+            hash_rows(&rows, &mut self.hashes, &self.hb);
+            spill_partitioned(iot, &chunk.data, &self.hashes);
+            self.hashes.clear();
+        }
+        for ht in self.hash_tables.inner_mut() {
+            *ht = PlIdHashMap::with_capacity(HASHMAP_INIT_SIZE);
+        }
+    }
+
+    /// Take the build table and create the operator that probes it in an inner or left join.
+    pub(super) fn probe_operator(&mut self, context: &PExecutionContext) -> GenericJoinProbe<K> {
+        let (left_df, materialized_join_cols, hash_tables, hashes) = self.take_build_table();
+        GenericJoinProbe::new(
+            left_df,
+            materialized_join_cols,
+            self.suffix.clone(),
+            self.hb.clone(),
+            hash_tables,
+            self.join_columns_left.clone(),
+            self.join_columns_right.clone(),
+            self.swapped,
+            hashes,
+            context,
+            self.join_args.clone(),
+            self.join_nulls,
+        )
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn take_build_table(
+        &mut self,
+    ) -> (
+        DataFrame,
+        Arc<[BinaryArray<i64>]>,
+        Arc<PartitionedMap<K>>,
+        Vec<u64>,
+    ) {
+        let chunks_len = self.chunks.len();
+        let left_df = accumulate_dataframes_vertical_unchecked(
+            std::mem::take(&mut self.chunks)
+                .into_iter()
+                .map(|chunk| chunk.data),
+        );
+        if left_df.height() > 0 {
+            assert_eq!(left_df.n_chunks(), chunks_len);
+        }
+        // Reallocate to Arc<[]> to get rid of double indirection as this is accessed on every
+        // hashtable cmp.
+        let materialized_join_cols = Arc::from(std::mem::take(&mut self.materialized_join_cols));
+        let hash_tables = Arc::new(PartitionedHashMap::new(std::mem::take(
+            self.hash_tables.inner_mut(),
+        )));
+
+        // take the buffers, this saves one allocation
+        let mut hashes = std::mem::take(&mut self.hashes);
+        hashes.clear();
+        (left_df, materialized_join_cols, hash_tables, hashes)
+    }
 }
 
 impl<K: ExtraPayload> Sink for GenericBuild<K> {
@@ -163,6 +282,18 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
     }
 
     fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        if self.ooc {
+            if !chunk.is_empty() {
+                let rows = self.set_join_series(context, &chunk)?.clone();
+                hash_rows(&rows, &mut self.hashes, &self.hb);
+                let iot = self.io_thread.read().unwrap();
+                spill_partitioned(iot.as_ref().unwrap(), &chunk.data, &self.hashes);
+                self.hashes.clear();
+                self.join_columns.clear();
+                self.materialized_join_cols.clear();
+            }
+            return Ok(SinkResult::CanHaveMoreInput);
+        }
         // we do some juggling here so that we don't
         // end up with empty chunks
         // But we always want one empty chunk if all is empty as we need
@@ -208,19 +339,44 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
         self.hashes.clear();
         self.join_columns.clear();
 
+        let chunk_bytes = chunk.data.estimated_size();
         self.chunks.push(chunk);
+
+        if self.can_spill {
+            let used = self.mem_track.fetch_add(chunk_bytes);
+            // we keep 3x the size of the build table free for the hash tables and the output
+            // before we go out of core
+            if self.mem_track.should_spill(used, context)
+                || ooc::memory_limit().is_some_and(|limit| used > limit)
+            {
+                self.init_ooc()?;
+                self.mem_track.release();
+            }
+        }
         Ok(SinkResult::CanHaveMoreInput)
     }
 
     fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        if self.ooc || other.ooc {
+            if !other.ooc {
+                other.spill_all();
+            }
+            if !self.ooc {
+                if self.is_empty() {
+                    self.ooc = true;
+                } else {
+                    self.init_ooc().unwrap();
+                }
+            }
+            return;
+        }
         if self.is_empty() {
-            let other = other.as_any().downcast_mut::<Self>().unwrap();
             if !other.is_empty() {
                 std::mem::swap(self, other);
             }
             return;
         }
-        let other = other.as_any().downcast_ref::<Self>().unwrap();
         if other.is_empty() {
             return;
         }
@@ -290,62 +446,43 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
             self.placeholder.clone(),
         );
         new.hb = self.hb.clone();
+        new.mem_track = self.mem_track.clone();
+        new.io_thread = self.io_thread.clone();
+        new.ooc = self.ooc;
         Box::new(new)
     }
 
     fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
-        let chunks_len = self.chunks.len();
-        let left_df = accumulate_dataframes_vertical_unchecked(
-            std::mem::take(&mut self.chunks)
-                .into_iter()
-                .map(|chunk| chunk.data),
-        );
-        if left_df.height() > 0 {
-            assert_eq!(left_df.n_chunks(), chunks_len);
+        if self.ooc {
+            self.spill_all();
+            let io_thread = self.io_thread.write().unwrap().take().unwrap();
+            let probe_operator = GenericJoinProbeOoc::new(
+                self.partition_build(),
+                self.hb.clone(),
+                self.join_columns_right.clone(),
+                io_thread,
+            );
+            self.placeholder.replace(Box::new(probe_operator));
+            return Ok(FinalizedSink::Operator);
         }
-        // Reallocate to Arc<[]> to get rid of double indirection as this is accessed on every
-        // hashtable cmp.
-        let materialized_join_cols = Arc::from(std::mem::take(&mut self.materialized_join_cols));
-        let suffix = self.suffix.clone();
-        let hb = self.hb.clone();
-        let hash_tables = Arc::new(PartitionedHashMap::new(std::mem::take(
-            self.hash_tables.inner_mut(),
-        )));
-        let join_columns_left = self.join_columns_left.clone();
-        let join_columns_right = self.join_columns_right.clone();
-
-        // take the buffers, this saves one allocation
-        let mut hashes = std::mem::take(&mut self.hashes);
-        hashes.clear();
 
         match self.join_args.how {
             JoinType::Inner | JoinType::Left => {
-                let probe_operator = GenericJoinProbe::new(
-                    left_df,
-                    materialized_join_cols,
-                    suffix,
-                    hb,
-                    hash_tables,
-                    join_columns_left,
-                    join_columns_right,
-                    self.swapped,
-                    hashes,
-                    context,
-                    self.join_args.clone(),
-                    self.join_nulls,
-                );
+                let probe_operator = self.probe_operator(context);
                 self.placeholder.replace(Box::new(probe_operator));
                 Ok(FinalizedSink::Operator)
             },
             JoinType::Full => {
+                let (left_df, materialized_join_cols, hash_tables, hashes) =
+                    self.take_build_table();
                 let coalesce = self.join_args.coalesce.coalesce(&JoinType::Full);
                 let probe_operator = GenericFullOuterJoinProbe::new(
                     left_df,
                     materialized_join_cols,
-                    suffix,
-                    hb,
+                    self.suffix.clone(),
+                    self.hb.clone(),
                     hash_tables,
-                    join_columns_left,
+                    self.join_columns_left.clone(),
                     self.swapped,
                     hashes,
                     self.join_nulls,
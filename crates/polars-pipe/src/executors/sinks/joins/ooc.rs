@@ -0,0 +1,225 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{OnceLock, RwLock};
+
+use arrow::array::BinaryArray;
+use polars_core::export::ahash::RandomState;
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_expr::state::ExecutionState;
+use polars_io::ipc::IpcReader;
+use polars_io::SerReader;
+
+use super::*;
+use crate::executors::sinks::io::IOThread;
+use crate::executors::sinks::joins::generic_build::GenericBuild;
+use crate::executors::sinks::utils::hash_rows;
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext, Sink};
+
+/// The number of partitions the build and probe tables of an out-of-core join are spilled in.
+const N_PARTITIONS: usize = 64;
+
+/// The memory budget in bytes of the build table of a join, as set by
+/// `POLARS_OOC_JOIN_MEMORY_LIMIT`. Joins of which the build table exceeds the budget are
+/// spilled to disk.
+pub(super) fn memory_limit() -> Option<usize> {
+    std::env::var("POLARS_OOC_JOIN_MEMORY_LIMIT")
+        .ok()
+        .map(|v| v.parse::<usize>().expect("integer"))
+}
+
+/// Dump the rows of `df` to the partition their hash belongs to.
+pub(super) fn spill_partitioned(io_thread: &IOThread, df: &DataFrame, hashes: &[u64]) {
+    let mut partitions = vec![vec![]; N_PARTITIONS];
+    for (i, h) in hashes.iter().enumerate() {
+        partitions[*h as usize % N_PARTITIONS].push(i as IdxSize);
+    }
+    for (part, idx) in partitions.into_iter().enumerate() {
+        if !idx.is_empty() {
+            // SAFETY: the indices are sorted and in bounds.
+            let df = unsafe { df._take_unchecked_slice_sorted(&idx, false, IsSorted::Ascending) };
+            io_thread.dump_partition_local(part as IdxSize, df)
+        }
+    }
+}
+
+/// Read all dumps of a partition into a single chunk, or an empty table if there are none.
+fn read_partition(io_thread: &IOThread, partition: usize) -> PolarsResult<DataFrame> {
+    let mut dir = io_thread.dir.clone();
+    dir.push(format!("{partition}"));
+    if !dir.exists() {
+        return Ok(DataFrame::from(io_thread.schema.as_ref()));
+    }
+    let files = std::fs::read_dir(&dir)?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<PolarsResult<Vec<PathBuf>>>()?;
+    let dfs = files
+        .iter()
+        .map(|path| {
+            let file = polars_utils::open_file(path)?;
+            IpcReader::new(file).set_rechunk(false).finish()
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    io_thread.clean(dir);
+    let mut df = accumulate_dataframes_vertical_unchecked(dfs);
+    df.as_single_chunk_par();
+    Ok(df)
+}
+
+struct SpilledTables {
+    build: IOThread,
+    // created when the first chunk is probed, as that determines the schema
+    probe: RwLock<Option<IOThread>>,
+    // the next partition to join in the flush phase
+    next_partition: AtomicUsize,
+    // an empty output chunk, which is what is returned when probing
+    empty_output: OnceLock<DataFrame>,
+}
+
+/// The probe side of a join of which the build table is spilled to disk (Grace hash join).
+///
+/// The probe chunks are partitioned by the hash of their keys in the same way as the build
+/// table. When all chunks are probed, every pair of build and probe partitions is joined in
+/// memory in the flush phase, so that only a single partition of the build table has to fit
+/// in memory. The rows of the output are not in the order of the probe table.
+#[derive(Clone)]
+pub(super) struct GenericJoinProbeOoc<K: ExtraPayload> {
+    // an empty build sink that is used to build the hash table of a partition
+    build: Arc<GenericBuild<K>>,
+    hb: RandomState,
+    join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+    tables: Arc<SpilledTables>,
+    hashes: Vec<u64>,
+    // the state of the probe phase, which is reused in the flush phase
+    execution_state: Option<ExecutionState>,
+}
+
+impl<K: ExtraPayload> GenericJoinProbeOoc<K> {
+    pub(super) fn new(
+        build: GenericBuild<K>,
+        hb: RandomState,
+        join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        io_thread: IOThread,
+    ) -> Self {
+        Self {
+            build: Arc::new(build),
+            hb,
+            join_columns_right,
+            tables: Arc::new(SpilledTables {
+                build: io_thread,
+                probe: Default::default(),
+                next_partition: Default::default(),
+                empty_output: Default::default(),
+            }),
+            hashes: vec![],
+            execution_state: None,
+        }
+    }
+
+    fn join(
+        &self,
+        context: &PExecutionContext,
+        build_df: DataFrame,
+        probe_df: DataFrame,
+    ) -> PolarsResult<DataFrame> {
+        let mut build = self.build.partition_build();
+        build.sink(context, DataChunk::new(0, build_df))?;
+        let mut probe = build.probe_operator(context);
+        match probe.execute(context, &DataChunk::new(0, probe_df))? {
+            OperatorResult::Finished(chunk) => Ok(chunk.data),
+            _ => unreachable!(),
+        }
+    }
+
+    fn probe_rows(
+        &self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<BinaryArray<i64>> {
+        let join_columns = self
+            .join_columns_right
+            .iter()
+            .map(|phys_e| {
+                let s = phys_e.evaluate(chunk, &context.execution_state)?;
+                Ok(s.to_physical_repr().rechunk().array_ref(0).clone())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(polars_row::convert_columns_no_order(&join_columns).into_array())
+    }
+}
+
+impl<K: ExtraPayload> Operator for GenericJoinProbeOoc<K> {
+    fn execute(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        if self.execution_state.is_none() {
+            self.execution_state = Some(context.execution_state.clone());
+        }
+        if self.tables.empty_output.get().is_none() {
+            let build_df = DataFrame::from(self.tables.build.schema.as_ref());
+            let out = self.join(context, build_df, chunk.data.clear())?;
+            let _ = self.tables.empty_output.set(out);
+        }
+
+        if !chunk.is_empty() {
+            let rows = self.probe_rows(context, chunk)?;
+            hash_rows(&rows, &mut self.hashes, &self.hb);
+
+            if self.tables.probe.read().unwrap().is_none() {
+                let mut probe = self.tables.probe.write().unwrap();
+                if probe.is_none() {
+                    *probe = Some(IOThread::try_new(
+                        Arc::new(chunk.data.schema()),
+                        "join_probe",
+                    )?);
+                }
+            }
+            let probe = self.tables.probe.read().unwrap();
+            spill_partitioned(probe.as_ref().unwrap(), &chunk.data, &self.hashes);
+            self.hashes.clear();
+        }
+
+        let out = self.tables.empty_output.get().unwrap().clone();
+        Ok(OperatorResult::Finished(chunk.with_data(out)))
+    }
+
+    fn flush(&mut self) -> PolarsResult<OperatorResult> {
+        let state = self.execution_state.clone().unwrap_or_default();
+        let context = PExecutionContext::new(state, false);
+        // Every thread joins partitions until none are left.
+        loop {
+            let partition = self.tables.next_partition.fetch_add(1, Ordering::Relaxed);
+            if partition >= N_PARTITIONS {
+                let out = self.tables.empty_output.get().unwrap().clone();
+                return Ok(OperatorResult::Finished(DataChunk::new(0, out)));
+            }
+            let build_df = read_partition(&self.tables.build, partition)?;
+            let probe = self.tables.probe.read().unwrap();
+            let probe_df = match probe.as_ref() {
+                Some(probe) => read_partition(probe, partition)?,
+                None => continue,
+            };
+            drop(probe);
+            let out = self.join(&context, build_df, probe_df)?;
+            if out.height() > 0 {
+                return Ok(OperatorResult::HaveMoreOutPut(DataChunk::new(0, out)));
+            }
+        }
+    }
+
+    fn must_flush(&self) -> bool {
+        true
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
+        Box::new(self.clone())
+    }
+
+    fn fmt(&self) -> &str {
+        "generic_join_probe_ooc"
+    }
+}
@@ -1,21 +1,64 @@
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
+use polars_expr::state::MemoryLimit;
 use polars_utils::sys::MEMINFO;
 
-use crate::pipeline::FORCE_OOC;
+use crate::operators::PExecutionContext;
+use crate::pipeline::{memory_budget, memory_in_use, release_memory, reserve_memory, FORCE_OOC};
 
 const TO_MB: usize = 2 << 19;
 
+/// The memory used by a node, which is accounted to the memory budget of the streaming engine
+/// and to the memory limit of its query until the node is dropped.
+#[derive(Default)]
+struct UsedMemory {
+    used: AtomicUsize,
+    query_limit: OnceLock<MemoryLimit>,
+    // the part of `used` that is accounted to `query_limit`
+    used_in_query: AtomicUsize,
+}
+
+impl UsedMemory {
+    /// Account the memory of the node to the memory limit of its query.
+    fn track_in_query(&self, limit: &MemoryLimit) -> &MemoryLimit {
+        let limit = self.query_limit.get_or_init(|| limit.clone());
+        let used = self.used.load(Ordering::Relaxed);
+        let tracked = self.used_in_query.swap(used, Ordering::Relaxed);
+        if used > tracked {
+            limit.track(used - tracked)
+        } else {
+            limit.untrack(tracked - used)
+        }
+        limit
+    }
+
+    fn release(&self) {
+        release_memory(self.used.swap(0, Ordering::Relaxed));
+        if let Some(limit) = self.query_limit.get() {
+            limit.untrack(self.used_in_query.swap(0, Ordering::Relaxed))
+        }
+    }
+}
+
+impl Drop for UsedMemory {
+    fn drop(&mut self) {
+        self.release()
+    }
+}
+
 #[derive(Clone)]
 pub(super) struct MemTracker {
     // available memory at the start of this node
     available_mem: Arc<AtomicUsize>,
-    used_by_node: Arc<AtomicUsize>,
+    used_by_node: Arc<UsedMemory>,
     fetch_count: Arc<AtomicUsize>,
     thread_count: usize,
     available_at_start: usize,
     refresh_interval: usize,
+    // if set, the available memory is what is left of the budget instead of the free memory of
+    // the system
+    budget: Option<usize>,
 }
 
 impl MemTracker {
@@ -33,17 +76,20 @@ impl MemTracker {
             thread_count,
             available_at_start: 0,
             refresh_interval,
+            budget: memory_budget(),
         };
-        let available = MEMINFO.free() as usize;
-        out.available_mem.store(available, Ordering::Relaxed);
-        out.available_at_start = available;
+        out.refresh_memory();
+        out.available_at_start = out.available_mem.load(Ordering::Relaxed);
         out
     }
 
     /// This shouldn't be called often as this is expensive.
     pub fn refresh_memory(&self) {
-        self.available_mem
-            .store(MEMINFO.free() as usize, Ordering::Relaxed);
+        let available = match self.budget {
+            Some(budget) => budget.saturating_sub(memory_in_use()),
+            None => MEMINFO.free() as usize,
+        };
+        self.available_mem.store(available, Ordering::Relaxed);
     }
 
     /// Get available memory of the system measured on latest refresh.
@@ -73,6 +119,30 @@ impl MemTracker {
 
     /// Increment the used memory and return the previous value.
     pub(super) fn fetch_add(&self, add: usize) -> usize {
-        self.used_by_node.fetch_add(add, Ordering::Relaxed)
+        reserve_memory(add);
+        self.used_by_node.used.fetch_add(add, Ordering::Relaxed)
+    }
+
+    /// Whether a node that holds `used` bytes should spill to disk. With a memory budget, that
+    /// is when the memory held by all nodes exceeds it. Otherwise the node keeps 3x the size of
+    /// its data free, so that there is memory left to process it.
+    ///
+    /// If the query has a memory limit, the node also spills when the memory held by the nodes
+    /// of the query exceeds it.
+    pub(super) fn should_spill(&self, used: usize, context: &PExecutionContext) -> bool {
+        if let Some(limit) = context.execution_state.memory_limit() {
+            if self.used_by_node.track_in_query(limit).is_exceeded() {
+                return true;
+            }
+        }
+        match self.budget {
+            Some(budget) => memory_in_use() > budget,
+            None => used * 3 > self.get_available(),
+        }
+    }
+
+    /// Stop accounting the memory of this node, as it was spilled to disk.
+    pub(super) fn release(&self) {
+        self.used_by_node.release()
     }
 }
@@ -35,9 +35,11 @@ pub(crate) fn get_base_temp_dir() -> &'static str {
         let tmp = std::env::var("POLARS_TEMP_DIR")
             .unwrap_or_else(|_| std::env::temp_dir().to_string_lossy().into_owned());
 
-        if polars_core::config::verbose() {
-            eprintln!("Temporary directory path in use: {}", &tmp);
-        }
+        polars_core::polars_event!(
+            target: "polars::streaming::spill",
+            "Temporary directory path in use: {}",
+            &tmp
+        );
         tmp
     })
 }
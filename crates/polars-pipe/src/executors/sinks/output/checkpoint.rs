@@ -0,0 +1,288 @@
+use std::borrow::Cow;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crossbeam_channel::bounded;
+use polars_core::prelude::*;
+use polars_plan::prelude::FileType;
+
+#[cfg(feature = "csv")]
+use crate::executors::sinks::output::csv::csv_writer;
+use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+#[cfg(feature = "ipc")]
+use crate::executors::sinks::output::ipc::ipc_writer;
+#[cfg(feature = "parquet")]
+use crate::executors::sinks::output::parquet::parquet_writer;
+use crate::pipeline::morsels_per_sink;
+
+const MANIFEST_NAME: &str = "_checkpoint";
+const PART_PREFIX: &str = "part-";
+
+type CreateWriter = Box<dyn Fn(File) -> PolarsResult<Box<dyn SinkWriter + Send>> + Send>;
+
+/// The number of rows after which a part is committed, as set by `POLARS_SINK_CHECKPOINT_ROWS`.
+fn rows_per_part() -> usize {
+    std::env::var("POLARS_SINK_CHECKPOINT_ROWS")
+        .map(|v| v.parse::<usize>().expect("integer").max(1))
+        .unwrap_or(1 << 20)
+}
+
+/// Identifies the schema of the query that wrote a checkpoint.
+fn schema_fingerprint(schema: &Schema) -> String {
+    schema
+        .iter()
+        .map(|(name, dtype)| format!("{:?}:{dtype}", name.as_str()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+struct Manifest {
+    schema: String,
+    // the committed parts and their number of rows
+    parts: Vec<(String, usize)>,
+    finished: bool,
+}
+
+impl Manifest {
+    fn read(path: &Path) -> PolarsResult<Self> {
+        let invalid =
+            || polars_err!(ComputeError: "invalid checkpoint manifest '{}'", path.display());
+        let mut manifest = Manifest {
+            schema: String::new(),
+            parts: vec![],
+            finished: false,
+        };
+        for line in fs::read_to_string(path)?.lines() {
+            let mut fields = line.split('\t');
+            match (fields.next(), fields.next(), fields.next()) {
+                (Some("schema"), Some(schema), None) => manifest.schema = schema.to_string(),
+                (Some("part"), Some(name), Some(rows)) => {
+                    let rows = rows.parse::<usize>().map_err(|_| invalid())?;
+                    manifest.parts.push((name.to_string(), rows))
+                },
+                (Some("finished"), None, None) => manifest.finished = true,
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// Replace the manifest in `dir` atomically, once it is durably written.
+    fn write(&self, dir: &Path) -> PolarsResult<()> {
+        let mut content = format!("schema\t{}\n", self.schema);
+        for (name, rows) in &self.parts {
+            content.push_str(&format!("part\t{name}\t{rows}\n"));
+        }
+        if self.finished {
+            content.push_str("finished\n");
+        }
+        let tmp_path = dir.join(format!("{MANIFEST_NAME}.tmp"));
+        let mut file = File::create(&tmp_path)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(tmp_path, dir.join(MANIFEST_NAME))?;
+        Ok(())
+    }
+}
+
+struct Part {
+    name: String,
+    writer: Box<dyn SinkWriter + Send>,
+    rows: usize,
+}
+
+/// Writes the stream to a directory of part files, which are committed to a manifest once they
+/// are durably written. A new writer resumes from the committed parts of an interrupted run.
+struct CheckpointWriter {
+    dir: PathBuf,
+    extension: &'static str,
+    create_writer: CreateWriter,
+    schema: SchemaRef,
+    manifest: Manifest,
+    // rows at the start of the stream that were committed by an interrupted run
+    skip: usize,
+    current: Option<Part>,
+    rows_per_part: usize,
+}
+
+impl CheckpointWriter {
+    fn new(
+        dir: &Path,
+        extension: &'static str,
+        create_writer: CreateWriter,
+        schema: SchemaRef,
+    ) -> PolarsResult<Self> {
+        fs::create_dir_all(dir)?;
+        let fingerprint = schema_fingerprint(&schema);
+        let manifest_path = dir.join(MANIFEST_NAME);
+        let mut manifest = Manifest {
+            schema: fingerprint.clone(),
+            parts: vec![],
+            finished: false,
+        };
+        if manifest_path.exists() {
+            let previous = Manifest::read(&manifest_path)?;
+            polars_ensure!(
+                previous.schema == fingerprint,
+                ComputeError: "the checkpoint in '{}' was written by a query with another schema",
+                dir.display()
+            );
+            // A finished run is not resumed but written again.
+            if !previous.finished {
+                manifest.parts = previous.parts;
+            }
+        }
+
+        // Remove the parts that are not committed, e.g. the part an interrupted run was writing.
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if name.starts_with(PART_PREFIX) && !manifest.parts.iter().any(|(p, _)| *p == name) {
+                fs::remove_file(entry.path())?;
+            }
+        }
+        manifest.write(dir)?;
+
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            extension,
+            create_writer,
+            schema,
+            skip: manifest.parts.iter().map(|(_, rows)| rows).sum(),
+            manifest,
+            current: None,
+            rows_per_part: rows_per_part(),
+        })
+    }
+
+    fn open_part(&mut self) -> PolarsResult<&mut Part> {
+        if self.current.is_none() {
+            let n = self.manifest.parts.len();
+            let name = format!("{PART_PREFIX}{n:05}.{}", self.extension);
+            let file = File::create(self.dir.join(&name))?;
+            self.current = Some(Part {
+                name,
+                writer: (self.create_writer)(file)?,
+                rows: 0,
+            });
+        }
+        Ok(self.current.as_mut().unwrap())
+    }
+
+    fn commit(&mut self) -> PolarsResult<()> {
+        if let Some(mut part) = self.current.take() {
+            part.writer._finish()?;
+            // Close the file before it is synced.
+            drop(part.writer);
+            File::open(self.dir.join(&part.name))?.sync_all()?;
+            self.manifest.parts.push((part.name, part.rows));
+            self.manifest.write(&self.dir)?;
+        }
+        Ok(())
+    }
+}
+
+impl SinkWriter for CheckpointWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let df = if self.skip == 0 {
+            Cow::Borrowed(df)
+        } else if df.height() <= self.skip {
+            self.skip -= df.height();
+            return Ok(());
+        } else {
+            let df = df.slice(self.skip as i64, df.height() - self.skip);
+            self.skip = 0;
+            Cow::Owned(df)
+        };
+
+        // Split the batch over the parts, so that a part is committed after `rows_per_part` rows.
+        let rows_per_part = self.rows_per_part;
+        let mut offset = 0;
+        while offset < df.height() {
+            let part = self.open_part()?;
+            let len = (rows_per_part - part.rows).min(df.height() - offset);
+            part.writer._write_batch(&df.slice(offset as i64, len))?;
+            part.rows += len;
+            offset += len;
+            if part.rows == rows_per_part {
+                self.commit()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        // Write an empty part if there are no rows, so that the schema is known.
+        if self.manifest.parts.is_empty() && self.current.is_none() {
+            let empty = DataFrame::from(self.schema.as_ref());
+            self.open_part()?.writer._write_batch(&empty)?;
+        }
+        self.commit()?;
+        self.manifest.finished = true;
+        self.manifest.write(&self.dir)
+    }
+}
+
+/// A sink that writes the stream to a directory of parquet, ipc or csv files and records its
+/// progress in a checkpoint manifest, so that an interrupted run can be resumed.
+///
+/// The rows that an interrupted run committed are skipped, so the rows must arrive in the same
+/// order, which is why the order is always maintained.
+pub struct CheckpointSink {}
+impl CheckpointSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(dir: &Path, file_type: &FileType, schema: &Schema) -> PolarsResult<FilesSink> {
+        let schema = Arc::new(schema.clone());
+        let writer_schema = schema.clone();
+        let (extension, create_writer): (&'static str, CreateWriter) = match file_type.clone() {
+            #[cfg(feature = "parquet")]
+            FileType::Parquet(options) => (
+                "parquet",
+                Box::new(move |file| {
+                    let writer = parquet_writer(file, options, &writer_schema)?;
+                    Ok(Box::new(writer) as Box<dyn SinkWriter + Send>)
+                }),
+            ),
+            #[cfg(feature = "ipc")]
+            FileType::Ipc(options) => (
+                "arrow",
+                Box::new(move |file| {
+                    let writer = ipc_writer(file, options, &writer_schema)?;
+                    Ok(Box::new(writer) as Box<dyn SinkWriter + Send>)
+                }),
+            ),
+            #[cfg(feature = "csv")]
+            FileType::Csv(options) => (
+                "csv",
+                Box::new(move |file| {
+                    let writer = csv_writer(file, options.clone(), &writer_schema)?;
+                    Ok(Box::new(writer) as Box<dyn SinkWriter + Send>)
+                }),
+            ),
+            #[allow(unreachable_patterns)]
+            _ => {
+                polars_bail!(InvalidOperation: "only parquet, ipc and csv sinks can be checkpointed")
+            },
+        };
+        let writer = CheckpointWriter::new(dir, extension, create_writer, schema)?;
+        let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            true,
+            morsels_per_sink,
+        )));
+
+        Ok(FilesSink {
+            sender,
+            io_thread_handle,
+        })
+    }
+}
@@ -22,7 +22,7 @@ pub(super) fn init_writer_thread(
     // all chunks per push should be collected to determine in which order they should
     // be written
     morsels_per_sink: usize,
-) -> JoinHandle<()> {
+) -> JoinHandle<PolarsResult<()>> {
     std::thread::spawn(move || {
         // keep chunks around until all chunks per sink are written
         // then we write them all at once.
@@ -51,7 +51,7 @@ pub(super) fn init_writer_thread(
                         if df.n_chunks() > 1 {
                             df.as_single_chunk();
                         }
-                        writer._write_batch(&df).unwrap();
+                        writer._write_batch(&df)?;
                     }
                 }
                 // all chunks are written remove them
@@ -62,13 +62,14 @@ pub(super) fn init_writer_thread(
                         if df.n_chunks() > 1 {
                             df.as_single_chunk();
                         }
-                        writer._write_batch(&df).unwrap();
+                        writer._write_batch(&df)?;
                     }
-                    writer._finish().unwrap();
-                    return;
+                    writer._finish()?;
+                    return Ok(());
                 }
             }
         }
+        Ok(())
     })
 }
 
@@ -76,15 +77,16 @@ pub(super) fn init_writer_thread(
 #[derive(Clone)]
 pub struct FilesSink {
     pub(crate) sender: Sender<Option<DataChunk>>,
-    pub(crate) io_thread_handle: Arc<Option<JoinHandle<()>>>,
+    pub(crate) io_thread_handle: Arc<Option<JoinHandle<PolarsResult<()>>>>,
 }
 
 impl Sink for FilesSink {
     fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
         // don't add empty dataframes
-        if chunk.data.height() > 0 {
-            self.sender.send(Some(chunk)).unwrap();
-        };
+        // if the io thread is gone, writing failed; the error is raised in `finalize`
+        if chunk.data.height() > 0 && self.sender.send(Some(chunk)).is_err() {
+            return Ok(SinkResult::Finished);
+        }
         Ok(SinkResult::CanHaveMoreInput)
     }
 
@@ -97,7 +99,8 @@ impl Sink for FilesSink {
     }
     fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         // `None` indicates that we can flush all remaining chunks.
-        self.sender.send(None).unwrap();
+        // This fails if the io thread already stopped on an error, which is returned below.
+        let _ = self.sender.send(None);
 
         // wait until all files written
         // some unwrap/mut kung-fu to get a hold of `self`
@@ -106,7 +109,7 @@ impl Sink for FilesSink {
             .take()
             .unwrap()
             .join()
-            .unwrap();
+            .unwrap()?;
 
         // return a dummy dataframe;
         Ok(FinalizedSink::Finished(Default::default()))
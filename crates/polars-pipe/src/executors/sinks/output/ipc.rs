@@ -1,3 +1,4 @@
+use std::fs::File;
 use std::path::Path;
 
 use crossbeam_channel::bounded;
@@ -8,15 +9,22 @@ use polars_io::prelude::*;
 use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
 use crate::pipeline::morsels_per_sink;
 
+pub(super) fn ipc_writer(
+    file: File,
+    options: IpcWriterOptions,
+    schema: &Schema,
+) -> PolarsResult<polars_io::ipc::BatchedWriter<File>> {
+    IpcWriter::new(file)
+        .with_compression(options.compression)
+        .batched(schema)
+}
+
 pub struct IpcSink {}
 impl IpcSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(path: &Path, options: IpcWriterOptions, schema: &Schema) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
-        let writer = IpcWriter::new(file)
-            .with_compression(options.compression)
-            .batched(schema)?;
-
+        let file = File::create(path)?;
+        let writer = ipc_writer(file, options, schema)?;
         let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
 
         let morsels_per_sink = morsels_per_sink();
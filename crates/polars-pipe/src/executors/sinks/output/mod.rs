@@ -1,3 +1,5 @@
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+mod checkpoint;
 #[cfg(feature = "csv")]
 mod csv;
 #[cfg(any(
@@ -14,6 +16,8 @@ mod json;
 #[cfg(feature = "parquet")]
 mod parquet;
 
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+pub use checkpoint::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
 #[cfg(feature = "ipc")]
@@ -1,4 +1,5 @@
 use std::any::Any;
+use std::fs::File;
 use std::path::Path;
 use std::thread::JoinHandle;
 
@@ -16,7 +17,7 @@ type RowGroups = Vec<RowGroupIterColumns<'static, PolarsError>>;
 
 pub(super) fn init_row_group_writer_thread(
     receiver: Receiver<Option<(IdxSize, RowGroups)>>,
-    writer: Arc<BatchedWriter<std::fs::File>>,
+    writer: Arc<BatchedWriter<File>>,
     // this is used to determine when a batch of chunks should be written to disk
     // all chunks per push should be collected to determine in which order they should
     // be written
@@ -51,25 +52,33 @@ pub(super) fn init_row_group_writer_thread(
     })
 }
 
+pub(super) fn parquet_writer(
+    file: File,
+    options: ParquetWriteOptions,
+    schema: &Schema,
+) -> PolarsResult<BatchedWriter<File>> {
+    ParquetWriter::new(file)
+        .with_compression(options.compression)
+        .with_data_page_size(options.data_pagesize_limit)
+        .with_statistics(options.statistics)
+        .with_row_group_size(options.row_group_size)
+        // This is important! Otherwise we will deadlock
+        // See: #7074
+        .set_parallel(false)
+        .batched(schema)
+}
+
 #[derive(Clone)]
 pub struct ParquetSink {
-    writer: Arc<BatchedWriter<std::fs::File>>,
+    writer: Arc<BatchedWriter<File>>,
     io_thread_handle: Arc<Option<JoinHandle<()>>>,
     sender: Sender<Option<(IdxSize, RowGroups)>>,
 }
 impl ParquetSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(path: &Path, options: ParquetWriteOptions, schema: &Schema) -> PolarsResult<Self> {
-        let file = std::fs::File::create(path)?;
-        let writer = ParquetWriter::new(file)
-            .with_compression(options.compression)
-            .with_data_page_size(options.data_pagesize_limit)
-            .with_statistics(options.statistics)
-            .with_row_group_size(options.row_group_size)
-            // This is important! Otherwise we will deadlock
-            // See: #7074
-            .set_parallel(false)
-            .batched(schema)?;
+        let file = File::create(path)?;
+        let writer = parquet_writer(file, options, schema)?;
 
         let writer = Arc::new(writer);
         let morsels_per_sink = morsels_per_sink();
@@ -8,7 +8,7 @@ use polars_core::series::IsSorted;
 use polars_core::utils::{
     accumulate_dataframes_vertical_unchecked, accumulate_dataframes_vertical_unchecked_optional,
 };
-use polars_core::POOL;
+use polars_core::{polars_event, POOL};
 use polars_io::ipc::IpcReader;
 use polars_io::SerReader;
 use polars_ops::prelude::*;
@@ -16,6 +16,7 @@ use rayon::prelude::*;
 
 use crate::executors::sinks::io::{DfIter, IOThread};
 use crate::executors::sinks::memory::MemTracker;
+use crate::executors::sinks::sort::sink::memory_limit;
 use crate::executors::sinks::sort::source::SortSource;
 use crate::operators::FinalizedSink;
 
@@ -116,7 +117,7 @@ impl PartitionSpiller {
                     }
                 })
         });
-        eprintln!("PARTITIONED FORCE SPILLED")
+        polars_event!(target: "polars::streaming::spill", "PARTITIONED FORCE SPILLED")
     }
 }
 
@@ -130,7 +131,6 @@ pub(super) fn sort_ooc(
     descending: bool,
     nulls_last: bool,
     slice: Option<(i64, usize)>,
-    verbose: bool,
     memtrack: MemTracker,
     ooc_start: Instant,
 ) -> PolarsResult<FinalizedSink> {
@@ -140,10 +140,14 @@ pub(super) fn sort_ooc(
         .map(|v| v.parse::<usize>().expect("integer"))
         .unwrap_or(1 << 26);
     let samples = samples.to_physical_repr().into_owned();
-    let spill_size = std::cmp::min(
+    let mut spill_size = std::cmp::min(
         memtrack.get_available_latest() / (samples.len() * 3),
         spill_size,
     );
+    // keep the buffered partitions within the memory budget
+    if let Some(limit) = memory_limit() {
+        spill_size = std::cmp::min(limit / (samples.len() + 1), spill_size);
+    }
 
     // we collect as I am not sure that if we write to the same directory the
     // iterator will read those also.
@@ -151,10 +155,8 @@ pub(super) fn sort_ooc(
     let dir = &io_thread.dir;
     let files = std::fs::read_dir(dir)?.collect::<std::io::Result<Vec<_>>>()?;
 
-    if verbose {
-        eprintln!("spill size: {} mb", spill_size / 1024 / 1024);
-        eprintln!("processing {} files", files.len());
-    }
+    polars_event!(target: "polars::streaming::spill", "spill size: {} mb", spill_size / 1024 / 1024);
+    polars_event!(target: "polars::streaming::spill", files = files.len(); "processing {} files", files.len());
 
     let partitions_spiller = PartitionSpiller::new(samples.len(), spill_size as u64);
 
@@ -182,9 +184,7 @@ pub(super) fn sort_ooc(
             PolarsResult::Ok(())
         })
     })?;
-    if verbose {
-        eprintln!("partitioning sort took: {:?}", now.elapsed());
-    }
+    polars_event!(target: "polars::streaming::spill", "partitioning sort took: {:?}", now.elapsed());
 
     // Branch for testing so we hit different parts in the Source phase.
     #[cfg(debug_assertions)]
@@ -217,7 +217,6 @@ pub(super) fn sort_ooc(
         descending,
         nulls_last,
         slice,
-        verbose,
         io_thread,
         memtrack,
         ooc_start,
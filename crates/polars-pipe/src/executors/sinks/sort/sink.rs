@@ -3,9 +3,9 @@ use std::sync::{Arc, RwLock};
 use std::time::Instant;
 
 use polars_core::chunked_array::ops::SortMultipleOptions;
-use polars_core::config::verbose;
 use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
+use polars_core::polars_event;
 use polars_core::prelude::{AnyValue, SchemaRef, Series, SortOptions};
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 
@@ -15,6 +15,14 @@ use crate::executors::sinks::sort::ooc::sort_ooc;
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
 use crate::pipeline::{morsels_per_sink, FORCE_OOC};
 
+/// The memory budget in bytes of the sort, as set by `POLARS_OOC_SORT_MEMORY_LIMIT`. Sorts of
+/// data that exceeds the budget are spilled to disk.
+pub(super) fn memory_limit() -> Option<usize> {
+    std::env::var("POLARS_OOC_SORT_MEMORY_LIMIT")
+        .ok()
+        .map(|v| v.parse::<usize>().expect("integer"))
+}
+
 pub struct SortSink {
     schema: SchemaRef,
     chunks: Vec<DataFrame>,
@@ -23,6 +31,8 @@ pub struct SortSink {
     mem_track: MemTracker,
     // sort in-memory or out-of-core
     ooc: bool,
+    // memory budget in bytes of the in-memory sort, after which we go out-of-core
+    memory_limit: Option<usize>,
     // when ooc, we write to disk using an IO thread
     // RwLock as we want to have multiple readers at once.
     io_thread: Arc<RwLock<Option<IOThread>>>,
@@ -57,6 +67,7 @@ impl SortSink {
             chunks: Default::default(),
             mem_track: MemTracker::new(n_morsels_per_sink),
             ooc,
+            memory_limit: memory_limit(),
             io_thread: Default::default(),
             sort_idx,
             slice,
@@ -67,18 +78,14 @@ impl SortSink {
             ooc_start: None,
         };
         if ooc {
-            if verbose() {
-                eprintln!("OOC sort forced");
-            }
+            polars_event!(target: "polars::streaming::spill", "OOC sort forced");
             out.init_ooc().unwrap();
         }
         out
     }
 
     fn init_ooc(&mut self) -> PolarsResult<()> {
-        if verbose() {
-            eprintln!("OOC sort started");
-        }
+        polars_event!(target: "polars::streaming::spill", "OOC sort started");
         self.ooc_start = Some(Instant::now());
         self.ooc = true;
 
@@ -90,17 +97,19 @@ impl SortSink {
         Ok(())
     }
 
-    fn store_chunk(&mut self, chunk: DataChunk) -> PolarsResult<()> {
+    fn store_chunk(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<()> {
         let chunk_bytes = chunk.data.estimated_size();
         if !self.ooc {
             let used = self.mem_track.fetch_add(chunk_bytes);
-            let free = self.mem_track.get_available();
 
             // we need some free memory to be able to sort
             // so we keep 3x the sort data size before we go out of core
-            if used * 3 > free {
+            if self.mem_track.should_spill(used, context)
+                || self.memory_limit.is_some_and(|limit| used > limit)
+            {
                 self.init_ooc()?;
                 self.dump(true)?;
+                self.mem_track.release();
             }
         };
         // don't add empty dataframes
@@ -142,8 +151,8 @@ impl SortSink {
 }
 
 impl Sink for SortSink {
-    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
-        self.store_chunk(chunk)?;
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        self.store_chunk(context, chunk)?;
 
         if self.ooc {
             self.dump(false)?;
@@ -172,6 +181,7 @@ impl Sink for SortSink {
             chunks: Default::default(),
             mem_track: self.mem_track.clone(),
             ooc: self.ooc,
+            memory_limit: self.memory_limit,
             io_thread: self.io_thread.clone(),
             sort_idx: self.sort_idx,
             slice: self.slice,
@@ -183,7 +193,7 @@ impl Sink for SortSink {
         })
     }
 
-    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         if self.ooc {
             // spill everything
             self.dump(true).unwrap();
@@ -194,13 +204,9 @@ impl Sink for SortSink {
             let dist = dist.sort_with(SortOptions::from(&self.sort_options))?;
 
             let instant = self.ooc_start.unwrap();
-            if context.verbose {
-                eprintln!("finished sinking into OOC sort in {:?}", instant.elapsed());
-            }
+            polars_event!(target: "polars::streaming::spill", "finished sinking into OOC sort in {:?}", instant.elapsed());
             block_thread_until_io_thread_done(&io_thread);
-            if context.verbose {
-                eprintln!("full file dump of OOC sort took {:?}", instant.elapsed());
-            }
+            polars_event!(target: "polars::streaming::spill", "full file dump of OOC sort took {:?}", instant.elapsed());
 
             sort_ooc(
                 io_thread,
@@ -209,7 +215,6 @@ impl Sink for SortSink {
                 self.sort_options.descending[0],
                 self.sort_options.nulls_last[0],
                 self.slice,
-                context.verbose,
                 self.mem_track.clone(),
                 instant,
             )
@@ -4,7 +4,7 @@ use std::time::Instant;
 
 use polars_core::prelude::*;
 use polars_core::utils::{accumulate_dataframes_vertical_unchecked, split_df};
-use polars_core::POOL;
+use polars_core::{polars_event, POOL};
 use rayon::prelude::*;
 
 use crate::executors::sinks::io::IOThread;
@@ -41,15 +41,12 @@ impl SortSource {
         descending: bool,
         nulls_last: bool,
         slice: Option<(i64, usize)>,
-        verbose: bool,
         io_thread: IOThread,
         memtrack: MemTracker,
         ooc_start: Instant,
         partition_spiller: PartitionSpiller,
     ) -> Self {
-        if verbose {
-            eprintln!("started sort source phase");
-        }
+        polars_event!(target: "polars::streaming::spill", "started sort source phase");
 
         files.sort_unstable_by_key(|entry| entry.0);
 
@@ -136,11 +133,17 @@ impl SortSource {
         let dfs = split_df(&mut df, self.n_threads, true);
         Ok(SourceResult::GotMoreData(self.finish_batch(dfs)))
     }
-    fn print_verbose(&self, verbose: bool) {
-        if verbose {
-            eprintln!("sort source phase took: {:?}", self.source_start.elapsed());
-            eprintln!("full ooc sort took: {:?}", self.ooc_start.elapsed());
-        }
+    fn print_verbose(&self) {
+        polars_event!(
+            target: "polars::streaming::spill",
+            "sort source phase took: {:?}",
+            self.source_start.elapsed()
+        );
+        polars_event!(
+            target: "polars::streaming::spill",
+            "full ooc sort took: {:?}",
+            self.ooc_start.elapsed()
+        );
     }
 
     fn get_from_memory(
@@ -163,10 +166,10 @@ impl SortSource {
 }
 
 impl Source for SortSource {
-    fn get_batches(&mut self, context: &PExecutionContext) -> PolarsResult<SourceResult> {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
         // early return
         if self.finished || self.current_part >= self.partition_spiller.len() {
-            self.print_verbose(context.verbose);
+            self.print_verbose();
             return Ok(SourceResult::Finished);
         }
         self.current_part += 1;
@@ -183,7 +186,7 @@ impl Source for SortSource {
                     false,
                 );
                 if read.is_empty() {
-                    self.print_verbose(context.verbose);
+                    self.print_verbose();
                     Ok(SourceResult::Finished)
                 } else {
                     self.finished = true;
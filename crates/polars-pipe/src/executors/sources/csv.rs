@@ -9,7 +9,12 @@ use polars_plan::prelude::FileScanOptions;
 use polars_utils::iter::EnumerateIdxTrait;
 
 use super::*;
-use crate::pipeline::determine_chunk_size;
+use crate::pipeline::{determine_chunk_size, source_batch_size};
+
+// Bounds of the byte ranges a file is split into. A file is split into a morsel per thread, but
+// tiny morsels aren't worth the overhead and huge morsels blow up the memory.
+const MIN_MORSEL_SIZE: usize = 1 << 16;
+const MAX_MORSEL_SIZE: usize = 1 << 22;
 
 pub(crate) struct CsvSource {
     #[allow(dead_code)]
@@ -19,7 +24,6 @@ pub(crate) struct CsvSource {
     // (so we have to order the `batched_reader` first in the struct fields)
     batched_reader: Option<BatchedCsvReader<'static>>,
     reader: Option<CsvReader<File>>,
-    n_threads: usize,
     paths: Arc<[PathBuf]>,
     options: Option<CsvReadOptions>,
     file_options: Option<FileScanOptions>,
@@ -83,7 +87,8 @@ impl CsvSource {
         });
         // inversely scale the chunk size by the number of threads so that we reduce memory pressure
         // in streaming
-        let chunk_size = determine_chunk_size(n_cols, POOL.current_num_threads())?;
+        let n_threads = POOL.current_num_threads();
+        let chunk_size = determine_chunk_size(n_cols, n_threads)?;
 
         if self.verbose {
             eprintln!("STREAMING CHUNK SIZE: {chunk_size} rows")
@@ -122,7 +127,17 @@ impl CsvSource {
 
         // Safety: `reader` outlives `batched_reader`
         let reader: &'static mut CsvReader<File> = unsafe { std::mem::transmute(reader) };
-        let batched_reader = reader.batched_borrowed()?;
+        let mut batched_reader = reader.batched_borrowed()?;
+        // Unless the chunk size is configured, we split the file into byte ranges that are
+        // aligned to the rows, so that the threads parse morsels of about the same size.
+        if config::get_streaming_chunk_size()?.is_none() {
+            let morsel_size =
+                (batched_reader.n_bytes() / n_threads).clamp(MIN_MORSEL_SIZE, MAX_MORSEL_SIZE);
+            if self.verbose {
+                eprintln!("STREAMING CSV MORSEL SIZE: {morsel_size} bytes")
+            }
+            batched_reader.with_morsel_size(morsel_size);
+        }
         self.batched_reader = Some(batched_reader);
         Ok(())
     }
@@ -138,7 +153,6 @@ impl CsvSource {
             schema,
             reader: None,
             batched_reader: None,
-            n_threads: POOL.current_num_threads(),
             paths,
             options: Some(options),
             file_options: Some(file_options),
@@ -152,7 +166,12 @@ impl CsvSource {
 
 impl Source for CsvSource {
     fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
-        loop {
+        let n_batches = source_batch_size();
+        let mut batches = Vec::with_capacity(n_batches);
+
+        // The batches of a file are parsed in parallel. If a file runs out we continue with the
+        // next, so that small files don't leave threads idle.
+        while batches.len() < n_batches {
             let first_read_from_file = self.reader.is_none();
 
             if first_read_from_file {
@@ -161,45 +180,50 @@ impl Source for CsvSource {
 
             if self.reader.is_none() {
                 // No more readers
-                return Ok(SourceResult::Finished);
+                break;
             }
 
-            let Some(batches) = self
+            let Some(file_batches) = self
                 .batched_reader
                 .as_mut()
                 .unwrap()
-                .next_batches(self.n_threads)?
+                .next_batches(n_batches - batches.len())?
             else {
+                self.batched_reader = None;
                 self.reader = None;
                 continue;
             };
 
             if first_read_from_file {
-                let first_df = batches.first().unwrap();
+                let first_df = file_batches.first().unwrap();
                 if self.schema_check_df.width() == 0 {
                     self.schema_check_df = first_df.clear();
                 }
                 self.schema_check_df.vstack(first_df)?;
             }
 
-            let index = get_source_index(0);
-            let mut n_rows_read = 0;
-            let out = batches
-                .into_iter()
-                .enumerate_u32()
-                .map(|(i, data)| {
-                    n_rows_read += data.height();
-                    DataChunk {
-                        chunk_index: (index + i) as IdxSize,
-                        data,
-                    }
-                })
-                .collect::<Vec<_>>();
+            // The row limit and index of the next file depend on the rows read so far.
+            let n_rows_read = file_batches.iter().map(|df| df.height()).sum::<usize>();
             self.n_rows_read = self.n_rows_read.saturating_add(n_rows_read);
-            get_source_index(out.len() as u32);
+            batches.extend(file_batches);
+        }
 
-            return Ok(SourceResult::GotMoreData(out));
+        if batches.is_empty() {
+            return Ok(SourceResult::Finished);
         }
+
+        let index = get_source_index(0);
+        let out = batches
+            .into_iter()
+            .enumerate_u32()
+            .map(|(i, data)| DataChunk {
+                chunk_index: (index + i) as IdxSize,
+                data,
+            })
+            .collect::<Vec<_>>();
+        get_source_index(out.len() as u32);
+
+        Ok(SourceResult::GotMoreData(out))
     }
     fn fmt(&self) -> &str {
         "csv"
@@ -9,10 +9,10 @@ use polars_utils::IdxSize;
 
 use crate::executors::sources::get_source_index;
 use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+use crate::pipeline::source_batch_size;
 
 pub struct DataFrameSource {
     dfs: Enumerate<IntoIter<DataFrame>>,
-    n_threads: usize,
 }
 
 impl DataFrameSource {
@@ -20,7 +20,7 @@ impl DataFrameSource {
         let n_threads = POOL.current_num_threads();
         let dfs = split_df(&mut df, n_threads, false);
         let dfs = dfs.into_iter().enumerate();
-        Self { dfs, n_threads }
+        Self { dfs }
     }
 }
 
@@ -32,7 +32,7 @@ impl Source for DataFrameSource {
                 chunk_index: (chunk_index as u32 + idx_offset) as IdxSize,
                 data,
             })
-            .take(self.n_threads)
+            .take(source_batch_size())
             .collect::<Vec<_>>();
         get_source_index(chunks.len() as u32);
 
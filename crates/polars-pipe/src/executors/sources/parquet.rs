@@ -26,7 +26,7 @@ use polars_utils::IdxSize;
 
 use crate::executors::sources::get_source_index;
 use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
-use crate::pipeline::determine_chunk_size;
+use crate::pipeline::{determine_chunk_size, source_batch_size};
 
 pub struct ParquetSource {
     batched_readers: VecDeque<BatchedParquetReader>,
@@ -283,7 +283,7 @@ impl Source for ParquetSource {
         };
 
         let batches =
-            get_runtime().block_on_potential_spawn(reader.next_batches(self.n_threads))?;
+            get_runtime().block_on_potential_spawn(reader.next_batches(source_batch_size()))?;
 
         Ok(match batches {
             None => {
@@ -1,9 +1,15 @@
+use std::sync::Arc;
+
 use polars_expr::state::ExecutionState;
 
+use crate::pipeline::{metrics_subscriber, StreamingMetricsSubscriber};
+
 pub struct PExecutionContext {
     // injected upstream in polars-lazy
     pub(crate) execution_state: ExecutionState,
     pub(crate) verbose: bool,
+    // the subscriber that receives the metrics of the pipelines, if any
+    pub(crate) metrics: Option<Arc<dyn StreamingMetricsSubscriber>>,
 }
 
 impl PExecutionContext {
@@ -11,6 +17,7 @@ impl PExecutionContext {
         PExecutionContext {
             execution_state: state,
             verbose,
+            metrics: metrics_subscriber(),
         }
     }
 }
@@ -1 +1,56 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
 
+use polars_core::POOL;
+
+// Memory held by the memory tracking sinks of all running pipelines.
+static MEMORY_IN_USE: AtomicUsize = AtomicUsize::new(0);
+
+/// The memory budget in bytes of the streaming engine, as set by
+/// `POLARS_STREAMING_MEMORY_LIMIT`. If set, the sinks spill to disk when the memory they hold
+/// exceeds the budget, instead of when the system runs low on free memory.
+pub(crate) fn memory_budget() -> Option<usize> {
+    std::env::var("POLARS_STREAMING_MEMORY_LIMIT")
+        .ok()
+        .map(|v| v.parse::<usize>().expect("integer"))
+}
+
+/// The maximum number of chunks a source produces at once, as set by
+/// `POLARS_STREAMING_QUEUE_DEPTH`. Every thread processes a single chunk, so this defaults to
+/// and is capped at the number of threads.
+pub(crate) fn queue_depth() -> usize {
+    let n_threads = POOL.current_num_threads();
+    std::env::var("POLARS_STREAMING_QUEUE_DEPTH")
+        .map(|v| v.parse::<usize>().expect("integer").clamp(1, n_threads))
+        .unwrap_or(n_threads)
+}
+
+/// Whether median and quantile aggregations may be approximated, as set by
+/// `POLARS_STREAMING_APPROX_QUANTILE`. Only then are group bys with these aggregations run in
+/// the streaming engine, which estimates them with mergeable sketches of the groups.
+pub(crate) fn approx_quantiles() -> bool {
+    std::env::var("POLARS_STREAMING_APPROX_QUANTILE").as_deref() == Ok("1")
+}
+
+pub(crate) fn memory_in_use() -> usize {
+    MEMORY_IN_USE.load(Ordering::Relaxed)
+}
+
+pub(crate) fn reserve_memory(bytes: usize) {
+    MEMORY_IN_USE.fetch_add(bytes, Ordering::Relaxed);
+}
+
+pub(crate) fn release_memory(bytes: usize) {
+    let _ = MEMORY_IN_USE.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+        Some(used.saturating_sub(bytes))
+    });
+}
+
+/// The number of chunks a source should produce at once. This is the queue depth, unless the
+/// memory budget is exceeded, in which case the sources apply backpressure by producing a
+/// single chunk at a time until the sinks have spilled.
+pub(crate) fn source_batch_size() -> usize {
+    match memory_budget() {
+        Some(budget) if memory_in_use() > budget => 1,
+        _ => queue_depth(),
+    }
+}
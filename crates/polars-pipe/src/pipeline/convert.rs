@@ -176,6 +176,16 @@ where
                 SinkType::Memory => {
                     Box::new(OrderedSink::new(input_schema.into_owned())) as Box<dyn SinkTrait>
                 },
+                #[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+                SinkType::File {
+                    path,
+                    file_type,
+                    checkpoint: true,
+                } => Box::new(CheckpointSink::new(
+                    path.as_ref().as_path(),
+                    file_type,
+                    input_schema.as_ref(),
+                )?) as Box<dyn SinkTrait>,
                 #[allow(unused_variables)]
                 SinkType::File {
                     path, file_type, ..
@@ -474,10 +484,11 @@ where
             keys,
             aggs,
             schema: output_schema,
+            maintain_order,
             options,
             ..
         } => {
-            let input_schema = lp_arena.get(*input).schema(lp_arena).as_ref().clone();
+            let mut input_schema = lp_arena.get(*input).schema(lp_arena).as_ref().clone();
             let key_columns = Arc::new(exprs_to_physical(
                 keys,
                 expr_arena,
@@ -485,9 +496,9 @@ where
                 Some(&input_schema),
             )?);
 
-            let mut aggregation_columns = Vec::with_capacity(aggs.len());
-            let mut agg_fns = Vec::with_capacity(aggs.len());
-            let mut input_agg_dtypes = Vec::with_capacity(aggs.len());
+            let mut aggregation_columns = Vec::with_capacity(aggs.len() + 1);
+            let mut agg_fns = Vec::with_capacity(aggs.len() + 1);
+            let mut input_agg_dtypes = Vec::with_capacity(aggs.len() + 1);
 
             for e in aggs {
                 let (input_dtype, index, agg_fn) =
@@ -496,17 +507,35 @@ where
                 agg_fns.push(agg_fn);
                 input_agg_dtypes.push(input_dtype);
             }
+
+            // To maintain the order, we aggregate where every group was first seen and order
+            // the groups on that once they are aggregated.
+            let (output_schema, slice) = if *maintain_order {
+                let (input_dtype, index, agg_fn) = group_by::aggregates::first_seen_agg();
+                aggregation_columns.push(index);
+                agg_fns.push(agg_fn);
+                input_agg_dtypes.push(input_dtype);
+
+                let mut schema = input_schema.as_ref().clone();
+                schema.with_column(group_by::FIRST_SEEN_NAME.into(), DataType::UInt64);
+                input_schema = Arc::new(schema);
+                let mut schema = output_schema.as_ref().clone();
+                schema.with_column(group_by::FIRST_SEEN_NAME.into(), DataType::UInt64);
+                (Arc::new(schema), None)
+            } else {
+                (output_schema.clone(), options.slice)
+            };
             let aggregation_columns = Arc::new(aggregation_columns);
 
-            if std::env::var("POLARS_STREAMING_GB2").as_deref() == Ok("1") {
+            let group_by_sink = if std::env::var("POLARS_STREAMING_GB2").as_deref() == Ok("1") {
                 Box::new(GenericGroupby2::new(
                     key_columns,
                     aggregation_columns,
                     Arc::from(agg_fns),
                     output_schema.clone(),
                     input_agg_dtypes,
-                    options.slice,
-                ))
+                    slice,
+                )) as Box<dyn SinkTrait>
             } else {
                 match (
                     output_schema.get_at_index(0).unwrap().1.to_physical(),
@@ -520,7 +549,7 @@ where
                                 agg_fns,
                                 input_schema,
                                 output_schema.clone(),
-                                options.slice,
+                                slice,
                             )) as Box<dyn SinkTrait>
                         })
                     },
@@ -530,7 +559,7 @@ where
                         agg_fns,
                         input_schema,
                         output_schema.clone(),
-                        options.slice,
+                        slice,
                     )) as Box<dyn SinkTrait>,
                     _ => Box::new(GenericGroupby2::new(
                         key_columns,
@@ -538,9 +567,19 @@ where
                         Arc::from(agg_fns),
                         output_schema.clone(),
                         input_agg_dtypes,
-                        options.slice,
+                        slice,
                     )),
                 }
+            };
+
+            if *maintain_order {
+                Box::new(group_by::OrderedGroupBySink::new(
+                    group_by_sink,
+                    output_schema,
+                    options.slice,
+                ))
+            } else {
+                group_by_sink
             }
         },
         lp => {
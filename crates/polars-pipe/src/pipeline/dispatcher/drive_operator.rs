@@ -1,4 +1,5 @@
 use super::*;
+use crate::pipeline::metrics::{execute_operator, sink_chunk};
 use crate::pipeline::*;
 
 /// Take data chunks from the sources and pushes them into the operators + sink. Every operator
@@ -45,7 +46,7 @@ pub(super) fn par_process_chunks(
 
             s.spawn(move |_| {
                 let out = if operator_pipe.is_empty() {
-                    sink.sink(ec, chunk)
+                    sink_chunk(ec, &mut **sink, chunk)
                 } else {
                     push_operators_single_thread(chunk, ec, operator_pipe, sink, must_flush)
                 };
@@ -98,13 +99,13 @@ pub(super) fn push_operators_single_thread(
     while let Some((op_i, chunk)) = in_process.pop() {
         match operators.get_mut(op_i) {
             None => {
-                if let SinkResult::Finished = sink.sink(ec, chunk)? {
+                if let SinkResult::Finished = sink_chunk(ec, &mut **sink, chunk)? {
                     return Ok(SinkResult::Finished);
                 }
             },
             Some(op) => {
                 let op = op.get_mut();
-                match op.execute(ec, &chunk)? {
+                match execute_operator(ec, op, &chunk)? {
                     OperatorResult::Finished(chunk) => {
                         must_flush.store(op.must_flush(), Ordering::Relaxed);
                         in_process.push((op_i + 1, chunk))
@@ -219,13 +220,13 @@ pub(super) fn flush_operators(
                     Some(chunk) => {
                         match operators.get_mut(op_i) {
                             None => {
-                                if let SinkResult::Finished = sink.sink(ec, chunk)? {
+                                if let SinkResult::Finished = sink_chunk(ec, &mut **sink, chunk)? {
                                     return Ok(SinkResult::Finished);
                                 }
                             },
                             Some(op) => {
                                 let op = op.get_mut();
-                                match op.execute(ec, &chunk)? {
+                                match execute_operator(ec, op, &chunk)? {
                                     OperatorResult::Finished(chunk) => {
                                         in_process.push((op_i + 1, Some(chunk)))
                                     },
@@ -191,6 +191,14 @@ impl PipeLine {
                 while let SourceResult::GotMoreData(chunks) = next_batches {
                     // Every batches iteration we check if we must continue.
                     ec.execution_state.should_stop()?;
+                    if let Some(subscriber) = &ec.metrics {
+                        subscriber.on_event(&StreamingEvent::Batch {
+                            source: src.fmt(),
+                            n_chunks: chunks.len(),
+                            queue_depth: queue_depth(),
+                            memory_in_use: memory_in_use(),
+                        });
+                    }
 
                     let (sink_result, next_batches2) = par_process_chunks(
                         chunks,
@@ -304,7 +312,7 @@ impl PipeLine {
     ) -> PolarsResult<Option<FinalizedSink>> {
         let (sink_shared_count, mut reduced_sink) = self.run_pipeline_no_finalize(ec, pipelines)?;
         assert_eq!(sink_shared_count, 0);
-        Ok(reduced_sink.finalize(ec).ok())
+        reduced_sink.finalize(ec).map(Some)
     }
 }
 
@@ -0,0 +1,109 @@
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use polars_core::error::PolarsResult;
+
+use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext, Sink, SinkResult};
+
+static SUBSCRIBER: RwLock<Option<Arc<dyn StreamingMetricsSubscriber>>> = RwLock::new(None);
+
+/// An event that is reported to the [`StreamingMetricsSubscriber`] during streaming execution.
+#[derive(Debug, Clone)]
+pub enum StreamingEvent<'a> {
+    /// A source produced a batch of chunks, which are processed at the same time.
+    Batch {
+        source: &'a str,
+        n_chunks: usize,
+        /// The maximum number of chunks in a batch.
+        queue_depth: usize,
+        /// The memory in bytes held by the sinks of all running pipelines.
+        memory_in_use: usize,
+    },
+    /// An operator processed a chunk. An operator that produces its output in multiple chunks
+    /// reports the rows of its input with the last of these.
+    Operator {
+        operator: &'a str,
+        rows_in: usize,
+        rows_out: usize,
+        elapsed: Duration,
+    },
+    /// A sink consumed a chunk.
+    Sink {
+        sink: &'a str,
+        rows: usize,
+        elapsed: Duration,
+    },
+    /// An operation started spilling to disk.
+    Spill { operation: &'a str },
+}
+
+/// Receives the [`StreamingEvent`]s of all streaming queries, e.g. to monitor long-running
+/// pipelines. Events are reported from the threads that execute the pipelines, so they should
+/// be handled quickly.
+pub trait StreamingMetricsSubscriber: Send + Sync {
+    fn on_event(&self, event: &StreamingEvent);
+}
+
+/// Set the subscriber that receives the metrics of streaming execution, or remove it with
+/// `None`. Queries that are already running keep reporting to the previous subscriber.
+pub fn set_streaming_metrics_subscriber(subscriber: Option<Arc<dyn StreamingMetricsSubscriber>>) {
+    *SUBSCRIBER.write().unwrap() = subscriber;
+}
+
+pub(crate) fn metrics_subscriber() -> Option<Arc<dyn StreamingMetricsSubscriber>> {
+    SUBSCRIBER.read().unwrap().clone()
+}
+
+/// Report an event to the subscriber, if any.
+pub(crate) fn report(event: StreamingEvent) {
+    if let Some(subscriber) = metrics_subscriber() {
+        subscriber.on_event(&event)
+    }
+}
+
+/// Execute an operator on a chunk and report it to the subscriber of the pipeline.
+pub(super) fn execute_operator(
+    ec: &PExecutionContext,
+    op: &mut dyn Operator,
+    chunk: &DataChunk,
+) -> PolarsResult<OperatorResult> {
+    let Some(subscriber) = &ec.metrics else {
+        return op.execute(ec, chunk);
+    };
+    let start = Instant::now();
+    let out = op.execute(ec, chunk)?;
+    let elapsed = start.elapsed();
+    let (rows_in, rows_out) = match &out {
+        OperatorResult::Finished(out) => (chunk.data.height(), out.data.height()),
+        // The operator is called again with the same chunk.
+        OperatorResult::HaveMoreOutPut(out) => (0, out.data.height()),
+        OperatorResult::NeedsNewData => (chunk.data.height(), 0),
+    };
+    subscriber.on_event(&StreamingEvent::Operator {
+        operator: op.fmt(),
+        rows_in,
+        rows_out,
+        elapsed,
+    });
+    Ok(out)
+}
+
+/// Push a chunk into a sink and report it to the subscriber of the pipeline.
+pub(super) fn sink_chunk(
+    ec: &PExecutionContext,
+    sink: &mut dyn Sink,
+    chunk: DataChunk,
+) -> PolarsResult<SinkResult> {
+    let Some(subscriber) = &ec.metrics else {
+        return sink.sink(ec, chunk);
+    };
+    let rows = chunk.data.height();
+    let start = Instant::now();
+    let out = sink.sink(ec, chunk)?;
+    subscriber.on_event(&StreamingEvent::Sink {
+        sink: sink.fmt(),
+        rows,
+        elapsed: start.elapsed(),
+    });
+    Ok(out)
+}
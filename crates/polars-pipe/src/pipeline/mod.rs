@@ -1,11 +1,18 @@
 mod config;
 mod convert;
 mod dispatcher;
+mod metrics;
 
+pub(crate) use config::{
+    approx_quantiles, memory_budget, memory_in_use, queue_depth, release_memory, reserve_memory,
+    source_batch_size,
+};
 pub use convert::{
     create_pipeline, get_dummy_operator, get_operator, get_sink, swap_join_order, CallBacks,
 };
 pub use dispatcher::{execute_pipeline, PipeLine};
+pub(crate) use metrics::{metrics_subscriber, report};
+pub use metrics::{set_streaming_metrics_subscriber, StreamingEvent, StreamingMetricsSubscriber};
 use polars_core::prelude::*;
 use polars_core::POOL;
 use polars_utils::cell::SyncUnsafeCell;
@@ -28,10 +35,8 @@ pub(crate) static FORCE_OOC: &str = "POLARS_FORCE_OOC";
 /// scale the chunk size depending on the number of
 /// columns. With 10 columns we use a chunk size of 40_000
 pub(crate) fn determine_chunk_size(n_cols: usize, n_threads: usize) -> PolarsResult<usize> {
-    if let Ok(val) = std::env::var("POLARS_STREAMING_CHUNK_SIZE") {
-        val.parse().map_err(
-            |_| polars_err!(ComputeError: "could not parse 'POLARS_STREAMING_CHUNK_SIZE' env var"),
-        )
+    if let Some(size) = polars_core::config::get_streaming_chunk_size()? {
+        Ok(size)
     } else {
         let thread_factor = std::cmp::max(12 / n_threads, 1);
         Ok(std::cmp::max(50_000 / n_cols.max(1) * thread_factor, 1000))
@@ -1,4 +1,12 @@
 use super::*;
+
+/// Text encoding of binary values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BinaryEncoding {
+    Hex,
+    Base64,
+}
+
 /// Specialized expressions for [`Series`] of [`DataType::String`].
 pub struct BinaryNameSpace(pub(crate) Expr);
 
@@ -58,4 +66,23 @@ impl BinaryNameSpace {
         self.0
             .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Base64Encode))
     }
+
+    /// Encode the binary values as strings in the given `encoding`.
+    #[cfg(feature = "binary_encoding")]
+    pub fn encode(self, encoding: BinaryEncoding) -> Expr {
+        match encoding {
+            BinaryEncoding::Hex => self.hex_encode(),
+            BinaryEncoding::Base64 => self.base64_encode(),
+        }
+    }
+
+    /// Decode binary values that hold text in the given `encoding`. If `strict`, invalid
+    /// values raise an error, otherwise they become null.
+    #[cfg(feature = "binary_encoding")]
+    pub fn decode(self, encoding: BinaryEncoding, strict: bool) -> Expr {
+        match encoding {
+            BinaryEncoding::Hex => self.hex_decode(strict),
+            BinaryEncoding::Base64 => self.base64_decode(strict),
+        }
+    }
 }
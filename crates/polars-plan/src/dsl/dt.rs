@@ -5,6 +5,9 @@ pub struct DateLikeNameSpace(pub(crate) Expr);
 
 impl DateLikeNameSpace {
     /// Add a given number of business days.
+    ///
+    /// The holidays of every row are `holidays` together with the dates in the row's list of
+    /// `row_holidays`, if that is given.
     #[cfg(feature = "business")]
     pub fn add_business_days(
         self,
@@ -12,14 +15,17 @@ impl DateLikeNameSpace {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
         roll: Roll,
+        row_holidays: Option<Expr>,
     ) -> Expr {
+        let mut input = vec![n];
+        input.extend(row_holidays);
         self.0.map_many_private(
             FunctionExpr::Business(BusinessFunction::AddBusinessDay {
                 week_mask,
                 holidays,
                 roll,
             }),
-            &[n],
+            &input,
             false,
             false,
         )
@@ -117,8 +123,18 @@ impl DateLikeNameSpace {
     /// Returns the ISO week number starting from 1.
     /// The return value ranges from 1 to 53. (The last week of year differs by years.)
     pub fn week(self) -> Expr {
+        self.week_with_start(WeekStart::Monday)
+    }
+
+    /// Get the week number of weeks that start on `week_start`.
+    ///
+    /// Like for ISO weeks, a week belongs to the year that contains its middle (fourth) day,
+    /// so the first week of a year is the one with most of its days in that year.
+    pub fn week_with_start(self, week_start: WeekStart) -> Expr {
         self.0
-            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Week))
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Week(
+                week_start,
+            )))
     }
 
     /// Extract the ISO week day from the underlying Date representation.
@@ -126,8 +142,15 @@ impl DateLikeNameSpace {
 
     /// Returns the weekday number where monday = 1 and sunday = 7
     pub fn weekday(self) -> Expr {
+        self.weekday_with_start(WeekStart::Monday)
+    }
+
+    /// Get the weekday number, where `week_start` = 1 and the day before it = 7.
+    pub fn weekday_with_start(self, week_start: WeekStart) -> Expr {
         self.0
-            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::WeekDay))
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::WeekDay(
+                week_start,
+            )))
     }
 
     /// Get the month of a Date/Datetime.
@@ -204,8 +227,14 @@ impl DateLikeNameSpace {
 
     /// Truncate the Datetime/Date range into buckets.
     pub fn truncate(self, every: Expr) -> Expr {
+        self.truncate_with_week_start(every, WeekStart::Monday)
+    }
+
+    /// Truncate the Datetime/Date range into buckets, where weekly buckets start on
+    /// `week_start`.
+    pub fn truncate_with_week_start(self, every: Expr, week_start: WeekStart) -> Expr {
         self.0.map_many_private(
-            FunctionExpr::TemporalExpr(TemporalFunction::Truncate),
+            FunctionExpr::TemporalExpr(TemporalFunction::Truncate(week_start)),
             &[every],
             false,
             false,
@@ -241,9 +270,12 @@ impl DateLikeNameSpace {
     }
 
     /// Round the Datetime/Date range into buckets.
-    pub fn round(self, every: Expr) -> Expr {
+    ///
+    /// Calendar durations such as `"1mo"` round to the nearest calendar boundary. A value
+    /// halfway between two boundaries is rounded according to `ties`.
+    pub fn round(self, every: Expr, ties: RoundTies) -> Expr {
         self.0.map_many_private(
-            FunctionExpr::TemporalExpr(TemporalFunction::Round),
+            FunctionExpr::TemporalExpr(TemporalFunction::Round(ties)),
             &[every],
             false,
             false,
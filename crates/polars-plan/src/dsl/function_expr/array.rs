@@ -9,6 +9,7 @@ pub enum ArrayFunction {
     Min,
     Max,
     Sum,
+    Mean,
     ToList,
     Unique(bool),
     NUnique,
@@ -38,6 +39,7 @@ impl ArrayFunction {
         match self {
             Min | Max => mapper.map_to_list_and_array_inner_dtype(),
             Sum => mapper.nested_sum_type(),
+            Mean => mapper.with_dtype(DataType::Float64),
             ToList => mapper.try_map_dtype(map_array_dtype_to_list_dtype),
             Unique(_) => mapper.try_map_dtype(map_array_dtype_to_list_dtype),
             NUnique => mapper.with_dtype(IDX_DTYPE),
@@ -75,6 +77,7 @@ impl Display for ArrayFunction {
             Min => "min",
             Max => "max",
             Sum => "sum",
+            Mean => "mean",
             ToList => "to_list",
             Unique(_) => "unique",
             NUnique => "n_unique",
@@ -108,6 +111,7 @@ impl From<ArrayFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Min => map!(min),
             Max => map!(max),
             Sum => map!(sum),
+            Mean => map!(mean),
             ToList => map!(to_list),
             Unique(stable) => map!(unique, stable),
             NUnique => map!(n_unique),
@@ -145,6 +149,10 @@ pub(super) fn sum(s: &Series) -> PolarsResult<Series> {
     s.array()?.array_sum()
 }
 
+pub(super) fn mean(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_mean()
+}
+
 pub(super) fn std(s: &Series, ddof: u8) -> PolarsResult<Series> {
     s.array()?.array_std(ddof)
 }
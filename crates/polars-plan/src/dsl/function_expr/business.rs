@@ -68,7 +68,8 @@ pub(super) fn business_day_count(
 ) -> PolarsResult<Series> {
     let start = &s[0];
     let end = &s[1];
-    polars_ops::prelude::business_day_count(start, end, week_mask, holidays)
+    let row_holidays = s.get(2);
+    polars_ops::prelude::business_day_count(start, end, week_mask, holidays, row_holidays)
 }
 
 #[cfg(feature = "business")]
@@ -80,5 +81,6 @@ pub(super) fn add_business_days(
 ) -> PolarsResult<Series> {
     let start = &s[0];
     let n = &s[1];
-    polars_ops::prelude::add_business_days(start, n, week_mask, holidays, roll)
+    let row_holidays = s.get(2);
+    polars_ops::prelude::add_business_days(start, n, week_mask, holidays, roll, row_holidays)
 }
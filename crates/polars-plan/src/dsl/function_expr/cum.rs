@@ -20,6 +20,13 @@ pub(super) fn cum_max(s: &Series, reverse: bool) -> PolarsResult<Series> {
     polars_ops::prelude::cum_max(s, reverse)
 }
 
+pub(super) fn groups_udf(
+    f: fn(&Series, &[i64], bool) -> PolarsResult<Series>,
+    reverse: bool,
+) -> Option<GroupsUdf> {
+    aligned_groups_udf(move |s, offsets| f(s, offsets, reverse))
+}
+
 pub(super) mod dtypes {
     use polars_core::utils::materialize_dyn_int;
     use DataType::*;
@@ -27,6 +34,11 @@ pub(super) mod dtypes {
     use super::*;
 
     pub fn cum_sum(dt: &DataType) -> DataType {
+        // The precision of a running sum is unbounded.
+        #[cfg(feature = "dtype-decimal")]
+        if let Decimal(_, scale) = dt {
+            return Decimal(None, *scale);
+        }
         if dt.is_logical() {
             dt.clone()
         } else {
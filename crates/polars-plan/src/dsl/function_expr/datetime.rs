@@ -24,8 +24,8 @@ pub enum TemporalFunction {
     IsoYear,
     Quarter,
     Month,
-    Week,
-    WeekDay,
+    Week(WeekStart),
+    WeekDay(WeekStart),
     Day,
     OrdinalDay,
     Time,
@@ -51,7 +51,7 @@ pub enum TemporalFunction {
     #[cfg(feature = "timezones")]
     ConvertTimeZone(TimeZone),
     TimeStamp(TimeUnit),
-    Truncate,
+    Truncate(WeekStart),
     #[cfg(feature = "offset_by")]
     OffsetBy,
     #[cfg(feature = "month_start")]
@@ -62,7 +62,7 @@ pub enum TemporalFunction {
     BaseUtcOffset,
     #[cfg(feature = "timezones")]
     DSTOffset,
-    Round,
+    Round(RoundTies),
     #[cfg(feature = "timezones")]
     ReplaceTimeZone(Option<TimeZone>, NonExistent),
     Combine(TimeUnit),
@@ -79,7 +79,7 @@ impl TemporalFunction {
             Millennium | Century => mapper.with_dtype(DataType::Int8),
             Year | IsoYear => mapper.with_dtype(DataType::Int32),
             OrdinalDay => mapper.with_dtype(DataType::Int16),
-            Month | Quarter | Week | WeekDay | Day | Hour | Minute | Second => {
+            Month | Quarter | Week(_) | WeekDay(_) | Day | Hour | Minute | Second => {
                 mapper.with_dtype(DataType::Int8)
             },
             Millisecond | Microsecond | Nanosecond => mapper.with_dtype(DataType::Int32),
@@ -106,7 +106,7 @@ impl TemporalFunction {
                 DataType::Datetime(tu, _) => Ok(DataType::Datetime(*tu, None)),
                 dtype => polars_bail!(ComputeError: "expected Datetime, got {}", dtype),
             }),
-            Truncate => mapper.with_same_dtype(),
+            Truncate(_) => mapper.with_same_dtype(),
             #[cfg(feature = "offset_by")]
             OffsetBy => mapper.with_same_dtype(),
             #[cfg(feature = "month_start")]
@@ -117,7 +117,7 @@ impl TemporalFunction {
             BaseUtcOffset => mapper.with_dtype(DataType::Duration(TimeUnit::Milliseconds)),
             #[cfg(feature = "timezones")]
             DSTOffset => mapper.with_dtype(DataType::Duration(TimeUnit::Milliseconds)),
-            Round => mapper.with_same_dtype(),
+            Round(_) => mapper.with_same_dtype(),
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(tz, _non_existent) => mapper.map_datetime_dtype_timezone(tz.as_ref()),
             DatetimeFunction {
@@ -149,8 +149,8 @@ impl Display for TemporalFunction {
             IsoYear => "iso_year",
             Quarter => "quarter",
             Month => "month",
-            Week => "week",
-            WeekDay => "weekday",
+            Week(_) => "week",
+            WeekDay(_) => "weekday",
             Day => "day",
             OrdinalDay => "ordinal_day",
             Time => "time",
@@ -176,7 +176,7 @@ impl Display for TemporalFunction {
             CastTimeUnit(_) => "cast_time_unit",
             WithTimeUnit(_) => "with_time_unit",
             TimeStamp(tu) => return write!(f, "dt.timestamp({tu})"),
-            Truncate => "truncate",
+            Truncate(_) => "truncate",
             #[cfg(feature = "offset_by")]
             OffsetBy => "offset_by",
             #[cfg(feature = "month_start")]
@@ -187,7 +187,7 @@ impl Display for TemporalFunction {
             BaseUtcOffset => "base_utc_offset",
             #[cfg(feature = "timezones")]
             DSTOffset => "dst_offset",
-            Round => "round",
+            Round(_) => "round",
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(_, _) => "replace_time_zone",
             DatetimeFunction { .. } => return write!(f, "dt.datetime"),
@@ -218,11 +218,31 @@ pub(super) fn month(s: &Series) -> PolarsResult<Series> {
 pub(super) fn quarter(s: &Series) -> PolarsResult<Series> {
     s.quarter().map(|ca| ca.into_series())
 }
-pub(super) fn week(s: &Series) -> PolarsResult<Series> {
-    s.week().map(|ca| ca.into_series())
-}
-pub(super) fn weekday(s: &Series) -> PolarsResult<Series> {
-    s.weekday().map(|ca| ca.into_series())
+pub(super) fn week(s: &Series, week_start: WeekStart) -> PolarsResult<Series> {
+    let days_from_monday = week_start.days_from_monday();
+    if days_from_monday == 0 {
+        return s.week().map(|ca| ca.into_series());
+    }
+    // Shift the dates such that the weeks start on Monday and their middle days stay in the
+    // same year, so that a week belongs to the year of its middle day, as in ISO 8601.
+    let shift = if days_from_monday <= 3 {
+        -days_from_monday
+    } else {
+        7 - days_from_monday
+    };
+    let days = date(s)?.to_physical_repr().into_owned();
+    let shifted = (&days + shift as i32).cast(&DataType::Date)?;
+    shifted.week().map(|ca| ca.into_series())
+}
+pub(super) fn weekday(s: &Series, week_start: WeekStart) -> PolarsResult<Series> {
+    let days_from_monday = week_start.days_from_monday() as i8;
+    let weekday = s.weekday()?;
+    if days_from_monday == 0 {
+        return Ok(weekday.into_series());
+    }
+    Ok(weekday
+        .apply_values(|day| (day - 1 - days_from_monday).rem_euclid(7) + 1)
+        .into_series())
 }
 pub(super) fn day(s: &Series) -> PolarsResult<Series> {
     s.day().map(|ca| ca.into_series())
@@ -383,7 +403,7 @@ pub(super) fn cast_time_unit(s: &Series, tu: TimeUnit) -> PolarsResult<Series> {
     }
 }
 
-pub(super) fn truncate(s: &[Series]) -> PolarsResult<Series> {
+pub(super) fn truncate(s: &[Series], week_start: WeekStart) -> PolarsResult<Series> {
     let time_series = &s[0];
     let every = s[1].str()?;
 
@@ -392,11 +412,17 @@ pub(super) fn truncate(s: &[Series]) -> PolarsResult<Series> {
             #[cfg(feature = "timezones")]
             Some(tz) => time_series
                 .datetime()?
-                .truncate(tz.parse::<Tz>().ok().as_ref(), every)?
+                .truncate(tz.parse::<Tz>().ok().as_ref(), every, week_start)?
+                .into_series(),
+            _ => time_series
+                .datetime()?
+                .truncate(None, every, week_start)?
                 .into_series(),
-            _ => time_series.datetime()?.truncate(None, every)?.into_series(),
         },
-        DataType::Date => time_series.date()?.truncate(None, every)?.into_series(),
+        DataType::Date => time_series
+            .date()?
+            .truncate(None, every, week_start)?
+            .into_series(),
         dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
     };
     out.set_sorted_flag(time_series.is_sorted_flag());
@@ -475,7 +501,7 @@ pub(super) fn dst_offset(s: &Series) -> PolarsResult<Series> {
     }
 }
 
-pub(super) fn round(s: &[Series]) -> PolarsResult<Series> {
+pub(super) fn round(s: &[Series], ties: RoundTies) -> PolarsResult<Series> {
     let time_series = &s[0];
     let every = s[1].str()?;
 
@@ -485,18 +511,18 @@ pub(super) fn round(s: &[Series]) -> PolarsResult<Series> {
             Some(tz) => time_series
                 .datetime()
                 .unwrap()
-                .round(every, tz.parse::<Tz>().ok().as_ref())?
+                .round(every, tz.parse::<Tz>().ok().as_ref(), ties)?
                 .into_series(),
             _ => time_series
                 .datetime()
                 .unwrap()
-                .round(every, None)?
+                .round(every, None, ties)?
                 .into_series(),
         },
         DataType::Date => time_series
             .date()
             .unwrap()
-            .round(every, None)?
+            .round(every, None, ties)?
             .into_series(),
         dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
     })
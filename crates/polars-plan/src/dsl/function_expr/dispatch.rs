@@ -5,18 +5,39 @@ pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
 }
 
 #[cfg(feature = "approx_unique")]
-pub(super) fn approx_n_unique(s: &Series) -> PolarsResult<Series> {
-    polars_ops::prelude::approx_n_unique(s)
+pub(super) fn approx_n_unique(s: &Series, precision: u8) -> PolarsResult<Series> {
+    polars_ops::prelude::approx_n_unique_with_precision(s, precision)
+}
+
+#[cfg(feature = "approx_quantile")]
+pub(super) fn quantile_approx(s: &Series, quantile: f64) -> PolarsResult<Series> {
+    polars_ops::prelude::quantile_approx(s, quantile)
 }
 
 #[cfg(feature = "diff")]
-pub(super) fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Series> {
-    polars_ops::prelude::diff(s, n, null_behavior)
+pub(super) fn diff(s: &[Series], null_behavior: NullBehavior) -> PolarsResult<Series> {
+    let (s, n) = (&s[0], &s[1]);
+    if n.len() == 1 {
+        let n = n.strict_cast(&DataType::Int64)?;
+        if let Some(n) = n.i64()?.get(0) {
+            return polars_ops::prelude::diff(s, n, null_behavior);
+        }
+    }
+    polars_ensure!(
+        null_behavior == NullBehavior::Ignore,
+        InvalidOperation: "nulls can only be dropped in 'diff' with a single number of periods"
+    );
+    let n = if n.len() == 1 {
+        n.new_from_index(0, s.len())
+    } else {
+        n.clone()
+    };
+    polars_ops::prelude::diff_by_periods(s, &n)
 }
 
 #[cfg(feature = "pct_change")]
-pub(super) fn pct_change(s: &[Series]) -> PolarsResult<Series> {
-    polars_ops::prelude::pct_change(&s[0], &s[1])
+pub(super) fn pct_change(s: &[Series], null_behavior: NullBehavior) -> PolarsResult<Series> {
+    polars_ops::prelude::pct_change(&s[0], &s[1], null_behavior)
 }
 
 #[cfg(feature = "interpolate")]
@@ -25,10 +46,10 @@ pub(super) fn interpolate(s: &Series, method: InterpolationMethod) -> PolarsResu
 }
 
 #[cfg(feature = "interpolate_by")]
-pub(super) fn interpolate_by(s: &[Series]) -> PolarsResult<Series> {
+pub(super) fn interpolate_by(s: &[Series], method: InterpolationMethod) -> PolarsResult<Series> {
     let by = &s[1];
     let by_is_sorted = by.is_sorted(Default::default())?;
-    polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted)
+    polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted, method)
 }
 
 pub(super) fn to_physical(s: &Series) -> PolarsResult<Series> {
@@ -65,6 +86,16 @@ pub(super) fn value_counts(
         .map(|df| df.into_struct(s.name()).into_series())
 }
 
+#[cfg(feature = "to_dummies")]
+pub(super) fn to_dummies(
+    s: &Series,
+    separator: Option<&str>,
+    drop_first: bool,
+) -> PolarsResult<Series> {
+    s.to_dummies(separator, drop_first)
+        .map(|df| df.into_struct(s.name()).into_series())
+}
+
 #[cfg(feature = "unique_counts")]
 pub(super) fn unique_counts(s: &Series) -> PolarsResult<Series> {
     polars_ops::prelude::unique_counts(s)
@@ -94,31 +125,65 @@ pub(super) fn forward_fill(s: &Series, limit: FillNullLimit) -> PolarsResult<Ser
     s.fill_null(FillNullStrategy::Forward(limit))
 }
 
-pub(super) fn max_horizontal(s: &mut [Series]) -> PolarsResult<Option<Series>> {
-    polars_ops::prelude::max_horizontal(s)
+pub(super) fn max_horizontal(s: &mut [Series], ignore_nulls: bool) -> PolarsResult<Option<Series>> {
+    polars_ops::prelude::max_horizontal(s, ignore_nulls)
 }
 
-pub(super) fn min_horizontal(s: &mut [Series]) -> PolarsResult<Option<Series>> {
-    polars_ops::prelude::min_horizontal(s)
+pub(super) fn min_horizontal(s: &mut [Series], ignore_nulls: bool) -> PolarsResult<Option<Series>> {
+    polars_ops::prelude::min_horizontal(s, ignore_nulls)
 }
 
-pub(super) fn sum_horizontal(s: &mut [Series]) -> PolarsResult<Option<Series>> {
-    polars_ops::prelude::sum_horizontal(s)
+pub(super) fn sum_horizontal(s: &mut [Series], ignore_nulls: bool) -> PolarsResult<Option<Series>> {
+    polars_ops::prelude::sum_horizontal(s, ignore_nulls)
 }
 
-pub(super) fn mean_horizontal(s: &mut [Series]) -> PolarsResult<Option<Series>> {
-    polars_ops::prelude::mean_horizontal(s)
+pub(super) fn mean_horizontal(
+    s: &mut [Series],
+    ignore_nulls: bool,
+) -> PolarsResult<Option<Series>> {
+    polars_ops::prelude::mean_horizontal(s, ignore_nulls)
 }
 
 pub(super) fn drop_nulls(s: &Series) -> PolarsResult<Series> {
     Ok(s.drop_nulls())
 }
 
+pub(super) fn arg_min(s: &Series) -> PolarsResult<Series> {
+    Ok(Series::new(
+        s.name(),
+        &[s.arg_min().map(|idx| idx as IdxSize)],
+    ))
+}
+
+pub(super) fn arg_max(s: &Series) -> PolarsResult<Series> {
+    Ok(Series::new(
+        s.name(),
+        &[s.arg_max().map(|idx| idx as IdxSize)],
+    ))
+}
+
+pub(super) fn product(s: &Series, overflow: ProductOverflow) -> PolarsResult<Series> {
+    polars_ops::prelude::product(s, overflow)
+}
+
 #[cfg(feature = "mode")]
 pub(super) fn mode(s: &Series) -> PolarsResult<Series> {
     mode::mode(s)
 }
 
+#[cfg(feature = "mode")]
+pub(super) fn mode_tie_break(s: &Series, tie_break: ModeTieBreak) -> PolarsResult<Series> {
+    mode::mode_tie_break(s, tie_break)
+}
+
+#[cfg(feature = "mode")]
+pub(super) fn mode_groups(
+    ca: &ListChunked,
+    tie_break: Option<ModeTieBreak>,
+) -> PolarsResult<ListChunked> {
+    mode::mode_groups(ca, tie_break)
+}
+
 #[cfg(feature = "moment")]
 pub(super) fn skew(s: &Series, bias: bool) -> PolarsResult<Series> {
     s.skew(bias).map(|opt_v| Series::new(s.name(), &[opt_v]))
@@ -139,12 +204,23 @@ pub(super) fn rank(s: &Series, options: RankOptions, seed: Option<u64>) -> Polar
     Ok(s.rank(options, seed))
 }
 
+#[cfg(feature = "rank")]
+pub(super) fn rank_groups(
+    s: &Series,
+    offsets: &[i64],
+    options: RankOptions,
+    seed: Option<u64>,
+) -> PolarsResult<Series> {
+    Ok(polars_ops::prelude::rank_groups(s, offsets, options, seed))
+}
+
 #[cfg(feature = "hist")]
 pub(super) fn hist(
     s: &[Series],
     bin_count: Option<usize>,
     include_category: bool,
     include_breakpoint: bool,
+    normalize: bool,
 ) -> PolarsResult<Series> {
     let bins = if s.len() == 2 {
         Some(s[1].clone())
@@ -152,7 +228,14 @@ pub(super) fn hist(
         None
     };
     let s = &s[0];
-    hist_series(s, bin_count, bins, include_category, include_breakpoint)
+    hist_series(
+        s,
+        bin_count,
+        bins,
+        include_category,
+        include_breakpoint,
+        normalize,
+    )
 }
 
 #[cfg(feature = "replace")]
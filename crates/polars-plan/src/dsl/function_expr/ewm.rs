@@ -11,3 +11,11 @@ pub(super) fn ewm_std(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
 pub(super) fn ewm_var(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
     polars_ops::prelude::ewm_var(s, options)
 }
+
+pub(super) fn ewm_cov(s: &[Series], options: EWMOptions) -> PolarsResult<Series> {
+    polars_ops::prelude::ewm_cov(&s[0], &s[1], options)
+}
+
+pub(super) fn ewm_corr(s: &[Series], options: EWMOptions) -> PolarsResult<Series> {
+    polars_ops::prelude::ewm_corr(&s[0], &s[1], options)
+}
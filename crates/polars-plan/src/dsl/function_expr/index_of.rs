@@ -0,0 +1,11 @@
+use polars_ops::series::index_of as find_index;
+
+use super::*;
+
+pub(super) fn index_of(s: &mut [Series]) -> PolarsResult<Series> {
+    let series = &s[0];
+    let value = &s[1];
+
+    let idx = find_index(series, value)?.map(|idx| idx as IdxSize);
+    Ok(IdxCa::from_iter_options(series.name(), std::iter::once(idx)).into_series())
+}
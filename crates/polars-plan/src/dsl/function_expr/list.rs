@@ -1,4 +1,3 @@
-use arrow::legacy::utils::CustomIterTools;
 use polars_ops::chunked_array::list::*;
 
 use super::*;
@@ -326,14 +325,10 @@ pub(super) fn slice(args: &mut [Series]) -> PolarsResult<Option<Series>> {
             let length_ca = length_s.cast(&DataType::Int64)?;
             let length_ca = length_ca.i64().unwrap();
 
-            list_ca
-                .amortized_iter()
-                .zip(length_ca)
-                .map(|(opt_s, opt_length)| match (opt_s, opt_length) {
-                    (Some(s), Some(length)) => Some(s.as_ref().slice(offset, length as usize)),
-                    _ => None,
-                })
-                .collect_trusted()
+            let slices = length_ca
+                .iter()
+                .map(|opt_length| opt_length.map(|length| (offset, length as usize)));
+            list_ca.lst_slice_by(slices)
         },
         (offset_len, 1) => {
             check_slice_arg_shape(offset_len, list_ca.len(), "offset")?;
@@ -344,14 +339,11 @@ pub(super) fn slice(args: &mut [Series]) -> PolarsResult<Option<Series>> {
                 .unwrap_or(usize::MAX);
             let offset_ca = offset_s.cast(&DataType::Int64)?;
             let offset_ca = offset_ca.i64().unwrap();
-            list_ca
-                .amortized_iter()
-                .zip(offset_ca)
-                .map(|(opt_s, opt_offset)| match (opt_s, opt_offset) {
-                    (Some(s), Some(offset)) => Some(s.as_ref().slice(offset, length_slice)),
-                    _ => None,
-                })
-                .collect_trusted()
+
+            let slices = offset_ca
+                .iter()
+                .map(|opt_offset| opt_offset.map(|offset| (offset, length_slice)));
+            list_ca.lst_slice_by(slices)
         },
         _ => {
             check_slice_arg_shape(offset_s.len(), list_ca.len(), "offset")?;
@@ -363,19 +355,14 @@ pub(super) fn slice(args: &mut [Series]) -> PolarsResult<Option<Series>> {
             let length_ca = length_s.cast(&DataType::Int64)?;
             let length_ca = length_ca.i64().unwrap();
 
-            list_ca
-                .amortized_iter()
-                .zip(offset_ca)
+            let slices = offset_ca
+                .iter()
                 .zip(length_ca)
-                .map(
-                    |((opt_s, opt_offset), opt_length)| match (opt_s, opt_offset, opt_length) {
-                        (Some(s), Some(offset), Some(length)) => {
-                            Some(s.as_ref().slice(offset, length as usize))
-                        },
-                        _ => None,
-                    },
-                )
-                .collect_trusted()
+                .map(|(opt_offset, opt_length)| match (opt_offset, opt_length) {
+                    (Some(offset), Some(length)) => Some((offset, length as usize)),
+                    _ => None,
+                });
+            list_ca.lst_slice_by(slices)
         },
     };
     out.rename(s.name());
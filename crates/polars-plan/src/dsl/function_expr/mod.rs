@@ -52,6 +52,8 @@ mod round;
 #[cfg(feature = "row_hash")]
 mod row_hash;
 pub(super) mod schema;
+#[cfg(feature = "index_of")]
+mod index_of;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
 mod shift_and_fill;
@@ -137,8 +139,14 @@ pub enum FunctionExpr {
         bin_count: Option<usize>,
         include_category: bool,
         include_breakpoint: bool,
+        normalize: bool,
     },
     NullCount,
+    ArgMin,
+    ArgMax,
+    Product {
+        overflow: ProductOverflow,
+    },
     Pow(PowFunction),
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
@@ -146,6 +154,8 @@ pub enum FunctionExpr {
     ArgWhere,
     #[cfg(feature = "search_sorted")]
     SearchSorted(SearchSortedSide),
+    #[cfg(feature = "index_of")]
+    IndexOf,
     #[cfg(feature = "range")]
     Range(RangeFunction),
     #[cfg(feature = "trigonometry")]
@@ -165,7 +175,9 @@ pub enum FunctionExpr {
     DropNans,
     DropNulls,
     #[cfg(feature = "mode")]
-    Mode,
+    Mode {
+        tie_break: Option<ModeTieBreak>,
+    },
     #[cfg(feature = "moment")]
     Skew(bool),
     #[cfg(feature = "moment")]
@@ -225,17 +237,23 @@ pub enum FunctionExpr {
     #[cfg(feature = "unique_counts")]
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
-    ApproxNUnique,
+    ApproxNUnique {
+        precision: u8,
+    },
+    #[cfg(feature = "approx_quantile")]
+    QuantileApprox {
+        quantile: f64,
+    },
     Coalesce,
     ShrinkType,
     #[cfg(feature = "diff")]
-    Diff(i64, NullBehavior),
+    Diff(NullBehavior),
     #[cfg(feature = "pct_change")]
-    PctChange,
+    PctChange(NullBehavior),
     #[cfg(feature = "interpolate")]
     Interpolate(InterpolationMethod),
     #[cfg(feature = "interpolate_by")]
-    InterpolateBy,
+    InterpolateBy(InterpolationMethod),
     #[cfg(feature = "log")]
     Entropy {
         base: f64,
@@ -273,9 +291,9 @@ pub enum FunctionExpr {
         ddof: u8,
     },
     #[cfg(feature = "peaks")]
-    PeakMin,
+    PeakMin(PeakPlateau),
     #[cfg(feature = "peaks")]
-    PeakMax,
+    PeakMax(PeakPlateau),
     #[cfg(feature = "cutqcut")]
     Cut {
         breaks: Vec<f64>,
@@ -295,6 +313,11 @@ pub enum FunctionExpr {
     RLE,
     #[cfg(feature = "rle")]
     RLEID,
+    #[cfg(feature = "to_dummies")]
+    ToDummies {
+        separator: Option<String>,
+        drop_first: bool,
+    },
     ToPhysical,
     #[cfg(feature = "random")]
     Random {
@@ -319,10 +342,18 @@ pub enum FunctionExpr {
     ForwardFill {
         limit: FillNullLimit,
     },
-    MaxHorizontal,
-    MinHorizontal,
-    SumHorizontal,
-    MeanHorizontal,
+    MaxHorizontal {
+        ignore_nulls: bool,
+    },
+    MinHorizontal {
+        ignore_nulls: bool,
+    },
+    SumHorizontal {
+        ignore_nulls: bool,
+    },
+    MeanHorizontal {
+        ignore_nulls: bool,
+    },
     #[cfg(feature = "ewma")]
     EwmMean {
         options: EWMOptions,
@@ -339,6 +370,14 @@ pub enum FunctionExpr {
     EwmVar {
         options: EWMOptions,
     },
+    #[cfg(feature = "ewma")]
+    EwmCov {
+        options: EWMOptions,
+    },
+    #[cfg(feature = "ewma")]
+    EwmCorr {
+        options: EWMOptions,
+    },
     #[cfg(feature = "replace")]
     Replace,
     #[cfg(feature = "replace")]
@@ -391,11 +430,11 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "fused")]
             Fused(f) => f.hash(state),
             #[cfg(feature = "diff")]
-            Diff(_, null_behavior) => null_behavior.hash(state),
+            Diff(null_behavior) => null_behavior.hash(state),
             #[cfg(feature = "interpolate")]
             Interpolate(f) => f.hash(state),
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {},
+            InterpolateBy(f) => f.hash(state),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 lib,
@@ -406,16 +445,23 @@ impl Hash for FunctionExpr {
                 lib.hash(state);
                 symbol.hash(state);
             },
-            MaxHorizontal | MinHorizontal | SumHorizontal | MeanHorizontal | DropNans
-            | DropNulls | Reverse | ArgUnique | Shift | ShiftAndFill => {},
+            MaxHorizontal { ignore_nulls }
+            | MinHorizontal { ignore_nulls }
+            | SumHorizontal { ignore_nulls }
+            | MeanHorizontal { ignore_nulls } => ignore_nulls.hash(state),
+            DropNans | DropNulls | Reverse | ArgUnique | Shift | ShiftAndFill => {},
             #[cfg(feature = "mode")]
-            Mode => {},
+            Mode { tie_break } => tie_break.hash(state),
             #[cfg(feature = "abs")]
             Abs => {},
             Negate => {},
             NullCount => {},
+            ArgMin | ArgMax => {},
+            Product { overflow } => overflow.hash(state),
             #[cfg(feature = "arg_where")]
             ArgWhere => {},
+            #[cfg(feature = "index_of")]
+            IndexOf => {},
             #[cfg(feature = "trigonometry")]
             Atan2 => {},
             #[cfg(feature = "dtype-struct")]
@@ -477,11 +523,13 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "unique_counts")]
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => {},
+            ApproxNUnique { precision } => precision.hash(state),
+            #[cfg(feature = "approx_quantile")]
+            QuantileApprox { quantile } => quantile.to_bits().hash(state),
             Coalesce => {},
             ShrinkType => {},
             #[cfg(feature = "pct_change")]
-            PctChange => {},
+            PctChange(null_behavior) => null_behavior.hash(state),
             #[cfg(feature = "log")]
             Entropy { base, normalize } => {
                 base.to_bits().hash(state);
@@ -506,9 +554,9 @@ impl Hash for FunctionExpr {
             LowerBound => {},
             ConcatExpr(a) => a.hash(state),
             #[cfg(feature = "peaks")]
-            PeakMin => {},
+            PeakMin(plateau) => plateau.hash(state),
             #[cfg(feature = "peaks")]
-            PeakMax => {},
+            PeakMax(plateau) => plateau.hash(state),
             #[cfg(feature = "cutqcut")]
             Cut {
                 breaks,
@@ -547,6 +595,14 @@ impl Hash for FunctionExpr {
             RLE => {},
             #[cfg(feature = "rle")]
             RLEID => {},
+            #[cfg(feature = "to_dummies")]
+            ToDummies {
+                separator,
+                drop_first,
+            } => {
+                separator.hash(state);
+                drop_first.hash(state);
+            },
             ToPhysical => {},
             SetSortedFlag(is_sorted) => is_sorted.hash(state),
             BackwardFill { limit } | ForwardFill { limit } => limit.hash(state),
@@ -558,15 +614,21 @@ impl Hash for FunctionExpr {
             EwmStd { options } => options.hash(state),
             #[cfg(feature = "ewma")]
             EwmVar { options } => options.hash(state),
+            #[cfg(feature = "ewma")]
+            EwmCov { options } => options.hash(state),
+            #[cfg(feature = "ewma")]
+            EwmCorr { options } => options.hash(state),
             #[cfg(feature = "hist")]
             Hist {
                 bin_count,
                 include_category,
                 include_breakpoint,
+                normalize,
             } => {
                 bin_count.hash(state);
                 include_category.hash(state);
                 include_breakpoint.hash(state);
+                normalize.hash(state);
             },
             #[cfg(feature = "replace")]
             Replace => {},
@@ -609,6 +671,9 @@ impl Display for FunctionExpr {
             Abs => "abs",
             Negate => "negate",
             NullCount => "null_count",
+            ArgMin => "arg_min",
+            ArgMax => "arg_max",
+            Product { .. } => "product",
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
@@ -616,6 +681,8 @@ impl Display for FunctionExpr {
             ArgWhere => "arg_where",
             #[cfg(feature = "search_sorted")]
             SearchSorted(_) => "search_sorted",
+            #[cfg(feature = "index_of")]
+            IndexOf => "index_of",
             #[cfg(feature = "range")]
             Range(func) => return write!(f, "{func}"),
             #[cfg(feature = "trigonometry")]
@@ -633,7 +700,7 @@ impl Display for FunctionExpr {
             DropNans => "drop_nans",
             DropNulls => "drop_nulls",
             #[cfg(feature = "mode")]
-            Mode => "mode",
+            Mode { .. } => "mode",
             #[cfg(feature = "moment")]
             Skew(_) => "skew",
             #[cfg(feature = "moment")]
@@ -677,17 +744,19 @@ impl Display for FunctionExpr {
             UniqueCounts => "unique_counts",
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => "approx_n_unique",
+            ApproxNUnique { .. } => "approx_n_unique",
+            #[cfg(feature = "approx_quantile")]
+            QuantileApprox { .. } => "quantile_approx",
             Coalesce => "coalesce",
             ShrinkType => "shrink_dtype",
             #[cfg(feature = "diff")]
-            Diff(_, _) => "diff",
+            Diff(_) => "diff",
             #[cfg(feature = "pct_change")]
-            PctChange => "pct_change",
+            PctChange(_) => "pct_change",
             #[cfg(feature = "interpolate")]
             Interpolate(_) => "interpolate",
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => "interpolate_by",
+            InterpolateBy(_) => "interpolate_by",
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
@@ -719,9 +788,9 @@ impl Display for FunctionExpr {
             #[cfg(feature = "cov")]
             Correlation { method, .. } => return Display::fmt(method, f),
             #[cfg(feature = "peaks")]
-            PeakMin => "peak_min",
+            PeakMin(_) => "peak_min",
             #[cfg(feature = "peaks")]
-            PeakMax => "peak_max",
+            PeakMax(_) => "peak_max",
             #[cfg(feature = "cutqcut")]
             Cut { .. } => "cut",
             #[cfg(feature = "cutqcut")]
@@ -733,6 +802,8 @@ impl Display for FunctionExpr {
             RLE => "rle",
             #[cfg(feature = "rle")]
             RLEID => "rle_id",
+            #[cfg(feature = "to_dummies")]
+            ToDummies { .. } => "to_dummies",
             ToPhysical => "to_physical",
             #[cfg(feature = "random")]
             Random { method, .. } => method.into(),
@@ -741,10 +812,10 @@ impl Display for FunctionExpr {
             FfiPlugin { lib, symbol, .. } => return write!(f, "{lib}:{symbol}"),
             BackwardFill { .. } => "backward_fill",
             ForwardFill { .. } => "forward_fill",
-            MaxHorizontal => "max_horizontal",
-            MinHorizontal => "min_horizontal",
-            SumHorizontal => "sum_horizontal",
-            MeanHorizontal => "mean_horizontal",
+            MaxHorizontal { .. } => "max_horizontal",
+            MinHorizontal { .. } => "min_horizontal",
+            SumHorizontal { .. } => "sum_horizontal",
+            MeanHorizontal { .. } => "mean_horizontal",
             #[cfg(feature = "ewma")]
             EwmMean { .. } => "ewm_mean",
             #[cfg(feature = "ewma_by")]
@@ -753,6 +824,10 @@ impl Display for FunctionExpr {
             EwmStd { .. } => "ewm_std",
             #[cfg(feature = "ewma")]
             EwmVar { .. } => "ewm_var",
+            #[cfg(feature = "ewma")]
+            EwmCov { .. } => "ewm_cov",
+            #[cfg(feature = "ewma")]
+            EwmCorr { .. } => "ewm_corr",
             #[cfg(feature = "hist")]
             Hist { .. } => "hist",
             #[cfg(feature = "replace")]
@@ -851,6 +926,134 @@ macro_rules! map {
     }};
 }
 
+/// A kernel that computes a function on all groups at once. It receives the groups as a
+/// [`ListChunked`] and returns the output of every group as a [`ListChunked`].
+pub type GroupsUdf = Arc<dyn Fn(&ListChunked) -> PolarsResult<ListChunked> + Send + Sync>;
+
+/// Create a [`GroupsUdf`] from a kernel that receives the values of the groups as a single
+/// [`Series`] and the offsets of the groups into it, and returns a [`Series`] that is
+/// aligned with the values.
+fn aligned_groups_udf<F>(f: F) -> Option<GroupsUdf>
+where
+    F: Fn(&Series, &[i64]) -> PolarsResult<Series> + Send + Sync + 'static,
+{
+    Some(Arc::new(move |ca: &ListChunked| {
+        let ca = ca.rechunk();
+        let offsets = ca.downcast_iter().next().unwrap().offsets().clone();
+        ca.apply_to_inner(&|values| f(&values, offsets.as_slice()))
+    }))
+}
+
+/// Create a [`GroupsUdf`] from a kernel that receives the values of the groups as a single
+/// [`Series`] and the offsets of the groups into it, and returns one value per group.
+fn reduce_groups_udf<F>(f: F) -> Option<GroupsUdf>
+where
+    F: Fn(&Series, &[i64]) -> PolarsResult<Series> + Send + Sync + 'static,
+{
+    Some(Arc::new(move |ca: &ListChunked| {
+        let ca = ca.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        // SAFETY: the values of the list have its inner dtype.
+        let values = unsafe {
+            Series::from_chunks_and_dtype_unchecked(
+                ca.name(),
+                vec![arr.values().clone()],
+                ca.inner_dtype(),
+            )
+        };
+        Ok(f(&values, arr.offsets().as_slice())?.as_list())
+    }))
+}
+
+impl FunctionExpr {
+    /// Get the kernel that computes this function on all groups in a single pass, if there
+    /// is one. Group-wise functions without such a kernel are called once per group.
+    pub fn groups_udf(&self) -> Option<GroupsUdf> {
+        use FunctionExpr::*;
+        match self {
+            #[cfg(feature = "rank")]
+            Rank { options, seed } => {
+                let (options, seed) = (*options, *seed);
+                aligned_groups_udf(move |s, offsets| {
+                    dispatch::rank_groups(s, offsets, options, seed)
+                })
+            },
+            #[cfg(feature = "cum_agg")]
+            CumCount { reverse } => {
+                cum::groups_udf(polars_ops::prelude::cum_count_groups, *reverse)
+            },
+            #[cfg(feature = "cum_agg")]
+            CumSum { reverse } => cum::groups_udf(polars_ops::prelude::cum_sum_groups, *reverse),
+            #[cfg(feature = "cum_agg")]
+            CumProd { reverse } => cum::groups_udf(polars_ops::prelude::cum_prod_groups, *reverse),
+            #[cfg(feature = "cum_agg")]
+            CumMin { reverse } => cum::groups_udf(polars_ops::prelude::cum_min_groups, *reverse),
+            #[cfg(feature = "cum_agg")]
+            CumMax { reverse } => cum::groups_udf(polars_ops::prelude::cum_max_groups, *reverse),
+            #[cfg(feature = "mode")]
+            Mode { tie_break } => {
+                let tie_break = *tie_break;
+                Some(Arc::new(move |ca: &ListChunked| {
+                    dispatch::mode_groups(ca, tie_break)
+                }))
+            },
+            #[cfg(feature = "approx_unique")]
+            ApproxNUnique { precision } => {
+                let precision = *precision;
+                reduce_groups_udf(move |s, offsets| {
+                    polars_ops::prelude::approx_n_unique_groups(s, offsets, precision)
+                })
+            },
+            #[cfg(feature = "approx_quantile")]
+            QuantileApprox { quantile } => {
+                let quantile = *quantile;
+                reduce_groups_udf(move |s, offsets| {
+                    polars_ops::prelude::quantile_approx_groups(s, offsets, quantile)
+                })
+            },
+            ForwardFill { limit } => {
+                let limit = *limit;
+                aligned_groups_udf(move |s, offsets| {
+                    polars_ops::prelude::forward_fill_groups(s, offsets, limit)
+                })
+            },
+            BackwardFill { limit } => {
+                let limit = *limit;
+                aligned_groups_udf(move |s, offsets| {
+                    polars_ops::prelude::backward_fill_groups(s, offsets, limit)
+                })
+            },
+            FillNullWithStrategy(FillNullStrategy::Forward(limit)) => {
+                ForwardFill { limit: *limit }.groups_udf()
+            },
+            FillNullWithStrategy(FillNullStrategy::Backward(limit)) => {
+                BackwardFill { limit: *limit }.groups_udf()
+            },
+            #[cfg(feature = "is_first_distinct")]
+            Boolean(BooleanFunction::IsFirstDistinct) => aligned_groups_udf(|s, offsets| {
+                Ok(polars_ops::prelude::is_first_distinct_groups(s, offsets)?.into_series())
+            }),
+            #[cfg(feature = "is_last_distinct")]
+            Boolean(BooleanFunction::IsLastDistinct) => aligned_groups_udf(|s, offsets| {
+                Ok(polars_ops::prelude::is_last_distinct_groups(s, offsets)?.into_series())
+            }),
+            ArgMin => reduce_groups_udf(|s, offsets| {
+                Ok(polars_ops::prelude::arg_min_groups(s, offsets).into_series())
+            }),
+            ArgMax => reduce_groups_udf(|s, offsets| {
+                Ok(polars_ops::prelude::arg_max_groups(s, offsets).into_series())
+            }),
+            Product { overflow } => {
+                let overflow = *overflow;
+                reduce_groups_udf(move |s, offsets| {
+                    polars_ops::prelude::product_groups(s, offsets, overflow)
+                })
+            },
+            _ => None,
+        }
+    }
+}
+
 impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
     fn from(func: FunctionExpr) -> Self {
         use FunctionExpr::*;
@@ -883,6 +1086,9 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 };
                 wrap!(f)
             },
+            ArgMin => map!(dispatch::arg_min),
+            ArgMax => map!(dispatch::arg_max),
+            Product { overflow } => map!(dispatch::product, overflow),
             Pow(func) => match func {
                 PowFunction::Generic => wrap!(pow::pow),
                 PowFunction::Sqrt => map!(pow::sqrt),
@@ -900,6 +1106,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             SearchSorted(side) => {
                 map_as_slice!(search_sorted::search_sorted_impl, side)
             },
+            #[cfg(feature = "index_of")]
+            IndexOf => map_as_slice!(index_of::index_of),
             #[cfg(feature = "range")]
             Range(func) => func.into(),
 
@@ -954,12 +1162,14 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 bin_count,
                 include_category,
                 include_breakpoint,
+                normalize,
             } => {
                 map_as_slice!(
                     dispatch::hist,
                     bin_count,
                     include_category,
-                    include_breakpoint
+                    include_breakpoint,
+                    normalize
                 )
             },
             ShiftAndFill => {
@@ -972,7 +1182,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 map_as_slice!(clip::clip, has_min, has_max)
             },
             #[cfg(feature = "mode")]
-            Mode => map!(dispatch::mode),
+            Mode { tie_break } => match tie_break {
+                None => map!(dispatch::mode),
+                Some(tie_break) => map!(dispatch::mode_tie_break, tie_break),
+            },
             #[cfg(feature = "moment")]
             Skew(bias) => map!(dispatch::skew, bias),
             #[cfg(feature = "moment")]
@@ -1018,20 +1231,22 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             UniqueCounts => map!(dispatch::unique_counts),
             Reverse => map!(dispatch::reverse),
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => map!(dispatch::approx_n_unique),
+            ApproxNUnique { precision } => map!(dispatch::approx_n_unique, precision),
+            #[cfg(feature = "approx_quantile")]
+            QuantileApprox { quantile } => map!(dispatch::quantile_approx, quantile),
             Coalesce => map_as_slice!(fill_null::coalesce),
             ShrinkType => map_owned!(shrink_type::shrink),
             #[cfg(feature = "diff")]
-            Diff(n, null_behavior) => map!(dispatch::diff, n, null_behavior),
+            Diff(null_behavior) => map_as_slice!(dispatch::diff, null_behavior),
             #[cfg(feature = "pct_change")]
-            PctChange => map_as_slice!(dispatch::pct_change),
+            PctChange(null_behavior) => map_as_slice!(dispatch::pct_change, null_behavior),
             #[cfg(feature = "interpolate")]
             Interpolate(method) => {
                 map!(dispatch::interpolate, method)
             },
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {
-                map_as_slice!(dispatch::interpolate_by)
+            InterpolateBy(method) => {
+                map_as_slice!(dispatch::interpolate_by, method)
             },
             #[cfg(feature = "log")]
             Entropy { base, normalize } => map!(log::entropy, base, normalize),
@@ -1058,9 +1273,9 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "cov")]
             Correlation { method, ddof } => map_as_slice!(correlation::corr, ddof, method),
             #[cfg(feature = "peaks")]
-            PeakMin => map!(peaks::peak_min),
+            PeakMin(plateau) => map!(peaks::peak_min, plateau),
             #[cfg(feature = "peaks")]
-            PeakMax => map!(peaks::peak_max),
+            PeakMax(plateau) => map!(peaks::peak_max, plateau),
             #[cfg(feature = "repeat_by")]
             RepeatBy => map_as_slice!(dispatch::repeat_by),
             Reshape(dims, nested) => map!(dispatch::reshape, &dims, &nested),
@@ -1096,6 +1311,11 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             RLE => map!(rle),
             #[cfg(feature = "rle")]
             RLEID => map!(rle_id),
+            #[cfg(feature = "to_dummies")]
+            ToDummies {
+                separator,
+                drop_first,
+            } => map!(dispatch::to_dummies, separator.as_deref(), drop_first),
             ToPhysical => map!(dispatch::to_physical),
             #[cfg(feature = "random")]
             Random { method, seed } => {
@@ -1131,10 +1351,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             },
             BackwardFill { limit } => map!(dispatch::backward_fill, limit),
             ForwardFill { limit } => map!(dispatch::forward_fill, limit),
-            MaxHorizontal => wrap!(dispatch::max_horizontal),
-            MinHorizontal => wrap!(dispatch::min_horizontal),
-            SumHorizontal => wrap!(dispatch::sum_horizontal),
-            MeanHorizontal => wrap!(dispatch::mean_horizontal),
+            MaxHorizontal { ignore_nulls } => wrap!(dispatch::max_horizontal, ignore_nulls),
+            MinHorizontal { ignore_nulls } => wrap!(dispatch::min_horizontal, ignore_nulls),
+            SumHorizontal { ignore_nulls } => wrap!(dispatch::sum_horizontal, ignore_nulls),
+            MeanHorizontal { ignore_nulls } => wrap!(dispatch::mean_horizontal, ignore_nulls),
             #[cfg(feature = "ewma")]
             EwmMean { options } => map!(ewm::ewm_mean, options),
             #[cfg(feature = "ewma_by")]
@@ -1143,6 +1363,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             EwmStd { options } => map!(ewm::ewm_std, options),
             #[cfg(feature = "ewma")]
             EwmVar { options } => map!(ewm::ewm_var, options),
+            #[cfg(feature = "ewma")]
+            EwmCov { options } => map_as_slice!(ewm::ewm_cov, options),
+            #[cfg(feature = "ewma")]
+            EwmCorr { options } => map_as_slice!(ewm::ewm_corr, options),
             #[cfg(feature = "replace")]
             Replace => {
                 map_as_slice!(dispatch::replace)
@@ -22,11 +22,8 @@ fn get_lib(lib: &str) -> PolarsResult<&'static PluginAndVersion> {
                 PolarsError::ComputeError(format!("error loading dynamic library: {e}").into())
             })?
         };
-        let version_function: libloading::Symbol<unsafe extern "C" fn() -> u32> = unsafe {
-            library
-                .get("_polars_plugin_get_version".as_bytes())
-                .unwrap()
-        };
+        let version_function: libloading::Symbol<unsafe extern "C" fn() -> u32> =
+            unsafe { get_symbol(&library, lib, "_polars_plugin_get_version")? };
 
         let version = unsafe { version_function() };
         let major = (version >> 16) as u16;
@@ -40,6 +37,17 @@ fn get_lib(lib: &str) -> PolarsResult<&'static PluginAndVersion> {
     }
 }
 
+/// Look up `symbol` in the plugin, which is not a valid plugin if it doesn't export it.
+unsafe fn get_symbol<'a, T>(
+    library: &'a Library,
+    lib: &str,
+    symbol: &str,
+) -> PolarsResult<libloading::Symbol<'a, T>> {
+    library.get(symbol.as_bytes()).map_err(
+        |e| polars_err!(ComputeError: "cannot find symbol '{}' in plugin '{}': {}", symbol, lib, e),
+    )
+}
+
 unsafe fn retrieve_error_msg(lib: &Library) -> &CStr {
     let symbol: libloading::Symbol<unsafe extern "C" fn() -> *mut std::os::raw::c_char> =
         lib.get(b"_polars_plugin_get_last_error_message\0").unwrap();
@@ -49,11 +57,11 @@ unsafe fn retrieve_error_msg(lib: &Library) -> &CStr {
 
 pub(super) unsafe fn call_plugin(
     s: &[Series],
-    lib: &str,
+    lib_path: &str,
     symbol: &str,
     kwargs: &[u8],
 ) -> PolarsResult<Series> {
-    let plugin = get_lib(lib)?;
+    let plugin = get_lib(lib_path)?;
     let lib = &plugin.0;
     let major = plugin.1;
 
@@ -74,9 +82,7 @@ pub(super) unsafe fn call_plugin(
                 *mut SeriesExport,
                 *const CallerContext,
             ),
-        > = lib
-            .get(format!("_polars_plugin_{}", symbol).as_bytes())
-            .unwrap();
+        > = get_symbol(lib, lib_path, &format!("_polars_plugin_{}", symbol))?;
 
         let input = s.iter().map(export_series).collect::<Vec<_>>();
         let input_len = s.len();
@@ -118,11 +124,11 @@ pub(super) unsafe fn call_plugin(
 
 pub(super) unsafe fn plugin_field(
     fields: &[Field],
-    lib: &str,
+    lib_path: &str,
     symbol: &str,
     kwargs: &[u8],
 ) -> PolarsResult<Field> {
-    let plugin = get_lib(lib)?;
+    let plugin = get_lib(lib_path)?;
     let lib = &plugin.0;
     let major = plugin.1;
     let minor = plugin.2;
@@ -150,9 +156,7 @@ pub(super) unsafe fn plugin_field(
                 // *mut ArrowSchema: pointer where the return value can be written
                 let symbol: libloading::Symbol<
                     unsafe extern "C" fn(*const ArrowSchema, usize, *mut ArrowSchema),
-                > = lib
-                    .get((format!("_polars_plugin_field_{}", symbol)).as_bytes())
-                    .unwrap();
+                > = get_symbol(lib, lib_path, &format!("_polars_plugin_field_{}", symbol))?;
                 symbol(slice_ptr, n_args, return_value_ptr);
             },
             1 => {
@@ -169,9 +173,7 @@ pub(super) unsafe fn plugin_field(
                         *const u8,
                         usize,
                     ),
-                > = lib
-                    .get((format!("_polars_plugin_field_{}", symbol)).as_bytes())
-                    .unwrap();
+                > = get_symbol(lib, lib_path, &format!("_polars_plugin_field_{}", symbol))?;
 
                 let kwargs_ptr = kwargs.as_ptr();
                 let kwargs_len = kwargs.len();
@@ -37,7 +37,8 @@ impl FunctionExpr {
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
             Negate => mapper.with_same_dtype(),
-            NullCount => mapper.with_dtype(IDX_DTYPE),
+            NullCount | ArgMin | ArgMax => mapper.with_dtype(IDX_DTYPE),
+            Product { overflow } => mapper.map_dtype(|dt| overflow.product_dtype(dt)),
             Pow(pow_function) => match pow_function {
                 PowFunction::Generic => mapper.pow_dtype(),
                 _ => mapper.map_to_float_dtype(),
@@ -49,6 +50,8 @@ impl FunctionExpr {
             ArgWhere => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "search_sorted")]
             SearchSorted(_) => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "index_of")]
+            IndexOf => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "range")]
             Range(func) => func.get_field(mapper),
             #[cfg(feature = "trigonometry")]
@@ -82,7 +85,7 @@ impl FunctionExpr {
             #[cfg(feature = "round_series")]
             Clip { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "mode")]
-            Mode => mapper.with_same_dtype(),
+            Mode { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "moment")]
             Skew(_) => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "moment")]
@@ -133,13 +136,21 @@ impl FunctionExpr {
             #[cfg(feature = "cum_agg")]
             CumMax { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            ApproxNUnique { .. } => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "approx_quantile")]
+            QuantileApprox { .. } => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "hist")]
             Hist {
                 include_category,
                 include_breakpoint,
+                normalize,
                 ..
             } => {
+                let count = if *normalize {
+                    Field::new("proportion", DataType::Float64)
+                } else {
+                    Field::new("count", IDX_DTYPE)
+                };
                 if *include_breakpoint || *include_category {
                     let mut fields = Vec::with_capacity(3);
                     if *include_breakpoint {
@@ -151,14 +162,14 @@ impl FunctionExpr {
                             DataType::Categorical(None, Default::default()),
                         ));
                     }
-                    fields.push(Field::new("count", IDX_DTYPE));
+                    fields.push(count);
                     mapper.with_dtype(DataType::Struct(fields))
                 } else {
-                    mapper.with_dtype(IDX_DTYPE)
+                    mapper.with_dtype(count.dtype)
                 }
             },
             #[cfg(feature = "diff")]
-            Diff(_, _) => mapper.map_dtype(|dt| match dt {
+            Diff(_) => mapper.map_dtype(|dt| match dt {
                 #[cfg(feature = "dtype-datetime")]
                 DataType::Datetime(tu, _) => DataType::Duration(*tu),
                 #[cfg(feature = "dtype-date")]
@@ -171,17 +182,24 @@ impl FunctionExpr {
                 dt => dt.clone(),
             }),
             #[cfg(feature = "pct_change")]
-            PctChange => mapper.map_dtype(|dt| match dt {
+            PctChange(_) => mapper.map_dtype(|dt| match dt {
                 DataType::Float64 | DataType::Float32 => dt.clone(),
                 _ => DataType::Float64,
             }),
             #[cfg(feature = "interpolate")]
             Interpolate(method) => match method {
-                InterpolationMethod::Linear => mapper.map_numeric_to_float_dtype(),
+                InterpolationMethod::Linear | InterpolationMethod::Cubic => {
+                    mapper.map_numeric_to_float_dtype()
+                },
                 InterpolationMethod::Nearest => mapper.with_same_dtype(),
             },
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => mapper.map_numeric_to_float_dtype(),
+            InterpolateBy(method) => match method {
+                InterpolationMethod::Linear | InterpolationMethod::Cubic => {
+                    mapper.map_numeric_to_float_dtype()
+                },
+                InterpolationMethod::Nearest => mapper.with_same_dtype(),
+            },
             ShrinkType => {
                 // we return the smallest type this can return
                 // this might not be correct once the actual data
@@ -217,9 +235,9 @@ impl FunctionExpr {
             #[cfg(feature = "cov")]
             Correlation { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "peaks")]
-            PeakMin => mapper.with_same_dtype(),
+            PeakMin(_) => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "peaks")]
-            PeakMax => mapper.with_same_dtype(),
+            PeakMax(_) => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "cutqcut")]
             Cut {
                 include_breaks: false,
@@ -283,6 +301,20 @@ impl FunctionExpr {
             }),
             #[cfg(feature = "rle")]
             RLEID => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "to_dummies")]
+            ToDummies {
+                separator,
+                drop_first,
+            } => {
+                let field = &fields[0];
+                let dummies = polars_ops::prelude::to_dummies_fields(
+                    field.name(),
+                    field.data_type(),
+                    separator.as_deref(),
+                    *drop_first,
+                );
+                mapper.with_opt_dtype(dummies.map(DataType::Struct))
+            },
             ToPhysical => mapper.to_physical_type(),
             #[cfg(feature = "random")]
             Random { .. } => mapper.with_same_dtype(),
@@ -295,16 +327,16 @@ impl FunctionExpr {
             } => unsafe { plugin::plugin_field(fields, lib, symbol.as_ref(), kwargs) },
             BackwardFill { .. } => mapper.with_same_dtype(),
             ForwardFill { .. } => mapper.with_same_dtype(),
-            MaxHorizontal => mapper.map_to_supertype(),
-            MinHorizontal => mapper.map_to_supertype(),
-            SumHorizontal => {
+            MaxHorizontal { .. } => mapper.map_to_supertype(),
+            MinHorizontal { .. } => mapper.map_to_supertype(),
+            SumHorizontal { .. } => {
                 if mapper.fields[0].data_type() == &DataType::Boolean {
                     mapper.with_dtype(DataType::UInt32)
                 } else {
                     mapper.map_to_supertype()
                 }
             },
-            MeanHorizontal => mapper.map_to_float_dtype(),
+            MeanHorizontal { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma")]
             EwmMean { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma_by")]
@@ -313,6 +345,14 @@ impl FunctionExpr {
             EwmStd { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma")]
             EwmVar { .. } => mapper.map_to_float_dtype(),
+            #[cfg(feature = "ewma")]
+            EwmCov { .. } | EwmCorr { .. } => mapper.try_map_dtypes(|dtypes| {
+                Ok(if dtypes.iter().all(|dt| **dt == DataType::Float32) {
+                    DataType::Float32
+                } else {
+                    DataType::Float64
+                })
+            }),
             #[cfg(feature = "replace")]
             Replace => mapper.with_same_dtype(),
             #[cfg(feature = "replace")]
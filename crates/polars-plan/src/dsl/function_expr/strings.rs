@@ -40,8 +40,14 @@ pub enum StringFunction {
     CountMatches(bool),
     EndsWith,
     Extract(usize),
+    ExtractNamed(String),
     ExtractAll,
     #[cfg(feature = "extract_groups")]
+    ExtractAllGroups {
+        dtype: DataType,
+        pat: String,
+    },
+    #[cfg(feature = "extract_groups")]
     ExtractGroups {
         dtype: DataType,
         pat: String,
@@ -72,6 +78,17 @@ pub enum StringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_normalize")]
+    Normalize {
+        form: UnicodeForm,
+        case_fold: bool,
+    },
+    #[cfg(feature = "string_similarity")]
+    LevenshteinDistance,
+    #[cfg(feature = "string_similarity")]
+    HammingDistance,
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
     #[cfg(feature = "string_pad")]
     PadStart {
         length: usize,
@@ -136,10 +153,20 @@ impl StringFunction {
             Contains { .. } => mapper.with_dtype(DataType::Boolean),
             CountMatches(_) => mapper.with_dtype(DataType::UInt32),
             EndsWith | StartsWith => mapper.with_dtype(DataType::Boolean),
-            Extract(_) => mapper.with_same_dtype(),
+            Extract(_) | ExtractNamed(_) => mapper.with_same_dtype(),
             ExtractAll => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
             #[cfg(feature = "extract_groups")]
-            ExtractGroups { dtype, .. } => mapper.with_dtype(dtype.clone()),
+            ExtractAllGroups { dtype, .. } => mapper.with_dtype(dtype.clone()),
+            #[cfg(feature = "extract_groups")]
+            ExtractGroups { dtype, .. } => match dtype {
+                // A pattern without capture groups yields a single null field
+                // named after the input column.
+                DataType::Struct(fields) if fields.is_empty() => {
+                    let name = mapper.args()[0].name();
+                    mapper.with_dtype(DataType::Struct(vec![Field::new(name, DataType::Null)]))
+                },
+                dtype => mapper.with_dtype(dtype.clone()),
+            },
             #[cfg(feature = "string_to_integer")]
             ToInteger { .. } => mapper.with_dtype(DataType::Int64),
             #[cfg(feature = "regex")]
@@ -154,6 +181,12 @@ impl StringFunction {
             Replace { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_normalize")]
+            Normalize { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "string_similarity")]
+            LevenshteinDistance | HammingDistance => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "temporal")]
             Strptime(dtype, _) => mapper.with_dtype(dtype.clone()),
             Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
@@ -201,13 +234,15 @@ impl Display for StringFunction {
             Contains { .. } => "contains",
             CountMatches(_) => "count_matches",
             EndsWith { .. } => "ends_with",
-            Extract(_) => "extract",
+            Extract(_) | ExtractNamed(_) => "extract",
             #[cfg(feature = "concat_str")]
             ConcatHorizontal { .. } => "concat_horizontal",
             #[cfg(feature = "concat_str")]
             ConcatVertical { .. } => "concat_vertical",
             ExtractAll => "extract_all",
             #[cfg(feature = "extract_groups")]
+            ExtractAllGroups { .. } => "extract_all_groups",
+            #[cfg(feature = "extract_groups")]
             ExtractGroups { .. } => "extract_groups",
             #[cfg(feature = "string_to_integer")]
             ToInteger { .. } => "to_integer",
@@ -230,6 +265,14 @@ impl Display for StringFunction {
             Replace { .. } => "replace",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_normalize")]
+            Normalize { .. } => "normalize",
+            #[cfg(feature = "string_similarity")]
+            LevenshteinDistance => "levenshtein_distance",
+            #[cfg(feature = "string_similarity")]
+            HammingDistance => "hamming_distance",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -292,10 +335,15 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             EndsWith { .. } => map_as_slice!(strings::ends_with),
             StartsWith { .. } => map_as_slice!(strings::starts_with),
             Extract(group_index) => map_as_slice!(strings::extract, group_index),
+            ExtractNamed(group_name) => map_as_slice!(strings::extract_named, &group_name),
             ExtractAll => {
                 map_as_slice!(strings::extract_all)
             },
             #[cfg(feature = "extract_groups")]
+            ExtractAllGroups { pat, dtype } => {
+                map!(strings::extract_all_groups, &pat, &dtype)
+            },
+            #[cfg(feature = "extract_groups")]
             ExtractGroups { pat, dtype } => {
                 map!(strings::extract_groups, &pat, &dtype)
             },
@@ -340,6 +388,14 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Replace { n, literal } => map_as_slice!(strings::replace, literal, n),
             #[cfg(feature = "string_reverse")]
             Reverse => map!(strings::reverse),
+            #[cfg(feature = "string_normalize")]
+            Normalize { form, case_fold } => map!(strings::normalize, form, case_fold),
+            #[cfg(feature = "string_similarity")]
+            LevenshteinDistance => map_as_slice!(strings::levenshtein_distance),
+            #[cfg(feature = "string_similarity")]
+            HammingDistance => map_as_slice!(strings::hamming_distance),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => map_as_slice!(strings::jaro_winkler),
             Uppercase => map!(uppercase),
             Lowercase => map!(lowercase),
             #[cfg(feature = "nightly")]
@@ -472,6 +528,20 @@ pub(super) fn extract(s: &[Series], group_index: usize) -> PolarsResult<Series>
     ca.extract(pat, group_index).map(|ca| ca.into_series())
 }
 
+/// Extract a named capture group of a regex pattern from a string value.
+pub(super) fn extract_named(s: &[Series], group_name: &str) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let pat = s[1].str()?;
+    ca.extract_named(pat, group_name).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "extract_groups")]
+/// Extract the capture groups of every regex match as a list of structs
+pub(super) fn extract_all_groups(s: &Series, pat: &str, dtype: &DataType) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    ca.extract_all_groups(pat, dtype).map(|ca| ca.into_series())
+}
+
 #[cfg(feature = "extract_groups")]
 /// Extract all capture groups from a regex pattern as a struct
 pub(super) fn extract_groups(s: &Series, pat: &str, dtype: &DataType) -> PolarsResult<Series> {
@@ -891,6 +961,33 @@ pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
     Ok(ca.str_reverse().into_series())
 }
 
+#[cfg(feature = "string_normalize")]
+pub(super) fn normalize(s: &Series, form: UnicodeForm, case_fold: bool) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.str_normalize(form, case_fold).into_series())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn levenshtein_distance(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.levenshtein_distance(other)?.into_series())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn hamming_distance(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.hamming_distance(other)?.into_series())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaro_winkler(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.jaro_winkler(other)?.into_series())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(s: &[Series], strict: bool) -> PolarsResult<Series> {
     let ca = s[0].str()?;
@@ -12,8 +12,8 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             IsoYear => map!(datetime::iso_year),
             Month => map!(datetime::month),
             Quarter => map!(datetime::quarter),
-            Week => map!(datetime::week),
-            WeekDay => map!(datetime::weekday),
+            Week(week_start) => map!(datetime::week, week_start),
+            WeekDay(week_start) => map!(datetime::weekday, week_start),
             Duration(tu) => map_as_slice!(datetime::duration, tu),
             Day => map!(datetime::day),
             OrdinalDay => map!(datetime::ordinal_day),
@@ -39,8 +39,8 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             ConvertTimeZone(tz) => map!(datetime::convert_time_zone, &tz),
             WithTimeUnit(tu) => map!(datetime::with_time_unit, tu),
             CastTimeUnit(tu) => map!(datetime::cast_time_unit, tu),
-            Truncate => {
-                map_as_slice!(datetime::truncate)
+            Truncate(week_start) => {
+                map_as_slice!(datetime::truncate, week_start)
             },
             #[cfg(feature = "offset_by")]
             OffsetBy => {
@@ -54,7 +54,7 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             BaseUtcOffset => map!(datetime::base_utc_offset),
             #[cfg(feature = "timezones")]
             DSTOffset => map!(datetime::dst_offset),
-            Round => map_as_slice!(datetime::round),
+            Round(ties) => map_as_slice!(datetime::round, ties),
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(tz, non_existent) => {
                 map_as_slice!(dispatch::replace_time_zone, tz.as_deref(), non_existent)
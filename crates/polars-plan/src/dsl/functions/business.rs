@@ -1,13 +1,19 @@
 use super::*;
 
+/// Count the business days between `start` and `end`, not including `end`.
+///
+/// The holidays of every row are `holidays` together with the dates in the row's list of
+/// `row_holidays`, if that is given.
 #[cfg(feature = "dtype-date")]
 pub fn business_day_count(
     start: Expr,
     end: Expr,
     week_mask: [bool; 7],
     holidays: Vec<i32>,
+    row_holidays: Option<Expr>,
 ) -> Expr {
-    let input = vec![start, end];
+    let mut input = vec![start, end];
+    input.extend(row_holidays);
 
     Expr::Function {
         input,
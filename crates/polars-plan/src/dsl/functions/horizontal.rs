@@ -1,18 +1,35 @@
 use super::*;
 
+/// The output type of a cumulative fold, which has a field for every input. If `acc` is set,
+/// the last input is the accumulator, which only gets a field, in front, if `include_init` is
+/// set. The fields get the `return_dtype`, or else the supertype of the inputs.
 #[cfg(feature = "dtype-struct")]
-fn cum_fold_dtype() -> GetOutput {
-    GetOutput::map_fields(|fields| {
-        let mut st = fields[0].dtype.clone();
-        for fld in &fields[1..] {
-            st = get_supertype(&st, &fld.dtype).unwrap();
-        }
+fn cum_fold_dtype(acc: bool, include_init: bool, return_dtype: Option<DataType>) -> GetOutput {
+    GetOutput::map_fields(move |fields| {
+        let dtype = match &return_dtype {
+            Some(dtype) => dtype.clone(),
+            None => {
+                let mut st = fields[0].dtype.clone();
+                for fld in &fields[1..] {
+                    st = get_supertype(&st, &fld.dtype).unwrap();
+                }
+                st
+            },
+        };
+        let name = &fields[0].name;
+        let fields = match fields.split_last() {
+            Some((init, fields)) if acc && include_init => {
+                std::iter::once(init).chain(fields).collect::<Vec<_>>()
+            },
+            Some((_, fields)) if acc => fields.iter().collect(),
+            _ => fields.iter().collect(),
+        };
         Ok(Field::new(
-            &fields[0].name,
+            name,
             DataType::Struct(
                 fields
                     .iter()
-                    .map(|fld| Field::new(fld.name(), st.clone()))
+                    .map(|fld| Field::new(fld.name(), dtype.clone()))
                     .collect(),
             ),
         ))
@@ -21,6 +38,23 @@ fn cum_fold_dtype() -> GetOutput {
 
 /// Accumulate over multiple columns horizontally / row wise.
 pub fn fold_exprs<F, E>(acc: Expr, f: F, exprs: E) -> Expr
+where
+    F: 'static + Fn(Series, Series) -> PolarsResult<Option<Series>> + Send + Sync + Clone,
+    E: AsRef<[Expr]>,
+{
+    fold_exprs_with_dtype(acc, f, exprs, None)
+}
+
+/// Accumulate over multiple columns horizontally / row wise, like [`fold_exprs`].
+///
+/// The result is cast to `return_dtype` if it is set, otherwise it is assumed to have the
+/// supertype of the inputs.
+pub fn fold_exprs_with_dtype<F, E>(
+    acc: Expr,
+    f: F,
+    exprs: E,
+    return_dtype: Option<DataType>,
+) -> Expr
 where
     F: 'static + Fn(Series, Series) -> PolarsResult<Option<Series>> + Send + Sync + Clone,
     E: AsRef<[Expr]>,
@@ -28,6 +62,10 @@ where
     let mut exprs = exprs.as_ref().to_vec();
     exprs.push(acc);
 
+    let output_type = match &return_dtype {
+        Some(dtype) => GetOutput::from_type(dtype.clone()),
+        None => GetOutput::super_type(),
+    };
     let function = SpecialEq::new(Arc::new(move |series: &mut [Series]| {
         let mut series = series.to_vec();
         let mut acc = series.pop().unwrap();
@@ -37,13 +75,16 @@ where
                 acc = a
             }
         }
-        Ok(Some(acc))
+        match &return_dtype {
+            Some(dtype) => acc.strict_cast(dtype).map(Some),
+            None => Ok(Some(acc)),
+        }
     }) as Arc<dyn SeriesUdf>);
 
     Expr::AnonymousFunction {
         input: exprs,
         function,
-        output_type: GetOutput::super_type(),
+        output_type,
         options: FunctionOptions {
             collect_groups: ApplyOptions::GroupWise,
             input_wildcard_expansion: true,
@@ -133,7 +174,7 @@ where
     Expr::AnonymousFunction {
         input: exprs,
         function,
-        output_type: cum_fold_dtype(),
+        output_type: cum_fold_dtype(false, false, None),
         options: FunctionOptions {
             collect_groups: ApplyOptions::GroupWise,
             input_wildcard_expansion: true,
@@ -147,6 +188,25 @@ where
 /// Accumulate over multiple columns horizontally / row wise.
 #[cfg(feature = "dtype-struct")]
 pub fn cum_fold_exprs<F, E>(acc: Expr, f: F, exprs: E, include_init: bool) -> Expr
+where
+    F: 'static + Fn(Series, Series) -> PolarsResult<Option<Series>> + Send + Sync + Clone,
+    E: AsRef<[Expr]>,
+{
+    cum_fold_exprs_with_dtype(acc, f, exprs, include_init, None)
+}
+
+/// Accumulate over multiple columns horizontally / row wise, like [`cum_fold_exprs`].
+///
+/// Every intermediate result is a field of the output struct, which is cast to
+/// `return_dtype` if it is set.
+#[cfg(feature = "dtype-struct")]
+pub fn cum_fold_exprs_with_dtype<F, E>(
+    acc: Expr,
+    f: F,
+    exprs: E,
+    include_init: bool,
+    return_dtype: Option<DataType>,
+) -> Expr
 where
     F: 'static + Fn(Series, Series) -> PolarsResult<Option<Series>> + Send + Sync + Clone,
     E: AsRef<[Expr]>,
@@ -154,6 +214,7 @@ where
     let mut exprs = exprs.as_ref().to_vec();
     exprs.push(acc);
 
+    let output_type = cum_fold_dtype(true, include_init, return_dtype.clone());
     let function = SpecialEq::new(Arc::new(move |series: &mut [Series]| {
         let mut series = series.to_vec();
         let mut acc = series.pop().unwrap();
@@ -167,9 +228,15 @@ where
             let name = s.name().to_string();
             if let Some(a) = f(acc.clone(), s)? {
                 acc = a;
-                acc.rename(&name);
-                result.push(acc.clone());
             }
+            acc.rename(&name);
+            result.push(acc.clone());
+        }
+        if let Some(dtype) = &return_dtype {
+            result = result
+                .iter()
+                .map(|s| s.strict_cast(dtype))
+                .collect::<PolarsResult<_>>()?;
         }
 
         StructChunked::new(acc.name(), &result).map(|ca| Some(ca.into_series()))
@@ -178,7 +245,7 @@ where
     Expr::AnonymousFunction {
         input: exprs,
         function,
-        output_type: cum_fold_dtype(),
+        output_type,
         options: FunctionOptions {
             collect_groups: ApplyOptions::GroupWise,
             input_wildcard_expansion: true,
@@ -189,11 +256,30 @@ where
     }
 }
 
+/// Replace the null values of boolean `exprs` by the identity of the reduction, so that they
+/// don't affect its outcome.
+fn skip_nulls(exprs: &[Expr], ignore_nulls: bool, identity: bool) -> Vec<Expr> {
+    if !ignore_nulls {
+        return exprs.to_vec();
+    }
+    exprs
+        .iter()
+        .map(|e| {
+            e.clone()
+                .strict_cast(DataType::Boolean)
+                .fill_null(lit(identity))
+        })
+        .collect()
+}
+
 /// Create a new column with the bitwise-and of the elements in each row.
 ///
 /// The name of the resulting column will be "all"; use [`alias`](Expr::alias) to choose a different name.
-pub fn all_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
-    let exprs = exprs.as_ref().to_vec();
+///
+/// Null values follow Kleene logic, unless `ignore_nulls` is set, in which case they are
+/// skipped and a row without valid values is `true`.
+pub fn all_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsResult<Expr> {
+    let exprs = skip_nulls(exprs.as_ref(), ignore_nulls, true);
     polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
     // This will be reduced to `expr & expr` during conversion to IR.
     Ok(Expr::Function {
@@ -209,8 +295,11 @@ pub fn all_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
 /// Create a new column with the bitwise-or of the elements in each row.
 ///
 /// The name of the resulting column will be "any"; use [`alias`](Expr::alias) to choose a different name.
-pub fn any_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
-    let exprs = exprs.as_ref().to_vec();
+///
+/// Null values follow Kleene logic, unless `ignore_nulls` is set, in which case they are
+/// skipped and a row without valid values is `false`.
+pub fn any_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsResult<Expr> {
+    let exprs = skip_nulls(exprs.as_ref(), ignore_nulls, false);
     polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
     // This will be reduced to `expr | expr` during conversion to IR.
     Ok(Expr::Function {
@@ -226,13 +315,15 @@ pub fn any_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
 /// Create a new column with the maximum value per row.
 ///
 /// The name of the resulting column will be `"max"`; use [`alias`](Expr::alias) to choose a different name.
-pub fn max_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+///
+/// Null values are skipped if `ignore_nulls` is set, otherwise a row with a null value is null.
+pub fn max_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsResult<Expr> {
     let exprs = exprs.as_ref().to_vec();
     polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
 
     Ok(Expr::Function {
         input: exprs,
-        function: FunctionExpr::MaxHorizontal,
+        function: FunctionExpr::MaxHorizontal { ignore_nulls },
         options: FunctionOptions {
             collect_groups: ApplyOptions::ElementWise,
             input_wildcard_expansion: true,
@@ -246,13 +337,15 @@ pub fn max_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
 /// Create a new column with the minimum value per row.
 ///
 /// The name of the resulting column will be `"min"`; use [`alias`](Expr::alias) to choose a different name.
-pub fn min_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+///
+/// Null values are skipped if `ignore_nulls` is set, otherwise a row with a null value is null.
+pub fn min_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsResult<Expr> {
     let exprs = exprs.as_ref().to_vec();
     polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
 
     Ok(Expr::Function {
         input: exprs,
-        function: FunctionExpr::MinHorizontal,
+        function: FunctionExpr::MinHorizontal { ignore_nulls },
         options: FunctionOptions {
             collect_groups: ApplyOptions::ElementWise,
             input_wildcard_expansion: true,
@@ -264,13 +357,15 @@ pub fn min_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
 }
 
 /// Sum all values horizontally across columns.
-pub fn sum_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+///
+/// Null values are skipped if `ignore_nulls` is set, otherwise a row with a null value is null.
+pub fn sum_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsResult<Expr> {
     let exprs = exprs.as_ref().to_vec();
     polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
 
     Ok(Expr::Function {
         input: exprs,
-        function: FunctionExpr::SumHorizontal,
+        function: FunctionExpr::SumHorizontal { ignore_nulls },
         options: FunctionOptions {
             collect_groups: ApplyOptions::ElementWise,
             input_wildcard_expansion: true,
@@ -282,13 +377,15 @@ pub fn sum_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
 }
 
 /// Compute the mean of all values horizontally across columns.
-pub fn mean_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+///
+/// Null values are skipped if `ignore_nulls` is set, otherwise a row with a null value is null.
+pub fn mean_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsResult<Expr> {
     let exprs = exprs.as_ref().to_vec();
     polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
 
     Ok(Expr::Function {
         input: exprs,
-        function: FunctionExpr::MeanHorizontal,
+        function: FunctionExpr::MeanHorizontal { ignore_nulls },
         options: FunctionOptions {
             collect_groups: ApplyOptions::ElementWise,
             input_wildcard_expansion: true,
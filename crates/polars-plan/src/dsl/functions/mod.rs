@@ -12,6 +12,8 @@ mod correlation;
 pub(crate) mod horizontal;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 mod index;
+#[cfg(feature = "ffi_plugin")]
+mod plugin;
 #[cfg(feature = "range")]
 mod range;
 mod repeat;
@@ -30,6 +32,8 @@ pub use correlation::*;
 pub use horizontal::*;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 pub use index::*;
+#[cfg(feature = "ffi_plugin")]
+pub use plugin::*;
 #[cfg(feature = "dtype-struct")]
 use polars_core::utils::get_supertype;
 #[cfg(all(feature = "range", feature = "temporal"))]
@@ -0,0 +1,27 @@
+use super::*;
+
+/// Call the expression kernel `function_name` exposed by the dynamic library at `plugin_path`
+/// over the C ABI of `polars-ffi`.
+///
+/// The `kwargs` are passed to the plugin as is, and it is up to the plugin to deserialize them.
+///
+/// # Safety
+/// The library is loaded and called into without any validation, so it must be a plugin that
+/// implements the plugin ABI for `function_name`.
+pub unsafe fn register_plugin_function(
+    plugin_path: &str,
+    function_name: &str,
+    args: Vec<Expr>,
+    kwargs: Vec<u8>,
+    options: FunctionOptions,
+) -> Expr {
+    Expr::Function {
+        input: args,
+        function: FunctionExpr::FfiPlugin {
+            lib: Arc::from(plugin_path),
+            symbol: Arc::from(function_name),
+            kwargs: Arc::from(kwargs),
+        },
+        options,
+    }
+}
@@ -36,6 +36,9 @@ pub fn int_ranges(start: Expr, end: Expr, step: Expr) -> Expr {
 }
 
 /// Create a date range from a `start` and `stop` expression.
+///
+/// `start` and `end` must be single values. `closed` decides which of them are part of the
+/// range. In a group-by aggregation, a range is created per group, which gives a list column.
 #[cfg(feature = "temporal")]
 pub fn date_range(start: Expr, end: Expr, interval: Duration, closed: ClosedWindow) -> Expr {
     let input = vec![start, end];
@@ -68,6 +71,9 @@ pub fn date_ranges(start: Expr, end: Expr, interval: Duration, closed: ClosedWin
 }
 
 /// Create a datetime range from a `start` and `stop` expression.
+///
+/// Like [`date_range`], with the output in `time_unit` and localized to `time_zone` if they
+/// are given. Naive `start` and `end` values are interpreted in that time zone.
 #[cfg(feature = "dtype-datetime")]
 pub fn datetime_range(
     start: Expr,
@@ -277,6 +277,9 @@ impl ListNameSpace {
     /// an `upper_bound` of struct fields that will be set.
     /// If this is incorrectly downstream operation may fail. For instance an `all().sum()` expression
     /// will look in the current schema to determine which columns to select.
+    ///
+    /// With [`ListToStructWidthStrategy::Fixed`] the number of fields is known, so the schema is
+    /// always correct and `upper_bound` is ignored.
     pub fn to_struct(
         self,
         n_fields: ListToStructWidthStrategy,
@@ -285,6 +288,11 @@ impl ListNameSpace {
     ) -> Expr {
         // heap allocate the output type and fill it later
         let out_dtype = Arc::new(RwLock::new(None::<DataType>));
+        let n_fields_upper_bound = match n_fields {
+            ListToStructWidthStrategy::Fixed(n) => n,
+            _ => upper_bound,
+        };
+        let schema_name_generator = name_generator.clone();
 
         self.0
             .map(
@@ -305,11 +313,11 @@ impl ListNameSpace {
                             let mut lock = out_dtype.write().unwrap();
 
                             let inner = dt.inner_dtype().unwrap();
-                            let fields = (0..upper_bound)
-                                .map(|i| {
-                                    let name = _default_struct_name_gen(i);
-                                    Field::from_owned(name, inner.clone())
-                                })
+                            let name_generator = schema_name_generator
+                                .as_deref()
+                                .unwrap_or(&_default_struct_name_gen);
+                            let fields = (0..n_fields_upper_bound)
+                                .map(|i| Field::from_owned(name_generator(i), inner.clone()))
                                 .collect();
                             let dt = DataType::Struct(fields);
 
@@ -374,11 +382,19 @@ impl ListNameSpace {
 
     /// Return the SET UNION between both list arrays.
     #[cfg(feature = "list_sets")]
-    pub fn union<E: Into<Expr>>(self, other: E) -> Expr {
+    pub fn set_union<E: Into<Expr>>(self, other: E) -> Expr {
         let other = other.into();
         self.set_operation(other, SetOperation::Union)
     }
 
+    /// Return the SET UNION between both list arrays.
+    ///
+    /// Alias for [`ListNameSpace::set_union`].
+    #[cfg(feature = "list_sets")]
+    pub fn union<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_union(other)
+    }
+
     /// Return the SET DIFFERENCE between both list arrays.
     #[cfg(feature = "list_sets")]
     pub fn set_difference<E: Into<Expr>>(self, other: E) -> Expr {
@@ -47,6 +47,7 @@ pub use arity::*;
 #[cfg(feature = "dtype-array")]
 pub use array::*;
 use arrow::legacy::prelude::QuantileInterpolOptions;
+pub use binary::BinaryEncoding;
 pub use expr::*;
 pub use function_expr::schema::FieldsMapper;
 pub use function_expr::*;
@@ -302,44 +303,12 @@ impl Expr {
 
     /// Get the index value that has the minimum value.
     pub fn arg_min(self) -> Self {
-        let options = FunctionOptions {
-            collect_groups: ApplyOptions::GroupWise,
-            returns_scalar: true,
-            fmt_str: "arg_min",
-            ..Default::default()
-        };
-
-        self.function_with_options(
-            move |s: Series| {
-                Ok(Some(Series::new(
-                    s.name(),
-                    &[s.arg_min().map(|idx| idx as u32)],
-                )))
-            },
-            GetOutput::from_type(IDX_DTYPE),
-            options,
-        )
+        self.apply_many_private(FunctionExpr::ArgMin, &[], true, false)
     }
 
     /// Get the index value that has the maximum value.
     pub fn arg_max(self) -> Self {
-        let options = FunctionOptions {
-            collect_groups: ApplyOptions::GroupWise,
-            returns_scalar: true,
-            fmt_str: "arg_max",
-            ..Default::default()
-        };
-
-        self.function_with_options(
-            move |s: Series| {
-                Ok(Some(Series::new(
-                    s.name(),
-                    &[s.arg_max().map(|idx| idx as IdxSize)],
-                )))
-            },
-            GetOutput::from_type(IDX_DTYPE),
-            options,
-        )
+        self.apply_many_private(FunctionExpr::ArgMax, &[], true, false)
     }
 
     /// Get the index values that would sort this expression.
@@ -374,6 +343,25 @@ impl Expr {
         }
     }
 
+    #[cfg(feature = "index_of")]
+    /// Find the index of the first value that equals `element`, or null if there is none.
+    ///
+    /// `element` must be a single value; a null `element` finds the first null. Sorted data
+    /// without nulls is binary searched.
+    pub fn index_of<E: Into<Expr>>(self, element: E) -> Expr {
+        let element = element.into();
+        Expr::Function {
+            input: vec![self, element],
+            function: FunctionExpr::IndexOf,
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::GroupWise,
+                returns_scalar: true,
+                fmt_str: "index_of",
+                ..Default::default()
+            },
+        }
+    }
+
     /// Cast expression to another data type.
     /// Throws an error if conversion had overflows.
     pub fn strict_cast(self, data_type: DataType) -> Self {
@@ -641,6 +629,33 @@ impl Expr {
         }
     }
 
+    /// Aggregate every group with a function/closure that reduces the `Series` of the group to
+    /// a single value. This should only be used in a group_by aggregation, e.g. to compute a
+    /// statistic that has no dedicated expression over the windows of `rolling` or
+    /// `group_by_dynamic`.
+    ///
+    /// Unlike [apply](Self::apply), which creates a list per group, the result has a row per
+    /// group. It is the responsibility of the caller that the function returns a `Series` of
+    /// length 1, and that the schema is correct by giving the correct output_type.
+    pub fn agg_map<F>(self, function: F, output_type: GetOutput) -> Self
+    where
+        F: Fn(Series) -> PolarsResult<Series> + 'static + Send + Sync,
+    {
+        let f = move |s: &mut [Series]| function(std::mem::take(&mut s[0])).map(Some);
+
+        Expr::AnonymousFunction {
+            input: vec![self],
+            function: SpecialEq::new(Arc::new(f)),
+            output_type,
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::GroupWise,
+                returns_scalar: true,
+                fmt_str: "agg_map",
+                ..Default::default()
+            },
+        }
+    }
+
     fn apply_private(self, function_expr: FunctionExpr) -> Self {
         Expr::Function {
             input: vec![self],
@@ -789,26 +804,13 @@ impl Expr {
 
     /// Get the product aggregation of an expression.
     pub fn product(self) -> Self {
-        let options = FunctionOptions {
-            collect_groups: ApplyOptions::GroupWise,
-            returns_scalar: true,
-            fmt_str: "product",
-            ..Default::default()
-        };
+        self.product_with_overflow(ProductOverflow::Wrap)
+    }
 
-        self.function_with_options(
-            move |s: Series| Some(s.product().map(|sc| sc.into_series(s.name()))).transpose(),
-            GetOutput::map_dtype(|dt| {
-                use DataType as T;
-                Ok(match dt {
-                    T::Float32 => T::Float32,
-                    T::Float64 => T::Float64,
-                    T::UInt64 => T::UInt64,
-                    _ => T::Int64,
-                })
-            }),
-            options,
-        )
+    /// Get the product aggregation of an expression, using `overflow` to decide in which data
+    /// type integers are multiplied.
+    pub fn product_with_overflow(self, overflow: ProductOverflow) -> Self {
+        self.apply_many_private(FunctionExpr::Product { overflow }, &[], true, false)
     }
 
     /// Fill missing value with next non-null.
@@ -953,6 +955,13 @@ impl Expr {
         self.over_with_options(partition_by, None, Default::default())
     }
 
+    /// Apply the expression over the groups of `partition_by`, see [`over`](Expr::over).
+    ///
+    /// If `order_by` is given, every group is sorted by it before the expression is applied,
+    /// so that order-sensitive expressions such as `shift` or `cum_sum` don't need the whole
+    /// frame to be sorted first. Multiple `order_by` expressions need the `dtype-struct`
+    /// feature. `options` determines how the results of the groups are mapped to the rows,
+    /// see [`WindowMapping`].
     pub fn over_with_options<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
         self,
         partition_by: E,
@@ -1067,10 +1076,29 @@ impl Expr {
         self.apply_private(BooleanFunction::IsUnique.into())
     }
 
+    /// Get an approximation of the `quantile` of the values, estimated with a t-digest.
+    #[cfg(feature = "approx_quantile")]
+    pub fn quantile_approx(self, quantile: f64) -> Self {
+        self.apply_many_private(FunctionExpr::QuantileApprox { quantile }, &[], true, false)
+    }
+
+    /// Get an approximation of the median of the values, estimated with a t-digest.
+    #[cfg(feature = "approx_quantile")]
+    pub fn median_approx(self) -> Self {
+        self.quantile_approx(0.5)
+    }
+
     /// Get the approximate count of unique values.
     #[cfg(feature = "approx_unique")]
     pub fn approx_n_unique(self) -> Self {
-        self.apply_private(FunctionExpr::ApproxNUnique)
+        self.approx_n_unique_with_precision(HLL_DEFAULT_PRECISION)
+    }
+
+    /// Get the approximate count of unique values, estimated with a HyperLogLog of
+    /// `2^precision` registers.
+    #[cfg(feature = "approx_unique")]
+    pub fn approx_n_unique_with_precision(self, precision: u8) -> Self {
+        self.apply_private(FunctionExpr::ApproxNUnique { precision })
             .with_function_options(|mut options| {
                 options.returns_scalar = true;
                 options
@@ -1222,7 +1250,21 @@ impl Expr {
     #[cfg(feature = "mode")]
     /// Compute the mode(s) of this column. This is the most occurring value.
     pub fn mode(self) -> Expr {
-        self.apply_private(FunctionExpr::Mode)
+        self.apply_private(FunctionExpr::Mode { tie_break: None })
+    }
+
+    #[cfg(feature = "mode")]
+    /// Compute the mode of this column, using `tie_break` to choose a single value if several
+    /// values occur most often.
+    pub fn mode_tie_break(self, tie_break: ModeTieBreak) -> Expr {
+        self.apply_many_private(
+            FunctionExpr::Mode {
+                tie_break: Some(tie_break),
+            },
+            &[],
+            true,
+            false,
+        )
     }
 
     /// Exclude a column from a wildcard/regex selection.
@@ -1269,9 +1311,16 @@ impl Expr {
     }
 
     #[cfg(feature = "interpolate_by")]
-    /// Fill null values using interpolation.
+    /// Fill null values using linear interpolation based on another column.
     pub fn interpolate_by(self, by: Expr) -> Expr {
-        self.apply_many_private(FunctionExpr::InterpolateBy, &[by], false, false)
+        self.interpolate_by_with_method(by, InterpolationMethod::Linear)
+    }
+
+    #[cfg(feature = "interpolate_by")]
+    /// Fill null values using interpolation based on another column, which holds the
+    /// x-coordinates of the values.
+    pub fn interpolate_by_with_method(self, by: Expr, method: InterpolationMethod) -> Expr {
+        self.apply_many_private(FunctionExpr::InterpolateBy(method), &[by], false, false)
     }
 
     #[cfg(feature = "rolling_window")]
@@ -1480,13 +1529,31 @@ impl Expr {
     }
 
     #[cfg(feature = "peaks")]
+    /// Get a boolean mask of the local minima, the values that are less than both their
+    /// neighbors. Values at the edges only need to be less than their single neighbor.
     pub fn peak_min(self) -> Expr {
-        self.apply_private(FunctionExpr::PeakMin)
+        self.peak_min_with_plateau(PeakPlateau::default())
+    }
+
+    #[cfg(feature = "peaks")]
+    /// Like [`Expr::peak_min`], with `plateau` deciding which values of a run of equal minima
+    /// are peaks.
+    pub fn peak_min_with_plateau(self, plateau: PeakPlateau) -> Expr {
+        self.apply_private(FunctionExpr::PeakMin(plateau))
     }
 
     #[cfg(feature = "peaks")]
+    /// Get a boolean mask of the local maxima, the values that are greater than both their
+    /// neighbors. Values at the edges only need to be greater than their single neighbor.
     pub fn peak_max(self) -> Expr {
-        self.apply_private(FunctionExpr::PeakMax)
+        self.peak_max_with_plateau(PeakPlateau::default())
+    }
+
+    #[cfg(feature = "peaks")]
+    /// Like [`Expr::peak_max`], with `plateau` deciding which values of a run of equal maxima
+    /// are peaks.
+    pub fn peak_max_with_plateau(self, plateau: PeakPlateau) -> Expr {
+        self.apply_private(FunctionExpr::PeakMax(plateau))
     }
 
     #[cfg(feature = "rank")]
@@ -1632,14 +1699,26 @@ impl Expr {
 
     #[cfg(feature = "diff")]
     /// Calculate the n-th discrete difference between values.
-    pub fn diff(self, n: i64, null_behavior: NullBehavior) -> Expr {
-        self.apply_private(FunctionExpr::Diff(n, null_behavior))
+    ///
+    /// `n` is either a single number of periods, or a number of periods per row, e.g. computed
+    /// from other columns. Rows whose period is null or reaches outside of the series are null.
+    /// [`NullBehavior::Drop`] drops the first `n` rows instead, which needs a single `n`.
+    /// Under `over`, the differences are taken within every group.
+    pub fn diff<E: Into<Expr>>(self, n: E, null_behavior: NullBehavior) -> Expr {
+        self.apply_many_private(FunctionExpr::Diff(null_behavior), &[n.into()], false, false)
     }
 
     #[cfg(feature = "pct_change")]
     /// Computes percentage change between values.
-    pub fn pct_change(self, n: Expr) -> Expr {
-        self.apply_many_private(FunctionExpr::PctChange, &[n], false, false)
+    ///
+    /// The number of periods `n` and `null_behavior` work like they do in [`Expr::diff`].
+    pub fn pct_change<E: Into<Expr>>(self, n: E, null_behavior: NullBehavior) -> Expr {
+        self.apply_many_private(
+            FunctionExpr::PctChange(null_behavior),
+            &[n.into()],
+            false,
+            false,
+        )
     }
 
     #[cfg(feature = "moment")]
@@ -1720,6 +1799,23 @@ impl Expr {
         self.apply_private(FunctionExpr::EwmVar { options })
     }
 
+    #[cfg(feature = "ewma")]
+    /// Calculate the exponentially-weighted moving covariance with `other`.
+    ///
+    /// Rows in which either value is null don't count as observations.
+    pub fn ewm_cov(self, other: Expr, options: EWMOptions) -> Self {
+        self.apply_many_private(FunctionExpr::EwmCov { options }, &[other], false, false)
+    }
+
+    #[cfg(feature = "ewma")]
+    /// Calculate the exponentially-weighted moving correlation with `other`.
+    ///
+    /// Rows in which either value is null don't count as observations. `options.bias` is
+    /// ignored, as the bias correction cancels out.
+    pub fn ewm_corr(self, other: Expr, options: EWMOptions) -> Self {
+        self.apply_many_private(FunctionExpr::EwmCorr { options }, &[other], false, false)
+    }
+
     /// Returns whether any of the values in the column are `true`.
     ///
     /// If `ignore_nulls` is `False`, [Kleene logic] is used to deal with nulls:
@@ -1773,6 +1869,23 @@ impl Expr {
         })
     }
 
+    #[cfg(feature = "to_dummies")]
+    /// Create a struct with a `UInt8` indicator field per unique value, named
+    /// `{name}{separator}{value}`, like `DataFrame::to_dummies` does for a column.
+    ///
+    /// The fields of an `Enum` are its categories, so only then is the output type known
+    /// before the query runs.
+    pub fn to_dummies(self, separator: Option<&str>, drop_first: bool) -> Self {
+        self.apply_private(FunctionExpr::ToDummies {
+            separator: separator.map(|sep| sep.to_string()),
+            drop_first,
+        })
+        .with_function_options(|mut opts| {
+            opts.pass_name_to_apply = true;
+            opts
+        })
+    }
+
     #[cfg(feature = "unique_counts")]
     /// Returns a count of the unique values in the order of appearance.
     /// This method differs from [`Expr::value_counts]` in that it does not return the
@@ -24,6 +24,9 @@ pub static mut CALL_SERIES_UDF_PYTHON: Option<
 pub static mut CALL_DF_UDF_PYTHON: Option<
     fn(s: DataFrame, lambda: &PyObject) -> PolarsResult<DataFrame>,
 > = None;
+pub static mut CALL_DTYPE_UDF_PYTHON: Option<
+    fn(dtype: &DataType, lambda: &PyObject) -> PolarsResult<DataType>,
+> = None;
 pub(super) const MAGIC_BYTE_MARK: &[u8] = "POLARS_PYTHON_UDF".as_bytes();
 
 #[derive(Clone, Debug)]
@@ -103,6 +106,9 @@ impl<'a> Deserialize<'a> for PythonFunction {
 pub struct PythonUdfExpression {
     python_function: PyObject,
     output_type: Option<DataType>,
+    /// Computes the output type from the input type, so that the schema can be resolved
+    /// without calling the function.
+    output_type_fn: Option<PyObject>,
     is_elementwise: bool,
     returns_scalar: bool,
 }
@@ -117,19 +123,35 @@ impl PythonUdfExpression {
         Self {
             python_function: lambda,
             output_type,
+            output_type_fn: None,
             is_elementwise,
             returns_scalar,
         }
     }
 
+    /// Set a function that computes the output type from the input type. It takes
+    /// precedence over the `output_type`.
+    pub fn with_output_type_fn(mut self, output_type_fn: Option<PyObject>) -> Self {
+        self.output_type_fn = output_type_fn;
+        self
+    }
+
+    fn resolve_output_type(&self, input_type: &DataType) -> PolarsResult<Option<DataType>> {
+        resolve_output_type(&self.output_type, &self.output_type_fn, input_type)
+    }
+
     #[cfg(feature = "serde")]
     pub(crate) fn try_deserialize(buf: &[u8]) -> PolarsResult<Arc<dyn SeriesUdf>> {
         debug_assert!(buf.starts_with(MAGIC_BYTE_MARK));
         // skip header
         let buf = &buf[MAGIC_BYTE_MARK.len()..];
         let mut reader = Cursor::new(buf);
-        let (output_type, is_elementwise, returns_scalar): (Option<DataType>, bool, bool) =
-            ciborium::de::from_reader(&mut reader).map_err(map_err)?;
+        let (output_type, is_elementwise, returns_scalar, has_output_type_fn): (
+            Option<DataType>,
+            bool,
+            bool,
+            bool,
+        ) = ciborium::de::from_reader(&mut reader).map_err(map_err)?;
 
         let remainder = &buf[reader.position() as usize..];
 
@@ -140,13 +162,25 @@ impl PythonUdfExpression {
                 .getattr("loads")
                 .unwrap();
             let arg = (PyBytes::new_bound(py, remainder),);
-            let python_function = pickle.call1(arg).map_err(from_pyerr)?;
-            Ok(Arc::new(PythonUdfExpression::new(
-                python_function.into(),
-                output_type,
-                is_elementwise,
-                returns_scalar,
-            )) as Arc<dyn SeriesUdf>)
+            let pickled = pickle.call1(arg).map_err(from_pyerr)?;
+            // The output type function is pickled along with the function.
+            let (python_function, output_type_fn) = if has_output_type_fn {
+                let (python_function, output_type_fn) = pickled
+                    .extract::<(PyObject, PyObject)>()
+                    .map_err(from_pyerr)?;
+                (python_function, Some(output_type_fn))
+            } else {
+                (pickled.into(), None)
+            };
+            Ok(Arc::new(
+                PythonUdfExpression::new(
+                    python_function,
+                    output_type,
+                    is_elementwise,
+                    returns_scalar,
+                )
+                .with_output_type_fn(output_type_fn),
+            ) as Arc<dyn SeriesUdf>)
         })
     }
 }
@@ -155,6 +189,37 @@ fn from_pyerr(e: PyErr) -> PolarsError {
     PolarsError::ComputeError(format!("error raised in python: {e}").into())
 }
 
+fn resolve_output_type(
+    output_type: &Option<DataType>,
+    output_type_fn: &Option<PyObject>,
+    input_type: &DataType,
+) -> PolarsResult<Option<DataType>> {
+    match output_type_fn {
+        Some(output_type_fn) => {
+            let func = unsafe { CALL_DTYPE_UDF_PYTHON.unwrap() };
+            func(input_type, output_type_fn).map(Some)
+        },
+        None => Ok(output_type.clone()),
+    }
+}
+
+fn output_field(
+    output_type: &Option<DataType>,
+    output_type_fn: &Option<PyObject>,
+    fld: &Field,
+) -> PolarsResult<Field> {
+    Ok(
+        match resolve_output_type(output_type, output_type_fn, fld.data_type())? {
+            Some(dt) => Field::new(fld.name(), dt),
+            None => {
+                let mut fld = fld.clone();
+                fld.coerce(DataType::Unknown(Default::default()));
+                fld
+            },
+        },
+    )
+}
+
 impl DataFrameUdf for PythonFunction {
     fn call_udf(&self, df: DataFrame) -> PolarsResult<DataFrame> {
         let func = unsafe { CALL_DF_UDF_PYTHON.unwrap() };
@@ -167,8 +232,7 @@ impl SeriesUdf for PythonUdfExpression {
         let func = unsafe { CALL_SERIES_UDF_PYTHON.unwrap() };
 
         let output_type = self
-            .output_type
-            .clone()
+            .resolve_output_type(s[0].dtype())?
             .unwrap_or_else(|| DataType::Unknown(Default::default()));
         let mut out = func(s[0].clone(), &self.python_function)?;
         if !matches!(output_type, DataType::Unknown(_)) {
@@ -194,6 +258,7 @@ impl SeriesUdf for PythonUdfExpression {
                 self.output_type.clone(),
                 self.is_elementwise,
                 self.returns_scalar,
+                self.output_type_fn.is_some(),
             ),
             &mut *buf,
         )
@@ -205,9 +270,13 @@ impl SeriesUdf for PythonUdfExpression {
                 .expect("Unable to import 'pickle'")
                 .getattr("dumps")
                 .unwrap();
-            let dumped = pickle
-                .call1((self.python_function.clone(),))
-                .map_err(from_pyerr)?;
+            let dumped = match &self.output_type_fn {
+                Some(output_type_fn) => {
+                    pickle.call1(((self.python_function.clone(), output_type_fn.clone()),))
+                },
+                None => pickle.call1((self.python_function.clone(),)),
+            }
+            .map_err(from_pyerr)?;
             let dumped = dumped.extract::<PyBackedBytes>().unwrap();
             buf.extend_from_slice(&dumped);
             Ok(())
@@ -216,15 +285,9 @@ impl SeriesUdf for PythonUdfExpression {
 
     fn get_output(&self) -> Option<GetOutput> {
         let output_type = self.output_type.clone();
+        let output_type_fn = self.output_type_fn.clone();
         Some(GetOutput::map_field(move |fld| {
-            Ok(match output_type {
-                Some(ref dt) => Field::new(fld.name(), dt.clone()),
-                None => {
-                    let mut fld = fld.clone();
-                    fld.coerce(DataType::Unknown(Default::default()));
-                    fld
-                },
-            })
+            output_field(&output_type, &output_type_fn, fld)
         }))
     }
 }
@@ -241,16 +304,9 @@ impl Expr {
 
         let returns_scalar = func.returns_scalar;
         let return_dtype = func.output_type.clone();
-        let output_type = GetOutput::map_field(move |fld| {
-            Ok(match return_dtype {
-                Some(ref dt) => Field::new(fld.name(), dt.clone()),
-                None => {
-                    let mut fld = fld.clone();
-                    fld.coerce(DataType::Unknown(Default::default()));
-                    fld
-                },
-            })
-        });
+        let return_dtype_fn = func.output_type_fn.clone();
+        let output_type =
+            GetOutput::map_field(move |fld| output_field(&return_dtype, &return_dtype_fn, fld));
 
         Expr::AnonymousFunction {
             input: vec![self],
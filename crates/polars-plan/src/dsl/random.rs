@@ -51,4 +51,52 @@ impl Expr {
             false,
         )
     }
+
+    /// Sample `n` values, every value with a probability proportional to its weight in
+    /// `weights`.
+    pub fn sample_n_weighted(
+        self,
+        n: Expr,
+        weights: Expr,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> Self {
+        self.apply_many_private(
+            FunctionExpr::Random {
+                method: RandomMethod::Sample {
+                    is_fraction: false,
+                    with_replacement,
+                    shuffle: false,
+                },
+                seed,
+            },
+            &[n, weights],
+            false,
+            false,
+        )
+    }
+
+    /// Sample a fraction of the values, every value with a probability proportional to its
+    /// weight in `weights`.
+    pub fn sample_frac_weighted(
+        self,
+        frac: Expr,
+        weights: Expr,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> Self {
+        self.apply_many_private(
+            FunctionExpr::Random {
+                method: RandomMethod::Sample {
+                    is_fraction: true,
+                    with_replacement,
+                    shuffle: false,
+                },
+                seed,
+            },
+            &[frac, weights],
+            false,
+            false,
+        )
+    }
 }
@@ -62,7 +62,8 @@ impl Expr {
         AggExpr::Sum(Arc::new(self)).into()
     }
 
-    /// Compute the histogram of a dataset.
+    /// Compute the histogram of a dataset. If `normalize` is set, the counts are replaced by
+    /// the proportion of the non-null values that falls into each bin.
     #[cfg(feature = "hist")]
     pub fn hist(
         self,
@@ -70,6 +71,7 @@ impl Expr {
         bin_count: Option<usize>,
         include_category: bool,
         include_breakpoint: bool,
+        normalize: bool,
     ) -> Self {
         let mut input = vec![self];
         if let Some(bins) = bins {
@@ -82,6 +84,7 @@ impl Expr {
                 bin_count,
                 include_category,
                 include_breakpoint,
+                normalize,
             },
             options: FunctionOptions {
                 collect_groups: ApplyOptions::GroupWise,
@@ -1,3 +1,5 @@
+#[cfg(any(feature = "string_encoding", feature = "binary_encoding"))]
+use super::binary::BinaryEncoding;
 use super::*;
 /// Specialized expressions for [`Series`] of [`DataType::String`].
 pub struct StringNameSpace(pub(crate) Expr);
@@ -121,6 +123,25 @@ impl StringNameSpace {
             )))
     }
 
+    /// Encode the UTF-8 bytes of the strings in the given `encoding`.
+    #[cfg(feature = "string_encoding")]
+    pub fn encode(self, encoding: BinaryEncoding) -> Expr {
+        match encoding {
+            BinaryEncoding::Hex => self.hex_encode(),
+            BinaryEncoding::Base64 => self.base64_encode(),
+        }
+    }
+
+    /// Decode strings in the given `encoding` to binary values. If `strict`, invalid
+    /// values raise an error, otherwise they become null.
+    #[cfg(feature = "binary_encoding")]
+    pub fn decode(self, encoding: BinaryEncoding, strict: bool) -> Expr {
+        match encoding {
+            BinaryEncoding::Hex => self.hex_decode(strict),
+            BinaryEncoding::Base64 => self.base64_decode(strict),
+        }
+    }
+
     /// Extract a regex pattern from the a string value. If `group_index` is out of bounds, null is returned.
     pub fn extract(self, pat: Expr, group_index: usize) -> Expr {
         self.0.map_many_private(
@@ -131,32 +152,46 @@ impl StringNameSpace {
         )
     }
 
+    /// Extract the capture group named `group_name` of a regex pattern from the string value.
+    /// If the pattern has no such group, an error is returned.
+    pub fn extract_named(self, pat: Expr, group_name: &str) -> Expr {
+        self.0.map_many_private(
+            StringFunction::ExtractNamed(group_name.to_string()).into(),
+            &[pat],
+            false,
+            true,
+        )
+    }
+
     #[cfg(feature = "extract_groups")]
     // Extract all captures groups from a regex pattern as a struct
     pub fn extract_groups(self, pat: &str) -> PolarsResult<Expr> {
         // regex will be compiled twice, because it doesn't support serde
         // and we need to compile it here to determine the output datatype
-        let reg = regex::Regex::new(pat)?;
-        let names = reg
-            .capture_names()
-            .enumerate()
-            .skip(1)
-            .map(|(idx, opt_name)| {
-                opt_name
-                    .map(|name| name.to_string())
-                    .unwrap_or_else(|| format!("{idx}"))
-            })
-            .collect::<Vec<_>>();
+        let dtype = capture_groups_dtype(pat)?;
 
-        let dtype = DataType::Struct(
-            names
-                .iter()
-                .map(|name| Field::new(name.as_str(), DataType::String))
-                .collect(),
+        Ok(self.0.map_private(
+            StringFunction::ExtractGroups {
+                dtype,
+                pat: pat.to_string(),
+            }
+            .into(),
+        ))
+    }
+
+    #[cfg(feature = "extract_groups")]
+    /// Extract the capture groups of every successive non-overlapping regex match as a list of
+    /// structs. Named groups become field names, unnamed groups are named by their index.
+    pub fn extract_all_groups(self, pat: &str) -> PolarsResult<Expr> {
+        let dtype = capture_groups_dtype(pat)?;
+        polars_ensure!(
+            !matches!(&dtype, DataType::Struct(fields) if fields.is_empty()),
+            ComputeError: "regex pattern '{}' has no capture groups", pat
         );
+        let dtype = DataType::List(Box::new(dtype));
 
         Ok(self.0.map_private(
-            StringFunction::ExtractGroups {
+            StringFunction::ExtractAllGroups {
                 dtype,
                 pat: pat.to_string(),
             }
@@ -400,6 +435,51 @@ impl StringNameSpace {
         )
     }
 
+    #[cfg(feature = "string_normalize")]
+    /// Bring each string into the Unicode normalization `form`. With `case_fold`, strings are
+    /// lowercased first, which makes them suitable for caseless matching, joins and group-bys.
+    pub fn normalize(self, form: UnicodeForm, case_fold: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Normalize {
+                form,
+                case_fold,
+            }))
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Levenshtein (edit) distance, in characters, to the strings in `other`.
+    pub fn levenshtein_distance(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::LevenshteinDistance),
+            &[other],
+            false,
+            true,
+        )
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Hamming distance, in characters, to the strings in `other`.
+    /// Strings with a different number of characters have a null distance.
+    pub fn hamming_distance(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::HammingDistance),
+            &[other],
+            false,
+            true,
+        )
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Jaro-Winkler similarity to the strings in `other`, from 0 (no similarity) to 1 (equal).
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::JaroWinkler),
+            &[other],
+            false,
+            true,
+        )
+    }
+
     #[cfg(feature = "string_reverse")]
     /// Reverse each string
     pub fn reverse(self) -> Expr {
@@ -547,6 +627,11 @@ impl StringNameSpace {
     }
 
     #[cfg(feature = "extract_jsonpath")]
+    /// Parse the JSON documents of a string column into values of `dtype`, like structs and
+    /// lists. Nulls and documents that are JSON `null` become nulls.
+    ///
+    /// If `dtype` is `None`, it is inferred from the first `infer_schema_len` rows, or all rows
+    /// if that is `None` too; the output type is then only known after the query runs.
     pub fn json_decode(self, dtype: Option<DataType>, infer_schema_len: Option<usize>) -> Expr {
         self.0
             .map_private(FunctionExpr::StringExpr(StringFunction::JsonDecode {
@@ -556,6 +641,12 @@ impl StringNameSpace {
     }
 
     #[cfg(feature = "extract_jsonpath")]
+    /// Extract the first match of the [JSONPath](https://goessner.net/articles/JsonPath/)
+    /// `pat`, like `$.a.b[0]`, from the JSON documents of a string column. Strings are
+    /// returned as is, other matches as JSON; documents without a match give null.
+    ///
+    /// `pat` can be a column with a path per row. Unlike [`StringNameSpace::json_decode`],
+    /// this doesn't build typed columns, so it is cheaper when a single field is needed.
     pub fn json_path_match(self, pat: Expr) -> Expr {
         self.0.map_many_private(
             FunctionExpr::StringExpr(StringFunction::JsonPathMatch),
@@ -565,3 +656,21 @@ impl StringNameSpace {
         )
     }
 }
+
+/// The struct type with a `String` field per capture group of `pat`.
+#[cfg(feature = "extract_groups")]
+fn capture_groups_dtype(pat: &str) -> PolarsResult<DataType> {
+    let reg = regex::Regex::new(pat)?;
+    let fields = reg
+        .capture_names()
+        .enumerate()
+        .skip(1)
+        .map(|(idx, opt_name)| {
+            let name = opt_name
+                .map(|name| name.to_string())
+                .unwrap_or_else(|| format!("{idx}"));
+            Field::new(name.as_str(), DataType::String)
+        })
+        .collect();
+    Ok(DataType::Struct(fields))
+}
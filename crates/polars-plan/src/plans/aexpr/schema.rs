@@ -250,6 +250,10 @@ impl AExpr {
                 let output_type = tmp.as_ref().unwrap_or(output_type);
                 let fields = func_args_to_fields(input, schema, arena, nested)?;
                 polars_ensure!(!fields.is_empty(), ComputeError: "expression: '{}' didn't get any inputs", options.fmt_str);
+                // A group-wise function that returns a scalar aggregates its groups.
+                if options.returns_scalar && options.is_groups_sensitive() {
+                    *nested = nested.saturating_sub(1);
+                }
                 output_type.get_field(schema, Context::Default, &fields)
             },
             Function {
@@ -400,6 +404,13 @@ fn get_arithmetic_field(
                         polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
                     },
                 },
+                // Scaling a duration keeps its time unit.
+                (Duration(_), r) if r.is_numeric() => match op {
+                    Operator::Multiply | Operator::Divide => return Ok(left_field),
+                    _ => {
+                        polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
+                    },
+                },
                 _ => {
                     // Avoid needlessly type casting numeric columns during arithmetic
                     // with literals.
@@ -51,6 +51,7 @@ impl DslBuilder {
                 enabled: Some(false),
                 ..Default::default()
             },
+            include_file_paths: None,
         };
 
         Ok(DslPlan::Scan {
@@ -83,6 +84,7 @@ impl DslBuilder {
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
         hive_options: HiveOptions,
+        include_file_paths: Option<Arc<str>>,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
 
@@ -94,6 +96,7 @@ impl DslBuilder {
             row_index,
             file_counter: Default::default(),
             hive_options,
+            include_file_paths,
         };
         Ok(DslPlan::Scan {
             paths,
@@ -115,6 +118,7 @@ impl DslBuilder {
     }
 
     #[cfg(feature = "ipc")]
+    #[allow(clippy::too_many_arguments)]
     pub fn scan_ipc<P: Into<Arc<[std::path::PathBuf]>>>(
         paths: P,
         options: IpcScanOptions,
@@ -123,6 +127,7 @@ impl DslBuilder {
         row_index: Option<RowIndex>,
         rechunk: bool,
         cloud_options: Option<CloudOptions>,
+        include_file_paths: Option<Arc<str>>,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
 
@@ -142,6 +147,7 @@ impl DslBuilder {
                     enabled: Some(false),
                     ..Default::default()
                 },
+                include_file_paths,
             },
             predicate: None,
             scan_type: FileScan::Ipc {
@@ -160,6 +166,7 @@ impl DslBuilder {
         read_options: CsvReadOptions,
         cache: bool,
         cloud_options: Option<CloudOptions>,
+        include_file_paths: Option<Arc<str>>,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
 
@@ -178,6 +185,7 @@ impl DslBuilder {
                 enabled: Some(false),
                 ..Default::default()
             },
+            include_file_paths,
         };
         Ok(DslPlan::Scan {
             paths,
@@ -235,11 +243,12 @@ impl DslBuilder {
                         .into_iter()
                         .map(|v| v.is_not_null())
                         .collect::<Vec<_>>(),
+                    false,
                 )
                 .unwrap(),
             )
         } else {
-            self.filter(all_horizontal([all().is_not_null()]).unwrap())
+            self.filter(all_horizontal([all().is_not_null()], false).unwrap())
         }
     }
 
@@ -125,10 +125,16 @@ pub fn to_alp_impl(
                             .map_err(|e| e.context(failed_here!(csv scan)))?
                     },
                     #[cfg(feature = "json")]
-                    FileScan::NDJson { options } => {
-                        scans::ndjson_file_info(&paths, &file_options, options)
-                            .map_err(|e| e.context(failed_here!(ndjson scan)))?
-                    },
+                    FileScan::NDJson {
+                        options,
+                        cloud_options,
+                    } => scans::ndjson_file_info(
+                        &paths,
+                        &file_options,
+                        options,
+                        cloud_options.as_ref(),
+                    )
+                    .map_err(|e| e.context(failed_here!(ndjson scan)))?,
                     // FileInfo should be set.
                     FileScan::Anonymous { .. } => unreachable!(),
                 }
@@ -150,6 +156,16 @@ pub fn to_alp_impl(
                 file_info.update_schema_with_hive_schema(hive_parts[0].schema().clone())?;
             }
 
+            if let Some(file_path_col) = &file_options.include_file_paths {
+                polars_ensure!(
+                    !file_info.schema.contains(file_path_col),
+                    Duplicate: "column name for file paths '{}' conflicts with a column in the file",
+                    file_path_col
+                );
+                let schema = Arc::make_mut(&mut file_info.schema);
+                schema.with_column(file_path_col.as_ref().into(), DataType::String);
+            }
+
             if let Some(row_index) = &file_options.row_index {
                 let schema = Arc::make_mut(&mut file_info.schema);
                 *schema = schema
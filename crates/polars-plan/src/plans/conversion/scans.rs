@@ -136,7 +136,6 @@ pub(super) fn csv_file_info(
     use std::io::{Read, Seek};
 
     use polars_core::{config, POOL};
-    use polars_io::csv::read::is_compressed;
     use polars_io::csv::read::schema_inference::SchemaInferenceResult;
     use polars_io::utils::get_reader_bytes;
     use rayon::iter::{IntoParallelIterator, ParallelIterator};
@@ -190,15 +189,8 @@ pub(super) fn csv_file_info(
 
         let mut magic_nr = [0u8; 4];
         let res_len = file.read(&mut magic_nr)?;
-        if res_len < 2 {
-            if csv_options.raise_if_empty {
-                polars_bail!(NoData: "empty CSV")
-            }
-        } else {
-            polars_ensure!(
-            !is_compressed(&magic_nr),
-            ComputeError: "cannot scan compressed csv; use `read_csv` for compressed data",
-            );
+        if res_len < 2 && csv_options.raise_if_empty {
+            polars_bail!(NoData: "empty CSV")
         }
 
         file.rewind()?;
@@ -279,10 +271,32 @@ pub(super) fn ndjson_file_info(
     paths: &[PathBuf],
     file_options: &FileScanOptions,
     ndjson_options: &mut NDJsonReadOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
 ) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+
     let path = get_path(paths)?;
+    let run_async = is_cloud_url(path) || config::force_async();
 
-    let f = polars_utils::open_file(path)?;
+    let f = if run_async {
+        #[cfg(feature = "cloud")]
+        {
+            let entries = polars_io::file_cache::init_entries_from_uri_list(
+                paths
+                    .iter()
+                    .map(|path| Arc::from(path.to_str().unwrap()))
+                    .collect::<Box<[_]>>(),
+                cloud_options,
+            )?;
+            entries.first().unwrap().try_open_check_latest()?
+        }
+        #[cfg(not(feature = "cloud"))]
+        {
+            panic!("required feature `cloud` is not enabled")
+        }
+    } else {
+        polars_utils::open_file(path)?
+    };
     let mut reader = std::io::BufReader::new(f);
 
     let (reader_schema, schema) = if let Some(schema) = ndjson_options.schema.take() {
@@ -141,7 +141,6 @@ impl ExprIR {
         self.node = node;
     }
 
-    #[cfg(feature = "cse")]
     pub(crate) fn set_alias(&mut self, name: ColumnName) {
         self.output_name = OutputName::Alias(name)
     }
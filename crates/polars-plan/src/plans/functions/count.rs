@@ -10,7 +10,7 @@ use polars_io::parquet::read::ParquetAsyncReader;
 use polars_io::parquet::read::ParquetReader;
 #[cfg(all(feature = "parquet", feature = "async"))]
 use polars_io::pl_async::{get_runtime, with_concurrency_budget};
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "json"))]
 use polars_io::{utils::is_cloud_url, SerReader};
 
 use super::*;
@@ -72,7 +72,7 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
                 metadata.as_ref(),
             ),
             #[cfg(feature = "json")]
-            FileScan::NDJson { options } => count_rows_ndjson(paths),
+            FileScan::NDJson { .. } => count_rows_ndjson(paths),
             FileScan::Anonymous { .. } => {
                 unreachable!()
             },
@@ -156,6 +156,11 @@ pub(super) fn count_rows_ipc(
             .iter()
             .map(|path| {
                 let mut reader = polars_utils::open_file(path)?;
+                // Files written with statistics store their record batch lengths in the footer.
+                let file_metadata = arrow::io::ipc::read::read_file_metadata(&mut reader)?;
+                if let Some(lengths) = polars_io::ipc::read_record_batch_lengths(&file_metadata) {
+                    return Ok(lengths.iter().sum());
+                }
                 count_rows_ipc_sync(&mut reader).map(|v| v as usize)
             })
             .sum()
@@ -186,8 +191,23 @@ pub(super) fn count_rows_ndjson(paths: &Arc<[PathBuf]>) -> PolarsResult<usize> {
     paths
         .iter()
         .map(|path| {
-            let reader = polars_io::ndjson::core::JsonLineReader::from_path(path)?;
-            reader.count()
+            let file = if is_cloud_url(path) || polars_core::config::force_async() {
+                #[cfg(feature = "cloud")]
+                {
+                    polars_io::file_cache::FILE_CACHE
+                        .get_entry(path.to_str().unwrap())
+                        // Safety: This was initialized by schema inference.
+                        .unwrap()
+                        .try_open_assume_latest()?
+                }
+                #[cfg(not(feature = "cloud"))]
+                {
+                    panic!("required feature `cloud` is not enabled")
+                }
+            } else {
+                polars_utils::open_file(path)?
+            };
+            polars_io::ndjson::core::JsonLineReader::new(file).count()
         })
         .sum()
 }
@@ -17,6 +17,18 @@ pub struct HivePartitions {
 }
 
 impl HivePartitions {
+    /// Create the partitions of a single file from its partition values, given as single
+    /// value Series in the order of `schema`.
+    pub fn from_partition_values(schema: SchemaRef, values: Vec<Series>) -> Self {
+        let column_stats = values
+            .into_iter()
+            .map(ColumnStats::from_column_literal)
+            .collect();
+        Self {
+            stats: BatchStats::new(schema, column_stats, None),
+        }
+    }
+
     pub fn get_statistics(&self) -> &BatchStats {
         &self.stats
     }
@@ -1,4 +1,5 @@
 use hashbrown::hash_map::RawEntryMut;
+use polars_core::polars_event;
 use polars_utils::vec::CapacityByFactor;
 
 use super::*;
@@ -695,9 +696,10 @@ impl CommonSubExprOptimizer {
             };
 
             if !valid {
-                if verbose() {
-                    eprintln!("materialized names collided in common subexpression elimination.\n backtrace and run without CSE")
-                }
+                polars_event!(
+                    target: "polars::optimizer::cse",
+                    "materialized names collided in common subexpression elimination.\n backtrace and run without CSE"
+                );
                 return Ok(None);
             }
         }
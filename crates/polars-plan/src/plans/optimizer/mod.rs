@@ -22,10 +22,12 @@ mod simplify_functions;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
 mod stack_opt;
+mod streaming_window;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
 use delay_rechunk::DelayRechunk;
 use polars_core::config::verbose;
+use polars_core::polars_span;
 use polars_io::predicates::PhysicalIoExpr;
 pub use predicate_pushdown::PredicatePushDown;
 pub use projection_pushdown::ProjectionPushDown;
@@ -65,6 +67,7 @@ pub fn optimize(
     scratch: &mut Vec<Node>,
     hive_partition_eval: HiveEval<'_>,
 ) -> PolarsResult<Node> {
+    let _span = polars_span!(target: "polars::optimizer", "optimize");
     #[allow(dead_code)]
     let verbose = verbose();
     // get toggle values
@@ -122,9 +125,7 @@ pub fn optimize(
         && members.has_duplicate_scans()
         && !members.has_cache
     {
-        if verbose {
-            eprintln!("found multiple sources; run comm_subplan_elim")
-        }
+        polars_core::polars_event!(target: "polars::optimizer", "found multiple sources; run comm_subplan_elim");
         let (lp, changed, cid2c) = cse::elim_cmn_subplans(lp_top, lp_arena, expr_arena);
 
         prune_unused_caches(lp_arena, cid2c);
@@ -193,6 +194,12 @@ pub fn optimize(
 
     lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?;
 
+    // The streaming engine cannot evaluate window expressions, so rewrite the ones it can
+    // stream as a group by and a join.
+    if streaming && !eager {
+        streaming_window::optimize(lp_top, lp_arena, expr_arena)?;
+    }
+
     if members.has_joins_or_unions && members.has_cache && _cse_plan_changed {
         // We only want to run this on cse inserted caches
         cache_states::set_cache_states(
@@ -233,3 +240,30 @@ pub fn optimize(
 
     Ok(lp_top)
 }
+
+/// Insert caches on the subplans that the optimized plans of `roots` have in common, e.g. the
+/// same scan, and update `roots` to the rewritten plans.
+///
+/// If the plans are executed with states split off the same `ExecutionState`, the shared
+/// subplans are only computed once. The plans must not contain caches of their own common
+/// subplan elimination, as the cache ids of separately optimized plans overlap.
+#[cfg(feature = "cse")]
+pub fn share_common_subplans(
+    roots: &mut [Node],
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> bool {
+    // A union of the plans serves as a common root; it is discarded afterwards.
+    let root = lp_arena.add(IR::Union {
+        inputs: roots.to_vec(),
+        options: Default::default(),
+    });
+    let (root, changed, cid2c) = cse::elim_cmn_subplans(root, lp_arena, expr_arena);
+    prune_unused_caches(lp_arena, cid2c);
+
+    let IR::Union { inputs, .. } = lp_arena.get(root) else {
+        unreachable!()
+    };
+    roots.copy_from_slice(inputs);
+    changed
+}
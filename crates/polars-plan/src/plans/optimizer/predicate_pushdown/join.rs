@@ -119,6 +119,139 @@ fn predicate_applies_to_both_tables(
     left_used && right_used
 }
 
+#[cfg(feature = "iejoin")]
+type Inequality = (ExprIR, InequalityOperator, ExprIR);
+
+/// Try to read a predicate as `left_column <op> right_column`, where the columns refer
+/// to the left and right table of a cross join.
+#[cfg(feature = "iejoin")]
+fn as_inequality(
+    node: Node,
+    expr_arena: &mut Arena<AExpr>,
+    schema_left: &Schema,
+    schema_right: &Schema,
+    suffix: &str,
+) -> Option<Inequality> {
+    let AExpr::BinaryExpr { left, op, right } = expr_arena.get(node) else {
+        return None;
+    };
+    let op = match op {
+        Operator::Lt => InequalityOperator::Lt,
+        Operator::LtEq => InequalityOperator::LtEq,
+        Operator::Gt => InequalityOperator::Gt,
+        Operator::GtEq => InequalityOperator::GtEq,
+        _ => return None,
+    };
+    let (AExpr::Column(a), AExpr::Column(b)) = (expr_arena.get(*left), expr_arena.get(*right))
+    else {
+        return None;
+    };
+
+    // Names of the right table that also occur in the left table got a suffix.
+    let right_name = |name: &Arc<str>| -> Option<Arc<str>> {
+        if schema_left.contains(name) {
+            None
+        } else if schema_right.contains(name) {
+            Some(name.clone())
+        } else if name.ends_with(suffix) {
+            let name = split_suffix(name, suffix);
+            (schema_right.contains(name) && schema_left.contains(name)).then(|| Arc::from(name))
+        } else {
+            None
+        }
+    };
+    let (left_name, op, right_name) = if schema_left.contains(a) {
+        (a.clone(), op, right_name(b)?)
+    } else if schema_left.contains(b) {
+        (b.clone(), op.flip(), right_name(a)?)
+    } else {
+        return None;
+    };
+
+    // The inequality join supports numeric keys of any kind and temporal keys of the
+    // same type.
+    let dtype_left = schema_left.get(&left_name)?;
+    let dtype_right = schema_right.get(&right_name)?;
+    let is_numeric = |dt: &DataType| dt.is_numeric() && !matches!(dt, DataType::Int128);
+    if !(is_numeric(dtype_left) && is_numeric(dtype_right)
+        || dtype_left.is_temporal() && dtype_left == dtype_right)
+    {
+        return None;
+    }
+
+    let left_on = ExprIR::from_node(expr_arena.add(AExpr::Column(left_name)), expr_arena);
+    let right_on = ExprIR::from_node(expr_arena.add(AExpr::Column(right_name)), expr_arena);
+    Some((left_on, op, right_on))
+}
+
+/// Move at most two inequalities of a conjunctive predicate on a cross join into
+/// `inequalities`, the other terms are added to `local_predicates`.
+#[cfg(feature = "iejoin")]
+#[allow(clippy::too_many_arguments)]
+fn extract_inequalities(
+    node: Node,
+    expr_arena: &mut Arena<AExpr>,
+    schema_left: &Schema,
+    schema_right: &Schema,
+    suffix: &str,
+    inequalities: &mut Vec<Inequality>,
+    local_predicates: &mut Vec<ExprIR>,
+) {
+    if let AExpr::BinaryExpr {
+        left,
+        op: Operator::And | Operator::LogicalAnd,
+        right,
+    } = expr_arena.get(node)
+    {
+        let (left, right) = (*left, *right);
+        for node in [left, right] {
+            extract_inequalities(
+                node,
+                expr_arena,
+                schema_left,
+                schema_right,
+                suffix,
+                inequalities,
+                local_predicates,
+            );
+        }
+        return;
+    }
+
+    if inequalities.len() < 2 {
+        if let Some(inequality) = as_inequality(node, expr_arena, schema_left, schema_right, suffix)
+        {
+            inequalities.push(inequality);
+            return;
+        }
+    }
+    local_predicates.push(ExprIR::from_node(node, expr_arena));
+}
+
+/// Turn a cross join into an inequality join on the extracted `inequalities`.
+#[cfg(feature = "iejoin")]
+fn to_inequality_join(
+    mut left_on: Vec<ExprIR>,
+    mut right_on: Vec<ExprIR>,
+    mut options: Arc<JoinOptions>,
+    inequalities: Vec<Inequality>,
+) -> (Vec<ExprIR>, Vec<ExprIR>, Arc<JoinOptions>) {
+    if inequalities.is_empty() {
+        return (left_on, right_on, options);
+    }
+    let mut operators = Vec::with_capacity(inequalities.len());
+    for (left, op, right) in inequalities {
+        left_on.push(left);
+        right_on.push(right);
+        operators.push(op);
+    }
+    Arc::make_mut(&mut options).args.how = JoinType::IEJoin(IEJoinOptions {
+        operator1: operators[0],
+        operator2: operators.get(1).copied(),
+    });
+    (left_on, right_on, options)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(super) fn process_join(
     opt: &PredicatePushDown,
@@ -149,6 +282,8 @@ pub(super) fn process_join(
     let mut pushdown_left = init_hashmap(Some(acc_predicates.len()));
     let mut pushdown_right = init_hashmap(Some(acc_predicates.len()));
     let mut local_predicates = Vec::with_capacity(acc_predicates.len());
+    #[cfg(feature = "iejoin")]
+    let mut inequalities = vec![];
 
     for (_, predicate) in acc_predicates {
         // Cross joins produce a cartesian product, so if a predicate combines columns from both tables, we should not push down.
@@ -161,6 +296,22 @@ pub(super) fn process_join(
                 options.args.suffix(),
             )
         {
+            // Inequalities between the tables can be evaluated by an inequality join
+            // instead of filtering the cartesian product. This is not possible if the
+            // cross join is sliced.
+            #[cfg(feature = "iejoin")]
+            if options.args.slice.is_none() {
+                extract_inequalities(
+                    predicate.node(),
+                    expr_arena,
+                    &schema_left,
+                    &schema_right,
+                    options.args.suffix(),
+                    &mut inequalities,
+                    &mut local_predicates,
+                );
+                continue;
+            }
             local_predicates.push(predicate);
             continue;
         }
@@ -245,6 +396,9 @@ pub(super) fn process_join(
     opt.pushdown_and_assign(input_left, pushdown_left, lp_arena, expr_arena)?;
     opt.pushdown_and_assign(input_right, pushdown_right, lp_arena, expr_arena)?;
 
+    #[cfg(feature = "iejoin")]
+    let (left_on, right_on, options) = to_inequality_join(left_on, right_on, options, inequalities);
+
     let lp = Join {
         input_left,
         input_right,
@@ -6,6 +6,7 @@ mod utils;
 
 use polars_core::datatypes::PlHashMap;
 use polars_core::prelude::*;
+use polars_core::{polars_event, polars_trace};
 use recursive::recursive;
 use utils::*;
 
@@ -21,7 +22,6 @@ pub type HiveEval<'a> =
 
 pub struct PredicatePushDown<'a> {
     hive_partition_eval: HiveEval<'a>,
-    verbose: bool,
     block_at_cache: bool,
 }
 
@@ -29,7 +29,6 @@ impl<'a> PredicatePushDown<'a> {
     pub fn new(hive_partition_eval: HiveEval<'a>) -> Self {
         Self {
             hive_partition_eval,
-            verbose: verbose(),
             block_at_cache: true,
         }
     }
@@ -310,6 +309,14 @@ impl<'a> PredicatePushDown<'a> {
                 filter: selection,
             } => {
                 let selection = predicate_at_scan(acc_predicates, selection, expr_arena);
+                if let Some(selection) = &selection {
+                    polars_trace!(
+                        target: "polars::optimizer::predicate_pushdown",
+                        predicate = selection.display(expr_arena),
+                        scan = "df";
+                        "pushed predicate into scan"
+                    );
+                }
                 let lp = DataFrameScan {
                     df,
                     schema,
@@ -331,7 +338,7 @@ impl<'a> PredicatePushDown<'a> {
                     debug_assert_aexpr_allows_predicate_pushdown(e.node(), expr_arena);
                 }
 
-                let local_predicates = match &scan_type {
+                let mut local_predicates = match &scan_type {
                     #[cfg(feature = "parquet")]
                     FileScan::Parquet { .. } => vec![],
                     #[cfg(feature = "ipc")]
@@ -349,6 +356,14 @@ impl<'a> PredicatePushDown<'a> {
                         }
                     },
                 };
+                // The file path column is added after the readers applied their predicates.
+                if let Some(ref file_path_col) = options.include_file_paths {
+                    local_predicates.extend(transfer_to_local_by_name(
+                        expr_arena,
+                        &mut acc_predicates,
+                        |name| name == *file_path_col,
+                    ));
+                }
                 let predicate = predicate_at_scan(acc_predicates, predicate.clone(), expr_arena);
 
                 if let (Some(hive_parts), Some(predicate)) = (&scan_hive_parts, &predicate) {
@@ -369,13 +384,14 @@ impl<'a> PredicatePushDown<'a> {
                             }
 
                             if paths.len() != new_paths.len() {
-                                if self.verbose {
-                                    eprintln!(
-                                        "hive partitioning: skipped {} files, first file : {}",
-                                        paths.len() - new_paths.len(),
-                                        paths[0].display()
-                                    )
-                                }
+                                polars_event!(
+                                    target: "polars::optimizer::predicate_pushdown",
+                                    skipped = paths.len() - new_paths.len(),
+                                    predicate = predicate.display(expr_arena);
+                                    "hive partitioning: skipped {} files, first file : {}",
+                                    paths.len() - new_paths.len(),
+                                    paths[0].display()
+                                );
                                 scan_type.remove_metadata();
                             }
                             if paths.is_empty() {
@@ -406,6 +422,14 @@ impl<'a> PredicatePushDown<'a> {
                     _ => true,
                 };
                 do_optimization &= predicate.is_some();
+                if do_optimization {
+                    polars_trace!(
+                        target: "polars::optimizer::predicate_pushdown",
+                        predicate = predicate.as_ref().unwrap().display(expr_arena),
+                        scan = <&str>::from(&scan_type);
+                        "pushed predicate into scan"
+                    );
+                }
 
                 let hive_parts = scan_hive_parts;
 
@@ -1,5 +1,9 @@
+#[cfg(feature = "dtype-struct")]
+mod unnest;
 mod unpivot;
 
+#[cfg(feature = "dtype-struct")]
+use unnest::process_unnest;
 use unpivot::process_unpivot;
 
 use super::*;
@@ -81,6 +85,16 @@ pub(super) fn process_functions(
                 expr_arena,
             )
         },
+        #[cfg(feature = "dtype-struct")]
+        Unnest { ref columns } => process_unnest(
+            proj_pd,
+            input,
+            columns,
+            acc_projections,
+            projections_seen,
+            lp_arena,
+            expr_arena,
+        ),
         _ => {
             if function.allow_projection_pd() && !acc_projections.is_empty() {
                 let original_acc_projection_len = acc_projections.len();
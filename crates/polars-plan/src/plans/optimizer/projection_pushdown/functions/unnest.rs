@@ -0,0 +1,91 @@
+use super::*;
+
+/// Push the projections down through an unnest.
+///
+/// The unnested struct columns are narrowed to the projected fields before they are unnested,
+/// so that fields that aren't selected are never materialized as columns.
+pub(super) fn process_unnest(
+    proj_pd: &mut ProjectionPushDown,
+    input: Node,
+    columns: &Arc<[Arc<str>]>,
+    acc_projections: Vec<ColumnNode>,
+    projections_seen: usize,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<IR> {
+    let function = FunctionNode::Unnest {
+        columns: columns.clone(),
+    };
+    if acc_projections.is_empty() {
+        let lp = IR::MapFunction { input, function };
+        return proj_pd.no_pushdown_restart_opt(
+            lp,
+            acc_projections,
+            projections_seen,
+            lp_arena,
+            expr_arena,
+        );
+    }
+
+    let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+    let projected = acc_projections
+        .iter()
+        .map(|node| column_node_to_name(*node, expr_arena))
+        .collect::<PlHashSet<_>>();
+
+    let mut input_projections = init_vec();
+    let mut input_names = init_set();
+    let mut narrowed = vec![];
+    for (name, dtype) in input_schema.iter() {
+        let unnested = columns
+            .iter()
+            .any(|column| column.as_ref() == name.as_str());
+        match dtype {
+            DataType::Struct(fields) if unnested => {
+                let mut needed = fields
+                    .iter()
+                    .map(|fld| fld.name().as_str())
+                    .filter(|fld| projected.contains(*fld))
+                    .collect::<Vec<_>>();
+                if needed.len() < fields.len() {
+                    // A struct can't be empty, so keep a field if none is projected.
+                    if needed.is_empty() {
+                        needed.push(fields[0].name().as_str());
+                    }
+                    let fields = needed
+                        .iter()
+                        .map(|fld| col(name.as_str()).struct_().field_by_name(fld))
+                        .collect();
+                    let expr = as_struct(fields).alias(name.as_str());
+                    narrowed.push(to_expr_ir(expr, expr_arena));
+                }
+            },
+            _ if unnested || projected.contains(name.as_str()) => {},
+            _ => continue,
+        }
+        let node = expr_arena.add(AExpr::Column(ColumnName::from(name.as_str())));
+        add_expr_to_accumulated(node, &mut input_projections, &mut input_names, expr_arena);
+    }
+
+    proj_pd.pushdown_and_assign(
+        input,
+        input_projections,
+        input_names,
+        projections_seen,
+        lp_arena,
+        expr_arena,
+    )?;
+
+    let mut builder = IRBuilder::new(input, expr_arena, lp_arena);
+    if !narrowed.is_empty() {
+        builder = builder.with_columns(narrowed, Default::default());
+    }
+    let lp = IR::MapFunction {
+        input: builder.node(),
+        function,
+    };
+    // The unnest still outputs the fields that were kept to have a non-empty struct.
+    Ok(IRBuilder::from_lp(lp, expr_arena, lp_arena)
+        .project_simple_nodes(acc_projections)?
+        .build())
+}
@@ -443,6 +443,12 @@ impl ProjectionPushDown {
                                 }
                             }
                         }
+                        // The file path column comes after the hive partitions.
+                        if let Some(ref file_path_col) = file_options.include_file_paths {
+                            if let Some(dt) = schema.shift_remove(file_path_col.as_ref()) {
+                                schema.with_column(file_path_col.as_ref().into(), dt);
+                            }
+                        }
                         Some(Arc::new(schema))
                     };
                 }
@@ -1,3 +1,4 @@
+use polars_core::polars_trace;
 use polars_core::prelude::PolarsResult;
 
 use crate::plans::aexpr::AExpr;
@@ -32,6 +33,13 @@ impl StackOptimizer {
                 for rule in rules.iter_mut() {
                     // keep iterating over same rule
                     while let Some(x) = rule.optimize_plan(lp_arena, expr_arena, current_node) {
+                        polars_trace!(
+                            target: "polars::optimizer",
+                            rule = rule.name(),
+                            node = current_node.0,
+                            plan = lp_arena.get(current_node).name();
+                            "optimization rule rewrote plan node"
+                        );
                         lp_arena.replace(current_node, x);
                         changed = true;
                     }
@@ -67,6 +75,13 @@ impl StackOptimizer {
                             lp_arena,
                             current_node,
                         )? {
+                            polars_trace!(
+                                target: "polars::optimizer",
+                                rule = rule.name(),
+                                node = current_node.0,
+                                expr = current_expr_node.0;
+                                "optimization rule rewrote expression"
+                            );
                             expr_arena.replace(current_expr_node, x);
                             changed = true;
                         }
@@ -83,6 +98,12 @@ impl StackOptimizer {
 }
 
 pub trait OptimizationRule {
+    /// Name of the rule, used when tracing the optimizations that were applied.
+    fn name(&self) -> &'static str {
+        let name = std::any::type_name::<Self>();
+        name.rsplit("::").next().unwrap_or(name)
+    }
+
     ///  Optimize (subplan) in LogicalPlan
     ///
     /// * `lp_arena` - LogicalPlan memory arena
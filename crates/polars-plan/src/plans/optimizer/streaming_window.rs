@@ -0,0 +1,243 @@
+use polars_utils::unitvec;
+use recursive::recursive;
+
+use super::*;
+
+const WINDOW_NAME_PREFIX: &str = "__POLARS_WINDOW_";
+
+/// Whether `node` evaluates to a single value per group, e.g. `sum(a) / len()`.
+fn is_group_scalar(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    match expr_arena.get(node) {
+        AExpr::Agg(agg) => {
+            !matches!(agg, IRAggExpr::Implode(_) | IRAggExpr::AggGroups(_))
+                && expr_arena
+                    .iter(node)
+                    .skip(1)
+                    .all(|(_, e)| !matches!(e, AExpr::Agg(_) | AExpr::Window { .. } | AExpr::Len))
+        },
+        AExpr::Len => true,
+        AExpr::Literal(lv) => lv.projects_as_scalar(),
+        AExpr::Cast { expr, .. } => is_group_scalar(*expr, expr_arena),
+        AExpr::BinaryExpr { left, right, .. } => {
+            is_group_scalar(*left, expr_arena) && is_group_scalar(*right, expr_arena)
+        },
+        AExpr::Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => [predicate, truthy, falsy]
+            .iter()
+            .all(|node| is_group_scalar(**node, expr_arena)),
+        _ => false,
+    }
+}
+
+/// Get the partition columns of a window expression that can be computed as a group by that
+/// is joined back onto its input, or `None` if it cannot.
+fn window_partition_columns(node: Node, expr_arena: &Arena<AExpr>) -> Option<Vec<ColumnName>> {
+    let AExpr::Window {
+        function,
+        partition_by,
+        order_by: None,
+        options: WindowType::Over(WindowMapping::GroupsToRows),
+    } = expr_arena.get(node)
+    else {
+        return None;
+    };
+    if !is_group_scalar(*function, expr_arena) {
+        return None;
+    }
+    let mut columns: Vec<ColumnName> = Vec::with_capacity(partition_by.len());
+    for node in partition_by {
+        let AExpr::Column(name) = expr_arena.get(*node) else {
+            return None;
+        };
+        if !columns.contains(name) {
+            columns.push(name.clone());
+        }
+    }
+    Some(columns)
+}
+
+/// Whether the plan at `node` only scans and projects, so that it is cheap to run it twice.
+fn is_cheap_to_recompute(node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> bool {
+    let mut stack = unitvec![node];
+    while let Some(node) = stack.pop() {
+        let lp = lp_arena.get(node);
+        match lp {
+            IR::Scan { .. } | IR::DataFrameScan { .. } => {},
+            IR::Filter { input, .. }
+            | IR::Select { input, .. }
+            | IR::HStack { input, .. }
+            | IR::SimpleProjection { input, .. } => {
+                if lp
+                    .get_exprs()
+                    .iter()
+                    .any(|e| has_aexpr_window(e.node(), expr_arena))
+                {
+                    return false;
+                }
+                stack.push(*input)
+            },
+            _ => return false,
+        }
+    }
+    true
+}
+
+#[recursive]
+fn copy_plan(node: Node, lp_arena: &mut Arena<IR>) -> Node {
+    let lp = lp_arena.get(node);
+    let exprs = lp.get_exprs();
+    let inputs = lp.get_inputs();
+    let inputs = inputs
+        .iter()
+        .map(|input| copy_plan(*input, lp_arena))
+        .collect();
+    let lp = lp_arena.get(node).with_exprs_and_input(exprs, inputs);
+    lp_arena.add(lp)
+}
+
+fn column_irs(columns: &[ColumnName], expr_arena: &mut Arena<AExpr>) -> Vec<ExprIR> {
+    columns
+        .iter()
+        .map(|name| {
+            let node = expr_arena.add(AExpr::Column(name.clone()));
+            ExprIR::new(node, OutputName::ColumnLhs(name.clone()))
+        })
+        .collect()
+}
+
+/// Rewrite the window expressions of the projection at `node` to a group by of which the result
+/// is joined back onto the input, as the streaming engine cannot evaluate window expressions
+/// but can stream group bys and joins.
+fn rewrite_windows(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    window_count: &mut usize,
+) -> PolarsResult<()> {
+    let (exprs, input) = match lp_arena.get(node) {
+        IR::Select { expr, input, .. }
+        | IR::HStack {
+            exprs: expr, input, ..
+        } => (expr.clone(), *input),
+        _ => return Ok(()),
+    };
+
+    let mut windows = vec![];
+    for e in &exprs {
+        for (node, ae) in (&*expr_arena).iter(e.node()) {
+            if matches!(ae, AExpr::Window { .. }) {
+                match window_partition_columns(node, expr_arena) {
+                    Some(columns) => windows.push((node, columns)),
+                    // All windows of the projection have to be rewritten for it to stream.
+                    None => return Ok(()),
+                }
+            }
+        }
+    }
+    if windows.is_empty() || !is_cheap_to_recompute(input, lp_arena, expr_arena) {
+        return Ok(());
+    }
+
+    // Group the windows by their partition columns, so that every group by is done once.
+    let mut group_bys: Vec<(Vec<ColumnName>, Vec<ExprIR>)> = vec![];
+    for (window, columns) in windows {
+        let AExpr::Window { function, .. } = expr_arena.get(window) else {
+            unreachable!()
+        };
+        let name = ColumnName::from(format!("{WINDOW_NAME_PREFIX}{window_count}"));
+        *window_count += 1;
+        let agg = ExprIR::new(*function, OutputName::Alias(name.clone()));
+        expr_arena.replace(window, AExpr::Column(name));
+
+        match group_bys.iter_mut().find(|(keys, _)| *keys == columns) {
+            Some((_, aggs)) => aggs.push(agg),
+            None => group_bys.push((columns, vec![agg])),
+        }
+    }
+
+    let mut joined = input;
+    for (keys, aggs) in group_bys {
+        let input = copy_plan(input, lp_arena);
+        let group_keys = column_irs(&keys, expr_arena);
+        let aggregated = IRBuilder::new(input, expr_arena, lp_arena)
+            .group_by(group_keys, aggs, None, false, Default::default())
+            .node();
+
+        let mut args = JoinArgs::new(JoinType::Left);
+        args.join_nulls = true;
+        args.coalesce = JoinCoalesce::CoalesceColumns;
+        let options = Arc::new(JoinOptions {
+            args,
+            ..Default::default()
+        });
+        let left_on = column_irs(&keys, expr_arena);
+        let right_on = column_irs(&keys, expr_arena);
+        joined = IRBuilder::new(joined, expr_arena, lp_arena)
+            .join(aggregated, left_on, right_on, options)
+            .node();
+    }
+
+    // The windows are replaced by the temporary columns, which should not be the output names.
+    let exprs = exprs
+        .into_iter()
+        .map(|mut e| {
+            if !e.has_alias() {
+                let name = e.output_name_arc().clone();
+                e.set_alias(name);
+            }
+            e
+        })
+        .collect();
+    let lp = match lp_arena.get(node) {
+        IR::Select { options, .. } => {
+            let options = *options;
+            IRBuilder::new(joined, expr_arena, lp_arena)
+                .project(exprs, options)
+                .build()
+        },
+        IR::HStack {
+            schema, options, ..
+        } => {
+            let options = *options;
+            let schema = schema.clone();
+            IRBuilder::new(joined, expr_arena, lp_arena)
+                .with_columns(exprs, options)
+                .project_simple(schema.iter_names().map(|name| name.as_str()))?
+                .build()
+        },
+        _ => unreachable!(),
+    };
+    lp_arena.replace(node, lp);
+    Ok(())
+}
+
+#[recursive]
+fn rewrite(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    window_count: &mut usize,
+) -> PolarsResult<()> {
+    let inputs = lp_arena.get(node).get_inputs();
+    for input in inputs.iter() {
+        rewrite(*input, lp_arena, expr_arena, window_count)?;
+    }
+    rewrite_windows(node, lp_arena, expr_arena, window_count)
+}
+
+/// Rewrite window expressions that are partitioned by columns and give a single value per
+/// group, e.g. `col("a").sum().over("g")`, to a group by that is joined back onto the input,
+/// so that the projections they are in can run in the streaming engine.
+///
+/// This is only done if the input only scans and projects, as it has to be run twice.
+pub(super) fn optimize(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let mut window_count = 0;
+    rewrite(root, lp_arena, expr_arena, &mut window_count)
+}
@@ -33,6 +33,8 @@ pub struct FileScanOptions {
     pub rechunk: bool,
     pub file_counter: FileCount,
     pub hive_options: HiveOptions,
+    /// Name of a column that holds the path of the file each row was read from.
+    pub include_file_paths: Option<Arc<str>>,
 }
 
 #[derive(Clone, Debug, Copy, Default, Eq, PartialEq, Hash)]
@@ -229,6 +231,9 @@ pub enum SinkType {
     File {
         path: Arc<PathBuf>,
         file_type: FileType,
+        /// Write a directory of part files of which the progress is recorded in a checkpoint
+        /// manifest, so that an interrupted run can be resumed.
+        checkpoint: bool,
     },
     #[cfg(feature = "cloud")]
     Cloud {
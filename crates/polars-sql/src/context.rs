@@ -2,21 +2,24 @@ use std::cell::RefCell;
 
 use polars_core::frame::row::Row;
 use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
 use polars_lazy::prelude::*;
 use polars_ops::frame::JoinCoalesce;
 use polars_plan::prelude::*;
 use sqlparser::ast::{
-    Distinct, ExcludeSelectItem, Expr as SQLExpr, FunctionArg, GroupByExpr, JoinConstraint,
-    JoinOperator, ObjectName, ObjectType, Offset, OrderByExpr, Query, Select, SelectItem, SetExpr,
-    SetOperator, SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
-    Value as SQLValue, Values, WildcardAdditionalOptions,
+    Distinct, ExcludeSelectItem, Expr as SQLExpr, FunctionArg, GroupByExpr, Ident, Insert,
+    JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset, OrderByExpr, Query, Select,
+    SelectItem, SetExpr, SetOperator, SetQuantifier, Statement, TableAlias, TableFactor,
+    TableWithJoins, UnaryOperator, Value as SQLValue, Values, WildcardAdditionalOptions,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
+use sqlparser::tokenizer::{Token, Tokenizer};
 
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
 use crate::sql_expr::{
-    parse_sql_array, parse_sql_expr, process_join_constraint, to_sql_interface_err,
+    grouping_marker_name, parse_sql_array, parse_sql_expr, process_join_constraint,
+    to_sql_interface_err,
 };
 use crate::table_functions::PolarsTableFunctions;
 
@@ -28,6 +31,8 @@ pub struct SQLContext {
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
 
+    pub(crate) params: Vec<AnyValue<'static>>,
+
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
     joined_aliases: RefCell<PlHashMap<String, PlHashMap<String, String>>>,
@@ -38,6 +43,7 @@ impl Default for SQLContext {
         Self {
             function_registry: Arc::new(DefaultFunctionRegistry {}),
             table_map: Default::default(),
+            params: Default::default(),
             cte_map: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
@@ -59,6 +65,22 @@ impl SQLContext {
         Self::default()
     }
 
+    /// Get the value of the parameter bound to a `$n` placeholder.
+    pub(crate) fn get_param(&self, placeholder: &str) -> PolarsResult<AnyValue<'static>> {
+        placeholder
+            .strip_prefix('$')
+            .and_then(|n| n.parse::<usize>().ok())
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|idx| self.params.get(idx))
+            .cloned()
+            .ok_or_else(|| {
+                polars_err!(
+                    SQLInterface: "no parameter bound to placeholder '{}' (found {} parameters)",
+                    placeholder, self.params.len()
+                )
+            })
+    }
+
     /// Get the names of all registered tables, in sorted order.
     pub fn get_tables(&self) -> Vec<String> {
         let mut tables = Vec::from_iter(self.table_map.keys().cloned());
@@ -109,6 +131,52 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
+        self.execute_with_params(query, &[])
+    }
+
+    /// Execute a SQL query with `$1`, `$2`, ... (or positional `?`) placeholders, which are
+    /// bound to the given parameters as literal values, returning a [`LazyFrame`].
+    /// ```rust
+    /// # use polars_sql::SQLContext;
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// let df = df! {
+    ///    "a" =>  [1, 2, 3],
+    /// }
+    /// .unwrap();
+    ///
+    /// ctx.register("df", df.lazy());
+    /// let sql_df = ctx
+    ///     .execute_with_params("SELECT * FROM df WHERE a > $1", &[AnyValue::Int32(1)])
+    ///     .unwrap()
+    ///     .collect()
+    ///     .unwrap();
+    /// assert_eq!(sql_df.height(), 2);
+    /// # }
+    ///```
+    pub fn execute_with_params(
+        &mut self,
+        query: &str,
+        params: &[AnyValue<'static>],
+    ) -> PolarsResult<LazyFrame> {
+        // number the positional '?' placeholders, so that they can be bound like '$n'
+        let mut n_positional = 0;
+        let tokens = Tokenizer::new(&GenericDialect, query)
+            .tokenize()
+            .map_err(to_sql_interface_err)?
+            .into_iter()
+            .map(|token| match token {
+                Token::Placeholder(p) if p == "?" => {
+                    n_positional += 1;
+                    Token::Placeholder(format!("${}", n_positional))
+                },
+                token => token,
+            })
+            .collect();
+
         let mut parser = Parser::new(&GenericDialect);
         parser = parser.with_options(ParserOptions {
             trailing_commas: true,
@@ -116,13 +184,27 @@ impl SQLContext {
         });
 
         let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
+            .with_tokens(tokens)
             .parse_statements()
             .map_err(to_sql_interface_err)?;
 
-        polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
-        let res = self.execute_statement(ast.first().unwrap())?;
+        polars_ensure!(!ast.is_empty(), SQLInterface: "no SQL statement found");
+
+        // The statements of a script are executed in order, returning the result of the last.
+        self.params = params.to_vec();
+        let mut res = Ok(DataFrame::empty().lazy());
+        for stmt in &ast {
+            res = self.execute_statement(stmt);
+            // Every statement should clear the statement-level maps.
+            self.cte_map.borrow_mut().clear();
+            self.table_aliases.borrow_mut().clear();
+            self.joined_aliases.borrow_mut().clear();
+            if res.is_err() {
+                break;
+            }
+        }
+        self.params.clear();
+        let res = res?;
 
         // Ensure the result uses the proper arenas.
         // This will instantiate new arenas with a new version.
@@ -130,11 +212,6 @@ impl SQLContext {
         let expr_arena = std::mem::take(&mut self.expr_arena);
         res.set_cached_arena(lp_arena, expr_arena);
 
-        // Every execution should clear the statement-level maps.
-        self.cte_map.borrow_mut().clear();
-        self.table_aliases.borrow_mut().clear();
-        self.joined_aliases.borrow_mut().clear();
-
         Ok(res)
     }
 
@@ -163,6 +240,7 @@ impl SQLContext {
             Statement::Query(query) => self.execute_query(query)?,
             stmt @ Statement::ShowTables { .. } => self.execute_show_tables(stmt)?,
             stmt @ Statement::CreateTable { .. } => self.execute_create_table(stmt)?,
+            Statement::Insert(insert) => self.execute_insert(insert)?,
             stmt @ Statement::Drop {
                 object_type: ObjectType::Table,
                 ..
@@ -186,9 +264,9 @@ impl SQLContext {
     }
 
     pub(super) fn get_table_from_current_scope(&self, name: &str) -> Option<LazyFrame> {
-        let table_name = self.table_map.get(name).cloned();
-        table_name
-            .or_else(|| self.cte_map.borrow().get(name).cloned())
+        // A CTE shadows a registered table of the same name.
+        let cte = self.cte_map.borrow().get(name).cloned();
+        cte.or_else(|| self.table_map.get(name).cloned())
             .or_else(|| {
                 self.table_aliases
                     .borrow()
@@ -276,11 +354,11 @@ impl SQLContext {
 
             #[cfg(feature = "semi_anti_join")]
             SetExpr::SetOperation {
-                op: SetOperator::Intersect | SetOperator::Except,
+                op: op @ (SetOperator::Intersect | SetOperator::Except),
                 set_quantifier,
                 left,
                 right,
-            } => self.process_except_intersect(left, right, set_quantifier, query),
+            } => self.process_except_intersect(op, left, right, set_quantifier, query),
 
             SetExpr::Values(Values {
                 explicit_row: _,
@@ -314,41 +392,39 @@ impl SQLContext {
     #[cfg(feature = "semi_anti_join")]
     fn process_except_intersect(
         &mut self,
+        op: &SetOperator,
         left: &SetExpr,
         right: &SetExpr,
         quantifier: &SetQuantifier,
         query: &Query,
     ) -> PolarsResult<LazyFrame> {
-        let (join_type, op_name) = match *query.body {
-            SetExpr::SetOperation {
-                op: SetOperator::Except,
-                ..
-            } => (JoinType::Anti, "EXCEPT"),
+        let (join_type, op_name) = match op {
+            SetOperator::Except => (JoinType::Anti, "EXCEPT"),
             _ => (JoinType::Semi, "INTERSECT"),
         };
         let mut lf = self.process_query(left, query)?;
-        let mut rf = self.process_query(right, query)?;
-        let join = lf
-            .clone()
-            .join_builder()
-            .with(rf.clone())
-            .how(join_type)
-            .join_nulls(true);
+        let rf = self.process_query(right, query)?;
 
         let lf_schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
         let lf_cols: Vec<_> = lf_schema.iter_names().map(|nm| col(nm)).collect();
         let joined_tbl = match quantifier {
             SetQuantifier::ByName | SetQuantifier::AllByName => {
                 // note: 'BY NAME' is pending https://github.com/sqlparser-rs/sqlparser-rs/pull/1309
-                join.on(lf_cols).finish()
+                lf.join_builder()
+                    .with(rf)
+                    .how(join_type)
+                    .join_nulls(true)
+                    .on(lf_cols)
+                    .finish()
             },
             SetQuantifier::Distinct | SetQuantifier::None => {
-                let rf_schema = rf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
-                let rf_cols: Vec<_> = rf_schema.iter_names().map(|nm| col(nm)).collect();
-                if lf_cols.len() != rf_cols.len() {
-                    polars_bail!(SQLInterface: "{} requires equal number of columns in each table (use '{} BY NAME' to combine mismatched tables)", op_name, op_name)
-                }
-                join.left_on(lf_cols).right_on(rf_cols).finish()
+                let (lf, rf) = self.align_set_operation(lf, rf, op_name)?;
+                lf.join_builder()
+                    .with(rf)
+                    .how(join_type)
+                    .join_nulls(true)
+                    .on(lf_cols)
+                    .finish()
             },
             _ => {
                 polars_bail!(SQLInterface: "'{} {}' is not supported", op_name, quantifier.to_string())
@@ -364,8 +440,8 @@ impl SQLContext {
         quantifier: &SetQuantifier,
         query: &Query,
     ) -> PolarsResult<LazyFrame> {
-        let mut lf = self.process_query(left, query)?;
-        let mut rf = self.process_query(right, query)?;
+        let lf = self.process_query(left, query)?;
+        let rf = self.process_query(right, query)?;
         let opts = UnionArgs {
             parallel: true,
             to_supertypes: true,
@@ -374,11 +450,7 @@ impl SQLContext {
         match quantifier {
             // UNION [ALL | DISTINCT]
             SetQuantifier::All | SetQuantifier::Distinct | SetQuantifier::None => {
-                let lf_schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
-                let rf_schema = rf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
-                if lf_schema.len() != rf_schema.len() {
-                    polars_bail!(SQLInterface: "UNION requires equal number of columns in each table (use 'UNION BY NAME' to combine mismatched tables)")
-                }
+                let (lf, rf) = self.align_set_operation(lf, rf, "UNION")?;
                 let concatenated = polars_lazy::dsl::concat(vec![lf, rf], opts);
                 match quantifier {
                     SetQuantifier::Distinct | SetQuantifier::None => {
@@ -401,6 +473,38 @@ impl SQLContext {
         }
     }
 
+    /// Match the columns of the two sides of a set operation by position: the columns of `rf`
+    /// take the names of those of `lf`, and both are cast to the supertype of every pair.
+    fn align_set_operation(
+        &mut self,
+        mut lf: LazyFrame,
+        mut rf: LazyFrame,
+        op_name: &str,
+    ) -> PolarsResult<(LazyFrame, LazyFrame)> {
+        let lf_schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        let rf_schema = rf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        if lf_schema.len() != rf_schema.len() {
+            polars_bail!(SQLInterface: "{} requires equal number of columns in each table (use '{} BY NAME' to combine mismatched tables)", op_name, op_name)
+        }
+        let mut lf_cols = Vec::with_capacity(lf_schema.len());
+        let mut rf_cols = Vec::with_capacity(rf_schema.len());
+        for ((l_name, l_dtype), (r_name, r_dtype)) in lf_schema.iter().zip(rf_schema.iter()) {
+            let dtype = try_get_supertype(l_dtype, r_dtype).map_err(|_| {
+                polars_err!(SQLInterface: "{} column '{}' has incompatible types ({} and {})", op_name, l_name, l_dtype, r_dtype)
+            })?;
+            let (mut l_col, mut r_col) = (col(l_name), col(r_name));
+            if l_dtype != &dtype {
+                l_col = l_col.cast(dtype.clone());
+            }
+            if r_dtype != &dtype {
+                r_col = r_col.cast(dtype);
+            }
+            lf_cols.push(l_col);
+            rf_cols.push(r_col.alias(l_name));
+        }
+        Ok((lf.select(lf_cols), rf.select(rf_cols)))
+    }
+
     fn process_values(&mut self, values: &[Vec<SQLExpr>]) -> PolarsResult<LazyFrame> {
         let frame_rows: Vec<Row> = values.iter().map(|row| {
             let row_data: Result<Vec<_>, _> = row.iter().map(|expr| {
@@ -423,8 +527,15 @@ impl SQLContext {
     // EXPLAIN SELECT * FROM DF
     fn execute_explain(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
-            Statement::Explain { statement, .. } => {
-                let lf = self.execute_statement(statement)?;
+            Statement::Explain {
+                analyze, statement, ..
+            } => {
+                polars_ensure!(!analyze, SQLInterface: "EXPLAIN ANALYZE is not supported");
+                // Only queries are explained, as other statements would modify the context.
+                let Statement::Query(query) = statement.as_ref() else {
+                    polars_bail!(SQLInterface: "EXPLAIN only supports queries; found {}", statement);
+                };
+                let lf = self.execute_query(query)?;
                 let plan = lf.describe_optimized_plan()?;
                 let plan = plan
                     .split('\n')
@@ -500,7 +611,8 @@ impl SQLContext {
                 let cte_name = cte.alias.name.value.clone();
                 let mut lf = self.execute_query(&cte.query)?;
                 lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
-                self.register_cte(&cte_name, lf);
+                // Every reference to the CTE shares a single cache, so that it is computed once.
+                self.register_cte(&cte_name, lf.cache());
             }
         }
         Ok(())
@@ -517,7 +629,31 @@ impl SQLContext {
                 let right_schema =
                     rf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
 
-                lf = match &tbl.join_operator {
+                // NATURAL joins are equivalent to USING the columns that both tables have
+                let join_operator = match &tbl.join_operator {
+                    JoinOperator::Inner(JoinConstraint::Natural)
+                    | JoinOperator::LeftOuter(JoinConstraint::Natural)
+                    | JoinOperator::FullOuter(JoinConstraint::Natural) => {
+                        let common = left_schema
+                            .iter_names()
+                            .filter(|name| right_schema.contains(name))
+                            .map(|name| Ident::new(name.as_str()))
+                            .collect::<Vec<_>>();
+                        polars_ensure!(
+                            !common.is_empty(),
+                            SQLInterface: "NATURAL JOIN of '{}' and '{}' has no common columns", l_name, r_name
+                        );
+                        let using = JoinConstraint::Using(common);
+                        match &tbl.join_operator {
+                            JoinOperator::Inner(_) => JoinOperator::Inner(using),
+                            JoinOperator::LeftOuter(_) => JoinOperator::LeftOuter(using),
+                            _ => JoinOperator::FullOuter(using),
+                        }
+                    },
+                    join_operator => join_operator.clone(),
+                };
+
+                lf = match &join_operator {
                     JoinOperator::FullOuter(constraint) => {
                         self.process_join(lf, rf, constraint, &l_name, &r_name, JoinType::Full)?
                     },
@@ -600,7 +736,7 @@ impl SQLContext {
         lf = self.process_where(lf, &select_stmt.selection)?;
 
         // Column projections.
-        let projections: Vec<_> = select_stmt
+        let mut projections: Vec<_> = select_stmt
             .projection
             .iter()
             .map(|select_item| {
@@ -628,10 +764,30 @@ impl SQLContext {
                 })
             })
             .collect::<PolarsResult<_>>()?;
+        lf = self.process_subqueries(lf, projections.iter_mut().collect());
 
         // Check for "GROUP BY ..." (after projections, as there may be ordinal/position ints).
         let mut group_by_keys: Vec<Expr> = Vec::new();
+        let mut grouping_sets: Option<Vec<Vec<Expr>>> = None;
         match &select_stmt.group_by {
+            // "GROUP BY ROLLUP(x, y)", "CUBE(x, y)" or "GROUPING SETS ((x, y), (x), ())"
+            GroupByExpr::Expressions(group_by_exprs)
+                if group_by_exprs.iter().any(|e| {
+                    matches!(
+                        e,
+                        SQLExpr::Rollup(_) | SQLExpr::Cube(_) | SQLExpr::GroupingSets(_)
+                    )
+                }) =>
+            {
+                let sets =
+                    self.expand_grouping_sets(group_by_exprs, schema.as_deref(), &projections)?;
+                for key in sets.iter().flatten() {
+                    if !group_by_keys.contains(key) {
+                        group_by_keys.push(key.clone());
+                    }
+                }
+                grouping_sets = Some(sets);
+            },
             // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
             GroupByExpr::Expressions(group_by_exprs) => {
                 // translate the group expressions, allowing ordinal values
@@ -734,7 +890,30 @@ impl SQLContext {
                 self.process_order_by(lf, &query.order_by)?
             }
         } else {
-            lf = self.process_group_by(lf, contains_wildcard, &group_by_keys, &projections)?;
+            let uses_grouping = projections.iter().any(|e| {
+                has_expr(e, |e| {
+                    matches!(e, Expr::Column(name) if name.starts_with(&grouping_marker_name("")))
+                })
+            });
+            lf = match grouping_sets {
+                Some(sets) => self.process_grouping_sets(
+                    lf,
+                    contains_wildcard,
+                    &group_by_keys,
+                    &sets,
+                    &projections,
+                )?,
+                None if uses_grouping => self.process_grouping_sets(
+                    lf,
+                    contains_wildcard,
+                    &group_by_keys,
+                    &[group_by_keys.clone()],
+                    &projections,
+                )?,
+                None => {
+                    self.process_group_by(lf, contains_wildcard, &group_by_keys, &projections)?
+                },
+            };
             lf = self.process_order_by(lf, &query.order_by)?;
 
             // Apply optional 'having' clause, post-aggregation.
@@ -799,6 +978,12 @@ impl SQLContext {
         join_type: JoinType,
     ) -> PolarsResult<LazyFrame> {
         let (left_on, right_on) = process_join_constraint(constraint, tbl_name, join_tbl_name)?;
+        // the key columns of a USING join are output once, coalescing the keys of both tables
+        let coalesce = if matches!(constraint, JoinConstraint::Using(_)) {
+            JoinCoalesce::CoalesceColumns
+        } else {
+            JoinCoalesce::KeepColumns
+        };
 
         let joined_tbl = left_tbl
             .clone()
@@ -808,7 +993,7 @@ impl SQLContext {
             .right_on(right_on)
             .how(join_type)
             .suffix(format!(":{}", join_tbl_name))
-            .coalesce(JoinCoalesce::KeepColumns)
+            .coalesce(coalesce)
             .finish();
 
         Ok(joined_tbl)
@@ -868,6 +1053,78 @@ impl SQLContext {
         }
     }
 
+    fn execute_insert(&mut self, insert: &Insert) -> PolarsResult<LazyFrame> {
+        let tbl_name = insert.table_name.to_string();
+        let Some(mut lf) = self.table_map.get(&tbl_name).cloned() else {
+            polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name);
+        };
+        let Some(source) = &insert.source else {
+            polars_bail!(SQLInterface: "only `INSERT INTO ... SELECT` and `INSERT INTO ... VALUES` are currently supported");
+        };
+        let mut rf = self.execute_query(source)?;
+        let schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        let source_schema = rf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+
+        // The source columns are matched by position with the given target columns (or else
+        // all columns of the table); target columns that are not given are set to null.
+        let targets = if insert.columns.is_empty() {
+            schema
+                .iter_names()
+                .map(|name| name.as_str())
+                .collect::<Vec<_>>()
+        } else {
+            insert
+                .columns
+                .iter()
+                .map(|ident| {
+                    polars_ensure!(
+                        schema.contains(&ident.value),
+                        ColumnNotFound: "column '{}' does not exist in table '{}'", ident.value, tbl_name
+                    );
+                    Ok(ident.value.as_str())
+                })
+                .collect::<PolarsResult<Vec<_>>>()?
+        };
+        polars_ensure!(
+            targets.len() == source_schema.len(),
+            SQLInterface: "INSERT INTO '{}' expects {} columns; found {}", tbl_name, targets.len(), source_schema.len()
+        );
+        let mut sources = PlHashMap::with_capacity(targets.len());
+        for (target, (name, dtype)) in targets.iter().zip(source_schema.iter()) {
+            let target_dtype = schema.get(target).unwrap();
+            polars_ensure!(
+                try_get_supertype(target_dtype, dtype).is_ok(),
+                SQLInterface: "cannot insert {} values into column '{}' of type {}", dtype, target, target_dtype
+            );
+            sources.insert(*target, col(name));
+        }
+        let exprs = schema
+            .iter()
+            .map(|(name, dtype)| {
+                let expr = match sources.get(name.as_str()) {
+                    Some(expr) => expr.clone().strict_cast(dtype.clone()),
+                    None => lit(LiteralValue::Null).cast(dtype.clone()),
+                };
+                expr.alias(name)
+            })
+            .collect::<Vec<_>>();
+
+        lf = polars_lazy::dsl::concat(
+            [lf, rf.select(exprs)],
+            UnionArgs {
+                parallel: true,
+                ..Default::default()
+            },
+        )?;
+        self.register(&tbl_name, lf);
+        let out = df! {
+            "Response" => ["INSERT INTO"]
+        }
+        .unwrap()
+        .lazy();
+        Ok(out)
+    }
+
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
@@ -1102,6 +1359,110 @@ impl SQLContext {
         Ok(aggregated.select(&final_projection))
     }
 
+    /// Expand the GROUP BY clause into its grouping sets, e.g. `GROUP BY a, ROLLUP(b, c)` into
+    /// `(a, b, c), (a, b), (a)`.
+    fn expand_grouping_sets(
+        &mut self,
+        group_by_exprs: &[SQLExpr],
+        schema: Option<&Schema>,
+        projections: &[Expr],
+    ) -> PolarsResult<Vec<Vec<Expr>>> {
+        let mut sets: Vec<Vec<Expr>> = vec![vec![]];
+        for e in group_by_exprs {
+            let element_sets: Vec<Vec<&SQLExpr>> = match e {
+                SQLExpr::Rollup(items) => (0..=items.len())
+                    .rev()
+                    .map(|n| items[..n].iter().flatten().collect())
+                    .collect(),
+                SQLExpr::Cube(items) => {
+                    polars_ensure!(items.len() <= 16, SQLInterface: "CUBE supports at most 16 elements; found {}", items.len());
+                    let n = items.len();
+                    (0..1usize << n)
+                        .rev()
+                        .map(|mask| {
+                            items
+                                .iter()
+                                .enumerate()
+                                .filter(|(i, _)| mask & (1 << (n - 1 - i)) != 0)
+                                .flat_map(|(_, item)| item)
+                                .collect()
+                        })
+                        .collect()
+                },
+                SQLExpr::GroupingSets(items) => {
+                    items.iter().map(|item| item.iter().collect()).collect()
+                },
+                e => vec![vec![e]],
+            };
+            let element_sets = element_sets
+                .into_iter()
+                .map(|set| {
+                    set.into_iter()
+                        .map(|e| self.expr_or_ordinal(e, schema, projections, "GROUP BY"))
+                        .collect::<PolarsResult<Vec<_>>>()
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            sets = sets
+                .iter()
+                .flat_map(|set| {
+                    element_sets
+                        .iter()
+                        .map(move |s| set.iter().chain(s).cloned().collect())
+                })
+                .collect();
+        }
+        Ok(sets)
+    }
+
+    /// Aggregate over every grouping set of `keys` and concatenate the results. The keys that
+    /// are not part of a set are null in its rows, and every key gets a hidden marker column
+    /// (grouped by as well) for the `GROUPING` function.
+    fn process_grouping_sets(
+        &mut self,
+        mut lf: LazyFrame,
+        contains_wildcard: bool,
+        keys: &[Expr],
+        sets: &[Vec<Expr>],
+        projections: &[Expr],
+    ) -> PolarsResult<LazyFrame> {
+        let schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        let key_fields = keys
+            .iter()
+            .map(|e| e.to_field(&schema, Context::Default))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let lf = lf.cache();
+        let mut frames = Vec::with_capacity(sets.len());
+        for set in sets {
+            let mut set_keys = Vec::with_capacity(keys.len() * 2);
+            let mut markers = Vec::with_capacity(keys.len());
+            for (key, field) in keys.iter().zip(&key_fields) {
+                let grouped = set.contains(key);
+                set_keys.push(if grouped {
+                    key.clone()
+                } else {
+                    lit(LiteralValue::Null)
+                        .cast(field.dtype.clone())
+                        .alias(field.name())
+                });
+                let marker = grouping_marker_name(field.name());
+                set_keys.push(col(&marker));
+                markers.push(typed_lit(if grouped { 0i32 } else { 1i32 }).alias(&marker));
+            }
+            frames.push(self.process_group_by(
+                lf.clone().with_columns(markers),
+                contains_wildcard,
+                &set_keys,
+                projections,
+            )?);
+        }
+        let opts = UnionArgs {
+            parallel: true,
+            to_supertypes: true,
+            ..Default::default()
+        };
+        polars_lazy::dsl::concat(frames, opts)
+    }
+
     fn process_limit_offset(
         &self,
         lf: LazyFrame,
@@ -1,21 +1,20 @@
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
-use polars_core::prelude::{polars_bail, polars_err, DataType, PolarsResult};
+use polars_core::export::chrono::Utc;
+use polars_core::prelude::{polars_bail, polars_err, DataType, NamedFrom, PolarsResult, Series};
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "list_eval")]
 use polars_lazy::dsl::ListNameSpaceExtension;
 use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
 use polars_plan::plans::{typed_lit, LiteralValue};
-#[cfg(feature = "list_eval")]
-use polars_plan::prelude::col;
 use polars_plan::prelude::LiteralValue::Null;
-use polars_plan::prelude::{lit, StrptimeOptions};
+use polars_plan::prelude::{col, lit, StrptimeOptions};
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
     OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
 };
 
-use crate::sql_expr::{parse_extract_date_part, parse_sql_expr};
+use crate::sql_expr::{grouping_marker_name, parse_extract_date_part, parse_sql_expr};
 use crate::SQLContext;
 
 pub(crate) struct SQLFunctionVisitor<'a> {
@@ -265,6 +264,12 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT DATE_PART('year', column_1) from df;
     /// SELECT DATE_PART('day', column_1) from df;
     DatePart,
+    /// SQL 'now' function.
+    /// Returns the current (UTC) date and time.
+    /// ```sql
+    /// SELECT * FROM df WHERE ts > NOW() - INTERVAL '7 days';
+    /// ```
+    Now,
 
     // ----
     // String functions
@@ -319,6 +324,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT LOWER(column_1) from df;
     /// ```
     Lower,
+    /// SQL 'lpad' function
+    /// Left-pads the value to `length` characters with a `fill` character (default: space);
+    /// longer values are truncated.
+    /// ```sql
+    /// SELECT LPAD(column_1, 5, '0') from df;
+    /// ```
+    LPad,
     /// SQL 'ltrim' function
     /// Strip whitespaces from the left.
     /// ```sql
@@ -337,6 +349,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT REGEXP_LIKE(column_1, 'xyz', 'i') from df;
     /// ```
     RegexpLike,
+    /// SQL 'regexp_replace' function
+    /// Replace the first match of `pattern` with `replacement` (optional: `flags`, of which
+    /// 'g' replaces all matches); `\\1` refers to the first capture group.
+    /// ```sql
+    /// SELECT REGEXP_REPLACE(column_1, '([0-9]+)-([0-9]+)', '\\2-\\1', 'g') from df;
+    /// ```
+    RegexpReplace,
     /// SQL 'replace' function
     /// Replace a given substring with another string.
     /// ```sql
@@ -355,12 +374,26 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT RIGHT(column_1, 3) from df;
     /// ```
     Right,
+    /// SQL 'rpad' function
+    /// Right-pads the value to `length` characters with a `fill` character (default: space);
+    /// longer values are truncated.
+    /// ```sql
+    /// SELECT RPAD(column_1, 5, '.') from df;
+    /// ```
+    RPad,
     /// SQL 'rtrim' function
     /// Strip whitespaces from the right.
     /// ```sql
     /// SELECT RTRIM(column_1) from df;
     /// ```
     RTrim,
+    /// SQL 'split_part' function
+    /// Splits the value on a delimiter and returns the `n`th part (1-indexed; negative values
+    /// count from the end), or an empty string if there is no such part.
+    /// ```sql
+    /// SELECT SPLIT_PART(column_1, ',', 2) from df;
+    /// ```
+    SplitPart,
     /// SQL 'starts_with' function
     /// Returns True if the value starts with the second argument.
     /// ```sql
@@ -381,6 +414,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT SUBSTR(column_1, 3, 5) from df;
     /// ```
     Substring,
+    /// SQL 'translate' function
+    /// Replaces every character of the value that occurs in `from` with the character at the
+    /// same position in `to`, or removes it if `to` is shorter.
+    /// ```sql
+    /// SELECT TRANSLATE(column_1, 'abc', 'xy') from df;
+    /// ```
+    Translate,
     /// SQL 'upper' function
     /// Returns an uppercased column.
     /// ```sql
@@ -453,6 +493,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT FIRST(column_1) from df;
     /// ```
     First,
+    /// SQL 'grouping' function
+    /// Returns a bit mask of the given GROUP BY columns that are aggregated (rather than
+    /// grouped by) in the grouping set of a ROLLUP, CUBE or GROUPING SETS row.
+    /// ```sql
+    /// SELECT a, b, GROUPING(a, b), SUM(c) from df GROUP BY ROLLUP(a, b);
+    /// ```
+    Grouping,
     /// SQL 'last' function
     /// Returns the last element of the grouping.
     /// ```sql
@@ -620,6 +667,7 @@ impl PolarsSQLFunctions {
             "first",
             "floor",
             "greatest",
+            "grouping",
             "if",
             "ifnull",
             "initcap",
@@ -633,11 +681,13 @@ impl PolarsSQLFunctions {
             "log1p",
             "log2",
             "lower",
+            "lpad",
             "ltrim",
             "max",
             "median",
             "min",
             "mod",
+            "now",
             "nullif",
             "octet_length",
             "pi",
@@ -645,13 +695,16 @@ impl PolarsSQLFunctions {
             "power",
             "radians",
             "regexp_like",
+            "regexp_replace",
             "replace",
             "reverse",
             "right",
+            "rpad",
             "round",
             "rtrim",
             "sign",
             "sin",
+            "split_part",
             "sind",
             "sqrt",
             "starts_with",
@@ -664,6 +717,7 @@ impl PolarsSQLFunctions {
             "sum",
             "tan",
             "tand",
+            "translate",
             "unnest",
             "upper",
             "var",
@@ -735,6 +789,7 @@ impl PolarsSQLFunctions {
             // ----
             "date" => Self::Date,
             "date_part" => Self::DatePart,
+            "now" => Self::Now,
 
             // ----
             // String functions
@@ -748,16 +803,21 @@ impl PolarsSQLFunctions {
             "length" | "char_length" | "character_length" => Self::Length,
             "left" => Self::Left,
             "lower" => Self::Lower,
+            "lpad" => Self::LPad,
             "ltrim" => Self::LTrim,
             "octet_length" => Self::OctetLength,
             "strpos" => Self::StrPos,
             "regexp_like" => Self::RegexpLike,
+            "regexp_replace" => Self::RegexpReplace,
             "replace" => Self::Replace,
             "reverse" => Self::Reverse,
             "right" => Self::Right,
+            "rpad" => Self::RPad,
             "rtrim" => Self::RTrim,
+            "split_part" => Self::SplitPart,
             "starts_with" => Self::StartsWith,
             "substr" => Self::Substring,
+            "translate" => Self::Translate,
             "upper" => Self::Upper,
 
             // ----
@@ -766,6 +826,7 @@ impl PolarsSQLFunctions {
             "avg" => Self::Avg,
             "count" => Self::Count,
             "first" => Self::First,
+            "grouping" => Self::Grouping,
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
@@ -882,7 +943,7 @@ impl SQLFunctionVisitor<'_> {
             // Conditional functions
             // ----
             Coalesce => self.visit_variadic(coalesce),
-            Greatest => self.visit_variadic(|exprs: &[Expr]| max_horizontal(exprs).unwrap()),
+            Greatest => self.visit_variadic(|exprs: &[Expr]| max_horizontal(exprs, true).unwrap()),
             If => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -904,7 +965,7 @@ impl SQLFunctionVisitor<'_> {
                     },
                 }
             },
-            Least => self.visit_variadic(|exprs: &[Expr]| min_horizontal(exprs).unwrap()),
+            Least => self.visit_variadic(|exprs: &[Expr]| min_horizontal(exprs, true).unwrap()),
             NullIf => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -950,6 +1011,7 @@ impl SQLFunctionVisitor<'_> {
                     },
                 }
             }),
+            Now => self.visit_nullary(|| lit(Utc::now().naive_utc())),
 
             // ----
             // String functions
@@ -1004,6 +1066,7 @@ impl SQLFunctionVisitor<'_> {
             }),
             Length => self.visit_unary(|e| e.str().len_chars()),
             Lower => self.visit_unary(|e| e.str().to_lowercase()),
+            LPad => self.try_visit_variadic(|exprs| pad_string(exprs, true)),
             LTrim => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1043,6 +1106,40 @@ impl SQLFunctionVisitor<'_> {
                     _ => polars_bail!(SQLSyntax: "invalid number of arguments for REGEXP_LIKE ({})",args.len()),
                 }
             },
+            RegexpReplace => {
+                let args = extract_args(function)?;
+                if !matches!(args.len(), 3 | 4) {
+                    polars_bail!(SQLSyntax: "invalid number of arguments for REGEXP_REPLACE ({})", args.len());
+                }
+                self.try_visit_variadic(|exprs| {
+                    let flags = match exprs.get(3) {
+                        None => "",
+                        Some(Expr::Literal(LiteralValue::String(f))) if f.chars().all(|c| "gimsx".contains(c)) => f.as_str(),
+                        Some(_) => polars_bail!(SQLSyntax: "invalid 'flags' for REGEXP_REPLACE ({})", args[3]),
+                    };
+                    let pat = match &exprs[1] {
+                        Expr::Literal(LiteralValue::String(p)) => {
+                            let inline_flags = flags.replace('g', "");
+                            if inline_flags.is_empty() {
+                                lit(p.clone())
+                            } else {
+                                lit(format!("(?{}){}", inline_flags, p))
+                            }
+                        },
+                        _ => polars_bail!(SQLSyntax: "REGEXP_REPLACE 'pattern' must be a literal string (found {})", args[1]),
+                    };
+                    let value = match &exprs[2] {
+                        Expr::Literal(LiteralValue::String(r)) => lit(regex_replacement(r)),
+                        e => e.clone(),
+                    };
+                    let e = exprs[0].clone();
+                    Ok(if flags.contains('g') {
+                        e.str().replace_all(pat, value, false)
+                    } else {
+                        e.str().replace(pat, value, false)
+                    })
+                })
+            },
             Replace => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1078,6 +1175,7 @@ impl SQLFunctionVisitor<'_> {
                         )),
                 })
             }),
+            RPad => self.try_visit_variadic(|exprs| pad_string(exprs, false)),
             RTrim => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1088,6 +1186,24 @@ impl SQLFunctionVisitor<'_> {
                     },
                 }
             },
+            SplitPart => {
+                let args = extract_args(function)?;
+                self.try_visit_ternary(|e, sep, part| {
+                    // note: 1-indexed, with negative values counting from the end
+                    let idx = match part {
+                        Expr::Literal(LiteralValue::Int(n)) if n > 0 => n - 1,
+                        Expr::Literal(LiteralValue::Int(n)) if n < 0 => n,
+                        _ => polars_bail!(SQLSyntax: "invalid 'part' for SPLIT_PART ({})", args[2]),
+                    };
+                    Ok(when(e.clone().is_null()).then(lit(Null)).otherwise(
+                        e.str()
+                            .split(sep)
+                            .list()
+                            .get(lit(idx), true)
+                            .fill_null(lit("")),
+                    ))
+                })
+            },
             StartsWith => self.visit_binary(|e, s| e.str().starts_with(s)),
             Substring => {
                 let args = extract_args(function)?;
@@ -1127,6 +1243,33 @@ impl SQLFunctionVisitor<'_> {
                     _ => polars_bail!(SQLSyntax: "invalid number of arguments for SUBSTR ({})", args.len()),
                 }
             },
+            Translate => self.try_visit_ternary(|e, from, to| match (from, to) {
+                (
+                    Expr::Literal(LiteralValue::String(from)),
+                    Expr::Literal(LiteralValue::String(to)),
+                ) => {
+                    let (mut patterns, mut replace_with) = (vec![], vec![]);
+                    let mut to = to.chars();
+                    for c in from.chars() {
+                        // characters that occur more than once map by their first occurrence
+                        let r = to.next().map(String::from).unwrap_or_default();
+                        if !patterns.contains(&c.to_string()) {
+                            patterns.push(c.to_string());
+                            replace_with.push(r);
+                        }
+                    }
+                    Ok(if patterns.is_empty() {
+                        e
+                    } else {
+                        e.str().replace_many(
+                            lit(Series::new("", patterns)),
+                            lit(Series::new("", replace_with)),
+                            false,
+                        )
+                    })
+                },
+                _ => polars_bail!(SQLSyntax: "TRANSLATE 'from' and 'to' must be literal strings"),
+            }),
             Upper => self.visit_unary(|e| e.str().to_uppercase()),
 
             // ----
@@ -1135,6 +1278,23 @@ impl SQLFunctionVisitor<'_> {
             Avg => self.visit_unary(Expr::mean),
             Count => self.visit_count(),
             First => self.visit_unary(Expr::first),
+            Grouping => self.try_visit_variadic(|exprs| {
+                let mut mask: Option<Expr> = None;
+                for e in exprs {
+                    let Expr::Column(name) = e else {
+                        polars_bail!(SQLSyntax: "GROUPING arguments must be GROUP BY columns; found {}", e)
+                    };
+                    let bit = col(&grouping_marker_name(name));
+                    mask = Some(match mask {
+                        Some(mask) => mask * lit(2) + bit,
+                        None => bit,
+                    });
+                }
+                match mask {
+                    Some(mask) => Ok(mask.alias("grouping")),
+                    None => polars_bail!(SQLSyntax: "GROUPING requires at least one column"),
+                }
+            }),
             Last => self.visit_unary(Expr::last),
             Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
             Median => self.visit_unary(Expr::median),
@@ -1480,6 +1640,57 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// Pad the string `exprs[0]` at the start (or end) to the literal length `exprs[1]` with the
+/// optional `exprs[2]` fill character (default: space), truncating longer strings.
+fn pad_string(exprs: &[Expr], start: bool) -> PolarsResult<Expr> {
+    let name = if start { "LPAD" } else { "RPAD" };
+    let (length, fill) = match exprs {
+        [_, length] => (length, ' '),
+        [_, length, Expr::Literal(LiteralValue::String(fill))] if fill.chars().count() == 1 => {
+            (length, fill.chars().next().unwrap())
+        },
+        [_, _, fill] => {
+            polars_bail!(SQLSyntax: "{} 'fill' must be a single character (found {:?})", name, fill)
+        },
+        _ => polars_bail!(SQLSyntax: "invalid number of arguments for {} ({})", name, exprs.len()),
+    };
+    let length = match length {
+        Expr::Literal(LiteralValue::Int(n)) if *n >= 0 => *n as usize,
+        _ => polars_bail!(SQLSyntax: "invalid 'length' for {} ({:?})", name, length),
+    };
+    let e = exprs[0].clone();
+    let padded = if start {
+        e.str().pad_start(length, fill)
+    } else {
+        e.str().pad_end(length, fill)
+    };
+    Ok(padded.str().slice(lit(0), lit(length as u64)))
+}
+
+/// Convert the `\1`-style group references (and `\&` for the whole match) of an SQL regex
+/// replacement string to the `${1}` syntax of the regex crate.
+fn regex_replacement(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '$' => out.push_str("$$"),
+            '\\' => match chars.next() {
+                Some(d) if d.is_ascii_digit() => {
+                    out.push_str("${");
+                    out.push(d);
+                    out.push('}');
+                },
+                Some('&') => out.push_str("${0}"),
+                Some(c) => out.push(c),
+                None => out.push('\\'),
+            },
+            c => out.push(c),
+        }
+    }
+    out
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
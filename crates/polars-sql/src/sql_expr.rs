@@ -181,6 +181,24 @@ pub(crate) fn map_sql_polars_datatype(data_type: &SQLDataType) -> PolarsResult<D
     })
 }
 
+/// Make the single column `name` of `lf` available to the outer query, under a unique name.
+fn subplan_column(lf: LazyFrame, name: &str) -> Expr {
+    let rand_string: String = thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(16)
+        .map(char::from)
+        .collect();
+    let new_name = String::from(name) + rand_string.as_str();
+    let lf = lf.rename([name], [new_name.as_str()]);
+    Expr::SubPlan(SpecialEq::new(Arc::new(lf.logical_plan)), vec![new_name])
+}
+
+/// Get the name of the (hidden) group key that marks whether the GROUP BY column `name` is
+/// aggregated in a grouping set, as returned by the SQL `GROUPING` function.
+pub(crate) fn grouping_marker_name(name: &str) -> String {
+    format!("__POLARS_GROUPING_{name}")
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
 pub enum SubqueryRestriction {
@@ -247,6 +265,7 @@ impl SQLExprVisitor<'_> {
             } => self.visit_cast(expr, data_type, format, kind),
             SQLExpr::Ceil { expr, .. } => Ok(self.visit_expr(expr)?.ceil()),
             SQLExpr::CompoundIdentifier(idents) => self.visit_compound_identifier(idents),
+            SQLExpr::Exists { subquery, negated } => self.visit_exists(subquery, *negated),
             SQLExpr::Extract { field, expr } => {
                 parse_extract_date_part(self.visit_expr(expr)?, field)
             },
@@ -316,7 +335,7 @@ impl SQLExprVisitor<'_> {
                     .contains(self.visit_expr(pattern)?, true);
                 Ok(if *negated { matches.not() } else { matches })
             },
-            SQLExpr::Subquery(_) => polars_bail!(SQLInterface: "unexpected subquery"),
+            SQLExpr::Subquery(subquery) => self.visit_scalar_subquery(subquery),
             SQLExpr::Trim {
                 expr,
                 trim_where,
@@ -337,35 +356,44 @@ impl SQLExprVisitor<'_> {
         subquery: &Subquery,
         restriction: SubqueryRestriction,
     ) -> PolarsResult<Expr> {
-        if subquery.with.is_some() {
-            polars_bail!(SQLSyntax: "SQL subquery cannot be a CTE 'WITH' clause");
-        }
-        let mut lf = self.ctx.execute_query_no_ctes(subquery)?;
+        let mut lf = self.execute_subquery(subquery)?;
         let schema = lf.schema_with_arenas(&mut self.ctx.lp_arena, &mut self.ctx.expr_arena)?;
 
         if restriction == SubqueryRestriction::SingleColumn {
             if schema.len() != 1 {
                 polars_bail!(SQLSyntax: "SQL subquery returns more than one column");
             }
-            let rand_string: String = thread_rng()
-                .sample_iter(&Alphanumeric)
-                .take(16)
-                .map(char::from)
-                .collect();
-
-            let schema_entry = schema.get_at_index(0);
-            if let Some((old_name, _)) = schema_entry {
-                let new_name = String::from(old_name.as_str()) + rand_string.as_str();
-                lf = lf.rename([old_name.to_string()], [new_name.clone()]);
-                return Ok(Expr::SubPlan(
-                    SpecialEq::new(Arc::new(lf.logical_plan)),
-                    vec![new_name],
-                ));
+            if let Some((name, _)) = schema.get_at_index(0) {
+                return Ok(subplan_column(lf, name));
             }
         };
         polars_bail!(SQLInterface: "subquery type not supported");
     }
 
+    fn execute_subquery(&mut self, subquery: &Subquery) -> PolarsResult<LazyFrame> {
+        if subquery.with.is_some() {
+            polars_bail!(SQLSyntax: "SQL subquery cannot be a CTE 'WITH' clause");
+        }
+        self.ctx.execute_query_no_ctes(subquery)
+    }
+
+    /// Visit a SQL subquery that is used as a value, which must return a single column. Its
+    /// first value is taken, or null if it returns no rows.
+    fn visit_scalar_subquery(&mut self, subquery: &Subquery) -> PolarsResult<Expr> {
+        Ok(self
+            .visit_subquery(subquery, SubqueryRestriction::SingleColumn)?
+            .first())
+    }
+
+    /// Visit a SQL `EXISTS` expression, which is true if the subquery returns any rows.
+    fn visit_exists(&mut self, subquery: &Subquery, negated: bool) -> PolarsResult<Expr> {
+        let lf = self
+            .execute_subquery(subquery)?
+            .select([len().alias("exists")]);
+        let exists = subplan_column(lf, "exists").first().gt(lit(0));
+        Ok(if negated { exists.not() } else { exists })
+    }
+
     /// Visit a single SQL identifier.
     ///
     /// e.g. column
@@ -414,27 +442,7 @@ impl SQLExprVisitor<'_> {
     }
 
     fn visit_interval(&self, interval: &Interval) -> PolarsResult<Expr> {
-        if interval.last_field.is_some()
-            || interval.leading_field.is_some()
-            || interval.leading_precision.is_some()
-            || interval.fractional_seconds_precision.is_some()
-        {
-            polars_bail!(SQLSyntax: "unsupported interval syntax ('{}')", interval)
-        }
-        let s = match &*interval.value {
-            SQLExpr::UnaryOp { .. } => {
-                polars_bail!(SQLSyntax: "unary ops are not valid on interval strings; found {}", interval.value)
-            },
-            SQLExpr::Value(SQLValue::SingleQuotedString(s)) => Some(s),
-            _ => None,
-        };
-        match s {
-            Some(s) if s.contains('-') => {
-                polars_bail!(SQLInterface: "minus signs are not yet supported in interval strings; found '{}'", s)
-            },
-            Some(s) => Ok(lit(Duration::parse_interval(s))),
-            None => polars_bail!(SQLSyntax: "invalid interval {:?}", interval),
-        }
+        Ok(lit(interval_duration(interval)?))
     }
 
     fn visit_like(
@@ -546,6 +554,30 @@ impl SQLExprVisitor<'_> {
         op: &BinaryOperator,
         right: &SQLExpr,
     ) -> PolarsResult<Expr> {
+        // a Duration literal counts a month as a fixed number of days, so
+        // intervals with months are added/subtracted with `offset_by`
+        let calendar_interval = match (left, op, right) {
+            (_, SQLBinaryOperator::Plus | SQLBinaryOperator::Minus, SQLExpr::Interval(i)) => {
+                Some((left, i))
+            },
+            (SQLExpr::Interval(i), SQLBinaryOperator::Plus, _) => Some((right, i)),
+            _ => None,
+        };
+        if let Some((expr, interval)) = calendar_interval {
+            let duration = interval_duration(interval)?;
+            if duration.months() != 0 {
+                let negative = matches!(op, SQLBinaryOperator::Minus) != duration.negative();
+                let offset = format!(
+                    "{}{}mo{}w{}d{}ns",
+                    if negative { "-" } else { "" },
+                    duration.months(),
+                    duration.weeks(),
+                    duration.days(),
+                    duration.nanoseconds(),
+                );
+                return Ok(self.visit_expr(expr)?.dt().offset_by(lit(offset)));
+            }
+        }
         let left = self.visit_expr(left)?;
         let mut right = self.visit_expr(right)?;
         right = self.convert_temporal_strings(&left, &right);
@@ -772,6 +804,9 @@ impl SQLExprVisitor<'_> {
                 bitstring_to_bytes_literal(b)?
             },
             SQLValue::SingleQuotedString(s) => lit(s.clone()),
+            SQLValue::Placeholder(p) => {
+                Expr::Literal(LiteralValue::try_from(self.ctx.get_param(p)?)?)
+            },
             other => polars_bail!(SQLInterface: "value {:?} is not supported", other),
         })
     }
@@ -826,6 +861,7 @@ impl SQLExprVisitor<'_> {
             SQLValue::SingleQuotedString(s) | SQLValue::DoubleQuotedString(s) => {
                 AnyValue::StringOwned(s.into())
             },
+            SQLValue::Placeholder(p) => self.ctx.get_param(p)?,
             other => polars_bail!(SQLInterface: "value {:?} is not currently supported", other),
         })
     }
@@ -1134,6 +1170,55 @@ pub(crate) fn parse_sql_array(expr: &SQLExpr, ctx: &mut SQLContext) -> PolarsRes
     }
 }
 
+/// Parse the [`Duration`] of an SQL interval, such as `INTERVAL '3 days'` or `INTERVAL '3' DAY`.
+fn interval_duration(interval: &Interval) -> PolarsResult<Duration> {
+    if interval.last_field.is_some()
+        || interval.leading_precision.is_some()
+        || interval.fractional_seconds_precision.is_some()
+    {
+        polars_bail!(SQLSyntax: "unsupported interval syntax ('{}')", interval)
+    }
+    let s = match &*interval.value {
+        SQLExpr::UnaryOp { .. } => {
+            polars_bail!(SQLSyntax: "unary ops are not valid on interval strings; found {}", interval.value)
+        },
+        SQLExpr::Value(SQLValue::SingleQuotedString(s)) => Some(s),
+        SQLExpr::Value(SQLValue::Number(n, _)) if interval.leading_field.is_some() => Some(n),
+        _ => None,
+    };
+    let unit = match &interval.leading_field {
+        None => "",
+        Some(DateTimeField::Year) => "year",
+        Some(DateTimeField::Quarter) => "quarter",
+        Some(DateTimeField::Month) => "month",
+        Some(DateTimeField::Week(None)) => "week",
+        Some(DateTimeField::Day) => "day",
+        Some(DateTimeField::Hour) => "hour",
+        Some(DateTimeField::Minute) => "minute",
+        Some(DateTimeField::Second) => "second",
+        Some(DateTimeField::Millisecond | DateTimeField::Milliseconds) => "millisecond",
+        Some(DateTimeField::Microsecond | DateTimeField::Microseconds) => "microsecond",
+        Some(DateTimeField::Nanosecond | DateTimeField::Nanoseconds) => "nanosecond",
+        Some(field) => {
+            polars_bail!(SQLSyntax: "unsupported interval field '{}' ('{}')", field, interval)
+        },
+    };
+    match s {
+        Some(s) if s.contains('-') => {
+            polars_bail!(SQLInterface: "minus signs are not yet supported in interval strings; found '{}'", s)
+        },
+        Some(s) if !unit.is_empty() => {
+            polars_ensure!(
+                !s.trim().is_empty() && s.trim().chars().all(|c| c.is_ascii_digit()),
+                SQLSyntax: "interval with a '{}' field must have an integer value; found '{}'", unit, s
+            );
+            Ok(Duration::parse_interval(&format!("{} {unit}", s.trim())))
+        },
+        Some(s) => Ok(Duration::parse_interval(s)),
+        None => polars_bail!(SQLSyntax: "invalid interval {:?}", interval),
+    }
+}
+
 pub(crate) fn parse_extract_date_part(expr: Expr, field: &DateTimeField) -> PolarsResult<Expr> {
     let field = match field {
         // handle 'DATE_PART' and all valid abbreviations/alternates
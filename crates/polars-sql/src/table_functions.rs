@@ -1,40 +1,42 @@
 use std::str::FromStr;
 
-#[cfg(any(
-    feature = "csv",
-    feature = "parquet",
-    feature = "ipc",
-    feature = "json"
-))]
-use polars_core::prelude::polars_ensure;
-use polars_core::prelude::{polars_bail, PolarsError, PolarsResult};
+use polars_core::prelude::{polars_bail, polars_ensure, polars_err, PolarsError, PolarsResult};
 #[cfg(feature = "csv")]
 use polars_lazy::prelude::LazyCsvReader;
 use polars_lazy::prelude::LazyFrame;
-use sqlparser::ast::{FunctionArg, FunctionArgExpr};
+use sqlparser::ast::{Expr as SQLExpr, FunctionArg, FunctionArgExpr, Value as SQLValue};
 
-/// Table functions that are supported by Polars
+/// Table functions that are supported by Polars.
+///
+/// The file path (which may be a glob pattern) can be followed by named options.
 #[allow(clippy::enum_variant_names)]
 pub(crate) enum PolarsTableFunctions {
     /// SQL 'read_csv' function
+    /// Options: `has_header`, `separator`, `quote_char`, `skip_rows`, `n_rows`,
+    /// `infer_schema_length`, `try_parse_dates` and `ignore_errors`.
     /// ```sql
     /// SELECT * FROM read_csv('path/to/file.csv')
+    /// SELECT * FROM read_csv('path/to/*.csv', separator => ';', has_header => false)
     /// ```
     #[cfg(feature = "csv")]
     ReadCsv,
     /// SQL 'read_parquet' function
+    /// Options: `n_rows`, `hive_partitioning`, `use_statistics`, `low_memory` and `rechunk`.
     /// ```sql
     /// SELECT * FROM read_parquet('path/to/file.parquet')
+    /// SELECT * FROM read_parquet('path/to/*.parquet', n_rows => 100)
     /// ```
     #[cfg(feature = "parquet")]
     ReadParquet,
     /// SQL 'read_ipc' function
+    /// Options: `n_rows`, `memory_map` and `rechunk`.
     /// ```sql
     /// SELECT * FROM read_ipc('path/to/file.ipc')
     /// ```
     #[cfg(feature = "ipc")]
     ReadIpc,
     /// SQL 'read_json' function. *Only ndjson is currently supported.*
+    /// Options: `n_rows`, `infer_schema_length`, `ignore_errors` and `low_memory`.
     /// ```sql
     /// SELECT * FROM read_json('path/to/file.json')
     /// ```
@@ -79,59 +81,164 @@ impl PolarsTableFunctions {
 
     #[cfg(feature = "csv")]
     fn read_csv(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_csv` expects a single file path; found {:?} arguments", args.len());
-
         use polars_lazy::frame::LazyFileListReader;
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyCsvReader::new(&path)
+
+        let (path, options) = self.get_path_and_options("read_csv", args)?;
+        let mut reader = LazyCsvReader::new(&path)
             .with_try_parse_dates(true)
-            .with_missing_is_null(true)
-            .finish()?;
-        Ok((path, lf))
+            .with_missing_is_null(true);
+        for option in options {
+            reader = match option.name {
+                "has_header" => reader.with_has_header(option.as_bool()?),
+                "separator" => reader.with_separator(option.as_byte()?),
+                "quote_char" => reader.with_quote_char(Some(option.as_byte()?)),
+                "skip_rows" => reader.with_skip_rows(option.as_usize()?),
+                "n_rows" => reader.with_n_rows(Some(option.as_usize()?)),
+                "infer_schema_length" => reader.with_infer_schema_length(Some(option.as_usize()?)),
+                "try_parse_dates" => reader.with_try_parse_dates(option.as_bool()?),
+                "ignore_errors" => reader.with_ignore_errors(option.as_bool()?),
+                _ => return Err(option.unsupported("read_csv")),
+            };
+        }
+        Ok((path, reader.finish()?))
     }
 
     #[cfg(feature = "parquet")]
     fn read_parquet(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_parquet` expects a single file path; found {:?} arguments", args.len());
+        use polars_lazy::prelude::ScanArgsParquet;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_parquet(&path, Default::default())?;
+        let (path, options) = self.get_path_and_options("read_parquet", args)?;
+        let mut scan_args = ScanArgsParquet::default();
+        for option in options {
+            match option.name {
+                "n_rows" => scan_args.n_rows = Some(option.as_usize()?),
+                "hive_partitioning" => scan_args.hive_options.enabled = Some(option.as_bool()?),
+                "use_statistics" => scan_args.use_statistics = option.as_bool()?,
+                "low_memory" => scan_args.low_memory = option.as_bool()?,
+                "rechunk" => scan_args.rechunk = option.as_bool()?,
+                _ => return Err(option.unsupported("read_parquet")),
+            }
+        }
+        let lf = LazyFrame::scan_parquet(&path, scan_args)?;
         Ok((path, lf))
     }
 
     #[cfg(feature = "ipc")]
     fn read_ipc(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_ipc` expects a single file path; found {:?} arguments", args.len());
+        use polars_lazy::prelude::ScanArgsIpc;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_ipc(&path, Default::default())?;
+        let (path, options) = self.get_path_and_options("read_ipc", args)?;
+        let mut scan_args = ScanArgsIpc::default();
+        for option in options {
+            match option.name {
+                "n_rows" => scan_args.n_rows = Some(option.as_usize()?),
+                "memory_map" => scan_args.memory_map = option.as_bool()?,
+                "rechunk" => scan_args.rechunk = option.as_bool()?,
+                _ => return Err(option.unsupported("read_ipc")),
+            }
+        }
+        let lf = LazyFrame::scan_ipc(&path, scan_args)?;
         Ok((path, lf))
     }
     #[cfg(feature = "json")]
     fn read_ndjson(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_ndjson` expects a single file path; found {:?} arguments", args.len());
+        use std::num::NonZeroUsize;
 
         use polars_lazy::frame::LazyFileListReader;
         use polars_lazy::prelude::LazyJsonLineReader;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyJsonLineReader::new(path.clone()).finish()?;
-        Ok((path, lf))
+        let (path, options) = self.get_path_and_options("read_json", args)?;
+        let mut reader = LazyJsonLineReader::new(path.clone());
+        for option in options {
+            reader = match option.name {
+                "n_rows" => reader.with_n_rows(Some(option.as_usize()?)),
+                "infer_schema_length" => {
+                    reader.with_infer_schema_length(NonZeroUsize::new(option.as_usize()?))
+                },
+                "ignore_errors" => reader.with_ignore_errors(option.as_bool()?),
+                "low_memory" => reader.low_memory(option.as_bool()?),
+                _ => return Err(option.unsupported("read_json")),
+            };
+        }
+        Ok((path, reader.finish()?))
     }
 
+    /// Get the file path, which is the single unnamed argument, and the `name => value` options
+    /// from the arguments of a table function.
     #[allow(dead_code)]
-    fn get_file_path_from_arg(&self, arg: &FunctionArg) -> PolarsResult<String> {
-        use sqlparser::ast::{Expr as SQLExpr, Value as SQLValue};
-        match arg {
+    fn get_path_and_options<'a>(
+        &self,
+        func_name: &str,
+        args: &'a [FunctionArg],
+    ) -> PolarsResult<(String, Vec<TableFunctionOption<'a>>)> {
+        let (mut paths, mut options) = (vec![], vec![]);
+        for arg in args {
+            match arg {
+                FunctionArg::Unnamed(_) => paths.push(arg),
+                FunctionArg::Named {
+                    name,
+                    arg: FunctionArgExpr::Expr(SQLExpr::Value(value)),
+                    ..
+                } => options.push(TableFunctionOption {
+                    name: name.value.as_str(),
+                    value,
+                }),
+                FunctionArg::Named { name, arg, .. } => polars_bail!(
+                    SQLSyntax: "`{}` option '{}' must be a literal value; found {}", func_name, name, arg
+                ),
+            }
+        }
+        polars_ensure!(paths.len() == 1, SQLSyntax: "`{}` expects a single file path; found {:?} arguments", func_name, paths.len());
+        let path = match paths[0] {
             FunctionArg::Unnamed(FunctionArgExpr::Expr(SQLExpr::Value(
                 SQLValue::SingleQuotedString(s),
-            ))) => Ok(s.to_string()),
-            _ => polars_bail!(
+            ))) => s.to_string(),
+            arg => polars_bail!(
                 SQLSyntax:
                 "expected a valid file path as a single-quoted string; found: {}", arg,
             ),
+        };
+        Ok((path, options))
+    }
+}
+
+/// A `name => value` option of a table function.
+#[allow(dead_code)]
+struct TableFunctionOption<'a> {
+    name: &'a str,
+    value: &'a SQLValue,
+}
+
+#[allow(dead_code)]
+impl TableFunctionOption<'_> {
+    fn as_bool(&self) -> PolarsResult<bool> {
+        match self.value {
+            SQLValue::Boolean(b) => Ok(*b),
+            v => polars_bail!(SQLSyntax: "option '{}' expects a boolean; found {}", self.name, v),
+        }
+    }
+
+    fn as_usize(&self) -> PolarsResult<usize> {
+        match self.value {
+            SQLValue::Number(n, _) if n.parse::<usize>().is_ok() => Ok(n.parse().unwrap()),
+            v => {
+                polars_bail!(SQLSyntax: "option '{}' expects a non-negative integer; found {}", self.name, v)
+            },
         }
     }
+
+    fn as_byte(&self) -> PolarsResult<u8> {
+        match self.value {
+            SQLValue::SingleQuotedString(s) if s.len() == 1 => Ok(s.as_bytes()[0]),
+            v => {
+                polars_bail!(SQLSyntax: "option '{}' expects a single (ASCII) character; found {}", self.name, v)
+            },
+        }
+    }
+
+    fn unsupported(&self, func_name: &str) -> PolarsError {
+        polars_err!(SQLSyntax: "`{}` does not support the '{}' option", func_name, self.name)
+    }
 }
 
 impl PolarsTableFunctions {
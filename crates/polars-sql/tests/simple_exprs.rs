@@ -527,6 +527,21 @@ fn test_ctes() -> PolarsResult<()> {
     let sql2 = r#"SELECT * FROM df0"#;
     assert!(context.execute(sql2).is_err());
 
+    // a CTE that is referenced multiple times is computed once
+    let sql3 = r#"
+        WITH df0 AS (SELECT a, SUM(b) AS b FROM df GROUP BY a),
+             df1 AS (SELECT * FROM df0 WHERE b > 100000)
+        SELECT COUNT(*) AS n FROM df0 UNION ALL SELECT COUNT(*) AS n FROM df1
+    "#;
+    let lf = context.execute(sql3)?;
+    assert!(lf.describe_optimized_plan()?.contains("CACHE"));
+    let out = lf.collect()?;
+    assert_eq!(out.column("n")?.get(0)?, AnyValue::UInt32(100));
+
+    // a CTE shadows a table of the same name
+    let sql4 = r#"WITH df AS (SELECT b FROM df WHERE a = 0) SELECT * FROM df"#;
+    assert_eq!(context.execute(sql4)?.collect()?.shape(), (99, 1));
+
     Ok(())
 }
 
@@ -644,3 +659,30 @@ fn test_iss_9471() {
     let res = context.execute(sql);
     assert!(res.is_err())
 }
+
+#[test]
+fn test_execute_with_params() -> PolarsResult<()> {
+    let df = create_sample_df()?;
+    let mut context = SQLContext::new();
+    context.register("df", df.clone().lazy());
+
+    let params = [AnyValue::Int64(10), AnyValue::Int64(20)];
+    let expected = df
+        .clone()
+        .lazy()
+        .filter(col("a").gt(lit(10i64)).and(col("a").lt(lit(20i64))))
+        .collect()?;
+    for sql in [
+        "SELECT * FROM df WHERE a > $1 AND a < $2",
+        "SELECT * FROM df WHERE a > ? AND a < ?",
+    ] {
+        let df_sql = context.execute_with_params(sql, &params)?.collect()?;
+        assert!(df_sql.equals(&expected));
+    }
+
+    let res = context.execute_with_params("SELECT * FROM df WHERE a > $3", &params);
+    assert!(res.is_err());
+    // parameters are only bound for the query they are passed with
+    assert!(context.execute("SELECT * FROM df WHERE a > $1").is_err());
+    Ok(())
+}
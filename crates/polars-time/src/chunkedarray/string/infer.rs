@@ -210,6 +210,51 @@ impl StrpTimeParser<i32> for DatetimeInfer<Int32Type> {
     }
 }
 
+/// Parse a value with an explicit, user provided format.
+pub trait FormatParser: PolarsNumericType {
+    fn parse_with_format(val: &str, fmt: &str, time_unit: Option<TimeUnit>)
+        -> Option<Self::Native>;
+}
+
+#[cfg(feature = "dtype-datetime")]
+impl FormatParser for Int64Type {
+    fn parse_with_format(val: &str, fmt: &str, time_unit: Option<TimeUnit>) -> Option<i64> {
+        match time_unit.expect("time_unit must be provided for datetime") {
+            TimeUnit::Nanoseconds => transform_datetime_ns(val, fmt),
+            TimeUnit::Microseconds => transform_datetime_us(val, fmt),
+            TimeUnit::Milliseconds => transform_datetime_ms(val, fmt),
+        }
+    }
+}
+
+#[cfg(feature = "dtype-date")]
+impl FormatParser for Int32Type {
+    fn parse_with_format(val: &str, fmt: &str, _time_unit: Option<TimeUnit>) -> Option<i32> {
+        transform_date(val, fmt)
+    }
+}
+
+/// Infer the temporal data type of `val` from the first of `formats` that parses it.
+///
+/// Formats that contain a time component yield a `Datetime`, date-only formats a `Date`.
+#[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+pub fn infer_dtype_with_formats(val: &str, formats: &[String]) -> Option<DataType> {
+    formats
+        .iter()
+        .find_map(|fmt| match NaiveDateTime::parse_from_str(val, fmt) {
+            Ok(_) => Some(DataType::Datetime(TimeUnit::Microseconds, None)),
+            Err(parse_error) => match parse_error.kind() {
+                ParseErrorKind::NotEnough => NaiveDate::parse_from_str(val, fmt)
+                    .ok()
+                    .map(|_| DataType::Date),
+                _ => None,
+            },
+        })
+}
+
+/// Number of non-null values used to detect the format of a column.
+const FORMAT_SAMPLE_SIZE: usize = 64;
+
 #[derive(Clone)]
 pub struct DatetimeInfer<T: PolarsNumericType> {
     pub pattern: Pattern,
@@ -217,6 +262,7 @@ pub struct DatetimeInfer<T: PolarsNumericType> {
     latest_fmt: &'static str,
     transform: fn(&str, &str) -> Option<T::Native>,
     transform_bytes: StrpTimeState,
+    from_naive: fn(NaiveDateTime) -> T::Native,
     fmt_len: u16,
     pub logical_type: DataType,
 }
@@ -233,6 +279,11 @@ impl TryFromWithUnit<Pattern> for DatetimeInfer<Int64Type> {
     fn try_from_with_unit(value: Pattern, time_unit: Option<TimeUnit>) -> PolarsResult<Self> {
         let time_unit = time_unit.expect("time_unit must be provided for datetime");
 
+        let from_naive = match time_unit {
+            TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+            TimeUnit::Microseconds => datetime_to_timestamp_us,
+            TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+        };
         let transform = match (time_unit, value) {
             (TimeUnit::Milliseconds, Pattern::DatetimeYMDZ) => transform_tzaware_datetime_ms,
             (TimeUnit::Milliseconds, _) => transform_datetime_ms,
@@ -257,6 +308,7 @@ impl TryFromWithUnit<Pattern> for DatetimeInfer<Int64Type> {
             latest_fmt: patterns[0],
             transform,
             transform_bytes: StrpTimeState::default(),
+            from_naive,
             fmt_len: 0,
             logical_type: DataType::Datetime(time_unit, None),
         })
@@ -275,6 +327,7 @@ impl TryFromWithUnit<Pattern> for DatetimeInfer<Int32Type> {
                 latest_fmt: patterns::DATE_D_M_Y[0],
                 transform: transform_date,
                 transform_bytes: StrpTimeState::default(),
+                from_naive: |ndt| naive_date_to_date(ndt.date()),
                 fmt_len: 0,
                 logical_type: DataType::Date,
             }),
@@ -284,6 +337,7 @@ impl TryFromWithUnit<Pattern> for DatetimeInfer<Int32Type> {
                 latest_fmt: patterns::DATE_Y_M_D[0],
                 transform: transform_date,
                 transform_bytes: StrpTimeState::default(),
+                from_naive: |ndt| naive_date_to_date(ndt.date()),
                 fmt_len: 0,
                 logical_type: DataType::Date,
             }),
@@ -318,11 +372,51 @@ impl<T: PolarsNumericType> DatetimeInfer<T>
 where
     ChunkedArray<T>: IntoSeries,
 {
+    /// Set the format to the one of this pattern that parses most of a sample of `ca`.
+    fn detect_format(&mut self, ca: &StringChunked) {
+        let sample = ca
+            .iter()
+            .flatten()
+            .take(FORMAT_SAMPLE_SIZE)
+            .collect::<Vec<_>>();
+
+        let mut best_count = 0;
+        for fmt in self.patterns {
+            let count = sample
+                .iter()
+                .filter(|val| (self.transform)(val, fmt).is_some())
+                .count();
+            if count > best_count {
+                best_count = count;
+                self.latest_fmt = fmt;
+                if count == sample.len() {
+                    break;
+                }
+            }
+        }
+    }
+
     fn coerce_string(&mut self, ca: &StringChunked) -> Series {
+        self.detect_format(ca);
+        let fmt = self.latest_fmt;
+        let fmt_len = strptime::fmt_len(fmt.as_bytes());
+        let from_naive = self.from_naive;
+
         let chunks = ca.downcast_iter().map(|array| {
-            let iter = array
-                .into_iter()
-                .map(|opt_val| opt_val.and_then(|val| self.parse(val)));
+            let iter = array.into_iter().map(|opt_val| {
+                opt_val.and_then(|val| {
+                    // Apply the detected format with our own parser and only
+                    // fall back to chrono and the other formats for stragglers.
+                    fmt_len
+                        .and_then(|fmt_len| unsafe {
+                            // SAFETY: fmt_len was computed with this `fmt`.
+                            self.transform_bytes
+                                .parse(val.as_bytes(), fmt.as_bytes(), fmt_len)
+                        })
+                        .map(from_naive)
+                        .or_else(|| self.parse(val))
+                })
+            });
             PrimitiveArray::from_trusted_len_iter(iter)
         });
         ChunkedArray::from_chunk_iter(ca.name(), chunks)
@@ -496,3 +590,37 @@ pub(crate) fn to_date(ca: &StringChunked) -> PolarsResult<DateChunked> {
         },
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_to_datetime_detects_format_and_parses_stragglers() {
+        let ca = StringChunked::new(
+            "a",
+            &[
+                Some("2021-01-01 10:00:00"),
+                None,
+                Some("2021-01-02 11:30:00"),
+                Some("2021/01/03 12:00:00"),
+                Some("2021-01-04T13:00"),
+            ],
+        );
+        let ambiguous = StringChunked::new("", &["raise"]);
+        let out = to_datetime(&ca, TimeUnit::Milliseconds, None, &ambiguous).unwrap();
+
+        let hour = 3_600_000;
+        let day = 24 * hour;
+        let jan_1 = 1_609_459_200_000;
+        let expected = [
+            Some(jan_1 + 10 * hour),
+            None,
+            Some(jan_1 + day + 11 * hour + 30 * 60_000),
+            Some(jan_1 + 2 * day + 12 * hour),
+            Some(jan_1 + 3 * day + 13 * hour),
+        ];
+        assert_eq!(out.0.into_iter().collect::<Vec<_>>(), expected);
+    }
+}
@@ -586,6 +586,10 @@ impl Wrap<&DataFrame> {
                             let dt = dt_local.slice(base_g[0] as i64, base_g[1] as usize);
                             let vals = dt.downcast_iter().next().unwrap();
                             let ts = vals.values().as_slice();
+                            if !matches!(dt.is_sorted_flag(), IsSorted::Ascending) {
+                                check_sortedness_slice(ts)?
+                            }
+
                             let sub_groups = group_by_values(
                                 options.period,
                                 options.offset,
@@ -792,6 +796,37 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_rolling_group_by_keys() -> PolarsResult<()> {
+        let date = Int64Chunked::new("dt", [1, 2, 3, 1, 2, 4])
+            .into_datetime(TimeUnit::Milliseconds, None)
+            .into_series();
+        let a = Series::new("a", [1, 2, 3, 10, 20, 30]);
+        let mut keys = Series::new("keys", [1, 1, 1, 2, 2, 2]);
+        keys.set_sorted_flag(IsSorted::Ascending);
+        let df = DataFrame::new(vec![date, a.clone(), keys.clone()])?;
+
+        let options = RollingGroupOptions {
+            index_column: "dt".into(),
+            period: Duration::parse("2ms"),
+            offset: Duration::parse("-2ms"),
+            closed_window: ClosedWindow::Right,
+        };
+        let (_, _, groups) = df.rolling(vec![keys.clone()], &options)?;
+        let sum = unsafe { a.agg_sum(&groups) };
+        let expected = Series::new("", [1, 3, 5, 10, 30, 30]);
+        assert_eq!(sum, expected);
+
+        // The index column has to be sorted within every group.
+        let date = Int64Chunked::new("dt", [1, 3, 2, 1, 2, 4])
+            .into_datetime(TimeUnit::Milliseconds, None)
+            .into_series();
+        let df = DataFrame::new(vec![date, a, keys.clone()])?;
+        assert!(df.rolling(vec![keys], &options).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_dynamic_group_by_window() -> PolarsResult<()> {
         let start = NaiveDate::from_ymd_opt(2021, 12, 16)
@@ -1,5 +1,5 @@
 use arrow::legacy::time_zone::Tz;
-use polars_core::prelude::arity::broadcast_try_binary_elementwise;
+use polars_core::prelude::arity::{broadcast_binary_elementwise, broadcast_try_binary_elementwise};
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 
@@ -57,7 +57,39 @@ fn apply_offsets_to_datetime(
     }
 }
 
+/// Offset `ts` by the fixed durations of a `Duration` Series, which don't depend on the
+/// calendar or the time zone.
+fn impl_offset_by_duration(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
+    let datetime = match ts.dtype() {
+        DataType::Date => ts.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+        DataType::Datetime(_, _) => ts.clone(),
+        dt => polars_bail!(
+            ComputeError: "cannot use 'offset_by' on Series of datatype {}", dt,
+        ),
+    };
+    let datetime = datetime.datetime().unwrap();
+    let offsets = offsets.cast(&DataType::Duration(datetime.time_unit()))?;
+    let offsets = offsets.duration().unwrap();
+    let out: Int64Chunked = broadcast_binary_elementwise(datetime, offsets, |t, offset| {
+        Some(t?.wrapping_add(offset?))
+    });
+    let mut out = out
+        .into_datetime(datetime.time_unit(), datetime.time_zone().clone())
+        .into_series()
+        .cast(ts.dtype())?;
+    // Adding the same duration to every datetime preserves the order.
+    if offsets.len() == 1 && offsets.get(0).is_some() {
+        out.set_sorted_flag(ts.is_sorted_flag());
+    } else {
+        out.set_sorted_flag(IsSorted::Not);
+    }
+    Ok(out)
+}
+
 pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
+    if let DataType::Duration(_) = offsets.dtype() {
+        return impl_offset_by_duration(ts, offsets);
+    }
     let preserve_sortedness: bool;
     let offsets = offsets.str()?;
     let out = match ts.dtype() {
@@ -3,17 +3,33 @@ use arrow::temporal_conversions::{MILLISECONDS, SECONDS_IN_DAY};
 use polars_core::prelude::arity::broadcast_try_binary_elementwise;
 use polars_core::prelude::*;
 use polars_utils::cache::FastFixedCache;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 
+/// Which window boundary to round to if a value lies exactly halfway between two of them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RoundTies {
+    /// Round to the later boundary.
+    #[default]
+    Up,
+    /// Round to the earlier boundary.
+    Down,
+}
+
 pub trait PolarsRound {
-    fn round(&self, every: &StringChunked, tz: Option<&Tz>) -> PolarsResult<Self>
+    /// Round every value to the nearest boundary of the windows of length `every`. Calendar
+    /// durations, such as `"1mo"` or `"1q"`, round to the nearest calendar boundary, as
+    /// truncating by them does.
+    fn round(&self, every: &StringChunked, tz: Option<&Tz>, ties: RoundTies) -> PolarsResult<Self>
     where
         Self: Sized;
 }
 
 impl PolarsRound for DatetimeChunked {
-    fn round(&self, every: &StringChunked, tz: Option<&Tz>) -> PolarsResult<Self> {
+    fn round(&self, every: &StringChunked, tz: Option<&Tz>, ties: RoundTies) -> PolarsResult<Self> {
         let mut duration_cache = FastFixedCache::new((every.len() as f64).sqrt() as usize);
         let offset = Duration::new(0);
         let out = broadcast_try_binary_elementwise(self, every, |opt_t, opt_every| {
@@ -33,7 +49,7 @@ impl PolarsRound for DatetimeChunked {
                         TimeUnit::Microseconds => Window::round_us,
                         TimeUnit::Milliseconds => Window::round_ms,
                     };
-                    func(&w, timestamp, tz).map(Some)
+                    func(&w, timestamp, tz, ties).map(Some)
                 },
                 _ => Ok(None),
             }
@@ -43,7 +59,12 @@ impl PolarsRound for DatetimeChunked {
 }
 
 impl PolarsRound for DateChunked {
-    fn round(&self, every: &StringChunked, _tz: Option<&Tz>) -> PolarsResult<Self> {
+    fn round(
+        &self,
+        every: &StringChunked,
+        _tz: Option<&Tz>,
+        ties: RoundTies,
+    ) -> PolarsResult<Self> {
         let mut duration_cache = FastFixedCache::new((every.len() as f64).sqrt() as usize);
         let offset = Duration::new(0);
         const MSECS_IN_DAY: i64 = MILLISECONDS * SECONDS_IN_DAY;
@@ -58,7 +79,7 @@ impl PolarsRound for DateChunked {
 
                     let w = Window::new(every, every, offset);
                     Ok(Some(
-                        (w.round_ms(MSECS_IN_DAY * t as i64, None)? / MSECS_IN_DAY) as i32,
+                        (w.round_ms(MSECS_IN_DAY * t as i64, None, ties)? / MSECS_IN_DAY) as i32,
                     ))
                 },
                 _ => Ok(None),
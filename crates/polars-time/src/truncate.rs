@@ -3,19 +3,55 @@ use arrow::temporal_conversions::MILLISECONDS_IN_DAY;
 use polars_core::prelude::arity::broadcast_try_binary_elementwise;
 use polars_core::prelude::*;
 use polars_utils::cache::FastFixedCache;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 
+/// The first day of a week, for truncating to weeks and for numbering weeks and weekdays.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WeekStart {
+    /// The ISO 8601 start of the week.
+    #[default]
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl WeekStart {
+    /// The number of days from Monday to the start of the week.
+    pub fn days_from_monday(self) -> i64 {
+        self as i64
+    }
+}
+
 pub trait PolarsTruncate {
-    fn truncate(&self, tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self>
+    /// Truncate every value to the start of its window of length `every`. Truncating to
+    /// weeks gives the last `week_start` day at or before the value.
+    fn truncate(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        week_start: WeekStart,
+    ) -> PolarsResult<Self>
     where
         Self: Sized;
 }
 
 impl PolarsTruncate for DatetimeChunked {
-    fn truncate(&self, tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self> {
+    fn truncate(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        week_start: WeekStart,
+    ) -> PolarsResult<Self> {
         let time_zone = self.time_zone();
-        let offset = Duration::new(0);
+        let tu = self.time_unit();
 
         // Let's check if we can use a fastpath...
         if every.len() == 1 {
@@ -41,18 +77,9 @@ impl PolarsTruncate for DatetimeChunked {
                         })
                         .into_datetime(self.time_unit(), time_zone.clone()));
                 } else {
-                    let w = Window::new(every_parsed, every_parsed, offset);
-                    let out = match self.time_unit() {
-                        TimeUnit::Milliseconds => {
-                            self.try_apply_nonnull_values_generic(|t| w.truncate_ms(t, tz))
-                        },
-                        TimeUnit::Microseconds => {
-                            self.try_apply_nonnull_values_generic(|t| w.truncate_us(t, tz))
-                        },
-                        TimeUnit::Nanoseconds => {
-                            self.try_apply_nonnull_values_generic(|t| w.truncate_ns(t, tz))
-                        },
-                    };
+                    let out = self.try_apply_nonnull_values_generic(|t| {
+                        every_parsed.truncate_with_week_start(t, tu, tz, week_start)
+                    });
                     return Ok(out?.into_datetime(self.time_unit(), self.time_zone().clone()));
                 }
             } else {
@@ -64,12 +91,6 @@ impl PolarsTruncate for DatetimeChunked {
         // A sqrt(n) cache is not too small, not too large.
         let mut duration_cache = FastFixedCache::new((every.len() as f64).sqrt() as usize);
 
-        let func = match self.time_unit() {
-            TimeUnit::Nanoseconds => Window::truncate_ns,
-            TimeUnit::Microseconds => Window::truncate_us,
-            TimeUnit::Milliseconds => Window::truncate_ms,
-        };
-
         let out = broadcast_try_binary_elementwise(self, every, |opt_timestamp, opt_every| match (
             opt_timestamp,
             opt_every,
@@ -82,8 +103,9 @@ impl PolarsTruncate for DatetimeChunked {
                     polars_bail!(ComputeError: "cannot truncate a Datetime to a negative duration")
                 }
 
-                let w = Window::new(every, every, offset);
-                func(&w, timestamp, tz).map(Some)
+                every
+                    .truncate_with_week_start(timestamp, tu, tz, week_start)
+                    .map(Some)
             },
             _ => Ok(None),
         });
@@ -92,8 +114,17 @@ impl PolarsTruncate for DatetimeChunked {
 }
 
 impl PolarsTruncate for DateChunked {
-    fn truncate(&self, _tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self> {
-        let offset = Duration::new(0);
+    fn truncate(
+        &self,
+        _tz: Option<&Tz>,
+        every: &StringChunked,
+        week_start: WeekStart,
+    ) -> PolarsResult<Self> {
+        let truncate = |every: &Duration, t: i32| -> PolarsResult<i32> {
+            let t = MILLISECONDS_IN_DAY * t as i64;
+            let t = every.truncate_with_week_start(t, TimeUnit::Milliseconds, None, week_start)?;
+            Ok((t / MILLISECONDS_IN_DAY) as i32)
+        };
         let out = match every.len() {
             1 => {
                 if let Some(every) = every.get(0) {
@@ -101,11 +132,7 @@ impl PolarsTruncate for DateChunked {
                     if every.negative {
                         polars_bail!(ComputeError: "cannot truncate a Date to a negative duration")
                     }
-                    let w = Window::new(every, every, offset);
-                    self.try_apply_nonnull_values_generic(|t| {
-                        Ok((w.truncate_ms(MILLISECONDS_IN_DAY * t as i64, None)?
-                            / MILLISECONDS_IN_DAY) as i32)
-                    })
+                    self.try_apply_nonnull_values_generic(|t| truncate(&every, t))
                 } else {
                     Ok(Int32Chunked::full_null(self.name(), self.len()))
                 }
@@ -122,11 +149,7 @@ impl PolarsTruncate for DateChunked {
                             polars_bail!(ComputeError: "cannot truncate a Date to a negative duration")
                         }
 
-                        let w = Window::new(every, every, offset);
-                        Ok(Some(
-                            (w.truncate_ms(MILLISECONDS_IN_DAY * t as i64, None)?
-                                / MILLISECONDS_IN_DAY) as i32,
-                        ))
+                        truncate(&every, t).map(Some)
                     },
                     _ => Ok(None),
                 }
@@ -10,7 +10,7 @@ use arrow::temporal_conversions::{
     NANOSECONDS,
 };
 use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
-use polars_core::datatypes::DataType;
+use polars_core::datatypes::{DataType, TimeUnit};
 use polars_core::export::arrow::temporal_conversions::MICROSECONDS;
 use polars_core::prelude::{
     datetime_to_timestamp_ms, datetime_to_timestamp_ns, datetime_to_timestamp_us, polars_bail,
@@ -26,6 +26,7 @@ use super::calendar::{
 #[cfg(feature = "timezones")]
 use crate::utils::{localize_datetime_opt, try_localize_datetime, unlocalize_datetime};
 use crate::windows::calendar::{is_leap_year, DAYS_PER_MONTH};
+use crate::WeekStart;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -150,12 +151,133 @@ impl Duration {
     /// Similarly for "calendar week", "calendar month", "calendar quarter",
     /// and "calendar year".
     ///
+    /// ISO 8601 durations, such as `"P1DT2H30M"` or `"PT0.5S"`, are accepted as well, see
+    /// [`Duration::to_iso_8601`] for the supported form.
+    ///
     /// # Panics
     /// If the given str is invalid for any reason.
     pub fn parse(duration: &str) -> Self {
+        let (negative, unsigned) = match duration.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, duration),
+        };
+        if let Some(iso) = unsigned.strip_prefix('P') {
+            return Self::parse_iso_8601(iso, negative);
+        }
         Self::_parse(duration, false)
     }
 
+    /// Parse the part of an ISO 8601 duration after the `P`.
+    fn parse_iso_8601(s: &str, negative: bool) -> Self {
+        fn invalid(s: &str) -> ! {
+            panic!("invalid ISO 8601 duration string 'P{s}', expected e.g. 'P1DT2H30M'")
+        }
+
+        let mut months = 0;
+        let mut weeks = 0;
+        let mut days = 0;
+        let mut nsecs = 0;
+
+        let mut rest = s;
+        let mut in_time = false;
+        if rest.is_empty() {
+            invalid(s)
+        }
+        while !rest.is_empty() {
+            if let Some(time) = rest.strip_prefix('T') {
+                if in_time || time.is_empty() {
+                    invalid(s)
+                }
+                in_time = true;
+                rest = time;
+                continue;
+            }
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or_else(|| invalid(s));
+            let number = &rest[..end];
+            let unit = rest[end..].chars().next().unwrap();
+            rest = &rest[end + unit.len_utf8()..];
+
+            // Only seconds may have a fraction.
+            if in_time && unit == 'S' {
+                let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+                if fraction.len() > 9 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                    invalid(s)
+                }
+                let whole = whole.parse::<i64>().unwrap_or_else(|_| invalid(s));
+                let fraction = format!("{fraction:0<9}").parse::<i64>().unwrap();
+                nsecs += whole * NS_SECOND + fraction;
+                continue;
+            }
+            let n = number.parse::<i64>().unwrap_or_else(|_| invalid(s));
+            match (in_time, unit) {
+                (false, 'Y') => months += n * 12,
+                (false, 'M') => months += n,
+                (false, 'W') => weeks += n,
+                (false, 'D') => days += n,
+                (true, 'H') => nsecs += n * NS_HOUR,
+                (true, 'M') => nsecs += n * NS_MINUTE,
+                _ => invalid(s),
+            }
+        }
+        Duration {
+            months,
+            weeks,
+            days,
+            nsecs,
+            negative,
+            parsed_int: false,
+        }
+    }
+
+    /// Format the duration as an ISO 8601 duration, such as `"P1Y2M3W4DT5H6M7.5S"`.
+    ///
+    /// Months are written as years and months, and nanoseconds as hours, minutes and
+    /// (fractional) seconds. Negative durations get a leading minus sign. Integer durations,
+    /// e.g. `"2i"`, have no ISO 8601 form.
+    pub fn to_iso_8601(&self) -> PolarsResult<String> {
+        polars_ensure!(
+            !self.parsed_int,
+            InvalidOperation: "an integer duration ('{}i') has no ISO 8601 form", self.nsecs
+        );
+        if self.is_zero() {
+            return Ok("PT0S".to_string());
+        }
+        let mut out = String::from(if self.negative { "-P" } else { "P" });
+        let (years, months) = (self.months / 12, self.months % 12);
+        for (n, unit) in [
+            (years, 'Y'),
+            (months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if n > 0 {
+                out.push_str(&format!("{n}{unit}"));
+            }
+        }
+        if self.nsecs > 0 {
+            out.push('T');
+            let (hours, minutes) = (self.nsecs / NS_HOUR, self.nsecs % NS_HOUR / NS_MINUTE);
+            for (n, unit) in [(hours, 'H'), (minutes, 'M')] {
+                if n > 0 {
+                    out.push_str(&format!("{n}{unit}"));
+                }
+            }
+            let nsecs = self.nsecs % NS_MINUTE;
+            if nsecs > 0 {
+                let (secs, fraction) = (nsecs / NS_SECOND, nsecs % NS_SECOND);
+                if fraction == 0 {
+                    out.push_str(&format!("{secs}S"));
+                } else {
+                    let fraction = format!("{fraction:09}");
+                    out.push_str(&format!("{secs}.{}S", fraction.trim_end_matches('0')));
+                }
+            }
+        }
+        Ok(out)
+    }
+
     #[doc(hidden)]
     /// Parse SQL-style "interval" string to Duration. Handles verbose
     /// units (such as 'year', 'minutes', etc.) and whitespace, as
@@ -627,6 +749,7 @@ impl Duration {
         _timestamp_to_datetime: G,
         _datetime_to_timestamp: J,
         daily_duration: i64,
+        week_start: WeekStart,
     ) -> PolarsResult<i64>
     where
         G: Fn(i64) -> NaiveDateTime,
@@ -652,8 +775,10 @@ impl Duration {
         //   t - (t % (7 * self.weeks * daily_duration))
         // then the timestamp would get truncated to the previous Thursday,
         // because 1970-01-01 (timestamp 0) is a Thursday.
-        // So, we adjust by 4 days to get to Monday.
-        let mut remainder = (t - 4 * daily_duration) % (7 * self.weeks * daily_duration);
+        // So, we adjust by 4 days to get to Monday, and further to get to the start of the week.
+        let days_to_week_start = 4 + week_start.days_from_monday();
+        let mut remainder =
+            (t - days_to_week_start * daily_duration) % (7 * self.weeks * daily_duration);
         if remainder < 0 {
             remainder += 7 * self.weeks * daily_duration
         }
@@ -766,6 +891,7 @@ impl Duration {
         nsecs_to_unit: F,
         timestamp_to_datetime: G,
         datetime_to_timestamp: J,
+        week_start: WeekStart,
     ) -> PolarsResult<i64>
     where
         F: Fn(i64) -> i64,
@@ -805,6 +931,7 @@ impl Duration {
                     timestamp_to_datetime,
                     datetime_to_timestamp,
                     duration,
+                    week_start,
                 )
             },
             // truncate by months
@@ -833,6 +960,7 @@ impl Duration {
             |nsecs| nsecs,
             timestamp_ns_to_datetime,
             datetime_to_timestamp_ns,
+            WeekStart::Monday,
         )
     }
 
@@ -845,6 +973,7 @@ impl Duration {
             |nsecs| nsecs / 1000,
             timestamp_us_to_datetime,
             datetime_to_timestamp_us,
+            WeekStart::Monday,
         )
     }
 
@@ -857,9 +986,47 @@ impl Duration {
             |nsecs| nsecs / 1_000_000,
             timestamp_ms_to_datetime,
             datetime_to_timestamp_ms,
+            WeekStart::Monday,
         )
     }
 
+    /// Truncate the given timestamp in `tu` by the window boundary, where weeks start on
+    /// `week_start` instead of Monday.
+    pub fn truncate_with_week_start(
+        &self,
+        t: i64,
+        tu: TimeUnit,
+        tz: Option<&Tz>,
+        week_start: WeekStart,
+    ) -> PolarsResult<i64> {
+        match tu {
+            TimeUnit::Nanoseconds => self.truncate_impl(
+                t,
+                tz,
+                |nsecs| nsecs,
+                timestamp_ns_to_datetime,
+                datetime_to_timestamp_ns,
+                week_start,
+            ),
+            TimeUnit::Microseconds => self.truncate_impl(
+                t,
+                tz,
+                |nsecs| nsecs / 1000,
+                timestamp_us_to_datetime,
+                datetime_to_timestamp_us,
+                week_start,
+            ),
+            TimeUnit::Milliseconds => self.truncate_impl(
+                t,
+                tz,
+                |nsecs| nsecs / 1_000_000,
+                timestamp_ms_to_datetime,
+                datetime_to_timestamp_ms,
+                week_start,
+            ),
+        }
+    }
+
     fn add_impl_month_week_or_day<F, G, J>(
         &self,
         t: i64,
@@ -1073,6 +1240,44 @@ mod test {
         assert_eq!(out.weeks(), 5);
     }
 
+    #[test]
+    fn test_parse_iso_8601() {
+        assert_eq!(Duration::parse("P1DT2H30M"), Duration::parse("1d2h30m"));
+        assert_eq!(Duration::parse("PT0.5S"), Duration::parse("500ms"));
+        assert_eq!(Duration::parse("-P1Y2M3W"), Duration::parse("-1y2mo3w"));
+        assert_eq!(Duration::parse("PT1M"), Duration::parse("1m"));
+        assert_eq!(Duration::parse("P1M"), Duration::parse("1mo"));
+
+        for duration in ["P1Y2M3W4DT5H6M7.008S", "-PT0.000000001S", "P2D", "PT0S"] {
+            assert_eq!(Duration::parse(duration).to_iso_8601().unwrap(), duration);
+        }
+        assert_eq!(Duration::parse("1h90s").to_iso_8601().unwrap(), "PT1H1M30S");
+        assert!(Duration::parse("2i").to_iso_8601().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid ISO 8601 duration")]
+    fn test_parse_iso_8601_invalid() {
+        Duration::parse("P1H");
+    }
+
+    #[test]
+    fn test_truncate_with_week_start() {
+        const MS_DAY: i64 = 86_400_000;
+        // 2024-01-03, a Wednesday.
+        let t = 19725 * MS_DAY;
+        let week = Duration::parse("1w");
+        let truncate = |week_start| {
+            week.truncate_with_week_start(t, TimeUnit::Milliseconds, None, week_start)
+                .unwrap()
+                / MS_DAY
+        };
+        assert_eq!(truncate(WeekStart::Monday), 19723);
+        assert_eq!(truncate(WeekStart::Wednesday), 19725);
+        assert_eq!(truncate(WeekStart::Thursday), 19719);
+        assert_eq!(truncate(WeekStart::Sunday), 19722);
+    }
+
     #[test]
     fn test_add_ns() {
         let t = 1;
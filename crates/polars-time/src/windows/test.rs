@@ -926,3 +926,42 @@ fn test_group_by_windows_offsets_3776() {
     );
     assert_eq!(groups, [[0, 1], [1, 1], [2, 1]]);
 }
+
+#[test]
+fn test_round_calendar_duration() {
+    let ts = |y, m, d, h| {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, 0, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_nanos_opt()
+            .unwrap()
+    };
+    let round = |every: &str, t: i64, ties: RoundTies| {
+        let every = Duration::parse(every);
+        Window::new(every, every, Duration::from_nsecs(0))
+            .round_ns(t, None, ties)
+            .unwrap()
+    };
+
+    // 15 days after the start of January, 16 days before the start of February.
+    assert_eq!(
+        round("1mo", ts(2023, 1, 16, 0), RoundTies::Up),
+        ts(2023, 1, 1, 0)
+    );
+    // 46 days after the start of the quarter, 44 days before the start of the next one.
+    assert_eq!(
+        round("1q", ts(2023, 2, 16, 0), RoundTies::Up),
+        ts(2023, 4, 1, 0)
+    );
+    // Exactly halfway through February of a leap year.
+    let t = ts(2024, 2, 15, 12);
+    assert_eq!(round("1mo", t, RoundTies::Up), ts(2024, 3, 1, 0));
+    assert_eq!(round("1mo", t, RoundTies::Down), ts(2024, 2, 1, 0));
+    assert_eq!(round("1d", t, RoundTies::Down), ts(2024, 2, 15, 0));
+    assert_eq!(
+        round("1mo", ts(2024, 2, 1, 0), RoundTies::Up),
+        ts(2024, 2, 1, 0)
+    );
+}
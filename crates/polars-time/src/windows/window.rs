@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 use arrow::legacy::time_zone::Tz;
 use arrow::temporal_conversions::*;
 use chrono::NaiveDateTime;
@@ -75,24 +77,44 @@ impl Window {
         self.every.truncate_ms(t, tz)
     }
 
+    /// Round `t` to the nearest of its window start and the next window start, which are
+    /// calendar aware for durations such as months, weeks and days.
+    fn round_impl(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        ties: RoundTies,
+        truncate: fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>,
+        add: fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>,
+    ) -> PolarsResult<i64> {
+        let lower = truncate(&self.every, t, tz)?;
+        if lower == t {
+            return Ok(t);
+        }
+        let upper = add(&self.every, lower, tz)?;
+        Ok(match (t - lower).cmp(&(upper - t)) {
+            Ordering::Less => lower,
+            Ordering::Greater => upper,
+            Ordering::Equal => match ties {
+                RoundTies::Up => upper,
+                RoundTies::Down => lower,
+            },
+        })
+    }
+
     /// Round the given ns timestamp by the window boundary.
-    pub fn round_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        let t = t + self.every.duration_ns() / 2_i64;
-        self.truncate_ns(t, tz)
+    pub fn round_ns(&self, t: i64, tz: Option<&Tz>, ties: RoundTies) -> PolarsResult<i64> {
+        self.round_impl(t, tz, ties, Duration::truncate_ns, Duration::add_ns)
     }
 
     /// Round the given us timestamp by the window boundary.
-    pub fn round_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        let t = t + self.every.duration_ns()
-            / (2 * timeunit_scale(ArrowTimeUnit::Nanosecond, ArrowTimeUnit::Microsecond) as i64);
-        self.truncate_us(t, tz)
+    pub fn round_us(&self, t: i64, tz: Option<&Tz>, ties: RoundTies) -> PolarsResult<i64> {
+        self.round_impl(t, tz, ties, Duration::truncate_us, Duration::add_us)
     }
 
     /// Round the given ms timestamp by the window boundary.
-    pub fn round_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        let t = t + self.every.duration_ns()
-            / (2 * timeunit_scale(ArrowTimeUnit::Nanosecond, ArrowTimeUnit::Millisecond) as i64);
-        self.truncate_ms(t, tz)
+    pub fn round_ms(&self, t: i64, tz: Option<&Tz>, ties: RoundTies) -> PolarsResult<i64> {
+        self.round_impl(t, tz, ties, Duration::truncate_ms, Duration::add_ms)
     }
 
     /// returns the bounds for the earliest window bounds
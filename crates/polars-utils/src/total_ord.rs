@@ -32,6 +32,30 @@ pub fn canonical_f64(x: f64) -> f64 {
     }
 }
 
+/// Converts an f32 into an integer key of which the ordering is the IEEE 754 totalOrder, so
+/// -0 < 0, except that all NaNs map to the same key, which is greater than any other.
+#[inline]
+pub fn total_order_key_f32(x: f32) -> i32 {
+    let bits = if x.is_nan() { 0x7fc00000 } else { x.to_bits() };
+    let bits = bits as i32;
+    // Flipping all but the sign bit of negative floats makes them order as signed integers.
+    bits ^ (((bits >> 31) as u32) >> 1) as i32
+}
+
+/// Converts an f64 into an integer key of which the ordering is the IEEE 754 totalOrder, so
+/// -0 < 0, except that all NaNs map to the same key, which is greater than any other.
+#[inline]
+pub fn total_order_key_f64(x: f64) -> i64 {
+    let bits = if x.is_nan() {
+        0x7ff8000000000000
+    } else {
+        x.to_bits()
+    };
+    let bits = bits as i64;
+    // Flipping all but the sign bit of negative floats makes them order as signed integers.
+    bits ^ (((bits >> 63) as u64) >> 1) as i64
+}
+
 /// Alternative trait for Eq. By consistently using this we can still be
 /// generic w.r.t Eq while getting a total ordering for floats.
 pub trait TotalEq {
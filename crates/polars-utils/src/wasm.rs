@@ -1,6 +1,10 @@
 pub struct Pool;
 
 impl Pool {
+    pub fn with_pool<R>(&self, f: impl FnOnce(&Self) -> R) -> R {
+        f(self)
+    }
+
     pub fn current_num_threads(&self) -> usize {
         rayon::current_num_threads()
     }
@@ -108,7 +108,8 @@
 //! whenever you can.
 //!
 //! As neither API is async they should be wrapped in _spawn_blocking_ when used in an async context
-//! to avoid blocking the async thread pool of the runtime.
+//! to avoid blocking the async thread pool of the runtime. With the `async` feature, lazy queries
+//! can instead be awaited with `LazyFrame::collect_async`.
 //!
 //! ## Expressions
 //! Polars has a powerful concept called expressions.
@@ -182,12 +183,20 @@
 //!     - `dot_diagram` - Create dot diagrams from lazy logical plans.
 //! * `sql` - Pass SQL queries to polars.
 //! * `streaming` - Be able to process datasets that are larger than RAM.
+//! * `testing` - `assert_frame_equal!` and `assert_series_equal!` with float tolerances and
+//!               readable diffs, like `polars.testing` in Python.
+//! * `proptest` - [proptest](https://docs.rs/proptest/) strategies that generate arbitrary
+//!                 [`Series`] and [`DataFrame`]s, see `polars::testing::strategies`.
+//! * `tracing` - Emit [tracing](https://docs.rs/tracing/) spans and events for optimizer rule
+//!                applications, predicate pushdown, node execution and spilling to disk.
 //! * `random` - Generate arrays with randomly sampled values
 //! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for [`StringChunked`] //!     - `string_pad` - `zfill`, `ljust`, `rjust`
 //!     - `string_to_integer` - `parse_int`
+//!     - `string_normalize` - Unicode normalization with `str_normalize`
+//!     - `string_similarity` - `levenshtein_distance`, `hamming_distance`, `jaro_winkler`
 //! * `object` - Support for generic ChunkedArrays called [`ObjectChunked<T>`] (generic over `T`).
 //!              These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
@@ -206,10 +215,17 @@
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `excel` - Read Excel (.xlsx) workbooks
+//!     - `fwf` - Read fixed-width text files
+//!     - `database` - Read query results of database drivers that return Arrow C streams (e.g. ADBC)
+//!     - `delta` - Read Delta Lake tables
+//!     - `partition` - Write a [`DataFrame`] to one directory per group of key columns
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!                      Supported compressions:
 //!                         * zip
 //!                         * gzip
+//!                         * zstd
+//!                         * bzip2
 //!
 //! [`StringChunked`]: crate::datatypes::StringChunked
 //! [column selection]: polars_lazy::dsl::col
@@ -224,11 +240,14 @@
 //!                And activates `pivot` and `transpose` operations
 //!     - `asof_join` - Join ASOF, to join on nearest keys instead of exact equality match.
 //!     - `cross_join` - Create the Cartesian product of two [`DataFrame`]s.
+//!     - `iejoin` - Join on inequality predicates between the two [`DataFrame`]s.
 //!     - `semi_anti_join` - SEMI and ANTI joins.
+//!     - `upsert` - Update matching rows and append new rows from another [`DataFrame`].
 //!     - `row_hash` - Utility to hash [`DataFrame`] rows to [`UInt64Chunked`]
 //!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
 //!     - `dataframe_arithmetic` - Arithmetic on ([`Dataframe`] and [`DataFrame`]s) and ([`DataFrame`] on [`Series`])
-//!     - `partition_by` - Split into multiple [`DataFrame`]s partitioned by groups.
+//!     - `partition_by` - Split into multiple [`DataFrame`]s or `LazyFrame`s partitioned by groups.
+//!     - `describe` - Summary statistics of the columns of a [`DataFrame`].
 //! * [`Series`]/[`Expr`] operations:
 //!     - `is_in` - Check for membership in [`Series`].
 //!     - `zip_with` - [Zip two Series/ ChunkedArrays](crate::chunked_array::ops::ChunkZip).
@@ -266,6 +285,7 @@
 //!     - `cumulative_eval` - Apply expressions over cumulatively increasing windows.
 //!     - `arg_where` - Get indices where condition holds.
 //!     - `search_sorted` - Find indices where elements should be inserted to maintain order.
+//!     - `index_of` - Find the index of the first occurrence of a value.
 //!     - `offset_by` - Add an offset to dates that take months and leap years into account.
 //!     - `trigonometry` - Trigonometric functions.
 //!     - `sign` - Compute the element-wise sign of a [`Series`].
@@ -407,6 +427,9 @@
 //! * `POLARS_PANIC_ON_ERR` -> panic instead of returning an Error.
 //! * `POLARS_NO_CHUNKED_JOIN` -> force rechunk before joins.
 //!
+//! `POLARS_MAX_THREADS`, `POLARS_VERBOSE` and `POLARS_STREAMING_CHUNK_SIZE` can also be set at
+//! runtime with [`Config`], which takes precedence over the environment variables.
+//!
 //! ## User guide
 //!
 //! If you want to read more, check the [user guide](https://docs.pola.rs/).
@@ -419,10 +442,13 @@ pub mod prelude;
 #[cfg(feature = "sql")]
 pub mod sql;
 
+pub use polars_core::config::Config;
 pub use polars_core::{
     apply_method_all_arrow_series, chunked_array, datatypes, df, error, frame, functions, series,
     testing,
 };
+#[cfg(feature = "testing")]
+pub use polars_core::{assert_frame_equal, assert_series_equal};
 #[cfg(feature = "dtype-categorical")]
 pub use polars_core::{enable_string_cache, using_string_cache};
 #[cfg(feature = "polars-io")]
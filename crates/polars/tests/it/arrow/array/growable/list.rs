@@ -145,3 +145,23 @@ fn test_from_two_lists() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn extend_copies() {
+    let data = vec![Some(vec![Some(1i32), Some(2)]), None, Some(vec![Some(3)])];
+
+    let array = create_list_array(data).sliced(1, 2);
+
+    let mut a = GrowableList::new(vec![&array], false, 0);
+    unsafe {
+        a.extend_copies(0, 0, 2, 3);
+    }
+    assert_eq!(a.len(), 6);
+
+    let result: ListArray<i32> = a.into();
+
+    let expected = [None, Some(vec![Some(3)])];
+    let expected = create_list_array(expected.iter().cloned().cycle().take(6).collect());
+
+    assert_eq!(result, expected)
+}
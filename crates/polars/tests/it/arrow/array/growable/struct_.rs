@@ -137,3 +137,52 @@ fn many() {
     );
     assert_eq!(expected, result.as_ref())
 }
+
+#[test]
+fn null_runs() {
+    let (fields, values) = some_values();
+
+    let array = StructArray::new(
+        fields.clone(),
+        values.clone(),
+        Some(Bitmap::from([true, true, false, false, true])),
+    );
+
+    let mut a = GrowableStruct::new(vec![&array], false, 0);
+
+    unsafe {
+        a.extend(0, 1, 4);
+        a.extend_copies(0, 0, 2, 2);
+    }
+    assert_eq!(a.len(), 8);
+    let result: StructArray = a.into();
+
+    let expected_string: Box<dyn Array> = Box::new(Utf8Array::<i32>::from([
+        Some("aa"),
+        None,
+        None,
+        Some("doe"),
+        Some("a"),
+        Some("aa"),
+        Some("a"),
+        Some("aa"),
+    ]));
+    let expected_int: Box<dyn Array> = Box::new(PrimitiveArray::<i32>::from(vec![
+        Some(2),
+        None,
+        None,
+        Some(5),
+        Some(1),
+        Some(2),
+        Some(1),
+        Some(2),
+    ]));
+    let expected = StructArray::new(
+        fields,
+        vec![expected_string, expected_int],
+        Some(Bitmap::from([
+            true, false, false, true, true, true, true, true,
+        ])),
+    );
+    assert_eq!(result, expected)
+}
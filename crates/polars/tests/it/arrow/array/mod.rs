@@ -9,6 +9,7 @@ mod growable;
 mod list;
 mod map;
 mod primitive;
+mod run_end_encoded;
 mod struct_;
 mod union;
 mod utf8;
@@ -0,0 +1,81 @@
+use arrow::array::growable::{Growable, GrowableRunEndEncoded};
+use arrow::array::*;
+use arrow::datatypes::{ArrowDataType, Field};
+
+fn data_type() -> ArrowDataType {
+    ArrowDataType::RunEndEncoded(Box::new(Field::new("values", ArrowDataType::Utf8, true)))
+}
+
+fn array() -> RunEndEncodedArray {
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("b")]);
+    RunEndEncodedArray::new(data_type(), vec![3, 5, 6].into(), values.boxed())
+}
+
+#[test]
+fn basics() {
+    let array = array();
+
+    assert_eq!(array.len(), 6);
+    assert_eq!(array.null_count(), 2);
+    assert!(array.is_null(3));
+    assert!(array.is_valid(5));
+    assert_eq!(
+        format!("{array:?}"),
+        "RunEndEncodedArray[a, a, a, None, None, b]"
+    );
+
+    let expected = Utf8Array::<i32>::from([Some("a"), Some("a"), Some("a"), None, None, Some("b")]);
+    assert_eq!(array.decode().as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn slice() {
+    let array = array().sliced(2, 3);
+
+    assert_eq!(array.runs().collect::<Vec<_>>(), [(0, 1), (1, 2)]);
+    assert_eq!(array.null_count(), 2);
+    assert_eq!(format!("{array:?}"), "RunEndEncodedArray[a, None, None]");
+
+    let trimmed = array.trimmed();
+    assert_eq!(trimmed.run_ends().as_slice(), &[1, 3]);
+    assert_eq!(trimmed.values().len(), 2);
+    assert_eq!(trimmed, array);
+}
+
+#[test]
+fn invalid_run_ends() {
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]).boxed();
+    assert!(RunEndEncodedArray::try_new(data_type(), vec![2, 2].into(), values.clone()).is_err());
+    assert!(RunEndEncodedArray::try_new(data_type(), vec![2].into(), values).is_err());
+}
+
+#[test]
+fn null_and_empty() {
+    let array = new_null_array(data_type(), 4);
+    assert_eq!(array.len(), 4);
+    assert_eq!(array.null_count(), 4);
+
+    let array = new_empty_array(data_type());
+    assert_eq!(array.len(), 0);
+}
+
+#[test]
+fn growable() {
+    let lhs = array();
+    let rhs = array().sliced(1, 3);
+    let mut growable = GrowableRunEndEncoded::new(vec![&lhs, &rhs], true, 0);
+
+    unsafe {
+        growable.extend(0, 2, 2);
+        growable.extend_validity(2);
+        growable.extend(1, 0, 3);
+        growable.extend_copies(0, 5, 1, 3);
+    }
+    let result: RunEndEncodedArray = growable.into();
+
+    assert_eq!(result.run_ends().as_slice(), &[1, 2, 4, 6, 7, 10]);
+    assert_eq!(
+        format!("{result:?}"),
+        "RunEndEncodedArray[a, None, None, None, a, a, None, b, b, b]"
+    );
+}
@@ -14,6 +14,36 @@ proptest! {
     }
 }
 
+fn bitmap_pair_strategy() -> impl Strategy<Value = (Bitmap, Bitmap)> {
+    bitmap_strategy().prop_flat_map(|lhs| {
+        let len = lhs.len();
+        (
+            Just(lhs),
+            prop::collection::vec(any::<bool>(), len..=len).prop_map(|v| Bitmap::from(&v)),
+        )
+    })
+}
+
+proptest! {
+    /// Asserts that the binary operations and predicates match their bitwise definitions, on
+    /// both unaligned and aligned bitmaps
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn binary((lhs, rhs) in bitmap_pair_strategy()) {
+        let aligned: Bitmap = lhs.iter().collect();
+        for lhs in [&lhs, &aligned] {
+            let zip = || lhs.iter().zip(rhs.iter());
+
+            assert_eq!(lhs & &rhs, zip().map(|(l, r)| l & r).collect::<Bitmap>());
+            assert_eq!(lhs | &rhs, zip().map(|(l, r)| l | r).collect::<Bitmap>());
+            assert_eq!(lhs ^ &rhs, zip().map(|(l, r)| l ^ r).collect::<Bitmap>());
+            assert_eq!(lhs.intersects_with(&rhs), zip().any(|(l, r)| l & r));
+            assert_eq!(lhs.is_subset_of(&rhs), zip().all(|(l, r)| !l | r));
+            assert_eq!(lhs.unset_bits(), lhs.iter().filter(|x| !x).count());
+        }
+    }
+}
+
 #[test]
 fn test_fast_paths() {
     let all_true = Bitmap::from(&[true, true]);
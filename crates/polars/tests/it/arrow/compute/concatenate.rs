@@ -0,0 +1,34 @@
+use arrow::array::*;
+use arrow::compute::concatenate::*;
+use polars_error::PolarsResult;
+
+#[test]
+fn concatenate_dictionaries_merges_values() -> PolarsResult<()> {
+    let a = DictionaryArray::try_from_keys(
+        PrimitiveArray::<u32>::from([Some(1), Some(0), None, Some(1)]),
+        Utf8ViewArray::from_slice([Some("a"), Some("b")]).boxed(),
+    )?;
+    let b = DictionaryArray::try_from_keys(
+        PrimitiveArray::<u32>::from([Some(0), Some(2), Some(1)]),
+        Utf8ViewArray::from_slice([Some("b"), Some("c"), None]).boxed(),
+    )?;
+
+    let result = concatenate_dictionaries(&[&a, &b])?;
+
+    let expected_values = Utf8ViewArray::from_slice([Some("a"), Some("b"), Some("c")]);
+    assert_eq!(result.values().as_ref(), &expected_values as &dyn Array);
+    let expected_keys =
+        PrimitiveArray::<u32>::from([Some(1), Some(0), None, Some(1), Some(1), None, Some(2)]);
+    assert_eq!(result.keys(), &expected_keys);
+    Ok(())
+}
+
+#[test]
+fn concatenate_dictionaries_requires_views() -> PolarsResult<()> {
+    let a = DictionaryArray::try_from_keys(
+        PrimitiveArray::<u32>::from_vec(vec![0]),
+        Utf8Array::<i32>::from_slice(["a"]).boxed(),
+    )?;
+    assert!(concatenate_dictionaries(&[&a, &a]).is_err());
+    Ok(())
+}
@@ -2,5 +2,6 @@ mod aggregate;
 mod bitwise;
 mod boolean;
 mod boolean_kleene;
+mod concatenate;
 
 mod arity_assign;
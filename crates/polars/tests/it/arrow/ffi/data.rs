@@ -52,3 +52,10 @@ fn binview_nullable_buffered() -> PolarsResult<()> {
     ]);
     test_round_trip(data)
 }
+
+#[test]
+fn run_end_encoded() -> PolarsResult<()> {
+    let values = Utf8ViewArray::from_slice([Some("foo"), None, Some("barbar")]);
+    let data = RunEndEncodedArray::from_runs(values.boxed(), vec![2, 3, 6].into())?;
+    test_round_trip(data)
+}
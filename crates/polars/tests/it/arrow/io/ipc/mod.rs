@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use arrow::array::*;
 use arrow::datatypes::{ArrowSchema, ArrowSchemaRef, Field};
-use arrow::io::ipc::read::{read_file_metadata, FileReader};
+use arrow::io::ipc::read::{read_file_metadata, read_stream_metadata, FileReader, StreamReader};
 use arrow::io::ipc::write::*;
 use arrow::io::ipc::IpcField;
 use arrow::record_batch::RecordBatchT;
@@ -16,7 +16,10 @@ pub(crate) fn write(
     compression: Option<Compression>,
 ) -> PolarsResult<Vec<u8>> {
     let result = vec![];
-    let options = WriteOptions { compression };
+    let options = WriteOptions {
+        compression,
+        ..Default::default()
+    };
     let mut writer = FileWriter::try_new(result, schema.clone(), ipc_fields.clone(), options)?;
     for batch in batches {
         writer.write(batch, ipc_fields.as_ref().map(|x| x.as_ref()))?;
@@ -78,3 +81,75 @@ fn write_binview() -> PolarsResult<()> {
     let columns = RecordBatchT::try_new(vec![array])?;
     round_trip(columns, schema, None, Some(Compression::ZSTD))
 }
+
+#[test]
+fn write_sliced_run_end_encoded() -> PolarsResult<()> {
+    let values = Utf8ViewArray::from_slice([Some("foo"), None, Some("bar"), Some("hamlet")]);
+    let array = RunEndEncodedArray::from_runs(values.boxed(), vec![2, 5, 6, 9].into())?
+        .sliced(3, 4)
+        .boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(vec![array])?;
+    round_trip(columns, schema, None, Some(Compression::ZSTD))
+}
+
+fn write_stream(
+    batches: &[RecordBatchT<Box<dyn Array>>],
+    schema: &ArrowSchemaRef,
+    delta_dictionaries: bool,
+) -> PolarsResult<Vec<u8>> {
+    let options = WriteOptions {
+        delta_dictionaries,
+        ..Default::default()
+    };
+    let mut writer = StreamWriter::new(vec![], options);
+    writer.start(schema, None)?;
+    for batch in batches {
+        writer.write(batch, None)?;
+    }
+    writer.finish()?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn write_delta_dictionaries() -> PolarsResult<()> {
+    // The buffers are padded to 64 bytes, so the values have to be longer for the deltas to be
+    // smaller.
+    let values = ["aa", "bb", "cc", "dd"].map(|s| s.repeat(64));
+    let values = Utf8Array::<i32>::from_slice(values).boxed();
+    let batches = [(vec![0, 1], 2), (vec![2, 0], 3), (vec![3, 1], 4)]
+        .into_iter()
+        .map(|(keys, n_values)| {
+            let keys = PrimitiveArray::<i32>::from_vec(keys);
+            let array = DictionaryArray::try_from_keys(keys, values.sliced(0, n_values))?.boxed();
+            RecordBatchT::try_new(vec![array])
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let schema = prep_schema(batches[0].arrays()[0].as_ref());
+
+    let full = write_stream(&batches, &schema, false)?;
+    let delta = write_stream(&batches, &schema, true)?;
+    assert!(delta.len() < full.len());
+
+    let mut reader = Cursor::new(delta);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let reader = StreamReader::new(reader, metadata, None);
+    let result = reader
+        .map(|state| state.map(|state| state.unwrap()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    assert_eq!(result.len(), batches.len());
+    for (result, expected) in result.iter().zip(&batches) {
+        let result = result.arrays()[0]
+            .as_any()
+            .downcast_ref::<DictionaryArray<i32>>()
+            .unwrap();
+        let expected = expected.arrays()[0]
+            .as_any()
+            .downcast_ref::<DictionaryArray<i32>>()
+            .unwrap();
+        assert_eq!(result.keys(), expected.keys());
+        assert_eq!(result.values(), expected.values());
+    }
+    Ok(())
+}
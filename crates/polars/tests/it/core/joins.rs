@@ -147,6 +147,40 @@ fn test_full_outer_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_full_outer_join_coalesce_renamed_key() -> PolarsResult<()> {
+    // The right key has the name of a non-key column of the left frame.
+    let df_left = df!(
+        "a" => [1, 2],
+        "b" => [10, 20]
+    )?;
+    let df_right = df!(
+        "b" => [2, 3],
+        "c" => ["x", "y"]
+    )?;
+
+    let out = df_left
+        .join(
+            &df_right,
+            ["a"],
+            ["b"],
+            JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::CoalesceColumns),
+        )?
+        .sort(["a"], Default::default())?;
+    let expected = df!(
+        "a" => [1, 2, 3],
+        "b" => [Some(10), Some(20), None],
+        "c" => [None, Some("x"), Some("y")]
+    )?;
+    assert!(out.equals_missing(&expected));
+
+    let out = df_left.full_join(&df_right, ["a"], ["b"])?;
+    assert_eq!(out.get_column_names(), &["a", "b", "b_right", "c"]);
+
+    Ok(())
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn test_join_with_nulls() {
@@ -593,6 +627,34 @@ fn test_join_floats() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_floats_total_order() -> PolarsResult<()> {
+    let df_a = df! {
+        "a" => &[0.0, -0.0, f64::NAN],
+        "b" => &[1, 2, 3]
+    }?;
+    let df_b = df! {
+        "a" => &[-0.0, -f64::NAN],
+        "c" => &["neg_zero", "nan"]
+    }?;
+
+    let out = df_a.join(&df_b, ["a"], ["a"], JoinType::Left.into())?;
+    assert_eq!(
+        Vec::from(out.column("c")?.str()?),
+        &[Some("neg_zero"), Some("neg_zero"), Some("nan")]
+    );
+
+    let args = JoinArgs::new(JoinType::Left).with_float_total_order(true);
+    let out = df_a.join(&df_b, ["a"], ["a"], args)?;
+    assert_eq!(
+        Vec::from(out.column("c")?.str()?),
+        &[None, Some("neg_zero"), Some("nan")]
+    );
+    assert_eq!(out.column("a")?.dtype(), &DataType::Float64);
+    Ok(())
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 #[cfg(feature = "lazy")]
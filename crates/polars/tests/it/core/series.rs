@@ -35,3 +35,37 @@ fn test_construct_list_of_null_series() {
     assert_eq!(s.null_count(), 0);
     assert_eq!(s.field().name(), "a");
 }
+
+#[test]
+#[cfg(feature = "dtype-i128")]
+fn test_int128_series() -> PolarsResult<()> {
+    let a = Series::new("a", &[i128::MAX - 1, -3, 5]);
+    let b = Series::new("b", &[1i128, 2, 3]);
+    assert_eq!(a.dtype(), &DataType::Int128);
+    assert_eq!((&a + &b)?, Series::new("a", &[i128::MAX, -1, 8]));
+    assert_eq!(
+        a.lt(&b)?.into_iter().collect::<Vec<_>>(),
+        &[Some(false), Some(true), Some(false)]
+    );
+
+    let sorted = a.sort(Default::default())?;
+    assert_eq!(sorted, Series::new("a", &[-3, 5, i128::MAX - 1]));
+
+    let c = Series::new("c", &[i64::MAX, 2]).cast(&DataType::Int128)?;
+    let c = (&c * &Series::new("", &[4i128, 4]))?;
+    assert_eq!(c, Series::new("c", &[i64::MAX as i128 * 4, 8]));
+    assert_eq!(
+        c.cast(&DataType::Int64)?,
+        Series::new("c", &[None, Some(8i64)])
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-i128")]
+fn test_int64_sum_overflow() -> PolarsResult<()> {
+    let s = Series::new("a", &[i64::MAX, 1]);
+    let sum = s.cast(&DataType::Int128)?.sum_reduce()?;
+    assert_eq!(sum.value(), &AnyValue::Int128(i64::MAX as i128 + 1));
+    Ok(())
+}
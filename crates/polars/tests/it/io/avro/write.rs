@@ -3,6 +3,7 @@ use std::io::Cursor;
 use arrow::array::*;
 use arrow::datatypes::*;
 use arrow::io::avro::avro_schema::file::{Block, CompressedBlock, Compression};
+use arrow::io::avro::avro_schema::read::read_metadata;
 use arrow::io::avro::avro_schema::write::{compress, write_block, write_metadata};
 use arrow::io::avro::write;
 use arrow::record_batch::RecordBatchT;
@@ -452,3 +453,99 @@ fn test_with_columns() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_write_multiple_chunks() -> PolarsResult<()> {
+    let mut write_df = df!(
+        "i64" => &[1, 2],
+        "string" => &["a", "b"]
+    )?;
+    let other = write_df.clone();
+    write_df.vstack_mut(&other)?;
+    assert_eq!(write_df.n_chunks(), 2);
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    AvroWriter::new(&mut buf)
+        .with_name("my_record".to_string())
+        .finish(&mut write_df)?;
+    buf.set_position(0);
+
+    let metadata = read_metadata(&mut buf)?;
+    assert_eq!(metadata.record.name, "my_record");
+    buf.set_position(0);
+
+    let read_df = AvroReader::new(buf).finish()?;
+    assert!(write_df.equals(&read_df));
+
+    Ok(())
+}
+
+#[test]
+fn test_with_reader_schema() -> PolarsResult<()> {
+    let mut df = df!(
+        "id" => &[1i32, 2],
+        "name" => &["a", "b"],
+        "score" => &[0.5f32, 1.5],
+        "legacy" => &["x", "y"]
+    )?;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    AvroWriter::new(&mut buf).finish(&mut df)?;
+
+    let reader_schema = r#"
+    {
+        "type": "record",
+        "name": "test",
+        "fields": [
+            {"name": "full_name", "type": "string", "aliases": ["name"]},
+            {"name": "id", "type": "long"},
+            {"name": "score", "type": "double"},
+            {"name": "country", "type": "string", "default": "NL"},
+            {"name": "rank", "type": ["null", "int"], "default": null}
+        ]
+    }"#;
+
+    buf.set_position(0);
+    let schema = AvroReader::new(buf.clone())
+        .with_reader_schema(Some(reader_schema.into()))
+        .schema()?;
+    assert_eq!(
+        schema.iter_names().collect::<Vec<_>>(),
+        &["full_name", "id", "score", "country", "rank"]
+    );
+
+    buf.set_position(0);
+    let read_df = AvroReader::new(buf.clone())
+        .with_reader_schema(Some(reader_schema.into()))
+        .finish()?;
+    let expected_df = df!(
+        "full_name" => &["a", "b"],
+        "id" => &[1i64, 2],
+        "score" => &[0.5f64, 1.5],
+        "country" => &["NL", "NL"],
+        "rank" => &[None::<i32>, None]
+    )?;
+    assert!(expected_df.equals_missing(&read_df));
+
+    // Projections refer to the fields of the reader schema.
+    buf.set_position(0);
+    let read_df = AvroReader::new(buf.clone())
+        .with_reader_schema(Some(reader_schema.into()))
+        .with_columns(Some(vec!["country".to_string()]))
+        .finish()?;
+    assert!(read_df.equals(&df!("country" => &["NL", "NL"])?));
+
+    // Fields missing in the file need a default and types can only be promoted.
+    for reader_schema in [
+        r#"{"type": "record", "name": "test", "fields": [{"name": "country", "type": "string"}]}"#,
+        r#"{"type": "record", "name": "test", "fields": [{"name": "name", "type": "int"}]}"#,
+    ] {
+        buf.set_position(0);
+        assert!(AvroReader::new(buf.clone())
+            .with_reader_schema(Some(reader_schema.into()))
+            .finish()
+            .is_err());
+    }
+
+    Ok(())
+}
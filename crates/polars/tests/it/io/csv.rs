@@ -138,6 +138,37 @@ fn test_read_csv_file() {
     assert_eq!(df.shape(), (27, 4));
 }
 
+#[test]
+fn test_read_csv_zero_copy_strings() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("zero_copy.csv");
+    let mut csv = String::from("id,text\n");
+    for i in 0..1000 {
+        match i % 3 {
+            0 => csv.push_str(&format!("{i},a long string value number {i}\n")),
+            1 => csv.push_str(&format!("{i},\"a quoted \"\"value\"\" number {i}\"\n")),
+            _ => csv.push_str(&format!("{i},short\n")),
+        }
+    }
+    csv.push_str("1000,ünïcödé strings are longer than they look\n");
+    std::fs::write(&path, csv)?;
+
+    let read = |zero_copy_strings| {
+        CsvReadOptions::default()
+            .with_zero_copy_strings(zero_copy_strings)
+            .try_into_reader_with_file_path(Some(path.clone()))?
+            .finish()
+    };
+    let expected = read(false)?;
+    let out = read(true)?;
+    assert!(out.equals(&expected));
+    assert_eq!(
+        out.column("text")?.str()?.get(1000),
+        Some("ünïcödé strings are longer than they look")
+    );
+    Ok(())
+}
+
 #[test]
 fn test_read_csv_filter() -> PolarsResult<()> {
     let df = CsvReadOptions::default()
@@ -739,6 +770,35 @@ null-value,b,bar
     Ok(())
 }
 
+#[test]
+fn test_null_values_per_column() -> PolarsResult<()> {
+    let csv = r"a,b,c
+1,NA,x
+NA,b,-
+-,NA,NA
+";
+
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options.with_null_values(Some(NullValues::Named(vec![
+                ("a".to_string(), "NA".to_string()),
+                ("a".to_string(), "-".to_string()),
+                ("c".to_string(), "-".to_string()),
+            ])))
+        })
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    let expected = df![
+        "a" => [Some(1i64), None, None],
+        "b" => ["NA", "b", "NA"],
+        "c" => [Some("x"), None, Some("NA")],
+    ]?;
+    assert!(df.equals_missing(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_no_newline_at_end() -> PolarsResult<()> {
     let csv = r"a,b
@@ -810,6 +870,81 @@ fn test_automatic_datetime_parsing_default_formats() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "temporal")]
+fn test_datetime_formats() -> PolarsResult<()> {
+    use polars_core::export::chrono;
+
+    let csv = r"ts,date,other
+2021-01-31,31/01/2021,x
+01/02/2021 10:30,01/02/2021,y
+,02/02/2021,z
+";
+    let formats: Arc<[String]> = [
+        "%Y-%m-%d".into(),
+        "%d/%m/%Y %H:%M".into(),
+        "%d/%m/%Y".into(),
+    ]
+    .into();
+
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options.with_datetime_formats(Some(formats.clone()))
+        })
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    let ts = df.column("ts")?;
+    assert_eq!(
+        ts.dtype(),
+        &DataType::Datetime(TimeUnit::Microseconds, None)
+    );
+    let expected = [
+        chrono::NaiveDate::from_ymd_opt(2021, 1, 31)
+            .unwrap()
+            .and_hms_opt(0, 0, 0),
+        chrono::NaiveDate::from_ymd_opt(2021, 2, 1)
+            .unwrap()
+            .and_hms_opt(10, 30, 0),
+        None,
+    ];
+    assert!(ts.equals_missing(
+        &Series::new("ts", expected).cast(&DataType::Datetime(TimeUnit::Microseconds, None))?
+    ));
+
+    let date = df.column("date")?;
+    assert_eq!(date.dtype(), &DataType::Date);
+    let expected = [
+        chrono::NaiveDate::from_ymd_opt(2021, 1, 31).unwrap(),
+        chrono::NaiveDate::from_ymd_opt(2021, 2, 1).unwrap(),
+        chrono::NaiveDate::from_ymd_opt(2021, 2, 2).unwrap(),
+    ];
+    assert!(date.equals(&Series::new("date", expected)));
+    assert_eq!(df.column("other")?.dtype(), &DataType::String);
+
+    // formats also apply to columns with an explicit temporal dtype
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .with_schema_overwrite(Some(Arc::new(Schema::from_iter([Field::new(
+            "ts",
+            DataType::Datetime(TimeUnit::Milliseconds, None),
+        )]))))
+        .map_parse_options(|parse_options| {
+            parse_options.with_datetime_formats(Some(formats.clone()))
+        })
+        .into_reader_with_file_handle(file)
+        .finish()?;
+    let ts = df.column("ts")?;
+    assert_eq!(
+        ts.dtype(),
+        &DataType::Datetime(TimeUnit::Milliseconds, None)
+    );
+    assert_eq!(ts.null_count(), 1);
+
+    Ok(())
+}
+
 #[test]
 fn test_no_quotes() -> PolarsResult<()> {
     let rolling_stones = r#"linenum,last_name,first_name
@@ -1391,3 +1526,188 @@ fn test_read_io_reader() {
     let expected = CsvReader::new(file).finish().unwrap();
     assert!(df.equals(&expected))
 }
+
+#[test]
+#[cfg(unix)]
+fn test_read_write_pipes() -> PolarsResult<()> {
+    use std::process::{Command, Stdio};
+
+    use polars::io::mmap::NonSeekableReader;
+
+    let expected = CsvReader::new(std::fs::File::open(FOODS_CSV)?).finish()?;
+
+    // The output of another process.
+    let mut child = Command::new("cat")
+        .arg(FOODS_CSV)
+        .stdout(Stdio::piped())
+        .spawn()?;
+    let stdout = child.stdout.take().unwrap();
+    let df = CsvReader::new(NonSeekableReader::new(stdout)).finish()?;
+    assert!(child.wait()?.success());
+    assert!(df.equals(&expected));
+
+    // A named pipe, which can't be memory mapped.
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("pipe");
+    assert!(Command::new("mkfifo").arg(&path).status()?.success());
+    let writer_path = path.clone();
+    let mut writer_df = expected.clone();
+    let writer = std::thread::spawn(move || {
+        let file = std::fs::OpenOptions::new().write(true).open(writer_path)?;
+        CsvWriter::new(file).finish(&mut writer_df)
+    });
+    let df = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(path.clone()))?
+        .finish()?;
+    writer.join().unwrap()?;
+    assert!(df.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "decompress", feature = "lazy"))]
+fn test_scan_compressed_csv() -> PolarsResult<()> {
+    let expected = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(FOODS_CSV.into()))?
+        .finish()?;
+
+    for ext in ["gz", "zst", "bz2"] {
+        let path = format!("{FOODS_CSV}.{ext}");
+        let df = CsvReadOptions::default()
+            .try_into_reader_with_file_path(Some(path.clone().into()))?
+            .finish()?;
+        assert!(df.equals(&expected));
+
+        let df = LazyCsvReader::new(&path)
+            .with_infer_schema_length(Some(3))
+            .finish()?
+            .filter(col("calories").gt(lit(100)))
+            .collect()?;
+        let expected = expected
+            .clone()
+            .lazy()
+            .filter(col("calories").gt(lit(100)))
+            .collect()?;
+        assert!(df.equals(&expected));
+
+        // The row index and `n_rows` count the rows before the predicate is applied.
+        let df = LazyCsvReader::new(&path)
+            .with_row_index(Some(RowIndex {
+                name: "rc".into(),
+                offset: 1,
+            }))
+            .with_n_rows(Some(20))
+            .finish()?
+            .filter(col("calories").gt(lit(100)))
+            .collect()?;
+        let expected = LazyCsvReader::new(FOODS_CSV)
+            .with_row_index(Some(RowIndex {
+                name: "rc".into(),
+                offset: 1,
+            }))
+            .with_n_rows(Some(20))
+            .finish()?
+            .filter(col("calories").gt(lit(100)))
+            .collect()?;
+        assert!(df.equals(&expected));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_ragged_lines() -> PolarsResult<()> {
+    let csv = "a,b,c\n1,2,3\n4,5\n6,7,8,9\n";
+    let read = |ragged_lines: RaggedLines| {
+        CsvReadOptions::default()
+            .map_parse_options(|parse_options| parse_options.with_ragged_lines(ragged_lines))
+            .into_reader_with_file_handle(Cursor::new(csv))
+            .finish()
+    };
+
+    // by default short lines are padded, long lines raise
+    assert!(read(RaggedLines::Pad).is_err());
+    assert!(read(RaggedLines::Raise).is_err());
+
+    let df = read(RaggedLines::Truncate)?;
+    let expected = df![
+        "a" => [1i64, 4, 6],
+        "b" => [2i64, 5, 7],
+        "c" => [Some(3i64), None, Some(8)],
+    ]?;
+    assert!(df.equals_missing(&expected));
+
+    // the deprecated flag still truncates when it is set directly
+    #[allow(deprecated)]
+    let df = CsvReadOptions::default()
+        .map_parse_options(|mut parse_options| {
+            parse_options.truncate_ragged_lines = true;
+            parse_options
+        })
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert!(df.equals_missing(&expected));
+
+    // only short lines
+    let csv = "a,b,c\n1,2,3\n4,5\n";
+    let df = CsvReadOptions::default()
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df.column("c")?.null_count(), 1);
+    let err = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_ragged_lines(RaggedLines::Raise))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()
+        .unwrap_err();
+    assert!(err.to_string().contains("found fewer fields"));
+
+    // a missing projected field is still detected
+    let err = CsvReadOptions::default()
+        .with_projection(Some(Arc::new(vec![2])))
+        .map_parse_options(|parse_options| parse_options.with_ragged_lines(RaggedLines::Raise))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()
+        .unwrap_err();
+    assert!(err.to_string().contains("found fewer fields"));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "streaming"))]
+fn test_sink_csv() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("sink.csv");
+    let df = df![
+        "a" => (0..10_000i64).collect::<Vec<_>>(),
+        "b" => (0..10_000i64).map(|v| format!("v{v}")).collect::<Vec<_>>(),
+    ]?;
+    let options = CsvWriterOptions {
+        batch_size: NonZeroUsize::new(100).unwrap(),
+        maintain_order: true,
+        ..Default::default()
+    };
+    df.clone()
+        .lazy()
+        .filter(col("a").gt_eq(lit(10)))
+        .sink_csv(path.clone(), options.clone())?;
+
+    let contents = std::fs::read_to_string(&path)?;
+    assert_eq!(contents.matches("a,b").count(), 1);
+    let out = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(path.clone()))?
+        .finish()?;
+    assert!(out.equals(&df.slice(10, 9_990)));
+
+    // an empty result still writes the header
+    df.clone()
+        .lazy()
+        .filter(col("a").lt(lit(0)))
+        .sink_csv(path.clone(), options.clone())?;
+    assert_eq!(std::fs::read_to_string(&path)?, "a,b\n");
+
+    // write errors are returned instead of panicking the io thread
+    let nested = df!["a" => [Series::new("", [1i64, 2])]]?;
+    assert!(nested.lazy().sink_csv(path.clone(), options).is_err());
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use std::num::NonZeroUsize;
+
+use arrow::array::{Array, Int32Array, StructArray, Utf8ViewArray};
+use arrow::datatypes::{ArrowDataType, Field as ArrowField};
+use arrow::ffi::{export_iterator, ArrowArrayStream};
+use polars::io::database::{read_database, DatabaseConnection};
+use polars::prelude::*;
+
+/// A connection that returns the same result, in record batches of 3 rows, for any query.
+struct MockConnection {
+    n_rows: i32,
+}
+
+unsafe impl DatabaseConnection for MockConnection {
+    fn execute(&mut self, query: &str) -> PolarsResult<Box<ArrowArrayStream>> {
+        polars_ensure!(query.starts_with("SELECT"), ComputeError: "invalid query: {}", query);
+        let fields = vec![
+            ArrowField::new("id", ArrowDataType::Int32, true),
+            ArrowField::new("name", ArrowDataType::Utf8View, true),
+        ];
+        let data_type = ArrowDataType::Struct(fields);
+        let batches = (0..self.n_rows)
+            .step_by(3)
+            .map(|start| {
+                let ids = (start..std::cmp::min(start + 3, self.n_rows)).collect::<Vec<_>>();
+                let names = ids.iter().map(|i| format!("name_{i}")).collect::<Vec<_>>();
+                let array = StructArray::new(
+                    data_type.clone(),
+                    vec![
+                        Int32Array::from_vec(ids.clone()).boxed(),
+                        Utf8ViewArray::from_slice_values(names).boxed(),
+                    ],
+                    None,
+                );
+                Ok(array.boxed())
+            })
+            .collect::<Vec<PolarsResult<Box<dyn Array>>>>();
+        let stream = export_iterator(
+            Box::new(batches.into_iter()),
+            ArrowField::new("", data_type, false),
+        );
+        Ok(Box::new(stream))
+    }
+}
+
+#[test]
+fn test_read_database() -> PolarsResult<()> {
+    let mut connection = MockConnection { n_rows: 10 };
+    let df = read_database(&mut connection, "SELECT * FROM t")?.finish()?;
+
+    let expected = df![
+        "id" => (0..10).collect::<Vec<i32>>(),
+        "name" => (0..10).map(|i| format!("name_{i}")).collect::<Vec<_>>(),
+    ]?;
+    assert!(df.equals(&expected));
+    assert_eq!(df.n_chunks(), 1);
+
+    assert!(read_database(&mut connection, "DROP TABLE t").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_read_database_batched() -> PolarsResult<()> {
+    let mut connection = MockConnection { n_rows: 10 };
+    let reader = read_database(&mut connection, "SELECT * FROM t")?;
+    assert_eq!(
+        reader.schema().as_ref(),
+        &Schema::from_iter([
+            Field::new("id", DataType::Int32),
+            Field::new("name", DataType::String)
+        ])
+    );
+
+    // Without a batch size the record batches of the driver are returned.
+    let heights = reader
+        .map(|df| df.map(|df| df.height()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(heights, [3, 3, 3, 1]);
+
+    let reader = read_database(&mut connection, "SELECT * FROM t")?
+        .with_batch_size(Some(NonZeroUsize::new(4).unwrap()));
+    let batches = reader.collect::<PolarsResult<Vec<_>>>()?;
+    let heights = batches.iter().map(|df| df.height()).collect::<Vec<_>>();
+    assert_eq!(heights, [4, 4, 2]);
+    assert_eq!(
+        batches[1]
+            .column("id")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [4, 5, 6, 7]
+    );
+    Ok(())
+}
@@ -0,0 +1,272 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use polars::io::delta::{DeltaTable, DeltaVersion};
+use polars::prelude::*;
+
+const SCHEMA_STRING: &str = r#"{"type":"struct","fields":[{"name":"id","type":"long","nullable":true,"metadata":{}},{"name":"value","type":"double","nullable":true,"metadata":{}},{"name":"region","type":"string","nullable":true,"metadata":{}},{"name":"year","type":"integer","nullable":true,"metadata":{}}]}"#;
+
+fn write_data_file(root: &Path, path: &str, ids: &[i64]) -> PolarsResult<()> {
+    let path = root.join(path);
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    let mut df = df![
+        "id" => ids,
+        "value" => ids.iter().map(|id| *id as f64 / 2.0).collect::<Vec<_>>(),
+    ]?;
+    ParquetWriter::new(File::create(path)?).finish(&mut df)?;
+    Ok(())
+}
+
+fn write_commit(root: &Path, version: i64, actions: &[String]) -> PolarsResult<()> {
+    let mut file = File::create(root.join(format!("_delta_log/{version:020}.json")))?;
+    for action in actions {
+        writeln!(file, "{action}")?;
+    }
+    Ok(())
+}
+
+fn add(path: &str, region: &str, year: i32) -> String {
+    format!(
+        r#"{{"add":{{"path":"{path}","partitionValues":{{"region":"{region}","year":"{year}"}},"size":1,"modificationTime":0,"dataChange":true}}}}"#
+    )
+}
+
+fn remove(path: &str) -> String {
+    format!(r#"{{"remove":{{"path":"{path}","deletionTimestamp":0,"dataChange":true}}}}"#)
+}
+
+fn commit_info(timestamp: i64) -> String {
+    format!(r#"{{"commitInfo":{{"timestamp":{timestamp},"operation":"WRITE"}}}}"#)
+}
+
+/// Create a table with the versions:
+/// 0. files a (eu, 2023) and b (us, 2023)
+/// 1. file c (eu, 2024) added, file a removed
+fn create_table(root: &Path) -> PolarsResult<()> {
+    std::fs::create_dir_all(root.join("_delta_log"))?;
+    write_data_file(root, "region=eu/year=2023/a.parquet", &[1, 2])?;
+    write_data_file(root, "region=us/year=2023/b%20file.parquet", &[3])?;
+    write_data_file(root, "data/c.parquet", &[4, 5, 6])?;
+
+    let protocol = r#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}"#.to_string();
+    let metadata = format!(
+        r#"{{"metaData":{{"id":"t","format":{{"provider":"parquet","options":{{}}}},"schemaString":{},"partitionColumns":["region","year"],"configuration":{{}}}}}}"#,
+        serde_json_string(SCHEMA_STRING)
+    );
+    write_commit(
+        root,
+        0,
+        &[
+            commit_info(1_000),
+            protocol,
+            metadata,
+            add("region=eu/year=2023/a.parquet", "eu", 2023),
+            add("region=us/year=2023/b%2520file.parquet", "us", 2023),
+        ],
+    )?;
+    write_commit(
+        root,
+        1,
+        &[
+            commit_info(2_000),
+            add("data/c.parquet", "eu", 2024),
+            remove("region=eu/year=2023/a.parquet"),
+        ],
+    )
+}
+
+fn serde_json_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\\\""))
+}
+
+fn scan(root: &Path, version: DeltaVersion) -> PolarsResult<DataFrame> {
+    let args = ScanArgsDelta {
+        version,
+        ..Default::default()
+    };
+    LazyFrame::scan_delta(root, args)?
+        .sort(["id"], Default::default())
+        .collect()
+}
+
+#[test]
+fn test_delta_log_replay() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let root = dir.path().to_path_buf();
+    create_table(&root)?;
+
+    let table = DeltaTable::load(&root, DeltaVersion::Latest)?;
+    assert_eq!(table.version(), 1);
+    assert_eq!(table.partition_columns(), ["region", "year"]);
+    let files = table
+        .files()
+        .iter()
+        .map(|f| {
+            (
+                f.path.strip_prefix(&root).unwrap().to_path_buf(),
+                f.partition_values.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        files,
+        [
+            (
+                PathBuf::from("region=us/year=2023/b%20file.parquet"),
+                vec![Some("us".into()), Some("2023".into())]
+            ),
+            (
+                PathBuf::from("data/c.parquet"),
+                vec![Some("eu".into()), Some("2024".into())]
+            ),
+        ]
+    );
+
+    let df = scan(&root, DeltaVersion::Latest)?;
+    let expected = df![
+        "id" => [3i64, 4, 5, 6],
+        "value" => [1.5, 2.0, 2.5, 3.0],
+        "region" => ["us", "eu", "eu", "eu"],
+        "year" => [2023i32, 2024, 2024, 2024],
+    ]?;
+    assert!(df.equals(&expected));
+
+    // Time travel.
+    let v0 = scan(&root, DeltaVersion::Version(0))?;
+    assert_eq!(
+        v0.column("id")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [1, 2, 3]
+    );
+    assert!(scan(&root, DeltaVersion::Timestamp(1_500))?.equals(&v0));
+    assert!(scan(&root, DeltaVersion::Timestamp(2_000))?.equals(&expected));
+    assert!(DeltaTable::load(&root, DeltaVersion::Version(2)).is_err());
+    assert!(DeltaTable::load(&root, DeltaVersion::Timestamp(999)).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_delta_partition_pruning() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let root = dir.path().to_path_buf();
+    create_table(&root)?;
+    // A file that can't be read. The partition values of the log tell it can be skipped.
+    write_commit(
+        &root,
+        2,
+        &[add("region=xx/year=2020/missing.parquet", "xx", 2020)],
+    )?;
+
+    let df = LazyFrame::scan_delta(&root, Default::default())?
+        .filter(col("region").eq(lit("eu")).and(col("year").gt(lit(2023))))
+        .select([col("id")])
+        .collect()?;
+    assert_eq!(
+        df.column("id")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [4, 5, 6]
+    );
+
+    let res = LazyFrame::scan_delta(&root, Default::default())?.collect();
+    assert!(res.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_delta_checkpoint() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let root = dir.path().to_path_buf();
+    create_table(&root)?;
+
+    // Checkpoint of version 1, the commit of version 1 is cleaned up.
+    let key_value = |keys: &[&str], values: &[&str]| -> PolarsResult<Series> {
+        Ok(StructChunked::new(
+            "entries",
+            &[Series::new("key", keys), Series::new("value", values)],
+        )?
+        .into_series())
+    };
+    let adds = StructChunked::new(
+        "add",
+        &[
+            Series::new(
+                "path",
+                [
+                    None,
+                    None,
+                    Some("region=us/year=2023/b%2520file.parquet"),
+                    Some("data/c.parquet"),
+                ],
+            ),
+            Series::new(
+                "partitionValues",
+                [
+                    key_value(&[], &[])?,
+                    key_value(&[], &[])?,
+                    key_value(&["region", "year"], &["us", "2023"])?,
+                    key_value(&["region", "year"], &["eu", "2024"])?,
+                ],
+            ),
+        ],
+    )?;
+    let metadata = StructChunked::new(
+        "metaData",
+        &[
+            Series::new("schemaString", [None, Some(SCHEMA_STRING), None, None]),
+            Series::new(
+                "partitionColumns",
+                [
+                    Series::new("", Vec::<String>::new()),
+                    Series::new("", ["region", "year"]),
+                    Series::new("", Vec::<String>::new()),
+                    Series::new("", Vec::<String>::new()),
+                ],
+            ),
+            Series::new(
+                "configuration",
+                [
+                    key_value(&[], &[])?,
+                    key_value(&[], &[])?,
+                    key_value(&[], &[])?,
+                    key_value(&[], &[])?,
+                ],
+            ),
+        ],
+    )?;
+    let protocol = StructChunked::new(
+        "protocol",
+        &[Series::new(
+            "minReaderVersion",
+            [Some(1i32), None, None, None],
+        )],
+    )?;
+    let mut checkpoint = DataFrame::new(vec![
+        adds.into_series(),
+        metadata.into_series(),
+        protocol.into_series(),
+    ])?;
+    let checkpoint_path = root.join(format!("_delta_log/{:020}.checkpoint.parquet", 1));
+    ParquetWriter::new(File::create(checkpoint_path)?).finish(&mut checkpoint)?;
+    std::fs::remove_file(root.join(format!("_delta_log/{:020}.json", 1)))?;
+    write_data_file(&root, "data/d.parquet", &[7])?;
+    write_commit(&root, 2, &[add("data/d.parquet", "us", 2024)])?;
+
+    let df = scan(&root, DeltaVersion::Latest)?;
+    assert_eq!(
+        df.column("id")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [3, 4, 5, 6, 7]
+    );
+    let df = scan(&root, DeltaVersion::Version(1))?;
+    assert_eq!(df.height(), 4);
+    let df = scan(&root, DeltaVersion::Version(0))?;
+    assert_eq!(df.height(), 3);
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use std::fs::File;
+
+use polars::io::excel::{read_excel, ExcelReader, ExcelSheet};
+use polars::prelude::*;
+
+const PATH: &str = "../../examples/datasets/foods1.xlsx";
+
+#[test]
+fn test_read_excel() -> PolarsResult<()> {
+    let df = read_excel(PATH)?;
+    let expected = df![
+        "category" => ["vegetables", "seafood", "meat", "fruit", "seafood"],
+        "calories" => [45.0, 150.0, 100.0, 60.0, 140.0],
+        "fats_g" => [0.5, 5.0, 5.0, 0.0, 5.0],
+        "sugars_g" => [2.0, 0.0, 0.0, 11.0, 1.0],
+    ]?;
+    assert!(df.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_read_excel_options() -> PolarsResult<()> {
+    let file = File::open(PATH)?;
+    let names = ExcelReader::new(file).sheet_names()?;
+    assert_eq!(names, ["foods", "events"]);
+
+    let file = File::open(PATH)?;
+    let df = ExcelReader::new(file)
+        .with_sheet(ExcelSheet::Name("events".into()))
+        .with_header_row(Some(1))
+        .finish()?;
+    assert_eq!(df.get_column_names(), ["name", "date", "ts", "flag"]);
+    assert_eq!(
+        df.dtypes(),
+        [
+            DataType::String,
+            DataType::Date,
+            DataType::Datetime(TimeUnit::Milliseconds, None),
+            DataType::Boolean,
+        ]
+    );
+    let dates = df.column("date")?.cast(&DataType::String)?;
+    assert_eq!(
+        Vec::from(dates.str()?),
+        [Some("2023-03-15"), Some("2023-03-16"), None]
+    );
+    let ts = df.column("ts")?.cast(&DataType::String)?;
+    assert_eq!(
+        Vec::from(ts.str()?),
+        [
+            Some("2023-03-15 12:00:00.000"),
+            Some("2023-03-16 06:00:00.000"),
+            None
+        ]
+    );
+
+    let file = File::open(PATH)?;
+    let schema = Schema::from_iter([Field::new("calories", DataType::Int32)]);
+    let df = ExcelReader::new(file)
+        .with_sheet(ExcelSheet::Index(0))
+        .with_schema_overwrite(Some(Arc::new(schema)))
+        .with_columns(Some(vec!["category".into(), "calories".into()]))
+        .with_n_rows(Some(2))
+        .finish()?;
+    let expected = df![
+        "category" => ["vegetables", "seafood"],
+        "calories" => [45i32, 150],
+    ]?;
+    assert!(df.equals(&expected));
+
+    let file = File::open(PATH)?;
+    let df = ExcelReader::new(file).with_header_row(None).finish()?;
+    assert_eq!(df.shape(), (6, 4));
+    assert_eq!(df.get_column_names()[0], "column_1");
+    assert_eq!(df.column("column_2")?.dtype(), &DataType::String);
+
+    let file = File::open(PATH)?;
+    assert!(ExcelReader::new(file)
+        .with_sheet(ExcelSheet::Name("missing".into()))
+        .finish()
+        .is_err());
+    Ok(())
+}
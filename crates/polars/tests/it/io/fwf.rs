@@ -0,0 +1,66 @@
+use std::io::Cursor;
+
+use polars::io::fwf::FwfReader;
+use polars::prelude::*;
+
+const FWF: &str = "\
+id   name      amount  flag
+1    alice      10.50  true
+2    bob         3.00  false
+3                      
+4    carol     100.25  true
+";
+
+#[test]
+fn test_read_fwf() -> PolarsResult<()> {
+    let df = FwfReader::new(Cursor::new(FWF))
+        .with_column_widths(&[5, 10, 7, 6])
+        .with_has_header(true)
+        .finish()?;
+
+    let expected = df![
+        "id" => [1i64, 2, 3, 4],
+        "name" => [Some("alice"), Some("bob"), None, Some("carol")],
+        "amount" => [Some(10.5), Some(3.0), None, Some(100.25)],
+        "flag" => [Some(true), Some(false), None, Some(true)],
+    ]?;
+    assert!(df.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_read_fwf_options() -> PolarsResult<()> {
+    let data = "# export\n0042**XY*\n0007*Z**\n0100**W**\n";
+    let df = FwfReader::new(Cursor::new(data))
+        .with_column_ranges(vec![0..4, 5..8])
+        .with_column_names(Some(vec!["code".into(), "label".into()]))
+        .with_comment_prefix(Some(b'#'))
+        .with_pad_char(b'*')
+        .with_schema_overwrite(Some(Arc::new(Schema::from_iter([Field::new(
+            "code",
+            DataType::String,
+        )]))))
+        .with_n_rows(Some(2))
+        .finish()?;
+
+    let expected = df![
+        "code" => ["0042", "0007"],
+        "label" => ["XY", "Z"],
+    ]?;
+    assert!(df.equals(&expected));
+
+    let df = FwfReader::new(Cursor::new(data))
+        .with_column_ranges(vec![0..4, 5..8])
+        .with_skip_rows(1)
+        .with_trim(false)
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["column_1", "column_2"]);
+    assert_eq!(df.column("column_1")?.i64()?.get(2), Some(100));
+    assert_eq!(df.column("column_2")?.str()?.get(0), Some("*XY"));
+
+    let res = FwfReader::new(Cursor::new(data))
+        .with_column_ranges(vec![0..4, 3..8])
+        .finish();
+    assert!(res.is_err());
+    Ok(())
+}
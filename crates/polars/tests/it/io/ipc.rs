@@ -137,6 +137,118 @@ fn test_write_with_compression() {
     }
 }
 
+#[test]
+fn test_write_with_compression_incompressible_buffers() {
+    // Random buffers don't shrink when compressed and are stored uncompressed.
+    let mut df = df![
+        "a" => (0..1000).map(|i| (i % 100) as i8).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| (i % 7 != 0).then_some(i as f64 / 3.0)).collect::<Vec<_>>(),
+        "c" => (0..1000u64).map(|i| i.wrapping_mul(0x9E3779B97F4A7C15)).collect::<Vec<_>>(),
+        "d" => (0..1000).map(|i| format!("category {}", i % 3)).collect::<Vec<_>>(),
+    ]
+    .unwrap();
+
+    let mut uncompressed = Cursor::new(Vec::new());
+    IpcWriter::new(&mut uncompressed).finish(&mut df).unwrap();
+
+    for compression in [IpcCompression::LZ4, IpcCompression::ZSTD] {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf)
+            .with_compression(Some(compression))
+            .finish(&mut df)
+            .unwrap();
+        assert!(buf.get_ref().len() < uncompressed.get_ref().len());
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish().unwrap();
+        assert!(df.equals_missing(&df_read));
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcStreamWriter::new(&mut buf)
+            .with_compression(Some(compression))
+            .finish(&mut df)
+            .unwrap();
+        buf.set_position(0);
+
+        let df_read = IpcStreamReader::new(buf).finish().unwrap();
+        assert!(df.equals_missing(&df_read));
+    }
+}
+
+#[test]
+fn test_memory_mapped_file_stays_registered() -> PolarsResult<()> {
+    use polars::io::mmap::try_create_file;
+
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("mmap.ipc");
+    let mut df = create_df();
+    IpcWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+    let read = || {
+        IpcReader::new(std::fs::File::open(&path)?)
+            .memory_mapped(Some(path.clone()))
+            .finish()
+    };
+    let a = read()?;
+    let b = read()?;
+    assert!(a.equals(&df));
+
+    // The file may not be written to while any of the mapped data is alive.
+    drop(a);
+    assert!(try_create_file(&path).is_err());
+    let s = b.column("temp")?.clone();
+    drop(b);
+    assert!(try_create_file(&path).is_err());
+    drop(s);
+    assert!(try_create_file(&path).is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_ipc_record_batch_statistics() -> PolarsResult<()> {
+    use polars::io::ipc::read_record_batch_lengths;
+
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("stats.ipc");
+    let mut df = df!("a" => [1, 2, 3], "b" => ["x", "y", "z"])?;
+    df.vstack_mut(&df!("a" => [4, 5, 6], "b" => ["x", "y", "z"])?)?;
+    df.vstack_mut(&df!("a" => [Some(7), None], "b" => ["x", "y"])?)?;
+    IpcWriter::new(std::fs::File::create(&path)?)
+        .with_statistics(true)
+        .finish(&mut df)?;
+
+    let metadata = arrow::io::ipc::read::read_file_metadata(&mut std::fs::File::open(&path)?)?;
+    assert_eq!(read_record_batch_lengths(&metadata), Some(vec![3, 3, 2]));
+    let custom_metadata = metadata.custom_metadata.unwrap();
+    assert_eq!(
+        custom_metadata["polars:record_batch_stats:0"],
+        "1,3,0;4,6,0;7,7,1"
+    );
+    // Only numeric columns get statistics.
+    assert!(!custom_metadata.contains_key("polars:record_batch_stats:1"));
+
+    let out = IpcReader::new(std::fs::File::open(&path)?).finish()?;
+    assert!(out.equals_missing(&df));
+
+    #[cfg(feature = "lazy")]
+    for memory_map in [false, true] {
+        let args = ScanArgsIpc {
+            memory_map,
+            ..Default::default()
+        };
+        let out = LazyFrame::scan_ipc(&path, args.clone())?
+            .filter(col("a").gt(lit(4)))
+            .collect()?;
+        assert!(out.equals(&df!("a" => [5, 6, 7], "b" => ["y", "z", "x"])?));
+
+        let out = LazyFrame::scan_ipc(&path, args)?
+            .select([len()])
+            .collect()?;
+        assert_eq!(out.column("len")?.idx()?.get(0), Some(8));
+    }
+    Ok(())
+}
+
 #[test]
 fn write_and_read_ipc_empty_series() {
     let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
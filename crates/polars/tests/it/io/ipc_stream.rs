@@ -153,4 +153,53 @@ mod test {
         let actual = IpcStreamReader::new(reader).finish().unwrap();
         assert_df_eq!(df(), actual);
     }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_scan_ipc_stream() -> PolarsResult<()> {
+        use polars_lazy::prelude::*;
+
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("scan.arrows");
+        let mut df = df!(
+            "a" => [1, 2, 3, 4],
+            "b" => ["w", "x", "y", "z"],
+            "c" => [1.0, 2.0, 3.0, 4.0],
+        )?;
+        IpcStreamWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+        let actual = LazyFrame::scan_ipc_stream(&path, Default::default())?
+            .select([col("c"), col("a")])
+            .slice(1, 2)
+            .collect()?;
+        let expected = df!(
+            "c" => [2.0, 3.0],
+            "a" => [2, 3],
+        )?;
+        assert_df_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "lazy", unix))]
+    fn test_scan_ipc_stream_from_pipe() -> PolarsResult<()> {
+        use polars_lazy::prelude::*;
+
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("pipe");
+        let status = std::process::Command::new("mkfifo").arg(&path).status()?;
+        assert!(status.success());
+
+        let writer_path = path.clone();
+        let writer = std::thread::spawn(move || {
+            let mut df = create_df();
+            let file = std::fs::OpenOptions::new().write(true).open(writer_path)?;
+            IpcStreamWriter::new(file).finish(&mut df)
+        });
+
+        let actual = LazyFrame::scan_ipc_stream(&path, Default::default())?.collect()?;
+        writer.join().unwrap()?;
+        assert_df_eq!(actual, create_df());
+        Ok(())
+    }
 }
@@ -179,3 +179,133 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+fn test_read_ndjson_batched() -> PolarsResult<()> {
+    let data = r#"{"a": 1, "b": "x"}
+{"a": 2, "b": "y"}
+
+{"a": 3, "b": "z"}
+{"a": 4, "b": null}
+{"a": 5, "b": "w"}
+"#;
+    let mut reader = BatchedJsonLineReader::new(Cursor::new(data))
+        .with_batch_size(NonZeroUsize::new(2).unwrap())
+        .infer_schema_len(NonZeroUsize::new(1));
+    let schema = reader.schema()?;
+    assert_eq!(schema.get("a"), Some(&DataType::Int64));
+
+    let batches = reader.collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(
+        batches.iter().map(|df| df.height()).collect::<Vec<_>>(),
+        [2, 2, 1]
+    );
+    let mut df = batches[0].clone();
+    for batch in &batches[1..] {
+        df.vstack_mut(batch)?;
+    }
+    let expected = df![
+        "a" => [1i64, 2, 3, 4, 5],
+        "b" => [Some("x"), Some("y"), Some("z"), None, Some("w")],
+    ]?;
+    assert!(df.equals_missing(&expected));
+
+    // Schema overwrite and row limit.
+    let overwrite = Schema::from_iter([Field::new("a", DataType::Float64)]);
+    let df = BatchedJsonLineReader::new(Cursor::new(data))
+        .with_schema_overwrite(&overwrite)
+        .with_n_rows(Some(3))
+        .next_batch()?
+        .unwrap();
+    assert_eq!(df.shape(), (3, 2));
+    assert_eq!(df.column("a")?.dtype(), &DataType::Float64);
+    Ok(())
+}
+
+#[test]
+fn test_read_ndjson_batched_ignore_errors() -> PolarsResult<()> {
+    let data = r#"{"a": 1}
+{"a": 2
+{"a": 3}
+"#;
+    let mut reader =
+        BatchedJsonLineReader::new(Cursor::new(data)).with_schema(Arc::new(Schema::from_iter([
+            Field::new("a", DataType::Int64),
+        ])));
+    assert!(reader.next_batch().is_err());
+
+    let df = BatchedJsonLineReader::new(Cursor::new(data))
+        .with_schema(Arc::new(Schema::from_iter([Field::new(
+            "a",
+            DataType::Int64,
+        )])))
+        .with_ignore_errors(true)
+        .next_batch()?
+        .unwrap();
+    let a = df.column("a")?.i64()?;
+    assert_eq!(Vec::from(a), [Some(1), None, Some(3)]);
+    Ok(())
+}
+
+#[test]
+fn test_write_nested_json_roundtrip() -> PolarsResult<()> {
+    let s = df!("x" => [Some(1i64), None, Some(3)], "y" => ["a", "b", "c"])?.into_struct("s");
+    let s = s.into_series();
+    let l = Series::new(
+        "l",
+        [
+            Series::new("", [1i64, 2]),
+            Series::new("", [3i64]),
+            Series::new("", [4i64, 5, 6]),
+        ],
+    );
+    let ls = Series::new("ls", [s.slice(0, 2), s.slice(2, 1), s.clone()]);
+    let ll = Series::new("ll", [l.slice(0, 2), l.slice(1, 1), l.clone()]);
+    let df = DataFrame::new(vec![s, l, ls, ll])?;
+
+    // Slicing makes the serializers start at an offset into the nested values.
+    for df in [df.clone(), df.slice(1, 2)] {
+        for lines in [false, true] {
+            let json_format = || match lines {
+                true => JsonFormat::JsonLines,
+                false => JsonFormat::Json,
+            };
+            let mut buf = vec![];
+            JsonWriter::new(&mut buf)
+                .with_json_format(json_format())
+                .finish(&mut df.clone())?;
+            let out = JsonReader::new(Cursor::new(buf))
+                .with_json_format(json_format())
+                .finish()?;
+            assert!(out.equals_missing(&df));
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-array")]
+fn test_write_json_array_column() -> PolarsResult<()> {
+    let a = Series::new(
+        "a",
+        [
+            Some(Series::new("", [1i64, 2])),
+            None,
+            Some(Series::new("", [5i64, 6])),
+        ],
+    )
+    .cast(&DataType::Array(Box::new(DataType::Int64), 2))?;
+    let mut df = DataFrame::new(vec![a])?.slice(1, 2);
+
+    let mut buf = vec![];
+    JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::JsonLines)
+        .finish(&mut df)?;
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "{\"a\":null}\n{\"a\":[5,6]}\n"
+    );
+
+    Ok(())
+}
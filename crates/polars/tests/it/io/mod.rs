@@ -9,6 +9,21 @@ mod parquet;
 #[cfg(feature = "avro")]
 mod avro;
 
+#[cfg(feature = "excel")]
+mod excel;
+
+#[cfg(feature = "fwf")]
+mod fwf;
+
+#[cfg(feature = "database")]
+mod database;
+
+#[cfg(all(feature = "delta", feature = "lazy"))]
+mod delta;
+
+#[cfg(feature = "partition")]
+mod partitioned;
+
 #[cfg(feature = "ipc")]
 mod ipc;
 #[cfg(feature = "ipc_streaming")]
@@ -212,3 +212,45 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-i128")]
+fn test_int128_roundtrip() -> PolarsResult<()> {
+    let mut df = df! {
+        "a" => [Some(10i128.pow(30)), None, Some(-7)]
+    }?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(read_df.column("a")?.dtype(), &DataType::Int128);
+    assert!(df.equals_missing(&read_df));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_enum_roundtrip_keeps_categories() -> PolarsResult<()> {
+    use polars::export::arrow::array::Utf8ViewArray;
+
+    let categories = Utf8ViewArray::from_slice_values(["low", "mid", "high"]);
+    let dtype = create_enum_data_type(categories);
+    let mut df = df! {
+        "a" => ["high", "low", "high"]
+    }?;
+    df.apply("a", |s| s.strict_cast(&dtype).unwrap())?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(read_df.schema().get("a"), Some(&dtype));
+
+    let read = read_df.column("a")?.categorical()?;
+    assert_eq!(read.get_rev_map().len(), 3);
+    let expected = df.column("a")?.categorical()?;
+    assert!(read
+        .physical()
+        .clone()
+        .into_series()
+        .equals(&expected.physical().clone().into_series()));
+    Ok(())
+}
@@ -1,11 +1,20 @@
 use std::io::BufReader;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+#[cfg(feature = "ipc")]
 use polars::io::ipc::{IpcReader, IpcWriterOption};
+use polars::io::partition::PartitionedWriter;
 use polars::io::prelude::SerReader;
-use polars::io::PartitionedWriter;
-use polars_error::PolarsResult;
-use tempfile;
+use polars::prelude::*;
+
+fn files_in(dir: &Path) -> PolarsResult<Vec<PathBuf>> {
+    std::fs::read_dir(dir)?
+        .map(|e| {
+            let entry = e?;
+            Ok(entry.path())
+        })
+        .collect::<PolarsResult<Vec<_>>>()
+}
 
 #[test]
 #[cfg(feature = "ipc")]
@@ -50,3 +59,64 @@ fn test_ipc_partition() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_csv_partition_file_name() -> PolarsResult<()> {
+    use polars::io::csv::write::CsvWriterOptions;
+
+    let df = df!("key" => ["x", "y", "x"], "value" => [1, 2, 3])?;
+    let tmp_dir = tempfile::tempdir()?;
+    let rootdir = tmp_dir.path().join("csv-partition");
+
+    PartitionedWriter::new(CsvWriterOptions::default(), rootdir.clone(), ["key"])
+        .with_parallel(false)
+        .with_file_name(|i| format!("part-{i}"))
+        .finish(&df)?;
+
+    let paths = files_in(&rootdir.join("key=x"))?;
+    assert_eq!(paths.len(), 1);
+    let file_name = paths[0].file_name().unwrap().to_str().unwrap();
+    assert!(file_name.starts_with("part-") && file_name.ends_with(".csv"));
+
+    let out = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(paths[0].clone()))?
+        .finish()?;
+    assert!(out.equals(&df!("key" => ["x", "x"], "value" => [1i64, 3])?));
+    assert_eq!(files_in(&rootdir.join("key=y"))?.len(), 1);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_write_partitioned_parquet() -> PolarsResult<()> {
+    use polars::io::parquet::write::ParquetWriteOptions;
+    use polars::io::partition::write_partitioned;
+
+    let df = df!("a" => [1, 2, 1, 2], "b" => ["p", "q", "p", "r"], "c" => [1.0, 2.0, 3.0, 4.0])?;
+    let tmp_dir = tempfile::tempdir()?;
+    let rootdir = tmp_dir.path().join("parquet-partition");
+
+    write_partitioned(
+        &df,
+        ParquetWriteOptions::default(),
+        rootdir.clone(),
+        ["a", "b"],
+    )?;
+
+    for (dir, expected) in [
+        (
+            "a=1/b=p",
+            df!("a" => [1, 1], "b" => ["p", "p"], "c" => [1.0, 3.0])?,
+        ),
+        ("a=2/b=q", df!("a" => [2], "b" => ["q"], "c" => [2.0])?),
+        ("a=2/b=r", df!("a" => [2], "b" => ["r"], "c" => [4.0])?),
+    ] {
+        let paths = files_in(&rootdir.join(dir))?;
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].extension().unwrap(), "parquet");
+        let out = ParquetReader::new(polars_utils::open_file(&paths[0])?).finish()?;
+        assert!(out.equals(&expected));
+    }
+    Ok(())
+}
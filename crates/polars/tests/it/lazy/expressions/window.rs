@@ -1,3 +1,5 @@
+use polars_core::series::IsSorted;
+
 use crate::lazy::*;
 
 #[test]
@@ -398,3 +400,76 @@ fn test_window_map_empty_df_3542() -> PolarsResult<()> {
     assert_eq!(out.height(), 0);
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "cum_agg")]
+fn test_cum_agg_window_functions() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "b", "a", "b", "a", "c"],
+        "v" => [Some(3), Some(1), None, Some(5), Some(1), Some(2)]
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("v").cum_sum(false).over([col("g")]).alias("sum"),
+            col("v").cum_sum(true).over([col("g")]).alias("sum_rev"),
+            col("v").cum_min(false).over([col("g")]).alias("min"),
+            col("v").cum_max(true).over([col("g")]).alias("max_rev"),
+            col("v").cum_count(false).over([col("g")]).alias("count"),
+            col("v").cum_count(true).over([col("g")]).alias("count_rev"),
+        ])
+        .collect()?;
+
+    let expected = df![
+        "sum" => [Some(3i32), Some(1), None, Some(6), Some(4), Some(2)],
+        "sum_rev" => [Some(4i32), Some(6), None, Some(5), Some(1), Some(2)],
+        "min" => [Some(3i32), Some(1), None, Some(1), Some(1), Some(2)],
+        "max_rev" => [Some(3i32), Some(5), None, Some(5), Some(1), Some(2)],
+        "count" => [1 as IdxSize, 1, 1, 2, 2, 1],
+        "count_rev" => [2 as IdxSize, 2, 1, 1, 1, 1],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_window_order_by() -> PolarsResult<()> {
+    let df = df![
+        "groups" => [1, 1, 1, 2, 2],
+        "t" => [3, 1, 2, 2, 1],
+        "values" => [10, 20, 30, 40, 50]
+    ]?;
+
+    // The groups are sorted by `t`, but the values are mapped back to their rows, also if the
+    // keys are sorted.
+    let expected = [Some(30), None, Some(20), Some(50), None];
+    for groups in [
+        col("groups"),
+        col("groups").set_sorted_flag(IsSorted::Ascending),
+    ] {
+        let out = df
+            .clone()
+            .lazy()
+            .select([col("values").shift(lit(1)).over_with_options(
+                [groups],
+                Some(([col("t")], SortOptions::default())),
+                WindowMapping::GroupsToRows,
+            )])
+            .collect()?;
+        assert_eq!(Vec::from(out.column("values")?.i32()?), expected);
+    }
+
+    let out = df
+        .lazy()
+        .select([col("values").shift(lit(1)).over_with_options(
+            [col("groups")],
+            Some(([col("t")], SortOptions::default())),
+            WindowMapping::Explode,
+        )])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("values")?.i32()?),
+        [None, Some(20), Some(30), None, Some(50)]
+    );
+    Ok(())
+}
@@ -110,3 +110,115 @@ fn fuzz_exprs() {
         }
     }
 }
+
+#[test]
+#[cfg(feature = "dtype-array")]
+fn test_array_mean() -> PolarsResult<()> {
+    let s = Series::new(
+        "a",
+        &[
+            Series::new("", &[1, 2]),
+            Series::new("", &[3, 6]),
+            Series::new("", &[Some(4), None]),
+        ],
+    )
+    .cast(&DataType::Array(Box::new(DataType::Int32), 2))?;
+
+    let out = DataFrame::new(vec![s])?
+        .lazy()
+        .select([col("a").arr().mean()])
+        .collect()?;
+    assert_eq!(
+        out.column("a")?,
+        &Series::new("a", &[Some(1.5), Some(4.5), Some(4.0)])
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-array", feature = "dtype-i128"))]
+fn test_array_mean_int128() -> PolarsResult<()> {
+    let s = Series::new(
+        "a",
+        &[Series::new("", &[1i64, 2]), Series::new("", &[3i64, 6])],
+    )
+    .cast(&DataType::Array(Box::new(DataType::Int128), 2))?;
+
+    let out = DataFrame::new(vec![s])?
+        .lazy()
+        .select([col("a").arr().mean()])
+        .collect()?;
+    assert_eq!(out.column("a")?, &Series::new("a", &[1.5, 4.5]));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "strings", feature = "extract_groups"))]
+fn test_extract_named_and_all_groups() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some("x=1, y=2"), None, Some("x=3")]
+    ]?;
+    let pat = r"(?P<key>\w)=(?P<value>\d)";
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").str().extract_named(lit(pat), "value")])
+        .collect()?;
+    assert_eq!(
+        out.column("a")?,
+        &Series::new("a", &[Some("1"), None, Some("3")])
+    );
+
+    let out = df
+        .lazy()
+        .select([col("a").str().extract_all_groups(pat)?])
+        .collect()?;
+    let out = out.column("a")?.list()?;
+    assert_eq!(out.null_count(), 1);
+
+    let first = out.get_as_series(0).unwrap();
+    let first = first.struct_()?;
+    assert_eq!(first.field_by_name("key")?, Series::new("key", &["x", "y"]));
+    assert_eq!(
+        first.field_by_name("value")?,
+        Series::new("value", &["1", "2"])
+    );
+    assert_eq!(out.get_as_series(2).unwrap().len(), 1);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "strings",
+    feature = "binary_encoding",
+    feature = "string_encoding"
+))]
+fn test_str_encode_decode_roundtrip() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some("foo"), None, Some("ab\u{e9}")]
+    ]?;
+
+    for encoding in [BinaryEncoding::Hex, BinaryEncoding::Base64] {
+        let out = df
+            .clone()
+            .lazy()
+            .select([col("a")
+                .str()
+                .encode(encoding)
+                .str()
+                .decode(encoding, true)
+                .cast(DataType::String)])
+            .collect()?;
+        assert!(out.equals_missing(&df));
+    }
+
+    let out = df![
+        "a" => ["zz", "666f6f"]
+    ]?
+    .lazy()
+    .select([col("a").str().decode(BinaryEncoding::Hex, false)])
+    .collect()?;
+    assert_eq!(out.column("a")?.null_count(), 1);
+    Ok(())
+}
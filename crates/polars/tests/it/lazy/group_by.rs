@@ -153,6 +153,51 @@ fn test_logical_mean_partitioned_group_by_block() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "dtype-decimal")]
+fn test_decimal_group_by_aggregations() -> PolarsResult<()> {
+    let value = Int128Chunked::from_slice_options(
+        "value",
+        &[Some(125), Some(250), None, Some(-100), Some(-5)],
+    )
+    .into_decimal_unchecked(Some(10), 2)
+    .into_series();
+    let df = DataFrame::new(vec![Series::new("group", &[1, 1, 1, 2, 2]), value])?;
+
+    let out = df
+        .lazy()
+        .group_by([col("group")])
+        .agg([
+            col("value").sum().alias("sum"),
+            col("value").min().alias("min"),
+            col("value").max().alias("max"),
+            col("value").mean().alias("mean"),
+        ])
+        .sort(["group"], Default::default())
+        .collect()?;
+
+    assert_eq!(
+        out.column("mean")?.dtype(),
+        &DataType::Decimal(Some(10), Some(2))
+    );
+    let get = |name: &str, i: usize| {
+        out.column(name)
+            .unwrap()
+            .get(i)
+            .unwrap()
+            .into_static()
+            .unwrap()
+    };
+    assert_eq!(get("sum", 0), AnyValue::Decimal(375, 2));
+    assert_eq!(get("min", 0), AnyValue::Decimal(125, 2));
+    assert_eq!(get("max", 1), AnyValue::Decimal(-5, 2));
+    // Means are rounded half away from zero at the scale of the column.
+    assert_eq!(get("mean", 0), AnyValue::Decimal(188, 2));
+    assert_eq!(get("mean", 1), AnyValue::Decimal(-53, 2));
+
+    Ok(())
+}
+
 #[test]
 fn test_filter_aggregated_expression() -> PolarsResult<()> {
     let df: DataFrame = df![
@@ -61,3 +61,64 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[test]
+#[cfg(all(
+    feature = "temporal",
+    feature = "dtype-date",
+    feature = "dynamic_group_by"
+))]
+fn test_group_by_dynamic_agg_map() -> PolarsResult<()> {
+    let start = NaiveDate::from_ymd_opt(2022, 2, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let stop = NaiveDate::from_ymd_opt(2022, 2, 6)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let range = polars_time::date_range(
+        "dt",
+        start,
+        stop,
+        Duration::parse("1d"),
+        ClosedWindow::Left,
+        TimeUnit::Milliseconds,
+        None,
+    )?
+    .into_series();
+
+    let df = df![
+        "dt" => range,
+        "a" => [1, 5, 2, 8, 3]
+    ]?;
+
+    // The range of the values in every window.
+    let spread = |s: Series| {
+        let max = s.max::<i32>()?.unwrap_or_default();
+        let min = s.min::<i32>()?.unwrap_or_default();
+        Ok(Series::new(s.name(), [max - min]))
+    };
+
+    let out = df
+        .lazy()
+        .group_by_dynamic(
+            col("dt"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("2d"),
+                period: Duration::parse("2d"),
+                offset: Duration::parse("0d"),
+                closed_window: ClosedWindow::Left,
+                label: Label::Left,
+                start_by: StartBy::DataPoint,
+                ..Default::default()
+            },
+        )
+        .agg([col("a").agg_map(spread, GetOutput::same_type())])
+        .collect()?;
+    let a = out.column("a")?;
+    assert_eq!(a.dtype(), &DataType::Int32);
+    assert_eq!(Vec::from(a.i32()?), &[Some(4), Some(6), Some(0)]);
+    Ok(())
+}
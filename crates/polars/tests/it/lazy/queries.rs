@@ -272,3 +272,21 @@ fn test_group_by_on_lists() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "partition_by")]
+fn test_partition_by_lazy() -> PolarsResult<()> {
+    use polars_lazy::frame::partition_by::partition_by_lazy;
+
+    let df = df![
+        "g" => [Some("b"), Some("a"), Some("b"), None, Some("a")],
+        "v" => [1, 2, 3, 4, 5]
+    ]?;
+    let expected = df.partition_by_stable(["g"], false)?;
+    let out = partition_by_lazy(&df, ["g"], true, false)?;
+    assert_eq!(out.len(), 3);
+    for (lf, expected) in out.into_iter().zip(&expected) {
+        assert!(lf.collect()?.equals_missing(expected));
+    }
+    Ok(())
+}
@@ -135,6 +135,7 @@ from polars.functions import (
     rolling_cov,
     select,
     set_random_seed,
+    set_streaming_metrics_callback,
     sql_expr,
     std,
     struct,
@@ -309,6 +310,7 @@ __all__ = [
     "col",
     "collect_all",
     "collect_all_async",
+    "set_streaming_metrics_callback",
     "concat_list",
     "concat_str",
     "corr",
@@ -182,4 +182,4 @@ def _combine_predicates(predicates: list[PyExpr]) -> PyExpr:
     if len(predicates) == 1:
         return predicates[0]
 
-    return plr.all_horizontal(predicates)
+    return plr.all_horizontal(predicates, False)
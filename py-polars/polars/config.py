@@ -61,7 +61,10 @@ _POLARS_CFG_ENV_VARS = {
     "POLARS_FMT_TABLE_HIDE_DATAFRAME_SHAPE_INFORMATION",
     "POLARS_FMT_TABLE_INLINE_COLUMN_DATA_TYPE",
     "POLARS_FMT_TABLE_ROUNDED_CORNERS",
+    "POLARS_STREAMING_APPROX_QUANTILE",
     "POLARS_STREAMING_CHUNK_SIZE",
+    "POLARS_STREAMING_MEMORY_LIMIT",
+    "POLARS_STREAMING_QUEUE_DEPTH",
     "POLARS_TABLE_WIDTH",
     "POLARS_VERBOSE",
     "POLARS_MAX_EXPR_DEPTH",
@@ -723,6 +726,29 @@ class Config(contextlib.ContextDecorator):
             os.environ["POLARS_FMT_TABLE_CELL_LIST_LEN"] = str(n)
         return cls
 
+    @classmethod
+    def set_streaming_approx_quantiles(cls, active: bool | None = True) -> type[Config]:
+        """
+        Allow the `streaming` engine to approximate medians and quantiles.
+
+        Group bys with `median` or `quantile` aggregations are not streamed by
+        default, as their exact result requires all values of a group at once.
+        When approximation is allowed, these aggregations are estimated with
+        mergeable sketches (t-digests) of the groups, so that they can be streamed.
+        Only numeric columns and literal quantiles are supported.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"g": [1, 1, 2], "v": [1.0, 2.0, 3.0]})
+        >>> with pl.Config(set_streaming_approx_quantiles=True):
+        ...     out = lf.group_by("g").agg(pl.median("v")).collect(streaming=True)
+        """
+        if active is None:
+            os.environ.pop("POLARS_STREAMING_APPROX_QUANTILE", None)
+        else:
+            os.environ["POLARS_STREAMING_APPROX_QUANTILE"] = str(int(active))
+        return cls
+
     @classmethod
     def set_streaming_chunk_size(cls, size: int | None) -> type[Config]:
         """
@@ -749,6 +775,61 @@ class Config(contextlib.ContextDecorator):
             os.environ["POLARS_STREAMING_CHUNK_SIZE"] = str(size)
         return cls
 
+    @classmethod
+    def set_streaming_memory_limit(cls, n_bytes: int | None) -> type[Config]:
+        """
+        Set the memory budget of the `streaming` engine.
+
+        By default, the streaming engine spills sorts and joins to disk when the
+        system runs low on free memory. With a memory budget, these spill as soon
+        as the data held by the engine exceeds the budget, and the sources read a
+        single chunk at a time until the data is spilled.
+
+        Parameters
+        ----------
+        n_bytes
+            Number of bytes the streaming engine may hold in memory. The budget is
+            shared by all queries that run at the same time.
+
+        Examples
+        --------
+        >>> pl.Config.set_streaming_memory_limit(2**30)  # doctest: +SKIP
+        """
+        if n_bytes is None:
+            os.environ.pop("POLARS_STREAMING_MEMORY_LIMIT", None)
+        else:
+            if n_bytes < 0:
+                msg = "memory limit must be >= 0"
+                raise ValueError(msg)
+
+            os.environ["POLARS_STREAMING_MEMORY_LIMIT"] = str(n_bytes)
+        return cls
+
+    @classmethod
+    def set_streaming_queue_depth(cls, depth: int | None) -> type[Config]:
+        """
+        Set the number of chunks the sources of the `streaming` engine read at once.
+
+        By default, the sources read a chunk for every thread, so that all threads
+        can process a chunk at the same time. A lower depth keeps fewer chunks in
+        memory at the expense of parallelism.
+
+        Parameters
+        ----------
+        depth
+            Number of chunks that are processed at the same time. This is capped
+            at the number of threads.
+        """
+        if depth is None:
+            os.environ.pop("POLARS_STREAMING_QUEUE_DEPTH", None)
+        else:
+            if depth < 1:
+                msg = "queue depth must be >= 1"
+                raise ValueError(msg)
+
+            os.environ["POLARS_STREAMING_QUEUE_DEPTH"] = str(depth)
+        return cls
+
     @classmethod
     def set_tbl_cell_alignment(
         cls, format: Literal["LEFT", "CENTER", "RIGHT"] | None
@@ -4031,12 +4031,45 @@ class DataFrame:
         sz = self._df.estimated_size()
         return scale_bytes(sz, unit)
 
+    def memory_usage(self) -> DataFrame:
+        """
+        Return a breakdown of the estimated (heap) allocated size of every column.
+
+        The result has a row per column, with the size in bytes of its validity
+        bitmaps, of its values (including offsets, string data and nested arrays) and
+        of the categories of a categorical column, their total and the number of
+        chunks of the column. The sizes are estimated as in :meth:`estimated_size`.
+
+        See Also
+        --------
+        estimated_size
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [1, None, 3], "b": ["x", "y", "z"]},
+        ...     schema={"a": pl.Int64, "b": pl.Categorical},
+        ... )
+        >>> df.memory_usage().select("column", "validity_bytes", "n_chunks")
+        shape: (2, 3)
+        ┌────────┬────────────────┬──────────┐
+        │ column ┆ validity_bytes ┆ n_chunks │
+        │ ---    ┆ ---            ┆ ---      │
+        │ str    ┆ u64            ┆ u64      │
+        ╞════════╪════════════════╪══════════╡
+        │ a      ┆ 1              ┆ 1        │
+        │ b      ┆ 0              ┆ 1        │
+        └────────┴────────────────┴──────────┘
+        """
+        return self._from_pydf(self._df.memory_usage())
+
     def transpose(
         self,
         *,
         include_header: bool = False,
         header_name: str = "column",
         column_names: str | Iterable[str] | None = None,
+        dtype: PolarsDataType | None = None,
     ) -> DataFrame:
         """
         Transpose a DataFrame over the diagonal.
@@ -4051,6 +4084,9 @@ class DataFrame:
         column_names
             Optional iterable yielding strings or a string naming an existing column.
             These will name the value (non-header) columns in the transposed data.
+        dtype
+            Data type of the value columns in the transposed data. If not set, the
+            supertype of all columns is used.
 
         Notes
         -----
@@ -4144,11 +4180,24 @@ class DataFrame:
         │ a      ┆ 1   ┆ 2   ┆ 3   │
         │ b      ┆ 4   ┆ 5   ┆ 6   │
         └────────┴─────┴─────┴─────┘
+
+        Cast the values to a given data type instead of their supertype
+
+        >>> df.transpose(column_names="id", dtype=pl.Float64)
+        shape: (2, 3)
+        ┌─────┬─────┬─────┐
+        │ i   ┆ j   ┆ k   │
+        │ --- ┆ --- ┆ --- │
+        │ f64 ┆ f64 ┆ f64 │
+        ╞═════╪═════╪═════╡
+        │ 1.0 ┆ 2.0 ┆ 3.0 │
+        │ 4.0 ┆ 5.0 ┆ 6.0 │
+        └─────┴─────┴─────┘
         """
         keep_names_as = header_name if include_header else None
         if isinstance(column_names, Generator):
             column_names = [next(column_names) for _ in range(self.height)]
-        return self._from_pydf(self._df.transpose(keep_names_as, column_names))
+        return self._from_pydf(self._df.transpose(keep_names_as, column_names, dtype))
 
     def reverse(self) -> DataFrame:
         """
@@ -5538,8 +5587,6 @@ class DataFrame:
         maintain_order
             Ensure that the order of the groups is consistent with the input data.
             This is slower than a default group by.
-            Settings this to `True` blocks the possibility
-            to run on the streaming engine.
 
             .. note::
                 Within each group, the order of rows is always preserved, regardless
@@ -6732,6 +6779,68 @@ class DataFrame:
             .collect(_eager=True)
         )
 
+    @unstable()
+    def join_where(
+        self,
+        other: DataFrame,
+        *predicates: Expr | Iterable[Expr],
+        suffix: str = "_right",
+    ) -> DataFrame:
+        """
+        Join on one or more predicates between the columns of both frames.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Up to two inequalities between a column of each frame, such as
+        `pl.col("ts") >= pl.col("start")`, are evaluated by an inequality join.
+        Other predicates filter the joined rows.
+
+        Parameters
+        ----------
+        other
+            DataFrame to join with.
+        *predicates
+            (In)Equality condition to join the two frames on. Columns of `other`
+            whose name also occurs in this frame are referred to with the `suffix`.
+        suffix
+            Suffix to append to columns with a duplicate name.
+
+        See Also
+        --------
+        join
+
+        Examples
+        --------
+        >>> events = pl.DataFrame({"ts": [1, 4, 7, 10]})
+        >>> windows = pl.DataFrame(
+        ...     {"start": [0, 3, 5], "end": [2, 6, 9], "id": ["a", "b", "c"]}
+        ... )
+        >>> events.join_where(
+        ...     windows, pl.col("ts") >= pl.col("start"), pl.col("ts") < pl.col("end")
+        ... )
+        shape: (3, 4)
+        ┌─────┬───────┬─────┬─────┐
+        │ ts  ┆ start ┆ end ┆ id  │
+        │ --- ┆ ---   ┆ --- ┆ --- │
+        │ i64 ┆ i64   ┆ i64 ┆ str │
+        ╞═════╪═══════╪═════╪═════╡
+        │ 1   ┆ 0     ┆ 2   ┆ a   │
+        │ 4   ┆ 3     ┆ 6   ┆ b   │
+        │ 7   ┆ 5     ┆ 9   ┆ c   │
+        └─────┴───────┴─────┴─────┘
+        """
+        if not isinstance(other, DataFrame):
+            msg = f"expected `other` join table to be a DataFrame, got {type(other).__name__!r}"
+            raise TypeError(msg)
+
+        return (
+            self.lazy()
+            .join_where(other.lazy(), *predicates, suffix=suffix)
+            .collect(_eager=True)
+        )
+
     def map_rows(
         self,
         function: Callable[[tuple[Any, ...]], Any],
@@ -9452,6 +9561,7 @@ class DataFrame:
         with_replacement: bool = False,
         shuffle: bool = False,
         seed: int | None = None,
+        weights: str | Series | None = None,
     ) -> DataFrame:
         """
         Sample from this DataFrame.
@@ -9472,6 +9582,10 @@ class DataFrame:
         seed
             Seed for the random number generator. If set to None (default), a
             random seed is generated for each sample operation.
+        weights
+            Name of a column or a Series with non-negative weights of the rows. If set,
+            every row is sampled with a probability proportional to its weight. Rows
+            with a zero or null weight are never sampled.
 
         Examples
         --------
@@ -9500,12 +9614,18 @@ class DataFrame:
         if seed is None:
             seed = random.randint(0, 10000)
 
+        if isinstance(weights, str):
+            weights = self.get_column(weights)
+        pyweights = None if weights is None else weights._s
+
         if n is None and fraction is not None:
             if not isinstance(fraction, pl.Series):
                 fraction = pl.Series("frac", [fraction])
 
             return self._from_pydf(
-                self._df.sample_frac(fraction._s, with_replacement, shuffle, seed)
+                self._df.sample_frac(
+                    fraction._s, with_replacement, shuffle, seed, pyweights
+                )
             )
 
         if n is None:
@@ -9514,7 +9634,9 @@ class DataFrame:
         if not isinstance(n, pl.Series):
             n = pl.Series("", [n])
 
-        return self._from_pydf(self._df.sample_n(n._s, with_replacement, shuffle, seed))
+        return self._from_pydf(
+            self._df.sample_n(n._s, with_replacement, shuffle, seed, pyweights)
+        )
 
     def fold(self, operation: Callable[[Series, Series], Series]) -> Series:
         """
@@ -13,7 +13,7 @@ from polars._utils.wrap import wrap_expr
 from polars.datatypes import DTYPE_TEMPORAL_UNITS, Date, Int32
 
 if TYPE_CHECKING:
-    from polars import Expr
+    from polars import Expr, Series
     from polars.type_aliases import (
         Ambiguous,
         EpochTimeUnit,
@@ -21,6 +21,7 @@ if TYPE_CHECKING:
         IntoExprColumn,
         NonExistent,
         Roll,
+        RoundTies,
         TimeUnit,
     )
 
@@ -37,7 +38,7 @@ class ExprDateTimeNameSpace:
         self,
         n: int | IntoExpr,
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
-        holidays: Iterable[dt.date] = (),
+        holidays: Iterable[dt.date] | Expr | Series = (),
         roll: Roll = "raise",
     ) -> Expr:
         """
@@ -65,6 +66,9 @@ class ExprDateTimeNameSpace:
                 my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
             and pass `holidays=my_holidays` when you call `business_day_count`.
+
+            Holidays can also differ per row, by passing an expression or Series
+            of data type `List(Date)` with the holidays of every row instead.
         roll
             What to do when the start date lands on a non-business day. Options are:
 
@@ -140,6 +144,10 @@ class ExprDateTimeNameSpace:
         └────────────┴─────────────────┘
         """
         n_pyexpr = parse_into_expression(n)
+        row_holidays = None
+        if isinstance(holidays, (pl.Expr, pl.Series)):
+            row_holidays = parse_into_expression(holidays)
+            holidays = ()
         unix_epoch = dt.date(1970, 1, 1)
         return wrap_expr(
             self._pyexpr.dt_add_business_days(
@@ -147,6 +155,7 @@ class ExprDateTimeNameSpace:
                 week_mask,
                 [(holiday - unix_epoch).days for holiday in holidays],
                 roll,
+                row_holidays,
             )
         )
 
@@ -277,7 +286,9 @@ class ExprDateTimeNameSpace:
         return wrap_expr(self._pyexpr.dt_truncate(every))
 
     @unstable()
-    def round(self, every: str | dt.timedelta | IntoExprColumn) -> Expr:
+    def round(
+        self, every: str | dt.timedelta | IntoExprColumn, *, ties: RoundTies = "up"
+    ) -> Expr:
         """
         Divide the date/datetime range into buckets.
 
@@ -298,6 +309,9 @@ class ExprDateTimeNameSpace:
         ----------
         every
             Every interval start and period length
+        ties : {'up', 'down'}
+            Whether a date/datetime exactly halfway between two interval boundaries
+            is mapped to the later (`'up'`) or the earlier (`'down'`) boundary.
 
         Returns
         -------
@@ -382,7 +396,7 @@ class ExprDateTimeNameSpace:
         if isinstance(every, dt.timedelta):
             every = parse_as_duration_string(every)
         every = parse_into_expression(every, str_as_lit=True)
-        return wrap_expr(self._pyexpr.dt_round(every))
+        return wrap_expr(self._pyexpr.dt_round(every, ties))
 
     def combine(self, time: dt.time | Expr, time_unit: TimeUnit = "us") -> Expr:
         """
@@ -1656,6 +1670,7 @@ class ExprDateTimeNameSpace:
 
             - `'raise'` (default): raise
             - `'null'`: set to null
+            - `'shift_forward'`: use the first datetime after the gap
 
         Examples
         --------
@@ -2014,7 +2029,7 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_total_nanoseconds())
 
-    def offset_by(self, by: str | Expr) -> Expr:
+    def offset_by(self, by: str | dt.timedelta | Expr) -> Expr:
         """
         Offset this date by a relative time offset.
 
@@ -2043,6 +2058,11 @@ class ExprDateTimeNameSpace:
             not be 24 hours, due to daylight savings). Similarly for "calendar week",
             "calendar month", "calendar quarter", and "calendar year".
 
+            The offset can also be an expression of data type `String` or
+            `Duration`, in which case every row is offset by its own value.
+            Durations have a fixed length, so they don't take the calendar into
+            account.
+
         Returns
         -------
         Expr
@@ -79,6 +79,7 @@ if TYPE_CHECKING:
     from polars._utils.various import (
         NoDefault,
     )
+    from polars.datatypes import DataType
     from polars.type_aliases import (
         ClosedInterval,
         FillNullStrategy,
@@ -86,9 +87,11 @@ if TYPE_CHECKING:
         IntoExpr,
         IntoExprColumn,
         MapElementsStrategy,
+        ModeTieBreak,
         NullBehavior,
         NumericLiteral,
         PolarsDataType,
+        ProductOverflow,
         RankMethod,
         RollingInterpolationMethod,
         SearchSortedSide,
@@ -1678,12 +1681,25 @@ class Expr:
         other = parse_into_expression(other)
         return self._from_pyexpr(self._pyexpr.dot(other))
 
-    def mode(self) -> Expr:
+    def mode(self, tie_break: ModeTieBreak | None = None) -> Expr:
         """
         Compute the most occurring value(s).
 
         Can return multiple Values.
 
+        Parameters
+        ----------
+        tie_break : {None, 'first', 'min', 'max'}
+            How to choose a single value if several values occur most often.
+
+            - None : return all of them, which makes the result a list in a
+              `group_by` aggregation.
+            - 'first' : the value that occurs first.
+            - 'min' : the smallest value.
+            - 'max' : the largest value.
+
+            A null value is only chosen if it is the sole mode.
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -1702,8 +1718,30 @@ class Expr:
         │ 1   ┆ 1   │
         │ 1   ┆ 2   │
         └─────┴─────┘
+
+        Choose a single value per group.
+
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "g": ["x", "x", "x", "x", "y"],
+        ...         "v": [3, 1, 3, 1, 2],
+        ...     }
+        ... )
+        >>> df.group_by("g", maintain_order=True).agg(
+        ...     pl.col("v").mode().alias("all"),
+        ...     pl.col("v").mode(tie_break="min").alias("min"),
+        ... )
+        shape: (2, 3)
+        ┌─────┬───────────┬─────┐
+        │ g   ┆ all       ┆ min │
+        │ --- ┆ ---       ┆ --- │
+        │ str ┆ list[i64] ┆ i64 │
+        ╞═════╪═══════════╪═════╡
+        │ x   ┆ [3, 1]    ┆ 1   │
+        │ y   ┆ [2]       ┆ 2   │
+        └─────┴───────────┴─────┘
         """
-        return self._from_pyexpr(self._pyexpr.mode())
+        return self._from_pyexpr(self._pyexpr.mode(tie_break))
 
     def cast(
         self,
@@ -2796,6 +2834,11 @@ class Expr:
         limit
             The number of consecutive null values to forward fill.
 
+        Notes
+        -----
+        In a group by or window context, such as `over`, values are only filled
+        within their group.
+
         See Also
         --------
         backward_fill
@@ -2832,6 +2875,11 @@ class Expr:
         limit
             The number of consecutive null values to backward fill.
 
+        Notes
+        -----
+        In a group by or window context, such as `over`, values are only filled
+        within their group.
+
         See Also
         --------
         forward_fill
@@ -3102,10 +3150,20 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.median())
 
-    def product(self) -> Expr:
+    def product(self, overflow: ProductOverflow = "wrap") -> Expr:
         """
         Compute the product of an expression.
 
+        Parameters
+        ----------
+        overflow : {'wrap', 'float64'}
+            How to deal with products of integers that don't fit in 64 bits.
+
+            - 'wrap' : multiply in `Int64` (`UInt64` for `UInt64` values) and wrap
+              around on overflow.
+            - 'float64' : multiply in `Float64`, which loses precision instead of
+              overflowing.
+
         Examples
         --------
         >>> df = pl.DataFrame({"a": [1, 2, 3]})
@@ -3118,8 +3176,21 @@ class Expr:
         ╞═════╡
         │ 6   │
         └─────┘
+
+        Products that overflow 64 bits can be computed as floats.
+
+        >>> df = pl.DataFrame({"a": [2**32, 2**32]})
+        >>> df.select(pl.col("a").product(overflow="float64"))
+        shape: (1, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ f64       │
+        ╞═══════════╡
+        │ 1.8447e19 │
+        └───────────┘
         """
-        return self._from_pyexpr(self._pyexpr.product())
+        return self._from_pyexpr(self._pyexpr.product(overflow))
 
     def n_unique(self) -> Expr:
         """
@@ -3147,12 +3218,20 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.n_unique())
 
-    def approx_n_unique(self) -> Expr:
+    def approx_n_unique(self, precision: int = 14) -> Expr:
         """
         Approximate count of unique values.
 
         This is done using the HyperLogLog++ algorithm for cardinality estimation.
 
+        Parameters
+        ----------
+        precision
+            The base-2 logarithm of the number of registers of the sketch, between 4
+            and 18. The relative error is about `1.04 / sqrt(2**precision)`, so a
+            lower precision makes the estimate cheaper and less accurate, which can be
+            worthwhile in a `group_by` with many groups.
+
         Examples
         --------
         >>> df = pl.DataFrame({"n": [1, 1, 2]})
@@ -3179,7 +3258,7 @@ class Expr:
         │ 1000  ┆ 1005   │
         └───────┴────────┘
         """
-        return self._from_pyexpr(self._pyexpr.approx_n_unique())
+        return self._from_pyexpr(self._pyexpr.approx_n_unique(precision))
 
     def null_count(self) -> Expr:
         """
@@ -3821,6 +3900,67 @@ class Expr:
         quantile = parse_into_expression(quantile)
         return self._from_pyexpr(self._pyexpr.quantile(quantile, interpolation))
 
+    def quantile_approx(self, quantile: float) -> Expr:
+        """
+        Get an approximation of the quantile value.
+
+        The quantile is estimated from a t-digest, a small summary of the values that
+        can be computed in parallel and keeps the extreme quantiles accurate. This is
+        much cheaper than :meth:`quantile` on large data, at the cost of a small error.
+        Null and NaN values are ignored.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        See Also
+        --------
+        quantile
+        median_approx
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").quantile_approx(0.25))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 1.0 │
+        └─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.quantile_approx(quantile))
+
+    def median_approx(self) -> Expr:
+        """
+        Get an approximation of the median value.
+
+        This is equivalent to `quantile_approx(0.5)`.
+
+        See Also
+        --------
+        median
+        quantile_approx
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"g": [1, 1, 1, 2, 2], "a": [3, 1, 2, 5, 4]})
+        >>> df.group_by("g", maintain_order=True).agg(pl.col("a").median_approx())
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ g   ┆ a   │
+        │ --- ┆ --- │
+        │ i64 ┆ f64 │
+        ╞═════╪═════╡
+        │ 1   ┆ 2.0 │
+        │ 2   ┆ 4.5 │
+        └─────┴─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.median_approx())
+
     @unstable()
     def cut(
         self,
@@ -4238,6 +4378,13 @@ class Expr:
                 result = pl.Series(result, dtype=self.return_dtype)
             return result
 
+    class _return_dtype_fn_wrapper:
+        def __init__(self, function: Callable[[DataType], PolarsDataType]):
+            self.function = function
+
+        def __call__(self, dtype: DataType) -> PolarsDataType:
+            return py_type_to_dtype(self.function(dtype))
+
     def map_batches(
         self,
         function: Callable[[Series], Series | Any],
@@ -4246,6 +4393,7 @@ class Expr:
         agg_list: bool = False,
         is_elementwise: bool = False,
         returns_scalar: bool = False,
+        return_dtype_fn: Callable[[DataType], PolarsDataType] | None = None,
     ) -> Expr:
         """
         Apply a custom python function to a whole Series or sequence of Series.
@@ -4283,10 +4431,16 @@ class Expr:
             a list in the output, since the assumption is that the function
             always returns something Series-like. If you want to keep the
             result as a scalar, set this argument to True.
+        return_dtype_fn
+            Function that computes the dtype of the output Series from the dtype of
+            the input Series. It takes precedence over `return_dtype` and lets the
+            schema be resolved without calling `function`, for functions whose
+            output dtype depends on their input.
 
         Warnings
         --------
-        If `return_dtype` is not provided, this may lead to unexpected results.
+        If neither `return_dtype` nor `return_dtype_fn` is provided, this may lead to
+        unexpected results.
         We allow this, but it is considered a bug in the user's query.
 
         See Also
@@ -4379,6 +4533,8 @@ class Expr:
         """
         if return_dtype is not None:
             return_dtype = py_type_to_dtype(return_dtype)
+        if return_dtype_fn is not None:
+            return_dtype_fn = self._return_dtype_fn_wrapper(return_dtype_fn)
 
         return self._from_pyexpr(
             self._pyexpr.map_batches(
@@ -4387,6 +4543,7 @@ class Expr:
                 agg_list,
                 is_elementwise,
                 returns_scalar,
+                return_dtype_fn,
             )
         )
 
@@ -5980,8 +6137,9 @@ class Expr:
 
         Parameters
         ----------
-        method : {'linear', 'nearest'}
-            Interpolation method.
+        method : {'linear', 'nearest', 'cubic'}
+            Interpolation method. `'cubic'` fits a natural cubic spline through the
+            non-null values.
 
         Examples
         --------
@@ -6051,7 +6209,9 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.interpolate(method))
 
-    def interpolate_by(self, by: IntoExpr) -> Expr:
+    def interpolate_by(
+        self, by: IntoExpr, method: InterpolationMethod = "linear"
+    ) -> Expr:
         """
         Fill null values using interpolation based on another column.
 
@@ -6059,6 +6219,9 @@ class Expr:
         ----------
         by
             Column to interpolate values based on.
+        method : {'linear', 'nearest', 'cubic'}
+            Interpolation method. `'cubic'` fits a natural cubic spline through the
+            non-null values.
 
         Examples
         --------
@@ -6084,7 +6247,7 @@ class Expr:
         └──────┴─────┴────────────────┘
         """
         by = parse_into_expression(by)
-        return self._from_pyexpr(self._pyexpr.interpolate_by(by))
+        return self._from_pyexpr(self._pyexpr.interpolate_by(by, method))
 
     @unstable()
     def rolling_min_by(
@@ -9172,6 +9335,7 @@ class Expr:
         with_replacement: bool = False,
         shuffle: bool = False,
         seed: int | None = None,
+        weights: IntoExprColumn | None = None,
     ) -> Expr:
         """
         Sample from this expression.
@@ -9190,6 +9354,10 @@ class Expr:
         seed
             Seed for the random number generator. If set to None (default), a
             random seed is generated for each sample operation.
+        weights
+            Non-negative weights of the values. If set, every value is sampled with a
+            probability proportional to its weight. Values with a zero or null weight
+            are never sampled.
 
         Examples
         --------
@@ -9210,17 +9378,22 @@ class Expr:
             msg = "cannot specify both `n` and `fraction`"
             raise ValueError(msg)
 
+        if weights is not None:
+            weights = parse_into_expression(weights)
+
         if fraction is not None:
             fraction = parse_into_expression(fraction)
             return self._from_pyexpr(
-                self._pyexpr.sample_frac(fraction, with_replacement, shuffle, seed)
+                self._pyexpr.sample_frac(
+                    fraction, with_replacement, shuffle, seed, weights
+                )
             )
 
         if n is None:
             n = 1
         n = parse_into_expression(n)
         return self._from_pyexpr(
-            self._pyexpr.sample_n(n, with_replacement, shuffle, seed)
+            self._pyexpr.sample_n(n, with_replacement, shuffle, seed, weights)
         )
 
     def ewm_mean(
@@ -9640,8 +9813,8 @@ class Expr:
                 as the computation is already parallelized per group.
         name
             Give the resulting count column a specific name;
-            if `normalize` is True defaults to "count",
-            otherwise defaults to "proportion".
+            if `normalize` is True defaults to "proportion",
+            otherwise defaults to "count".
         normalize
             If true gives relative frequencies of the unique values
 
@@ -9949,6 +10122,7 @@ class Expr:
         bin_count: int | None = None,
         include_category: bool = False,
         include_breakpoint: bool = False,
+        normalize: bool = False,
     ) -> Expr:
         """
         Bin values into buckets and count their occurrences.
@@ -9969,6 +10143,9 @@ class Expr:
             Include a column that indicates the upper breakpoint.
         include_category
             Include a column that shows the intervals as categories.
+        normalize
+            Return the proportion of the non-null values in each bin instead of the
+            count, in a column named "proportion".
 
         Returns
         -------
@@ -10011,7 +10188,9 @@ class Expr:
                 bins = pl.Series(bins)
             bins = parse_into_expression(bins)
         return self._from_pyexpr(
-            self._pyexpr.hist(bins, bin_count, include_category, include_breakpoint)
+            self._pyexpr.hist(
+                bins, bin_count, include_category, include_breakpoint, normalize
+            )
         )
 
     def replace(
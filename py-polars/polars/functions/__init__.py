@@ -123,6 +123,7 @@ __all__ = [
     "col",
     "collect_all",
     "collect_all_async",
+    "set_streaming_metrics_callback",
     "concat_list",
     "concat_str",
     "corr",
@@ -16,7 +16,9 @@ if TYPE_CHECKING:
     from polars.type_aliases import IntoExpr
 
 
-def all_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+def all_horizontal(
+    *exprs: IntoExpr | Iterable[IntoExpr], ignore_nulls: bool = False
+) -> Expr:
     """
     Compute the bitwise AND horizontally across columns.
 
@@ -25,11 +27,13 @@ def all_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     *exprs
         Column(s) to use in the aggregation. Accepts expression input. Strings are
         parsed as column names, other non-expression inputs are parsed as literals.
+    ignore_nulls
+        Ignore null values, in which case a row without non-null values is `True`.
 
     Notes
     -----
-    `Kleene logic`_ is used to deal with nulls: if the column contains any null values
-    and no `False` values, the output is null.
+    Unless `ignore_nulls` is set, `Kleene logic`_ is used to deal with nulls: if the
+    column contains any null values and no `False` values, the output is null.
 
     .. _Kleene logic: https://en.wikipedia.org/wiki/Three-valued_logic
 
@@ -58,10 +62,12 @@ def all_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     └───────┴───────┴─────┴───────┘
     """
     pyexprs = parse_into_list_of_expressions(*exprs)
-    return wrap_expr(plr.all_horizontal(pyexprs))
+    return wrap_expr(plr.all_horizontal(pyexprs, ignore_nulls))
 
 
-def any_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+def any_horizontal(
+    *exprs: IntoExpr | Iterable[IntoExpr], ignore_nulls: bool = False
+) -> Expr:
     """
     Compute the bitwise OR horizontally across columns.
 
@@ -70,11 +76,13 @@ def any_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     *exprs
         Column(s) to use in the aggregation. Accepts expression input. Strings are
         parsed as column names, other non-expression inputs are parsed as literals.
+    ignore_nulls
+        Ignore null values, in which case a row without non-null values is `False`.
 
     Notes
     -----
-    `Kleene logic`_ is used to deal with nulls: if the column contains any null values
-    and no `True` values, the output is null.
+    Unless `ignore_nulls` is set, `Kleene logic`_ is used to deal with nulls: if the
+    column contains any null values and no `True` values, the output is null.
 
     .. _Kleene logic: https://en.wikipedia.org/wiki/Three-valued_logic
 
@@ -103,10 +111,12 @@ def any_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     └───────┴───────┴─────┴───────┘
     """
     pyexprs = parse_into_list_of_expressions(*exprs)
-    return wrap_expr(plr.any_horizontal(pyexprs))
+    return wrap_expr(plr.any_horizontal(pyexprs, ignore_nulls))
 
 
-def max_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+def max_horizontal(
+    *exprs: IntoExpr | Iterable[IntoExpr], ignore_nulls: bool = True
+) -> Expr:
     """
     Get the maximum value horizontally across columns.
 
@@ -115,6 +125,9 @@ def max_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     *exprs
         Column(s) to use in the aggregation. Accepts expression input. Strings are
         parsed as column names, other non-expression inputs are parsed as literals.
+    ignore_nulls
+        Ignore null values (default).
+        If set to `False`, any null value in the input will lead to a null output.
 
     Examples
     --------
@@ -138,10 +151,12 @@ def max_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     └─────┴──────┴─────┴─────┘
     """
     pyexprs = parse_into_list_of_expressions(*exprs)
-    return wrap_expr(plr.max_horizontal(pyexprs))
+    return wrap_expr(plr.max_horizontal(pyexprs, ignore_nulls))
 
 
-def min_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+def min_horizontal(
+    *exprs: IntoExpr | Iterable[IntoExpr], ignore_nulls: bool = True
+) -> Expr:
     """
     Get the minimum value horizontally across columns.
 
@@ -150,6 +165,9 @@ def min_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     *exprs
         Column(s) to use in the aggregation. Accepts expression input. Strings are
         parsed as column names, other non-expression inputs are parsed as literals.
+    ignore_nulls
+        Ignore null values (default).
+        If set to `False`, any null value in the input will lead to a null output.
 
     Examples
     --------
@@ -173,10 +191,12 @@ def min_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     └─────┴──────┴─────┴─────┘
     """
     pyexprs = parse_into_list_of_expressions(*exprs)
-    return wrap_expr(plr.min_horizontal(pyexprs))
+    return wrap_expr(plr.min_horizontal(pyexprs, ignore_nulls))
 
 
-def sum_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+def sum_horizontal(
+    *exprs: IntoExpr | Iterable[IntoExpr], ignore_nulls: bool = True
+) -> Expr:
     """
     Sum all values horizontally across columns.
 
@@ -185,6 +205,9 @@ def sum_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     *exprs
         Column(s) to use in the aggregation. Accepts expression input. Strings are
         parsed as column names, other non-expression inputs are parsed as literals.
+    ignore_nulls
+        Ignore null values (default).
+        If set to `False`, any null value in the input will lead to a null output.
 
     Examples
     --------
@@ -208,10 +231,12 @@ def sum_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     └─────┴──────┴─────┴─────┘
     """
     pyexprs = parse_into_list_of_expressions(*exprs)
-    return wrap_expr(plr.sum_horizontal(pyexprs))
+    return wrap_expr(plr.sum_horizontal(pyexprs, ignore_nulls))
 
 
-def mean_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+def mean_horizontal(
+    *exprs: IntoExpr | Iterable[IntoExpr], ignore_nulls: bool = True
+) -> Expr:
     """
     Compute the mean of all values horizontally across columns.
 
@@ -220,6 +245,9 @@ def mean_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     *exprs
         Column(s) to use in the aggregation. Accepts expression input. Strings are
         parsed as column names, other non-expression inputs are parsed as literals.
+    ignore_nulls
+        Ignore null values (default).
+        If set to `False`, any null value in the input will lead to a null output.
 
     Examples
     --------
@@ -243,7 +271,7 @@ def mean_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     └─────┴──────┴─────┴──────┘
     """
     pyexprs = parse_into_list_of_expressions(*exprs)
-    return wrap_expr(plr.mean_horizontal(pyexprs))
+    return wrap_expr(plr.mean_horizontal(pyexprs, ignore_nulls))
 
 
 def cum_sum_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
@@ -4,6 +4,7 @@ import contextlib
 from datetime import date
 from typing import TYPE_CHECKING, Iterable
 
+import polars._reexport as pl
 from polars._utils.parse import parse_into_expression
 from polars._utils.wrap import wrap_expr
 
@@ -11,7 +12,7 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
     import polars.polars as plr
 
 if TYPE_CHECKING:
-    from polars import Expr
+    from polars import Expr, Series
     from polars.type_aliases import IntoExprColumn
 
 
@@ -19,7 +20,7 @@ def business_day_count(
     start: date | IntoExprColumn,
     end: date | IntoExprColumn,
     week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
-    holidays: Iterable[date] = (),
+    holidays: Iterable[date] | Expr | Series = (),
 ) -> Expr:
     """
     Count the number of business days between `start` and `end` (not including `end`).
@@ -48,6 +49,9 @@ def business_day_count(
 
         and pass `holidays=my_holidays` when you call `business_day_count`.
 
+        Holidays can also differ per row, by passing an expression or Series of
+        data type `List(Date)` with the holidays of every row instead.
+
     Returns
     -------
     Expr
@@ -112,6 +116,10 @@ def business_day_count(
     """
     start_pyexpr = parse_into_expression(start)
     end_pyexpr = parse_into_expression(end)
+    row_holidays = None
+    if isinstance(holidays, (pl.Expr, pl.Series)):
+        row_holidays = parse_into_expression(holidays)
+        holidays = ()
     unix_epoch = date(1970, 1, 1)
     return wrap_expr(
         plr.business_day_count(
@@ -119,5 +127,6 @@ def business_day_count(
             end_pyexpr,
             week_mask,
             [(holiday - unix_epoch).days for holiday in holidays],
+            row_holidays,
         )
     )
@@ -1050,6 +1050,8 @@ def fold(
     acc: IntoExpr,
     function: Callable[[Series, Series], Series],
     exprs: Sequence[Expr | str] | Expr,
+    *,
+    return_dtype: PolarsDataType | None = None,
 ) -> Expr:
     """
     Accumulate over multiple columns horizontally/ row wise with a left fold.
@@ -1064,6 +1066,9 @@ def fold(
         Fn(acc, value) -> new_value
     exprs
         Expressions to aggregate over. May also be a wildcard expression.
+    return_dtype
+        dtype of the result, to which it is cast. If not set, the result is assumed to
+        have the supertype of the accumulator and the expressions.
 
     Notes
     -----
@@ -1151,7 +1156,7 @@ def fold(
         exprs = [exprs]
 
     exprs = parse_into_list_of_expressions(exprs)
-    return wrap_expr(plr.fold(acc, function, exprs))
+    return wrap_expr(plr.fold(acc, function, exprs, return_dtype))
 
 
 def reduce(
@@ -1223,6 +1228,7 @@ def cum_fold(
     exprs: Sequence[Expr | str] | Expr,
     *,
     include_init: bool = False,
+    return_dtype: PolarsDataType | None = None,
 ) -> Expr:
     """
     Cumulatively fold horizontally across columns with a left fold.
@@ -1241,6 +1247,9 @@ def cum_fold(
         Expressions to aggregate over. May also be a wildcard expression.
     include_init
         Include the initial accumulator state as struct field.
+    return_dtype
+        dtype of the struct fields, to which they are cast. If not set, the fields are
+        assumed to have the supertype of the accumulator and the expressions.
 
     Notes
     -----
@@ -1276,7 +1285,9 @@ def cum_fold(
         exprs = [exprs]
 
     exprs = parse_into_list_of_expressions(exprs)
-    return wrap_expr(plr.cum_fold(acc, function, exprs, include_init).alias("cum_fold"))
+    return wrap_expr(
+        plr.cum_fold(acc, function, exprs, include_init, return_dtype).alias("cum_fold")
+    )
 
 
 def cum_reduce(
@@ -1860,6 +1871,48 @@ def collect_all_async(
     return result  # type: ignore[return-value]
 
 
+@unstable()
+def set_streaming_metrics_callback(
+    callback: Callable[[dict[str, Any]], None] | None,
+) -> None:
+    """
+    Set a callback that receives the metrics of the streaming engine.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    The callback is called with a dictionary for every event of every query that
+    runs in the streaming engine, which allows monitoring long-running pipelines.
+    The `"event"` key holds the kind of event:
+
+    * `"batch"`: a source produced `n_chunks` chunks of at most `queue_depth`,
+      while the sinks held `memory_in_use` bytes.
+    * `"operator"`: an operator processed a chunk of `rows_in` rows into
+      `rows_out` rows in `elapsed` seconds.
+    * `"sink"`: a sink consumed a chunk of `rows` rows in `elapsed` seconds.
+    * `"spill"`: an `operation` started spilling to disk.
+
+    The callback is called from the threads that execute the query, and should
+    return quickly.
+
+    Parameters
+    ----------
+    callback
+        Function that is called with every event, or `None` to remove the
+        current callback.
+
+    Examples
+    --------
+    >>> events = []
+    >>> pl.set_streaming_metrics_callback(events.append)  # doctest: +SKIP
+    >>> pl.LazyFrame({"a": [1, 2, 3]}).sort("a").collect(
+    ...     streaming=True
+    ... )  # doctest: +SKIP
+    >>> pl.set_streaming_metrics_callback(None)  # doctest: +SKIP
+    """
+    plr.set_streaming_metrics_callback(callback)
+
 def select(*exprs: IntoExpr | Iterable[IntoExpr], **named_exprs: IntoExpr) -> DataFrame:
     """
     Run polars expressions without a context.
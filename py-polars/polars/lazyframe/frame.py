@@ -2123,6 +2123,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         row_group_size: int | None = None,
         data_pagesize_limit: int | None = None,
         maintain_order: bool = True,
+        checkpoint: bool = False,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2182,6 +2183,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
+        checkpoint
+            Write the result to a directory of part files at `path` and record the
+            committed parts in a `_checkpoint` manifest. If the query is
+            interrupted, running it again resumes after the last committed part.
+            The rows must arrive in the same order, so `maintain_order` is implied.
+            The number of rows per part is set by `POLARS_SINK_CHECKPOINT_ROWS`.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2238,6 +2245,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             row_group_size=row_group_size,
             data_pagesize_limit=data_pagesize_limit,
             maintain_order=maintain_order,
+            checkpoint=checkpoint,
         )
 
     @unstable()
@@ -2247,6 +2255,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         *,
         compression: str | None = "zstd",
         maintain_order: bool = True,
+        checkpoint: bool = False,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2273,6 +2282,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
+        checkpoint
+            Write the result to a directory of part files at `path` and record the
+            committed parts in a `_checkpoint` manifest. If the query is
+            interrupted, running it again resumes after the last committed part.
+            The rows must arrive in the same order, so `maintain_order` is implied.
+            The number of rows per part is set by `POLARS_SINK_CHECKPOINT_ROWS`.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2308,6 +2323,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             path=path,
             compression=compression,
             maintain_order=maintain_order,
+            checkpoint=checkpoint,
         )
 
     @unstable()
@@ -2328,6 +2344,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
         maintain_order: bool = True,
+        checkpoint: bool = False,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2399,6 +2416,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
+        checkpoint
+            Write the result to a directory of part files at `path` and record the
+            committed parts in a `_checkpoint` manifest. If the query is
+            interrupted, running it again resumes after the last committed part.
+            The rows must arrive in the same order, so `maintain_order` is implied.
+            The number of rows per part is set by `POLARS_SINK_CHECKPOINT_ROWS`.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2452,6 +2475,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             null_value=null_value,
             quote_style=quote_style,
             maintain_order=maintain_order,
+            checkpoint=checkpoint,
         )
 
     @unstable()
@@ -3185,8 +3209,6 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Ensure that the order of the groups is consistent with the input data.
             This is slower than a default group by.
-            Setting this to `True` blocks the possibility
-            to run on the streaming engine.
         **named_by
             Additional columns to group by, specified as keyword arguments.
             The columns will be renamed to the keyword used.
@@ -4146,6 +4168,65 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             )
         )
 
+    @unstable()
+    def join_where(
+        self,
+        other: LazyFrame,
+        *predicates: Expr | Iterable[Expr],
+        suffix: str = "_right",
+    ) -> LazyFrame:
+        """
+        Join on one or more predicates between the columns of both frames.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Up to two inequalities between a column of each frame, such as
+        `pl.col("ts") >= pl.col("start")`, are evaluated by an inequality join.
+        Other predicates filter the joined rows.
+
+        Parameters
+        ----------
+        other
+            Lazy DataFrame to join with.
+        *predicates
+            (In)Equality condition to join the two frames on. Columns of `other`
+            whose name also occurs in this frame are referred to with the `suffix`.
+        suffix
+            Suffix to append to columns with a duplicate name.
+
+        See Also
+        --------
+        join
+
+        Examples
+        --------
+        >>> events = pl.LazyFrame({"ts": [1, 4, 7, 10]})
+        >>> windows = pl.LazyFrame(
+        ...     {"start": [0, 3, 5], "end": [2, 6, 9], "id": ["a", "b", "c"]}
+        ... )
+        >>> events.join_where(
+        ...     windows, pl.col("ts") >= pl.col("start"), pl.col("ts") < pl.col("end")
+        ... ).collect()
+        shape: (3, 4)
+        ┌─────┬───────┬─────┬─────┐
+        │ ts  ┆ start ┆ end ┆ id  │
+        │ --- ┆ ---   ┆ --- ┆ --- │
+        │ i64 ┆ i64   ┆ i64 ┆ str │
+        ╞═════╪═══════╪═════╪═════╡
+        │ 1   ┆ 0     ┆ 2   ┆ a   │
+        │ 4   ┆ 3     ┆ 6   ┆ b   │
+        │ 7   ┆ 5     ┆ 9   ┆ c   │
+        └─────┴───────┴─────┴─────┘
+        """
+        if not isinstance(other, LazyFrame):
+            msg = f"expected `other` join table to be a LazyFrame, not a {type(other).__name__!r}"
+            raise TypeError(msg)
+
+        pyexprs = parse_into_list_of_expressions(*predicates)
+        return self._from_pyldf(self._ldf.join_where(other._ldf, pyexprs, suffix))
+
     def with_columns(
         self,
         *exprs: IntoExpr | Iterable[IntoExpr],
@@ -19,6 +19,7 @@ if TYPE_CHECKING:
         IntoExprColumn,
         NonExistent,
         Roll,
+        RoundTies,
         TemporalLiteral,
         TimeUnit,
     )
@@ -41,7 +42,7 @@ class DateTimeNameSpace:
         self,
         n: int | IntoExpr,
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
-        holidays: Iterable[dt.date] = (),
+        holidays: Iterable[dt.date] | Expr | Series = (),
         roll: Roll = "raise",
     ) -> Expr:
         """
@@ -69,6 +70,9 @@ class DateTimeNameSpace:
                 my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
             and pass `holidays=my_holidays` when you call `business_day_count`.
+
+            Holidays can also differ per row, by passing an expression or Series
+            of data type `List(Date)` with the holidays of every row instead.
         roll
             What to do when the start date lands on a non-business day. Options are:
 
@@ -1248,6 +1252,7 @@ class DateTimeNameSpace:
 
             - `'raise'` (default): raise
             - `'null'`: set to null
+            - `'shift_forward'`: use the first datetime after the gap
 
         Examples
         --------
@@ -1566,7 +1571,7 @@ class DateTimeNameSpace:
         ]
         """
 
-    def offset_by(self, by: str | Expr) -> Series:
+    def offset_by(self, by: str | dt.timedelta | Expr) -> Series:
         """
         Offset this date by a relative time offset.
 
@@ -1596,6 +1601,11 @@ class DateTimeNameSpace:
             "calendar week", "calendar month", "calendar quarter", and
             "calendar year".
 
+            The offset can also be an expression of data type `String` or
+            `Duration`, in which case every row is offset by its own value.
+            Durations have a fixed length, so they don't take the calendar into
+            account.
+
         Returns
         -------
         Series
@@ -1756,7 +1766,9 @@ class DateTimeNameSpace:
         """
 
     @unstable()
-    def round(self, every: str | dt.timedelta | IntoExprColumn) -> Series:
+    def round(
+        self, every: str | dt.timedelta | IntoExprColumn, *, ties: RoundTies = "up"
+    ) -> Series:
         """
         Divide the date/ datetime range into buckets.
 
@@ -1777,6 +1789,9 @@ class DateTimeNameSpace:
         ----------
         every
             Every interval start and period length
+        ties : {'up', 'down'}
+            Whether a date/datetime exactly halfway between two interval boundaries
+            is mapped to the later (`'up'`) or the earlier (`'down'`) boundary.
 
         Returns
         -------
@@ -130,11 +130,13 @@ if TYPE_CHECKING:
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
+        ModeTieBreak,
         MultiIndexSelector,
         NonNestedLiteral,
         NullBehavior,
         NumericLiteral,
         PolarsDataType,
+        ProductOverflow,
         PythonLiteral,
         RankMethod,
         RollingInterpolationMethod,
@@ -1865,17 +1867,30 @@ class Series:
         """
         return self._s.mean()
 
-    def product(self) -> int | float:
+    def product(self, overflow: ProductOverflow = "wrap") -> int | float:
         """
         Reduce this Series to the product value.
 
+        Parameters
+        ----------
+        overflow : {'wrap', 'float64'}
+            How to deal with products of integers that don't fit in 64 bits.
+
+            - 'wrap' : multiply in `Int64` (`UInt64` for `UInt64` values) and wrap
+              around on overflow.
+            - 'float64' : multiply in `Float64`, which loses precision instead of
+              overflowing.
+
         Examples
         --------
         >>> s = pl.Series("a", [1, 2, 3])
         >>> s.product()
         6
+        >>> s = pl.Series("a", [2**32, 2**32])
+        >>> s.product(overflow="float64")
+        1.8446744073709552e+19
         """
-        return self._s.product()
+        return self._s.product(overflow)
 
     def pow(self, exponent: int | float | Series) -> Series:
         """
@@ -2343,6 +2358,7 @@ class Series:
         bin_count: int | None = None,
         include_category: bool = True,
         include_breakpoint: bool = True,
+        normalize: bool = False,
     ) -> DataFrame:
         """
         Bin values into buckets and count their occurrences.
@@ -2363,6 +2379,9 @@ class Series:
             Include a column that indicates the upper breakpoint.
         include_category
             Include a column that shows the intervals as categories.
+        normalize
+            Return the proportion of the non-null values in each bin instead of the
+            count, in a column named "proportion".
 
         Returns
         -------
@@ -2393,6 +2412,7 @@ class Series:
                     bin_count=bin_count,
                     include_category=include_category,
                     include_breakpoint=include_breakpoint,
+                    normalize=normalize,
                 )
             )
             .to_series()
@@ -2426,8 +2446,8 @@ class Series:
                 as the computation is already parallelized per group.
         name
             Give the resulting count column a specific name;
-            if `normalize` is True defaults to "count",
-            otherwise defaults to "proportion".
+            if `normalize` is True defaults to "proportion",
+            otherwise defaults to "count".
         normalize
             If true gives relative frequencies of the unique values
 
@@ -4903,12 +4923,24 @@ class Series:
             raise ShapeError(msg)
         return self._s.dot(other._s)
 
-    def mode(self) -> Series:
+    def mode(self, tie_break: ModeTieBreak | None = None) -> Series:
         """
         Compute the most occurring value(s).
 
         Can return multiple Values.
 
+        Parameters
+        ----------
+        tie_break : {None, 'first', 'min', 'max'}
+            How to choose a single value if several values occur most often.
+
+            - None : return all of them.
+            - 'first' : the value that occurs first.
+            - 'min' : the smallest value.
+            - 'max' : the largest value.
+
+            A null value is only chosen if it is the sole mode.
+
         Examples
         --------
         >>> s = pl.Series("a", [1, 2, 2, 3])
@@ -4918,6 +4950,13 @@ class Series:
         [
                 2
         ]
+        >>> s = pl.Series("a", [3, 1, 3, 1])
+        >>> s.mode(tie_break="max")
+        shape: (1,)
+        Series: 'a' [i64]
+        [
+                3
+        ]
         """
 
     def sign(self) -> Series:
@@ -5895,6 +5934,7 @@ class Series:
         with_replacement: bool = False,
         shuffle: bool = False,
         seed: int | None = None,
+        weights: Series | None = None,
     ) -> Series:
         """
         Sample from this Series.
@@ -5913,6 +5953,10 @@ class Series:
         seed
             Seed for the random number generator. If set to None (default), a
             random seed is generated for each sample operation.
+        weights
+            Non-negative weights of the values. If set, every value is sampled with a
+            probability proportional to its weight. Values with a zero or null weight
+            are never sampled.
 
         Examples
         --------
@@ -6072,8 +6116,9 @@ class Series:
 
         Parameters
         ----------
-        method : {'linear', 'nearest'}
-            Interpolation method.
+        method : {'linear', 'nearest', 'cubic'}
+            Interpolation method. `'cubic'` fits a natural cubic spline through the
+            non-null values.
 
         Examples
         --------
@@ -6090,7 +6135,9 @@ class Series:
         ]
         """
 
-    def interpolate_by(self, by: IntoExpr) -> Series:
+    def interpolate_by(
+        self, by: IntoExpr, method: InterpolationMethod = "linear"
+    ) -> Series:
         """
         Fill null values using interpolation based on another column.
 
@@ -6098,6 +6145,9 @@ class Series:
         ----------
         by
             Column to interpolate values based on.
+        method : {'linear', 'nearest', 'cubic'}
+            Interpolation method. `'cubic'` fits a natural cubic spline through the
+            non-null values.
 
         Examples
         --------
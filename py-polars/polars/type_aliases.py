@@ -95,17 +95,20 @@ IndexOrder: TypeAlias = Literal["c", "fortran"]
 IpcCompression: TypeAlias = Literal["uncompressed", "lz4", "zstd"]
 JoinValidation: TypeAlias = Literal["m:m", "m:1", "1:m", "1:1"]
 Label: TypeAlias = Literal["left", "right", "datapoint"]
-NonExistent: TypeAlias = Literal["raise", "null"]
+ModeTieBreak: TypeAlias = Literal["first", "min", "max"]
+NonExistent: TypeAlias = Literal["raise", "null", "shift_forward"]
 NullBehavior: TypeAlias = Literal["ignore", "drop"]
 ParallelStrategy: TypeAlias = Literal["auto", "columns", "row_groups", "none"]
 ParquetCompression: TypeAlias = Literal[
     "lz4", "uncompressed", "snappy", "gzip", "lzo", "brotli", "zstd"
 ]
+ProductOverflow: TypeAlias = Literal["wrap", "float64"]
 PivotAgg: TypeAlias = Literal[
     "min", "max", "first", "last", "sum", "mean", "median", "len"
 ]
 RankMethod: TypeAlias = Literal["average", "min", "max", "dense", "ordinal", "random"]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
+RoundTies: TypeAlias = Literal["up", "down"]
 SizeUnit: TypeAlias = Literal[
     "b",
     "kb",
@@ -137,7 +140,7 @@ MapElementsStrategy: TypeAlias = Literal["thread_local", "threading"]
 # The following have a Rust enum equivalent with a different name
 AsofJoinStrategy: TypeAlias = Literal["backward", "forward", "nearest"]  # AsofStrategy
 ClosedInterval: TypeAlias = Literal["left", "right", "both", "none"]  # ClosedWindow
-InterpolationMethod: TypeAlias = Literal["linear", "nearest"]
+InterpolationMethod: TypeAlias = Literal["linear", "nearest", "cubic"]
 JoinStrategy: TypeAlias = Literal[
     "inner", "left", "full", "semi", "anti", "cross", "outer"
 ]  # JoinType
@@ -298,7 +298,7 @@ impl ToPyObject for Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "Unknown")).unwrap();
                 class.call0().unwrap().into()
             },
-            DataType::BinaryOffset => {
+            DataType::BinaryOffset | DataType::Int128 => {
                 unimplemented!()
             },
         }
@@ -656,9 +656,10 @@ impl<'py> FromPyObject<'py> for Wrap<InterpolationMethod> {
         let parsed = match &*(ob.extract::<PyBackedStr>()?) {
             "linear" => InterpolationMethod::Linear,
             "nearest" => InterpolationMethod::Nearest,
+            "cubic" => InterpolationMethod::Cubic,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "interpolation `method` must be one of {{'linear', 'nearest'}}, got {v}",
+                    "interpolation `method` must be one of {{'linear', 'nearest', 'cubic'}}, got {v}",
                 )))
             },
         };
@@ -821,14 +822,46 @@ impl<'py> FromPyObject<'py> for Wrap<ListToStructWidthStrategy> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<ModeTieBreak> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "first" => ModeTieBreak::First,
+            "min" => ModeTieBreak::Min,
+            "max" => ModeTieBreak::Max,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`tie_break` must be one of {{'first', 'min', 'max'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<NonExistent> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "null" => NonExistent::Null,
             "raise" => NonExistent::Raise,
+            "shift_forward" => NonExistent::ShiftForward,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "`non_existent` must be one of {{'null', 'raise'}}, got {v}",
+                    "`non_existent` must be one of {{'null', 'raise', 'shift_forward'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<RoundTies> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "up" => RoundTies::Up,
+            "down" => RoundTies::Down,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`ties` must be one of {{'up', 'down'}}, got {v}",
                 )))
             },
         };
@@ -899,6 +932,21 @@ impl<'py> FromPyObject<'py> for Wrap<IndexOrder> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<ProductOverflow> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "wrap" => ProductOverflow::Wrap,
+            "float64" => ProductOverflow::Float64,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`overflow` must be one of {{'wrap', 'float64'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<QuantileInterpolOptions> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -62,6 +62,11 @@ impl PyDataFrame {
         self.df.estimated_size()
     }
 
+    pub fn memory_usage(&self) -> PyResult<Self> {
+        let df = self.df.memory_usage().map_err(PyPolarsErr::from)?;
+        Ok(df.into())
+    }
+
     pub fn dtype_strings(&self) -> Vec<String> {
         self.df
             .get_columns()
@@ -120,31 +125,45 @@ impl PyDataFrame {
         Ok(df.into())
     }
 
+    #[pyo3(signature = (n, with_replacement, shuffle, seed, weights=None))]
     pub fn sample_n(
         &self,
         n: &PySeries,
         with_replacement: bool,
         shuffle: bool,
         seed: Option<u64>,
+        weights: Option<&PySeries>,
     ) -> PyResult<Self> {
-        let df = self
-            .df
-            .sample_n(&n.series, with_replacement, shuffle, seed)
-            .map_err(PyPolarsErr::from)?;
+        let df = match weights {
+            Some(weights) => {
+                self.df
+                    .sample_n_weighted(&n.series, &weights.series, with_replacement, seed)
+            },
+            None => self.df.sample_n(&n.series, with_replacement, shuffle, seed),
+        }
+        .map_err(PyPolarsErr::from)?;
         Ok(df.into())
     }
 
+    #[pyo3(signature = (frac, with_replacement, shuffle, seed, weights=None))]
     pub fn sample_frac(
         &self,
         frac: &PySeries,
         with_replacement: bool,
         shuffle: bool,
         seed: Option<u64>,
+        weights: Option<&PySeries>,
     ) -> PyResult<Self> {
-        let df = self
-            .df
-            .sample_frac(&frac.series, with_replacement, shuffle, seed)
-            .map_err(PyPolarsErr::from)?;
+        let df = match weights {
+            Some(weights) => {
+                self.df
+                    .sample_frac_weighted(&frac.series, &weights.series, with_replacement, seed)
+            },
+            None => self
+                .df
+                .sample_frac(&frac.series, with_replacement, shuffle, seed),
+        }
+        .map_err(PyPolarsErr::from)?;
         Ok(df.into())
     }
 
@@ -566,11 +585,12 @@ impl PyDataFrame {
         Ok(hash.into_series().into())
     }
 
-    #[pyo3(signature = (keep_names_as, column_names))]
+    #[pyo3(signature = (keep_names_as, column_names, dtype=None))]
     pub fn transpose(
         &mut self,
         keep_names_as: Option<&str>,
         column_names: &Bound<PyAny>,
+        dtype: Option<Wrap<DataType>>,
     ) -> PyResult<Self> {
         let new_col_names = if let Ok(name) = column_names.extract::<Vec<String>>() {
             Some(Either::Right(name))
@@ -581,7 +601,7 @@ impl PyDataFrame {
         };
         Ok(self
             .df
-            .transpose(keep_names_as, new_col_names)
+            .transpose_with_dtype(keep_names_as, new_col_names, dtype.map(|d| d.0).as_ref())
             .map_err(PyPolarsErr::from)?
             .into())
     }
@@ -65,7 +65,7 @@ impl From<&DataType> for PyDataType {
             DataType::Categorical(_, _) => Categorical,
             DataType::Enum(rev_map, _) => Enum(rev_map.as_ref().unwrap().get_categories().clone()),
             DataType::Struct(_) => Struct,
-            DataType::Null | DataType::Unknown(_) | DataType::BinaryOffset => {
+            DataType::Null | DataType::Unknown(_) | DataType::BinaryOffset | DataType::Int128 => {
                 panic!("null or unknown not expected here")
             },
         }
@@ -74,7 +74,8 @@ impl std::convert::From<PyPolarsErr> for PyErr {
                 PolarsError::StructFieldNotFound(name) => {
                     StructFieldNotFoundError::new_err(name.to_string())
                 },
-                PolarsError::Context { .. } => {
+                PolarsError::Cancelled(err) => ComputeError::new_err(err.to_string()),
+                PolarsError::Context { .. } | PolarsError::Expression { .. } => {
                     let tmp = PyPolarsErr::Polars(err.context_trace());
                     PyErr::from(tmp)
                 },
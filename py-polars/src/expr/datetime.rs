@@ -12,11 +12,13 @@ impl PyExpr {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
         roll: Wrap<Roll>,
+        row_holidays: Option<PyExpr>,
     ) -> Self {
+        let row_holidays = row_holidays.map(|e| e.inner);
         self.inner
             .clone()
             .dt()
-            .add_business_days(n.inner, week_mask, holidays, roll.0)
+            .add_business_days(n.inner, week_mask, holidays, roll.0, row_holidays)
             .into()
     }
 
@@ -90,8 +92,8 @@ impl PyExpr {
         self.inner.clone().dt().dst_offset().into()
     }
 
-    fn dt_round(&self, every: Self) -> Self {
-        self.inner.clone().dt().round(every.inner).into()
+    fn dt_round(&self, every: Self, ties: Wrap<RoundTies>) -> Self {
+        self.inner.clone().dt().round(every.inner, ties.0).into()
     }
 
     fn dt_combine(&self, time: Self, time_unit: Wrap<TimeUnit>) -> Self {
@@ -374,6 +374,7 @@ impl PyExpr {
                     nulls_last,
                     multithreaded,
                     maintain_order,
+                    ..Default::default()
                 },
             )
             .into()
@@ -444,8 +445,19 @@ impl PyExpr {
             .into()
     }
 
-    fn approx_n_unique(&self) -> Self {
-        self.inner.clone().approx_n_unique().into()
+    fn approx_n_unique(&self, precision: u8) -> Self {
+        self.inner
+            .clone()
+            .approx_n_unique_with_precision(precision)
+            .into()
+    }
+
+    fn quantile_approx(&self, quantile: f64) -> Self {
+        self.inner.clone().quantile_approx(quantile).into()
+    }
+
+    fn median_approx(&self) -> Self {
+        self.inner.clone().median_approx().into()
     }
 
     fn is_first_distinct(&self) -> Self {
@@ -713,15 +725,15 @@ impl PyExpr {
             .into()
     }
 
-    fn product(&self) -> Self {
-        self.inner.clone().product().into()
+    fn product(&self, overflow: Wrap<ProductOverflow>) -> Self {
+        self.inner.clone().product_with_overflow(overflow.0).into()
     }
 
     fn shrink_dtype(&self) -> Self {
         self.inner.clone().shrink_dtype().into()
     }
 
-    #[pyo3(signature = (lambda, output_type, agg_list, is_elementwise, returns_scalar))]
+    #[pyo3(signature = (lambda, output_type, agg_list, is_elementwise, returns_scalar, output_type_fn=None))]
     fn map_batches(
         &self,
         lambda: PyObject,
@@ -729,6 +741,7 @@ impl PyExpr {
         agg_list: bool,
         is_elementwise: bool,
         returns_scalar: bool,
+        output_type_fn: Option<PyObject>,
     ) -> Self {
         map_single(
             self,
@@ -737,6 +750,7 @@ impl PyExpr {
             agg_list,
             is_elementwise,
             returns_scalar,
+            output_type_fn,
         )
     }
 
@@ -747,8 +761,12 @@ impl PyExpr {
     fn reinterpret(&self, signed: bool) -> Self {
         self.inner.clone().reinterpret(signed).into()
     }
-    fn mode(&self) -> Self {
-        self.inner.clone().mode().into()
+    #[pyo3(signature = (tie_break=None))]
+    fn mode(&self, tie_break: Option<Wrap<ModeTieBreak>>) -> Self {
+        match tie_break {
+            None => self.inner.clone().mode().into(),
+            Some(tie_break) => self.inner.clone().mode_tie_break(tie_break.0).into(),
+        }
     }
     fn exclude(&self, columns: Vec<String>) -> Self {
         self.inner.clone().exclude(columns).into()
@@ -760,8 +778,11 @@ impl PyExpr {
     fn interpolate(&self, method: Wrap<InterpolationMethod>) -> Self {
         self.inner.clone().interpolate(method.0).into()
     }
-    fn interpolate_by(&self, by: PyExpr) -> Self {
-        self.inner.clone().interpolate_by(by.inner).into()
+    fn interpolate_by(&self, by: PyExpr, method: Wrap<InterpolationMethod>) -> Self {
+        self.inner
+            .clone()
+            .interpolate_by_with_method(by.inner, method.0)
+            .into()
     }
 
     fn lower_bound(&self) -> Self {
@@ -786,7 +807,10 @@ impl PyExpr {
 
     #[cfg(feature = "pct_change")]
     fn pct_change(&self, n: Self) -> Self {
-        self.inner.clone().pct_change(n.inner).into()
+        self.inner
+            .clone()
+            .pct_change(n.inner, NullBehavior::Ignore)
+            .into()
     }
 
     fn skew(&self, bias: bool) -> Self {
@@ -809,26 +833,40 @@ impl PyExpr {
         self.inner.clone().shuffle(seed).into()
     }
 
-    #[pyo3(signature = (n, with_replacement, shuffle, seed))]
-    fn sample_n(&self, n: Self, with_replacement: bool, shuffle: bool, seed: Option<u64>) -> Self {
-        self.inner
-            .clone()
-            .sample_n(n.inner, with_replacement, shuffle, seed)
-            .into()
+    #[pyo3(signature = (n, with_replacement, shuffle, seed, weights=None))]
+    fn sample_n(
+        &self,
+        n: Self,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+        weights: Option<Self>,
+    ) -> Self {
+        let expr = self.inner.clone();
+        match weights {
+            Some(weights) => expr.sample_n_weighted(n.inner, weights.inner, with_replacement, seed),
+            None => expr.sample_n(n.inner, with_replacement, shuffle, seed),
+        }
+        .into()
     }
 
-    #[pyo3(signature = (frac, with_replacement, shuffle, seed))]
+    #[pyo3(signature = (frac, with_replacement, shuffle, seed, weights=None))]
     fn sample_frac(
         &self,
         frac: Self,
         with_replacement: bool,
         shuffle: bool,
         seed: Option<u64>,
+        weights: Option<Self>,
     ) -> Self {
-        self.inner
-            .clone()
-            .sample_frac(frac.inner, with_replacement, shuffle, seed)
-            .into()
+        let expr = self.inner.clone();
+        match weights {
+            Some(weights) => {
+                expr.sample_frac_weighted(frac.inner, weights.inner, with_replacement, seed)
+            },
+            None => expr.sample_frac(frac.inner, with_replacement, shuffle, seed),
+        }
+        .into()
     }
 
     fn ewm_mean(&self, alpha: f64, adjust: bool, min_periods: usize, ignore_nulls: bool) -> Self {
@@ -947,18 +985,25 @@ impl PyExpr {
     }
 
     #[cfg(feature = "hist")]
-    #[pyo3(signature = (bins, bin_count, include_category, include_breakpoint))]
+    #[pyo3(signature = (bins, bin_count, include_category, include_breakpoint, normalize))]
     fn hist(
         &self,
         bins: Option<PyExpr>,
         bin_count: Option<usize>,
         include_category: bool,
         include_breakpoint: bool,
+        normalize: bool,
     ) -> Self {
         let bins = bins.map(|e| e.inner);
         self.inner
             .clone()
-            .hist(bins, bin_count, include_category, include_breakpoint)
+            .hist(
+                bins,
+                bin_count,
+                include_category,
+                include_breakpoint,
+                normalize,
+            )
             .into()
     }
 }
@@ -9,8 +9,10 @@ pub fn business_day_count(
     end: PyExpr,
     week_mask: [bool; 7],
     holidays: Vec<i32>,
+    row_holidays: Option<PyExpr>,
 ) -> PyExpr {
     let start = start.inner;
     let end = end.inner;
-    dsl::business_day_count(start, end, week_mask, holidays).into()
+    let row_holidays = row_holidays.map(|e| e.inner);
+    dsl::business_day_count(start, end, week_mask, holidays, row_holidays).into()
 }
@@ -75,6 +75,7 @@ pub fn arg_sort_by(
             nulls_last,
             multithreaded,
             maintain_order,
+            ..Default::default()
         },
     )
     .into()
@@ -223,11 +224,24 @@ pub fn arctan2(y: PyExpr, x: PyExpr) -> PyExpr {
 }
 
 #[pyfunction]
-pub fn cum_fold(acc: PyExpr, lambda: PyObject, exprs: Vec<PyExpr>, include_init: bool) -> PyExpr {
+pub fn cum_fold(
+    acc: PyExpr,
+    lambda: PyObject,
+    exprs: Vec<PyExpr>,
+    include_init: bool,
+    return_dtype: Option<Wrap<DataType>>,
+) -> PyExpr {
     let exprs = exprs.to_exprs();
 
     let func = move |a: Series, b: Series| binary_lambda(&lambda, a, b);
-    dsl::cum_fold_exprs(acc.inner, func, exprs, include_init).into()
+    dsl::cum_fold_exprs_with_dtype(
+        acc.inner,
+        func,
+        exprs,
+        include_init,
+        return_dtype.map(|dt| dt.0),
+    )
+    .into()
 }
 
 #[pyfunction]
@@ -391,11 +405,16 @@ pub fn first() -> PyExpr {
 }
 
 #[pyfunction]
-pub fn fold(acc: PyExpr, lambda: PyObject, exprs: Vec<PyExpr>) -> PyExpr {
+pub fn fold(
+    acc: PyExpr,
+    lambda: PyObject,
+    exprs: Vec<PyExpr>,
+    return_dtype: Option<Wrap<DataType>>,
+) -> PyExpr {
     let exprs = exprs.to_exprs();
 
     let func = move |a: Series, b: Series| binary_lambda(&lambda, a, b);
-    dsl::fold_exprs(acc.inner, func, exprs).into()
+    dsl::fold_exprs_with_dtype(acc.inner, func, exprs, return_dtype.map(|dt| dt.0)).into()
 }
 
 #[pyfunction]
@@ -91,3 +91,78 @@ pub fn get_trim_decimal_zeros() -> PyResult<Option<bool>> {
     use polars_core::fmt::get_trim_decimal_zeros;
     Ok(Some(get_trim_decimal_zeros()))
 }
+
+/// Forwards the events of the streaming engine to a Python callable as dictionaries.
+#[cfg(feature = "streaming")]
+struct PyStreamingMetricsSubscriber(PyObject);
+
+#[cfg(feature = "streaming")]
+impl polars::prelude::StreamingMetricsSubscriber for PyStreamingMetricsSubscriber {
+    fn on_event(&self, event: &polars::prelude::StreamingEvent) {
+        use polars::prelude::StreamingEvent;
+        use pyo3::types::PyDict;
+
+        Python::with_gil(|py| {
+            let dict = PyDict::new_bound(py);
+            let set_items = || -> PyResult<()> {
+                match event {
+                    StreamingEvent::Batch {
+                        source,
+                        n_chunks,
+                        queue_depth,
+                        memory_in_use,
+                    } => {
+                        dict.set_item("event", "batch")?;
+                        dict.set_item("source", source)?;
+                        dict.set_item("n_chunks", n_chunks)?;
+                        dict.set_item("queue_depth", queue_depth)?;
+                        dict.set_item("memory_in_use", memory_in_use)?;
+                    },
+                    StreamingEvent::Operator {
+                        operator,
+                        rows_in,
+                        rows_out,
+                        elapsed,
+                    } => {
+                        dict.set_item("event", "operator")?;
+                        dict.set_item("operator", operator)?;
+                        dict.set_item("rows_in", rows_in)?;
+                        dict.set_item("rows_out", rows_out)?;
+                        dict.set_item("elapsed", elapsed.as_secs_f64())?;
+                    },
+                    StreamingEvent::Sink {
+                        sink,
+                        rows,
+                        elapsed,
+                    } => {
+                        dict.set_item("event", "sink")?;
+                        dict.set_item("sink", sink)?;
+                        dict.set_item("rows", rows)?;
+                        dict.set_item("elapsed", elapsed.as_secs_f64())?;
+                    },
+                    StreamingEvent::Spill { operation } => {
+                        dict.set_item("event", "spill")?;
+                        dict.set_item("operation", operation)?;
+                    },
+                }
+                Ok(())
+            };
+            set_items()
+                .and_then(|_| self.0.call1(py, (dict.clone(),)))
+                .map_err(|err| err.restore(py))
+                .ok();
+        });
+    }
+}
+
+#[cfg(feature = "streaming")]
+#[pyfunction]
+pub fn set_streaming_metrics_callback(callback: Option<PyObject>) {
+    use std::sync::Arc;
+
+    use polars::prelude::{set_streaming_metrics_subscriber, StreamingMetricsSubscriber};
+
+    set_streaming_metrics_subscriber(callback.map(|callback| {
+        Arc::new(PyStreamingMetricsSubscriber(callback)) as Arc<dyn StreamingMetricsSubscriber>
+    }));
+}
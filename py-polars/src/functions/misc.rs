@@ -1,5 +1,3 @@
-use std::sync::Arc;
-
 use polars_plan::prelude::*;
 use pyo3::prelude::*;
 
@@ -34,22 +32,24 @@ pub fn register_plugin_function(
         ApplyOptions::GroupWise
     };
 
-    Ok(Expr::Function {
-        input: args.to_exprs(),
-        function: FunctionExpr::FfiPlugin {
-            lib: Arc::from(plugin_path),
-            symbol: Arc::from(function_name),
-            kwargs: Arc::from(kwargs),
-        },
-        options: FunctionOptions {
-            collect_groups,
-            input_wildcard_expansion,
-            returns_scalar,
-            cast_to_supertypes: cast_to_supertype,
-            pass_name_to_apply,
-            changes_length,
-            ..Default::default()
-        },
-    }
-    .into())
+    let options = FunctionOptions {
+        collect_groups,
+        input_wildcard_expansion,
+        returns_scalar,
+        cast_to_supertypes: cast_to_supertype,
+        pass_name_to_apply,
+        changes_length,
+        ..Default::default()
+    };
+    // SAFETY: the Python side resolved the plugin path to the shared library of the plugin.
+    let expr = unsafe {
+        polars_plan::dsl::functions::register_plugin_function(
+            plugin_path,
+            function_name,
+            args.to_exprs(),
+            kwargs,
+            options,
+        )
+    };
+    Ok(expr.into())
 }
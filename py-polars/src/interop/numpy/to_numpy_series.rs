@@ -246,6 +246,11 @@ fn series_to_numpy_with_copy(py: Python, s: &Series, writable: bool) -> PyObject
             let values = ca.iter_str().map(|s| s.into_py(py));
             PyArray1::from_iter_bound(py, values).into_py(py)
         },
+        Int128 => {
+            let ca = s.i128().unwrap();
+            let values = ca.iter().map(|v| v.into_py(py));
+            PyArray1::from_iter_bound(py, values).into_py(py)
+        },
         Decimal(_, _) => {
             let ca = s.decimal().unwrap();
             let values = decimal_to_pyobject_iter(py, ca).map(|v| v.into_py(py));
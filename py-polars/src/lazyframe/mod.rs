@@ -349,6 +349,7 @@ impl PyLazyFrame {
             use_statistics,
             hive_options,
             glob,
+            include_file_paths: None,
         };
 
         let lf = if path.is_some() {
@@ -417,6 +418,7 @@ impl PyLazyFrame {
             memory_map,
             #[cfg(feature = "cloud")]
             cloud_options,
+            include_file_paths: None,
         };
 
         let lf = if let Some(path) = &path {
@@ -537,6 +539,7 @@ impl PyLazyFrame {
                 nulls_last: vec![nulls_last],
                 multithreaded,
                 maintain_order,
+                ..Default::default()
             },
         )
         .into()
@@ -559,6 +562,7 @@ impl PyLazyFrame {
                 nulls_last,
                 maintain_order,
                 multithreaded,
+                ..Default::default()
             },
         )
         .into()
@@ -666,7 +670,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(all(feature = "streaming", feature = "parquet"))]
-    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, maintain_order))]
+    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, maintain_order, checkpoint))]
     fn sink_parquet(
         &self,
         py: Python,
@@ -677,6 +681,7 @@ impl PyLazyFrame {
         row_group_size: Option<usize>,
         data_pagesize_limit: Option<usize>,
         maintain_order: bool,
+        checkpoint: bool,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
 
@@ -692,19 +697,25 @@ impl PyLazyFrame {
         // threads we deadlock.
         py.allow_threads(|| {
             let ldf = self.ldf.clone();
-            ldf.sink_parquet(path, options).map_err(PyPolarsErr::from)
+            if checkpoint {
+                ldf.sink_checkpointed(path, FileType::Parquet(options))
+            } else {
+                ldf.sink_parquet(path, options)
+            }
+            .map_err(PyPolarsErr::from)
         })?;
         Ok(())
     }
 
     #[cfg(all(feature = "streaming", feature = "ipc"))]
-    #[pyo3(signature = (path, compression, maintain_order))]
+    #[pyo3(signature = (path, compression, maintain_order, checkpoint))]
     fn sink_ipc(
         &self,
         py: Python,
         path: PathBuf,
         compression: Option<Wrap<IpcCompression>>,
         maintain_order: bool,
+        checkpoint: bool,
     ) -> PyResult<()> {
         let options = IpcWriterOptions {
             compression: compression.map(|c| c.0),
@@ -715,13 +726,18 @@ impl PyLazyFrame {
         // threads we deadlock.
         py.allow_threads(|| {
             let ldf = self.ldf.clone();
-            ldf.sink_ipc(path, options).map_err(PyPolarsErr::from)
+            if checkpoint {
+                ldf.sink_checkpointed(path, FileType::Ipc(options))
+            } else {
+                ldf.sink_ipc(path, options)
+            }
+            .map_err(PyPolarsErr::from)
         })?;
         Ok(())
     }
 
     #[cfg(all(feature = "streaming", feature = "csv"))]
-    #[pyo3(signature = (path, include_bom, include_header, separator, line_terminator, quote_char, batch_size, datetime_format, date_format, time_format, float_precision, null_value, quote_style, maintain_order))]
+    #[pyo3(signature = (path, include_bom, include_header, separator, line_terminator, quote_char, batch_size, datetime_format, date_format, time_format, float_precision, null_value, quote_style, maintain_order, checkpoint))]
     fn sink_csv(
         &self,
         py: Python,
@@ -739,6 +755,7 @@ impl PyLazyFrame {
         null_value: Option<String>,
         quote_style: Option<Wrap<QuoteStyle>>,
         maintain_order: bool,
+        checkpoint: bool,
     ) -> PyResult<()> {
         let quote_style = quote_style.map_or(QuoteStyle::default(), |wrap| wrap.0);
         let null_value = null_value.unwrap_or(SerializeOptions::default().null);
@@ -767,7 +784,12 @@ impl PyLazyFrame {
         // threads we deadlock.
         py.allow_threads(|| {
             let ldf = self.ldf.clone();
-            ldf.sink_csv(path, options).map_err(PyPolarsErr::from)
+            if checkpoint {
+                ldf.sink_checkpointed(path, FileType::Csv(options))
+            } else {
+                ldf.sink_csv(path, options)
+            }
+            .map_err(PyPolarsErr::from)
         })?;
         Ok(())
     }
@@ -982,6 +1004,14 @@ impl PyLazyFrame {
             .into())
     }
 
+    #[cfg(feature = "iejoin")]
+    fn join_where(&self, other: Self, predicates: Vec<PyExpr>, suffix: String) -> Self {
+        let ldf = self.ldf.clone();
+        let other = other.ldf;
+        ldf.join_where(other, predicates.to_exprs(), Some(suffix))
+            .into()
+    }
+
     fn with_column(&mut self, expr: PyExpr) -> Self {
         let ldf = self.ldf.clone();
         ldf.with_column(expr.inner).into()
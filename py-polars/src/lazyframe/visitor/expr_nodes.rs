@@ -10,7 +10,7 @@ use polars_plan::prelude::{
     AExpr, FunctionExpr, GroupbyOptions, IRAggExpr, LiteralValue, Operator, PowFunction,
     WindowMapping, WindowType,
 };
-use polars_time::prelude::RollingGroupOptions;
+use polars_time::prelude::{RollingGroupOptions, RoundTies};
 use pyo3::exceptions::PyNotImplementedError;
 use pyo3::prelude::*;
 
@@ -156,6 +156,12 @@ pub enum PyStringFunction {
     ZFill,
     ContainsMany,
     ReplaceMany,
+    ExtractNamed,
+    ExtractAllGroups,
+    LevenshteinDistance,
+    HammingDistance,
+    JaroWinkler,
+    Normalize,
 }
 
 #[pymethods]
@@ -764,9 +770,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::Extract(_) => {
                         (PyStringFunction::Extract.into_py(py),).to_object(py)
                     },
+                    StringFunction::ExtractNamed(group_name) => {
+                        (PyStringFunction::ExtractNamed.into_py(py), group_name).to_object(py)
+                    },
                     StringFunction::ExtractAll => {
                         (PyStringFunction::ExtractAll.into_py(py),).to_object(py)
                     },
+                    StringFunction::ExtractAllGroups { dtype, pat } => (
+                        PyStringFunction::ExtractAllGroups.into_py(py),
+                        Wrap(dtype.clone()).to_object(py),
+                        pat,
+                    )
+                        .to_object(py),
                     StringFunction::ExtractGroups { dtype, pat } => (
                         PyStringFunction::ExtractGroups.into_py(py),
                         Wrap(dtype.clone()).to_object(py),
@@ -801,6 +816,21 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::Reverse => {
                         (PyStringFunction::Reverse.into_py(py),).to_object(py)
                     },
+                    StringFunction::Normalize { form, case_fold } => (
+                        PyStringFunction::Normalize.into_py(py),
+                        format!("{form:?}"),
+                        case_fold,
+                    )
+                        .to_object(py),
+                    StringFunction::LevenshteinDistance => {
+                        (PyStringFunction::LevenshteinDistance.into_py(py),).to_object(py)
+                    },
+                    StringFunction::HammingDistance => {
+                        (PyStringFunction::HammingDistance.into_py(py),).to_object(py)
+                    },
+                    StringFunction::JaroWinkler => {
+                        (PyStringFunction::JaroWinkler.into_py(py),).to_object(py)
+                    },
                     StringFunction::PadStart { length, fill_char } => {
                         (PyStringFunction::PadStart.into_py(py), length, fill_char).to_object(py)
                     },
@@ -888,8 +918,8 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     TemporalFunction::IsoYear => (PyTemporalFunction::IsoYear,).into_py(py),
                     TemporalFunction::Quarter => (PyTemporalFunction::Quarter,).into_py(py),
                     TemporalFunction::Month => (PyTemporalFunction::Month,).into_py(py),
-                    TemporalFunction::Week => (PyTemporalFunction::Week,).into_py(py),
-                    TemporalFunction::WeekDay => (PyTemporalFunction::WeekDay,).into_py(py),
+                    TemporalFunction::Week(_) => (PyTemporalFunction::Week,).into_py(py),
+                    TemporalFunction::WeekDay(_) => (PyTemporalFunction::WeekDay,).into_py(py),
                     TemporalFunction::Day => (PyTemporalFunction::Day,).into_py(py),
                     TemporalFunction::OrdinalDay => (PyTemporalFunction::OrdinalDay,).into_py(py),
                     TemporalFunction::Time => (PyTemporalFunction::Time,).into_py(py),
@@ -936,7 +966,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     TemporalFunction::TimeStamp(time_unit) => {
                         (PyTemporalFunction::TimeStamp, Wrap(*time_unit)).into_py(py)
                     },
-                    TemporalFunction::Truncate => (PyTemporalFunction::Truncate).into_py(py),
+                    TemporalFunction::Truncate(_) => (PyTemporalFunction::Truncate).into_py(py),
                     TemporalFunction::OffsetBy => (PyTemporalFunction::OffsetBy,).into_py(py),
                     TemporalFunction::MonthStart => (PyTemporalFunction::MonthStart,).into_py(py),
                     TemporalFunction::MonthEnd => (PyTemporalFunction::MonthEnd,).into_py(py),
@@ -944,7 +974,14 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         (PyTemporalFunction::BaseUtcOffset,).into_py(py)
                     },
                     TemporalFunction::DSTOffset => (PyTemporalFunction::DSTOffset,).into_py(py),
-                    TemporalFunction::Round => (PyTemporalFunction::Round).into_py(py),
+                    TemporalFunction::Round(ties) => (
+                        PyTemporalFunction::Round,
+                        match ties {
+                            RoundTies::Up => "up",
+                            RoundTies::Down => "down",
+                        },
+                    )
+                        .into_py(py),
                     TemporalFunction::ReplaceTimeZone(time_zone, non_existent) => (
                         PyTemporalFunction::ReplaceTimeZone,
                         time_zone
@@ -953,6 +990,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         match non_existent {
                             NonExistent::Null => "nullify",
                             NonExistent::Raise => "raise",
+                            NonExistent::ShiftForward => "shift_forward",
                         },
                     )
                         .into_py(py),
@@ -1007,6 +1045,11 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Abs => ("abs",).to_object(py),
                 FunctionExpr::Hist { .. } => return Err(PyNotImplementedError::new_err("hist")),
                 FunctionExpr::NullCount => ("null_count",).to_object(py),
+                FunctionExpr::ArgMin => ("arg_min",).to_object(py),
+                FunctionExpr::ArgMax => ("arg_max",).to_object(py),
+                FunctionExpr::Product { .. } => {
+                    return Err(PyNotImplementedError::new_err("product"))
+                },
                 FunctionExpr::Pow(f) => match f {
                     PowFunction::Generic => ("pow",).to_object(py),
                     PowFunction::Sqrt => ("sqrt",).to_object(py),
@@ -1019,6 +1062,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::SearchSorted(_) => {
                     return Err(PyNotImplementedError::new_err("search sorted"))
                 },
+                FunctionExpr::IndexOf => return Err(PyNotImplementedError::new_err("index of")),
                 FunctionExpr::Range(_) => return Err(PyNotImplementedError::new_err("range")),
                 FunctionExpr::Trigonometry(trigfun) => match trigfun {
                     TrigonometricFunction::Cos => ("cos",),
@@ -1096,7 +1140,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Shift => ("shift",).to_object(py),
                 FunctionExpr::DropNans => ("dropnan",).to_object(py),
                 FunctionExpr::DropNulls => ("dropnull",).to_object(py),
-                FunctionExpr::Mode => ("mode",).to_object(py),
+                FunctionExpr::Mode { .. } => ("mode",).to_object(py),
                 FunctionExpr::Skew(_) => return Err(PyNotImplementedError::new_err("skew")),
                 FunctionExpr::Kurtosis(_, _) => {
                     return Err(PyNotImplementedError::new_err("kurtosis"))
@@ -1129,21 +1173,24 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     normalize: _,
                 } => return Err(PyNotImplementedError::new_err("value counts")),
                 FunctionExpr::UniqueCounts => ("unique_counts",).to_object(py),
-                FunctionExpr::ApproxNUnique => {
+                FunctionExpr::ApproxNUnique { .. } => {
                     return Err(PyNotImplementedError::new_err("approx nunique"))
                 },
+                FunctionExpr::QuantileApprox { .. } => {
+                    return Err(PyNotImplementedError::new_err("quantile approx"))
+                },
                 FunctionExpr::Coalesce => ("coalesce",).to_object(py),
                 FunctionExpr::ShrinkType => {
                     return Err(PyNotImplementedError::new_err("shrink type"))
                 },
-                FunctionExpr::Diff(_, _) => return Err(PyNotImplementedError::new_err("diff")),
-                FunctionExpr::PctChange => {
+                FunctionExpr::Diff(_) => return Err(PyNotImplementedError::new_err("diff")),
+                FunctionExpr::PctChange(_) => {
                     return Err(PyNotImplementedError::new_err("pct change"))
                 },
                 FunctionExpr::Interpolate(_) => {
                     return Err(PyNotImplementedError::new_err("interpolate"))
                 },
-                FunctionExpr::InterpolateBy => {
+                FunctionExpr::InterpolateBy(_) => {
                     return Err(PyNotImplementedError::new_err("interpolate_by"))
                 },
                 FunctionExpr::Entropy {
@@ -1167,8 +1214,8 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Correlation { .. } => {
                     return Err(PyNotImplementedError::new_err("corr"))
                 },
-                FunctionExpr::PeakMin => return Err(PyNotImplementedError::new_err("peak min")),
-                FunctionExpr::PeakMax => return Err(PyNotImplementedError::new_err("peak max")),
+                FunctionExpr::PeakMin(_) => return Err(PyNotImplementedError::new_err("peak min")),
+                FunctionExpr::PeakMax(_) => return Err(PyNotImplementedError::new_err("peak max")),
                 FunctionExpr::Cut { .. } => return Err(PyNotImplementedError::new_err("cut")),
                 FunctionExpr::QCut { .. } => return Err(PyNotImplementedError::new_err("qcut")),
                 FunctionExpr::RLE => return Err(PyNotImplementedError::new_err("rle")),
@@ -1197,16 +1244,16 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::ForwardFill { limit: _ } => {
                     return Err(PyNotImplementedError::new_err("forward fill"))
                 },
-                FunctionExpr::SumHorizontal => {
+                FunctionExpr::SumHorizontal { .. } => {
                     return Err(PyNotImplementedError::new_err("sum horizontal"))
                 },
-                FunctionExpr::MaxHorizontal => {
+                FunctionExpr::MaxHorizontal { .. } => {
                     return Err(PyNotImplementedError::new_err("max horizontal"))
                 },
-                FunctionExpr::MeanHorizontal => {
+                FunctionExpr::MeanHorizontal { .. } => {
                     return Err(PyNotImplementedError::new_err("mean horizontal"))
                 },
-                FunctionExpr::MinHorizontal => {
+                FunctionExpr::MinHorizontal { .. } => {
                     return Err(PyNotImplementedError::new_err("min horizontal"))
                 },
                 FunctionExpr::EwmMean { options: _ } => {
@@ -1218,6 +1265,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::EwmVar { options: _ } => {
                     return Err(PyNotImplementedError::new_err("ewm var"))
                 },
+                FunctionExpr::EwmCov { options: _ } => {
+                    return Err(PyNotImplementedError::new_err("ewm cov"))
+                },
+                FunctionExpr::EwmCorr { options: _ } => {
+                    return Err(PyNotImplementedError::new_err("ewm corr"))
+                },
                 FunctionExpr::Replace => return Err(PyNotImplementedError::new_err("replace")),
                 FunctionExpr::ReplaceStrict { return_dtype: _ } => {
                     return Err(PyNotImplementedError::new_err("replace_strict"))
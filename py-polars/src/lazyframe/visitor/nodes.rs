@@ -420,6 +420,9 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     JoinType::Full => "full",
                     JoinType::AsOf(_) => return Err(PyNotImplementedError::new_err("asof join")),
                     JoinType::Cross => "cross",
+                    JoinType::IEJoin(_) => {
+                        return Err(PyNotImplementedError::new_err("inequality join"))
+                    },
                     JoinType::Semi => "leftsemi",
                     JoinType::Anti => "leftanti",
                 },
@@ -264,6 +264,9 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::thread_pool_size))
         .unwrap();
+    #[cfg(feature = "streaming")]
+    m.add_wrapped(wrap_pyfunction!(functions::set_streaming_metrics_callback))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::enable_string_cache))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::disable_string_cache))
@@ -130,11 +130,13 @@ pub fn map_single(
     agg_list: bool,
     is_elementwise: bool,
     returns_scalar: bool,
+    output_type_fn: Option<PyObject>,
 ) -> PyExpr {
     let output_type = output_type.map(|wrap| wrap.0);
 
     let func =
-        python_udf::PythonUdfExpression::new(lambda, output_type, is_elementwise, returns_scalar);
+        python_udf::PythonUdfExpression::new(lambda, output_type, is_elementwise, returns_scalar)
+            .with_output_type_fn(output_type_fn);
     pyexpr.inner.clone().map_python(func, agg_list).into()
 }
 
@@ -55,6 +55,22 @@ fn python_function_caller_df(df: DataFrame, lambda: &PyObject) -> PolarsResult<D
     })
 }
 
+fn python_function_caller_dtype(dtype: &DataType, lambda: &PyObject) -> PolarsResult<DataType> {
+    Python::with_gil(|py| {
+        let out = lambda
+            .call1(py, (Wrap(dtype.clone()).to_object(py),))
+            .map_err(|e| ComputeError(format!("{}", e).into()))?;
+        let dtype = out.extract::<Wrap<DataType>>(py).map_err(|_| {
+            let pytype = out.bind(py).get_type();
+            ComputeError(
+                format!("expected the return dtype function to return a dtype, got a '{pytype}'")
+                    .into(),
+            )
+        })?;
+        Ok(dtype.0)
+    })
+}
+
 fn warning_function(msg: &str, warning: PolarsWarning) {
     Python::with_gil(|py| {
         let warn_fn = UTILS.bind(py).getattr(intern!(py, "_polars_warn")).unwrap();
@@ -96,6 +112,8 @@ pub fn __register_startup_deps() {
         unsafe { python_udf::CALL_SERIES_UDF_PYTHON = Some(python_function_caller_series) }
         // register DATAFRAME UDF
         unsafe { python_udf::CALL_DF_UDF_PYTHON = Some(python_function_caller_df) }
+        // register the UDF that computes the output dtype of a SERIES UDF
+        unsafe { python_udf::CALL_DTYPE_UDF_PYTHON = Some(python_function_caller_dtype) }
         // register warning function for `polars_warn!`
         unsafe { polars_error::set_warning_function(warning_function) };
         Python::with_gil(|py| {
@@ -94,14 +94,10 @@ impl PySeries {
         .into_py(py))
     }
 
-    fn product(&self, py: Python) -> PyResult<PyObject> {
-        Ok(Wrap(
-            self.series
-                .product()
-                .map_err(PyPolarsErr::from)?
-                .as_any_value(),
-        )
-        .into_py(py))
+    fn product(&self, py: Python, overflow: Wrap<ProductOverflow>) -> PyResult<PyObject> {
+        let s =
+            polars_ops::prelude::product(&self.series, overflow.0).map_err(PyPolarsErr::from)?;
+        Ok(Wrap(s.get(0).map_err(PyPolarsErr::from)?).into_py(py))
     }
 
     fn quantile(
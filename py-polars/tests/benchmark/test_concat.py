@@ -0,0 +1,40 @@
+"""Benchmark tests for concatenating nested data."""
+
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+
+pytestmark = pytest.mark.benchmark()
+
+
+@pytest.fixture(scope="module")
+def list_of_struct_chunks() -> list[pl.DataFrame]:
+    # A list of structs of which a field is again a list of structs, with nulls at
+    # every level so the growables can't take their null-free fast paths.
+    inner = pl.struct(
+        pl.int_range(pl.len()).alias("x"),
+        pl.when(pl.int_range(pl.len()) % 7 != 0).then(pl.lit("value")).alias("y"),
+    )
+    df = (
+        pl.DataFrame({"id": range(20_000)})
+        .with_columns(
+            pl.struct(
+                pl.col("id").alias("a"),
+                pl.concat_list(inner, inner, inner).alias("b"),
+            ).alias("s")
+        )
+        .with_columns(
+            pl.when(pl.col("id") % 5 != 0)
+            .then(pl.concat_list("s", "s", "s", "s"))
+            .alias("nested")
+        )
+    )
+    return [df.slice(offset, 2_000) for offset in range(0, df.height, 2_000)]
+
+
+def test_concat_list_of_struct(list_of_struct_chunks: list[pl.DataFrame]) -> None:
+    out = pl.concat(list_of_struct_chunks, rechunk=True)
+    assert out.n_chunks() == 1
+    assert out.height == sum(df.height for df in list_of_struct_chunks)
@@ -1135,9 +1135,26 @@ def test_replace_time_zone_non_existent_null() -> None:
     assert_series_equal(result, expected)
 
 
+def test_replace_time_zone_non_existent_shift_forward() -> None:
+    result = (
+        pl.Series(["2021-03-28 02:30", "2021-03-28 03:30", "2021-03-28 01:30"])
+        .str.to_datetime(time_unit="ms")
+        .dt.replace_time_zone("Europe/Warsaw", non_existent="shift_forward")
+    )
+    expected = pl.Series(
+        [
+            datetime(2021, 3, 28, 3, 0),
+            datetime(2021, 3, 28, 3, 30),
+            datetime(2021, 3, 28, 1, 30),
+        ]
+    ).dt.replace_time_zone("Europe/Warsaw")
+    assert_series_equal(result, expected.dt.cast_time_unit("ms"))
+
+
 def test_invalid_non_existent() -> None:
     with pytest.raises(
-        ValueError, match="`non_existent` must be one of {'null', 'raise'}, got cabbage"
+        ValueError,
+        match="`non_existent` must be one of {'null', 'raise', 'shift_forward'}, got",
     ):
         (
             pl.Series([datetime(2020, 1, 1)]).dt.replace_time_zone(
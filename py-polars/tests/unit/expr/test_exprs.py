@@ -21,7 +21,7 @@ from tests.unit.conftest import (
 if TYPE_CHECKING:
     from zoneinfo import ZoneInfo
 
-    from polars.type_aliases import PolarsDataType
+    from polars.type_aliases import PolarsDataType, RankMethod
 else:
     from polars._utils.convert import string_to_zoneinfo as ZoneInfo
 
@@ -378,6 +378,35 @@ def test_rank_so_4109() -> None:
     }
 
 
+@pytest.mark.parametrize("method", ["average", "min", "max", "dense"])
+@pytest.mark.parametrize("descending", [False, True])
+def test_rank_over_groups(method: RankMethod, descending: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 2, 1, 2, 1, 3, 2, 1, 2],
+            "a": [3, None, 1, 2.5, 3, 0.0, 2.5, None, -1.0],
+        }
+    )
+    out = df.select(pl.col("a").rank(method, descending=descending).over("g"))
+    expected = (
+        df.with_row_index()
+        .group_by("g")
+        .map_groups(
+            lambda gdf: gdf.with_columns(
+                pl.col("a").rank(method, descending=descending)
+            )
+        )
+        .sort("index")
+        .select("a")
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").rank(method, descending=descending)
+    )
+    assert out["a"].list.len().to_list() == [4, 4, 1]
+
+
 def test_rank_string_null_11252() -> None:
     rank = pl.Series([None, "", "z", None, "a"]).rank()
     assert rank.to_list() == [None, 1.0, 3.0, None, 2.0]
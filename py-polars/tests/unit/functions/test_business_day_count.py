@@ -130,6 +130,23 @@ def test_business_day_count_w_holidays() -> None:
     assert_series_equal(result, expected)
 
 
+def test_business_day_count_w_holidays_column() -> None:
+    df = pl.DataFrame(
+        {
+            "start": [date(2020, 1, 1), date(2020, 1, 2), date(2020, 1, 2)],
+            "end": [date(2020, 1, 2), date(2020, 1, 10), date(2020, 1, 9)],
+            "holidays": [[date(2020, 1, 1)], [date(2020, 1, 9)], None],
+        }
+    )
+    result = df.select(
+        business_day_count=pl.business_day_count(
+            "start", "end", holidays=pl.col("holidays")
+        ),
+    )["business_day_count"]
+    expected = pl.Series("business_day_count", [0, 5, 5], pl.Int32)
+    assert_series_equal(result, expected)
+
+
 @given(
     start=st.dates(min_value=dt.date(1969, 1, 1), max_value=dt.date(1970, 12, 31)),
     end=st.dates(min_value=dt.date(1969, 1, 1), max_value=dt.date(1970, 12, 31)),
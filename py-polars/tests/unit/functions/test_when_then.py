@@ -622,3 +622,37 @@ def test_when_then_supertype_15975_comment() -> None:
     )
 
     assert q.collect()["val"].to_list() == [1.0, 1.5, 16.0]
+
+
+def test_when_then_chain_evaluates_undecided_rows() -> None:
+    df = pl.DataFrame({"a": ["1", "x", None, "3", "y"], "b": [1, 2, 3, 4, 5]})
+
+    # The strict casts would fail on the rows that an earlier branch decided.
+    expr = (
+        pl.when(pl.col("a") == "x")
+        .then(pl.lit(0))
+        .when(pl.col("a") == "y")
+        .then(pl.col("b") * 10)
+        .otherwise(pl.col("a").cast(pl.Int64, strict=True))
+        .alias("c")
+    )
+    expected = [1, 0, None, 3, 50]
+    assert df.select(expr)["c"].to_list() == expected
+    out = df.lazy().select(expr).collect(streaming=True)
+    assert out["c"].to_list() == expected
+
+    expr = (
+        pl.when(pl.col("b") > 3)
+        .then(pl.col("b"))
+        .when(pl.col("b") > 1)
+        .then(pl.lit(1.5))
+        .otherwise(pl.col("b") - 10)
+    )
+    assert df.select(expr)["b"].to_list() == [-9.0, 1.5, 1.5, 4.0, 5.0]
+
+    out = (
+        df.with_columns(k=pl.col("b") % 2)
+        .group_by("k", maintain_order=True)
+        .agg(expr)
+    )
+    assert out["b"].to_list() == [[-9.0, 1.5, 5.0], [1.5, 4.0]]
@@ -78,3 +78,50 @@ def test_alias_prune_in_fold_15438() -> None:
         }
     )
     assert_frame_equal(df, expected)
+
+
+def test_fold_return_dtype() -> None:
+    lf = pl.LazyFrame({"a": [1, 2], "b": [3, 4]})
+
+    result = lf.select(
+        pl.fold(
+            acc=pl.lit(""),
+            function=lambda acc, x: acc + x.cast(pl.String),
+            exprs=pl.all(),
+            return_dtype=pl.String,
+        ).alias("concat"),
+        pl.fold(
+            acc=pl.lit(0),
+            function=lambda acc, x: acc + x,
+            exprs=pl.all(),
+            return_dtype=pl.Float64,
+        ).alias("sum"),
+    )
+    expected = pl.DataFrame({"concat": ["13", "24"], "sum": [4.0, 6.0]})
+    assert result.collect_schema() == expected.schema
+    assert_frame_equal(result.collect(), expected)
+
+
+def test_cum_fold_schema() -> None:
+    lf = pl.LazyFrame({"a": [1, 2], "b": [3, 4]})
+
+    for include_init in [False, True]:
+        result = lf.select(
+            pl.cum_fold(
+                pl.lit(0),
+                lambda acc, x: acc + x,
+                pl.all(),
+                include_init=include_init,
+                return_dtype=pl.Int16,
+            )
+        )
+        assert result.collect_schema() == result.collect().schema
+
+    result = lf.select(
+        pl.cum_fold(pl.lit(0), lambda acc, x: acc + x, pl.all(), return_dtype=pl.Int16)
+    )
+    expected = pl.DataFrame(
+        {"cum_fold": [{"a": 1, "b": 4}, {"a": 2, "b": 6}]},
+        schema={"cum_fold": pl.Struct({"a": pl.Int16, "b": pl.Int16})},
+    )
+    assert_frame_equal(result.collect(), expected)
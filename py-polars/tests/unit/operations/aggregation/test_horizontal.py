@@ -460,3 +460,46 @@ def test_fold_all_schema() -> None:
     # divide because of overflow
     result = df.select(pl.sum_horizontal(pl.all().hash(seed=1) // int(1e8)))
     assert result.dtypes == [pl.UInt64]
+
+
+@pytest.mark.parametrize("ignore_nulls", [True, False])
+def test_horizontal_ignore_nulls(ignore_nulls: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, None, 3, None],
+            "b": [4, 5, None, None],
+            "p": [True, None, False, None],
+            "q": [True, True, None, None],
+        }
+    )
+    result = df.select(
+        sum=pl.sum_horizontal("a", "b", ignore_nulls=ignore_nulls),
+        mean=pl.mean_horizontal("a", "b", ignore_nulls=ignore_nulls),
+        min=pl.min_horizontal("a", "b", ignore_nulls=ignore_nulls),
+        max=pl.max_horizontal("a", "b", ignore_nulls=ignore_nulls),
+        all=pl.all_horizontal("p", "q", ignore_nulls=ignore_nulls),
+        any=pl.any_horizontal("p", "q", ignore_nulls=ignore_nulls),
+    )
+    if ignore_nulls:
+        expected = pl.DataFrame(
+            {
+                "sum": [5, 5, 3, 0],
+                "mean": [2.5, 5.0, 3.0, None],
+                "min": [1, 5, 3, None],
+                "max": [4, 5, 3, None],
+                "all": [True, True, False, True],
+                "any": [True, True, False, False],
+            }
+        )
+    else:
+        expected = pl.DataFrame(
+            {
+                "sum": [5, None, None, None],
+                "mean": [2.5, None, None, None],
+                "min": [1, None, None, None],
+                "max": [4, None, None, None],
+                "all": [True, None, False, None],
+                "any": [True, True, None, None],
+            }
+        )
+    assert_frame_equal(result, expected)
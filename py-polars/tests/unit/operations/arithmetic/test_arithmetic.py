@@ -676,8 +676,8 @@ def test_arithmetic_duration_div_multiply() -> None:
             ("a", pl.Duration(time_unit="us")),
             ("b", pl.Duration(time_unit="us")),
             ("c", pl.Duration(time_unit="us")),
-            ("d", pl.Unknown()),
-            ("e", pl.Unknown()),
+            ("d", pl.Duration(time_unit="us")),
+            ("e", pl.Duration(time_unit="us")),
             ("f", pl.Float64()),
         ]
     )
@@ -742,6 +742,45 @@ def test_arithmetic_duration_div_multiply() -> None:
     }
 
 
+def test_arithmetic_duration_numeric_columns() -> None:
+    df = pl.DataFrame(
+        {
+            "a": pl.Series([10, 20, 30], dtype=pl.Duration("ms")),
+            "b": pl.Series([1_500, 2_000, 500], dtype=pl.Duration("us")),
+            "f": [0.25, 1.5, None],
+            "i": [2, -1, 4],
+        }
+    )
+    q = df.lazy().select(
+        mul=pl.col("a") * pl.col("f"),
+        rmul=pl.col("i") * pl.col("a"),
+        div=pl.col("a") / pl.col("i"),
+        ratio=pl.col("a") / pl.col("b"),
+    )
+    assert q.collect_schema() == pl.Schema(
+        [
+            ("mul", pl.Duration("ms")),
+            ("rmul", pl.Duration("ms")),
+            ("div", pl.Duration("ms")),
+            ("ratio", pl.Float64()),
+        ]
+    )
+    assert q.collect().to_dict(as_series=False) == {
+        "mul": [timedelta(milliseconds=3), timedelta(milliseconds=30), None],
+        "rmul": [
+            timedelta(milliseconds=20),
+            timedelta(milliseconds=-20),
+            timedelta(milliseconds=120),
+        ],
+        "div": [
+            timedelta(milliseconds=5),
+            timedelta(milliseconds=-20),
+            timedelta(milliseconds=7),
+        ],
+        "ratio": [10_000 / 1_500, 10.0, 60.0],
+    }
+
+
 def test_invalid_shapes_err() -> None:
     with pytest.raises(
         InvalidOperationError,
@@ -117,3 +117,31 @@ def test_lazy_map_schema() -> None:
     assert df.lazy().map_batches(
         custom2, validate_output_schema=False
     ).collect().to_dict(as_series=False) == {"a": ["1", "2", "3"], "b": ["a", "b", "c"]}
+
+
+def test_map_batches_return_dtype_fn() -> None:
+    calls = 0
+
+    def to_string(s: pl.Series) -> pl.Series:
+        nonlocal calls
+        calls += 1
+        return s.cast(pl.String) if s.dtype.is_integer() else s
+
+    lf = pl.LazyFrame({"a": [1, 2, 3], "b": [1.0, 2.0, 3.0]})
+    expr = pl.all().map_batches(
+        to_string,
+        return_dtype_fn=lambda dtype: pl.String if dtype.is_integer() else dtype,
+        is_elementwise=True,
+    )
+    q = lf.select(expr)
+
+    # the schema is resolved without calling the function
+    assert q.collect_schema() == pl.Schema({"a": pl.String, "b": pl.Float64})
+    assert calls == 0
+    assert "STREAMING" in q.explain(streaming=True)
+
+    out = q.collect(streaming=True)
+    assert out.to_dict(as_series=False) == {
+        "a": ["1", "2", "3"],
+        "b": [1.0, 2.0, 3.0],
+    }
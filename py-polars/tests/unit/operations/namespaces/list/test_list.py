@@ -370,6 +370,18 @@ def test_list_eval_dtype_inference() -> None:
     ]
 
 
+def test_list_eval_parallel_matches_sequential() -> None:
+    # nulls force evaluation per sublist; enough rows to split over threads
+    s = pl.Series("a", [[i, i + 1, None] if i % 7 else None for i in range(1000)])
+    expr = pl.element().drop_nulls().sum()
+
+    expected = s.list.eval(expr, parallel=False)
+    result = s.list.eval(expr, parallel=True)
+    assert_series_equal(result, expected)
+    assert result[1].to_list() == [3]
+    assert result[0] is None
+
+
 def test_list_eval_categorical() -> None:
     df = pl.DataFrame({"test": [["a", None]]}, schema={"test": pl.List(pl.Categorical)})
     df = df.select(
@@ -704,6 +716,42 @@ def test_list_gather_oob_10079() -> None:
         df.select(pl.col("a").gather(999))
 
 
+def test_list_slice_per_row() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2, 3], None, [4, 5], [6, 7, 8, 9], []],
+            "offset": [1, 0, None, -2, 0],
+            "length": [1, 2, 1, 5, 1],
+        }
+    )
+    out = df.select(pl.col("a").list.slice("offset", "length"))
+    assert out["a"].to_list() == [[2], None, None, [8, 9], []]
+
+    out = df.slice(1).select(pl.col("a").list.slice(0, "length"))
+    assert out["a"].to_list() == [None, [4], [6, 7, 8, 9], []]
+
+
+def test_list_gather_sublists() -> None:
+    s = pl.Series("a", [[1, 2, 3], None, [4, 5], []])
+    assert s.list.gather([-1, 0, None], null_on_oob=True).to_list() == [
+        [3, 1, None],
+        None,
+        [5, 4, None],
+        [None, None, None],
+    ]
+
+    df = pl.DataFrame({"a": s, "idx": [[0, -3], [0], None, [0]]})
+    out = df.select(pl.col("a").list.gather("idx", null_on_oob=True))
+    assert out["a"].to_list() == [[1, 1], None, None, [None]]
+    out = df.select(pl.col("a").list.gather(pl.lit([[1]]), null_on_oob=True))
+    assert out["a"].to_list() == [[2], None, [5], [None]]
+
+    s = pl.Series("a", [["x", "y"], ["z"]], dtype=pl.List(pl.Categorical))
+    out = s.list.gather([-1])
+    assert out.dtype == pl.List(pl.Categorical)
+    assert out.to_list() == [["y"], ["z"]]
+
+
 def test_utf8_empty_series_arg_min_max_10703() -> None:
     res = pl.select(pl.lit(pl.Series("list", [["a"], []]))).with_columns(
         pl.all(),
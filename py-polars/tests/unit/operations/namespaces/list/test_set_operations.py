@@ -187,3 +187,36 @@ def test_broadcast_sliced() -> None:
     expected = pl.DataFrame({"a": [[2], [3, 4]]})
 
     assert_frame_equal(out, expected)
+
+
+def test_list_set_operations_boolean() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[True, False], [True, True], [False]],
+            "b": [[False], [False, True], [None]],
+        },
+        schema={"a": pl.List(pl.Boolean), "b": pl.List(pl.Boolean)},
+    )
+
+    assert df.select(pl.col("a").list.set_union("b"))["a"].to_list() == [
+        [True, False],
+        [True, False],
+        [False, None],
+    ]
+    assert df.select(pl.col("a").list.set_intersection("b"))["a"].to_list() == [
+        [False],
+        [True],
+        [],
+    ]
+    assert df.select(pl.col("a").list.set_difference("b"))["a"].to_list() == [
+        [True],
+        [],
+        [False],
+    ]
+    assert df.select(pl.col("a").list.set_symmetric_difference("b"))[
+        "a"
+    ].to_list() == [
+        [True],
+        [False],
+        [False, None],
+    ]
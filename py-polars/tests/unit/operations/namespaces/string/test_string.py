@@ -1450,6 +1450,13 @@ def test_string_extract_groups_lazy_schema_10305() -> None:
     assert df.collect_schema() == {"candidate": pl.String, "ref": pl.String}
 
 
+def test_string_extract_groups_no_captures_lazy_schema() -> None:
+    lf = pl.LazyFrame({"a": ["x", None]})
+    q = lf.select(pl.col("a").str.extract_groups("x"))
+
+    assert q.collect_schema() == q.collect().schema
+
+
 def test_string_reverse() -> None:
     df = pl.DataFrame(
         {
@@ -155,6 +155,27 @@ def test_add_business_days_w_holidays() -> None:
     assert_series_equal(result, expected)
 
 
+def test_add_business_days_w_holidays_column() -> None:
+    df = pl.DataFrame(
+        {
+            "start": [date(2020, 1, 1), date(2020, 1, 2), date(2020, 1, 2)],
+            "n": [1, 5, 7],
+            "holidays": [
+                [date(2020, 1, 2)],
+                [date(2020, 1, 3), date(2020, 1, 9)],
+                None,
+            ],
+        }
+    )
+    result = df.select(
+        result=pl.col("start").dt.add_business_days("n", holidays=pl.col("holidays")),
+    )["result"]
+    expected = pl.Series(
+        "result", [date(2020, 1, 3), date(2020, 1, 13), date(2020, 1, 13)]
+    )
+    assert_series_equal(result, expected)
+
+
 def test_add_business_days_w_roll() -> None:
     df = pl.DataFrame(
         {
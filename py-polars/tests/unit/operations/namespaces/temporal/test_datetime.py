@@ -622,6 +622,44 @@ def test_round_negative() -> None:
         pl.Series([datetime(1895, 5, 7)]).dt.round("-1m")
 
 
+def test_round_calendar_duration() -> None:
+    s = pl.Series(
+        [
+            datetime(2023, 1, 16),
+            datetime(2023, 2, 16),
+            datetime(2024, 2, 15, 12),
+            datetime(2024, 2, 1),
+        ]
+    )
+    assert s.dt.round("1mo").to_list() == [
+        datetime(2023, 1, 1),
+        datetime(2023, 3, 1),
+        datetime(2024, 3, 1),
+        datetime(2024, 2, 1),
+    ]
+    assert s.dt.round("1mo", ties="down").to_list() == [
+        datetime(2023, 1, 1),
+        datetime(2023, 3, 1),
+        datetime(2024, 2, 1),
+        datetime(2024, 2, 1),
+    ]
+    assert s.dt.round("1q").to_list() == [
+        datetime(2023, 1, 1),
+        datetime(2023, 4, 1),
+        datetime(2024, 4, 1),
+        datetime(2024, 1, 1),
+    ]
+    assert s.cast(pl.Date).dt.round("1q").to_list() == [
+        date(2023, 1, 1),
+        date(2023, 4, 1),
+        date(2024, 1, 1),
+        date(2024, 1, 1),
+    ]
+
+    with pytest.raises(ValueError, match="`ties` must be one of"):
+        s.dt.round("1mo", ties="even")  # type: ignore[arg-type]
+
+
 @pytest.mark.parametrize(
     ("time_unit", "date_in_that_unit"),
     [
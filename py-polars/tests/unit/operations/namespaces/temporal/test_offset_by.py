@@ -1,12 +1,12 @@
 from __future__ import annotations
 
-from datetime import date, datetime
+from datetime import date, datetime, timedelta
 from typing import TYPE_CHECKING
 
 import pytest
 
 import polars as pl
-from polars.testing import assert_series_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
     from polars.type_aliases import TimeUnit
@@ -116,3 +116,28 @@ def test_datetime_offset_by(
         time_zone
     )
     assert_series_equal(result, expected)
+
+
+def test_offset_by_per_row() -> None:
+    df = pl.DataFrame(
+        {
+            "dates": [datetime(2020, 1, 31), datetime(2020, 2, 29), None],
+            "offset": ["1mo", "-1d", "1y"],
+            "grace": [timedelta(days=1), None, timedelta(hours=6)],
+        }
+    )
+    result = df.select(
+        by_str=pl.col("dates").dt.offset_by(pl.col("offset")),
+        by_duration=pl.col("dates").dt.offset_by(pl.col("grace")),
+        by_timedelta=pl.col("dates").dt.offset_by(timedelta(hours=36)),
+        date=pl.col("dates").cast(pl.Date).dt.offset_by(pl.col("grace")),
+    )
+    expected = pl.DataFrame(
+        {
+            "by_str": [datetime(2020, 2, 29), datetime(2020, 2, 28), None],
+            "by_duration": [datetime(2020, 2, 1), None, None],
+            "by_timedelta": [datetime(2020, 2, 1, 12), datetime(2020, 3, 1, 12), None],
+            "date": [date(2020, 2, 1), None, None],
+        }
+    )
+    assert_frame_equal(result, expected)
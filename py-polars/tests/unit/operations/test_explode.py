@@ -202,6 +202,27 @@ def test_explode_invalid_element_count() -> None:
         df.explode(["col1", "col2"])
 
 
+
+def test_explode_multiple_sliced() -> None:
+    a = pl.Series("a", [[1, 2], [3], [4, 5]])
+    b = pl.Series("b", [["x"], ["y", "z"], ["u"], ["v", "w"]]).slice(1, 3)
+    c = pl.Series("c", [[0, 0], [1, 1], [2, 2]], dtype=pl.Array(pl.Int64, 2))
+    df = pl.DataFrame([a, b])
+
+    result = df.explode(["a", "b"])
+    expected = pl.DataFrame({"a": [1, 2, 3, 4, 5], "b": ["y", "z", "u", "v", "w"]})
+    assert_frame_equal(result, expected)
+
+    # the offsets of a sliced list don't match those of an array
+    d = pl.Series("d", [[9], [1, 2], [3, 4], [5, 6]]).slice(1, 3)
+    result = pl.DataFrame([c, d]).explode(["c", "d"])
+    expected = pl.DataFrame({"c": [0, 0, 1, 1, 2, 2], "d": [1, 2, 3, 4, 5, 6]})
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(ShapeError, match='but "a" and "c" differ'):
+        pl.DataFrame([a, c]).explode(["a", "c"])
+
+
 def test_logical_explode() -> None:
     out = (
         pl.DataFrame(
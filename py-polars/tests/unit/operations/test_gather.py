@@ -1,4 +1,5 @@
 import polars as pl
+from polars.testing import assert_frame_equal
 
 
 def test_negative_index() -> None:
@@ -40,3 +41,16 @@ def test_gather_lit_single_16535() -> None:
     assert df.group_by(["x"], maintain_order=True).agg(pl.all().gather([1])).to_dict(
         as_series=False
     ) == {"x": [1, 2], "y": [[4], [3]]}
+
+
+def test_gather_parallel_output_ranges() -> None:
+    # large enough to be gathered in ranges on the thread pool
+    n = (1 << 20) + 3
+    df = pl.DataFrame({"a": range(n)}).with_columns(
+        pl.when(pl.col("a") % 3 != 0).then(pl.col("a")).alias("b"),
+        pl.col("a").cast(pl.String).alias("c"),
+        pl.concat_list("a", "a").alias("d"),
+        pl.struct("a", "b").alias("e"),
+    )
+    out = df.select(pl.all().gather(pl.int_range(n - 1, -1, -1)))
+    assert_frame_equal(out, df.reverse())
@@ -1130,3 +1130,97 @@ def test_absence_off_null_prop_8224() -> None:
         pl.List(pl.Float64),
         pl.List(pl.Float64),
     ]
+
+
+def test_group_by_mode() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 1, 2, 2, 3],
+            "int": [3, 1, 1, 3, None, None, 5],
+            "str": ["b", "a", "b", "a", "x", "y", "z"],
+        }
+    ).with_columns(cat=pl.col("str").cast(pl.Categorical))
+
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("int").mode(),
+        pl.col("str").mode().alias("str_all"),
+        pl.col("int").mode(tie_break="min").alias("int_min"),
+        pl.col("str").mode(tie_break="max").alias("str_max"),
+        pl.col("cat").mode(tie_break="first").cast(pl.String),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2, 3],
+            "int": [[3, 1], [None], [5]],
+            "str_all": [["b", "a"], ["x", "y"], ["z"]],
+            "int_min": [1, None, 5],
+            "str_max": ["b", "y", "z"],
+            "cat": ["b", "x", "z"],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    # The modes over a window agree with the per-group results.
+    out = df.select(pl.col("int").mode(tie_break="max").over("g"))
+    assert out["int"].to_list() == [3, 3, 3, 3, None, None, 5]
+
+
+def test_group_by_product() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 2, 1, 2, 3, 1],
+            "a": [2, None, 3, None, 2**16, 4],
+            "b": [0.5, 1.5, 2.0, 2.0, None, 1.0],
+        }
+    )
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").product(),
+        pl.col("b").product(),
+        (pl.col("a") * 2**32).product(overflow="float64").alias("a_float"),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2, 3],
+            "a": [24, 1, 2**16],
+            "b": [1.0, 3.0, 1.0],
+            "a_float": [24 * 2.0**96, 1.0, 2.0**48],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.select(pl.col("a").product().over("g"))
+    assert out["a"].to_list() == [24, 1, 24, 1, 2**16, 24]
+
+
+def test_group_by_arg_min_max() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2, 3, 3],
+            "t": [3.0, None, 5.0, None, None, 2.0, 2.0],
+            "v": ["a", "b", "c", "d", "e", "f", "g"],
+        }
+    )
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("t").arg_min().alias("t_arg_min"),
+        pl.col("t").arg_max().alias("t_arg_max"),
+        pl.col("v").arg_max().alias("v_arg_max"),
+        pl.col("v").get(pl.col("t").arg_max()).alias("v_at_t_max"),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2, 3],
+            "t_arg_min": [0, None, 0],
+            "t_arg_max": [2, None, 0],
+            "v_arg_max": [2, 1, 1],
+            "v_at_t_max": ["c", None, "f"],
+        },
+        schema_overrides={
+            "t_arg_min": pl.UInt32,
+            "t_arg_max": pl.UInt32,
+            "v_arg_max": pl.UInt32,
+        },
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.select(pl.col("v").get(pl.col("t").arg_max()).over("g"))
+    assert out["v"].to_list() == ["c", "c", "c", None, None, "f", "f"]
@@ -0,0 +1,82 @@
+from datetime import date
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def test_join_where_between() -> None:
+    events = pl.DataFrame({"ts": [7, None, 1, 4, 10], "id": [1, 2, 3, 4, 5]})
+    windows = pl.DataFrame(
+        {"start": [5, 0, 3, None], "end": [9, 2, 6, 8], "id": [10, 20, 30, 40]}
+    )
+
+    out = events.join_where(
+        windows, pl.col("ts") >= pl.col("start"), pl.col("end") > pl.col("ts")
+    )
+    expected = pl.DataFrame(
+        {
+            "ts": [7, 1, 4],
+            "id": [1, 3, 4],
+            "start": [5, 0, 3],
+            "end": [9, 2, 6],
+            "id_right": [10, 20, 30],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize("op", ["lt", "le", "gt", "ge"])
+def test_join_where_matches_cross_join_filter(op: str) -> None:
+    left = pl.LazyFrame({"a": [3, 1, 2, 2, None], "b": [1.0, 2.0, 3.0, 4.0, 5.0]})
+    right = pl.LazyFrame({"a": [2, None, 1, 3], "c": [4.5, 0.5, 3.0, 1.0]})
+
+    predicates = [
+        getattr(pl.col("a"), op)(pl.col("a_right")),
+        pl.col("b") <= pl.col("c"),
+    ]
+    out = left.join_where(right, *predicates).collect()
+    expected = (
+        left.join(right, how="cross")
+        .filter(predicates)
+        .collect(predicate_pushdown=False)
+    )
+    assert_frame_equal(out, expected)
+    assert "IEJOIN" in left.join_where(right, *predicates).explain()
+
+
+def test_join_where_temporal_with_extra_predicate() -> None:
+    orders = pl.LazyFrame(
+        {
+            "product": ["a", "b", "a"],
+            "ordered": [date(2024, 1, 5), date(2024, 2, 1), date(2024, 3, 1)],
+        }
+    )
+    prices = pl.LazyFrame(
+        {
+            "product": ["a", "a", "b"],
+            "valid_from": [date(2024, 1, 1), date(2024, 2, 1), date(2024, 1, 1)],
+            "valid_to": [date(2024, 2, 1), date(2024, 4, 1), date(2024, 4, 1)],
+            "price": [10, 12, 7],
+        }
+    )
+
+    out = (
+        orders.join_where(
+            prices,
+            pl.col("ordered") >= pl.col("valid_from"),
+            pl.col("ordered") < pl.col("valid_to"),
+            pl.col("product") == pl.col("product_right"),
+        )
+        .select("product", "ordered", "price")
+        .collect()
+    )
+    expected = pl.DataFrame(
+        {
+            "product": ["a", "b", "a"],
+            "ordered": [date(2024, 1, 5), date(2024, 2, 1), date(2024, 3, 1)],
+            "price": [10, 7, 12],
+        }
+    )
+    assert_frame_equal(out, expected)
@@ -157,3 +157,11 @@ def test_interpolate_temporal_nearest(
     assert result.collect_schema()["a"] == input_dtype
     expected = pl.DataFrame({"a": output}, schema={"a": input_dtype})
     assert_frame_equal(result.collect(), expected)
+
+
+def test_interpolate_cubic() -> None:
+    df = pl.LazyFrame({"a": [1, None, 3, None, 1]})
+    result = df.with_columns(pl.col("a").interpolate(method="cubic"))
+    assert result.collect_schema()["a"] == pl.Float64
+    expected = pl.DataFrame({"a": [1.0, 2.375, 3.0, 2.375, 1.0]})
+    assert_frame_equal(result.collect(), expected)
@@ -205,3 +205,20 @@ def test_interpolate_by_invalid() -> None:
         match="null values in `by` column are not yet supported in 'interpolate_by'",
     ):
         s.interpolate_by(by)
+
+
+def test_interpolate_by_methods() -> None:
+    df = pl.DataFrame({"values": [1, None, None, 4], "times": [0, 1, 3, 4]})
+    result = df.select(pl.col("values").interpolate_by("times", method="nearest"))
+    expected = pl.DataFrame({"values": [1, 1, 4, 4]})
+    assert_frame_equal(result, expected)
+
+    # A natural cubic spline doesn't depend on the scale of `by`.
+    df = pl.DataFrame({"values": [1, None, 3, None, 1], "times": [0, 2, 4, 6, 8]})
+    result = df.select(pl.col("values").interpolate_by("times", method="cubic"))
+    expected = pl.DataFrame({"values": [1.0, 2.375, 3.0, 2.375, 1.0]})
+    assert_frame_equal(result, expected)
+
+    s = pl.Series([1, None, 3])
+    with pytest.raises(InvalidOperationError):
+        s.interpolate_by(pl.Series([1, 2, 1]), method="cubic")
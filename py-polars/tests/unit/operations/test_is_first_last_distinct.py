@@ -156,3 +156,29 @@ def test_is_first_last_distinct_all_null(dtypes: PolarsDataType) -> None:
     s = pl.Series([None, None, None], dtype=dtypes)
     assert s.is_first_distinct().to_list() == [True, False, False]
     assert s.is_last_distinct().to_list() == [False, False, True]
+
+
+def test_is_first_last_distinct_over() -> None:
+    df = pl.DataFrame(
+        {
+            "group": [1, 1, 2, 1, 2, 2],
+            "a": [1, 1, 1, None, None, None],
+            "b": ["x", "x", "x", "y", None, None],
+        }
+    )
+    result = df.select(
+        first=pl.struct("a", "b").is_first_distinct().over("group"),
+        last=pl.struct("a", "b").is_last_distinct().over("group"),
+        first_a=pl.col("a").is_first_distinct().over("group"),
+    )
+    expected = pl.DataFrame(
+        {
+            "first": [True, False, True, True, True, False],
+            "last": [False, True, True, True, False, True],
+            "first_a": [True, False, True, True, True, False],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.filter(pl.col("a").is_last_distinct().over("group"))
+    assert result["b"].to_list() == ["x", "x", "y", None]
@@ -1044,6 +1044,16 @@ def test_asof_join_nearest_by_with_tolerance() -> None:
     assert_frame_equal(out, expected)
 
 
+def test_asof_join_nearest_string_key_raises() -> None:
+    df1 = pl.DataFrame({"key": ["a", "c"], "group": [1, 1]}).set_sorted("key")
+    df2 = pl.DataFrame({"key": ["b", "d"], "group": [1, 1]}).set_sorted("key")
+
+    with pytest.raises(pl.InvalidOperationError, match="'nearest' strategy"):
+        df1.join_asof(df2, on="key", strategy="nearest")
+    with pytest.raises(pl.InvalidOperationError, match="'nearest' strategy"):
+        df1.join_asof(df2, on="key", by="group", strategy="nearest")
+
+
 def test_asof_join_nearest_by_date() -> None:
     df1 = pl.DataFrame(
         {
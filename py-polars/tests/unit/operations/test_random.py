@@ -3,7 +3,7 @@ from __future__ import annotations
 import pytest
 
 import polars as pl
-from polars.exceptions import ShapeError
+from polars.exceptions import ComputeError, ShapeError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 
@@ -167,3 +167,23 @@ def test_sample_16232() -> None:
     assert df.select(pl.col("b").list.sample(n=pl.col("a"), seed=0)).to_dict(
         as_series=False
     ) == {"b": [[], [], [1]]}
+
+
+def test_sample_weighted() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 4, 5], "w": [0.0, 1.0, None, 3.0, 0]})
+
+    # rows with a zero or null weight are never sampled
+    out = df.sample(n=50, with_replacement=True, seed=0, weights="w")
+    assert set(out["a"]) <= {2, 4}
+    out = df.sample(n=2, seed=0, weights=df["w"])
+    assert sorted(out["a"]) == [2, 4]
+    with pytest.raises(ShapeError):
+        df.sample(n=3, seed=0, weights="w")
+
+    out = df.select(pl.col("a").sample(fraction=0.4, seed=0, weights=pl.col("w")))
+    assert sorted(out["a"]) == [2, 4]
+    s = df["a"].sample(n=20, with_replacement=True, seed=0, weights=df["w"])
+    assert set(s) <= {2, 4}
+
+    with pytest.raises(ComputeError, match="non-negative"):
+        df.sample(n=1, seed=0, weights=pl.Series([1, -1, 1, 1, 1]))
@@ -49,6 +49,22 @@ def test_hist() -> None:
     ).to_series().to_list() == [0, 3, 4]
 
 
+def test_hist_normalize() -> None:
+    a = pl.Series("a", [1, 3, 8, 8, None, 2, 1, 3])
+    out = a.hist(bins=[0, 2], include_category=False, normalize=True)
+    expected = pl.DataFrame(
+        {"breakpoint": [0.0, 2.0, float("inf")], "proportion": [0.0, 3 / 7, 4 / 7]}
+    )
+    assert_frame_equal(out, expected)
+
+    out = a.to_frame().select(pl.col("a").hist(bins=[0, 2], normalize=True))
+    assert out.schema == {"a": pl.Float64}
+    assert out["a"].sum() == pytest.approx(1.0)
+
+    empty = pl.Series("a", [None], dtype=pl.Float64)
+    assert empty.hist(normalize=True)["proportion"].to_list() == [0.0]
+
+
 @pytest.mark.parametrize("values", [[], [None]])
 def test_hist_empty_or_all_null(values: list[None]) -> None:
     ser = pl.Series(values, dtype=pl.Float64)
@@ -153,6 +169,38 @@ def test_quantile(fruits_cars: pl.DataFrame) -> None:
     assert fruits_cars.select(pl.col("A").quantile(0.24, "linear"))["A"][0] == 1.96
 
 
+def test_quantile_approx() -> None:
+    n = 100_000
+    values = np.random.default_rng(0).permutation(n)
+    df = pl.DataFrame({"g": values % 3, "a": values, "b": values.astype(float)})
+
+    out = df.select(
+        pl.col("a").quantile_approx(0.0).alias("min"),
+        pl.col("a").quantile_approx(1.0).alias("max"),
+        pl.col("a").median_approx(),
+        pl.col("b").quantile_approx(0.99).alias("p99"),
+    )
+    assert out["min"].item() == 0.0
+    assert out["max"].item() == n - 1
+    assert out["a"].item() == pytest.approx((n - 1) / 2, rel=0.01)
+    assert out["p99"].item() == pytest.approx(0.99 * (n - 1), rel=0.01)
+
+    out = df.group_by("g").agg(
+        approx=pl.col("a").quantile_approx(0.25),
+        exact=pl.col("a").quantile(0.25, "linear"),
+    )
+    for approx, exact in out.select("approx", "exact").iter_rows():
+        assert approx == pytest.approx(exact, rel=0.01)
+
+    # Nulls are ignored, and empty groups have no quantile.
+    df = pl.DataFrame({"g": [1, 1, 2], "a": [1.0, None, None]})
+    out = df.group_by("g", maintain_order=True).agg(pl.col("a").median_approx())
+    assert out["a"].to_list() == [1.0, None]
+
+    with pytest.raises(pl.exceptions.ComputeError, match="quantile"):
+        df.select(pl.col("a").quantile_approx(1.5))
+
+
 def test_count() -> None:
     lf = pl.LazyFrame(
         {
@@ -349,6 +349,25 @@ def test_top_k_reverse() -> None:
         df.top_k(1, by=["a", "b"], reverse=[True])
 
 
+
+@pytest.mark.parametrize("k", [0, 3, 10, 500, 1000])
+def test_top_k_multiple_columns(k: int) -> None:
+    df = pl.DataFrame(
+        {
+            "score": [i % 7 for i in range(1000)],
+            "ts": [(i * 31) % 1000 for i in range(1000)],
+            "id": range(1000),
+        }
+    )
+    result = df.top_k(k, by=["score", "ts"], reverse=[False, True])
+    expected = df.sort(["score", "ts"], descending=[True, False]).head(k)
+    assert_frame_equal(result, expected, check_row_order=False)
+
+    result = df.bottom_k(k, by=["score", "ts"], reverse=[False, True])
+    expected = df.sort(["score", "ts"], descending=[False, True]).head(k)
+    assert_frame_equal(result, expected, check_row_order=False)
+
+
 def test_top_k_9385() -> None:
     lf = pl.LazyFrame({"b": [True, False]})
     result = lf.sort(["b"]).slice(0, 1)
@@ -210,3 +210,16 @@ def test_transpose_multiple_chunks() -> None:
     df = pl.DataFrame({"a": ["1"]})
     expected = pl.DataFrame({"column_0": ["1"], "column_1": ["1"]})
     assert_frame_equal(df.vstack(df).transpose(), expected)
+
+
+def test_transpose_dtype() -> None:
+    df = pl.DataFrame({"id": ["x", "y"], "a": [1, 2], "b": [1.5, 2.5]})
+    result = df.transpose(include_header=True, column_names="id", dtype=pl.Int64)
+    expected = pl.DataFrame({"column": ["a", "b"], "x": [1, 1], "y": [2, 2]})
+    assert_frame_equal(result, expected)
+
+    result = df.transpose(dtype=pl.String)
+    expected = pl.DataFrame(
+        {"column_0": ["x", "1", "1.5"], "column_1": ["y", "2", "2.5"]}
+    )
+    assert_frame_equal(result, expected)
@@ -87,3 +87,22 @@ def test_value_counts_duplicate_name() -> None:
 
 def test_count() -> None:
     assert pl.Series([None, 1, None, 2, 3]).count() == 3
+
+
+def test_value_counts_options() -> None:
+    s = pl.Series("a", [None, 3, 1, 3, None, 3, 2, 1] * 1000)
+    out = s.value_counts(sort=True, parallel=True, name="freq", normalize=True)
+    expected = pl.DataFrame(
+        {"a": [3, None, 1, 2], "freq": [0.375, 0.25, 0.25, 0.125]}
+    )
+    assert_frame_equal(out.sort("freq", "a", descending=[True, False]), expected)
+
+    # the proportions are relative to the group size in a group by context
+    df = pl.DataFrame({"g": [1, 1, 1, 2], "a": ["x", "y", "x", "y"]})
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").value_counts(sort=True, normalize=True).first()
+    )
+    assert out.to_dict(as_series=False) == {
+        "g": [1, 2],
+        "a": [{"a": "x", "proportion": 2 / 3}, {"a": "y", "proportion": 1.0}],
+    }
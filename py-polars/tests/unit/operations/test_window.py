@@ -503,3 +503,29 @@ def test_window_chunked_std_17102() -> None:
     df = pl.concat([c1, c2], rechunk=False)
     out = df.select(pl.col("B").std().over("A").alias("std"))
     assert out.unique().item() == 0.7071067811865476
+
+
+def test_window_fill_null_limit() -> None:
+    df = pl.DataFrame(
+        {
+            "key": ["a", "a", "b", "a", "b", "b"],
+            "value": [1, None, None, None, 2, None],
+        }
+    )
+    result = df.select(
+        forward=pl.col("value").forward_fill(limit=1).over("key"),
+        strategy=pl.col("value").fill_null(strategy="forward", limit=1).over("key"),
+        backward=pl.col("value").backward_fill().over("key"),
+    )
+    expected = pl.DataFrame(
+        {
+            "forward": [1, 1, None, None, 2, 2],
+            "strategy": [1, 1, None, None, 2, 2],
+            "backward": [1, None, 2, None, 2, None],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.group_by("key", maintain_order=True).agg(pl.col("value").forward_fill())
+    expected = pl.DataFrame({"key": ["a", "b"], "value": [[1, 1, 1], [None, 2, 2]]})
+    assert_frame_equal(result, expected)
@@ -18,3 +18,32 @@ def test_lf_approx_n_unique_deprecated() -> None:
         result = df.approx_n_unique()
     expected = pl.LazyFrame({"a": [2], "b": [1]}).cast(pl.UInt32)
     assert_frame_equal(result, expected)
+
+
+def test_approx_n_unique_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2, 3],
+            "a": ["1", "2", "1", None, None, "x"],
+        }
+    )
+    result = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").approx_n_unique(),
+        pl.col("a").approx_n_unique(precision=12).alias("low_precision"),
+    )
+    expected = pl.DataFrame(
+        {"g": [1, 2, 3], "a": [2, 1, 1], "low_precision": [2, 1, 1]},
+        schema_overrides={"a": pl.UInt32, "low_precision": pl.UInt32},
+    )
+    assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("precision", [4, 10, 18])
+def test_approx_n_unique_precision(precision: int) -> None:
+    n = 100_000
+    result = pl.select(pl.int_range(n).approx_n_unique(precision=precision)).item()
+    margin = 1.04 / (2**precision) ** 0.5 * 6
+    assert abs(result - n) <= margin * n
+
+    with pytest.raises(pl.exceptions.ComputeError, match="precision"):
+        pl.select(pl.int_range(n).approx_n_unique(precision=3))
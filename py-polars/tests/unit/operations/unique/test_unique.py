@@ -154,3 +154,18 @@ def test_unique_with_null() -> None:
         {"a": [1, 2, 3, 4], "b": ["a", "b", "c", "c"], "c": [None, None, None, None]}
     )
     assert_frame_equal(df.unique(maintain_order=True), expected_df)
+
+
+@pytest.mark.parametrize("subset", [None, "a", ["a", "b"]])
+@pytest.mark.parametrize("maintain_order", [False, True])
+def test_unique_keep_any(subset: str | list[str] | None, maintain_order: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, None, 1, 2, None, 3],
+            "b": ["x", "y", "z", "x", "y", "z", None],
+            "c": [1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 4.0],
+        }
+    )
+    result = df.unique(subset, keep="any", maintain_order=maintain_order)
+    expected = df.head(3).vstack(df.tail(1))
+    assert_frame_equal(result, expected, check_row_order=maintain_order)
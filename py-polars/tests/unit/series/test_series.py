@@ -985,6 +985,13 @@ def test_mode() -> None:
     # sorted data
     assert pl.int_range(0, 3, eager=True).mode().to_list() == [2, 1, 0]
 
+    s = pl.Series("a", [3, 1, None, 3, 1, None])
+    assert s.mode(tie_break="first").item() == 3
+    assert s.mode(tie_break="min").item() == 1
+    assert s.mode(tie_break="max").item() == 3
+    with pytest.raises(ValueError, match="tie_break"):
+        s.mode(tie_break="last")  # type: ignore[arg-type]
+
 
 def test_rank() -> None:
     s = pl.Series("a", [1, 2, 3, 2, 2, 3, 0])
@@ -1722,6 +1729,12 @@ def test_product() -> None:
     out = a.product()
     assert math.isnan(out)
 
+    a = pl.Series("a", [2**32, 2**32])
+    assert a.product() == 0
+    assert a.product(overflow="float64") == 2.0**64
+    with pytest.raises(ValueError, match="overflow"):
+        a.product(overflow="int128")  # type: ignore[arg-type]
+
 
 def test_ceil() -> None:
     s = pl.Series([1.8, 1.2, 3.0])
@@ -212,6 +212,66 @@ def test_group_by_ordinal_position() -> None:
         assert_frame_equal(res2, expected.select(pl.nth(0, 1)))
 
 
+
+def test_group_by_grouping_sets() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["xx", "yy", "xx", "yy"],
+            "b": [1, 1, 2, 1],
+            "c": [10, 20, 30, 40],
+        }
+    )
+    res = df.sql(
+        """
+        SELECT a, b, SUM(c) AS total, GROUPING(a, b) AS g
+        FROM self
+        GROUP BY ROLLUP(a, b)
+        ORDER BY g, a, b
+        """
+    )
+    assert res.rows() == [
+        ("xx", 1, 10, 0),
+        ("xx", 2, 30, 0),
+        ("yy", 1, 60, 0),
+        ("xx", None, 40, 1),
+        ("yy", None, 60, 1),
+        (None, None, 100, 3),
+    ]
+
+    res = df.sql(
+        """
+        SELECT a, b, COUNT(*) AS n
+        FROM self
+        GROUP BY CUBE(a, b)
+        ORDER BY a NULLS FIRST, b NULLS FIRST
+        """
+    )
+    assert res.rows() == [
+        (None, None, 4),
+        (None, 1, 3),
+        (None, 2, 1),
+        ("xx", None, 2),
+        ("xx", 1, 1),
+        ("xx", 2, 1),
+        ("yy", None, 2),
+        ("yy", 1, 2),
+    ]
+
+    res = df.sql(
+        """
+        SELECT a, b, MAX(c) AS c
+        FROM self
+        GROUP BY GROUPING SETS ((a), (b))
+        ORDER BY a NULLS LAST, b
+        """
+    )
+    assert res.rows() == [
+        ("xx", None, 30),
+        ("yy", None, 40),
+        (None, 1, 40),
+        (None, 2, 30),
+    ]
+
 def test_group_by_errors() -> None:
     df = pl.DataFrame(
         {
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 from io import BytesIO
 from pathlib import Path
+from typing import Any
 
 import pytest
 
@@ -123,7 +124,7 @@ def test_join_inner(foods_ipc_path: Path, join_clause: str) -> None:
         eager=True,
     )
 
-    assert out.to_dict(as_series=False) == {
+    expected = {
         "category": ["vegetables", "vegetables"],
         "calories": [45, 20],
         "fats_g": [0.5, 0.0],
@@ -133,6 +134,36 @@ def test_join_inner(foods_ipc_path: Path, join_clause: str) -> None:
         "fats_g:foods2": [0.5, 0.5],
         "sugars_g:foods2": [2, 2],
     }
+    if join_clause.startswith("USING"):
+        # the key columns of a USING join are coalesced
+        del expected["category:foods2"]
+    assert out.to_dict(as_series=False) == expected
+
+
+@pytest.mark.parametrize(
+    ("join_type", "expected"),
+    [
+        ("INNER", [(2, "y", 20.0)]),
+        ("LEFT", [(1, "x", None), (2, "y", 20.0)]),
+        ("FULL", [(1, "x", None), (2, "y", 20.0), (3, None, 30.0)]),
+    ],
+)
+def test_join_using_natural(join_type: str, expected: list[tuple[Any, ...]]) -> None:
+    frames = {
+        "tbl_a": pl.DataFrame({"id": [1, 2], "x": ["x", "y"]}),
+        "tbl_b": pl.DataFrame({"id": [2, 3], "y": [20.0, 30.0]}),
+        "tbl_c": pl.DataFrame({"z": [0]}),
+    }
+    with pl.SQLContext(frames) as ctx:
+        for join_clause in (
+            f"{join_type} JOIN tbl_b USING (id)",
+            f"NATURAL {join_type} JOIN tbl_b",
+        ):
+            out = ctx.execute(f"SELECT * FROM tbl_a {join_clause} ORDER BY id")
+            assert out.collect().rows() == expected
+
+        with pytest.raises(SQLInterfaceError, match="has no common columns"):
+            ctx.execute("SELECT * FROM tbl_a NATURAL JOIN tbl_c")
 
 
 @pytest.mark.parametrize(
@@ -269,3 +269,27 @@ def test_read_csv(tmp_path: Path) -> None:
         match="`read_csv` expects a single file path; found 3 arguments",
     ):
         pl.sql("SELECT * FROM read_csv('a','b','c')")
+
+
+def test_read_files_with_options(tmp_path: Path) -> None:
+    csv_target = tmp_path / "data.csv"
+    csv_target.write_text("1;x\n2;y\n3;z\n")
+    res = pl.sql(
+        f"SELECT * FROM read_csv('{csv_target}', separator => ';', has_header => false)"
+    ).collect()
+    assert res.rows() == [(1, "x"), (2, "y"), (3, "z")]
+
+    for n in range(3):
+        pl.DataFrame({"n": [n, n]}).write_parquet(tmp_path / f"data_{n}.parquet")
+    parquet_glob = tmp_path / "data_*.parquet"
+    res = pl.sql(f"SELECT SUM(n) FROM read_parquet('{parquet_glob}')").collect()
+    assert res.item() == 6
+
+    res = pl.sql(f"SELECT * FROM read_parquet('{parquet_glob}', n_rows => 3)").collect()
+    assert res["n"].to_list() == [0, 0, 1]
+
+    with pytest.raises(
+        SQLSyntaxError,
+        match="`read_csv` does not support the 'sep' option",
+    ):
+        pl.sql(f"SELECT * FROM read_csv('{csv_target}', sep => ';')")
@@ -58,6 +58,46 @@ def test_except_intersect_errors(op: str) -> None:
         pl.sql(f"SELECT x FROM df1 {op} SELECT x, y FROM df2", eager=False)
 
 
+
+def test_set_ops_positional_columns() -> None:
+    with pl.SQLContext(
+        df1=pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]}),
+        df2=pl.DataFrame({"c": [3.5, 2.0], "d": ["z", "y"]}),
+        df3=pl.DataFrame({"e": [1], "f": ["x"]}),
+        eager=True,
+    ) as ctx:
+        # columns are matched by position and cast to their supertypes
+        res = ctx.execute("SELECT * FROM df1 UNION ALL SELECT * FROM df2")
+        assert res.schema == {"a": pl.Float64, "b": pl.String}
+        assert res.rows() == [
+            (1.0, "x"),
+            (2.0, "y"),
+            (3.0, "z"),
+            (3.5, "z"),
+            (2.0, "y"),
+        ]
+
+        res = ctx.execute("SELECT * FROM df1 INTERSECT SELECT * FROM df2")
+        assert res.rows() == [(2.0, "y")]
+
+        # nested set operations
+        res = ctx.execute(
+            """
+            (SELECT * FROM df1 EXCEPT SELECT * FROM df2)
+            UNION
+            (SELECT * FROM df1 INTERSECT SELECT * FROM df3)
+            """
+        )
+        assert sorted(res.rows()) == [(1.0, "x"), (3.0, "z")]
+
+        with pytest.raises(
+            SQLInterfaceError,
+            match=r"UNION column 'a' has incompatible types \(bool and date\)",
+        ):
+            ctx.execute(
+                "SELECT a > 1, b FROM df1 UNION SELECT DATE('2020-01-01'), d FROM df2"
+            )
+
 @pytest.mark.parametrize(
     ("cols1", "cols2", "union_subtype", "expected"),
     [
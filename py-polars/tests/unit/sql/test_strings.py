@@ -324,6 +324,54 @@ def test_string_replace() -> None:
             ctx.execute("SELECT REPLACE(words,'coffee') FROM df")
 
 
+
+def test_string_regexp_replace() -> None:
+    # note: backslashes are escaped in SQL string literals
+    df = pl.DataFrame({"s": ["12-34 56-78", "Abc abc", None]})
+    res = df.sql(
+        r"""
+        SELECT
+          REGEXP_REPLACE(s, '([0-9]+)-([0-9]+)', '\\2-\\1') AS r1,
+          REGEXP_REPLACE(s, '([0-9]+)-([0-9]+)', '\\2-\\1', 'g') AS r2,
+          REGEXP_REPLACE(s, 'abc', '$x', 'gi') AS r3,
+        FROM self
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "r1": ["34-12 56-78", "Abc abc", None],
+        "r2": ["34-12 78-56", "Abc abc", None],
+        "r3": ["12-34 56-78", "$x $x", None],
+    }
+    with pytest.raises(SQLSyntaxError, match="invalid 'flags' for REGEXP_REPLACE"):
+        df.sql("SELECT REGEXP_REPLACE(s, 'a', 'b', 'q') FROM self")
+
+
+def test_string_pad_split_part_translate() -> None:
+    df = pl.DataFrame({"s": ["a,bb,ccc", "xyz", "", None]})
+    res = df.sql(
+        """
+        SELECT
+          LPAD(s, 5, '0') AS lpad,
+          RPAD(s, 4) AS rpad,
+          SPLIT_PART(s, ',', 2) AS part2,
+          SPLIT_PART(s, ',', -1) AS part_last,
+          TRANSLATE(s, 'a,x', 'A;') AS translated,
+        FROM self
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "lpad": ["a,bb,", "00xyz", "00000", None],
+        "rpad": ["a,bb", "xyz ", "    ", None],
+        "part2": ["bb", "", "", None],
+        "part_last": ["ccc", "xyz", "", None],
+        "translated": ["A;bb;ccc", "yz", "", None],
+    }
+    with pytest.raises(SQLSyntaxError, match="LPAD 'fill' must be a single character"):
+        df.sql("SELECT LPAD(s, 5, 'ab') FROM self")
+    with pytest.raises(SQLSyntaxError, match="invalid 'part' for SPLIT_PART"):
+        df.sql("SELECT SPLIT_PART(s, ',', 0) FROM self")
+
+
 def test_string_substr() -> None:
     df = pl.DataFrame(
         {"scol": ["abcdefg", "abcde", "abc", None], "n": [-2, 3, 2, None]}
@@ -146,3 +146,34 @@ def test_in_subquery() -> None:
             """,
             eager=True,
         )
+
+
+def test_scalar_subquery() -> None:
+    df = pl.DataFrame({"x": [1, 2, 3, 4], "y": [10, 20, 30, 40]})
+    res = pl.SQLContext(df=df).execute(
+        """
+        SELECT x, y - (SELECT MIN(y) FROM df) AS dy
+        FROM df
+        WHERE x > (SELECT AVG(x) FROM df)
+        """,
+        eager=True,
+    )
+    assert_frame_equal(res, pl.DataFrame({"x": [3, 4], "dy": [20, 30]}))
+
+
+@pytest.mark.parametrize(
+    ("condition", "expected"),
+    [
+        ("EXISTS (SELECT * FROM df_other WHERE w > 2)", [1, 2, 3]),
+        ("EXISTS (SELECT * FROM df_other WHERE w > 5)", []),
+        ("NOT EXISTS (SELECT w FROM df_other WHERE w > 5)", [1, 2, 3]),
+    ],
+)
+def test_exists_subquery(condition: str, expected: list[int]) -> None:
+    df = pl.DataFrame({"x": [1, 2, 3]})
+    df_other = pl.DataFrame({"w": [1, 2, 3]})
+    res = pl.SQLContext(df=df, df_other=df_other).execute(
+        f"SELECT x FROM df WHERE {condition}",
+        eager=True,
+    )
+    assert_frame_equal(res, pl.DataFrame({"x": expected}, schema={"x": pl.Int64}))
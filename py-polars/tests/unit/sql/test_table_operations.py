@@ -53,6 +53,24 @@ def test_explain_query(test_frame: pl.LazyFrame) -> None:
         )
 
 
+def test_explain_optimized_plan(test_frame: pl.LazyFrame) -> None:
+    # the plan is optimized, and explaining doesn't execute other statements
+    with pl.SQLContext(frame=test_frame) as ctx:
+        plan = ctx.execute(
+            "EXPLAIN SELECT y FROM frame WHERE x > 1", eager=True
+        ).to_series()
+        assert plan.name == "Logical Plan"
+        assert "SELECTION" in "\n".join(plan)
+
+        for stmt in ("CREATE TABLE tbl AS SELECT * FROM frame", "DROP TABLE frame"):
+            with pytest.raises(SQLInterfaceError, match="only supports queries"):
+                ctx.execute(f"EXPLAIN {stmt}")
+        assert ctx.tables() == ["frame"]
+
+        with pytest.raises(SQLInterfaceError, match="ANALYZE is not supported"):
+            ctx.execute("EXPLAIN ANALYZE SELECT * FROM frame")
+
+
 def test_show_tables(test_frame: pl.LazyFrame) -> None:
     # 'show tables' lists all tables registered with the sql context in sorted order
     with pl.SQLContext(
@@ -81,3 +99,37 @@ def test_truncate_table(truncate_sql: str, test_frame: pl.LazyFrame) -> None:
 
         res = ctx.execute("SELECT * FROM frame")
         assert_frame_equal(res, expected)
+
+
+def test_create_table_insert_into(test_frame: pl.LazyFrame) -> None:
+    # a multi-statement script that creates a table and appends rows to it
+    with pl.SQLContext(frame=test_frame) as ctx:
+        res = ctx.execute(
+            """
+            CREATE TABLE tbl AS SELECT x, y FROM frame WHERE x < 3;
+            INSERT INTO tbl SELECT x, y FROM frame WHERE x = 3;
+            INSERT INTO tbl VALUES (4, 'ddd');
+            INSERT INTO tbl (y) VALUES ('eee');
+            SELECT * FROM tbl
+            """,
+            eager=True,
+        )
+        assert ctx.tables() == ["frame", "tbl"]
+        assert_frame_equal(
+            res,
+            pl.DataFrame(
+                {
+                    "x": [1, 2, 3, 4, None],
+                    "y": ["aaa", "bbb", "ccc", "ddd", "eee"],
+                },
+                schema_overrides={"x": pl.UInt8},
+            ),
+        )
+
+        with pytest.raises(SQLInterfaceError, match="expects 2 columns; found 1"):
+            ctx.execute("INSERT INTO tbl VALUES (5)")
+        ctx.execute("CREATE TABLE flags AS SELECT x > 1 AS flag FROM frame")
+        with pytest.raises(SQLInterfaceError, match="cannot insert date values"):
+            ctx.execute("INSERT INTO flags SELECT z FROM frame")
+        with pytest.raises(SQLInterfaceError, match="'missing' does not exist"):
+            ctx.execute("INSERT INTO missing VALUES (5, 'fff')")
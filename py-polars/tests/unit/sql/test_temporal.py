@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from datetime import date, datetime, time
+from datetime import date, datetime, time, timedelta, timezone
 from typing import Any, Literal
 
 import pytest
@@ -282,3 +282,52 @@ def test_timestamp_time_unit_errors() -> None:
             match="sql parser error: Expected literal int, found: - ",
         ):
             ctx.execute("SELECT ts::timestamp(-3) FROM frame_data")
+
+
+def test_interval_arithmetic() -> None:
+    df = pl.DataFrame(
+        {
+            "ts": [datetime(2024, 1, 31, 10, 30), datetime(2024, 3, 15, 12, 0)],
+            "dt": [date(2024, 1, 31), date(2024, 3, 15)],
+        }
+    )
+    with pl.SQLContext(df=df, eager=True) as ctx:
+        res = ctx.execute(
+            """
+            SELECT
+              ts + INTERVAL '1 hour' AS ts1,
+              ts - INTERVAL '3' DAY AS ts2,
+              ts + INTERVAL '1 month 2 days' AS ts3,
+              INTERVAL '1 year' + ts AS ts4,
+              dt - INTERVAL '1 month' AS dt1,
+            FROM df
+            """
+        )
+    expected = pl.DataFrame(
+        {
+            "ts1": [datetime(2024, 1, 31, 11, 30), datetime(2024, 3, 15, 13, 0)],
+            "ts2": [datetime(2024, 1, 28, 10, 30), datetime(2024, 3, 12, 12, 0)],
+            "ts3": [datetime(2024, 3, 2, 10, 30), datetime(2024, 4, 17, 12, 0)],
+            "ts4": [datetime(2025, 1, 31, 10, 30), datetime(2025, 3, 15, 12, 0)],
+            "dt1": [date(2023, 12, 31), date(2024, 2, 15)],
+        }
+    )
+    assert_frame_equal(res, expected)
+
+    with pytest.raises(
+        SQLSyntaxError,
+        match="interval with a 'day' field must have an integer value",
+    ):
+        pl.sql_expr("INTERVAL '1.5' DAY")
+
+
+def test_now_minus_interval() -> None:
+    now = datetime.now(timezone.utc).replace(tzinfo=None)
+    df = pl.DataFrame(
+        {
+            "id": [1, 2, 3],
+            "ts": [now - timedelta(days=2), now - timedelta(days=10), now],
+        }
+    )
+    res = df.sql("SELECT id FROM self WHERE ts > NOW() - INTERVAL '7 days'")
+    assert res["id"].to_list() == [1, 3]
@@ -361,3 +361,59 @@ def test_streaming_with_hconcat(tmp_path: Path) -> None:
     )
 
     assert_frame_equal(result, expected)
+
+
+def test_streaming_window_over() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", None, "b", "a"],
+            "h": [1, 1, 2, 2, 1, 1],
+            "x": [1, 2, 3, 4, 5, 6],
+        }
+    )
+    q = df.lazy().with_columns(
+        pl.col("x").sum().over("g").alias("sum"),
+        (pl.col("x") - pl.col("x").mean().over("g", "h")).alias("centered"),
+        pl.len().over("g").alias("len"),
+    )
+
+    assert q.explain(streaming=True).startswith("STREAMING")
+    assert_frame_equal(q.collect(streaming=True), q.collect())
+
+    # Windows that don't give a single value per group are not rewritten.
+    q = df.lazy().select(pl.col("x").cum_sum().over("g"))
+    assert not q.explain(streaming=True).startswith("STREAMING")
+    assert_frame_equal(q.collect(streaming=True), q.collect())
+
+
+@pytest.mark.write_disk()
+def test_streaming_metrics_callback(tmp_path: Path, monkeypatch: Any) -> None:
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+    events: list[dict[str, Any]] = []
+
+    q = (
+        pl.LazyFrame({"a": np.arange(10_000)})
+        .filter(pl.col("a") % 2 == 0)
+        .sort("a", descending=True)
+    )
+    pl.set_streaming_metrics_callback(events.append)
+    try:
+        result = q.collect(streaming=True)
+    finally:
+        pl.set_streaming_metrics_callback(None)
+    assert result.height == 5_000
+
+    kinds = {e["event"] for e in events}
+    assert {"batch", "operator", "sink", "spill"} <= kinds
+    batches = [e for e in events if e["event"] == "batch"]
+    assert all(0 < e["n_chunks"] <= e["queue_depth"] for e in batches)
+    filtered = [e for e in events if e.get("operator") == "filter"]
+    assert sum(e["rows_in"] for e in filtered) == 10_000
+    assert sum(e["rows_out"] for e in filtered) == 5_000
+    assert {e["operation"] for e in events if e["event"] == "spill"} == {"sort"}
+
+    # Removing the callback stops the events.
+    n_events = len(events)
+    q.collect(streaming=True)
+    assert len(events) == n_events
@@ -519,3 +519,72 @@ def test_streaming_group_by_boolean_mean_15610(
     )
 
     assert_frame_equal(out, expect)
+
+
+@pytest.mark.parametrize("keys", [["a"], ["b"], ["a", "b"]])
+def test_streaming_group_by_maintain_order(keys: list[str]) -> None:
+    np.random.seed(0)
+    a = np.random.randint(0, 1000, 10_000)
+    lf = pl.concat(
+        [
+            pl.LazyFrame({"a": a[i : i + 1000], "b": a[i : i + 1000].astype(str)})
+            for i in range(0, 10_000, 1000)
+        ],
+        rechunk=False,
+    )
+    q = lf.group_by(keys, maintain_order=True).agg(
+        pl.len(), pl.col("a").sum().alias("a_sum")
+    )
+
+    assert q.explain(streaming=True).startswith("STREAMING")
+    assert_frame_equal(q.collect(streaming=True), q.collect(streaming=False))
+
+    q = q.slice(10, 20)
+    assert_frame_equal(q.collect(streaming=True), q.collect(streaming=False))
+
+
+@pytest.mark.write_disk()
+def test_streaming_group_by_maintain_order_ooc(
+    random_integers: pl.Series,
+    tmp_path: Path,
+    monkeypatch: Any,
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+
+    lf = random_integers.to_frame().lazy()
+    q = lf.group_by("a", maintain_order=True).agg(pl.len())
+    assert_frame_equal(q.collect(streaming=True), q.collect(streaming=False))
+
+
+def test_streaming_group_by_approx_quantiles() -> None:
+    np.random.seed(0)
+    lf = pl.concat(
+        [
+            pl.LazyFrame(
+                {
+                    "g": np.random.randint(0, 5, 10_000),
+                    "v": np.random.uniform(0, 1000, 10_000),
+                }
+            )
+            for _ in range(10)
+        ],
+        rechunk=False,
+    )
+    lf = pl.concat([lf, pl.LazyFrame({"g": [5], "v": [None]}, schema=lf.schema)])
+    q = lf.group_by("g").agg(
+        pl.col("v").median().alias("median"),
+        pl.col("v").quantile(0.9).alias("q90"),
+        pl.col("v").cast(pl.Int32).quantile(0.1).alias("q10"),
+    )
+
+    # Exact medians and quantiles cannot be streamed.
+    assert not q.explain(streaming=True).startswith("STREAMING")
+
+    with pl.Config(set_streaming_approx_quantiles=True):
+        assert q.explain(streaming=True).startswith("STREAMING")
+        result = q.collect(streaming=True).sort("g")
+    expected = q.collect(streaming=False).sort("g")
+    assert result[-1, "median"] is None
+    assert_frame_equal(result, expected, check_exact=False, rtol=0.02)
@@ -36,6 +36,37 @@ def test_scan_slice_streaming(io_files_path: Path) -> None:
     assert df.shape == (5, 4)
 
 
+@pytest.mark.write_disk()
+def test_streaming_scan_csv_many_small_files(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    for i in range(20):
+        df = pl.DataFrame({"a": range(i * 10, i * 10 + i + 1), "b": str(i)})
+        df.write_csv(tmp_path / f"small_{i:02}.csv")
+
+    q = pl.scan_csv(tmp_path / "small_*.csv", row_index_name="idx").filter(
+        pl.col("a") % 2 == 0
+    )
+    assert_frame_equal(q.collect(streaming=True), q.collect())
+
+
+@pytest.mark.write_disk()
+def test_streaming_scan_csv_single_file_morsels(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    n = 200_000
+    df = pl.DataFrame(
+        {
+            "a": range(n),
+            "b": [f"line\n{i}" if i % 7 == 0 else str(i) for i in range(n)],
+        }
+    )
+    df.write_csv(tmp_path / "large.csv")
+
+    q = pl.scan_csv(tmp_path / "large.csv", row_index_name="idx")
+    assert_frame_equal(q.collect(streaming=True), q.collect())
+    q = q.head(123_456)
+    assert_frame_equal(q.collect(streaming=True), q.collect())
+
+
 @pytest.mark.parametrize("dtype", [pl.Int8, pl.UInt8, pl.Int16, pl.UInt16])
 def test_scan_csv_overwrite_small_dtypes(
     io_files_path: Path, dtype: pl.DataType
@@ -127,6 +158,35 @@ def test_sink_csv_14494(tmp_path: Path) -> None:
     assert pl.read_csv(tmp_path / "sink.csv").columns == ["c"]
 
 
+@pytest.mark.write_disk()
+def test_sink_parquet_checkpoint(
+    tmp_path: Path, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    monkeypatch.setenv("POLARS_SINK_CHECKPOINT_ROWS", "100")
+    df = pl.DataFrame({"a": range(1_000), "b": [str(i) for i in range(1_000)]})
+    out = tmp_path / "out"
+
+    df.lazy().sink_parquet(out, checkpoint=True)
+    parts = sorted(out.glob("part-*.parquet"))
+    assert len(parts) > 1
+    assert_frame_equal(pl.read_parquet(out / "*.parquet"), df)
+
+    # Simulate an interrupted run: the last part is not committed and another part
+    # was only partially written.
+    manifest = (out / "_checkpoint").read_text().splitlines()
+    assert manifest[-1] == "finished"
+    (out / "_checkpoint").write_text("\n".join(manifest[:-2]) + "\n")
+    (out / "part-99999.parquet").write_bytes(b"garbage")
+
+    df.lazy().sink_parquet(out, checkpoint=True)
+    assert sorted(out.glob("part-*.parquet")) == parts
+    assert_frame_equal(pl.read_parquet(out / "*.parquet"), df)
+
+    # A checkpoint of a query with another schema is not resumed.
+    with pytest.raises(pl.ComputeError, match="another schema"):
+        df.lazy().select("a").sink_parquet(out, checkpoint=True)
+
+
 def test_sink_csv_with_options() -> None:
     """
     Test with all possible options.
@@ -152,6 +212,7 @@ def test_sink_csv_with_options() -> None:
             null_value="BOOM",
             quote_style="always",
             maintain_order=False,
+            checkpoint=True,
         )
 
         ldf.optimization_toggle().sink_csv.assert_called_with(
@@ -169,6 +230,7 @@ def test_sink_csv_with_options() -> None:
             null_value="BOOM",
             quote_style="always",
             maintain_order=False,
+            checkpoint=True,
         )
 
 
@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 from datetime import datetime
-from typing import TYPE_CHECKING, Literal
+from typing import TYPE_CHECKING, Any, Literal
 
 import numpy as np
 import pandas as pd
@@ -102,6 +102,27 @@ def test_streaming_joins() -> None:
         assert_frame_equal(a, pl_result, check_dtypes=False)
 
 
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("how", ["inner", "left"])
+def test_streaming_join_ooc(
+    how: JoinStrategy, tmp_path: Path, monkeypatch: Any, capfd: Any
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_OOC_JOIN_MEMORY_LIMIT", "1")
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    df_a = pl.DataFrame(
+        {"a": [None, *range(1_000)], "b": [f"a{i}" for i in range(1_001)]}
+    )
+    df_b = pl.DataFrame({"a": [None, *range(0, 2_000, 3)], "c": range(668)})
+
+    expected = df_a.join(df_b, on="a", how=how).sort("b", "c")
+    out = df_a.lazy().join(df_b.lazy(), on="a", how=how).collect(streaming=True)
+    assert_frame_equal(out.sort("b", "c"), expected)
+    assert "OOC join started" in capfd.readouterr().err
+
+
 def test_streaming_cross_join_empty() -> None:
     df1 = pl.LazyFrame(
         data={
@@ -127,6 +148,22 @@ def test_streaming_cross_join_empty() -> None:
     assert out.columns == ["col1", "col1_right"]
 
 
+@pytest.mark.write_disk()
+def test_streaming_cross_join_ooc(tmp_path: Path, monkeypatch: Any, capfd: Any) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    df_a = pl.DataFrame({"a": range(300), "b": [f"a{i}" for i in range(300)]})
+    df_b = pl.DataFrame({"a": range(500)})
+
+    expected = df_a.join(df_b, how="cross").sort("a", "a_right")
+    with pl.Config(set_streaming_memory_limit=1):
+        out = df_a.lazy().join(df_b.lazy(), how="cross").collect(streaming=True)
+    assert_frame_equal(out.sort("a", "a_right"), expected)
+    assert "OOC cross join started" in capfd.readouterr().err
+
+
 def test_streaming_join_rechunk_12498() -> None:
     rows = pl.int_range(0, 2)
 
@@ -92,6 +92,23 @@ def test_ooc_sort(tmp_path: Path, monkeypatch: Any) -> None:
         assert_series_equal(out, s.sort(descending=descending))
 
 
+@pytest.mark.write_disk()
+def test_ooc_sort_memory_limit(tmp_path: Path, monkeypatch: Any, capfd: Any) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_OOC_SORT_MEMORY_LIMIT", str(1 << 16))
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    s = pl.arange(0, 100_000, eager=True).rename("idx")
+    df = s.shuffle(seed=1).to_frame()
+
+    out = df.lazy().sort("idx").collect(streaming=True).to_series()
+    assert_series_equal(out, s)
+    err = capfd.readouterr().err
+    assert "OOC sort started" in err
+    assert "OOC sort forced" not in err
+
+
 @pytest.mark.debug()
 @pytest.mark.write_disk()
 @pytest.mark.parametrize("spill_source", [True, False])
@@ -293,3 +310,18 @@ def test_sort_descending_nulls_last(descending: bool, nulls_last: bool) -> None:
         .collect(streaming=True),
         pl.DataFrame({"x": ref_x, "y": ref_y}),
     )
+
+
+@pytest.mark.write_disk()
+def test_streaming_memory_budget(tmp_path: Path, monkeypatch: Any, capfd: Any) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    s = pl.arange(0, 1_000_000, eager=True).rename("idx")
+    df = s.shuffle(seed=1).to_frame()
+
+    with pl.Config(set_streaming_memory_limit=1 << 20, set_streaming_queue_depth=2):
+        out = df.lazy().sort("idx").collect(streaming=True).to_series()
+    assert_series_equal(out, s)
+    assert "OOC sort started" in capfd.readouterr().err
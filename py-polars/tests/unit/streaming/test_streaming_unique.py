@@ -52,3 +52,19 @@ def test_streaming_unique(monkeypatch: Any, capfd: Any) -> None:
     assert_frame_equal(q.collect(streaming=True), q.collect(streaming=False))
     (_, err) = capfd.readouterr()
     assert "df -> re-project-sink -> sort_multiple" in err
+
+
+@pytest.mark.parametrize("subset", [None, "a", ["a", "b"]])
+def test_streaming_unique_keep_any(subset: str | list[str] | None) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, None, 1, 2, None, 3],
+            "b": ["x", "y", "z", "x", "y", "z", None],
+            "c": [1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 4.0],
+        }
+    )
+    q = df.lazy().unique(subset, keep="any", maintain_order=False)
+    assert q.explain(streaming=True).startswith("STREAMING")
+    assert_frame_equal(
+        q.collect(streaming=True), q.collect(streaming=False), check_row_order=False
+    )
@@ -742,6 +742,18 @@ def test_set_streaming_chunk_size() -> None:
         cfg.set_streaming_chunk_size(0)
 
 
+def test_set_streaming_memory_limit_and_queue_depth() -> None:
+    with pl.Config() as cfg:
+        cfg.set_streaming_memory_limit(1 << 20).set_streaming_queue_depth(2)
+        assert os.environ.get("POLARS_STREAMING_MEMORY_LIMIT") == str(1 << 20)
+        assert os.environ.get("POLARS_STREAMING_QUEUE_DEPTH") == "2"
+
+    with pytest.raises(ValueError), pl.Config() as cfg:
+        cfg.set_streaming_memory_limit(-1)
+    with pytest.raises(ValueError), pl.Config() as cfg:
+        cfg.set_streaming_queue_depth(0)
+
+
 def test_set_fmt_str_lengths_invalid_length() -> None:
     with pl.Config() as cfg:
         with pytest.raises(ValueError):
@@ -822,7 +834,15 @@ def test_warn_unstable(recwarn: pytest.WarningsRecorder) -> None:
             True,
             "1",
         ),
+        (
+            "POLARS_STREAMING_APPROX_QUANTILE",
+            "set_streaming_approx_quantiles",
+            True,
+            "1",
+        ),
         ("POLARS_STREAMING_CHUNK_SIZE", "set_streaming_chunk_size", 100, "100"),
+        ("POLARS_STREAMING_MEMORY_LIMIT", "set_streaming_memory_limit", 100, "100"),
+        ("POLARS_STREAMING_QUEUE_DEPTH", "set_streaming_queue_depth", 2, "2"),
         ("POLARS_TABLE_WIDTH", "set_tbl_width_chars", 80, "80"),
         ("POLARS_VERBOSE", "set_verbose", True, "1"),
         ("POLARS_WARN_UNSTABLE", "warn_unstable", True, "1"),
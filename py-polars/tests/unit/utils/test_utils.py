@@ -171,6 +171,25 @@ def test_estimated_size() -> None:
         s.estimated_size("milkshake")  # type: ignore[arg-type]
 
 
+def test_memory_usage() -> None:
+    df = pl.DataFrame(
+        {"a": [1, None, 3], "b": ["x", "y", "x"]},
+        schema={"a": pl.Int64, "b": pl.Categorical},
+    )
+    df = pl.concat([df, df], rechunk=False)
+    usage = df.memory_usage()
+
+    assert usage["column"].to_list() == ["a", "b"]
+    assert usage["n_chunks"].to_list() == [2, 2]
+    assert usage["validity_bytes"].to_list() == [2, 0]
+    assert usage["values_bytes"][0] == 6 * 8
+    assert usage["dictionary_bytes"][0] == 0
+    assert usage["dictionary_bytes"][1] > 0
+    assert usage["total_bytes"].to_list() == [
+        s.estimated_size() for s in df.get_columns()
+    ]
+
+
 @pytest.mark.parametrize(
     ("v1", "v2"),
     [